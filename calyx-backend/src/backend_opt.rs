@@ -12,6 +12,7 @@ pub enum BackendOpt {
     Mlir,
     Resources,
     Sexp,
+    Json,
     #[cfg(feature = "yxi")]
     Yxi,
     Firrtl,
@@ -30,6 +31,7 @@ fn backends() -> Vec<(&'static str, BackendOpt)> {
         ("mlir", BackendOpt::Mlir),
         ("resources", BackendOpt::Resources),
         ("sexp", BackendOpt::Sexp),
+        ("json", BackendOpt::Json),
         #[cfg(feature = "yxi")]
         ("yxi", BackendOpt::Yxi),
         ("firrtl", BackendOpt::Firrtl),
@@ -72,6 +74,7 @@ impl ToString for BackendOpt {
             Self::Mlir => "mlir",
             Self::Resources => "resources",
             Self::Sexp => "sexp",
+            Self::Json => "json",
             Self::Verilog => "verilog",
             Self::Xilinx => "xilinx",
             Self::XilinxXml => "xilinx-xml",
@@ -11,10 +11,14 @@ use std::cell::RefCell;
 use std::collections::HashSet;
 use std::io;
 
+mod primitives;
+
 pub(super) const SPACING: &str = "    ";
 
 /// Implements a simple FIRRTL backend. The backend only accepts Calyx programs with no control
-/// and no groups.
+/// and no groups. Primitives are defined natively in FIRRTL with `--emit-firrtl-primitives`
+/// or as extmodules with `--emit-primitive-extmodules`; with both flags, extmodules are only
+/// used for primitives without a FIRRTL implementation.
 #[derive(Default)]
 pub struct FirrtlBackend;
 
@@ -37,8 +41,8 @@ impl Backend for FirrtlBackend {
     fn emit(ctx: &ir::Context, file: &mut OutputFile) -> CalyxResult<()> {
         let out = &mut file.get_write();
         writeln!(out, "circuit {}:", ctx.entrypoint)?;
-        if ctx.bc.emit_primitive_extmodules {
-            emit_extmodules(ctx, out)?;
+        if ctx.bc.emit_firrtl_primitives || ctx.bc.emit_primitive_extmodules {
+            emit_primitives(ctx, out)?;
         }
         for comp in ctx.components.iter() {
             emit_component(comp, out)?
@@ -47,11 +51,11 @@ impl Backend for FirrtlBackend {
     }
 }
 
-fn emit_extmodules<F: io::Write>(
+fn emit_primitives<F: io::Write>(
     ctx: &ir::Context,
     out: &mut F,
 ) -> Result<(), calyx_utils::Error> {
-    let mut extmodule_set: HashSet<String> = HashSet::new();
+    let mut module_set: HashSet<String> = HashSet::new();
     for comp in &ctx.components {
         for cell in comp.cells.iter() {
             let cell_borrowed = cell.as_ref().borrow();
@@ -63,14 +67,36 @@ fn emit_extmodules<F: io::Write>(
             {
                 let curr_module_name =
                     get_primitive_module_name(name, param_binding);
-                if extmodule_set.insert(curr_module_name.clone()) {
-                    emit_primitive_extmodule(
-                        cell.borrow().ports(),
+                if !module_set.insert(curr_module_name.clone()) {
+                    continue;
+                }
+                let ports = cell_borrowed.ports();
+                let body = if ctx.bc.emit_firrtl_primitives {
+                    primitives::module_body(name, param_binding, ports)
+                } else {
+                    None
+                };
+                match body {
+                    Some(body) => emit_primitive_module(
+                        ports,
                         &curr_module_name,
-                        name,
-                        param_binding,
+                        &body,
                         out,
-                    )?;
+                    )?,
+                    None if ctx.bc.emit_primitive_extmodules => {
+                        emit_primitive_extmodule(
+                            ports,
+                            &curr_module_name,
+                            name,
+                            param_binding,
+                            out,
+                        )?
+                    }
+                    None => {
+                        return Err(calyx_utils::Error::misc(format!(
+                            "FIRRTL backend has no implementation of primitive `{name}'. Use --emit-primitive-extmodules to use its SystemVerilog implementation."
+                        )))
+                    }
                 }
             };
         }
@@ -178,6 +204,29 @@ fn get_primitive_module_name(name: &Id, param_binding: &Binding) -> String {
     primitive_string
 }
 
+fn emit_primitive_module<F: io::Write>(
+    ports: &[RRC<Port>],
+    curr_module_name: &str,
+    body: &[String],
+    f: &mut F,
+) -> io::Result<()> {
+    writeln!(f, "{}module {}:", SPACING, curr_module_name)?;
+    for port in ports {
+        let port_borrowed = port.borrow();
+        emit_port(port_borrowed, false, f)?;
+    }
+    writeln!(f)?;
+    for line in body {
+        if line.is_empty() {
+            writeln!(f)?;
+        } else {
+            writeln!(f, "{}{}", SPACING.repeat(2), line)?;
+        }
+    }
+    writeln!(f)?;
+    Ok(())
+}
+
 fn emit_primitive_extmodule<F: io::Write>(
     ports: &[RRC<Port>],
     curr_module_name: &String,
@@ -0,0 +1,255 @@
+//! FIRRTL implementations of the core primitives.
+//!
+//! Each implementation matches the behavior of the SystemVerilog
+//! implementation in `primitives/` so that programs compiled through FIRRTL
+//! simulate the same as programs compiled by the Verilog backend.
+
+use super::SPACING;
+use calyx_ir::{self as ir, Binding, RRC};
+
+/// Returns the statements of a FIRRTL module implementing the primitive
+/// `name` with the parameters `params`, or `None` if there is no FIRRTL
+/// implementation of the primitive. Nested statements are indented with
+/// [SPACING] relative to the body of the module.
+pub(super) fn module_body(
+    name: &ir::Id,
+    params: &Binding,
+    ports: &[RRC<ir::Port>],
+) -> Option<Vec<String>> {
+    let param = |p: &str| {
+        get_param(params, p)
+            .unwrap_or_else(|| panic!("Primitive {name} has no parameter {p}"))
+    };
+    let has_reset = ports.iter().any(|p| p.borrow().name == "reset");
+    let binop = |op: &str| vec![format!("out <= {op}(left, right)")];
+
+    let body = match name.as_ref() {
+        "std_const" => vec![format!(
+            "out <= UInt<{}>({})",
+            param("WIDTH"),
+            param("VALUE")
+        )],
+        "std_wire" => vec!["out <= in".to_string()],
+        "undef" => vec!["out is invalid".to_string()],
+        "std_slice" => {
+            vec![format!("out <= bits(in, {}, 0)", param("OUT_WIDTH") - 1)]
+        }
+        "std_pad" => vec![format!("out <= pad(in, {})", param("OUT_WIDTH"))],
+        "std_cat" => binop("cat"),
+        "std_bit_slice" => vec![format!(
+            "out <= bits(in, {}, {})",
+            param("END_IDX"),
+            param("START_IDX")
+        )],
+        "std_not" => vec!["out <= not(in)".to_string()],
+        "std_and" => binop("and"),
+        "std_or" => binop("or"),
+        "std_xor" => binop("xor"),
+        // The result of `add` and `sub` is one bit wider than the inputs.
+        "std_add" => vec!["out <= tail(add(left, right), 1)".to_string()],
+        "std_sub" => vec!["out <= tail(sub(left, right), 1)".to_string()],
+        "std_gt" => binop("gt"),
+        "std_lt" => binop("lt"),
+        "std_eq" => binop("eq"),
+        "std_neq" => binop("neq"),
+        "std_ge" => binop("geq"),
+        "std_le" => binop("leq"),
+        "std_lsh" => shift(param("WIDTH"), "dshl"),
+        "std_rsh" => shift(param("WIDTH"), "dshr"),
+        "std_mux" => vec!["out <= mux(cond, tru, fal)".to_string()],
+        "std_reg" => register(param("WIDTH"), 0),
+        "init_one_reg" => register(param("WIDTH"), 1),
+        "std_mult_pipe" => mult_pipe(param("WIDTH")),
+        "comb_mem_d1" | "comb_mem_d2" | "comb_mem_d3" | "comb_mem_d4" => {
+            memory(params, false, has_reset)
+        }
+        "seq_mem_d1" | "seq_mem_d2" | "seq_mem_d3" | "seq_mem_d4" => {
+            memory(params, true, has_reset)
+        }
+        _ => return None,
+    };
+    Some(body)
+}
+
+fn get_param(params: &Binding, name: &str) -> Option<u64> {
+    params.iter().find(|(id, _)| id == name).map(|(_, v)| *v)
+}
+
+/// Number of bits needed to represent `n` distinct values.
+fn clog2(n: u64) -> u64 {
+    if n <= 1 {
+        0
+    } else {
+        64 - (n - 1).leading_zeros() as u64
+    }
+}
+
+/// Truncate or zero-extend `expr` to exactly `width` bits.
+fn fit(expr: &str, width: u64) -> String {
+    format!("bits(pad({expr}, {width}), {}, 0)", width - 1)
+}
+
+/// A shift by `right`. FIRRTL limits the width of dynamic shift amounts, so
+/// only the bits needed to shift within `width` are used and larger amounts
+/// produce zero, like in SystemVerilog.
+fn shift(width: u64, op: &str) -> Vec<String> {
+    let amount_bits = clog2(width).max(1);
+    vec![
+        format!(
+            "node shifted = {op}(left, bits(right, {}, 0))",
+            amount_bits - 1
+        ),
+        format!(
+            "out <= mux(geq(right, UInt({width})), UInt(0), {})",
+            fit("shifted", width)
+        ),
+    ]
+}
+
+/// A register of `width` bits that resets to `init`.
+fn register(width: u64, init: u64) -> Vec<String> {
+    let s = SPACING;
+    vec![
+        format!("reg out_reg : UInt<{width}>, clk"),
+        "reg done_reg : UInt<1>, clk".to_string(),
+        "when reset :".to_string(),
+        format!("{s}out_reg <= UInt({init})"),
+        format!("{s}done_reg <= UInt(0)"),
+        "else :".to_string(),
+        format!("{s}when write_en :"),
+        format!("{s}{s}out_reg <= in"),
+        format!("{s}{s}done_reg <= UInt(1)"),
+        format!("{s}else :"),
+        format!("{s}{s}done_reg <= UInt(0)"),
+        "out <= out_reg".to_string(),
+        "done <= done_reg".to_string(),
+    ]
+}
+
+/// A three-cycle unsigned multiplier. The inputs are registered in the first
+/// cycle and multiplied in the second, and `done` is high in the third.
+fn mult_pipe(width: u64) -> Vec<String> {
+    let s = SPACING;
+    vec![
+        format!("reg ltmp : UInt<{width}>, clk"),
+        format!("reg rtmp : UInt<{width}>, clk"),
+        format!("reg out_tmp : UInt<{}>, clk", width * 2),
+        "reg done_buf0 : UInt<1>, clk".to_string(),
+        "reg done_buf1 : UInt<1>, clk".to_string(),
+        "done_buf0 <= go".to_string(),
+        "when go :".to_string(),
+        format!("{s}done_buf1 <= done_buf0"),
+        "else :".to_string(),
+        format!("{s}done_buf1 <= UInt(0)"),
+        "when reset :".to_string(),
+        format!("{s}ltmp <= UInt(0)"),
+        format!("{s}rtmp <= UInt(0)"),
+        format!("{s}out_tmp <= UInt(0)"),
+        "else :".to_string(),
+        format!("{s}when go :"),
+        format!("{s}{s}ltmp <= left"),
+        format!("{s}{s}rtmp <= right"),
+        format!("{s}{s}out_tmp <= mul(ltmp, rtmp)"),
+        format!("{s}else :"),
+        format!("{s}{s}ltmp <= UInt(0)"),
+        format!("{s}{s}rtmp <= UInt(0)"),
+        format!("out <= bits(out_tmp, {}, 0)", width - 1),
+        "done <= done_buf1".to_string(),
+    ]
+}
+
+/// A memory with the dimensions in `params`. Multi-dimensional memories are
+/// stored in a single FIRRTL memory in row-major order. Memories with
+/// sequential reads register the read data when `content_en` is high and
+/// `write_en` is low; other memories read combinationally.
+fn memory(params: &Binding, seq: bool, has_reset: bool) -> Vec<String> {
+    let width = get_param(params, "WIDTH").unwrap();
+    let sizes: Vec<u64> = match get_param(params, "SIZE") {
+        Some(size) => vec![size],
+        None => (0..)
+            .map_while(|i| get_param(params, &format!("D{i}_SIZE")))
+            .collect(),
+    };
+    let depth: u64 = sizes.iter().product();
+    let addr_width = clog2(depth).max(1);
+
+    // Row-major address: addr0 * (D1_SIZE * ...) + addr1 * (D2_SIZE * ...)
+    let mut addr = String::new();
+    for (i, _) in sizes.iter().enumerate() {
+        let stride: u64 = sizes[i + 1..].iter().product();
+        let term = if stride == 1 {
+            format!("addr{i}")
+        } else {
+            format!("mul(addr{i}, UInt({stride}))")
+        };
+        addr = if addr.is_empty() {
+            term
+        } else {
+            format!("add({addr}, {term})")
+        };
+    }
+
+    let s = SPACING;
+    let enable = if seq {
+        "and(content_en, write_en)"
+    } else {
+        "write_en"
+    };
+    let write_en = if has_reset {
+        format!("and({enable}, not(reset))")
+    } else {
+        enable.to_string()
+    };
+    let done_en = if seq { "content_en" } else { "write_en" };
+
+    let mut body = vec![
+        "mem internal_mem :".to_string(),
+        format!("{s}data-type => UInt<{width}>"),
+        format!("{s}depth => {depth}"),
+        format!("{s}read-latency => 0"),
+        format!("{s}write-latency => 1"),
+        format!("{s}reader => r"),
+        format!("{s}writer => w"),
+        format!("{s}read-under-write => undefined"),
+        String::new(),
+        format!("node addr = {}", fit(&addr, addr_width)),
+        "internal_mem.r.addr <= addr".to_string(),
+        "internal_mem.r.en <= UInt(1)".to_string(),
+        "internal_mem.r.clk <= clk".to_string(),
+        "internal_mem.w.addr <= addr".to_string(),
+        format!("internal_mem.w.en <= {write_en}"),
+        "internal_mem.w.clk <= clk".to_string(),
+        "internal_mem.w.data <= write_data".to_string(),
+        "internal_mem.w.mask <= UInt(1)".to_string(),
+        String::new(),
+        "reg done_reg : UInt<1>, clk".to_string(),
+    ];
+    if has_reset {
+        body.extend([
+            "when reset :".to_string(),
+            format!("{s}done_reg <= UInt(0)"),
+            "else :".to_string(),
+            format!("{s}done_reg <= {done_en}"),
+        ]);
+    } else {
+        body.push(format!("done_reg <= {done_en}"));
+    }
+    body.push("done <= done_reg".to_string());
+
+    if seq {
+        // Reading while writing leaves the read data undefined, so the
+        // register simply keeps its value.
+        body.extend([
+            format!("reg read_reg : UInt<{width}>, clk"),
+            "when reset :".to_string(),
+            format!("{s}read_reg <= UInt(0)"),
+            "else :".to_string(),
+            format!("{s}when and(content_en, not(write_en)) :"),
+            format!("{s}{s}read_reg <= internal_mem.r.data"),
+            "read_data <= read_reg".to_string(),
+        ]);
+    } else {
+        body.push("read_data <= internal_mem.r.data".to_string());
+    }
+    body
+}
@@ -0,0 +1,38 @@
+//! Serializes the IR as JSON.
+//! The format is described by `docs/serialized-ir.schema.json` and can be
+//! read back with `calyx -f json`.
+
+use crate::traits::Backend;
+use calyx_ir as ir;
+use calyx_utils::{CalyxResult, Error, OutputFile};
+
+#[derive(Default)]
+pub struct JsonBackend;
+
+impl Backend for JsonBackend {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+
+    /// OK to run this analysis on any Calyx program
+    fn validate(_ctx: &ir::Context) -> CalyxResult<()> {
+        Ok(())
+    }
+
+    /// Don't need to take care of this for this pass
+    fn link_externs(
+        _ctx: &ir::Context,
+        _file: &mut OutputFile,
+    ) -> CalyxResult<()> {
+        Ok(())
+    }
+
+    fn emit(ctx: &ir::Context, file: &mut OutputFile) -> CalyxResult<()> {
+        let out = &mut file.get_write();
+        serde_json::to_writer_pretty(&mut *out, ctx).map_err(|err| {
+            Error::write_error(format!("Failed to serialize IR: {err}"))
+        })?;
+        writeln!(out)?;
+        Ok(())
+    }
+}
@@ -31,5 +31,10 @@ mod sexp;
 #[cfg(feature = "sexp")]
 pub use sexp::SexpBackend;
 
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+pub use json::JsonBackend;
+
 #[cfg(feature = "xilinx")]
 pub mod xilinx;
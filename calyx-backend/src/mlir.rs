@@ -2,7 +2,8 @@
 use super::traits::Backend;
 use calyx_frontend::GetAttributes;
 use calyx_ir::{self as ir, RRC};
-use calyx_utils::Error;
+use calyx_utils::{DiagnosticContext, Error, WithPos};
+use itertools::Itertools;
 use std::collections::HashMap;
 use std::io;
 use std::rc::Rc;
@@ -15,8 +16,14 @@ impl Backend for MlirBackend {
         "mlir"
     }
 
-    fn validate(_prog: &ir::Context) -> calyx_utils::CalyxResult<()> {
-        Ok(())
+    /// The program must not read group holes and its guards must be lowered.
+    /// Invokes may only bind the inputs of the invoked cell.
+    fn validate(prog: &ir::Context) -> calyx_utils::CalyxResult<()> {
+        let mut diag = DiagnosticContext::default();
+        for comp in &prog.components {
+            Self::validate_component(comp, &mut diag);
+        }
+        diag.into_result().map(|_| ())
     }
 
     fn emit(
@@ -112,6 +119,10 @@ impl MlirBackend {
             Self::write_group(&group.borrow(), 4, f)?;
             writeln!(f)?;
         }
+        for group in comp.get_static_groups().iter() {
+            Self::write_static_group(&group.borrow(), 4, f)?;
+            writeln!(f)?;
+        }
         for comb_group in comp.comb_groups.iter() {
             Self::write_comb_group(&comb_group.borrow(), 4, f)?;
             writeln!(f)?;
@@ -154,51 +165,21 @@ impl MlirBackend {
                     .map(|(k, v)| (k.as_ref(), *v))
                     .collect();
                 match name.as_ref() {
-                    "undef" => {
-                        write!(f, "calyx.undefined @{cell_name}")?
-                    }
-                    "std_reg" => {
-                        write!(f, "calyx.register @{cell_name}")?
-                    }
-                    "comb_mem_d1" => write!(
+                    "undef" => write!(f, "calyx.undefined @{cell_name}")?,
+                    "std_reg" => write!(f, "calyx.register @{cell_name}")?,
+                    "comb_mem_d1" | "comb_mem_d2" | "comb_mem_d3"
+                    | "comb_mem_d4" => Self::write_memory(
+                        "calyx.memory",
+                        &cell_name,
+                        &bind,
                         f,
-                        "calyx.memory @{cell_name} <[{}] x {}> [{}]",
-                        bind["SIZE"],
-                        bind["WIDTH"],
-                        bind["IDX_SIZE"]
                     )?,
-                    "comb_mem_d2" => write!(
+                    "seq_mem_d1" | "seq_mem_d2" | "seq_mem_d3"
+                    | "seq_mem_d4" => Self::write_memory(
+                        "calyx.seq_mem",
+                        &cell_name,
+                        &bind,
                         f,
-                        "calyx.memory @{cell_name} <[{}, {}] x {}> [{}, {}]",
-                        bind["D0_SIZE"],
-                        bind["D1_SIZE"],
-                        bind["WIDTH"],
-                        bind["D0_IDX_SIZE"],
-                        bind["D1_IDX_SIZE"]
-                    )?,
-                    "comb_mem_d3" => write!(
-                        f,
-                        "calyx.memory @{cell_name} <[{}, {}, {}] x {}> [{}, {}, {}]",
-                        bind["D0_SIZE"],
-                        bind["D1_SIZE"],
-                        bind["D2_SIZE"],
-                        bind["WIDTH"],
-                        bind["D0_IDX_SIZE"],
-                        bind["D1_IDX_SIZE"],
-                        bind["D2_IDX_SIZE"]
-                    )?,
-                    "comb_mem_d4" => write!(
-                        f,
-                        "calyx.memory @{cell_name} <[{}, {}, {}, {}] x {}> [{}, {}, {}, {}]",
-                        bind["D0_SIZE"],
-                        bind["D1_SIZE"],
-                        bind["D2_SIZE"],
-                        bind["D3_SIZE"],
-                        bind["WIDTH"],
-                        bind["D0_IDX_SIZE"],
-                        bind["D1_IDX_SIZE"],
-                        bind["D2_IDX_SIZE"],
-                        bind["D3_IDX_SIZE"]
                     )?,
                     prim => write!(f, "calyx.{prim} @{cell_name}")?,
                 }
@@ -215,6 +196,34 @@ impl MlirBackend {
         Ok(true)
     }
 
+    /// Write the memory operation `op`. The size and index size of each
+    /// dimension are read from the parameter binding `bind`.
+    fn write_memory<F: io::Write>(
+        op: &str,
+        cell_name: &str,
+        bind: &HashMap<&str, u64>,
+        f: &mut F,
+    ) -> io::Result<()> {
+        let (sizes, idx_sizes): (Vec<u64>, Vec<u64>) =
+            if bind.contains_key("SIZE") {
+                (vec![bind["SIZE"]], vec![bind["IDX_SIZE"]])
+            } else {
+                (0..)
+                    .map_while(|d| {
+                        let size = bind.get(format!("D{d}_SIZE").as_str())?;
+                        Some((*size, bind[format!("D{d}_IDX_SIZE").as_str()]))
+                    })
+                    .unzip()
+            };
+        write!(
+            f,
+            "{op} @{cell_name} <[{}] x {}> [{}]",
+            sizes.iter().join(", "),
+            bind["WIDTH"],
+            idx_sizes.iter().join(", ")
+        )
+    }
+
     /// Format and write a cell.
     pub fn write_cell<F: io::Write>(
         cell: &ir::Cell,
@@ -250,6 +259,19 @@ impl MlirBackend {
         assign: &ir::Assignment<ir::Nothing>,
         indent_level: usize,
         f: &mut F,
+    ) -> io::Result<()> {
+        let guard = Self::guard_access(&assign.guard, |_| {
+            unreachable!("dynamic assignment with timing guard")
+        });
+        Self::write_guarded_assignment(assign, guard, indent_level, f)
+    }
+
+    /// Write `assign`, guarded by the value `guard` if there is one.
+    fn write_guarded_assignment<F: io::Write, T>(
+        assign: &ir::Assignment<T>,
+        guard: Option<String>,
+        indent_level: usize,
+        f: &mut F,
     ) -> io::Result<()> {
         write!(f, "{}", " ".repeat(indent_level))?;
         let dst = assign.dst.borrow();
@@ -265,17 +287,27 @@ impl MlirBackend {
                 )?;
             }
         }
-        if let ir::Guard::Port(p) = &*assign.guard {
-            write!(f, "{} ? ", Self::get_port_access(&p.borrow()))?;
-        } else if matches!(&*assign.guard, ir::Guard::True) {
-            /* Print nothing */
-        } else {
-            panic!("Failed to compile guard: {}.\nFirst run the `lower-guards` pass. If you did, report this as an issue.", ir::Printer::guard_str(&assign.guard));
+        if let Some(guard) = guard {
+            write!(f, "{guard} ? ")?;
         }
         write!(f, "{}", Self::get_port_access(&assign.src.borrow()),)?;
         write!(f, " : i{}", assign.src.borrow().width)
     }
 
+    /// The value that implements a lowered guard, or `None` if the guard is
+    /// always true. `info` gives the value for timing guards.
+    fn guard_access<T: ToString + Eq>(
+        guard: &ir::Guard<T>,
+        info: impl Fn(&T) -> String,
+    ) -> Option<String> {
+        match guard {
+            ir::Guard::True => None,
+            ir::Guard::Port(p) => Some(Self::get_port_access(&p.borrow())),
+            ir::Guard::Info(t) => Some(info(t)),
+            _ => panic!("Failed to compile guard: {}.\nFirst run the `lower-guards` pass. If you did, report this as an issue.", ir::Printer::guard_str(guard)),
+        }
+    }
+
     /// Format and write a group.
     pub fn write_group<F: io::Write>(
         group: &ir::Group,
@@ -297,6 +329,64 @@ impl MlirBackend {
         Ok(())
     }
 
+    /// Name of the value that is true during the cycles of `timing` in the
+    /// static group `group`.
+    fn cycle_name(group: ir::Id, timing: &ir::StaticTiming) -> String {
+        let (start, end) = timing.get_interval();
+        format!("%{group}.cycle_{start}_{end}")
+    }
+
+    /// Format and write a static group. Timing guards are implemented with
+    /// `calyx.cycle` operations defined at the start of the group.
+    pub fn write_static_group<F: io::Write>(
+        group: &ir::StaticGroup,
+        indent_level: usize,
+        f: &mut F,
+    ) -> io::Result<()> {
+        let name = group.name();
+        write!(f, "{}", " ".repeat(indent_level))?;
+        write!(
+            f,
+            "calyx.static_group latency<{}> @{}",
+            group.get_latency(),
+            name.id
+        )?;
+        writeln!(f, " {{")?;
+
+        let intervals = group
+            .assignments
+            .iter()
+            .filter_map(|assign| match &*assign.guard {
+                ir::Guard::Info(timing) => Some(timing.get_interval()),
+                _ => None,
+            })
+            .unique();
+        for (start, end) in intervals {
+            write!(f, "{}", " ".repeat(indent_level + 2))?;
+            let timing = ir::StaticTiming::new((start, end));
+            write!(
+                f,
+                "{} = calyx.cycle {start}",
+                Self::cycle_name(name, &timing)
+            )?;
+            // calyx.cycle includes the last cycle of the interval
+            if end > start + 1 {
+                write!(f, " -> {}", end - 1)?;
+            }
+            writeln!(f)?;
+        }
+
+        for assign in &group.assignments {
+            let guard = Self::guard_access(&assign.guard, |timing| {
+                Self::cycle_name(name, timing)
+            });
+            Self::write_guarded_assignment(assign, guard, indent_level + 2, f)?;
+            writeln!(f)?;
+        }
+        write!(f, "{}}}", " ".repeat(indent_level))?;
+        write!(f, "{}", Self::format_attributes(&group.attributes))
+    }
+
     /// Format and write combinational groups
     pub fn write_comb_group<F: io::Write>(
         group: &ir::CombGroup,
@@ -324,19 +414,27 @@ impl MlirBackend {
         indent_level: usize,
         f: &mut F,
     ) -> io::Result<()> {
+        if let ir::Control::Static(sc) = control {
+            return Self::write_static_control(sc, indent_level, f);
+        }
         write!(f, "{}", " ".repeat(indent_level))?;
         match control {
             ir::Control::Enable(ir::Enable { group, .. }) => {
                 write!(f, "calyx.enable @{}", group.borrow().name().id)
             }
-            ir::Control::Static(_) => {
-                todo!("static control not currently supported for MLIR")
-            }
-            ir::Control::Invoke(ir::Invoke { .. }) => {
-                todo!("invoke operator for MLIR backend")
-            }
-            ir::Control::Repeat(ir::Repeat { .. }) => {
-                todo!("repeat operator for MLIR backend")
+            ir::Control::Static(_) => unreachable!(),
+            ir::Control::Invoke(ir::Invoke {
+                comp,
+                inputs,
+                ref_cells,
+                ..
+            }) => Self::write_invoke(&comp.borrow(), inputs, ref_cells, f),
+            ir::Control::Repeat(ir::Repeat {
+                body, num_repeats, ..
+            }) => {
+                writeln!(f, "calyx.repeat {num_repeats} {{")?;
+                Self::write_control(body, indent_level + 2, f)?;
+                write!(f, "{}}}", " ".repeat(indent_level))
             }
             ir::Control::Seq(ir::Seq { stmts, .. }) => {
                 writeln!(f, "calyx.seq {{")?;
@@ -400,6 +498,112 @@ impl MlirBackend {
         writeln!(f)
     }
 
+    /// Write an invoke of `cell` with the `inputs` bound and the `ref_cells`
+    /// passed to it.
+    fn write_invoke<F: io::Write>(
+        cell: &ir::Cell,
+        inputs: &[(ir::Id, RRC<ir::Port>)],
+        ref_cells: &[(ir::Id, RRC<ir::Cell>)],
+        f: &mut F,
+    ) -> io::Result<()> {
+        let name = cell.name();
+        write!(f, "calyx.invoke @{}", name.id)?;
+        if !ref_cells.is_empty() {
+            write!(
+                f,
+                "[{}]",
+                ref_cells
+                    .iter()
+                    .map(|(id, c)| format!("{id} = {}", c.borrow().name()))
+                    .join(", ")
+            )?;
+        }
+        write!(
+            f,
+            "({})",
+            inputs
+                .iter()
+                .map(|(port, src)| format!(
+                    "%{name}.{port} = {}",
+                    Self::get_port_access(&src.borrow())
+                ))
+                .join(", ")
+        )?;
+        write!(
+            f,
+            " -> ({})",
+            inputs
+                .iter()
+                .map(|(_, src)| format!("i{}", src.borrow().width))
+                .join(", ")
+        )
+    }
+
+    /// Format and write a static control program
+    pub fn write_static_control<F: io::Write>(
+        control: &ir::StaticControl,
+        indent_level: usize,
+        f: &mut F,
+    ) -> io::Result<()> {
+        write!(f, "{}", " ".repeat(indent_level))?;
+        match control {
+            ir::StaticControl::Enable(ir::StaticEnable { group, .. }) => {
+                write!(f, "calyx.enable @{}", group.borrow().name().id)
+            }
+            ir::StaticControl::Repeat(ir::StaticRepeat {
+                body,
+                num_repeats,
+                ..
+            }) => {
+                writeln!(f, "calyx.static_repeat {num_repeats} {{")?;
+                Self::write_static_control(body, indent_level + 2, f)?;
+                write!(f, "{}}}", " ".repeat(indent_level))
+            }
+            ir::StaticControl::Seq(ir::StaticSeq { stmts, .. }) => {
+                writeln!(f, "calyx.static_seq {{")?;
+                for stmt in stmts {
+                    Self::write_static_control(stmt, indent_level + 2, f)?;
+                }
+                write!(f, "{}}}", " ".repeat(indent_level))
+            }
+            ir::StaticControl::Par(ir::StaticPar { stmts, .. }) => {
+                writeln!(f, "calyx.static_par {{")?;
+                for stmt in stmts {
+                    Self::write_static_control(stmt, indent_level + 2, f)?;
+                }
+                write!(f, "{}}}", " ".repeat(indent_level))
+            }
+            ir::StaticControl::If(ir::StaticIf {
+                port,
+                tbranch,
+                fbranch,
+                ..
+            }) => {
+                writeln!(
+                    f,
+                    "calyx.static_if {} {{",
+                    Self::get_port_access(&port.borrow())
+                )?;
+                Self::write_static_control(tbranch, indent_level + 2, f)?;
+                write!(f, "{}}}", " ".repeat(indent_level))?;
+                if let ir::StaticControl::Empty(_) = **fbranch {
+                    Ok(())
+                } else {
+                    writeln!(f, " else {{")?;
+                    Self::write_static_control(fbranch, indent_level + 2, f)?;
+                    write!(f, "{}}}", " ".repeat(indent_level))
+                }
+            }
+            ir::StaticControl::Empty(_) => writeln!(f),
+            ir::StaticControl::Invoke(_) => {
+                unreachable!("static invoke is rejected by validate")
+            }
+        }?;
+        let attr = control.get_attributes();
+        write!(f, "{}", Self::format_attributes(attr))?;
+        writeln!(f)
+    }
+
     /// Get the port access expression.
     fn get_port_access(port: &ir::Port) -> String {
         match &port.parent {
@@ -416,8 +620,169 @@ impl MlirBackend {
                     _ => format!("%{}.{}", cell.name().id, port.name.id),
                 }
             }
-            ir::PortParent::Group(_) => unimplemented!(),
-            ir::PortParent::StaticGroup(_) => unimplemented!(),
+            ir::PortParent::Group(_) | ir::PortParent::StaticGroup(_) => {
+                unreachable!("reading holes is rejected by validate")
+            }
+        }
+    }
+}
+
+/// Validation of the constructs that can be emitted.
+impl MlirBackend {
+    fn validate_component(comp: &ir::Component, diag: &mut DiagnosticContext) {
+        for group in comp.get_groups().iter() {
+            for assign in &group.borrow().assignments {
+                Self::validate_assignment(assign, false, diag);
+            }
+        }
+        for group in comp.get_static_groups().iter() {
+            for assign in &group.borrow().assignments {
+                Self::validate_assignment(assign, true, diag);
+                if assign.dst.borrow().is_hole() {
+                    diag.err(
+                        Error::malformed_structure(format!(
+                            "MLIR backend does not support writing to the hole `{}` of a static group",
+                            assign.dst.borrow().canonical()
+                        ))
+                        .with_pos(&assign.attributes),
+                    );
+                }
+            }
+        }
+        for group in comp.comb_groups.iter() {
+            for assign in &group.borrow().assignments {
+                Self::validate_assignment(assign, false, diag);
+            }
+        }
+        for assign in &comp.continuous_assignments {
+            Self::validate_assignment(assign, false, diag);
+        }
+        Self::validate_control(&comp.control.borrow(), diag);
+    }
+
+    /// Report an error at `pos` if `port` is a group hole.
+    fn validate_read<P: WithPos>(
+        port: &RRC<ir::Port>,
+        pos: &P,
+        diag: &mut DiagnosticContext,
+    ) {
+        let port = port.borrow();
+        if port.is_hole() {
+            diag.err(
+                Error::malformed_structure(format!(
+                    "MLIR backend does not support reading the hole `{}`",
+                    port.canonical()
+                ))
+                .with_pos(pos),
+            );
+        }
+    }
+
+    /// Guards must be a single port, or a timing guard in static groups.
+    fn validate_assignment<T: ToString + Eq>(
+        assign: &ir::Assignment<T>,
+        is_static: bool,
+        diag: &mut DiagnosticContext,
+    ) {
+        Self::validate_read(&assign.src, &assign.attributes, diag);
+        match &*assign.guard {
+            ir::Guard::True => (),
+            ir::Guard::Port(p) => {
+                Self::validate_read(p, &assign.attributes, diag)
+            }
+            ir::Guard::Info(_) if is_static => (),
+            guard => diag.err(
+                Error::malformed_structure(format!(
+                    "MLIR backend only supports guards that are a single port{}, found `{}`. Run the `lower-guards` pass first.",
+                    if is_static { " or a timing interval" } else { "" },
+                    ir::Printer::guard_str(guard)
+                ))
+                .with_pos(&assign.attributes),
+            ),
+        }
+    }
+
+    fn validate_control(control: &ir::Control, diag: &mut DiagnosticContext) {
+        match control {
+            ir::Control::Seq(ir::Seq { stmts, .. })
+            | ir::Control::Par(ir::Par { stmts, .. }) => {
+                for stmt in stmts {
+                    Self::validate_control(stmt, diag);
+                }
+            }
+            ir::Control::If(ir::If {
+                port,
+                tbranch,
+                fbranch,
+                ..
+            }) => {
+                Self::validate_read(port, control, diag);
+                Self::validate_control(tbranch, diag);
+                Self::validate_control(fbranch, diag);
+            }
+            ir::Control::While(ir::While { port, body, .. }) => {
+                Self::validate_read(port, control, diag);
+                Self::validate_control(body, diag);
+            }
+            ir::Control::Repeat(ir::Repeat { body, .. }) => {
+                Self::validate_control(body, diag);
+            }
+            ir::Control::Invoke(ir::Invoke {
+                inputs,
+                outputs,
+                comb_group,
+                ..
+            }) => {
+                for (_, port) in inputs {
+                    Self::validate_read(port, control, diag);
+                }
+                if !outputs.is_empty() {
+                    diag.err(Error::malformed_control(
+                        "MLIR backend does not support invoke with output bindings. Run the `compile-invoke` pass first.",
+                    ).with_pos(control));
+                }
+                if comb_group.is_some() {
+                    diag.err(Error::malformed_control(
+                        "MLIR backend does not support invoke with a combinational group. Run the `compile-invoke` pass first.",
+                    ).with_pos(control));
+                }
+            }
+            ir::Control::Static(sc) => Self::validate_static_control(sc, diag),
+            ir::Control::Enable(_) | ir::Control::Empty(_) => (),
+        }
+    }
+
+    fn validate_static_control(
+        control: &ir::StaticControl,
+        diag: &mut DiagnosticContext,
+    ) {
+        match control {
+            ir::StaticControl::Seq(ir::StaticSeq { stmts, .. })
+            | ir::StaticControl::Par(ir::StaticPar { stmts, .. }) => {
+                for stmt in stmts {
+                    Self::validate_static_control(stmt, diag);
+                }
+            }
+            ir::StaticControl::If(ir::StaticIf {
+                port,
+                tbranch,
+                fbranch,
+                ..
+            }) => {
+                Self::validate_read(port, control.get_attributes(), diag);
+                Self::validate_static_control(tbranch, diag);
+                Self::validate_static_control(fbranch, diag);
+            }
+            ir::StaticControl::Repeat(ir::StaticRepeat { body, .. }) => {
+                Self::validate_static_control(body, diag);
+            }
+            ir::StaticControl::Invoke(_) => diag.err(
+                Error::malformed_control(
+                    "MLIR backend does not support static invoke. Run the `compile-invoke` pass first.",
+                )
+                .with_pos(control.get_attributes()),
+            ),
+            ir::StaticControl::Enable(_) | ir::StaticControl::Empty(_) => (),
         }
     }
 }
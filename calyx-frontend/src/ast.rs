@@ -1,6 +1,6 @@
 //! Abstract Syntax Tree for Calyx
 use super::parser;
-use crate::{Attributes, PortDef, Primitive};
+use crate::{Attributes, PortDef, Primitive, Width};
 use atty::Stream;
 use calyx_utils::{CalyxResult, Error, GPosIdx, Id};
 use std::{num::NonZeroU64, path::PathBuf};
@@ -14,6 +14,8 @@ pub struct NamespaceDef {
     pub components: Vec<ComponentDef>,
     /// Extern statements and any primitive declarations in them.
     pub externs: Vec<(Option<String>, Vec<Primitive>)>,
+    /// Named constants defined at the top level.
+    pub consts: Vec<ConstDef>,
     /// Optional opaque metadata
     pub metadata: Option<String>,
 }
@@ -43,12 +45,17 @@ impl NamespaceDef {
 }
 
 /// AST statement for defining components.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ComponentDef {
     /// Name of the component.
     pub name: Id,
+    /// Width parameters of the component. A parameterized component is
+    /// instantiated once for every list of arguments it is used with.
+    pub params: Vec<Id>,
+    /// Named constants defined by the component.
+    pub consts: Vec<ConstDef>,
     /// Defines input and output ports along with their attributes.
-    pub signature: Vec<PortDef<u64>>,
+    pub signature: Vec<PortDef<ConstExpr>>,
     /// List of instantiated sub-components
     pub cells: Vec<Cell>,
     /// List of groups
@@ -72,13 +79,15 @@ impl ComponentDef {
         name: S,
         is_comb: bool,
         latency: Option<NonZeroU64>,
-        signature: Vec<PortDef<u64>>,
+        signature: Vec<PortDef<ConstExpr>>,
     ) -> Self
     where
         S: Into<Id>,
     {
         Self {
             name: name.into(),
+            params: Vec::new(),
+            consts: Vec::new(),
             signature,
             cells: Vec::new(),
             groups: Vec::new(),
@@ -94,7 +103,7 @@ impl ComponentDef {
 
 /// Statement that refers to a port on a subcomponent.
 /// This is distinct from a `Portdef` which defines a port.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Port {
     /// Refers to the port named `port` on the subcomponent
     /// `component`.
@@ -109,11 +118,74 @@ pub enum Port {
     Hole { group: Id, name: Id },
 }
 
+// ===================================
+// AST for constant expressions
+// ===================================
+
+/// Binary operators in constant expressions.
+#[derive(Debug, Clone, Copy)]
+pub enum ConstOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// The kinds of constant expressions.
+#[derive(Debug, Clone)]
+pub enum ConstExprKind {
+    /// A number.
+    Num(u64),
+    /// A parameter of the component or a named constant.
+    Var(Id),
+    /// A binary arithmetic operation.
+    Op(ConstOp, Box<ConstExpr>, Box<ConstExpr>),
+    /// A call to a built-in function like `clog2` or `max`.
+    Call(Id, Vec<ConstExpr>),
+}
+
+/// An expression over numbers, parameters, and named constants that is
+/// evaluated by the compiler. Used for the widths of ports and the
+/// parameters of cells.
+#[derive(Debug, Clone)]
+pub struct ConstExpr {
+    pub kind: ConstExprKind,
+    pub span: GPosIdx,
+}
+
+impl From<u64> for ConstExpr {
+    fn from(value: u64) -> Self {
+        ConstExpr {
+            kind: ConstExprKind::Num(value),
+            span: GPosIdx::UNKNOWN,
+        }
+    }
+}
+
+impl ConstExpr {
+    /// The width denoted by this expression if it is a number or a name.
+    pub fn as_width(&self) -> Option<Width> {
+        match &self.kind {
+            ConstExprKind::Num(value) => Some(Width::Const { value: *value }),
+            ConstExprKind::Var(value) => Some(Width::Param { value: *value }),
+            ConstExprKind::Op(..) | ConstExprKind::Call(..) => None,
+        }
+    }
+}
+
+/// A named constant: `const NAME = expr;`
+#[derive(Debug, Clone)]
+pub struct ConstDef {
+    pub name: Id,
+    pub value: ConstExpr,
+    pub span: GPosIdx,
+}
+
 // ===================================
 // AST for wire guard expressions
 // ===================================
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum NumType {
     Decimal,
     Binary,
@@ -122,17 +194,41 @@ pub enum NumType {
 }
 
 /// Custom bitwidth numbers
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BitNum {
-    pub width: u64,
+    pub width: Width,
     pub num_type: NumType,
     pub val: u64,
     pub span: GPosIdx,
 }
 
+impl BitNum {
+    /// The error message to report if the number cannot be represented in
+    /// `width` bits.
+    pub fn width_error(&self, width: u64) -> Option<String> {
+        // the below cast is safe since the width must be less than 64 for
+        // the given literal to be unrepresentable
+        if width == 0 || (width < 64 && u64::pow(2, width as u32) <= self.val) {
+            let lit_str = match self.num_type {
+                NumType::Binary => format!("{:b}", self.val),
+                NumType::Decimal => format!("{}", self.val),
+                NumType::Octal => format!("{:o}", self.val),
+                NumType::Hex => format!("{:x}", self.val),
+            };
+            let bit_plural = if width == 1 { "bit" } else { "bits" };
+            Some(format!(
+                "Cannot represent given literal '{}' in {} {}",
+                lit_str, width, bit_plural
+            ))
+        } else {
+            None
+        }
+    }
+}
+
 /// Atomic operations used in guard conditions and RHS of the
 /// guarded assignments.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Atom {
     /// Accessing a particular port on a component.
     Port(Port),
@@ -141,7 +237,7 @@ pub enum Atom {
 }
 
 /// The AST for GuardExprs
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum GuardExpr {
     // Logical operations
     And(Box<GuardExpr>, Box<GuardExpr>),
@@ -155,7 +251,7 @@ pub enum GuardExpr {
 pub type CompGuard = (GuardComp, Atom, Atom);
 
 /// The AST for StaticGuardExprs
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum StaticGuardExpr {
     And(Box<StaticGuardExpr>, Box<StaticGuardExpr>),
     Or(Box<StaticGuardExpr>, Box<StaticGuardExpr>),
@@ -166,7 +262,7 @@ pub enum StaticGuardExpr {
 }
 
 /// Possible comparison operators for guards.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum GuardComp {
     Eq,
     Neq,
@@ -177,14 +273,14 @@ pub enum GuardComp {
 }
 
 /// Guards `expr` using the optional guard condition `guard`.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Guard {
     pub guard: Option<GuardExpr>,
     pub expr: Atom,
 }
 
 /// Guards `expr` using the optional guard condition `guard`.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StaticGuard {
     pub guard: Option<StaticGuardExpr>,
     pub expr: Atom,
@@ -195,16 +291,16 @@ pub struct StaticGuard {
 // ===================================
 
 /// Prototype of the cell definition
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Proto {
     /// Name of the primitive.
     pub name: Id,
-    /// Parameter binding for primitives
-    pub params: Vec<u64>,
+    /// Parameter binding for primitives and parameterized components
+    pub params: Vec<ConstExpr>,
 }
 
 /// The Cell AST nodes.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Cell {
     /// Name of the cell.
     pub name: Id,
@@ -222,7 +318,7 @@ impl Cell {
     pub fn from(
         name: Id,
         proto: Id,
-        params: Vec<u64>,
+        params: Vec<ConstExpr>,
         attributes: Attributes,
         reference: bool,
     ) -> Cell {
@@ -238,7 +334,7 @@ impl Cell {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Group {
     pub name: Id,
     pub wires: Vec<Wire>,
@@ -246,7 +342,7 @@ pub struct Group {
     pub is_comb: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StaticGroup {
     pub name: Id,
     pub wires: Vec<StaticWire>,
@@ -255,7 +351,7 @@ pub struct StaticGroup {
 }
 
 /// Data for the `->` structure statement.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Wire {
     /// Source of the wire.
     pub src: Guard,
@@ -268,7 +364,7 @@ pub struct Wire {
 }
 
 /// Data for the `->` structure statement.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StaticWire {
     /// Source of the wire.
     pub src: StaticGuard,
@@ -283,7 +379,7 @@ pub struct StaticWire {
 /// Control AST nodes.
 /// Since enables and static enables are indistinguishable to the AST, there
 /// is single Control Enum for both Static and Dynamic Control
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[allow(clippy::large_enum_variant)]
 pub enum Control {
     /// Represents sequential composition of control statements.
@@ -119,6 +119,14 @@ pub enum NumAttr {
     /// dynamic.
     /// Therefore, we only place if we can *guarantee* the interval of the component.
     Interval,
+    #[strum(serialize = "fsm_duplicate")]
+    /// Implement the static FSM for this control node with this many copies of
+    /// the FSM register, spreading the queries of the FSM across the copies.
+    FSMDuplicate,
+    #[strum(serialize = "fsm_split")]
+    /// Partition the states of the static FSM for this control node across
+    /// this many smaller FSM registers. Ignored by one-hot FSMs.
+    FSMSplit,
 }
 impl From<NumAttr> for Attribute {
     fn from(attr: NumAttr) -> Self {
@@ -131,7 +139,7 @@ impl std::fmt::Display for NumAttr {
     }
 }
 
-#[derive(AsRefStr, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+#[derive(AsRefStr, EnumString, Clone, Copy, Hash, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[allow(non_camel_case_types)]
 /// Internal attributes that cannot be parsed back from the IL.
@@ -174,6 +182,18 @@ impl std::fmt::Display for Attribute {
         }
     }
 }
+impl Attribute {
+    /// Parse the name of any attribute, including the internal attributes
+    /// that cannot be written in the IL. Used to read back serialized IR.
+    pub fn from_name(s: &str) -> CalyxResult<Self> {
+        Attribute::from_str(s).or_else(|err| {
+            InternalAttr::from_str(s)
+                .map(Attribute::Internal)
+                .map_err(|_| err)
+        })
+    }
+}
+
 impl FromStr for Attribute {
     type Err = Error;
 
@@ -234,13 +254,3 @@ impl InlineAttributes {
         })
     }
 }
-
-#[cfg(feature = "serialize")]
-impl serde::Serialize for InlineAttributes {
-    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        self.to_owned().attrs.serialize(ser)
-    }
-}
@@ -13,7 +13,6 @@ struct HeapAttrInfo {
 
 /// Attributes associated with a specific IR structure.
 #[derive(Default, Debug, Clone)]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Attributes {
     /// Inlined attributes
     inl: InlineAttributes,
@@ -169,12 +168,104 @@ impl Attributes {
     }
 }
 
+/// Attributes are serialized as a map from their names to their values along
+/// with the source position they were defined at, if it is known.
 #[cfg(feature = "serialize")]
-impl serde::Serialize for HeapAttrInfo {
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerAttributes<M> {
+    attrs: M,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pos: Option<GPosIdx>,
+}
+
+/// The map of attribute names to values in serialized [Attributes].
+#[cfg(feature = "serialize")]
+struct AttrMap<A>(A);
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for AttrMap<&Attributes> {
     fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        ser.collect_map(self.to_owned().attrs.iter())
+        let attrs = self.0;
+        ser.collect_map(
+            attrs
+                .hinfo
+                .attrs
+                .iter()
+                .map(|(k, v)| (k.to_string(), *v))
+                .chain(attrs.inl.iter().map(|k| (k.as_ref().to_string(), 1))),
+        )
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for AttrMap<Attributes> {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct AttrVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for AttrVisitor {
+            type Value = AttrMap<Attributes>;
+
+            fn expecting(
+                &self,
+                f: &mut std::fmt::Formatter,
+            ) -> std::fmt::Result {
+                write!(f, "a map from attribute names to values")
+            }
+
+            fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+            where
+                M: serde::de::MapAccess<'de>,
+            {
+                use serde::de::Error;
+                let mut attrs = Attributes::default();
+                while let Some((name, val)) = map.next_entry::<String, u64>()? {
+                    let attr = Attribute::from_name(&name)
+                        .map_err(|e| M::Error::custom(e.message()))?;
+                    if matches!(attr, Attribute::Bool(_)) && val != 1 {
+                        return Err(M::Error::custom(format!(
+                            "{name} is a boolean attribute and can only have a value of 1"
+                        )));
+                    }
+                    attrs.insert(attr, val);
+                }
+                Ok(AttrMap(attrs))
+            }
+        }
+
+        de.deserialize_map(AttrVisitor)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for Attributes {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let attrs = SerAttributes {
+            attrs: AttrMap(self),
+            pos: self.hinfo.span.into_option(),
+        };
+        serde::Serialize::serialize(&attrs, ser)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for Attributes {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let SerAttributes::<AttrMap<Attributes>> {
+            attrs: AttrMap(attrs),
+            pos,
+        } = serde::Deserialize::deserialize(de)?;
+        Ok(attrs.add_span(pos.unwrap_or_default()))
     }
 }
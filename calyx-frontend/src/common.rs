@@ -18,6 +18,7 @@ use smallvec::SmallVec;
 /// The signature of a port is represented using [PortDef] which also specify
 /// the direction of the port.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Primitive {
     /// Name of this primitive.
     pub name: Id,
@@ -90,9 +91,10 @@ impl GetName for Primitive {
 }
 
 /// Definition of a port parameterized by a width type.
-/// Ports on Primitives can be parameteris and use [Width].
-/// Ports on Components cannot be parameterized and therefore use `u64`.
+/// Ports on Primitives and parameterized Components use [Width].
+/// Ports on instantiated Components are not parameterized and use `u64`.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct PortDef<W> {
     /// The name of the port.
     name: Id,
@@ -133,6 +135,7 @@ impl<W> PortDef<W> {
 
 /// Represents an abstract width of a primitive signature.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub enum Width {
     /// The width is a constant.
     Const { value: u64 },
@@ -195,7 +198,7 @@ impl PortDef<Width> {
 
 /// Direction of a port on a cell.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     /// Input port.
     Input,
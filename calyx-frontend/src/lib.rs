@@ -5,6 +5,7 @@
 //! in the `ir` module.
 
 pub mod ast;
+pub mod mlir_parser;
 pub mod parser;
 
 mod attribute;
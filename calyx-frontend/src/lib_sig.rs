@@ -238,3 +238,99 @@ impl LibrarySignatures {
             .collect_vec()
     }
 }
+
+/// Serialized form of a [PrimitiveInfo]. The primitives of an extern block
+/// are stored as a list since their names are already part of them.
+#[cfg(feature = "serialize")]
+#[derive(serde::Serialize, serde::Deserialize)]
+enum SerPrimitiveInfo<P> {
+    Extern {
+        path: PathBuf,
+        primitives: Vec<P>,
+        is_source: bool,
+    },
+    Inline {
+        primitive: P,
+        is_source: bool,
+    },
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for LibrarySignatures {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        ser.collect_seq(self.prims.iter().map(|prim| match prim {
+            PrimitiveInfo::Extern {
+                path,
+                primitives,
+                is_source,
+            } => SerPrimitiveInfo::Extern {
+                path: path.clone(),
+                primitives: primitives.values().collect_vec(),
+                is_source: *is_source,
+            },
+            PrimitiveInfo::Inline {
+                primitive,
+                is_source,
+            } => SerPrimitiveInfo::Inline {
+                primitive,
+                is_source: *is_source,
+            },
+        }))
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for LibrarySignatures {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        let infos = Vec::<SerPrimitiveInfo<Primitive>>::deserialize(de)?;
+        let mut lib = LibrarySignatures::default();
+        for info in infos {
+            let (prims, is_source) = match &info {
+                SerPrimitiveInfo::Extern {
+                    primitives,
+                    is_source,
+                    ..
+                } => (primitives.iter().collect_vec(), *is_source),
+                SerPrimitiveInfo::Inline {
+                    primitive,
+                    is_source,
+                } => (vec![primitive], *is_source),
+            };
+            if let Some(prim) =
+                prims.iter().find(|p| lib.find_primitive(p.name).is_some())
+            {
+                return Err(D::Error::custom(format!(
+                    "Primitive `{}` is already defined in the context.",
+                    prim.name
+                )));
+            }
+            let prim_info = match info {
+                SerPrimitiveInfo::Extern {
+                    path, primitives, ..
+                } => {
+                    if lib.extern_paths().contains(&&path) {
+                        return Err(D::Error::custom(format!(
+                            "Extern block with file `{}` is defined twice",
+                            path.display()
+                        )));
+                    }
+                    lib.add_extern(path, primitives)
+                }
+                SerPrimitiveInfo::Inline { primitive, .. } => {
+                    lib.add_inline_primitive(primitive)
+                }
+            };
+            if is_source {
+                prim_info.set_source();
+            }
+        }
+        Ok(lib)
+    }
+}
@@ -0,0 +1,1730 @@
+#![allow(clippy::upper_case_acronyms)]
+
+//! Parser for programs in the textual form of the CIRCT Calyx dialect.
+//!
+//! Operations are first parsed into a form that mirrors the MLIR program and
+//! then lowered into the frontend AST. Lowering resolves the SSA values used
+//! by the operations into ports and constants, and infers the parameters of
+//! primitives from the types of their results.
+use super::ast::{self, Atom, BitNum, Control, GuardExpr, NumType};
+use super::Attributes;
+use crate::{
+    Attribute, BoolAttr, Direction, LibrarySignatures, PortDef, Width,
+};
+use calyx_utils::{self, CalyxResult, Error, Id};
+use calyx_utils::{FileIdx, GPosIdx, GlobalPositionTable, WithPos};
+use pest_consume::{match_nodes, Parser};
+use std::collections::{hash_map::Entry, HashMap};
+use std::fs;
+use std::io::Read;
+use std::num::NonZeroU64;
+use std::path::Path;
+
+type ParseResult<T> = Result<T, pest_consume::Error<Rule>>;
+
+/// Data associated with parsing the file.
+#[derive(Clone)]
+struct UserData {
+    /// Index to the current file
+    pub file: FileIdx,
+}
+
+type Node<'i> = pest_consume::Node<'i, Rule, UserData>;
+
+// include the grammar file so that Cargo knows to rebuild this file on grammar changes
+const _GRAMMAR: &str = include_str!("mlir.pest");
+
+/// Libraries that define the primitives used by the operations of the
+/// dialect. Programs in the dialect implicitly import them.
+pub const MLIR_IMPORTS: [&str; 4] = [
+    "primitives/core.futil",
+    "primitives/binary_operators.futil",
+    "primitives/memories/comb.futil",
+    "primitives/memories/seq.futil",
+];
+
+#[derive(Parser)]
+#[grammar = "mlir.pest"]
+pub struct MlirParser;
+
+impl MlirParser {
+    /// Parse the MLIR program in the file at `path`. The primitives it uses
+    /// are looked up in `lib`.
+    pub fn parse_file(
+        path: &Path,
+        lib: &LibrarySignatures,
+    ) -> CalyxResult<ast::NamespaceDef> {
+        let content = &fs::read(path).map_err(|err| {
+            Error::invalid_file(format!(
+                "Failed to read {}: {err}",
+                path.to_string_lossy(),
+            ))
+        })?;
+        let string_content = std::str::from_utf8(content)?.to_string();
+        Self::parse_source(
+            path.to_string_lossy().to_string(),
+            string_content,
+            lib,
+        )
+    }
+
+    /// Parse an MLIR program from `r`.
+    pub fn parse<R: Read>(
+        mut r: R,
+        lib: &LibrarySignatures,
+    ) -> CalyxResult<ast::NamespaceDef> {
+        let mut buf = String::new();
+        r.read_to_string(&mut buf).map_err(|err| {
+            Error::invalid_file(format!("Failed to parse buffer: {err}",))
+        })?;
+        Self::parse_source("<stdin>".to_string(), buf, lib)
+    }
+
+    fn parse_source(
+        name: String,
+        content: String,
+        lib: &LibrarySignatures,
+    ) -> CalyxResult<ast::NamespaceDef> {
+        let file =
+            GlobalPositionTable::as_mut().add_file(name.clone(), content);
+        let user_data = UserData { file };
+        let content = GlobalPositionTable::as_ref().get_source(file);
+        let module =
+            MlirParser::parse_with_userdata(Rule::file, content, user_data)
+                .and_then(|inputs| inputs.single())
+                .and_then(MlirParser::file)
+                .map_err(|e| {
+                    Error::misc(format!(
+                        "Failed to parse `{name}`: {err}",
+                        err = e.with_path(&name)
+                    ))
+                })?;
+        module.lower(lib)
+    }
+
+    fn get_span(node: &Node) -> GPosIdx {
+        let ud = node.user_data();
+        let sp = node.as_span();
+        let pos = GlobalPositionTable::as_mut().add_pos(
+            ud.file,
+            sp.start(),
+            sp.end(),
+        );
+        GPosIdx(pos)
+    }
+}
+
+// ================ Parsed operations =====================
+
+/// A use or definition of an SSA value.
+struct Value {
+    name: String,
+    span: GPosIdx,
+}
+
+/// What a cell operation instantiates.
+enum Proto {
+    /// A primitive. Parameters that are not bound are inferred from the
+    /// types of the results.
+    Primitive {
+        name: Id,
+        prim: Id,
+        bindings: Vec<(Id, u64)>,
+        attributes: Attributes,
+    },
+    /// A component defined in the module.
+    Component {
+        name: Id,
+        component: Id,
+        attributes: Attributes,
+    },
+    /// A `hw.constant` with the given literal value.
+    Constant(String),
+}
+
+/// An operation that defines the ports of a cell.
+struct CellOp {
+    results: Vec<Value>,
+    proto: Proto,
+    types: Vec<u64>,
+    span: GPosIdx,
+}
+
+enum Dest {
+    Port(Value),
+    /// The `go` or `done` hole of the enclosing group.
+    Hole(&'static str),
+}
+
+struct AssignOp {
+    dest: Dest,
+    guard: Option<Value>,
+    src: Value,
+    attributes: Attributes,
+}
+
+enum GroupOp {
+    Assign(AssignOp),
+    /// `%v = calyx.cycle start -> end`. The interval includes `end`.
+    Cycle(Value, u64, u64),
+}
+
+enum GroupKind {
+    Dynamic,
+    Comb,
+    Static(NonZeroU64),
+}
+
+struct GroupDef {
+    name: Id,
+    kind: GroupKind,
+    body: Vec<GroupOp>,
+    attributes: Attributes,
+}
+
+enum WireOp {
+    Group(GroupDef),
+    Assign(AssignOp),
+    Cell(CellOp),
+}
+
+enum ControlOp {
+    Enable {
+        group: Id,
+        attributes: Attributes,
+    },
+    Seq {
+        stmts: Vec<ControlOp>,
+        is_static: bool,
+        attributes: Attributes,
+    },
+    Par {
+        stmts: Vec<ControlOp>,
+        is_static: bool,
+        attributes: Attributes,
+    },
+    If {
+        port: Value,
+        cond: Option<Id>,
+        tbranch: Vec<ControlOp>,
+        fbranch: Vec<ControlOp>,
+        is_static: bool,
+        attributes: Attributes,
+    },
+    While {
+        port: Value,
+        cond: Option<Id>,
+        body: Vec<ControlOp>,
+        attributes: Attributes,
+    },
+    Repeat {
+        num_repeats: u64,
+        body: Vec<ControlOp>,
+        is_static: bool,
+        attributes: Attributes,
+    },
+    Invoke {
+        comp: Id,
+        ref_cells: Vec<(Id, Id)>,
+        inputs: Vec<(Value, Value)>,
+        attributes: Attributes,
+    },
+}
+
+/// A port of a component signature.
+type PortOp = (Id, u64, Attributes);
+
+struct ComponentOp {
+    name: Id,
+    inputs: Vec<PortOp>,
+    outputs: Vec<PortOp>,
+    attributes: Attributes,
+    cells: Vec<CellOp>,
+    wires: Vec<WireOp>,
+    control: Vec<ControlOp>,
+}
+
+struct ModuleOp {
+    entrypoint: Option<String>,
+    components: Vec<ComponentOp>,
+}
+
+#[pest_consume::parser]
+impl MlirParser {
+    fn EOI(_input: Node) -> ParseResult<()> {
+        Ok(())
+    }
+
+    fn num(input: Node) -> ParseResult<u64> {
+        input
+            .as_str()
+            .parse::<u64>()
+            .map_err(|_| input.error("Expected valid number"))
+    }
+
+    fn name(input: Node) -> ParseResult<Id> {
+        Ok(Id::new(input.as_str()))
+    }
+
+    fn ssa(input: Node) -> ParseResult<Value> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [name(name)] => Value { name: name.to_string(), span }
+        ))
+    }
+
+    fn symbol(input: Node) -> ParseResult<Id> {
+        Ok(match_nodes!(
+            input.into_children();
+            [name(name)] => name
+        ))
+    }
+
+    fn int_type(input: Node) -> ParseResult<u64> {
+        Ok(match_nodes!(
+            input.into_children();
+            [num(width)] => width
+        ))
+    }
+
+    fn types(input: Node) -> ParseResult<Vec<u64>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [int_type(types)..] => types.collect()
+        ))
+    }
+
+    fn char(input: Node) -> ParseResult<&str> {
+        Ok(input.as_str())
+    }
+
+    fn string_lit(input: Node) -> ParseResult<String> {
+        Ok(match_nodes!(
+            input.into_children();
+            [char(c)..] => c.collect::<Vec<_>>().join("")
+        ))
+    }
+
+    // ================ Attributes =====================
+    fn attr_key(input: Node) -> ParseResult<String> {
+        Ok(match_nodes!(
+            input.into_children();
+            [name(name)] => name.to_string(),
+            [string_lit(name)] => name,
+        ))
+    }
+
+    fn attr_value(input: Node) -> ParseResult<u64> {
+        Ok(match_nodes!(
+            input.into_children();
+            [num(val)] => val,
+            [num(val), int_type(_)] => val,
+        ))
+    }
+
+    fn attribute(input: Node) -> ParseResult<(Attribute, u64)> {
+        let (key, val) = match_nodes!(
+            input.clone().into_children();
+            [attr_key(key)] => (key, 1),
+            [attr_key(key), attr_value(val)] => (key, val),
+        );
+        Attribute::from_name(&key)
+            .map(|attr| (attr, val))
+            .map_err(|e| input.error(format!("{:?}", e)))
+    }
+
+    fn attributes(input: Node) -> ParseResult<Attributes> {
+        match_nodes!(
+            input.clone().into_children();
+            [attribute(kvs)..] => kvs.collect::<Vec<_>>().try_into().map_err(|e| input.error(format!("{:?}", e)))
+        )
+    }
+
+    // ================ Module =====================
+    fn module_attribute(input: Node) -> ParseResult<(Id, String)> {
+        Ok(match_nodes!(
+            input.into_children();
+            [name(key), string_lit(val)] => (key, val)
+        ))
+    }
+
+    fn module_attributes(input: Node) -> ParseResult<Option<String>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [module_attribute(attrs)..] => attrs
+                .filter(|(key, _)| key == "calyx.entrypoint")
+                .map(|(_, val)| val)
+                .last()
+        ))
+    }
+
+    fn components(input: Node) -> ParseResult<Vec<ComponentOp>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [component(comps)..] => comps.collect()
+        ))
+    }
+
+    fn file(input: Node) -> ParseResult<ModuleOp> {
+        Ok(match_nodes!(
+            input.into_children();
+            [module_attributes(entrypoint), components(components), EOI(_)] => ModuleOp {
+                entrypoint,
+                components,
+            },
+            [components(components), EOI(_)] => ModuleOp {
+                entrypoint: None,
+                components,
+            },
+        ))
+    }
+
+    fn port_def(input: Node) -> ParseResult<PortOp> {
+        Ok(match_nodes!(
+            input.into_children();
+            [ssa(port), int_type(width)] =>
+                (Id::new(port.name), width, Attributes::default()),
+            [ssa(port), int_type(width), attributes(attrs)] =>
+                (Id::new(port.name), width, attrs),
+        ))
+    }
+
+    fn port_defs(input: Node) -> ParseResult<Vec<PortOp>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [port_def(ports)..] => ports.collect()
+        ))
+    }
+
+    fn component(input: Node) -> ParseResult<ComponentOp> {
+        let span = Self::get_span(&input);
+        let (name, inputs, outputs, attributes, cells, wires, control) = match_nodes!(
+            input.into_children();
+            [
+                symbol(name),
+                port_defs(inputs),
+                port_defs(outputs),
+                cells(cells),
+                wires(wires),
+                control(control)
+            ] => (name, inputs, outputs, Attributes::default(), cells, wires, control),
+            [
+                symbol(name),
+                port_defs(inputs),
+                port_defs(outputs),
+                attributes(attrs),
+                cells(cells),
+                wires(wires),
+                control(control)
+            ] => (name, inputs, outputs, attrs, cells, wires, control),
+        );
+        Ok(ComponentOp {
+            name,
+            inputs,
+            outputs,
+            attributes: attributes.add_span(span),
+            cells,
+            wires,
+            control,
+        })
+    }
+
+    // ================ Cells =====================
+    fn results(input: Node) -> ParseResult<Vec<Value>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [ssa(results)..] => results.collect()
+        ))
+    }
+
+    fn instance(input: Node) -> ParseResult<Proto> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [symbol(name), symbol(component)] => Proto::Component {
+                name,
+                component,
+                attributes: Attributes::default().add_span(span),
+            },
+            [symbol(name), symbol(component), attributes(attrs)] => Proto::Component {
+                name,
+                component,
+                attributes: attrs.add_span(span),
+            },
+        ))
+    }
+
+    fn memory_kind(input: Node) -> ParseResult<bool> {
+        Ok(input.as_str() == "calyx.seq_mem")
+    }
+
+    fn nums(input: Node) -> ParseResult<Vec<u64>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [num(nums)..] => nums.collect()
+        ))
+    }
+
+    fn memory(input: Node) -> ParseResult<Proto> {
+        let span = Self::get_span(&input);
+        let (is_seq, name, sizes, width, idx_sizes, attrs) = match_nodes!(
+            input.clone().into_children();
+            [memory_kind(k), symbol(n), nums(s), num(w), nums(i)] =>
+                (k, n, s, w, i, Attributes::default()),
+            [memory_kind(k), symbol(n), nums(s), num(w), nums(i), attributes(a)] =>
+                (k, n, s, w, i, a),
+        );
+        if sizes.len() != idx_sizes.len() || sizes.len() > 4 {
+            return Err(input.error(
+                "Memories have one to four dimensions, each with a size and an index size",
+            ));
+        }
+        let dims = sizes.len();
+        let mut bindings = vec![(Id::new("WIDTH"), width)];
+        for (i, (size, idx_size)) in
+            sizes.into_iter().zip(idx_sizes).enumerate()
+        {
+            let (size_param, idx_param) = if dims == 1 {
+                ("SIZE".to_string(), "IDX_SIZE".to_string())
+            } else {
+                (format!("D{i}_SIZE"), format!("D{i}_IDX_SIZE"))
+            };
+            bindings.push((Id::new(size_param), size));
+            bindings.push((Id::new(idx_param), idx_size));
+        }
+        let kind = if is_seq { "seq" } else { "comb" };
+        Ok(Proto::Primitive {
+            name,
+            prim: Id::new(format!("{kind}_mem_d{dims}")),
+            bindings,
+            attributes: attrs.add_span(span),
+        })
+    }
+
+    fn const_value(input: Node) -> ParseResult<String> {
+        Ok(input.as_str().to_string())
+    }
+
+    fn constant(input: Node) -> ParseResult<Proto> {
+        Ok(match_nodes!(
+            input.into_children();
+            [const_value(val)] => Proto::Constant(val)
+        ))
+    }
+
+    fn prim_name(input: Node) -> ParseResult<Id> {
+        Ok(match &input.as_str()["calyx.".len()..] {
+            "register" => Id::new("std_reg"),
+            "undefined" => Id::new("undef"),
+            prim => Id::new(prim),
+        })
+    }
+
+    fn primitive(input: Node) -> ParseResult<Proto> {
+        let span = Self::get_span(&input);
+        let (prim, name, attrs) = match_nodes!(
+            input.into_children();
+            [prim_name(prim), symbol(name)] => (prim, name, Attributes::default()),
+            [prim_name(prim), symbol(name), attributes(attrs)] => (prim, name, attrs),
+        );
+        Ok(Proto::Primitive {
+            name,
+            prim,
+            bindings: vec![],
+            attributes: attrs.add_span(span),
+        })
+    }
+
+    fn cell_op(input: Node) -> ParseResult<Proto> {
+        Ok(match_nodes!(
+            input.into_children();
+            [instance(proto)] => proto,
+            [memory(proto)] => proto,
+            [constant(proto)] => proto,
+            [primitive(proto)] => proto,
+        ))
+    }
+
+    fn cell(input: Node) -> ParseResult<CellOp> {
+        let span = Self::get_span(&input);
+        let (results, proto, types) = match_nodes!(
+            input.into_children();
+            [results(rs), cell_op(p)] => (rs, p, vec![]),
+            [results(rs), cell_op(p), types(ts)] => (rs, p, ts),
+        );
+        Ok(CellOp {
+            results,
+            proto,
+            types,
+            span,
+        })
+    }
+
+    fn cells(input: Node) -> ParseResult<Vec<CellOp>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [cell(cells)..] => cells.collect()
+        ))
+    }
+
+    // ================ Wires =====================
+    fn guard(input: Node) -> ParseResult<Value> {
+        Ok(match_nodes!(
+            input.into_children();
+            [ssa(guard)] => guard
+        ))
+    }
+
+    /// The guard, source and attributes of an assignment-like operation.
+    fn guarded(input: Node) -> ParseResult<(Option<Value>, Value, Attributes)> {
+        let span = Self::get_span(&input);
+        let (guard, src, attrs) = match_nodes!(
+            input.into_children();
+            [guard(g), ssa(src), int_type(_)] => (Some(g), src, Attributes::default()),
+            [guard(g), ssa(src), int_type(_), attributes(a)] => (Some(g), src, a),
+            [ssa(src), int_type(_)] => (None, src, Attributes::default()),
+            [ssa(src), int_type(_), attributes(a)] => (None, src, a),
+        );
+        Ok((guard, src, attrs.add_span(span)))
+    }
+
+    fn assign(input: Node) -> ParseResult<AssignOp> {
+        Ok(match_nodes!(
+            input.into_children();
+            [ssa(dest), guarded((guard, src, attributes))] => AssignOp {
+                dest: Dest::Port(dest),
+                guard,
+                src,
+                attributes,
+            }
+        ))
+    }
+
+    fn group_go(input: Node) -> ParseResult<AssignOp> {
+        Ok(match_nodes!(
+            input.into_children();
+            [guarded((guard, src, attributes))] => AssignOp {
+                dest: Dest::Hole("go"),
+                guard,
+                src,
+                attributes,
+            }
+        ))
+    }
+
+    fn group_done(input: Node) -> ParseResult<AssignOp> {
+        Ok(match_nodes!(
+            input.into_children();
+            [guarded((guard, src, attributes))] => AssignOp {
+                dest: Dest::Hole("done"),
+                guard,
+                src,
+                attributes,
+            }
+        ))
+    }
+
+    fn cycle(input: Node) -> ParseResult<GroupOp> {
+        Ok(match_nodes!(
+            input.into_children();
+            [ssa(val), num(start)] => GroupOp::Cycle(val, start, start),
+            [ssa(val), num(start), num(end)] => GroupOp::Cycle(val, start, end),
+        ))
+    }
+
+    fn group_op(input: Node) -> ParseResult<GroupOp> {
+        Ok(match_nodes!(
+            input.into_children();
+            [group_go(assign)] => GroupOp::Assign(assign),
+            [group_done(assign)] => GroupOp::Assign(assign),
+            [assign(assign)] => GroupOp::Assign(assign),
+            [cycle(cycle)] => cycle,
+        ))
+    }
+
+    fn group_body(input: Node) -> ParseResult<Vec<GroupOp>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [group_op(ops)..] => ops.collect()
+        ))
+    }
+
+    fn group(input: Node) -> ParseResult<GroupDef> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [symbol(name), group_body(body)] => GroupDef {
+                name, kind: GroupKind::Dynamic, body,
+                attributes: Attributes::default().add_span(span),
+            },
+            [symbol(name), group_body(body), attributes(attrs)] => GroupDef {
+                name, kind: GroupKind::Dynamic, body,
+                attributes: attrs.add_span(span),
+            },
+        ))
+    }
+
+    fn comb_group(input: Node) -> ParseResult<GroupDef> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [symbol(name), group_body(body)] => GroupDef {
+                name, kind: GroupKind::Comb, body,
+                attributes: Attributes::default().add_span(span),
+            },
+            [symbol(name), group_body(body), attributes(attrs)] => GroupDef {
+                name, kind: GroupKind::Comb, body,
+                attributes: attrs.add_span(span),
+            },
+        ))
+    }
+
+    fn static_group(input: Node) -> ParseResult<GroupDef> {
+        let span = Self::get_span(&input);
+        let (latency, name, body, attrs) = match_nodes!(
+            input.clone().into_children();
+            [num(l), symbol(n), group_body(b)] => (l, n, b, Attributes::default()),
+            [num(l), symbol(n), group_body(b), attributes(a)] => (l, n, b, a),
+        );
+        let latency = NonZeroU64::new(latency).ok_or_else(|| {
+            input.error("Static groups must have a non-zero latency")
+        })?;
+        Ok(GroupDef {
+            name,
+            kind: GroupKind::Static(latency),
+            body,
+            attributes: attrs.add_span(span),
+        })
+    }
+
+    fn wire(input: Node) -> ParseResult<WireOp> {
+        Ok(match_nodes!(
+            input.into_children();
+            [group(group)] => WireOp::Group(group),
+            [comb_group(group)] => WireOp::Group(group),
+            [static_group(group)] => WireOp::Group(group),
+            [assign(assign)] => WireOp::Assign(assign),
+            [cell(cell)] => WireOp::Cell(cell),
+        ))
+    }
+
+    fn wires(input: Node) -> ParseResult<Vec<WireOp>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [wire(wires)..] => wires.collect()
+        ))
+    }
+
+    // ================ Control =====================
+    fn block(input: Node) -> ParseResult<Vec<ControlOp>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [stmt(stmts)..] => stmts.collect()
+        ))
+    }
+
+    fn with_group(input: Node) -> ParseResult<Id> {
+        Ok(match_nodes!(
+            input.into_children();
+            [symbol(group)] => group
+        ))
+    }
+
+    fn else_block(input: Node) -> ParseResult<Vec<ControlOp>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [block(stmts)] => stmts
+        ))
+    }
+
+    fn enable(input: Node) -> ParseResult<ControlOp> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [symbol(group)] => ControlOp::Enable {
+                group,
+                attributes: Attributes::default().add_span(span),
+            },
+            [symbol(group), attributes(attrs)] => ControlOp::Enable {
+                group,
+                attributes: attrs.add_span(span),
+            },
+        ))
+    }
+
+    fn block_attrs(input: Node) -> ParseResult<(Vec<ControlOp>, Attributes)> {
+        Ok(match_nodes!(
+            input.into_children();
+            [block(stmts)] => (stmts, Attributes::default()),
+            [block(stmts), attributes(attrs)] => (stmts, attrs),
+        ))
+    }
+
+    fn seq(input: Node) -> ParseResult<ControlOp> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [block_attrs((stmts, attrs))] => ControlOp::Seq {
+                stmts,
+                is_static: false,
+                attributes: attrs.add_span(span),
+            }
+        ))
+    }
+
+    fn par(input: Node) -> ParseResult<ControlOp> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [block_attrs((stmts, attrs))] => ControlOp::Par {
+                stmts,
+                is_static: false,
+                attributes: attrs.add_span(span),
+            }
+        ))
+    }
+
+    fn static_seq(input: Node) -> ParseResult<ControlOp> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [block_attrs((stmts, attrs))] => ControlOp::Seq {
+                stmts,
+                is_static: true,
+                attributes: attrs.add_span(span),
+            }
+        ))
+    }
+
+    fn static_par(input: Node) -> ParseResult<ControlOp> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [block_attrs((stmts, attrs))] => ControlOp::Par {
+                stmts,
+                is_static: true,
+                attributes: attrs.add_span(span),
+            }
+        ))
+    }
+
+    fn if_stmt(input: Node) -> ParseResult<ControlOp> {
+        let span = Self::get_span(&input);
+        let (port, cond, tbranch, fbranch, attrs) = match_nodes!(
+            input.into_children();
+            [ssa(p), block(t)] => (p, None, t, vec![], None),
+            [ssa(p), block(t), attributes(a)] => (p, None, t, vec![], Some(a)),
+            [ssa(p), block(t), else_block(f)] => (p, None, t, f, None),
+            [ssa(p), block(t), else_block(f), attributes(a)] => (p, None, t, f, Some(a)),
+            [ssa(p), with_group(c), block(t)] => (p, Some(c), t, vec![], None),
+            [ssa(p), with_group(c), block(t), attributes(a)] => (p, Some(c), t, vec![], Some(a)),
+            [ssa(p), with_group(c), block(t), else_block(f)] => (p, Some(c), t, f, None),
+            [ssa(p), with_group(c), block(t), else_block(f), attributes(a)] => (p, Some(c), t, f, Some(a)),
+        );
+        Ok(ControlOp::If {
+            port,
+            cond,
+            tbranch,
+            fbranch,
+            is_static: false,
+            attributes: attrs.unwrap_or_default().add_span(span),
+        })
+    }
+
+    fn static_if(input: Node) -> ParseResult<ControlOp> {
+        let span = Self::get_span(&input);
+        let (port, tbranch, fbranch, attrs) = match_nodes!(
+            input.into_children();
+            [ssa(p), block(t)] => (p, t, vec![], None),
+            [ssa(p), block(t), attributes(a)] => (p, t, vec![], Some(a)),
+            [ssa(p), block(t), else_block(f)] => (p, t, f, None),
+            [ssa(p), block(t), else_block(f), attributes(a)] => (p, t, f, Some(a)),
+        );
+        Ok(ControlOp::If {
+            port,
+            cond: None,
+            tbranch,
+            fbranch,
+            is_static: true,
+            attributes: attrs.unwrap_or_default().add_span(span),
+        })
+    }
+
+    fn while_stmt(input: Node) -> ParseResult<ControlOp> {
+        let span = Self::get_span(&input);
+        let (port, cond, body, attrs) = match_nodes!(
+            input.into_children();
+            [ssa(p), block(b)] => (p, None, b, None),
+            [ssa(p), block(b), attributes(a)] => (p, None, b, Some(a)),
+            [ssa(p), with_group(c), block(b)] => (p, Some(c), b, None),
+            [ssa(p), with_group(c), block(b), attributes(a)] => (p, Some(c), b, Some(a)),
+        );
+        Ok(ControlOp::While {
+            port,
+            cond,
+            body,
+            attributes: attrs.unwrap_or_default().add_span(span),
+        })
+    }
+
+    fn repeat(input: Node) -> ParseResult<ControlOp> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [num(num_repeats), block_attrs((body, attrs))] => ControlOp::Repeat {
+                num_repeats,
+                body,
+                is_static: false,
+                attributes: attrs.add_span(span),
+            }
+        ))
+    }
+
+    fn static_repeat(input: Node) -> ParseResult<ControlOp> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [num(num_repeats), block_attrs((body, attrs))] => ControlOp::Repeat {
+                num_repeats,
+                body,
+                is_static: true,
+                attributes: attrs.add_span(span),
+            }
+        ))
+    }
+
+    fn ref_cell(input: Node) -> ParseResult<(Id, Id)> {
+        Ok(match_nodes!(
+            input.into_children();
+            [name(outcell), name(incell)] => (outcell, incell)
+        ))
+    }
+
+    fn ref_cells(input: Node) -> ParseResult<Vec<(Id, Id)>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [ref_cell(cells)..] => cells.collect()
+        ))
+    }
+
+    fn invoke_arg(input: Node) -> ParseResult<(Value, Value)> {
+        Ok(match_nodes!(
+            input.into_children();
+            [ssa(port), ssa(src)] => (port, src)
+        ))
+    }
+
+    fn invoke_args(input: Node) -> ParseResult<Vec<(Value, Value)>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [invoke_arg(args)..] => args.collect()
+        ))
+    }
+
+    fn invoke(input: Node) -> ParseResult<ControlOp> {
+        let span = Self::get_span(&input);
+        let mut comp = None;
+        let mut ref_cells = vec![];
+        let mut inputs = vec![];
+        let mut attributes = Attributes::default();
+        for child in input.into_children() {
+            match child.as_rule() {
+                Rule::symbol => comp = Some(Self::symbol(child)?),
+                Rule::ref_cells => ref_cells = Self::ref_cells(child)?,
+                Rule::invoke_args => inputs = Self::invoke_args(child)?,
+                Rule::attributes => attributes = Self::attributes(child)?,
+                _ => (),
+            }
+        }
+        Ok(ControlOp::Invoke {
+            comp: comp.unwrap(),
+            ref_cells,
+            inputs,
+            attributes: attributes.add_span(span),
+        })
+    }
+
+    fn stmt(input: Node) -> ParseResult<ControlOp> {
+        Ok(match_nodes!(
+            input.into_children();
+            [enable(c)] => c,
+            [seq(c)] => c,
+            [par(c)] => c,
+            [if_stmt(c)] => c,
+            [while_stmt(c)] => c,
+            [repeat(c)] => c,
+            [invoke(c)] => c,
+            [static_seq(c)] => c,
+            [static_par(c)] => c,
+            [static_if(c)] => c,
+            [static_repeat(c)] => c,
+        ))
+    }
+
+    fn control(input: Node) -> ParseResult<Vec<ControlOp>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [block(stmts)] => stmts
+        ))
+    }
+}
+
+// ================ Lowering =====================
+
+/// What an SSA value refers to.
+#[derive(Clone, Copy)]
+enum Def {
+    /// A port of the component being defined.
+    This(Id),
+    /// A port of a cell.
+    Cell(Id, Id),
+    /// A constant value and its width.
+    Const(u64, u64),
+    /// True during the cycles `[start, end)` of the enclosing static group.
+    Cycle(u64, u64),
+}
+
+/// The values defined in the component being lowered.
+#[derive(Default)]
+struct Scope {
+    defs: HashMap<String, Def>,
+}
+
+impl Scope {
+    fn define(&mut self, value: &Value, def: Def) -> CalyxResult<()> {
+        match self.defs.entry(value.name.clone()) {
+            Entry::Occupied(_) => {
+                Err(Error::already_bound(Id::new(&value.name), "value")
+                    .with_pos(&value.span))
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(def);
+                Ok(())
+            }
+        }
+    }
+
+    fn get(&self, value: &Value) -> CalyxResult<Def> {
+        self.defs.get(&value.name).copied().ok_or_else(|| {
+            Error::undefined(Id::new(&value.name), "value")
+                .with_pos(&value.span)
+        })
+    }
+
+    fn port(&self, value: &Value) -> CalyxResult<ast::Port> {
+        match self.get(value)? {
+            Def::This(port) => Ok(ast::Port::This { port }),
+            Def::Cell(component, port) => {
+                Ok(ast::Port::Comp { component, port })
+            }
+            Def::Const(..) => Err(Error::malformed_structure(format!(
+                "`%{}` is a constant and not a port",
+                value.name
+            ))
+            .with_pos(&value.span)),
+            Def::Cycle(..) => Err(Error::malformed_structure(format!(
+                "`%{}` is defined by `calyx.cycle` and can only guard assignments in its static group",
+                value.name
+            ))
+            .with_pos(&value.span)),
+        }
+    }
+
+    fn atom(&self, value: &Value) -> CalyxResult<Atom> {
+        match self.get(value)? {
+            Def::Const(val, width) => Ok(Atom::Num(BitNum {
+                width: width.into(),
+                num_type: NumType::Decimal,
+                val,
+                span: value.span,
+            })),
+            _ => self.port(value).map(Atom::Port),
+        }
+    }
+}
+
+/// The value of the `hw.constant` literal `lit` with `width` bits. Negative
+/// literals are represented in two's complement.
+fn constant_value(lit: &str, width: u64) -> Option<u64> {
+    let mask = if width >= 64 {
+        u64::MAX
+    } else {
+        (1 << width) - 1
+    };
+    match lit {
+        "true" => Some(1),
+        "false" => Some(0),
+        _ => match lit.strip_prefix('-') {
+            Some(mag) => Some(mag.parse::<u64>().ok()?.wrapping_neg() & mask),
+            None => lit.parse::<u64>().ok().filter(|val| val & !mask == 0),
+        },
+    }
+}
+
+/// Lowers the operations of a module into the frontend AST.
+struct Lowering<'a> {
+    lib: &'a LibrarySignatures,
+    /// The ports of the components defined in the module.
+    signatures: HashMap<Id, Vec<Id>>,
+}
+
+impl ModuleOp {
+    fn lower(self, lib: &LibrarySignatures) -> CalyxResult<ast::NamespaceDef> {
+        let lowering = Lowering {
+            lib,
+            signatures: self
+                .components
+                .iter()
+                .map(|comp| {
+                    let ports = comp
+                        .inputs
+                        .iter()
+                        .chain(&comp.outputs)
+                        .map(|(name, ..)| *name)
+                        .collect();
+                    (comp.name, ports)
+                })
+                .collect(),
+        };
+        let mut components = self
+            .components
+            .into_iter()
+            .map(|comp| lowering.component(comp))
+            .collect::<CalyxResult<Vec<_>>>()?;
+
+        // The entrypoint of the program is named `main` or marked
+        // `@toplevel`.
+        if let Some(entry) = self.entrypoint.filter(|entry| entry != "main") {
+            if !components
+                .iter()
+                .any(|comp| comp.attributes.has(BoolAttr::TopLevel))
+            {
+                let Some(top) =
+                    components.iter_mut().find(|comp| comp.name == entry)
+                else {
+                    return Err(Error::misc(format!(
+                        "Entrypoint `{entry}` is not defined"
+                    )));
+                };
+                top.attributes.insert(BoolAttr::TopLevel, 1);
+            }
+        }
+
+        Ok(ast::NamespaceDef {
+            imports: MLIR_IMPORTS.iter().map(|imp| imp.to_string()).collect(),
+            components,
+            externs: vec![],
+            consts: vec![],
+            metadata: None,
+        })
+    }
+}
+
+impl Lowering<'_> {
+    fn component(&self, comp: ComponentOp) -> CalyxResult<ast::ComponentDef> {
+        let mut scope = Scope::default();
+        let mut signature = vec![];
+        for (ports, direction) in [
+            (comp.inputs, Direction::Input),
+            (comp.outputs, Direction::Output),
+        ] {
+            for (name, width, attributes) in ports {
+                let value = Value {
+                    name: name.to_string(),
+                    span: attributes.copy_span(),
+                };
+                scope.define(&value, Def::This(name))?;
+                signature.push(PortDef::new(
+                    name,
+                    width.into(),
+                    direction.clone(),
+                    attributes,
+                ));
+            }
+        }
+        let mut def = ast::ComponentDef::new(comp.name, false, None, signature);
+        def.attributes = comp.attributes;
+
+        // Cells may be defined in the wires, after the assignments using them
+        let mut cells = comp.cells;
+        let mut wires = vec![];
+        for op in comp.wires {
+            match op {
+                WireOp::Cell(cell) => cells.push(cell),
+                op => wires.push(op),
+            }
+        }
+        for cell in cells {
+            if let Some(cell) = self.cell(cell, &mut scope)? {
+                def.cells.push(cell);
+            }
+        }
+        for op in wires {
+            match op {
+                WireOp::Group(group) => {
+                    Self::group(group, &mut scope, &mut def)?
+                }
+                WireOp::Assign(assign) => {
+                    let wire = Self::wire(assign, None, &scope)?;
+                    def.continuous_assignments.push(wire);
+                }
+                WireOp::Cell(_) => unreachable!("cells are lowered first"),
+            }
+        }
+        def.control = Self::region(comp.control, false, &scope)?;
+        Ok(def)
+    }
+
+    /// Lower a cell operation and define its results. Returns `None` for
+    /// constants, which are not cells in the AST.
+    fn cell(
+        &self,
+        op: CellOp,
+        scope: &mut Scope,
+    ) -> CalyxResult<Option<ast::Cell>> {
+        if !matches!(op.proto, Proto::Constant(_))
+            && op.results.len() != op.types.len()
+        {
+            return Err(Error::malformed_structure(format!(
+                "Operation defines {} values but has {} types",
+                op.results.len(),
+                op.types.len()
+            ))
+            .with_pos(&op.span));
+        }
+        match op.proto {
+            Proto::Constant(lit) => {
+                let width = match (&op.types[..], lit.as_str()) {
+                    (&[width], _) => width,
+                    ([], "true" | "false") => 1,
+                    _ => {
+                        return Err(Error::malformed_structure(
+                            "`hw.constant` needs the type of its value",
+                        )
+                        .with_pos(&op.span))
+                    }
+                };
+                let [result] = &op.results[..] else {
+                    return Err(Error::malformed_structure(
+                        "`hw.constant` defines a single value",
+                    )
+                    .with_pos(&op.span));
+                };
+                let val = constant_value(&lit, width).ok_or_else(|| {
+                    Error::malformed_structure(format!(
+                        "`{lit}` is not a valid constant of width {width}"
+                    ))
+                    .with_pos(&op.span)
+                })?;
+                scope.define(result, Def::Const(val, width))?;
+                Ok(None)
+            }
+            Proto::Primitive {
+                name,
+                prim,
+                bindings,
+                attributes,
+            } => {
+                let Some(sig) = self.lib.find_primitive(prim) else {
+                    return Err(
+                        Error::undefined(prim, "primitive").with_pos(&op.span)
+                    );
+                };
+                let ports = sig
+                    .signature
+                    .iter()
+                    .map(|pd| pd.name())
+                    .collect::<Vec<_>>();
+                let defined =
+                    Self::define_results(name, &ports, &op.results, scope)?;
+
+                // Infer the parameters from the widths of the ports
+                let mut params: HashMap<Id, u64> =
+                    bindings.into_iter().collect();
+                for (idx, width) in defined.into_iter().zip(op.types) {
+                    let expected = match &sig.signature[idx].width {
+                        Width::Const { value } => *value,
+                        Width::Param { value } => {
+                            *params.entry(*value).or_insert(width)
+                        }
+                    };
+                    if expected != width {
+                        return Err(Error::malformed_structure(format!(
+                            "Port `{}` of `{name}` has width {width} but `{prim}` requires width {expected}",
+                            ports[idx]
+                        ))
+                        .with_pos(&op.span));
+                    }
+                }
+                let params = sig
+                    .params
+                    .iter()
+                    .map(|param| {
+                        params.get(param).copied().map(ast::ConstExpr::from).ok_or_else(|| {
+                            Error::malformed_structure(format!(
+                                "Cannot infer parameter `{param}` of `{name}` from the widths of its ports"
+                            ))
+                            .with_pos(&op.span)
+                        })
+                    })
+                    .collect::<CalyxResult<_>>()?;
+                Ok(Some(ast::Cell::from(name, prim, params, attributes, false)))
+            }
+            Proto::Component {
+                name,
+                component,
+                attributes,
+            } => {
+                let Some(ports) = self.signatures.get(&component) else {
+                    return Err(Error::undefined(component, "component")
+                        .with_pos(&op.span));
+                };
+                Self::define_results(name, ports, &op.results, scope)?;
+                Ok(Some(ast::Cell::from(
+                    name,
+                    component,
+                    vec![],
+                    attributes,
+                    false,
+                )))
+            }
+        }
+    }
+
+    /// Define the `results` of the cell `cell` with the given `ports`. A
+    /// result named `%cell.port` is that port and any other result is the
+    /// port at the same position. Returns the index of each defined port.
+    fn define_results(
+        cell: Id,
+        ports: &[Id],
+        results: &[Value],
+        scope: &mut Scope,
+    ) -> CalyxResult<Vec<usize>> {
+        let prefix = format!("{cell}.");
+        results
+            .iter()
+            .enumerate()
+            .map(|(i, result)| {
+                let idx = result
+                    .name
+                    .strip_prefix(&prefix)
+                    .and_then(|port| ports.iter().position(|p| p == port))
+                    .unwrap_or(i);
+                let Some(port) = ports.get(idx) else {
+                    return Err(Error::malformed_structure(format!(
+                        "`{cell}` defines {} values but has {} ports",
+                        results.len(),
+                        ports.len()
+                    ))
+                    .with_pos(&result.span));
+                };
+                scope.define(result, Def::Cell(cell, *port))?;
+                Ok(idx)
+            })
+            .collect()
+    }
+
+    fn group(
+        group: GroupDef,
+        scope: &mut Scope,
+        def: &mut ast::ComponentDef,
+    ) -> CalyxResult<()> {
+        let GroupDef {
+            name,
+            kind,
+            body,
+            attributes,
+        } = group;
+        // Values defined by `calyx.cycle` are only visible in their group
+        let mut cycles = vec![];
+        let mut assigns = vec![];
+        for op in body {
+            match op {
+                GroupOp::Assign(assign) => assigns.push(assign),
+                GroupOp::Cycle(value, start, end) => {
+                    if !matches!(kind, GroupKind::Static(_)) || end < start {
+                        return Err(Error::malformed_structure(
+                            "`calyx.cycle` defines an interval of cycles in a static group",
+                        )
+                        .with_pos(&value.span));
+                    }
+                    scope.define(&value, Def::Cycle(start, end + 1))?;
+                    cycles.push(value);
+                }
+            }
+        }
+        match kind {
+            GroupKind::Static(latency) => {
+                let wires = assigns
+                    .into_iter()
+                    .map(|assign| Self::static_wire(assign, name, scope))
+                    .collect::<CalyxResult<_>>()?;
+                def.static_groups.push(ast::StaticGroup {
+                    name,
+                    wires,
+                    attributes,
+                    latency,
+                });
+            }
+            GroupKind::Dynamic | GroupKind::Comb => {
+                let wires = assigns
+                    .into_iter()
+                    .map(|assign| Self::wire(assign, Some(name), scope))
+                    .collect::<CalyxResult<_>>()?;
+                def.groups.push(ast::Group {
+                    name,
+                    wires,
+                    attributes,
+                    is_comb: matches!(kind, GroupKind::Comb),
+                });
+            }
+        }
+        for value in cycles {
+            scope.defs.remove(&value.name);
+        }
+        Ok(())
+    }
+
+    fn dest(
+        dest: Dest,
+        group: Option<Id>,
+        attributes: &Attributes,
+        scope: &Scope,
+    ) -> CalyxResult<ast::Port> {
+        match (dest, group) {
+            (Dest::Port(port), _) => scope.port(&port),
+            (Dest::Hole(hole), Some(group)) => Ok(ast::Port::Hole {
+                group,
+                name: Id::new(hole),
+            }),
+            (Dest::Hole(hole), None) => Err(Error::malformed_structure(
+                format!("`calyx.group_{hole}` can only be used in groups"),
+            )
+            .with_pos(attributes)),
+        }
+    }
+
+    fn wire(
+        assign: AssignOp,
+        group: Option<Id>,
+        scope: &Scope,
+    ) -> CalyxResult<ast::Wire> {
+        let guard = assign
+            .guard
+            .map(|guard| scope.atom(&guard).map(GuardExpr::Atom))
+            .transpose()?;
+        Ok(ast::Wire {
+            src: ast::Guard {
+                guard,
+                expr: scope.atom(&assign.src)?,
+            },
+            dest: Self::dest(assign.dest, group, &assign.attributes, scope)?,
+            attributes: assign.attributes,
+        })
+    }
+
+    fn static_wire(
+        assign: AssignOp,
+        group: Id,
+        scope: &Scope,
+    ) -> CalyxResult<ast::StaticWire> {
+        let guard = match assign.guard {
+            None => None,
+            Some(guard) => Some(match scope.get(&guard)? {
+                Def::Cycle(start, end) => {
+                    ast::StaticGuardExpr::StaticInfo((start, end))
+                }
+                _ => ast::StaticGuardExpr::Atom(scope.atom(&guard)?),
+            }),
+        };
+        Ok(ast::StaticWire {
+            src: ast::StaticGuard {
+                guard,
+                expr: scope.atom(&assign.src)?,
+            },
+            dest: Self::dest(
+                assign.dest,
+                Some(group),
+                &assign.attributes,
+                scope,
+            )?,
+            attributes: assign.attributes,
+        })
+    }
+
+    fn stmts(
+        stmts: Vec<ControlOp>,
+        scope: &Scope,
+    ) -> CalyxResult<Vec<Control>> {
+        stmts
+            .into_iter()
+            .map(|stmt| Self::control(stmt, scope))
+            .collect()
+    }
+
+    /// Lower the statements of a region. Regions with several statements
+    /// run them in sequence.
+    fn region(
+        stmts: Vec<ControlOp>,
+        is_static: bool,
+        scope: &Scope,
+    ) -> CalyxResult<Control> {
+        let mut stmts = Self::stmts(stmts, scope)?;
+        Ok(match stmts.len() {
+            0 => Control::empty(),
+            1 => stmts.pop().unwrap(),
+            _ if is_static => Control::StaticSeq {
+                stmts,
+                attributes: Attributes::default(),
+                latency: None,
+            },
+            _ => Control::Seq {
+                stmts,
+                attributes: Attributes::default(),
+            },
+        })
+    }
+
+    fn control(op: ControlOp, scope: &Scope) -> CalyxResult<Control> {
+        Ok(match op {
+            ControlOp::Enable { group, attributes } => Control::Enable {
+                comp: group,
+                attributes,
+            },
+            ControlOp::Seq {
+                stmts,
+                is_static,
+                attributes,
+            } => {
+                let stmts = Self::stmts(stmts, scope)?;
+                if is_static {
+                    Control::StaticSeq {
+                        stmts,
+                        attributes,
+                        latency: None,
+                    }
+                } else {
+                    Control::Seq { stmts, attributes }
+                }
+            }
+            ControlOp::Par {
+                stmts,
+                is_static,
+                attributes,
+            } => {
+                let stmts = Self::stmts(stmts, scope)?;
+                if is_static {
+                    Control::StaticPar {
+                        stmts,
+                        attributes,
+                        latency: None,
+                    }
+                } else {
+                    Control::Par { stmts, attributes }
+                }
+            }
+            ControlOp::If {
+                port,
+                cond,
+                tbranch,
+                fbranch,
+                is_static,
+                attributes,
+            } => {
+                let port = scope.port(&port)?;
+                let tbranch =
+                    Box::new(Self::region(tbranch, is_static, scope)?);
+                let fbranch =
+                    Box::new(Self::region(fbranch, is_static, scope)?);
+                if is_static {
+                    Control::StaticIf {
+                        port,
+                        tbranch,
+                        fbranch,
+                        attributes,
+                        latency: None,
+                    }
+                } else {
+                    Control::If {
+                        port,
+                        cond,
+                        tbranch,
+                        fbranch,
+                        attributes,
+                    }
+                }
+            }
+            ControlOp::While {
+                port,
+                cond,
+                body,
+                attributes,
+            } => Control::While {
+                port: scope.port(&port)?,
+                cond,
+                body: Box::new(Self::region(body, false, scope)?),
+                attributes,
+            },
+            ControlOp::Repeat {
+                num_repeats,
+                body,
+                is_static,
+                attributes,
+            } => {
+                let body = Box::new(Self::region(body, is_static, scope)?);
+                if is_static {
+                    Control::StaticRepeat {
+                        num_repeats,
+                        body,
+                        attributes,
+                    }
+                } else {
+                    Control::Repeat {
+                        num_repeats,
+                        body,
+                        attributes,
+                    }
+                }
+            }
+            ControlOp::Invoke {
+                comp,
+                ref_cells,
+                inputs,
+                attributes,
+            } => {
+                let inputs = inputs
+                    .into_iter()
+                    .map(|(port, src)| match scope.get(&port)? {
+                        Def::Cell(cell, name) if cell == comp => {
+                            Ok((name, scope.atom(&src)?))
+                        }
+                        _ => Err(Error::malformed_control(format!(
+                            "`%{}` is not a port of `{comp}`",
+                            port.name
+                        ))
+                        .with_pos(&port.span)),
+                    })
+                    .collect::<CalyxResult<_>>()?;
+                Control::Invoke {
+                    comp,
+                    inputs,
+                    outputs: vec![],
+                    attributes,
+                    comb_group: None,
+                    ref_cells,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Workspace;
+
+    /// Parse `body` as the only component of a module.
+    fn parse(body: &str) -> CalyxResult<ast::NamespaceDef> {
+        let lib = Workspace::from_compile_lib().unwrap().lib;
+        let src = format!(
+            "module attributes {{calyx.entrypoint = \"main\"}} {{
+  calyx.component @main(%go: i1 {{go}}, %clk: i1 {{clk}}, %reset: i1 {{reset}}) -> (%done: i1 {{done}}) {{
+{body}
+  }}
+}}"
+        );
+        MlirParser::parse(src.as_bytes(), &lib)
+    }
+
+    fn parse_err(body: &str) -> String {
+        match parse(body) {
+            Ok(_) => panic!("parsing should fail:\n{body}"),
+            Err(err) => err.message(),
+        }
+    }
+
+    const REGISTER: &str = "    %r.in, %r.write_en, %r.clk, %r.reset, %r.out, %r.done = calyx.register @r : i32, i1, i1, i1, i32, i1";
+    const WIRES: &str = "    calyx.wires {
+      %true = hw.constant true
+      calyx.group @write {
+        calyx.assign %r.write_en = %true : i1
+        calyx.group_done %r.done : i1
+      }
+    }
+    calyx.control {
+      calyx.enable @write
+    }";
+
+    #[test]
+    fn parses_component() {
+        let ns = parse(&format!("{REGISTER}\n{WIRES}")).unwrap();
+        assert_eq!(ns.components.len(), 1);
+        assert_eq!(ns.components[0].cells.len(), 1);
+    }
+
+    #[test]
+    fn unknown_op() {
+        let msg = parse_err(&format!(
+            "    %f.in, %f.out = calyx.std_frobnicate @f : i32, i32\n{WIRES}"
+        ));
+        assert!(msg.contains("std_frobnicate"), "{msg}");
+
+        let msg = parse_err(&format!(
+            "{REGISTER}\n    calyx.wires {{\n      calyx.frobnicate @r\n    }}\n    calyx.control {{}}"
+        ));
+        assert!(msg.contains("Failed to parse"), "{msg}");
+    }
+
+    #[test]
+    fn bad_types() {
+        // The width of `out` does not agree with the width of `in`
+        let msg = parse_err(&format!(
+            "    %r.in, %r.write_en, %r.clk, %r.reset, %r.out, %r.done = calyx.register @r : i32, i1, i1, i1, i8, i1\n{WIRES}"
+        ));
+        assert!(msg.contains("requires width 32"), "{msg}");
+
+        // The control port of a register is a single bit
+        let msg = parse_err(&format!(
+            "    %r.in, %r.write_en, %r.clk, %r.reset, %r.out, %r.done = calyx.register @r : i32, i2, i1, i1, i32, i1\n{WIRES}"
+        ));
+        assert!(msg.contains("write_en"), "{msg}");
+
+        // Only integer types are supported
+        let msg = parse_err(&format!(
+            "    %r.in, %r.write_en, %r.clk, %r.reset, %r.out, %r.done = calyx.register @r : f32, i1, i1, i1, f32, i1\n{WIRES}"
+        ));
+        assert!(msg.contains("Failed to parse"), "{msg}");
+
+        // There must be a type for every value
+        let msg = parse_err(&format!(
+            "    %r.in, %r.write_en, %r.clk, %r.reset, %r.out, %r.done = calyx.register @r : i32, i1\n{WIRES}"
+        ));
+        assert!(msg.contains("6 values but has 2 types"), "{msg}");
+    }
+
+    #[test]
+    fn truncated_input() {
+        let src = format!("{REGISTER}\n{WIRES}");
+        for len in [0, REGISTER.len() / 2, REGISTER.len(), src.len() - 1] {
+            let lib = Workspace::from_compile_lib().unwrap().lib;
+            let truncated = format!(
+                "module {{\n  calyx.component @main() -> () {{\n{}",
+                &src[..len]
+            );
+            let msg = match MlirParser::parse(truncated.as_bytes(), &lib) {
+                Ok(_) => panic!("parsing should fail:\n{truncated}"),
+                Err(err) => err.message(),
+            };
+            assert!(msg.contains("Failed to parse"), "{msg}");
+        }
+    }
+
+    #[test]
+    fn undefined_value() {
+        let msg = parse_err(&format!(
+            "{REGISTER}\n    calyx.wires {{\n      calyx.assign %r.in = %missing : i32\n    }}\n    calyx.control {{}}"
+        ));
+        assert!(msg.contains("missing"), "{msg}");
+    }
+}
@@ -2,7 +2,8 @@
 
 //! Parser for Calyx programs.
 use super::ast::{
-    self, BitNum, Control, GuardComp as GC, GuardExpr, NumType, StaticGuardExpr,
+    self, BitNum, ConstExpr, ConstExprKind, ConstOp, Control, GuardComp as GC,
+    GuardExpr, NumType, StaticGuardExpr,
 };
 use super::Attributes;
 use crate::{Attribute, Direction, PortDef, Primitive, Width};
@@ -39,6 +40,13 @@ lazy_static::lazy_static! {
     PrattParser::new()
         .op(Op::infix(Rule::guard_or, Assoc::Left))
         .op(Op::infix(Rule::guard_and, Assoc::Left));
+
+    static ref CONST_PRATT: PrattParser<Rule> =
+    PrattParser::new()
+        .op(Op::infix(Rule::const_add, Assoc::Left)
+            | Op::infix(Rule::const_sub, Assoc::Left))
+        .op(Op::infix(Rule::const_mul, Assoc::Left)
+            | Op::infix(Rule::const_div, Assoc::Left));
 }
 
 #[derive(Parser)]
@@ -55,10 +63,25 @@ impl CalyxParser {
                 path.to_string_lossy(),
             ))
         })?;
-        // Add a new file to the position table
         let string_content = std::str::from_utf8(content)?.to_string();
+        let out = Self::parse_source(path, string_content)?;
+        log::info!(
+            "Parsed `{}` in {}ms",
+            path.to_string_lossy(),
+            time.elapsed().as_millis()
+        );
+        Ok(out)
+    }
+
+    /// Parse `content` as if it were the contents of the file at `path`.
+    /// Used to check programs that have not been written to disk yet.
+    pub fn parse_source(
+        path: &Path,
+        content: String,
+    ) -> CalyxResult<ast::NamespaceDef> {
+        // Add a new file to the position table
         let file = GlobalPositionTable::as_mut()
-            .add_file(path.to_string_lossy().to_string(), string_content);
+            .add_file(path.to_string_lossy().to_string(), content);
         let user_data = UserData { file };
         let content = GlobalPositionTable::as_ref().get_source(file);
         // Parse the file
@@ -86,11 +109,6 @@ impl CalyxParser {
                 err = e
             ))
         })?;
-        log::info!(
-            "Parsed `{}` in {}ms",
-            path.to_string_lossy(),
-            time.elapsed().as_millis()
-        );
         Ok(out)
     }
 
@@ -184,6 +202,64 @@ impl CalyxParser {
             })
             .parse(pairs)
     }
+
+    #[allow(clippy::result_large_err)]
+    fn const_expr_helper(
+        ud: UserData,
+        pairs: pest::iterators::Pairs<Rule>,
+    ) -> ParseResult<ConstExpr> {
+        CONST_PRATT
+            .map_primary(|primary| match primary.as_rule() {
+                Rule::const_atom => Self::const_atom(Node::new_with_user_data(
+                    primary,
+                    ud.clone(),
+                )),
+                x => unreachable!("Unexpected rule {:?} for const_expr", x),
+            })
+            .map_infix(|lhs, op, rhs| {
+                let (lhs, rhs) = (lhs?, rhs?);
+                let op = match op.as_rule() {
+                    Rule::const_add => ConstOp::Add,
+                    Rule::const_sub => ConstOp::Sub,
+                    Rule::const_mul => ConstOp::Mul,
+                    Rule::const_div => ConstOp::Div,
+                    _ => unreachable!(),
+                };
+                // The operation spans from the start of `lhs` to the end of
+                // `rhs`.
+                let (_, start, _) = lhs.span.get_location();
+                let (_, _, end) = rhs.span.get_location();
+                let span = GPosIdx(
+                    GlobalPositionTable::as_mut().add_pos(ud.file, start, end),
+                );
+                Ok(ConstExpr {
+                    kind: ConstExprKind::Op(op, Box::new(lhs), Box::new(rhs)),
+                    span,
+                })
+            })
+            .parse(pairs)
+    }
+
+    /// The widths of the ports of primitives can only be numbers or
+    /// parameters.
+    #[allow(clippy::result_large_err)]
+    fn prim_signature(
+        input: &Node,
+        signature: Vec<PortDef<ConstExpr>>,
+    ) -> ParseResult<Vec<PortDef<Width>>> {
+        signature
+            .into_iter()
+            .map(|pd| {
+                let Some(width) = pd.width.as_width() else {
+                    return Err(input.error(format!(
+                        "Width of primitive port `{}` must be a number or a parameter",
+                        pd.name()
+                    )));
+                };
+                Ok(PortDef::new(pd.name(), width, pd.direction, pd.attributes))
+            })
+            .collect()
+    }
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -191,6 +267,7 @@ enum ExtOrComp {
     Ext((Option<String>, Vec<Primitive>)),
     Comp(ComponentDef),
     PrimInline(Primitive),
+    Const(ast::ConstDef),
 }
 
 #[pest_consume::parser]
@@ -294,58 +371,51 @@ impl CalyxParser {
             .map_err(|_| input.error("Expected binary number"))
     }
 
+    fn num_val(input: Node) -> ParseResult<(NumType, u64)> {
+        Ok(match_nodes!(
+            input.into_children();
+            [decimal(val)] => (NumType::Decimal, val),
+            [hex(val)] => (NumType::Hex, val),
+            [octal(val)] => (NumType::Octal, val),
+            [binary(val)] => (NumType::Binary, val),
+        ))
+    }
+
     fn num_lit(input: Node) -> ParseResult<BitNum> {
         let span = Self::get_span(&input);
-        let num = match_nodes!(
+        let (width, num) = match_nodes!(
             input.clone().into_children();
-            [bitwidth(width), decimal(val)] => BitNum {
-                    width,
-                    num_type: NumType::Decimal,
-                    val,
-                    span
-                },
-            [bitwidth(width), hex(val)] => BitNum {
-                    width,
-                    num_type: NumType::Hex,
-                    val,
-                    span
-                },
-            [bitwidth(width), octal(val)] => BitNum {
-                    width,
-                    num_type: NumType::Octal,
-                    val,
-                    span
-                },
-            [bitwidth(width), binary(val)] => BitNum {
-                    width,
-                    num_type: NumType::Binary,
+            [bitwidth(width), num_val((num_type, val))] => (width, BitNum {
+                    width: Width::Const { value: width },
+                    num_type,
                     val,
                     span
-                },
-
+                }),
         );
 
-        // the below cast is safe since the width must be less than 64 for
-        // the given literal to be unrepresentable
-        if num.width == 0
-            || (num.width < 64 && u64::pow(2, num.width as u32) <= num.val)
-        {
-            let lit_str = match num.num_type {
-                NumType::Binary => format!("{:b}", num.val),
-                NumType::Decimal => format!("{}", num.val),
-                NumType::Octal => format!("{:o}", num.val),
-                NumType::Hex => format!("{:x}", num.val),
-            };
-            let bit_plural = if num.width == 1 { "bit" } else { "bits" };
-            Err(input.error(format!(
-                "Cannot represent given literal '{}' in {} {}",
-                lit_str, num.width, bit_plural
-            )))
-        } else {
-            Ok(num)
+        match num.width_error(width) {
+            Some(msg) => Err(input.error(msg)),
+            None => Ok(num),
         }
     }
 
+    fn const_name(input: Node) -> ParseResult<Id> {
+        Ok(Id::new(input.as_str()))
+    }
+
+    fn param_num_lit(input: Node) -> ParseResult<BitNum> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [const_name(value), num_val((num_type, val))] => BitNum {
+                width: Width::Param { value },
+                num_type,
+                val,
+                span
+            },
+        ))
+    }
+
     fn char(input: Node) -> ParseResult<&str> {
         Ok(input.as_str())
     }
@@ -423,33 +493,85 @@ impl CalyxParser {
         )
     }
 
+    // ================ Constants =====================
+    fn const_call(input: Node) -> ParseResult<ConstExpr> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [const_name(func), const_expr(args)..] => ConstExpr {
+                kind: ConstExprKind::Call(func, args.collect()),
+                span,
+            },
+        ))
+    }
+
+    fn const_atom(input: Node) -> ParseResult<ConstExpr> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [bitwidth(value)] => ConstExpr {
+                kind: ConstExprKind::Num(value),
+                span,
+            },
+            [const_call(call)] => call,
+            [const_name(name)] => ConstExpr {
+                kind: ConstExprKind::Var(name),
+                span,
+            },
+            [const_expr(expr)] => expr,
+        ))
+    }
+
+    fn const_expr(input: Node) -> ParseResult<ConstExpr> {
+        let ud = input.user_data().clone();
+        Self::const_expr_helper(ud, input.into_pair().into_inner())
+    }
+
+    fn const_decl(input: Node) -> ParseResult<ast::ConstDef> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [const_name(name), const_expr(value)] => ast::ConstDef {
+                name,
+                value,
+                span,
+            },
+        ))
+    }
+
+    fn consts(input: Node) -> ParseResult<Vec<ast::ConstDef>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [const_decl(consts)..] => consts.collect()
+        ))
+    }
+
     // ================ Signature =====================
     fn params(input: Node) -> ParseResult<Vec<Id>> {
         Ok(match_nodes!(
             input.into_children();
-            [identifier(id)..] => id.collect()
+            [const_name(id)..] => id.collect()
         ))
     }
 
-    fn args(input: Node) -> ParseResult<Vec<u64>> {
+    fn args(input: Node) -> ParseResult<Vec<ConstExpr>> {
         Ok(match_nodes!(
             input.into_children();
-            [bitwidth(bw)..] => bw.collect(),
+            [const_expr(args)..] => args.collect(),
             [] => vec![]
         ))
     }
 
-    fn io_port(input: Node) -> ParseResult<(Id, Width, Attributes)> {
+    fn io_port(input: Node) -> ParseResult<(Id, ConstExpr, Attributes)> {
+        let span = Self::get_span(&input);
         Ok(match_nodes!(
             input.into_children();
-            [at_attributes(attrs), identifier(id), bitwidth(value)] =>
-                (id, Width::Const { value }, attrs),
-            [at_attributes(attrs), identifier(id), identifier(value)] =>
-                (id, Width::Param { value }, attrs)
+            [at_attributes(attrs), identifier(id), const_expr(width)] =>
+                (id, width, attrs.add_span(span)),
         ))
     }
 
-    fn inputs(input: Node) -> ParseResult<Vec<PortDef<Width>>> {
+    fn inputs(input: Node) -> ParseResult<Vec<PortDef<ConstExpr>>> {
         Ok(match_nodes!(
             input.into_children();
             [io_port((name, width, attributes))] => {
@@ -469,7 +591,7 @@ impl CalyxParser {
         ))
     }
 
-    fn outputs(input: Node) -> ParseResult<Vec<PortDef<Width>>> {
+    fn outputs(input: Node) -> ParseResult<Vec<PortDef<ConstExpr>>> {
         Ok(match_nodes!(
             input.into_children();
             [io_port((name, width, attributes))] => {
@@ -489,7 +611,7 @@ impl CalyxParser {
         ))
     }
 
-    fn signature(input: Node) -> ParseResult<Vec<PortDef<Width>>> {
+    fn signature(input: Node) -> ParseResult<Vec<PortDef<ConstExpr>>> {
         Ok(match_nodes!(
             input.into_children();
             // NOTE(rachit): We expect the signature to be extended to have `go`,
@@ -506,7 +628,7 @@ impl CalyxParser {
     // ==============Primitives=====================
     fn sig_with_params(
         input: Node,
-    ) -> ParseResult<(Vec<Id>, Vec<PortDef<Width>>)> {
+    ) -> ParseResult<(Vec<Id>, Vec<PortDef<ConstExpr>>)> {
         Ok(match_nodes!(
             input.into_children();
             [params(p), signature(s)] => (p, s),
@@ -516,11 +638,11 @@ impl CalyxParser {
     fn primitive(input: Node) -> ParseResult<Primitive> {
         let span = Self::get_span(&input);
         Ok(match_nodes!(
-            input.into_children();
+            input.clone().into_children();
             [name_with_attribute((name, attrs)), sig_with_params((p, s))] => Primitive {
                 name,
                 params: p,
-                signature: s,
+                signature: Self::prim_signature(&input, s)?,
                 attributes: attrs.add_span(span),
                 is_comb: false,
                 latency: None,
@@ -529,7 +651,7 @@ impl CalyxParser {
             [comb_or_static(cs_res), name_with_attribute((name, attrs)), sig_with_params((p, s))] => Primitive {
                 name,
                 params: p,
-                signature: s,
+                signature: Self::prim_signature(&input, s)?,
                 attributes: attrs.add_span(span),
                 is_comb: cs_res.is_none(),
                 latency: cs_res,
@@ -597,6 +719,7 @@ impl CalyxParser {
             input.into_children();
             [LHS(port)] => Ok(ast::Atom::Port(port)),
             [num_lit(num)] => Ok(ast::Atom::Num(num)),
+            [param_num_lit(num)] => Ok(ast::Atom::Num(num)),
             [bad_num(_)] => unreachable!("bad_num returned non-error result"),
         )
     }
@@ -800,7 +923,8 @@ impl CalyxParser {
         Ok(match_nodes!(
             input.into_children();
             [identifier(name), port(p)] => (name, ast::Atom::Port(p)),
-            [identifier(name), num_lit(bn)] => (name, ast::Atom::Num(bn))
+            [identifier(name), num_lit(bn)] => (name, ast::Atom::Num(bn)),
+            [identifier(name), param_num_lit(bn)] => (name, ast::Atom::Num(bn)),
 
         ))
     }
@@ -1096,7 +1220,8 @@ impl CalyxParser {
             [
                 comb_or_static(cs_res),
                 name_with_attribute((name, attributes)),
-                signature(sig),
+                sig_with_params((params, signature)),
+                consts(consts),
                 cells(cells),
                 connections(connections)
             ] => {
@@ -1104,21 +1229,11 @@ impl CalyxParser {
                     Err(input.error("Static Component must have defined control"))?;
                 }
                 let (continuous_assignments, groups, static_groups) = connections;
-                let sig = sig.into_iter().map(|pd| {
-                    if let Width::Const { value } = pd.width {
-                        Ok(PortDef::new(
-                            pd.name(),
-                            value,
-                            pd.direction,
-                            pd.attributes
-                        ))
-                    } else {
-                        Err(input.error("Components cannot use parameters"))
-                    }
-                }).collect::<Result<_, _>>()?;
                 Ok(ComponentDef {
                     name,
-                    signature: sig,
+                    params,
+                    consts,
+                    signature,
                     cells,
                     groups,
                     static_groups,
@@ -1131,27 +1246,18 @@ impl CalyxParser {
             },
             [
                 name_with_attribute((name, attributes)),
-                signature(sig),
+                sig_with_params((params, signature)),
+                consts(consts),
                 cells(cells),
                 connections(connections),
                 control(control)
             ] => {
                 let (continuous_assignments, groups, static_groups) = connections;
-                let sig = sig.into_iter().map(|pd| {
-                    if let Width::Const { value } = pd.width {
-                        Ok(PortDef::new(
-                            pd.name(),
-                            value,
-                            pd.direction,
-                            pd.attributes
-                        ))
-                    } else {
-                        Err(input.error("Components cannot use parameters"))
-                    }
-                }).collect::<Result<_, _>>()?;
                 Ok(ComponentDef {
                     name,
-                    signature: sig,
+                    params,
+                    consts,
+                    signature,
                     cells,
                     groups,
                     static_groups,
@@ -1165,27 +1271,18 @@ impl CalyxParser {
             [
                 comb_or_static(cs_res),
                 name_with_attribute((name, attributes)),
-                signature(sig),
+                sig_with_params((params, signature)),
+                consts(consts),
                 cells(cells),
                 connections(connections),
                 control(control),
             ] => {
                 let (continuous_assignments, groups, static_groups) = connections;
-                let sig = sig.into_iter().map(|pd| {
-                    if let Width::Const { value } = pd.width {
-                        Ok(PortDef::new(
-                            pd.name(),
-                            value,
-                            pd.direction,
-                            pd.attributes
-                        ))
-                    } else {
-                        Err(input.error("Components cannot use parameters"))
-                    }
-                }).collect::<Result<_, _>>()?;
                 Ok(ComponentDef {
                     name,
-                    signature: sig,
+                    params,
+                    consts,
+                    signature,
                     cells,
                     groups,
                     static_groups,
@@ -1216,12 +1313,12 @@ impl CalyxParser {
     fn prim_inline(input: Node) -> ParseResult<Primitive> {
         let span = Self::get_span(&input);
         Ok(match_nodes!(
-            input.into_children();
+            input.clone().into_children();
             [name_with_attribute((name, attrs)), sig_with_params((p, s)), block_string(b)] => {
             Primitive {
                 name,
                 params: p,
-                signature: s,
+                signature: Self::prim_signature(&input, s)?,
                 attributes: attrs.add_span(span),
                 is_comb: false,
                 latency: None,
@@ -1230,7 +1327,7 @@ impl CalyxParser {
             [comb_or_static(cs_res), name_with_attribute((name, attrs)), sig_with_params((p, s)), block_string(b)] => Primitive {
                 name,
                 params: p,
-                signature: s,
+                signature: Self::prim_signature(&input, s)?,
                 attributes: attrs.add_span(span),
                 is_comb: cs_res.is_none(),
                 latency: cs_res,
@@ -1245,6 +1342,7 @@ impl CalyxParser {
             [component(comp)] => ExtOrComp::Comp(comp),
             [ext(ext)] => ExtOrComp::Ext(ext),
             [prim_inline(prim_inline)] => ExtOrComp::PrimInline(prim_inline),
+            [const_decl(def)] => ExtOrComp::Const(def),
         ))
     }
 
@@ -1283,12 +1381,14 @@ impl CalyxParser {
                         imports,
                         components: Vec::new(),
                         externs: Vec::new(),
+                        consts: Vec::new(),
                         metadata: if m != *"" { Some(m) } else { None }
                     };
                 for m in mixed {
                     match m {
                         ExtOrComp::Ext(ext) => namespace.externs.push(ext),
                         ExtOrComp::Comp(comp) => namespace.components.push(comp),
+                        ExtOrComp::Const(def) => namespace.consts.push(def),
                         ExtOrComp::PrimInline(prim) => {
                             if let Some((_, prim_inlines)) = namespace.externs.iter_mut().find(|(filename, _)| filename.is_none()) {
                                 prim_inlines.push(prim)
@@ -1307,12 +1407,14 @@ impl CalyxParser {
                         imports,
                         components: Vec::new(),
                         externs: Vec::new(),
+                        consts: Vec::new(),
                         metadata: None
                     };
                 for m in mixed {
                     match m {
                         ExtOrComp::Ext(ext) => namespace.externs.push(ext),
                         ExtOrComp::Comp(comp) => namespace.components.push(comp),
+                        ExtOrComp::Const(def) => namespace.consts.push(def),
                         ExtOrComp::PrimInline(prim) => {
                             if let Some((_, prim_inlines)) = namespace.externs.iter_mut().find(|(filename, _)| filename.is_none()) {
                                 prim_inlines.push(prim)
@@ -1,6 +1,6 @@
 use super::{
-    ast::{ComponentDef, NamespaceDef},
-    parser,
+    ast::{ComponentDef, ConstDef, NamespaceDef},
+    mlir_parser, parser,
 };
 use crate::LibrarySignatures;
 use calyx_utils::{CalyxResult, Error};
@@ -46,6 +46,8 @@ pub struct Workspace {
     pub declarations: Vec<ComponentDef>,
     /// Absolute path to extern definitions and primitives defined by them.
     pub lib: LibrarySignatures,
+    /// Named constants defined at the top level of any file.
+    pub consts: Vec<ConstDef>,
     /// Original import statements present in the top-level file.
     pub original_imports: Vec<String>,
     /// Optional opaque metadata attached to the top-level file
@@ -146,6 +148,58 @@ impl Workspace {
         )
     }
 
+    /// Construct a new workspace from `source`, the unsaved contents of the
+    /// file at `file`. Imports are resolved relative to `file`.
+    /// If SHALLOW is true, imported components are only added as declarations.
+    pub fn construct_from_source<const SHALLOW: bool>(
+        file: &Path,
+        source: String,
+        lib_path: &Path,
+    ) -> CalyxResult<Self> {
+        let ns = parser::CalyxParser::parse_source(file, source)?;
+        Self::construct_from_namespace::<SHALLOW>(
+            ns,
+            Self::get_parent(file),
+            vec![],
+            lib_path,
+        )
+    }
+
+    /// Construct a new workspace from a program in the CIRCT Calyx dialect.
+    /// The libraries defining the primitives used by the dialect are
+    /// imported first so that the parameters of cells can be inferred.
+    pub fn construct_from_mlir(
+        file: &Option<PathBuf>,
+        lib_path: &Path,
+    ) -> CalyxResult<Self> {
+        let imports = NamespaceDef {
+            imports: mlir_parser::MLIR_IMPORTS
+                .iter()
+                .map(|imp| imp.to_string())
+                .collect(),
+            components: vec![],
+            externs: vec![],
+            consts: vec![],
+            metadata: None,
+        };
+        let parent_path = file
+            .as_ref()
+            .map(|p| Self::get_parent(p))
+            .unwrap_or_else(|| PathBuf::from("."));
+        let mut ws = Self::construct_from_namespace::<false>(
+            imports,
+            parent_path,
+            vec![],
+            lib_path,
+        )?;
+        let ns = match file {
+            Some(path) => mlir_parser::MlirParser::parse_file(path, &ws.lib)?,
+            None => mlir_parser::MlirParser::parse(std::io::stdin(), &ws.lib)?,
+        };
+        ws.components.extend(ns.components);
+        Ok(ws)
+    }
+
     /// Construct the Workspace using the given [NamespaceDef] and ignore all
     /// imported dependencies.
     pub fn construct_shallow(
@@ -213,6 +267,8 @@ impl Workspace {
             }
         }
 
+        self.consts.extend(ns.consts);
+
         // Add components defined by this namespace to either components or
         // declarations
         if !is_source && shallow {
@@ -247,7 +303,22 @@ impl Workspace {
             .as_ref()
             .map(|p| Self::get_parent(p))
             .unwrap_or_else(|| PathBuf::from("."));
+        Self::construct_from_namespace::<SHALLOW>(
+            ns,
+            parent_path,
+            files,
+            lib_path,
+        )
+    }
 
+    /// Construct the Workspace from the already parsed namespace `ns`, which
+    /// lives in the directory `parent_path`, and the source `files`.
+    fn construct_from_namespace<const SHALLOW: bool>(
+        ns: NamespaceDef,
+        parent_path: PathBuf,
+        files: Vec<PathBuf>,
+        lib_path: &Path,
+    ) -> CalyxResult<Self> {
         // Set of current dependencies and whether they are considered source files.
         let mut dependencies: Vec<(PathBuf, bool)> =
             files.into_iter().map(|p| (p, true)).collect();
@@ -1,8 +1,9 @@
 //! An IR context. This is the top-level object for an IR and contains all information
 //! need to transform, lower, an emit a program.
 //! Passes usually have transform/analyze the components in the IR.
-use super::{Component, Id};
+use super::{Component, Id, ProfilingInfo};
 use calyx_frontend::LibrarySignatures;
+use calyx_utils::Error;
 
 /// Configuration information for the backends.
 #[derive(Default)]
@@ -16,6 +17,8 @@ pub struct BackendConf {
     /// [FIRRTL backend only] Emit extmodule declarations for primtives
     /// for use with SystemVerilog implementations
     pub emit_primitive_extmodules: bool,
+    /// [FIRRTL backend only] Emit FIRRTL implementations of primitives
+    pub emit_firrtl_primitives: bool,
 }
 
 /// The IR Context that represents an entire Calyx program with all of its
@@ -34,6 +37,10 @@ pub struct Context {
     pub extra_opts: Vec<String>,
     /// An optional opaque metadata string which is used by Cider
     pub metadata: Option<String>,
+    /// Warnings reported by the passes that have run so far
+    pub warnings: Vec<Error>,
+    /// Profiling information recorded by the passes that compile control
+    pub profiling_info: Vec<ProfilingInfo>,
 }
 
 impl Context {
@@ -0,0 +1,847 @@
+//! Deserialization of a [Context] from the format produced by its
+//! [serde::Serialize] implementation.
+//!
+//! The serialized IR inlines every port, cell and group it refers to, so it
+//! is first read into mirror structures that only use names. The mirrors are
+//! then turned back into the AST and built with [ast_to_ir], which restores
+//! all the links between the IR nodes.
+use crate::{
+    from_ast::ast_to_ir, serializers::FORMAT_VERSION, Attributes, BoolAttr,
+    Context, Direction, Id, LibrarySignatures, Nothing, PortComp, PortDef,
+    StaticTiming,
+};
+use calyx_frontend::{ast, Workspace};
+use calyx_utils::{CalyxResult, Error, GPosIdx};
+use serde::{de, Deserialize, Deserializer};
+use std::collections::{HashMap, HashSet};
+use std::num::NonZeroU64;
+
+#[derive(Deserialize)]
+struct DeContext {
+    version: u64,
+    components: Vec<DeComponent>,
+    entrypoint: Id,
+    lib: LibrarySignatures,
+    metadata: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct DeComponent {
+    name: Id,
+    signature: DeCell,
+    cells: Vec<DeCell>,
+    groups: Vec<DeGroup>,
+    static_groups: Vec<DeStaticGroup>,
+    comb_groups: Vec<DeCombGroup>,
+    continuous_assignments: Vec<DeAssignment<Nothing>>,
+    control: DeControl,
+    attributes: Attributes,
+    is_comb: bool,
+    latency: Option<NonZeroU64>,
+}
+
+#[derive(Deserialize)]
+struct DePort {
+    name: Id,
+    width: u64,
+    direction: Direction,
+    /// Name of the cell or group that defines this port
+    parent: Id,
+    attributes: Attributes,
+}
+
+/// Primitive cells are rebuilt from the library, so only the name and the
+/// parameters of their prototype are used.
+#[derive(Deserialize)]
+#[allow(dead_code)]
+enum DeCellType {
+    Primitive {
+        name: Id,
+        param_binding: Vec<(Id, u64)>,
+        is_comb: bool,
+        latency: Option<NonZeroU64>,
+    },
+    Component {
+        name: Id,
+    },
+    ThisComponent,
+    Constant {
+        val: u64,
+        width: u64,
+    },
+}
+
+#[derive(Deserialize)]
+struct DeCell {
+    name: Id,
+    ports: Vec<DePort>,
+    prototype: DeCellType,
+    attributes: Attributes,
+    reference: bool,
+}
+
+#[derive(Deserialize)]
+enum DeGuard<T> {
+    Or(Box<DeGuard<T>>, Box<DeGuard<T>>),
+    And(Box<DeGuard<T>>, Box<DeGuard<T>>),
+    Not(Box<DeGuard<T>>),
+    True,
+    CompOp(PortComp, DePort, DePort),
+    Port(DePort),
+    Info(T),
+}
+
+#[derive(Deserialize)]
+struct DeAssignment<T> {
+    dst: DePort,
+    src: DePort,
+    guard: Box<DeGuard<T>>,
+    attributes: Attributes,
+}
+
+#[derive(Deserialize)]
+struct DeGroup {
+    name: Id,
+    assignments: Vec<DeAssignment<Nothing>>,
+    #[allow(dead_code)]
+    holes: Vec<DePort>,
+    attributes: Attributes,
+}
+
+#[derive(Deserialize)]
+struct DeStaticGroup {
+    name: Id,
+    assignments: Vec<DeAssignment<StaticTiming>>,
+    #[allow(dead_code)]
+    holes: Vec<DePort>,
+    attributes: Attributes,
+    latency: u64,
+}
+
+#[derive(Deserialize)]
+struct DeCombGroup {
+    name: Id,
+    assignments: Vec<DeAssignment<Nothing>>,
+    attributes: Attributes,
+}
+
+#[derive(Deserialize)]
+struct DeStmts<C> {
+    stmts: Vec<C>,
+    attributes: Attributes,
+}
+
+#[derive(Deserialize)]
+struct DeStaticStmts {
+    stmts: Vec<DeStaticControl>,
+    attributes: Attributes,
+    latency: u64,
+}
+
+#[derive(Deserialize)]
+struct DeIf {
+    port: DePort,
+    cond: Option<DeCombGroup>,
+    tbranch: Box<DeControl>,
+    fbranch: Box<DeControl>,
+    attributes: Attributes,
+}
+
+#[derive(Deserialize)]
+struct DeStaticIf {
+    port: DePort,
+    latency: u64,
+    tbranch: Box<DeStaticControl>,
+    fbranch: Box<DeStaticControl>,
+    attributes: Attributes,
+}
+
+#[derive(Deserialize)]
+struct DeWhile {
+    port: DePort,
+    cond: Option<DeCombGroup>,
+    body: Box<DeControl>,
+    attributes: Attributes,
+}
+
+#[derive(Deserialize)]
+struct DeRepeat {
+    attributes: Attributes,
+    body: Box<DeControl>,
+    num_repeats: u64,
+}
+
+#[derive(Deserialize)]
+struct DeStaticRepeat {
+    attributes: Attributes,
+    body: Box<DeStaticControl>,
+    num_repeats: u64,
+    #[allow(dead_code)]
+    latency: u64,
+}
+
+#[derive(Deserialize)]
+struct DeEnable<G> {
+    group: G,
+    attributes: Attributes,
+}
+
+#[derive(Deserialize)]
+struct DeInvoke {
+    comp: DeCell,
+    inputs: Vec<(Id, DePort)>,
+    outputs: Vec<(Id, DePort)>,
+    attributes: Attributes,
+    comb_group: Option<DeCombGroup>,
+    ref_cells: Vec<(Id, DeCell)>,
+}
+
+#[derive(Deserialize)]
+struct DeStaticInvoke {
+    comp: DeCell,
+    latency: u64,
+    inputs: Vec<(Id, DePort)>,
+    outputs: Vec<(Id, DePort)>,
+    attributes: Attributes,
+    ref_cells: Vec<(Id, DeCell)>,
+    comb_group: Option<DeCombGroup>,
+}
+
+#[derive(Deserialize)]
+struct DeEmpty {
+    attributes: Attributes,
+}
+
+#[derive(Deserialize)]
+enum DeControl {
+    Seq(DeStmts<DeControl>),
+    Par(DeStmts<DeControl>),
+    If(DeIf),
+    While(DeWhile),
+    Repeat(DeRepeat),
+    Invoke(DeInvoke),
+    Enable(DeEnable<DeGroup>),
+    Empty(DeEmpty),
+    Static(DeStaticControl),
+}
+
+#[derive(Deserialize)]
+enum DeStaticControl {
+    Repeat(DeStaticRepeat),
+    Enable(DeEnable<DeStaticGroup>),
+    Par(DeStaticStmts),
+    Seq(DeStaticStmts),
+    If(DeStaticIf),
+    Empty(DeEmpty),
+    Invoke(DeStaticInvoke),
+}
+
+impl<'de> Deserialize<'de> for Context {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ctx = DeContext::deserialize(de)?;
+        ctx.into_context()
+            .map_err(|err| de::Error::custom(err.message()))
+    }
+}
+
+impl DeContext {
+    fn into_context(self) -> CalyxResult<Context> {
+        if self.version != FORMAT_VERSION {
+            return Err(Error::misc(format!(
+                "Serialized IR has format version {} but this compiler reads version {FORMAT_VERSION}",
+                self.version
+            )));
+        }
+        let entrypoint = self.entrypoint;
+        let mut components = self
+            .components
+            .into_iter()
+            .map(DeComponent::into_ast)
+            .collect::<CalyxResult<Vec<_>>>()?;
+
+        // The entrypoint is found using the attributes of the components or
+        // its name.
+        if entrypoint != "main"
+            && !components
+                .iter()
+                .any(|c| c.attributes.has(BoolAttr::TopLevel))
+        {
+            let Some(top) =
+                components.iter_mut().find(|c| c.name == entrypoint)
+            else {
+                return Err(Error::misc(format!(
+                    "Entrypoint `{entrypoint}` is not defined"
+                )));
+            };
+            top.attributes.insert(BoolAttr::TopLevel, 1);
+        }
+
+        let ws = Workspace {
+            components,
+            lib: self.lib,
+            metadata: self.metadata,
+            ..Default::default()
+        };
+        let mut ctx = ast_to_ir(ws)?;
+        ctx.entrypoint = entrypoint;
+        Ok(ctx)
+    }
+}
+
+/// Names defined in a component, used to find out what the parent of each
+/// serialized port is.
+struct Names {
+    /// Name of the signature cell
+    this: Id,
+    /// Constant cells with their value and width
+    constants: HashMap<Id, (u64, u64)>,
+    /// Names of all the groups, static groups and combinational groups
+    groups: HashSet<Id>,
+}
+
+impl Names {
+    fn port(&self, port: DePort) -> CalyxResult<ast::Port> {
+        if port.parent == self.this {
+            Ok(ast::Port::This { port: port.name })
+        } else if self.groups.contains(&port.parent) {
+            Ok(ast::Port::Hole {
+                group: port.parent,
+                name: port.name,
+            })
+        } else if self.constants.contains_key(&port.parent) {
+            Err(Error::malformed_structure(format!(
+                "Constant `{}` cannot be the destination of an assignment",
+                port.parent
+            )))
+        } else {
+            Ok(ast::Port::Comp {
+                component: port.parent,
+                port: port.name,
+            })
+        }
+    }
+
+    fn atom(&self, port: DePort) -> CalyxResult<ast::Atom> {
+        match self.constants.get(&port.parent) {
+            Some(&(val, width)) => Ok(num(val, width)),
+            None => self.port(port).map(ast::Atom::Port),
+        }
+    }
+
+    fn comp_guard(
+        &self,
+        op: PortComp,
+        left: DePort,
+        right: DePort,
+    ) -> CalyxResult<ast::CompGuard> {
+        let op = match op {
+            PortComp::Eq => ast::GuardComp::Eq,
+            PortComp::Neq => ast::GuardComp::Neq,
+            PortComp::Gt => ast::GuardComp::Gt,
+            PortComp::Lt => ast::GuardComp::Lt,
+            PortComp::Geq => ast::GuardComp::Geq,
+            PortComp::Leq => ast::GuardComp::Leq,
+        };
+        Ok((op, self.atom(left)?, self.atom(right)?))
+    }
+
+    fn guard(&self, guard: DeGuard<Nothing>) -> CalyxResult<ast::GuardExpr> {
+        Ok(match guard {
+            DeGuard::Or(l, r) => ast::GuardExpr::Or(
+                Box::new(self.guard(*l)?),
+                Box::new(self.guard(*r)?),
+            ),
+            DeGuard::And(l, r) => ast::GuardExpr::And(
+                Box::new(self.guard(*l)?),
+                Box::new(self.guard(*r)?),
+            ),
+            DeGuard::Not(g) => ast::GuardExpr::Not(Box::new(self.guard(*g)?)),
+            DeGuard::True => ast::GuardExpr::Atom(num(1, 1)),
+            DeGuard::CompOp(op, l, r) => {
+                ast::GuardExpr::CompOp(self.comp_guard(op, l, r)?)
+            }
+            DeGuard::Port(p) => ast::GuardExpr::Atom(self.atom(p)?),
+            DeGuard::Info(Nothing) => ast::GuardExpr::Atom(num(1, 1)),
+        })
+    }
+
+    fn static_guard(
+        &self,
+        guard: DeGuard<StaticTiming>,
+    ) -> CalyxResult<ast::StaticGuardExpr> {
+        Ok(match guard {
+            DeGuard::Or(l, r) => ast::StaticGuardExpr::Or(
+                Box::new(self.static_guard(*l)?),
+                Box::new(self.static_guard(*r)?),
+            ),
+            DeGuard::And(l, r) => ast::StaticGuardExpr::And(
+                Box::new(self.static_guard(*l)?),
+                Box::new(self.static_guard(*r)?),
+            ),
+            DeGuard::Not(g) => {
+                ast::StaticGuardExpr::Not(Box::new(self.static_guard(*g)?))
+            }
+            DeGuard::True => ast::StaticGuardExpr::Atom(num(1, 1)),
+            DeGuard::CompOp(op, l, r) => {
+                ast::StaticGuardExpr::CompOp(self.comp_guard(op, l, r)?)
+            }
+            DeGuard::Port(p) => ast::StaticGuardExpr::Atom(self.atom(p)?),
+            DeGuard::Info(timing) => {
+                ast::StaticGuardExpr::StaticInfo(timing.get_interval())
+            }
+        })
+    }
+
+    fn wire(&self, assign: DeAssignment<Nothing>) -> CalyxResult<ast::Wire> {
+        let guard = match *assign.guard {
+            DeGuard::True => None,
+            g => Some(self.guard(g)?),
+        };
+        Ok(ast::Wire {
+            src: ast::Guard {
+                guard,
+                expr: self.atom(assign.src)?,
+            },
+            dest: self.port(assign.dst)?,
+            attributes: assign.attributes,
+        })
+    }
+
+    fn static_wire(
+        &self,
+        assign: DeAssignment<StaticTiming>,
+    ) -> CalyxResult<ast::StaticWire> {
+        let guard = match *assign.guard {
+            DeGuard::True => None,
+            g => Some(self.static_guard(g)?),
+        };
+        Ok(ast::StaticWire {
+            src: ast::StaticGuard {
+                guard,
+                expr: self.atom(assign.src)?,
+            },
+            dest: self.port(assign.dst)?,
+            attributes: assign.attributes,
+        })
+    }
+
+    fn bindings(
+        &self,
+        ports: Vec<(Id, DePort)>,
+    ) -> CalyxResult<Vec<(Id, ast::Atom)>> {
+        ports
+            .into_iter()
+            .map(|(name, port)| Ok((name, self.atom(port)?)))
+            .collect()
+    }
+
+    fn control(&self, con: DeControl) -> CalyxResult<ast::Control> {
+        Ok(match con {
+            DeControl::Seq(DeStmts { stmts, attributes }) => {
+                ast::Control::Seq {
+                    stmts: self.controls(stmts)?,
+                    attributes,
+                }
+            }
+            DeControl::Par(DeStmts { stmts, attributes }) => {
+                ast::Control::Par {
+                    stmts: self.controls(stmts)?,
+                    attributes,
+                }
+            }
+            DeControl::If(DeIf {
+                port,
+                cond,
+                tbranch,
+                fbranch,
+                attributes,
+            }) => ast::Control::If {
+                port: self.port(port)?,
+                cond: cond.map(|cg| cg.name),
+                tbranch: Box::new(self.control(*tbranch)?),
+                fbranch: Box::new(self.control(*fbranch)?),
+                attributes,
+            },
+            DeControl::While(DeWhile {
+                port,
+                cond,
+                body,
+                attributes,
+            }) => ast::Control::While {
+                port: self.port(port)?,
+                cond: cond.map(|cg| cg.name),
+                body: Box::new(self.control(*body)?),
+                attributes,
+            },
+            DeControl::Repeat(DeRepeat {
+                attributes,
+                body,
+                num_repeats,
+            }) => ast::Control::Repeat {
+                num_repeats,
+                body: Box::new(self.control(*body)?),
+                attributes,
+            },
+            DeControl::Invoke(DeInvoke {
+                comp,
+                inputs,
+                outputs,
+                attributes,
+                comb_group,
+                ref_cells,
+            }) => ast::Control::Invoke {
+                comp: comp.name,
+                inputs: self.bindings(inputs)?,
+                outputs: self.bindings(outputs)?,
+                attributes,
+                comb_group: comb_group.map(|cg| cg.name),
+                ref_cells: ref_cells
+                    .into_iter()
+                    .map(|(name, cell)| (name, cell.name))
+                    .collect(),
+            },
+            DeControl::Enable(DeEnable { group, attributes }) => {
+                ast::Control::Enable {
+                    comp: group.name,
+                    attributes,
+                }
+            }
+            DeControl::Empty(DeEmpty { attributes }) => {
+                ast::Control::Empty { attributes }
+            }
+            DeControl::Static(sc) => self.static_control(sc)?,
+        })
+    }
+
+    fn controls(
+        &self,
+        stmts: Vec<DeControl>,
+    ) -> CalyxResult<Vec<ast::Control>> {
+        stmts.into_iter().map(|c| self.control(c)).collect()
+    }
+
+    fn static_control(
+        &self,
+        con: DeStaticControl,
+    ) -> CalyxResult<ast::Control> {
+        Ok(match con {
+            DeStaticControl::Repeat(DeStaticRepeat {
+                attributes,
+                body,
+                num_repeats,
+                ..
+            }) => ast::Control::StaticRepeat {
+                num_repeats,
+                body: Box::new(self.static_control(*body)?),
+                attributes,
+            },
+            DeStaticControl::Enable(DeEnable { group, attributes }) => {
+                ast::Control::Enable {
+                    comp: group.name,
+                    attributes,
+                }
+            }
+            DeStaticControl::Par(DeStaticStmts {
+                stmts,
+                attributes,
+                latency,
+            }) => ast::Control::StaticPar {
+                stmts: self.static_controls(stmts)?,
+                attributes,
+                latency: NonZeroU64::new(latency),
+            },
+            DeStaticControl::Seq(DeStaticStmts {
+                stmts,
+                attributes,
+                latency,
+            }) => ast::Control::StaticSeq {
+                stmts: self.static_controls(stmts)?,
+                attributes,
+                latency: NonZeroU64::new(latency),
+            },
+            DeStaticControl::If(DeStaticIf {
+                port,
+                latency,
+                tbranch,
+                fbranch,
+                attributes,
+            }) => ast::Control::StaticIf {
+                port: self.port(port)?,
+                tbranch: Box::new(self.static_control(*tbranch)?),
+                fbranch: Box::new(self.static_control(*fbranch)?),
+                attributes,
+                latency: NonZeroU64::new(latency),
+            },
+            DeStaticControl::Empty(DeEmpty { attributes }) => {
+                ast::Control::Empty { attributes }
+            }
+            DeStaticControl::Invoke(DeStaticInvoke {
+                comp,
+                latency,
+                inputs,
+                outputs,
+                attributes,
+                ref_cells,
+                comb_group,
+            }) => ast::Control::StaticInvoke {
+                comp: comp.name,
+                inputs: self.bindings(inputs)?,
+                outputs: self.bindings(outputs)?,
+                attributes,
+                ref_cells: ref_cells
+                    .into_iter()
+                    .map(|(name, cell)| (name, cell.name))
+                    .collect(),
+                comb_group: comb_group.map(|cg| cg.name),
+                latency: NonZeroU64::new(latency),
+            },
+        })
+    }
+
+    fn static_controls(
+        &self,
+        stmts: Vec<DeStaticControl>,
+    ) -> CalyxResult<Vec<ast::Control>> {
+        stmts.into_iter().map(|c| self.static_control(c)).collect()
+    }
+}
+
+/// A constant written in the AST
+fn num(val: u64, width: u64) -> ast::Atom {
+    ast::Atom::Num(ast::BitNum {
+        width: width.into(),
+        num_type: ast::NumType::Decimal,
+        val,
+        span: GPosIdx::UNKNOWN,
+    })
+}
+
+impl DeComponent {
+    fn into_ast(self) -> CalyxResult<ast::ComponentDef> {
+        let constants = self
+            .cells
+            .iter()
+            .filter_map(|cell| match cell.prototype {
+                DeCellType::Constant { val, width } => {
+                    Some((cell.name, (val, width)))
+                }
+                _ => None,
+            })
+            .collect();
+        let groups = self
+            .groups
+            .iter()
+            .map(|g| g.name)
+            .chain(self.static_groups.iter().map(|g| g.name))
+            .chain(self.comb_groups.iter().map(|g| g.name))
+            .collect();
+        let names = Names {
+            this: self.signature.name,
+            constants,
+            groups,
+        };
+
+        // The ports of the signature cell face the inside of the component,
+        // so they are in the opposite direction of the component's ports.
+        let signature = self
+            .signature
+            .ports
+            .into_iter()
+            .map(|p| {
+                PortDef::new(
+                    p.name,
+                    p.width.into(),
+                    p.direction.reverse(),
+                    p.attributes,
+                )
+            })
+            .collect();
+
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for cell in self.cells {
+            let (proto, params) = match cell.prototype {
+                DeCellType::Primitive {
+                    name,
+                    param_binding,
+                    ..
+                } => {
+                    let params = param_binding
+                        .into_iter()
+                        .map(|(_, v)| v.into())
+                        .collect();
+                    (name, params)
+                }
+                DeCellType::Component { name } => (name, vec![]),
+                DeCellType::Constant { .. } => continue,
+                DeCellType::ThisComponent => {
+                    return Err(Error::malformed_structure(format!(
+                        "Cell `{}` cannot be the signature of the component",
+                        cell.name
+                    )))
+                }
+            };
+            cells.push(ast::Cell::from(
+                cell.name,
+                proto,
+                params,
+                cell.attributes,
+                cell.reference,
+            ));
+        }
+
+        let mut groups =
+            Vec::with_capacity(self.groups.len() + self.comb_groups.len());
+        for group in self.groups {
+            groups.push(ast::Group {
+                name: group.name,
+                wires: group
+                    .assignments
+                    .into_iter()
+                    .map(|a| names.wire(a))
+                    .collect::<CalyxResult<_>>()?,
+                attributes: group.attributes,
+                is_comb: false,
+            });
+        }
+        for group in self.comb_groups {
+            groups.push(ast::Group {
+                name: group.name,
+                wires: group
+                    .assignments
+                    .into_iter()
+                    .map(|a| names.wire(a))
+                    .collect::<CalyxResult<_>>()?,
+                attributes: group.attributes,
+                is_comb: true,
+            });
+        }
+
+        let mut static_groups = Vec::with_capacity(self.static_groups.len());
+        for group in self.static_groups {
+            let Some(latency) = NonZeroU64::new(group.latency) else {
+                return Err(Error::malformed_structure(format!(
+                    "Static group `{}` has a latency of zero",
+                    group.name
+                )));
+            };
+            static_groups.push(ast::StaticGroup {
+                name: group.name,
+                wires: group
+                    .assignments
+                    .into_iter()
+                    .map(|a| names.static_wire(a))
+                    .collect::<CalyxResult<_>>()?,
+                attributes: group.attributes,
+                latency,
+            });
+        }
+
+        let continuous_assignments = self
+            .continuous_assignments
+            .into_iter()
+            .map(|a| names.wire(a))
+            .collect::<CalyxResult<_>>()?;
+
+        Ok(ast::ComponentDef {
+            name: self.name,
+            params: vec![],
+            consts: vec![],
+            signature,
+            cells,
+            groups,
+            static_groups,
+            continuous_assignments,
+            control: names.control(self.control)?,
+            attributes: self.attributes,
+            is_comb: self.is_comb,
+            latency: self.latency,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Printer;
+    use calyx_frontend::NamespaceDef;
+
+    const PROGRAM: &str = r#"
+component incr<"promotable"=1>(@data in: 8) -> (@data out: 8) {
+  cells {
+    add = std_add(8);
+  }
+  wires {
+    add.left = in;
+    add.right = 8'd1;
+    out = add.out;
+  }
+  control {}
+}
+component main<"toplevel"=1>(@go go: 1, @clk clk: 1, @reset reset: 1) -> (@done done: 1) {
+  cells {
+    r = std_reg(8);
+    inc = incr();
+    @external cond = std_wire(1);
+  }
+  wires {
+    group write<"promotable"=1> {
+      r.in = inc.out;
+      r.write_en = !cond.out | r.out == 8'd3 ? 1'd1;
+      write[done] = r.done;
+    }
+    static<1> group bump {
+      r.in = %0 ? inc.out;
+      r.write_en = %0 & cond.out ? 1'd1;
+    }
+    comb group check {
+      cond.in = r.done;
+    }
+  }
+  control {
+    seq {
+      @bound(4) while cond.out with check {
+        seq { write; invoke inc(in = r.out)(); }
+      }
+      static repeat 2 { bump; }
+      if cond.out with check { write; } else { }
+    }
+  }
+}
+"#;
+
+    fn context() -> Context {
+        let mut ws = Workspace::from_compile_lib().unwrap();
+        let ns = NamespaceDef::construct_from_str(PROGRAM).unwrap();
+        ws.components.extend(ns.components);
+        ast_to_ir(ws).unwrap()
+    }
+
+    fn print(ctx: &Context) -> String {
+        let mut out = Vec::new();
+        Printer::write_context(ctx, false, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let ctx = context();
+        let json = serde_json::to_string(&ctx).unwrap();
+        let read: Context = serde_json::from_str(&json).unwrap();
+        assert_eq!(read.entrypoint, ctx.entrypoint);
+        assert_eq!(print(&read), print(&ctx));
+    }
+
+    #[test]
+    fn version_mismatch() {
+        let mut json = serde_json::to_value(context()).unwrap();
+        json["version"] = (FORMAT_VERSION + 1).into();
+        let err = serde_json::from_value::<Context>(json)
+            .err()
+            .expect("reading a newer version should fail")
+            .to_string();
+        assert!(err.contains("format version"), "{err}");
+    }
+}
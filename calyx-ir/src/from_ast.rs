@@ -5,8 +5,8 @@ use super::{
     RESERVED_NAMES, RRC,
 };
 use crate::{Nothing, PortComp, StaticTiming};
-use calyx_frontend::{ast, BoolAttr, NumAttr, Workspace};
-use calyx_utils::{CalyxResult, Error, GPosIdx, WithPos};
+use calyx_frontend::{ast, BoolAttr, NumAttr, Width, Workspace};
+use calyx_utils::{CalyxResult, DiagnosticContext, Error, GPosIdx, WithPos};
 use itertools::Itertools;
 
 use std::collections::{HashMap, HashSet};
@@ -148,7 +148,7 @@ fn check_valid_port(
 }
 
 /// Validates a component signature to make sure there are not duplicate ports.
-fn check_signature(pds: &[PortDef<u64>]) -> CalyxResult<()> {
+fn check_signature<W>(pds: &[PortDef<W>], diag: &mut DiagnosticContext) {
     let mut ports: HashSet<Id> = HashSet::new();
     for pd in pds {
         let name = pd.name();
@@ -158,14 +158,14 @@ fn check_signature(pds: &[PortDef<u64>]) -> CalyxResult<()> {
                 if !ports.contains(&name) {
                     ports.insert(name);
                 } else {
-                    return Err(Error::already_bound(name, "port".to_string()));
+                    diag.err(Error::already_bound(name, "port".to_string()));
                 }
             }
             Direction::Output => {
                 if !ports.contains(&name) {
                     ports.insert(name);
                 } else {
-                    return Err(Error::already_bound(name, "port".to_string()));
+                    diag.err(Error::already_bound(name, "port".to_string()));
                 }
             }
             Direction::Inout => {
@@ -173,11 +173,12 @@ fn check_signature(pds: &[PortDef<u64>]) -> CalyxResult<()> {
             }
         }
     }
-    Ok(())
 }
 
 /// Construct an IR representation using a parsed AST and command line options.
-pub fn ast_to_ir(mut workspace: Workspace) -> CalyxResult<Context> {
+/// Problems in different components are all reported together.
+pub fn ast_to_ir(workspace: Workspace) -> CalyxResult<Context> {
+    let mut diag = DiagnosticContext::default();
     let prims = workspace.lib.signatures().collect_vec();
     let mut all_names: HashSet<&Id> =
         HashSet::with_capacity(workspace.components.len() + prims.len());
@@ -188,7 +189,7 @@ pub fn ast_to_ir(mut workspace: Workspace) -> CalyxResult<Context> {
 
     for bound in prim_names.chain(comp_names) {
         if all_names.contains(bound) {
-            return Err(Error::already_bound(
+            diag.err(Error::already_bound(
                 *bound,
                 "component or primitive".to_string(),
             ));
@@ -196,6 +197,76 @@ pub fn ast_to_ir(mut workspace: Workspace) -> CalyxResult<Context> {
         all_names.insert(bound);
     }
 
+    let names = all_names.into_iter().copied().collect();
+    let Some(globals) =
+        diag.accumulate(bind_consts(&workspace.consts, &Binding::new()))
+    else {
+        return Err(Error::from_diagnostics(diag));
+    };
+
+    // Instantiate the parameterized components used by the program
+    let (generics, concrete): (Vec<_>, Vec<_>) = workspace
+        .components
+        .into_iter()
+        .partition(|comp| !comp.params.is_empty());
+    let mut instantiator = Instantiator {
+        generics: HashMap::new(),
+        declared: workspace
+            .declarations
+            .iter()
+            .filter(|comp| !comp.params.is_empty())
+            .map(|comp| comp.name)
+            .collect(),
+        instances: HashMap::new(),
+        names,
+        globals,
+        depth: 0,
+    };
+    for comp in generics {
+        if comp.attributes.has(BoolAttr::TopLevel) || comp.name == "main" {
+            diag.err(
+                Error::malformed_structure(format!(
+                    "entrypoint component `{}` cannot have parameters",
+                    comp.name
+                ))
+                .with_pos(&comp.attributes),
+            );
+        }
+        for def in &comp.consts {
+            if comp.params.contains(&def.name) {
+                diag.err(
+                    Error::already_bound(def.name, "parameter".to_string())
+                        .with_pos(&def.span),
+                );
+            }
+        }
+        instantiator.generics.insert(comp.name, comp);
+    }
+    let mut components = Vec::with_capacity(concrete.len());
+    for comp in concrete {
+        diag.accumulate(instantiator.instantiate(
+            comp,
+            Binding::new(),
+            &mut components,
+        ));
+    }
+    // Parameterized components that are never used would otherwise not be
+    // checked at all
+    let used: HashSet<Id> = instantiator
+        .instances
+        .keys()
+        .map(|(comp, _)| *comp)
+        .collect();
+    for comp in instantiator.generics.values() {
+        if !used.contains(&comp.name) {
+            diag.accumulate(instantiator.check_names(comp));
+        }
+    }
+    // Components cannot be built without the instances they use
+    if diag.has_errors() {
+        return Err(Error::from_diagnostics(diag));
+    }
+
     // Build the signature context
     let mut sig_ctx = SigCtx {
         lib: workspace.lib,
@@ -205,26 +276,36 @@ pub fn ast_to_ir(mut workspace: Workspace) -> CalyxResult<Context> {
     // Add declarations to context
     for comp in workspace
         .declarations
-        .iter_mut()
-        .chain(workspace.components.iter_mut())
+        .iter()
+        .filter(|comp| comp.params.is_empty())
+        .chain(components.iter())
     {
-        let sig = &mut comp.signature;
-        check_signature(&*sig)?;
+        let Some(mut sig) =
+            diag.accumulate(instantiator.scope(comp, Binding::new()).and_then(
+                |binding| {
+                    comp.signature
+                        .iter()
+                        .map(|pd| resolve_port(pd, &binding))
+                        .collect::<CalyxResult<Vec<_>>>()
+                },
+            ))
+        else {
+            continue;
+        };
+        check_signature(&sig, &mut diag);
         // extend the signature if the component does not have the @nointerface attribute.
         if !comp.attributes.has(BoolAttr::NoInterface) && !comp.is_comb {
-            Component::extend_signature(sig);
+            Component::extend_signature(&mut sig);
         }
-        sig_ctx
-            .comp_sigs
-            .insert(comp.name, (sig.clone(), comp.latency));
+        sig_ctx.comp_sigs.insert(comp.name, (sig, comp.latency));
     }
 
     // building components from `ast::ComponentDef`s to `ir::Component`
-    let comps: Vec<Component> = workspace
-        .components
+    let comps: Vec<Component> = components
         .into_iter()
-        .map(|comp| build_component(comp, &mut sig_ctx))
-        .collect::<Result<_, _>>()?;
+        .filter_map(|comp| diag.accumulate(build_component(comp, &mut sig_ctx)))
+        .collect();
+    diag.into_result()?;
 
     // Find the entrypoint for the program.
     let entrypoint = comps
@@ -241,13 +322,16 @@ pub fn ast_to_ir(mut workspace: Workspace) -> CalyxResult<Context> {
         entrypoint,
         extra_opts: vec![],
         metadata: workspace.metadata,
+        warnings: vec![],
+        profiling_info: vec![],
     })
 }
 
 fn validate_component(
     comp: &ast::ComponentDef,
     sig_ctx: &SigCtx,
-) -> CalyxResult<()> {
+    diag: &mut DiagnosticContext,
+) {
     let mut cells: HashMap<Id, GPosIdx> = HashMap::new();
     let mut groups: HashMap<Id, GPosIdx> = HashMap::new();
 
@@ -256,22 +340,27 @@ fn validate_component(
         if let Some(pos) = cells.get(&cell.name) {
             let prev =
                 pos.into_option().map(|s| s.format("Previous definition"));
-            return Err(Error::already_bound(cell.name, "cell".to_string())
-                .with_pos(attrs)
-                .with_post_msg(prev));
+            diag.err(
+                Error::already_bound(cell.name, "cell".to_string())
+                    .with_pos(attrs)
+                    .with_post_msg(prev),
+            );
+        } else {
+            cells.insert(cell.name, cell.attributes.copy_span());
         }
-        cells.insert(cell.name, cell.attributes.copy_span());
 
         let proto_name = cell.prototype.name;
 
         if sig_ctx.lib.find_primitive(proto_name).is_none()
             && !sig_ctx.comp_sigs.contains_key(&proto_name)
         {
-            return Err(Error::undefined(
-                proto_name,
-                "primitive or component".to_string(),
-            )
-            .with_pos(attrs));
+            diag.err(
+                Error::undefined(
+                    proto_name,
+                    "primitive or component".to_string(),
+                )
+                .with_pos(attrs),
+            );
         }
     }
 
@@ -281,28 +370,23 @@ fn validate_component(
         if let Some(pos) = groups.get(name) {
             let prev =
                 pos.into_option().map(|s| s.format("Previous definition"));
-            return Err(Error::already_bound(*name, "group".to_string())
-                .with_pos(attrs)
-                .with_post_msg(prev));
-        }
-        if let Some(pos) = cells.get(name) {
-            let prev =
-                pos.into_option().map(|s| s.format("Previous definition"));
-            return Err(Error::already_bound(*name, "cell".to_string())
-                .with_pos(attrs)
-                .with_post_msg(prev));
-        }
-        if let Some(pos) = cells.get(name) {
+            diag.err(
+                Error::already_bound(*name, "group".to_string())
+                    .with_pos(attrs)
+                    .with_post_msg(prev),
+            );
+        } else if let Some(pos) = cells.get(name) {
             let prev =
                 pos.into_option().map(|s| s.format("Previous definition"));
-            return Err(Error::already_bound(*name, "cell".to_string())
-                .with_pos(attrs)
-                .with_post_msg(prev));
+            diag.err(
+                Error::already_bound(*name, "cell".to_string())
+                    .with_pos(attrs)
+                    .with_post_msg(prev),
+            );
+        } else {
+            groups.insert(*name, group.attributes.copy_span());
         }
-        groups.insert(*name, group.attributes.copy_span());
     }
-
-    Ok(())
 }
 
 /// Build an `ir::component::Component` using an `frontend::ast::ComponentDef`.
@@ -310,12 +394,14 @@ fn build_component(
     comp: ast::ComponentDef,
     sig_ctx: &mut SigCtx,
 ) -> CalyxResult<Component> {
-    // Validate the component before building it.
-    validate_component(&comp, sig_ctx)?;
+    // Validate the component before building it. All the problems found are reported together.
+    let mut diag = DiagnosticContext::default();
+    validate_component(&comp, sig_ctx, &mut diag);
+    diag.into_result()?;
 
     let mut ir_component = Component::new(
         comp.name,
-        comp.signature,
+        sig_ctx.comp_sigs[&comp.name].0.clone(),
         !comp.attributes.has(BoolAttr::NoInterface) && !comp.is_comb,
         comp.is_comb,
         // we may change latency from None to Some(inferred latency)
@@ -360,6 +446,484 @@ fn build_component(
     Ok(ir_component)
 }
 
+///////////////// Parameterized Components /////////////////////////
+
+/// Values bound to the parameters and named constants in scope.
+type Binding = HashMap<Id, u64>;
+
+/// The maximum depth of nested instances of parameterized components. Bounds
+/// the instances created by components that instantiate themselves.
+const MAX_INSTANCE_DEPTH: usize = 256;
+
+/// Look up the value bound to a parameter or constant.
+fn lookup(name: Id, binding: &Binding, pos: &impl WithPos) -> CalyxResult<u64> {
+    binding.get(&name).copied().ok_or_else(|| {
+        Error::undefined(name, "parameter or constant".to_string())
+            .with_pos(pos)
+    })
+}
+
+/// Resolve a width using the values bound to parameters and constants.
+fn resolve_width(
+    width: &Width,
+    binding: &Binding,
+    pos: &impl WithPos,
+) -> CalyxResult<u64> {
+    match width {
+        Width::Const { value } => Ok(*value),
+        Width::Param { value } => lookup(*value, binding, pos),
+    }
+}
+
+/// Evaluate a constant expression using the values bound to parameters and
+/// constants. Expressions are evaluated over natural numbers, so results
+/// that would be negative are errors.
+fn eval_const(expr: &ast::ConstExpr, binding: &Binding) -> CalyxResult<u64> {
+    use ast::{ConstExprKind as K, ConstOp};
+    let err =
+        |msg: String| Error::malformed_structure(msg).with_pos(&expr.span);
+    match &expr.kind {
+        K::Num(value) => Ok(*value),
+        K::Var(name) => lookup(*name, binding, &expr.span),
+        K::Op(op, l, r) => {
+            let l = eval_const(l, binding)?;
+            let r = eval_const(r, binding)?;
+            match op {
+                ConstOp::Add => l
+                    .checked_add(r)
+                    .ok_or_else(|| err(format!("`{l} + {r}` overflows"))),
+                ConstOp::Sub => l
+                    .checked_sub(r)
+                    .ok_or_else(|| err(format!("`{l} - {r}` is negative"))),
+                ConstOp::Mul => l
+                    .checked_mul(r)
+                    .ok_or_else(|| err(format!("`{l} * {r}` overflows"))),
+                ConstOp::Div => l
+                    .checked_div(r)
+                    .ok_or_else(|| err(format!("`{l} / {r}` divides by zero"))),
+            }
+        }
+        K::Call(func, args) => {
+            let args = args
+                .iter()
+                .map(|arg| eval_const(arg, binding))
+                .collect::<CalyxResult<Vec<_>>>()?;
+            match (func.as_ref(), args.as_slice()) {
+                // Like `$clog2` in Verilog, `clog2(0)` is 0.
+                ("clog2", [n]) => Ok(match n.checked_sub(1) {
+                    Some(n) => u64::from(u64::BITS - n.leading_zeros()),
+                    None => 0,
+                }),
+                ("clog2", _) => Err(err(format!(
+                    "`clog2` takes 1 argument but {} arguments were given",
+                    args.len()
+                ))),
+                ("max", _) => Ok(args.into_iter().max().unwrap_or_default()),
+                _ => Err(Error::undefined(*func, "function".to_string())
+                    .with_pos(&expr.span)),
+            }
+        }
+    }
+}
+
+/// Add the names used by `expr` to `names`.
+fn const_names(expr: &ast::ConstExpr, names: &mut Vec<Id>) {
+    use ast::ConstExprKind as K;
+    match &expr.kind {
+        K::Num(_) => (),
+        K::Var(name) => names.push(*name),
+        K::Op(_, l, r) => {
+            const_names(l, names);
+            const_names(r, names);
+        }
+        K::Call(_, args) => args.iter().for_each(|arg| const_names(arg, names)),
+    }
+}
+
+/// Evaluate the constant named `name` after the constants it uses.
+/// `active` contains the constants whose evaluation is in progress.
+fn bind_const(
+    name: Id,
+    defs: &HashMap<Id, &ast::ConstDef>,
+    binding: &mut Binding,
+    done: &mut HashSet<Id>,
+    active: &mut HashSet<Id>,
+) -> CalyxResult<()> {
+    if done.contains(&name) {
+        return Ok(());
+    }
+    let def = defs[&name];
+    if !active.insert(name) {
+        return Err(Error::malformed_structure(format!(
+            "constant `{name}` is defined in terms of itself"
+        ))
+        .with_pos(&def.span));
+    }
+    let mut deps = Vec::new();
+    const_names(&def.value, &mut deps);
+    for dep in deps {
+        if defs.contains_key(&dep) {
+            bind_const(dep, defs, binding, done, active)?;
+        }
+    }
+    let value = eval_const(&def.value, binding)?;
+    binding.insert(name, value);
+    active.remove(&name);
+    done.insert(name);
+    Ok(())
+}
+
+/// Evaluate the constants in `consts`, which may refer to each other in any
+/// order, and bind them on top of the names bound in `outer`.
+fn bind_consts(
+    consts: &[ast::ConstDef],
+    outer: &Binding,
+) -> CalyxResult<Binding> {
+    let mut defs = HashMap::with_capacity(consts.len());
+    for def in consts {
+        if defs.insert(def.name, def).is_some() {
+            return Err(Error::already_bound(def.name, "constant".to_string())
+                .with_pos(&def.span));
+        }
+    }
+    let mut binding = outer.clone();
+    let mut done = HashSet::with_capacity(consts.len());
+    for def in consts {
+        bind_const(
+            def.name,
+            &defs,
+            &mut binding,
+            &mut done,
+            &mut HashSet::new(),
+        )?;
+    }
+    Ok(binding)
+}
+
+/// Resolve the width of a port definition.
+fn resolve_port(
+    pd: &PortDef<ast::ConstExpr>,
+    binding: &Binding,
+) -> CalyxResult<PortDef<u64>> {
+    Ok(PortDef::new(
+        pd.name(),
+        eval_const(&pd.width, binding)?,
+        pd.direction.clone(),
+        pd.attributes.clone(),
+    ))
+}
+
+/// Resolve the width of a number and check that it fits.
+fn resolve_atom(atom: &mut ast::Atom, binding: &Binding) -> CalyxResult<()> {
+    if let ast::Atom::Num(num) = atom {
+        let width = resolve_width(&num.width, binding, &num.span)?;
+        if let Some(msg) = num.width_error(width) {
+            return Err(Error::malformed_structure(msg).with_pos(&num.span));
+        }
+        num.width = width.into();
+    }
+    Ok(())
+}
+
+/// Apply `f` to every atom in `guard`.
+fn guard_atoms<F>(guard: &mut ast::GuardExpr, f: &mut F) -> CalyxResult<()>
+where
+    F: FnMut(&mut ast::Atom) -> CalyxResult<()>,
+{
+    use ast::GuardExpr as GE;
+    match guard {
+        GE::And(l, r) | GE::Or(l, r) => {
+            guard_atoms(l, f)?;
+            guard_atoms(r, f)
+        }
+        GE::Not(g) => guard_atoms(g, f),
+        GE::CompOp((_, l, r)) => {
+            f(l)?;
+            f(r)
+        }
+        GE::Atom(atom) => f(atom),
+    }
+}
+
+/// Apply `f` to every atom in `guard`.
+fn static_guard_atoms<F>(
+    guard: &mut ast::StaticGuardExpr,
+    f: &mut F,
+) -> CalyxResult<()>
+where
+    F: FnMut(&mut ast::Atom) -> CalyxResult<()>,
+{
+    use ast::StaticGuardExpr as SGE;
+    match guard {
+        SGE::And(l, r) | SGE::Or(l, r) => {
+            static_guard_atoms(l, f)?;
+            static_guard_atoms(r, f)
+        }
+        SGE::Not(g) => static_guard_atoms(g, f),
+        SGE::CompOp((_, l, r)) => {
+            f(l)?;
+            f(r)
+        }
+        SGE::Atom(atom) => f(atom),
+        SGE::StaticInfo(_) => Ok(()),
+    }
+}
+
+/// Apply `f` to every atom in `control`.
+fn control_atoms<F>(control: &mut ast::Control, f: &mut F) -> CalyxResult<()>
+where
+    F: FnMut(&mut ast::Atom) -> CalyxResult<()>,
+{
+    use ast::Control as C;
+    match control {
+        C::Seq { stmts, .. }
+        | C::Par { stmts, .. }
+        | C::StaticSeq { stmts, .. }
+        | C::StaticPar { stmts, .. } => {
+            stmts.iter_mut().try_for_each(|stmt| control_atoms(stmt, f))
+        }
+        C::If {
+            tbranch, fbranch, ..
+        }
+        | C::StaticIf {
+            tbranch, fbranch, ..
+        } => {
+            control_atoms(tbranch, f)?;
+            control_atoms(fbranch, f)
+        }
+        C::While { body, .. }
+        | C::Repeat { body, .. }
+        | C::StaticRepeat { body, .. } => control_atoms(body, f),
+        C::Invoke {
+            inputs, outputs, ..
+        }
+        | C::StaticInvoke {
+            inputs, outputs, ..
+        } => inputs
+            .iter_mut()
+            .chain(outputs.iter_mut())
+            .try_for_each(|(_, atom)| f(atom)),
+        C::Enable { .. } | C::Empty { .. } => Ok(()),
+    }
+}
+
+/// Apply `f` to every atom in the wires and control of `comp`.
+fn component_atoms<F>(
+    comp: &mut ast::ComponentDef,
+    f: &mut F,
+) -> CalyxResult<()>
+where
+    F: FnMut(&mut ast::Atom) -> CalyxResult<()>,
+{
+    for wire in comp
+        .groups
+        .iter_mut()
+        .flat_map(|g| &mut g.wires)
+        .chain(&mut comp.continuous_assignments)
+    {
+        f(&mut wire.src.expr)?;
+        if let Some(guard) = &mut wire.src.guard {
+            guard_atoms(guard, f)?;
+        }
+    }
+    for wire in comp.static_groups.iter_mut().flat_map(|g| &mut g.wires) {
+        f(&mut wire.src.expr)?;
+        if let Some(guard) = &mut wire.src.guard {
+            static_guard_atoms(guard, f)?;
+        }
+    }
+    control_atoms(&mut comp.control, f)
+}
+
+/// Check that `expr` only uses the names in `scope`.
+fn check_const_names(
+    expr: &ast::ConstExpr,
+    scope: &HashSet<Id>,
+) -> CalyxResult<()> {
+    use ast::ConstExprKind as K;
+    match &expr.kind {
+        K::Num(_) => Ok(()),
+        K::Var(name) if scope.contains(name) => Ok(()),
+        K::Var(name) => {
+            Err(Error::undefined(*name, "parameter or constant".to_string())
+                .with_pos(&expr.span))
+        }
+        K::Op(_, l, r) => {
+            check_const_names(l, scope)?;
+            check_const_names(r, scope)
+        }
+        K::Call(_, args) => args
+            .iter()
+            .try_for_each(|arg| check_const_names(arg, scope)),
+    }
+}
+
+/// Instantiates parameterized components for every list of arguments that
+/// they are used with, so that later passes only see concrete components.
+struct Instantiator {
+    /// Parameterized components defined by the program.
+    generics: HashMap<Id, ast::ComponentDef>,
+    /// Parameterized components that are only declared and therefore cannot
+    /// be instantiated.
+    declared: HashSet<Id>,
+    /// The instance created for each component and list of arguments.
+    instances: HashMap<(Id, Vec<u64>), Id>,
+    /// Names of all components and primitives.
+    names: HashSet<Id>,
+    /// Values of the constants defined at the top level.
+    globals: Binding,
+    /// The number of instances that are being instantiated.
+    depth: usize,
+}
+
+impl Instantiator {
+    /// A name for the instance of `comp` with `args` that does not conflict
+    /// with any other component or primitive.
+    fn instance_name(&mut self, comp: Id, args: &[u64]) -> Id {
+        let base = format!("{}_{}", comp, args.iter().join("_"));
+        let mut name = Id::new(&base);
+        let mut idx = 0;
+        while self.names.contains(&name) {
+            idx += 1;
+            name = Id::new(format!("{base}_{idx}"));
+        }
+        self.names.insert(name);
+        name
+    }
+
+    /// The names in scope in `comp` when its parameters are bound to
+    /// `params`.
+    fn scope(
+        &self,
+        comp: &ast::ComponentDef,
+        params: Binding,
+    ) -> CalyxResult<Binding> {
+        let mut outer = self.globals.clone();
+        outer.extend(params);
+        bind_consts(&comp.consts, &outer)
+    }
+
+    /// Check that a parameterized component that is never instantiated only
+    /// uses its parameters and the constants in scope. All other checks
+    /// depend on the values of its parameters.
+    fn check_names(&self, comp: &ast::ComponentDef) -> CalyxResult<()> {
+        let scope: HashSet<Id> = self
+            .globals
+            .keys()
+            .chain(&comp.params)
+            .chain(comp.consts.iter().map(|def| &def.name))
+            .copied()
+            .collect();
+        for expr in comp
+            .consts
+            .iter()
+            .map(|def| &def.value)
+            .chain(comp.signature.iter().map(|pd| &pd.width))
+            .chain(comp.cells.iter().flat_map(|cell| &cell.prototype.params))
+        {
+            check_const_names(expr, &scope)?;
+        }
+        component_atoms(&mut comp.clone(), &mut |atom| match &*atom {
+            ast::Atom::Num(ast::BitNum {
+                width: Width::Param { value },
+                span,
+                ..
+            }) if !scope.contains(value) => Err(Error::undefined(
+                *value,
+                "parameter or constant".to_string(),
+            )
+            .with_pos(span)),
+            _ => Ok(()),
+        })
+    }
+
+    /// Resolve all parameters and constants in `comp` using the values bound
+    /// to its parameters in `params` and add it to `out`, after the
+    /// instances of the parameterized components it uses.
+    fn instantiate(
+        &mut self,
+        mut comp: ast::ComponentDef,
+        params: Binding,
+        out: &mut Vec<ast::ComponentDef>,
+    ) -> CalyxResult<()> {
+        let binding = &self.scope(&comp, params)?;
+        comp.consts.clear();
+        for pd in &mut comp.signature {
+            pd.width = eval_const(&pd.width, binding)?.into();
+        }
+
+        let mut new_instances: Vec<(Id, Id, Binding)> = Vec::new();
+        for cell in &mut comp.cells {
+            let args = cell
+                .prototype
+                .params
+                .iter()
+                .map(|arg| eval_const(arg, binding))
+                .collect::<CalyxResult<Vec<_>>>()?;
+            let proto = cell.prototype.name;
+            if self.declared.contains(&proto) {
+                return Err(Error::malformed_structure(format!(
+                    "parameterized component `{proto}` is only declared and cannot be instantiated"
+                ))
+                .with_pos(&cell.attributes));
+            }
+            let Some(params) =
+                self.generics.get(&proto).map(|g| g.params.clone())
+            else {
+                cell.prototype.params =
+                    args.into_iter().map(ast::ConstExpr::from).collect();
+                continue;
+            };
+            if params.len() != args.len() {
+                return Err(Error::malformed_structure(format!(
+                    "component `{}` requires {} parameters but instantiation provides {} parameters",
+                    proto,
+                    params.len(),
+                    args.len(),
+                ))
+                .with_pos(&cell.attributes));
+            }
+            let name = match self.instances.get(&(proto, args.clone())) {
+                Some(name) => *name,
+                None => {
+                    if self.depth == MAX_INSTANCE_DEPTH {
+                        return Err(Error::malformed_structure(format!(
+                            "instances of parameterized components are nested more than {MAX_INSTANCE_DEPTH} levels deep"
+                        ))
+                        .with_pos(&cell.attributes));
+                    }
+                    let name = self.instance_name(proto, &args);
+                    let instance_binding =
+                        params.into_iter().zip(args.iter().copied()).collect();
+                    new_instances.push((proto, name, instance_binding));
+                    self.instances.insert((proto, args), name);
+                    name
+                }
+            };
+            cell.prototype = ast::Proto {
+                name,
+                params: vec![],
+            };
+        }
+
+        component_atoms(&mut comp, &mut |atom| resolve_atom(atom, binding))?;
+
+        self.depth += 1;
+        let res = new_instances.into_iter().try_for_each(
+            |(proto, name, instance_params)| {
+                let mut instance = self.generics[&proto].clone();
+                instance.name = name;
+                instance.params = vec![];
+                self.instantiate(instance, instance_params, out)
+            },
+        );
+        self.depth -= 1;
+        res?;
+        out.push(comp);
+        Ok(())
+    }
+}
+
 ///////////////// Cell Construction /////////////////////////
 
 fn add_cell(
@@ -370,14 +934,28 @@ fn add_cell(
     let proto_name = cell.prototype.name;
 
     let res = if sig_ctx.lib.find_primitive(proto_name).is_some() {
+        let params = cell
+            .prototype
+            .params
+            .iter()
+            .map(|arg| eval_const(arg, &Binding::new()))
+            .collect::<CalyxResult<Vec<_>>>()?;
         let c = builder
-            .try_add_primitive(cell.name, proto_name, &cell.prototype.params)
+            .try_add_primitive(cell.name, proto_name, &params)
             .map_err(|e| e.with_pos(&cell.attributes))?;
         c.borrow_mut().set_reference(cell.reference);
         c
     } else {
         // Validator ensures that if the protoype is not a primitive, it
         // is a component.
+        if !cell.prototype.params.is_empty() {
+            return Err(Error::malformed_structure(format!(
+                "component `{}` does not have parameters but instantiation provides {} parameters",
+                proto_name,
+                cell.prototype.params.len()
+            ))
+            .with_pos(&cell.attributes));
+        }
         let name = builder.component.generate_name(cell.name);
         let sig = &sig_ctx.comp_sigs[&proto_name].0;
         let typ = CellType::Component { name: proto_name };
@@ -485,7 +1063,8 @@ fn atom_to_port(
 ) -> CalyxResult<RRC<Port>> {
     match atom {
         ast::Atom::Num(n) => {
-            let port = builder.add_constant(n.val, n.width).borrow().get("out");
+            let width = resolve_width(&n.width, &Binding::new(), &n.span)?;
+            let port = builder.add_constant(n.val, width).borrow().get("out");
             Ok(Rc::clone(&port))
         }
         ast::Atom::Port(p) => get_port_ref(p, builder.component),
@@ -21,6 +21,9 @@ mod structure;
 /// Modules to rewrite the IR
 pub mod rewriter;
 
+/// Profiling information about generated control logic
+pub mod profiling;
+
 // Re-export types at the module level.
 pub use builder::Builder;
 pub use calyx_utils::{GetName, Id};
@@ -35,6 +38,7 @@ pub use control::{
 pub use flat_guard::{FlatGuard, GuardPool, GuardRef};
 pub use guard::{Guard, Nothing, PortComp, StaticTiming};
 pub use printer::Printer;
+pub use profiling::ProfilingInfo;
 pub use reserved_names::RESERVED_NAMES;
 pub use rewriter::Rewriter;
 pub use structure::{
@@ -58,4 +62,8 @@ mod macros;
 /// Serializer methods for IR nodes.
 pub mod serializers;
 
+/// Deserializer for the IR.
+#[cfg(feature = "serialize")]
+mod deserializers;
+
 pub mod utils;
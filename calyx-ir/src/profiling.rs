@@ -0,0 +1,232 @@
+//! Profiling information about the control logic generated by the compiler.
+//!
+//! Passes that compile control programs record which groups their FSMs and
+//! other control logic run. The information is serialized to JSON so that
+//! profilers can map the signals of a simulation back to the groups of the
+//! program. All names are relative to the instance of `component`: cells are
+//! referred to by their name, ports by `cell.port`, and the ports of the
+//! component itself by the name of the port.
+use crate::Id;
+#[cfg(feature = "profiling")]
+use serde::{Serialize, Serializer};
+
+/// Information about the control logic generated by a single pass.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "profiling", derive(Serialize))]
+pub enum ProfilingInfo {
+    /// A dynamic FSM generated by `tdcc`.
+    Fsm(FSMInfo),
+    /// A group that is not managed by an FSM. This can happen if the group is
+    /// the only group in a control program or in an arm of a `par`.
+    SingleEnable(SingleEnableInfo),
+    /// A group generated by `tdcc` that runs the arms of a `par`.
+    Par(ParInfo),
+    /// A static FSM generated by `compile-static`.
+    StaticFsm(StaticFSMInfo),
+    /// A static group generated by `static-inline` from static control.
+    StaticGroup(StaticGroupInfo),
+    /// The groups that implement a barrier generated by `compile-sync`.
+    Barrier(BarrierInfo),
+}
+
+/// A group that is not managed by an FSM.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "profiling", derive(Serialize))]
+pub struct SingleEnableInfo {
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub component: Id,
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub group: Id,
+}
+
+/// A dynamic FSM and the groups that each of its states runs.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "profiling", derive(Serialize))]
+pub struct FSMInfo {
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub component: Id,
+    /// The group that implements the FSM.
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub group: Id,
+    /// The register that holds the state of the FSM.
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub fsm: Id,
+    pub states: Vec<FSMStateInfo>,
+}
+
+/// A state of a dynamic FSM and the group it runs.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "profiling", derive(Serialize))]
+pub struct FSMStateInfo {
+    /// The number of the state.
+    pub id: u64,
+    /// The value of the FSM register in this state, which depends on the
+    /// encoding of the FSM.
+    pub value: u64,
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub group: Id,
+}
+
+/// A group that runs the arms of a `par`. Arms that are not a single group
+/// are compiled into FSMs, which are the children of the group.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "profiling", derive(Serialize))]
+pub struct ParInfo {
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub component: Id,
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub group: Id,
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "ids_serialize_passthrough")
+    )]
+    pub children: Vec<Id>,
+}
+
+/// A static FSM and the static groups it runs.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "profiling", derive(Serialize))]
+pub struct StaticFSMInfo {
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub component: Id,
+    /// The registers that hold the state of the FSM. Duplicated FSMs only
+    /// list the first copy.
+    pub registers: Vec<StaticFSMRegister>,
+    pub groups: Vec<StaticFSMGroup>,
+}
+
+/// A register that holds the states `[beg, end)` of a static FSM.
+///
+/// A binary register holds state `s` as `s - beg + offset`, and a one-hot
+/// register holds it as `1 << s`. Values that do not correspond to a state
+/// mean that the state of the FSM is held by another register.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "profiling", derive(Serialize))]
+pub struct StaticFSMRegister {
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub fsm: Id,
+    pub one_hot: bool,
+    pub beg: u64,
+    pub end: u64,
+    pub offset: u64,
+}
+
+/// A static group run by a static FSM. The FSM is in the states of the group
+/// when the dynamic `early_reset` group that replaces the static group runs.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "profiling", derive(Serialize))]
+pub struct StaticFSMGroup {
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub group: Id,
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub early_reset: Id,
+    /// The groups that run `early_reset` in the dynamic control program.
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "ids_serialize_passthrough")
+    )]
+    pub wrappers: Vec<Id>,
+}
+
+/// A static group that runs its children during some of its states.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "profiling", derive(Serialize))]
+pub struct StaticGroupInfo {
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub component: Id,
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub group: Id,
+    pub children: Vec<StaticChildInfo>,
+}
+
+/// A child that runs during the states `[beg, end)` of a static group. The
+/// child is in state `s - beg` when the group is in state `s`.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "profiling", derive(Serialize))]
+pub struct StaticChildInfo {
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub group: Id,
+    pub beg: u64,
+    pub end: u64,
+    /// If set, the child only runs when the port has this value.
+    pub condition: Option<(String, bool)>,
+}
+
+/// A barrier and the groups that implement it.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "profiling", derive(Serialize))]
+pub struct BarrierInfo {
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "id_serialize_passthrough")
+    )]
+    pub component: Id,
+    /// The index of the barrier in `@sync(n)`.
+    pub barrier: u64,
+    #[cfg_attr(
+        feature = "profiling",
+        serde(serialize_with = "ids_serialize_passthrough")
+    )]
+    pub groups: Vec<Id>,
+}
+
+#[cfg(feature = "profiling")]
+fn id_serialize_passthrough<S>(id: &Id, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    id.to_string().serialize(ser)
+}
+
+#[cfg(feature = "profiling")]
+fn ids_serialize_passthrough<S>(ids: &[Id], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ser.collect_seq(ids.iter().map(|id| id.to_string()))
+}
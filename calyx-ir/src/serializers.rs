@@ -10,15 +10,26 @@ use serde::{
 #[cfg(feature = "serialize")]
 use serde_with::SerializeAs;
 
+/// Version of the format of the serialized IR. It changes whenever a change
+/// to the IR makes older serialized programs unreadable.
+///
+/// Version 1 added the `version`, `lib` and `metadata` fields of
+/// [crate::Context] to the unversioned format. The versions are listed in
+/// `docs/serialized-ir.md`.
+pub const FORMAT_VERSION: u64 = 1;
+
 #[cfg(feature = "serialize")]
 impl Serialize for Context {
     fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut ctx = ser.serialize_struct("Context", 2)?;
+        let mut ctx = ser.serialize_struct("Context", 5)?;
+        ctx.serialize_field("version", &FORMAT_VERSION)?;
         ctx.serialize_field("components", &self.components)?;
         ctx.serialize_field("entrypoint", &self.entrypoint)?;
+        ctx.serialize_field("lib", &self.lib)?;
+        ctx.serialize_field("metadata", &self.metadata)?;
         ctx.end()
     }
 }
@@ -86,6 +86,14 @@ impl Range {
             end: Point::zero(),
         }
     }
+
+    pub fn start(&self) -> &Point {
+        &self.start
+    }
+
+    pub fn end(&self) -> &Point {
+        &self.end
+    }
 }
 
 impl<'a> From<ts::Node<'a>> for Range {
@@ -1,38 +1,101 @@
 use std::path::Path;
+use std::sync::Mutex;
 
+use calyx_opt::pass_manager::PassManager;
+use calyx_utils::{
+    CalyxResult, DiagnosticContext, Error, GlobalPositionTable, Severity,
+};
 use resolve_path::PathResolveExt;
 
 pub struct Diagnostic;
 
+/// The compiler stores every file it parses in a global position table that
+/// is not synchronized and never shrinks. Uses of the compiler hold this lock
+/// so that concurrent requests do not race on the table.
+static POSITION_TABLE: Mutex<()> = Mutex::new(());
+
+/// Run `f`, which uses the compiler, and then remove the files and positions
+/// it added to the global position table, so that checking a document on
+/// every edit does not leak a copy of it. Nothing that refers to a position
+/// may escape `f`.
+pub fn with_position_table<T>(f: impl FnOnce() -> T) -> T {
+    let _guard = POSITION_TABLE.lock().unwrap_or_else(|e| e.into_inner());
+    let checkpoint = GlobalPositionTable::as_ref().checkpoint();
+    let out = f();
+    GlobalPositionTable::as_mut().rollback(checkpoint);
+    out
+}
+
 /// A Calyx error message
 #[derive(Debug)]
 pub struct CalyxError {
-    #[allow(unused)]
-    pub file_name: String,
+    /// The file the error is in, if the error has a position
+    pub file_name: Option<String>,
     pub pos_start: usize,
     pub pos_end: usize,
     pub msg: String,
+    pub severity: Severity,
+    /// This diagnostic is about a definition that is never used
+    pub unused: bool,
+}
+
+impl CalyxError {
+    fn new(severity: Severity, err: &Error) -> Self {
+        let (file_name, pos_start, pos_end) = err.location();
+        let unused = err.kind() == "unused";
+        Self {
+            file_name: err.pos().into_option().map(|_| file_name.to_string()),
+            pos_start,
+            pos_end,
+            msg: err.message(),
+            // Unused groups are errors for the compiler, but while editing
+            // they are usually just not used *yet*.
+            severity: if unused { Severity::Warning } else { severity },
+            unused,
+        }
+    }
 }
 
 impl Diagnostic {
-    /// Run the `calyx` compiler on `path` with libraries at `lib_path`
-    pub fn did_save(path: &Path, lib_path: &Path) -> Vec<CalyxError> {
-        calyx_frontend::Workspace::construct(
-            &Some(path.to_path_buf()),
-            lib_path.resolve().as_ref(),
-        )
-        .and_then(calyx_ir::from_ast::ast_to_ir)
-        // TODO: call well-formed pass
-        .map(|_| vec![])
-        .unwrap_or_else(|e| {
-            let (file_name, pos_start, pos_end) = e.location();
-            let msg = e.message();
-            vec![CalyxError {
-                file_name: file_name.to_string(),
-                pos_start,
-                pos_end,
-                msg,
-            }]
+    /// Check `text`, the possibly unsaved contents of the file at `path`,
+    /// with libraries at `lib_path`. Runs the frontend and the `validate`
+    /// passes and returns every error and warning they find, sorted by
+    /// position.
+    pub fn check(path: &Path, text: &str, lib_path: &Path) -> Vec<CalyxError> {
+        with_position_table(|| {
+            let ws = calyx_frontend::Workspace::construct_from_source::<false>(
+                path,
+                text.to_string(),
+                lib_path.resolve().as_ref(),
+            );
+            let mut diag = DiagnosticContext::default();
+            if let Err(e) = Self::validate(ws, &mut diag) {
+                diag.err(e);
+            }
+            diag.sorted()
+                .into_iter()
+                .map(|(severity, err)| CalyxError::new(severity, err))
+                .collect()
         })
     }
+
+    /// Build the IR for `ws` and run the `validate` passes on it. Warnings
+    /// from the passes that succeeded are added to `diag`.
+    fn validate(
+        ws: CalyxResult<calyx_frontend::Workspace>,
+        diag: &mut DiagnosticContext,
+    ) -> CalyxResult<()> {
+        let mut ctx = calyx_ir::from_ast::ast_to_ir(ws?)?;
+        let res = PassManager::default_passes()?.execute_plan(
+            &mut ctx,
+            &["validate".to_string()],
+            &[],
+            &[],
+            false,
+        );
+        for warning in ctx.warnings.drain(..) {
+            diag.warning(warning);
+        }
+        res
+    }
 }
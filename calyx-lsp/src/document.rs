@@ -21,6 +21,21 @@ pub struct Document {
     parser: ts::Parser,
     /// Map the stores information about every component defined in this file.
     pub components: HashMap<String, ComponentInfo>,
+    /// How the client counts the columns of positions
+    pub encoding: Encoding,
+    /// Set when an edit could not be applied. The text no longer matches the
+    /// client's until the client sends the whole text again.
+    pub out_of_sync: bool,
+}
+
+/// The unit that the columns of LSP positions are counted in
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Encoding {
+    /// Bytes of UTF-8
+    Utf8,
+    /// UTF-16 code units, which clients use unless another encoding is
+    /// negotiated
+    Utf16,
 }
 
 /// Public information about a component
@@ -47,6 +62,8 @@ pub enum Things<'a> {
     Cell(ts::Node<'a>, String),
     /// Identifier referring to a port
     SelfPort(ts::Node<'a>, String),
+    /// Identifier referring to the port of a cell, with the name of the cell
+    CellPort(ts::Node<'a>, String, String),
     /// Identifier refeferring to a component
     Component(String),
     /// Identifier referring to a group
@@ -55,6 +72,36 @@ pub enum Things<'a> {
     Import(ts::Node<'a>, String),
 }
 
+impl<'a> Things<'a> {
+    /// The node of the identifier, if there is one
+    pub fn node(&self) -> Option<ts::Node<'a>> {
+        match self {
+            Things::Cell(node, _)
+            | Things::SelfPort(node, _)
+            | Things::CellPort(node, _, _)
+            | Things::Group(node, _)
+            | Things::Import(node, _) => Some(*node),
+            Things::Component(_) => None,
+        }
+    }
+
+    /// Check if `self` and `other` refer to the same thing, assuming that they
+    /// are in the same component.
+    pub fn same_as(&self, other: &Things) -> bool {
+        match (self, other) {
+            (Things::Cell(_, a), Things::Cell(_, b))
+            | (Things::SelfPort(_, a), Things::SelfPort(_, b))
+            | (Things::Group(_, a), Things::Group(_, b))
+            | (Things::Import(_, a), Things::Import(_, b))
+            | (Things::Component(a), Things::Component(b)) => a == b,
+            (Things::CellPort(_, c1, p1), Things::CellPort(_, c2, p2)) => {
+                c1 == c2 && p1 == p2
+            }
+            _ => false,
+        }
+    }
+}
+
 /// Describes the section of a Calyx program we are currently editing.
 #[derive(Debug)]
 pub enum Context {
@@ -84,6 +131,8 @@ impl Document {
             tree: None,
             parser,
             components: HashMap::default(),
+            encoding: Encoding::Utf16,
+            out_of_sync: false,
         }
     }
 
@@ -97,12 +146,86 @@ impl Document {
     /// Update the document with a with entirely new text.
     pub fn parse_whole_text(&mut self, text: &str) {
         self.text = text.to_string();
-        self.tree = self.parser.parse(text, None);
+        self.tree = None;
+        self.out_of_sync = false;
+        self.reparse();
+    }
+
+    /// Replace the text in `range` with `text`. Without a range, `text` is
+    /// the new text of the whole document. The old tree is edited so that
+    /// treesitter only has to reparse the parts of the document that changed.
+    ///
+    /// An edit with a range that does not exist in the document leaves the
+    /// document out of sync, and every later ranged edit is refused until the
+    /// whole text is sent again.
+    pub fn apply_change(
+        &mut self,
+        range: Option<Range>,
+        text: &str,
+    ) -> Result<(), String> {
+        let Some(range) = range else {
+            self.parse_whole_text(text);
+            return Ok(());
+        };
+        if self.out_of_sync {
+            return Err(format!("{} is out of sync", self.url));
+        }
+        let (Some(start_byte), Some(old_end_byte)) = (
+            self.position_to_byte(range.start()),
+            self.position_to_byte(range.end()),
+        ) else {
+            self.out_of_sync = true;
+            return Err(format!(
+                "{} has no range {range:?}, the document is out of sync",
+                self.url
+            ));
+        };
+        if start_byte > old_end_byte {
+            self.out_of_sync = true;
+            return Err(format!(
+                "invalid range {range:?} in {}, the document is out of sync",
+                self.url
+            ));
+        }
+        // positions are recomputed from the offsets because clients may
+        // send columns past the end of a line
+        let start_position = self.byte_to_point(start_byte).unwrap();
+        let old_end_position = self.byte_to_point(old_end_byte).unwrap();
+        let new_end_position = match text.rfind('\n') {
+            Some(i) => Point::new(
+                start_position.row() + text.matches('\n').count(),
+                text.len() - i - 1,
+            ),
+            None => Point::new(
+                start_position.row(),
+                start_position.column() + text.len(),
+            ),
+        };
+
+        self.text.replace_range(start_byte..old_end_byte, text);
+        if let Some(tree) = self.tree.as_mut() {
+            tree.edit(&ts::InputEdit {
+                start_byte,
+                old_end_byte,
+                new_end_byte: start_byte + text.len(),
+                start_position: start_position.into(),
+                old_end_position: old_end_position.into(),
+                new_end_position: new_end_position.into(),
+            });
+        }
+        self.reparse();
+        Ok(())
+    }
+
+    /// Parse the text of the document, reusing the old tree if there is one.
+    fn reparse(&mut self) {
+        self.tree = self.parser.parse(&self.text, self.tree.as_ref());
         self.update_component_map();
+        #[cfg(feature = "log")]
         log::Debug::update(
             "tree",
             self.tree.as_ref().unwrap().root_node().to_sexp(),
-        )
+        );
     }
 
     /// Returns the root `treesit` node.
@@ -112,20 +235,81 @@ impl Document {
 
     /// Translate a `byte_offset` into a `Point`.
     pub fn byte_to_point(&self, byte_offset: usize) -> Option<Point> {
-        if byte_offset == 0 {
-            Some(Point::zero())
-        } else if byte_offset < self.text.len() {
-            let portion = &self.text[..byte_offset];
-            let lines = portion.lines();
-            let line_num = lines.clone().count();
-            let res = lines.last().map(|l| Point::new(line_num - 1, l.len()));
-            log::stdout!("{byte_offset} -> {res:?}");
-            res
-        } else {
-            None
+        let portion = self.text.get(..byte_offset)?;
+        let line = portion.matches('\n').count();
+        let column = portion.len() - portion.rfind('\n').map_or(0, |i| i + 1);
+        let res = Point::new(line, column);
+        log::stdout!("{byte_offset} -> {res:?}");
+        Some(res)
+    }
+
+    /// Translate a `point` from the client, whose column is counted in
+    /// `self.encoding`, into a byte offset. Columns past the end of a line
+    /// refer to the end of the line, and columns inside of a character are
+    /// invalid.
+    pub fn position_to_byte(&self, point: &Point) -> Option<usize> {
+        let line_start = match point.row() {
+            0 => 0,
+            row => self.text.match_indices('\n').nth(row - 1)?.0 + 1,
+        };
+        let line = self.text[line_start..].split('\n').next().unwrap();
+        let column = match self.encoding {
+            Encoding::Utf8 => {
+                let column = point.column().min(line.len());
+                line.is_char_boundary(column).then_some(column)?
+            }
+            Encoding::Utf16 => {
+                let mut units = 0;
+                let mut column = line.len();
+                for (i, c) in line.char_indices() {
+                    if units >= point.column() {
+                        column = i;
+                        break;
+                    }
+                    units += c.len_utf16();
+                }
+                // the column is in the middle of a surrogate pair
+                if units > point.column() {
+                    return None;
+                }
+                column
+            }
+        };
+        Some(line_start + column)
+    }
+
+    /// Translate a `byte_offset` into a position for the client, whose column
+    /// is counted in `self.encoding`.
+    pub fn byte_to_position(&self, byte_offset: usize) -> Option<Point> {
+        let point = self.byte_to_point(byte_offset)?;
+        match self.encoding {
+            Encoding::Utf8 => Some(point),
+            Encoding::Utf16 => {
+                let line =
+                    &self.text[byte_offset - point.column()..byte_offset];
+                Some(Point::new(point.row(), line.encode_utf16().count()))
+            }
         }
     }
 
+    /// Translate a `position` from the client into a `Point` of the tree.
+    pub fn position_to_point(&self, position: lspt::Position) -> Option<Point> {
+        self.position_to_byte(&position.into())
+            .and_then(|byte_offset| self.byte_to_point(byte_offset))
+    }
+
+    /// The range of `node` as positions for the client.
+    pub fn node_range(&self, node: ts::Node) -> Range {
+        self.byte_to_position(node.start_byte())
+            .zip(self.byte_to_position(node.end_byte()))
+            .map_or_else(|| Range::from(node), |(s, e)| Range::new(s, e))
+    }
+
+    /// The current text of the document.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
     /// Compile `pattern` into a treesit query, run the query,
     /// and return a map of capture names to captured nodes.
     pub fn captures<'a, 'node: 'a>(
@@ -384,52 +568,105 @@ impl Document {
 
     /// Find the semantic thing that is under `point`
     pub fn thing_at_point(&self, point: Point) -> Option<Things> {
-        self.node_at_point(&point).and_then(|node| {
-            if node.parent().is_some_and(|p| p.kind() == "port") {
+        self.node_at_point(&point)
+            .and_then(|node| self.thing_of_node(node))
+    }
+
+    /// Find the semantic thing that the identifier `node` refers to
+    pub fn thing_of_node<'a>(
+        &'a self,
+        node: ts::Node<'a>,
+    ) -> Option<Things<'a>> {
+        let text = || self.node_text(&node).to_string();
+        let parent_kind = node.parent().map(|p| p.kind());
+        match parent_kind? {
+            "port" => {
                 // when our parent is a port and we have a next sibling
                 // we are looking at a cell. if we don't have a next
                 // sibling, we are looking at a port on our current component
                 if node.next_sibling().is_some() {
-                    Some(Things::Cell(node, self.node_text(&node).to_string()))
+                    Some(Things::Cell(node, text()))
                 } else if node.prev_sibling().is_none() {
-                    Some(Things::SelfPort(
-                        node,
-                        self.node_text(&node).to_string(),
-                    ))
+                    Some(Things::SelfPort(node, text()))
                 } else {
-                    None
+                    // otherwise, we are looking at the port of a cell
+                    node.prev_sibling().and_then(|dot| dot.prev_sibling()).map(
+                        |cell| {
+                            Things::CellPort(
+                                node,
+                                self.node_text(&cell).to_string(),
+                                text(),
+                            )
+                        },
+                    )
                 }
-            } else if node.parent().is_some_and(|p| p.kind() == "enable") {
-                // if we are in an enable control statement, we are looking
-                // at a group
-                Some(Things::Group(node, self.node_text(&node).to_string()))
-            } else if node.parent().is_some_and(|p| p.kind() == "hole") {
-                // if we are looking at the first part of a hole, we are looking
-                // at a group name
-                if node.next_sibling().is_some() {
-                    Some(Things::Group(node, self.node_text(&node).to_string()))
+            }
+            // if we are in an enable control statement, we are looking
+            // at a group
+            "enable" => Some(Things::Group(node, text())),
+            // if we are looking at the first part of a hole, we are looking
+            // at a group name
+            "hole" if node.next_sibling().is_some() => {
+                Some(Things::Group(node, text()))
+            }
+            // inside a control `with` statement, we are looking at a group
+            "port_with" => Some(Things::Group(node, text())),
+            // the cell being invoked, or the combinational group of the
+            // invoke
+            "invoke" => {
+                if node.prev_sibling().is_some_and(|p| p.kind() == "with") {
+                    Some(Things::Group(node, text()))
                 } else {
-                    None
+                    Some(Things::Cell(node, text()))
                 }
-            } else if node.parent().is_some_and(|p| p.kind() == "port_with") {
-                // inside a control `with` statement, we are looking at a group
-                Some(Things::Group(node, self.node_text(&node).to_string()))
-            } else if node.parent().is_some_and(|p| p.kind() == "instantiation")
-            {
-                // inside a cell instantiation, we are looking at a component
-                Some(Things::Component(self.node_text(&node).to_string()))
-            } else if node.parent().is_some_and(|p| p.kind() == "import") {
-                // inside an import, we are ofc looking at an import
-                Some(Things::Import(
-                    node,
-                    self.node_text(&node).to_string().replace('"', ""),
-                ))
-            } else {
-                None
             }
+            // the cell passed as a reference cell of an invoke
+            "invoke_ref_arg" if node.next_sibling().is_none() => {
+                Some(Things::Cell(node, text()))
+            }
+            // definitions
+            "cell_assignment" => Some(Things::Cell(node, text())),
+            "group" => Some(Things::Group(node, text())),
+            "io_port" if node.next_sibling().is_some() => {
+                Some(Things::SelfPort(node, text()))
+            }
+            // inside a cell instantiation, we are looking at a component
+            "instantiation" if node.next_sibling().is_some() => {
+                Some(Things::Component(text()))
+            }
+            // inside an import, we are ofc looking at an import
+            "import" => Some(Things::Import(node, text().replace('"', ""))),
+            _ => None,
+        }
+    }
+
+    /// Check if the identifier `node` is where a cell, group, or port is
+    /// defined
+    pub fn is_definition(&self, node: ts::Node) -> bool {
+        node.parent().is_some_and(|p| {
+            matches!(p.kind(), "cell_assignment" | "group" | "io_port")
         })
     }
 
+    /// Find all the identifiers in the component containing `thing` that
+    /// refer to `thing`, including its definition
+    pub fn references<'a>(&'a self, thing: &Things<'a>) -> Vec<ts::Node<'a>> {
+        thing
+            .node()
+            .and_then(|node| node.parent_until(|n| n.kind() == "component"))
+            .map(|comp_node| {
+                self.captures(comp_node, "(ident) @id")["id"]
+                    .iter()
+                    .filter(|n| {
+                        self.thing_of_node(**n)
+                            .is_some_and(|other| other.same_as(thing))
+                    })
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Find the context of the thing at point
     pub fn context_at_point(&self, point: &Point) -> Context {
         // to find the context at point, we can't just find the node and
@@ -488,3 +725,101 @@ impl Document {
         node.utf8_text(self.text.as_bytes()).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(text: &str, encoding: Encoding) -> Document {
+        let url = lspt::Url::parse("file:///test.futil").unwrap();
+        let mut doc = Document::new(url);
+        doc.encoding = encoding;
+        doc.parse_whole_text(text);
+        doc
+    }
+
+    fn range(start: (usize, usize), end: (usize, usize)) -> Option<Range> {
+        Some(Range::new(
+            Point::new(start.0, start.1),
+            Point::new(end.0, end.1),
+        ))
+    }
+
+    #[test]
+    fn utf16_columns_after_multibyte_characters() {
+        let mut doc = document("// héllo\nx", Encoding::Utf16);
+        doc.apply_change(range((0, 5), (0, 6)), "L").unwrap();
+        assert_eq!(doc.text(), "// héLlo\nx");
+        doc.apply_change(range((1, 0), (1, 1)), "ü").unwrap();
+        assert_eq!(doc.text(), "// héLlo\nü");
+        assert_eq!(
+            doc.byte_to_position(doc.text().len()),
+            Some(Point::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn utf16_columns_after_surrogate_pairs() {
+        let mut doc = document("a😀b", Encoding::Utf16);
+        doc.apply_change(range((0, 3), (0, 4)), "c").unwrap();
+        assert_eq!(doc.text(), "a😀c");
+        // column 2 is in the middle of the emoji
+        assert!(doc.apply_change(range((0, 2), (0, 3)), "").is_err());
+        assert!(doc.out_of_sync);
+    }
+
+    #[test]
+    fn utf8_columns() {
+        let mut doc = document("a😀b", Encoding::Utf8);
+        doc.apply_change(range((0, 5), (0, 6)), "c").unwrap();
+        assert_eq!(doc.text(), "a😀c");
+        assert!(doc.apply_change(range((0, 2), (0, 3)), "").is_err());
+    }
+
+    #[test]
+    fn columns_past_the_end_of_a_line() {
+        let mut doc = document("ab\ncd", Encoding::Utf16);
+        doc.apply_change(range((0, 10), (1, 0)), "").unwrap();
+        assert_eq!(doc.text(), "abcd");
+    }
+
+    #[test]
+    fn invalid_edits_need_a_resync() {
+        let mut doc = document("é\n", Encoding::Utf16);
+        assert!(doc.apply_change(range((5, 0), (5, 1)), "x").is_err());
+        assert_eq!(doc.text(), "é\n");
+        // valid edits are refused until the whole text is sent
+        assert!(doc.apply_change(range((0, 0), (0, 0)), "x").is_err());
+        assert_eq!(doc.text(), "é\n");
+        doc.apply_change(None, "é\nx").unwrap();
+        assert!(!doc.out_of_sync);
+        doc.apply_change(range((1, 1), (1, 1)), "y").unwrap();
+        assert_eq!(doc.text(), "é\nxy");
+    }
+
+    #[test]
+    fn node_positions_after_multibyte_characters() {
+        let doc = document(
+            "component main() -> () {
+  cells { /* é😀 */ r = std_reg(32); }
+  wires {}
+  control {}
+}
+",
+            Encoding::Utf16,
+        );
+        // `r` starts at byte 23 of its line, but at UTF-16 column 20
+        let point = doc.position_to_point(lspt::Position::new(1, 20));
+        assert_eq!(point, Some(Point::new(1, 23)));
+        let Some(Things::Cell(node, name)) = doc.thing_at_point(point.unwrap())
+        else {
+            panic!("expected the cell `r`");
+        };
+        assert_eq!(name, "r");
+        let range = doc.node_range(node);
+        assert_eq!(range.start(), &Point::new(1, 20));
+        assert_eq!(range.end(), &Point::new(1, 21));
+        // a column inside of the emoji is not a position
+        assert_eq!(doc.position_to_point(lspt::Position::new(1, 15)), None);
+    }
+}
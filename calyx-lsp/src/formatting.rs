@@ -0,0 +1,418 @@
+//! Formats a document by printing the program that the compiler builds from
+//! it with [ir::Printer]. The printer drops comments, so each comment is put
+//! back next to the thing it was next to in the document.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+
+use calyx_ir as ir;
+use resolve_path::PathResolveExt;
+use tower_lsp::lsp_types as lspt;
+use tree_sitter as ts;
+
+use crate::{
+    convert::{Point, Range},
+    diagnostic::with_position_table,
+    document::Document,
+};
+
+/// The indentation of each level of braces in printed programs
+const INDENT: &str = "  ";
+
+pub trait FormattingProvider {
+    /// Format the document the way that the compiler prints programs, using
+    /// the libraries at `lib_path` to resolve its imports. Only programs that
+    /// the compiler accepts can be formatted.
+    fn format(&self, lib_path: &Path) -> Result<Vec<lspt::TextEdit>, String>;
+}
+
+impl FormattingProvider for Document {
+    fn format(&self, lib_path: &Path) -> Result<Vec<lspt::TextEdit>, String> {
+        let formatted = self.formatted(lib_path)?;
+        if formatted == self.text() {
+            return Ok(vec![]);
+        }
+        let end = self.byte_to_position(self.text().len()).unwrap();
+        Ok(vec![lspt::TextEdit::new(
+            Range::new(Point::zero(), end).into(),
+            formatted,
+        )])
+    }
+}
+
+/// One step on the way from the root of a syntax tree to a node. Nodes that
+/// define something are found by their name, so that they are found even if
+/// the printer reorders them, and other nodes by their position among the
+/// siblings of the same kind.
+#[derive(Debug, PartialEq)]
+struct Step {
+    kind: String,
+    key: Key,
+}
+
+#[derive(Debug, PartialEq)]
+enum Key {
+    Name(String),
+    Index(usize),
+}
+
+/// Where a comment goes in the printed program, relative to the node at the
+/// end of a path of [Step]s.
+enum Anchor {
+    /// On the lines before the node
+    Before(Vec<Step>),
+    /// At the end of the line that the node ends on
+    After(Vec<Step>),
+    /// At the end of the block of the node, before its closing brace
+    End(Vec<Step>),
+}
+
+impl Anchor {
+    fn path(&self) -> &[Step] {
+        match self {
+            Anchor::Before(path) | Anchor::After(path) | Anchor::End(path) => {
+                path
+            }
+        }
+    }
+}
+
+impl Document {
+    /// The formatted text of the document. Documents with syntax errors are
+    /// not formatted because their comments may not be in the tree.
+    fn formatted(&self, lib_path: &Path) -> Result<String, String> {
+        let root = self
+            .root_node()
+            .ok_or_else(|| "the document has not been parsed".to_string())?;
+        if root.has_error() {
+            return Err("cannot format a document with syntax errors".into());
+        }
+        let path = self
+            .url
+            .to_file_path()
+            .map_err(|_| format!("cannot format {}", self.url))?;
+        let printed = with_position_table(|| {
+            print_program(&path, self.text(), lib_path)
+        })?;
+
+        let out = Document::new_with_text(self.url.clone(), &printed);
+        let out_root = out.root_node().unwrap();
+        let mut inserts: Vec<(usize, String)> = self
+            .comments(root)
+            .into_iter()
+            .map(|comment| {
+                let text = self.node_text(&comment).trim_end();
+                out.insertion(out_root, self.anchor(comment), text)
+            })
+            .collect();
+        // comments that are put in the same place stay in order
+        inserts.sort_by_key(|(offset, _)| *offset);
+
+        let mut formatted = String::with_capacity(printed.len());
+        let mut last = 0;
+        for (offset, text) in inserts {
+            formatted.push_str(&printed[last..offset]);
+            formatted.push_str(&text);
+            last = offset;
+        }
+        formatted.push_str(&printed[last..]);
+        Ok(formatted)
+    }
+
+    /// The comments under `root` in the order they appear in the document.
+    fn comments<'a>(&self, root: ts::Node<'a>) -> Vec<ts::Node<'a>> {
+        let mut comments = vec![];
+        let mut cursor = root.walk();
+        'walk: loop {
+            let node = cursor.node();
+            if node.kind() == "comment" {
+                comments.push(node);
+            } else if cursor.goto_first_child() {
+                continue;
+            }
+            // move on to the next node that has not been visited
+            while !cursor.goto_next_sibling() {
+                if !cursor.goto_parent() {
+                    break 'walk;
+                }
+            }
+        }
+        comments
+    }
+
+    /// Comments at the end of a line stay after the node before them, and
+    /// other comments are put before the node after them, or at the end of
+    /// the enclosing block if there is none.
+    fn anchor(&self, comment: ts::Node) -> Anchor {
+        let named = |node: &ts::Node| node.is_named() && !node.is_extra();
+        let prev = siblings(comment, ts::Node::prev_sibling).find(named);
+        let next = siblings(comment, ts::Node::next_sibling).find(named);
+        match (prev, next) {
+            (Some(prev), _)
+                if prev.end_position().row == comment.start_position().row =>
+            {
+                Anchor::After(self.path(prev))
+            }
+            (_, Some(next)) => Anchor::Before(self.path(next)),
+            _ => Anchor::End(comment.parent().map_or(vec![], |p| self.path(p))),
+        }
+    }
+
+    /// The steps from the root of the tree to `node`.
+    fn path(&self, mut node: ts::Node) -> Vec<Step> {
+        let mut steps = vec![];
+        while let Some(parent) = node.parent() {
+            steps.push(self.step(parent, node));
+            node = parent;
+        }
+        steps.reverse();
+        steps
+    }
+
+    /// The step from `parent` to its child `node`.
+    fn step(&self, parent: ts::Node, node: ts::Node) -> Step {
+        let key = match self.name(node) {
+            Some(name) => Key::Name(name),
+            None => {
+                let mut cursor = parent.walk();
+                let index = parent
+                    .named_children(&mut cursor)
+                    .filter(|n| n.kind() == node.kind())
+                    .take_while(|n| *n != node)
+                    .count();
+                Key::Index(index)
+            }
+        };
+        Step {
+            kind: node.kind().to_string(),
+            key,
+        }
+    }
+
+    /// The name of the thing that `node` defines, if it defines something.
+    fn name(&self, node: ts::Node) -> Option<String> {
+        let kind = match node.kind() {
+            "component" | "primitive" | "cell_assignment" | "group"
+            | "io_port" => "ident",
+            "import" | "extern" => "string",
+            _ => return None,
+        };
+        let mut cursor = node.walk();
+        let name = node
+            .named_children(&mut cursor)
+            .find(|n| n.kind() == kind)
+            .map(|n| self.node_text(&n).to_string());
+        name
+    }
+
+    /// Follow `path` from `root` as far as it exists in this document.
+    /// Returns the last node that was found and whether it is the end of the
+    /// path.
+    fn follow<'a>(
+        &self,
+        root: ts::Node<'a>,
+        path: &[Step],
+    ) -> (ts::Node<'a>, bool) {
+        let mut node = root;
+        for step in path {
+            let mut cursor = node.walk();
+            let child = node
+                .named_children(&mut cursor)
+                .find(|n| self.step(node, *n) == *step);
+            match child {
+                Some(child) => node = child,
+                None => return (node, false),
+            }
+        }
+        (node, true)
+    }
+
+    /// The offset in this document to insert the comment `text` at `anchor`,
+    /// and the text to insert there. Comments whose anchor does not exist
+    /// are put at the end of the closest block that does.
+    fn insertion(
+        &self,
+        root: ts::Node,
+        anchor: Anchor,
+        text: &str,
+    ) -> (usize, String) {
+        let (node, found) = self.follow(root, anchor.path());
+        match anchor {
+            Anchor::Before(_) if found => self.insert_before(node, text),
+            Anchor::After(_) if found => self.insert_after(node, text),
+            _ => {
+                let closing = node
+                    .child(node.child_count().saturating_sub(1))
+                    .filter(|n| n.kind() == "}");
+                match closing {
+                    Some(closing) => {
+                        let start = self.line_start(closing.start_byte());
+                        let indent = self.indentation(start);
+                        (start, format!("{indent}{INDENT}{text}\n"))
+                    }
+                    None if node == root => (0, format!("{text}\n")),
+                    None => self.insert_after(node, text),
+                }
+            }
+        }
+    }
+
+    /// Put the comment `text` before `node`. Block comments can stay on the
+    /// same line as the node, but line comments get their own line.
+    fn insert_before(&self, node: ts::Node, text: &str) -> (usize, String) {
+        let start = self.line_start(node.start_byte());
+        let indent = self.indentation(start);
+        if start + indent.len() == node.start_byte() || text.starts_with("//") {
+            (start, format!("{indent}{text}\n"))
+        } else {
+            (node.start_byte(), format!("{text} "))
+        }
+    }
+
+    /// Put the comment `text` at the end of the line that `node` ends on.
+    fn insert_after(&self, node: ts::Node, text: &str) -> (usize, String) {
+        let end = self.text()[node.end_byte()..]
+            .find('\n')
+            .map_or(self.text().len(), |i| node.end_byte() + i);
+        (end, format!(" {text}"))
+    }
+
+    /// The offset of the start of the line that `offset` is on.
+    fn line_start(&self, offset: usize) -> usize {
+        self.text()[..offset].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    /// The indentation of the line that starts at `start`.
+    fn indentation(&self, start: usize) -> &str {
+        let line = &self.text()[start..];
+        &line[..line.len() - line.trim_start_matches(' ').len()]
+    }
+}
+
+/// The siblings of `node` in the direction of `step`, nearest first.
+fn siblings<'a>(
+    node: ts::Node<'a>,
+    step: fn(&ts::Node<'a>) -> Option<ts::Node<'a>>,
+) -> impl Iterator<Item = ts::Node<'a>> {
+    std::iter::successors(step(&node), step)
+}
+
+/// Print the program in `text`, the contents of the file at `path`, with its
+/// imports, the same way as the compiler does.
+fn print_program(
+    path: &Path,
+    text: &str,
+    lib_path: &Path,
+) -> Result<String, String> {
+    let mut ws = calyx_frontend::Workspace::construct_from_source::<true>(
+        path,
+        text.to_string(),
+        lib_path.resolve().as_ref(),
+    )
+    .map_err(|e| e.message())?;
+    // libraries do not have an entry point, so make one up while building
+    // the program
+    let has_entry = ws.components.iter().any(|comp| {
+        comp.name == "main" || comp.attributes.has(ir::BoolAttr::TopLevel)
+    });
+    let entry = match ws.components.first_mut() {
+        Some(comp) if !has_entry => {
+            comp.attributes.insert(ir::BoolAttr::TopLevel, 1);
+            Some(comp.name)
+        }
+        _ => None,
+    };
+    let imports = ws.original_imports.clone();
+    let declared: HashMap<ir::Id, Vec<ir::Id>> = ws
+        .components
+        .iter()
+        .map(|comp| {
+            (
+                comp.name,
+                comp.signature.iter().map(|pd| pd.name()).collect(),
+            )
+        })
+        .collect();
+    let mut ctx = ir::from_ast::ast_to_ir(ws).map_err(|e| e.message())?;
+    if let Some(entry) = entry {
+        ctx.components
+            .iter_mut()
+            .find(|comp| comp.name == entry)
+            .unwrap()
+            .attributes
+            .remove(ir::BoolAttr::TopLevel);
+    }
+    // the interface ports that the compiler adds are not in the document
+    for comp in &ctx.components {
+        if let Some(ports) = declared.get(&comp.name) {
+            comp.signature
+                .borrow_mut()
+                .ports
+                .retain(|port| ports.contains(&port.borrow().name));
+        }
+    }
+
+    let mut out = vec![];
+    for import in imports {
+        writeln!(out, "import \"{import}\";").unwrap();
+    }
+    ir::Printer::write_context(&ctx, true, &mut out).unwrap();
+    Ok(String::from_utf8(out).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+
+    /// The repository, where the standard library is
+    fn lib_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .to_path_buf()
+    }
+
+    fn format(path: &Path, text: &str) -> Result<String, String> {
+        let url = lspt::Url::from_file_path(path).unwrap();
+        Document::new_with_text(url, text).formatted(&lib_path())
+    }
+
+    /// Every `.futil` file in `tests/formatting` is formatted to the
+    /// `.expect` file next to it, which formatting leaves as it is.
+    #[test]
+    fn golden() {
+        let dir =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/formatting");
+        let mut checked = 0;
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "futil") {
+                let input = fs::read_to_string(&path).unwrap();
+                let expect =
+                    fs::read_to_string(path.with_extension("expect")).unwrap();
+                assert_eq!(format(&path, &input).unwrap(), expect, "{path:?}");
+                assert_eq!(format(&path, &expect).unwrap(), expect, "{path:?}");
+                checked += 1;
+            }
+        }
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let path = lib_path().join("test.futil");
+        assert!(format(&path, "component main() -> () {\n  cells {").is_err());
+    }
+
+    #[test]
+    fn invalid_programs() {
+        let path = lib_path().join("test.futil");
+        let text = "component main() -> () {\n  cells {}\n  wires {}\n  \
+                    control { g; }\n}\n";
+        assert!(format(&path, text).is_err());
+    }
+}
@@ -4,7 +4,6 @@ use tower_lsp::lsp_types as lspt;
 use tree_sitter as ts;
 
 use crate::{
-    convert::Range,
     document::{Document, Things},
     query_result::QueryResult,
     Config,
@@ -54,7 +53,9 @@ pub trait DefinitionProvider {
         thing: Things,
     ) -> Option<DefRes> {
         match thing {
-            Things::Cell(node, name) => self.find_cell(url, node, name),
+            Things::Cell(node, name) | Things::CellPort(node, name, _) => {
+                self.find_cell(url, node, name)
+            }
             Things::SelfPort(node, name) => {
                 self.find_self_port(url, node, name)
             }
@@ -103,7 +104,7 @@ impl DefinitionProvider for Document {
             .map(|node| {
                 DefRes::Found(lspt::Location::new(
                     url,
-                    Range::from(node).into(),
+                    self.node_range(node).into(),
                 ))
             })
     }
@@ -119,7 +120,7 @@ impl DefinitionProvider for Document {
             .map(|n| {
                 DefRes::Found(lspt::Location::new(
                     url.clone(),
-                    Range::from(n).into(),
+                    self.node_range(n).into(),
                 ))
             })
     }
@@ -135,7 +136,7 @@ impl DefinitionProvider for Document {
             .map(|node| {
                 DefRes::Found(lspt::Location::new(
                     url.clone(),
-                    Range::from(node).into(),
+                    self.node_range(node).into(),
                 ))
             })
     }
@@ -168,7 +169,7 @@ impl DefinitionProvider for Document {
             .map(|n| {
                 DefRes::Found(lspt::Location::new(
                     self.url.clone(),
-                    Range::from(n).into(),
+                    self.node_range(n).into(),
                 ))
             })
             .or_else(|| {
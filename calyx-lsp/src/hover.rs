@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use itertools::{multizip, Itertools};
+use tree_sitter as ts;
+
+use crate::{
+    convert::Point,
+    document::{Document, Things},
+    query_result::QueryResult,
+    ts_utils::ParentUntil,
+    Config,
+};
+
+/// The instantiation of a cell, like `std_reg(32)`
+#[derive(Clone, Debug)]
+pub struct Instantiation {
+    prototype: String,
+    args: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub enum HoverRes {
+    /// We have found the markdown to show
+    Found(String),
+    /// Continue searching these paths for the signature of the instantiated
+    /// primitive or component
+    Continue(Vec<PathBuf>, Instantiation),
+}
+
+impl QueryResult for HoverRes {
+    type Data = String;
+    type Needle = Instantiation;
+
+    fn found(&self) -> Option<Self::Data> {
+        match self {
+            HoverRes::Found(text) => Some(text.clone()),
+            HoverRes::Continue(_, _) => None,
+        }
+    }
+
+    fn paths(&self) -> Vec<PathBuf> {
+        match self {
+            HoverRes::Found(_) => vec![],
+            HoverRes::Continue(paths, _) => paths.clone(),
+        }
+    }
+
+    fn resume(&self, config: &Config, doc: &Document) -> Option<Self> {
+        match self {
+            HoverRes::Found(_) => Some(self.clone()),
+            HoverRes::Continue(_, inst) => {
+                Some(doc.signature_hover(config, inst))
+            }
+        }
+    }
+}
+
+pub trait HoverProvider {
+    /// Describe the thing at `point`. For cells, and the primitives or
+    /// components they instantiate, this is the signature of the cell with
+    /// the parameters replaced by the arguments of the cell.
+    fn hover(&self, config: &Config, point: &Point) -> Option<HoverRes>;
+
+    /// Find the signature of `inst` in this document, or continue searching
+    /// the imports of this document.
+    fn signature_hover(
+        &self,
+        config: &Config,
+        inst: &Instantiation,
+    ) -> HoverRes;
+}
+
+impl HoverProvider for Document {
+    fn hover(&self, config: &Config, point: &Point) -> Option<HoverRes> {
+        let node = self.node_at_point(point)?;
+        let inst = match self.thing_of_node(node)? {
+            Things::Cell(node, name) | Things::CellPort(node, name, _) => {
+                self.instantiation_of(node, &name)?
+            }
+            Things::Component(_) => self.instantiation(node.parent()?)?,
+            _ => return None,
+        };
+        Some(self.signature_hover(config, &inst))
+    }
+
+    fn signature_hover(
+        &self,
+        config: &Config,
+        inst: &Instantiation,
+    ) -> HoverRes {
+        self.resolved_signature(inst)
+            .map(|sig| HoverRes::Found(format!("```calyx\n{sig}\n```")))
+            .unwrap_or_else(|| {
+                HoverRes::Continue(
+                    self.resolved_imports(config).collect(),
+                    inst.clone(),
+                )
+            })
+    }
+}
+
+impl Document {
+    /// Find the instantiation of the cell `name` in the component that
+    /// contains `node`
+    fn instantiation_of(
+        &self,
+        node: ts::Node,
+        name: &str,
+    ) -> Option<Instantiation> {
+        let comp_node = node.parent_until(|n| n.kind() == "component")?;
+        let map = self.captures(
+            comp_node,
+            "(cell_assignment (ident) @name (instantiation) @inst)",
+        );
+        multizip((map["name"].iter(), map["inst"].iter()))
+            .find(|(n, _)| self.node_text(n) == name)
+            .and_then(|(_, inst)| self.instantiation(*inst))
+    }
+
+    /// Read the prototype and arguments of the instantiation `node`
+    fn instantiation(&self, node: ts::Node) -> Option<Instantiation> {
+        let map = self
+            .captures(node, "(instantiation (ident) @proto (arg_list) @args)");
+        let prototype = self.node_text(map["proto"].first()?).to_string();
+        let args = self.captures(*map["args"].first()?, "(number) @arg")["arg"]
+            .iter()
+            .map(|n| self.node_text(n).to_string())
+            .collect();
+        Some(Instantiation { prototype, args })
+    }
+
+    /// The signature of the primitive or component instantiated by `inst`,
+    /// if it is defined in this document
+    fn resolved_signature(&self, inst: &Instantiation) -> Option<String> {
+        let def = self
+            .components()
+            .find(|n| self.node_text(n) == inst.prototype)?
+            .parent()?;
+        let params: Vec<&str> = self.captures(def, "(params (ident) @param)")
+            ["param"]
+            .iter()
+            .map(|n| self.node_text(n))
+            .collect();
+        let bindings: HashMap<&str, &str> = params
+            .iter()
+            .copied()
+            .zip(inst.args.iter().map(String::as_str))
+            .collect();
+
+        let ports = |list: &ts::Node| {
+            let map =
+                self.captures(*list, "(io_port (ident) @name . (_) @width)");
+            multizip((map["name"].iter(), map["width"].iter()))
+                .map(|(name, width)| {
+                    let width = self.node_text(width);
+                    format!(
+                        "{}: {}",
+                        self.node_text(name),
+                        bindings.get(width).unwrap_or(&width)
+                    )
+                })
+                .join(", ")
+        };
+        let sig = self.captures(
+            def,
+            "(signature (io_port_list) @inputs (io_port_list) @outputs)",
+        );
+        let inputs = ports(sig["inputs"].first()?);
+        let outputs = ports(sig["outputs"].first()?);
+
+        let params = if params.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "[{}]",
+                params
+                    .iter()
+                    .map(|p| match bindings.get(p) {
+                        Some(arg) => format!("{p}={arg}"),
+                        None => p.to_string(),
+                    })
+                    .join(", ")
+            )
+        };
+        Some(format!(
+            "{} {}{params}({inputs}) -> ({outputs})",
+            def.kind(),
+            inst.prototype
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower_lsp::lsp_types as lspt;
+
+    const PROGRAM: &str = r#"component main() -> () {
+  cells {
+    r = std_reg(32);
+    add = std_add(32);
+  }
+  wires {
+    group incr {
+      add.left = r.out;
+      r.in = add.out;
+      r.write_en = 1'd1;
+      incr[done] = r.done;
+    }
+  }
+  control {
+    incr;
+  }
+}
+extern "std.sv" {
+  primitive std_reg[WIDTH](in: WIDTH, write_en: 1) -> (out: WIDTH, done: 1);
+}
+"#;
+
+    fn hover(point: Point) -> Option<HoverRes> {
+        let url = lspt::Url::parse("file:///test.futil").unwrap();
+        let doc = Document::new_with_text(url, PROGRAM);
+        doc.hover(&Config::default(), &point)
+    }
+
+    #[test]
+    fn hover_binds_parameters() {
+        let sig = "```calyx\nprimitive std_reg[WIDTH=32](in: 32, write_en: 1) \
+                   -> (out: 32, done: 1)\n```";
+        // the definition of the cell, a use of the cell, and the primitive
+        for point in [Point::new(2, 4), Point::new(7, 17), Point::new(2, 8)] {
+            assert_eq!(hover(point).and_then(|res| res.found()).unwrap(), sig);
+        }
+    }
+
+    #[test]
+    fn hover_continues_in_imports() {
+        // `std_add` is not defined in this document
+        match hover(Point::new(3, 4)) {
+            Some(HoverRes::Continue(_, inst)) => {
+                assert_eq!(inst.prototype, "std_add");
+                assert_eq!(inst.args, ["32"]);
+            }
+            res => panic!("unexpected hover result: {res:?}"),
+        }
+    }
+
+    #[test]
+    fn no_hover_on_groups() {
+        assert!(hover(Point::new(14, 4)).is_none());
+    }
+}
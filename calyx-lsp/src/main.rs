@@ -2,9 +2,13 @@ mod completion;
 mod convert;
 mod diagnostic;
 mod document;
+mod formatting;
 mod goto_definition;
+mod hover;
 mod log;
 mod query_result;
+mod references;
+mod symbols;
 mod ts_utils;
 
 use std::collections::HashMap;
@@ -12,11 +16,15 @@ use std::fs;
 use std::path::PathBuf;
 use std::sync::RwLock;
 
-use convert::{Point, Range};
+use calyx_utils::Severity;
+use convert::Range;
 use diagnostic::Diagnostic;
-use document::Document;
+use document::{Document, Encoding};
+use formatting::FormattingProvider;
 use goto_definition::DefinitionProvider;
+use hover::HoverProvider;
 use query_result::QueryResult;
+use references::ReferenceProvider;
 use serde::Deserialize;
 use tower_lsp::lsp_types::{self as lspt, Url};
 use tower_lsp::{jsonrpc, Client, LanguageServer, LspService, Server};
@@ -24,6 +32,7 @@ use tree_sitter as ts;
 
 use crate::completion::CompletionProvider;
 use crate::log::Debug;
+use crate::symbols::SymbolProvider;
 
 extern "C" {
     /// Bind the tree-sitter parser to something that we can use in Rust
@@ -50,14 +59,24 @@ impl Default for CalyxLspConfig {
     }
 }
 
+/// How long to wait after an edit before checking a document. Documents are
+/// only checked once the user stops typing for this long.
+#[cfg(feature = "diagnostics")]
+const DIAGNOSTICS_DELAY: std::time::Duration =
+    std::time::Duration::from_millis(300);
+
 /// Data for the Calyx Language Server
 struct Backend {
     /// Connection to the client that is used for sending data
     client: Client,
     /// Currently open documents
     open_docs: RwLock<HashMap<lspt::Url, document::Document>>,
+    /// The version of the latest edit to each open document
+    versions: RwLock<HashMap<lspt::Url, i32>>,
     /// Server configuration
     config: RwLock<Config>,
+    /// The position encoding agreed on with the client
+    encoding: RwLock<Encoding>,
 }
 
 impl Backend {
@@ -65,14 +84,19 @@ impl Backend {
         Self {
             client,
             open_docs: RwLock::new(HashMap::default()),
+            versions: RwLock::new(HashMap::default()),
             config: RwLock::new(Config::default()),
+            encoding: RwLock::new(Encoding::Utf16),
         }
     }
 
     /// Open a new document located at `url` with contents `text`.
     fn open(&self, url: lspt::Url, text: String) {
+        let mut doc = Document::new(url.clone());
+        doc.encoding = *self.encoding.read().unwrap();
+        doc.parse_whole_text(&text);
         let mut map = self.open_docs.write().unwrap();
-        map.insert(url.clone(), Document::new_with_text(url, &text));
+        map.insert(url, doc);
     }
 
     /// Open a new document located at `url` with contents read
@@ -125,40 +149,66 @@ impl Backend {
             .map(|mut map| map.get_mut(url).map(updater));
     }
 
-    /// Publish diagnostics for document `url`.
+    /// The library path that imports are resolved with.
+    fn lib_path(&self) -> PathBuf {
+        self.config.read().unwrap().calyx_lsp.library_paths[0]
+            .to_string()
+            .into()
+    }
+
+    /// Publish diagnostics for document `url`, checking its current text.
     async fn publish_diagnostics(&self, url: &lspt::Url) {
         // TODO: factor the bulk of this method somewhere else
-        let lib_path: PathBuf =
-            self.config.read().unwrap().calyx_lsp.library_paths[0]
-                .to_string()
-                .into();
+        let lib_path = self.lib_path();
+        let Ok(path) = url.to_file_path() else {
+            return;
+        };
+        let file_name = path.to_string_lossy().to_string();
         let diags = self
             .read_document(url, |doc| {
                 Some(
-                    Diagnostic::did_save(
-                        &url.to_file_path().unwrap(),
-                        &lib_path,
-                    )
-                    .into_iter()
-                    .filter_map(|diag| {
-                        doc.byte_to_point(diag.pos_start).and_then(|s| {
-                            doc.byte_to_point(diag.pos_end)
-                                .map(|e| (Range::new(s, e), diag.msg))
+                    Diagnostic::check(&path, doc.text(), &lib_path)
+                        .into_iter()
+                        .filter_map(|diag| {
+                            if diag.file_name.as_ref() == Some(&file_name) {
+                                let s = doc.byte_to_position(diag.pos_start)?;
+                                let e = doc.byte_to_position(diag.pos_end)?;
+                                Some((Range::new(s, e), diag))
+                            } else if diag.severity == Severity::Error {
+                                // problems in imported files, or without a
+                                // position, are shown at the top of the file
+                                Some((Range::zero(), diag))
+                            } else {
+                                None
+                            }
                         })
-                    })
-                    .map(|(range, message)| lspt::Diagnostic {
-                        range: range.into(),
-                        severity: Some(lspt::DiagnosticSeverity::ERROR),
-                        code: None,
-                        code_description: None,
-                        source: Some("calyx".to_string()),
-                        message,
-                        related_information: None,
-                        tags: None,
-                        data: None,
-                    })
-                    .inspect(|diag| log::stdout!("{diag:#?}"))
-                    .collect(),
+                        .map(|(range, diag)| lspt::Diagnostic {
+                            range: range.into(),
+                            severity: Some(match diag.severity {
+                                Severity::Error => {
+                                    lspt::DiagnosticSeverity::ERROR
+                                }
+                                Severity::Warning => {
+                                    lspt::DiagnosticSeverity::WARNING
+                                }
+                            }),
+                            code: None,
+                            code_description: None,
+                            source: Some("calyx".to_string()),
+                            message: match &diag.file_name {
+                                Some(f) if *f != file_name => {
+                                    format!("{f}: {}", diag.msg)
+                                }
+                                _ => diag.msg,
+                            },
+                            related_information: None,
+                            tags: diag.unused.then(|| {
+                                vec![lspt::DiagnosticTag::UNNECESSARY]
+                            }),
+                            data: None,
+                        })
+                        .inspect(|diag| log::stdout!("{diag:#?}"))
+                        .collect(),
                 )
             })
             .unwrap_or_default();
@@ -182,23 +232,44 @@ impl LanguageServer for Backend {
     /// LSP method: 'initialize'
     async fn initialize(
         &self,
-        _ip: lspt::InitializeParams,
+        ip: lspt::InitializeParams,
     ) -> jsonrpc::Result<lspt::InitializeResult> {
         Debug::init("init");
+        // the columns of positions are byte offsets when the client supports
+        // it, and UTF-16 code units otherwise
+        let utf8 = ip
+            .capabilities
+            .general
+            .and_then(|general| general.position_encodings)
+            .is_some_and(|encodings| {
+                encodings.contains(&lspt::PositionEncodingKind::UTF8)
+            });
+        let (encoding, position_encoding) = if utf8 {
+            (Encoding::Utf8, lspt::PositionEncodingKind::UTF8)
+        } else {
+            (Encoding::Utf16, lspt::PositionEncodingKind::UTF16)
+        };
+        *self.encoding.write().unwrap() = encoding;
         Ok(lspt::InitializeResult {
             server_info: None,
             capabilities: lspt::ServerCapabilities {
-                // TODO: switch to incremental parsing
+                position_encoding: Some(position_encoding),
                 text_document_sync: Some(
                     lspt::TextDocumentSyncCapability::Options(
                         lspt::TextDocumentSyncOptions {
                             open_close: Some(true),
-                            change: Some(lspt::TextDocumentSyncKind::FULL),
+                            change: Some(
+                                lspt::TextDocumentSyncKind::INCREMENTAL,
+                            ),
                             will_save: None,
                             will_save_wait_until: None,
+                            // the saved text resyncs documents when an
+                            // edit could not be applied
                             save: Some(
-                                lspt::TextDocumentSyncSaveOptions::Supported(
-                                    true,
+                                lspt::TextDocumentSyncSaveOptions::SaveOptions(
+                                    lspt::SaveOptions {
+                                        include_text: Some(true),
+                                    },
                                 ),
                             ),
                         },
@@ -216,8 +287,17 @@ impl LanguageServer for Backend {
                     completion_item: None,
                 }),
                 hover_provider: Some(lspt::HoverProviderCapability::Simple(
-                    false,
+                    true,
                 )),
+                references_provider: Some(lspt::OneOf::Left(true)),
+                rename_provider: Some(lspt::OneOf::Right(
+                    lspt::RenameOptions {
+                        prepare_provider: Some(true),
+                        work_done_progress_options: Default::default(),
+                    },
+                )),
+                document_symbol_provider: Some(lspt::OneOf::Left(true)),
+                document_formatting_provider: Some(lspt::OneOf::Left(true)),
                 ..Default::default()
             },
         })
@@ -285,22 +365,67 @@ impl LanguageServer for Backend {
     /// LSP method: 'textDocument/didChange'
     /// Called when the client updates a text document. Here we process all
     /// the text_update events in the order that they are defined in `params`.
-    /// Because we are using the `Full` sync-mode, this should be a single
-    /// event containing the entire updated source.
+    /// Because we are using the `Incremental` sync-mode, each event replaces
+    /// a range of the document, unless it has no range, in which case it
+    /// contains the entire updated source. The diagnostics are updated to
+    /// match the new source, even though it is not saved yet, once no other
+    /// edit arrives for `DIAGNOSTICS_DELAY`.
+    /// When an edit can't be applied, the user is asked to save the document
+    /// so that the server gets its whole text again.
     async fn did_change(&self, params: lspt::DidChangeTextDocumentParams) {
-        self.update(&params.text_document.uri, |doc| {
+        let url = &params.text_document.uri;
+        let mut failed = None;
+        let mut was_in_sync = true;
+        self.update(url, |doc| {
+            was_in_sync = !doc.out_of_sync;
             for event in &params.content_changes {
-                doc.parse_whole_text(&event.text);
+                let range = event.range.map(Range::from);
+                if let Err(msg) = doc.apply_change(range, &event.text) {
+                    failed = Some(msg);
+                }
             }
         });
+        if let Some(msg) = failed {
+            log::stdout!("{msg}");
+            if was_in_sync {
+                self.client
+                    .show_message(
+                        lspt::MessageType::WARNING,
+                        format!(
+                            "calyx-lsp lost track of an edit to {url}. \
+                             Save the file to resynchronize it."
+                        ),
+                    )
+                    .await;
+            }
+            return;
+        }
+        #[cfg(feature = "diagnostics")]
+        {
+            let version = params.text_document.version;
+            self.versions.write().unwrap().insert(url.clone(), version);
+            tokio::time::sleep(DIAGNOSTICS_DELAY).await;
+            // a later edit checks the document instead
+            if self.versions.read().unwrap().get(url) == Some(&version) {
+                self.publish_diagnostics(url).await;
+            }
+        }
     }
 
     /// LSP method: 'textDocument/didSave'
     /// Called when the document was just saved. We use to this update the
-    /// diagnostics for the saved file.
-    #[cfg(feature = "diagnostics")]
+    /// diagnostics for the saved file, and to resynchronize documents that
+    /// an edit could not be applied to.
     async fn did_save(&self, params: lspt::DidSaveTextDocumentParams) {
         let url = &params.text_document.uri;
+        if let Some(text) = &params.text {
+            self.update(url, |doc| {
+                if doc.out_of_sync {
+                    doc.parse_whole_text(text);
+                }
+            });
+        }
+        #[cfg(feature = "diagnostics")]
         self.publish_diagnostics(url).await;
     }
 
@@ -314,9 +439,10 @@ impl LanguageServer for Backend {
         let config = &self.config.read().unwrap();
         Ok(self
             .read_document(url, |doc| {
-                doc.thing_at_point(
-                    params.text_document_position_params.position.into(),
+                doc.position_to_point(
+                    params.text_document_position_params.position,
                 )
+                .and_then(|point| doc.thing_at_point(point))
                 .and_then(|thing| doc.find_thing(config, url.clone(), thing))
             })
             .and_then(|gdr| {
@@ -335,11 +461,12 @@ impl LanguageServer for Backend {
         params: lspt::CompletionParams,
     ) -> jsonrpc::Result<Option<lspt::CompletionResponse>> {
         let url = &params.text_document_position.text_document.uri;
-        let point: Point = params.text_document_position.position.into();
+        let position = params.text_document_position.position;
         let trigger_char = params.context.and_then(|cc| cc.trigger_character);
         let config = self.config.read().unwrap();
         Ok(self
             .read_document(url, |doc| {
+                let point = doc.position_to_point(position)?;
                 doc.complete(trigger_char.as_deref(), &point, &config)
             })
             .map(|reses| {
@@ -363,6 +490,116 @@ impl LanguageServer for Backend {
             }))
     }
 
+    /// LSP method: 'textDocument/hover'
+    /// Called when the client hovers over a point in the file.
+    async fn hover(
+        &self,
+        params: lspt::HoverParams,
+    ) -> jsonrpc::Result<Option<lspt::Hover>> {
+        let url = &params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+        let config = &self.config.read().unwrap();
+        Ok(self
+            .read_document(url, |doc| {
+                doc.position_to_point(position)
+                    .and_then(|point| doc.hover(config, &point))
+            })
+            .and_then(|res| {
+                res.resolve(|res, path| {
+                    let url = lspt::Url::from_file_path(path).unwrap();
+                    self.read_and_open(&url, |doc| res.resume(config, doc))
+                })
+            })
+            .map(|text| lspt::Hover {
+                contents: lspt::HoverContents::Markup(lspt::MarkupContent {
+                    kind: lspt::MarkupKind::Markdown,
+                    value: text,
+                }),
+                range: None,
+            }))
+    }
+
+    /// LSP method: 'textDocument/references'
+    /// Called when the client requests all the references to the thing at
+    /// a point in the file.
+    async fn references(
+        &self,
+        params: lspt::ReferenceParams,
+    ) -> jsonrpc::Result<Option<Vec<lspt::Location>>> {
+        let url = &params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        Ok(self.read_document(url, |doc| {
+            doc.position_to_point(position).and_then(|point| {
+                doc.find_references(&point, params.context.include_declaration)
+            })
+        }))
+    }
+
+    /// LSP method: 'textDocument/prepareRename'
+    /// Called before renaming to check that the thing at a point in the file
+    /// can be renamed.
+    async fn prepare_rename(
+        &self,
+        params: lspt::TextDocumentPositionParams,
+    ) -> jsonrpc::Result<Option<lspt::PrepareRenameResponse>> {
+        let url = &params.text_document.uri;
+        Ok(self
+            .read_document(url, |doc| {
+                doc.position_to_point(params.position)
+                    .and_then(|point| doc.prepare_rename(&point))
+            })
+            .map(|range| lspt::PrepareRenameResponse::Range(range.into())))
+    }
+
+    /// LSP method: 'textDocument/rename'
+    /// Called when the client renames the thing at a point in the file.
+    async fn rename(
+        &self,
+        params: lspt::RenameParams,
+    ) -> jsonrpc::Result<Option<lspt::WorkspaceEdit>> {
+        let url = &params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        match self.read_document(url, |doc| {
+            let point = doc.position_to_point(position)?;
+            Some(doc.rename(&point, &params.new_name))
+        }) {
+            Some(Ok(edits)) => Ok(Some(lspt::WorkspaceEdit::new(
+                HashMap::from([(url.clone(), edits)]),
+            ))),
+            Some(Err(msg)) => Err(jsonrpc::Error::invalid_params(msg)),
+            None => Ok(None),
+        }
+    }
+
+    /// LSP method: 'textDocument/documentSymbol'
+    /// Called when the client requests an outline of the file.
+    async fn document_symbol(
+        &self,
+        params: lspt::DocumentSymbolParams,
+    ) -> jsonrpc::Result<Option<lspt::DocumentSymbolResponse>> {
+        Ok(self
+            .read_document(&params.text_document.uri, |doc| {
+                Some(doc.document_symbols())
+            })
+            .map(lspt::DocumentSymbolResponse::Nested))
+    }
+
+    /// LSP method: 'textDocument/formatting'
+    /// Called when the client requests that we format the whole file.
+    async fn formatting(
+        &self,
+        params: lspt::DocumentFormattingParams,
+    ) -> jsonrpc::Result<Option<Vec<lspt::TextEdit>>> {
+        let lib_path = self.lib_path();
+        match self.read_document(&params.text_document.uri, |doc| {
+            Some(doc.format(&lib_path))
+        }) {
+            Some(Ok(edits)) => Ok(Some(edits)),
+            Some(Err(msg)) => Err(jsonrpc::Error::invalid_params(msg)),
+            None => Ok(None),
+        }
+    }
+
     /// LSP method: 'shutdown'
     async fn shutdown(&self) -> jsonrpc::Result<()> {
         log::stdout!("shutdown");
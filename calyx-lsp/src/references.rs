@@ -0,0 +1,252 @@
+use regex::Regex;
+use tower_lsp::lsp_types as lspt;
+
+use crate::{
+    convert::{Point, Range},
+    document::{Document, Things},
+};
+
+pub trait ReferenceProvider {
+    /// Find the locations of every reference to the cell, group, or port at
+    /// `point`.
+    fn find_references(
+        &self,
+        point: &Point,
+        include_definition: bool,
+    ) -> Option<Vec<lspt::Location>>;
+
+    /// Return the range of the identifier at `point` if it can be renamed.
+    fn prepare_rename(&self, point: &Point) -> Option<Range>;
+
+    /// Compute the edits that rename the cell or group at `point` to
+    /// `new_name`. Cells and groups are local to their component, so all the
+    /// edits are in this document.
+    fn rename(
+        &self,
+        point: &Point,
+        new_name: &str,
+    ) -> Result<Vec<lspt::TextEdit>, String>;
+}
+
+impl ReferenceProvider for Document {
+    fn find_references(
+        &self,
+        point: &Point,
+        include_definition: bool,
+    ) -> Option<Vec<lspt::Location>> {
+        self.thing_at_point(point.clone()).map(|thing| {
+            self.references(&thing)
+                .into_iter()
+                .filter(|n| include_definition || !self.is_definition(*n))
+                .map(|n| {
+                    lspt::Location::new(
+                        self.url.clone(),
+                        self.node_range(n).into(),
+                    )
+                })
+                .collect()
+        })
+    }
+
+    fn prepare_rename(&self, point: &Point) -> Option<Range> {
+        match self.thing_at_point(point.clone())? {
+            Things::Cell(node, _) | Things::Group(node, _) => {
+                Some(self.node_range(node))
+            }
+            _ => None,
+        }
+    }
+
+    fn rename(
+        &self,
+        point: &Point,
+        new_name: &str,
+    ) -> Result<Vec<lspt::TextEdit>, String> {
+        let thing = self
+            .thing_at_point(point.clone())
+            .ok_or("nothing to rename here")?;
+        let node = match &thing {
+            Things::Cell(node, _) | Things::Group(node, _) => *node,
+            _ => return Err("only cells and groups can be renamed".to_string()),
+        };
+
+        let ident = Regex::new(r"^[a-zA-Z_]+[a-zA-Z0-9_\-]*$").unwrap();
+        if !ident.is_match(new_name) {
+            return Err(format!("`{new_name}` is not a valid identifier"));
+        }
+
+        let comp_name = self.enclosing_component_name(node);
+        let info = comp_name
+            .as_ref()
+            .and_then(|name| self.components.get(name))
+            .ok_or("could not find the enclosing component")?;
+        if info.cells.contains_key(new_name)
+            || info.groups.iter().any(|g| g == new_name)
+        {
+            return Err(format!(
+                "`{new_name}` is already defined in component `{}`",
+                comp_name.unwrap()
+            ));
+        }
+
+        // other components refer to reference cells by name when invoking
+        // this component, so renaming them is not local
+        if let Things::Cell(_, name) = &thing {
+            let is_ref = self
+                .enclosing_cells(node)
+                .filter(|n| self.node_text(n) == *name)
+                .any(|n| n.prev_sibling().is_some_and(|p| p.kind() == "ref"));
+            if is_ref {
+                return Err("reference cells cannot be renamed".to_string());
+            }
+        }
+
+        Ok(self
+            .references(&thing)
+            .into_iter()
+            .map(|n| {
+                lspt::TextEdit::new(
+                    self.node_range(n).into(),
+                    new_name.to_string(),
+                )
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM: &str = r#"component main() -> () {
+  cells {
+    r = std_reg(32);
+    add = std_add(32);
+  }
+  wires {
+    group incr {
+      add.left = r.out;
+      add.right = 32'd1;
+      r.in = add.out;
+      r.write_en = 1'd1;
+      incr[done] = r.done;
+    }
+    group init {
+      r.in = 32'd0;
+      r.write_en = 1'd1;
+      init[done] = r.done;
+    }
+  }
+  control {
+    seq { init; incr; incr; }
+  }
+}
+"#;
+
+    fn document() -> Document {
+        let url = lspt::Url::parse("file:///test.futil").unwrap();
+        Document::new_with_text(url, PROGRAM)
+    }
+
+    /// The start of each location as `(row, column)`
+    fn starts(locations: &[lspt::Location]) -> Vec<(u32, u32)> {
+        locations
+            .iter()
+            .map(|l| (l.range.start.line, l.range.start.character))
+            .collect()
+    }
+
+    /// Apply `edits` to the text of `doc`. The program is ASCII, so columns
+    /// are byte offsets into their line.
+    fn apply(doc: &Document, edits: &[lspt::TextEdit]) -> String {
+        let mut lines: Vec<String> =
+            doc.text().lines().map(str::to_string).collect();
+        let mut edits = edits.to_vec();
+        edits.sort_by_key(|e| (e.range.start.line, e.range.start.character));
+        for edit in edits.iter().rev() {
+            let line = &mut lines[edit.range.start.line as usize];
+            line.replace_range(
+                edit.range.start.character as usize
+                    ..edit.range.end.character as usize,
+                &edit.new_text,
+            );
+        }
+        lines.join("\n")
+    }
+
+    #[test]
+    fn references_across_groups() {
+        let doc = document();
+        // `r` in `add.left = r.out`
+        let refs = doc.find_references(&Point::new(7, 17), true).unwrap();
+        assert_eq!(
+            starts(&refs),
+            [
+                (2, 4),
+                (7, 17),
+                (9, 6),
+                (10, 6),
+                (11, 19),
+                (14, 6),
+                (15, 6),
+                (16, 19)
+            ]
+        );
+
+        let refs = doc.find_references(&Point::new(7, 17), false).unwrap();
+        assert_eq!(refs.len(), 7);
+        assert!(!starts(&refs).contains(&(2, 4)));
+    }
+
+    #[test]
+    fn references_to_group() {
+        let doc = document();
+        // the second `incr` in the control
+        let refs = doc.find_references(&Point::new(20, 22), true).unwrap();
+        assert_eq!(starts(&refs), [(6, 10), (11, 6), (20, 16), (20, 22)]);
+    }
+
+    #[test]
+    fn rename_cell() {
+        let doc = document();
+        assert_eq!(
+            doc.prepare_rename(&Point::new(2, 4)).map(lspt::Range::from),
+            Some(lspt::Range::new(
+                lspt::Position::new(2, 4),
+                lspt::Position::new(2, 5)
+            ))
+        );
+
+        let edits = doc.rename(&Point::new(14, 6), "acc").unwrap();
+        assert_eq!(edits.len(), 8);
+        let text = apply(&doc, &edits);
+        assert!(text.contains("    acc = std_reg(32);"));
+        assert!(text.contains("add.left = acc.out;"));
+        assert!(text.contains("init[done] = acc.done;"));
+        assert!(!text.contains(" r."));
+    }
+
+    #[test]
+    fn rename_group() {
+        let doc = document();
+        let edits = doc.rename(&Point::new(6, 10), "step").unwrap();
+        let text = apply(&doc, &edits);
+        assert!(text.contains("group step {"));
+        assert!(text.contains("step[done] = r.done;"));
+        assert!(text.contains("seq { init; step; step; }"));
+        // the port of the cell is left alone
+        assert!(text.contains("r.in = add.out;"));
+    }
+
+    #[test]
+    fn rename_errors() {
+        let doc = document();
+        // ports cannot be renamed
+        assert!(doc.prepare_rename(&Point::new(7, 19)).is_none());
+        assert!(doc.rename(&Point::new(7, 19), "o").is_err());
+        // names must be unused in the component
+        assert!(doc.rename(&Point::new(2, 4), "add").is_err());
+        assert!(doc.rename(&Point::new(2, 4), "init").is_err());
+        assert!(doc.rename(&Point::new(2, 4), "1r").is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use tower_lsp::lsp_types as lspt;
+use tree_sitter as ts;
+
+use crate::document::Document;
+
+pub trait SymbolProvider {
+    /// Outline of the document: components with their cells, groups, and
+    /// control, followed by primitives.
+    fn document_symbols(&self) -> Vec<lspt::DocumentSymbol>;
+}
+
+impl SymbolProvider for Document {
+    fn document_symbols(&self) -> Vec<lspt::DocumentSymbol> {
+        let Some(root) = self.root_node() else {
+            return vec![];
+        };
+        let comps = self.captures(root, "(component (ident) @name) @comp");
+        let prims = self.captures(root, "(primitive (ident) @name) @prim");
+
+        let components = comps["comp"].iter().zip(comps["name"].iter()).map(
+            |(comp, name)| {
+                let cells = self.captures(
+                    *comp,
+                    "(cell_assignment (ident) @name (instantiation) @inst) @cell",
+                );
+                let groups =
+                    self.captures(*comp, "(group (ident) @name) @group");
+                let control = self.captures(*comp, "(control) @control");
+
+                let children = cells["cell"]
+                    .iter()
+                    .zip(cells["name"].iter())
+                    .zip(cells["inst"].iter())
+                    .map(|((cell, name), inst)| {
+                        self.symbol(
+                            name,
+                            Some(self.node_text(inst).to_string()),
+                            lspt::SymbolKind::VARIABLE,
+                            *cell,
+                            vec![],
+                        )
+                    })
+                    .chain(groups["group"].iter().zip(groups["name"].iter()).map(
+                        |(group, name)| {
+                            let detail = if group
+                                .child(0)
+                                .is_some_and(|c| c.kind() == "comb")
+                            {
+                                "comb group"
+                            } else {
+                                "group"
+                            };
+                            self.symbol(
+                                name,
+                                Some(detail.to_string()),
+                                lspt::SymbolKind::FUNCTION,
+                                *group,
+                                vec![],
+                            )
+                        },
+                    ))
+                    .chain(control["control"].iter().map(|control| {
+                        // there is no name to select, so select the keyword
+                        let keyword = control.child(0).unwrap_or(*control);
+                        self.symbol(
+                            &keyword,
+                            None,
+                            lspt::SymbolKind::NAMESPACE,
+                            *control,
+                            vec![],
+                        )
+                    }))
+                    .collect();
+
+                self.symbol(
+                    name,
+                    Some("component".to_string()),
+                    lspt::SymbolKind::CLASS,
+                    *comp,
+                    children,
+                )
+            },
+        );
+
+        let primitives = prims["prim"].iter().zip(prims["name"].iter()).map(
+            |(prim, name)| {
+                self.symbol(
+                    name,
+                    Some("primitive".to_string()),
+                    lspt::SymbolKind::INTERFACE,
+                    *prim,
+                    vec![],
+                )
+            },
+        );
+
+        components.chain(primitives).collect()
+    }
+}
+
+impl Document {
+    /// Create a symbol named by the identifier `name` spanning `node`
+    #[allow(deprecated)]
+    fn symbol(
+        &self,
+        name: &ts::Node,
+        detail: Option<String>,
+        kind: lspt::SymbolKind,
+        node: ts::Node,
+        children: Vec<lspt::DocumentSymbol>,
+    ) -> lspt::DocumentSymbol {
+        lspt::DocumentSymbol {
+            name: self.node_text(name).to_string(),
+            detail,
+            kind,
+            tags: None,
+            // deprecated in favor of `tags`, but we still have to set it
+            deprecated: None,
+            range: self.node_range(node).into(),
+            selection_range: self.node_range(*name).into(),
+            children: Some(children),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM: &str = r#"component main() -> () {
+  cells {
+    r = std_reg(32);
+  }
+  wires {
+    group incr {
+      r.in = 32'd1;
+      r.write_en = 1'd1;
+      incr[done] = r.done;
+    }
+    comb group cond {
+    }
+  }
+  control {
+    incr;
+  }
+}
+extern "std.sv" {
+  primitive std_reg[WIDTH](in: WIDTH, write_en: 1) -> (out: WIDTH, done: 1);
+}
+"#;
+
+    /// The name, detail, and kind of each symbol
+    fn summary(
+        symbols: &[lspt::DocumentSymbol],
+    ) -> Vec<(&str, Option<&str>, lspt::SymbolKind)> {
+        symbols
+            .iter()
+            .map(|s| (s.name.as_str(), s.detail.as_deref(), s.kind))
+            .collect()
+    }
+
+    #[test]
+    fn outline() {
+        let url = lspt::Url::parse("file:///test.futil").unwrap();
+        let doc = Document::new_with_text(url, PROGRAM);
+        let symbols = doc.document_symbols();
+        assert_eq!(
+            summary(&symbols),
+            [
+                ("main", Some("component"), lspt::SymbolKind::CLASS),
+                ("std_reg", Some("primitive"), lspt::SymbolKind::INTERFACE),
+            ]
+        );
+
+        let main = &symbols[0];
+        assert_eq!(
+            summary(main.children.as_ref().unwrap()),
+            [
+                ("r", Some("std_reg(32)"), lspt::SymbolKind::VARIABLE),
+                ("incr", Some("group"), lspt::SymbolKind::FUNCTION),
+                ("cond", Some("comb group"), lspt::SymbolKind::FUNCTION),
+                ("control", None, lspt::SymbolKind::NAMESPACE),
+            ]
+        );
+        // the whole component is the range, its name is the selection
+        assert_eq!(main.range.start, lspt::Position::new(0, 0));
+        assert_eq!(main.range.end, lspt::Position::new(16, 1));
+        assert_eq!(main.selection_range.start, lspt::Position::new(0, 10));
+    }
+}
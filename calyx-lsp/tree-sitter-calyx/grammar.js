@@ -105,7 +105,7 @@ module.exports = grammar({
     wire_assignment: $ => seq(optional($.at_attribute), $.lhs, '=', choice($.switch, $.expr), ';'),
 
     // control
-    control: $ => seq('control', '{', $.control_inner, '}'),
+    control: $ => seq('control', '{', optional($.control_inner), '}'),
     control_inner: $ => $.stmt,
     enable: $ => seq(repeat($.at_attribute), optional($.ident), ';'),
     invoke_ref_arg: $ => seq($.ident, '=', $.ident),
@@ -6,7 +6,7 @@
 #endif
 
 #define LANGUAGE_VERSION 14
-#define STATE_COUNT 462
+#define STATE_COUNT 463
 #define LARGE_STATE_COUNT 2
 #define SYMBOL_COUNT 126
 #define ALIAS_COUNT 0
@@ -951,21 +951,21 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [30] = 30,
   [31] = 31,
   [32] = 32,
-  [33] = 31,
+  [33] = 30,
   [34] = 34,
   [35] = 35,
-  [36] = 29,
+  [36] = 36,
   [37] = 37,
   [38] = 38,
   [39] = 39,
-  [40] = 40,
+  [40] = 28,
   [41] = 41,
   [42] = 42,
   [43] = 43,
   [44] = 44,
   [45] = 45,
   [46] = 46,
-  [47] = 37,
+  [47] = 47,
   [48] = 48,
   [49] = 49,
   [50] = 50,
@@ -991,7 +991,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [70] = 70,
   [71] = 71,
   [72] = 72,
-  [73] = 73,
+  [73] = 38,
   [74] = 74,
   [75] = 75,
   [76] = 76,
@@ -999,10 +999,10 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [78] = 78,
   [79] = 79,
   [80] = 80,
-  [81] = 80,
+  [81] = 81,
   [82] = 82,
-  [83] = 83,
-  [84] = 82,
+  [83] = 80,
+  [84] = 79,
   [85] = 85,
   [86] = 86,
   [87] = 87,
@@ -1015,17 +1015,17 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [94] = 94,
   [95] = 95,
   [96] = 96,
-  [97] = 95,
+  [97] = 97,
   [98] = 98,
-  [99] = 88,
+  [99] = 99,
   [100] = 100,
-  [101] = 98,
+  [101] = 101,
   [102] = 102,
   [103] = 103,
   [104] = 104,
-  [105] = 105,
-  [106] = 106,
-  [107] = 107,
+  [105] = 97,
+  [106] = 89,
+  [107] = 96,
   [108] = 108,
   [109] = 109,
   [110] = 110,
@@ -1042,7 +1042,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [121] = 121,
   [122] = 122,
   [123] = 123,
-  [124] = 124,
+  [124] = 121,
   [125] = 125,
   [126] = 126,
   [127] = 127,
@@ -1054,9 +1054,9 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [133] = 133,
   [134] = 134,
   [135] = 135,
-  [136] = 126,
+  [136] = 136,
   [137] = 137,
-  [138] = 138,
+  [138] = 129,
   [139] = 139,
   [140] = 140,
   [141] = 141,
@@ -1066,11 +1066,11 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [145] = 145,
   [146] = 146,
   [147] = 147,
-  [148] = 128,
+  [148] = 148,
   [149] = 149,
   [150] = 150,
-  [151] = 149,
-  [152] = 152,
+  [151] = 151,
+  [152] = 151,
   [153] = 153,
   [154] = 154,
   [155] = 155,
@@ -1085,11 +1085,11 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [164] = 164,
   [165] = 165,
   [166] = 166,
-  [167] = 28,
+  [167] = 167,
   [168] = 168,
   [169] = 169,
   [170] = 170,
-  [171] = 171,
+  [171] = 35,
   [172] = 172,
   [173] = 173,
   [174] = 174,
@@ -1099,26 +1099,26 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [178] = 178,
   [179] = 179,
   [180] = 180,
-  [181] = 28,
-  [182] = 182,
+  [181] = 181,
+  [182] = 35,
   [183] = 183,
   [184] = 184,
   [185] = 185,
   [186] = 186,
   [187] = 187,
   [188] = 188,
-  [189] = 185,
+  [189] = 189,
   [190] = 190,
   [191] = 191,
   [192] = 192,
-  [193] = 41,
+  [193] = 72,
   [194] = 194,
   [195] = 195,
-  [196] = 194,
-  [197] = 192,
-  [198] = 198,
+  [196] = 188,
+  [197] = 179,
+  [198] = 183,
   [199] = 199,
-  [200] = 41,
+  [200] = 200,
   [201] = 201,
   [202] = 202,
   [203] = 203,
@@ -1126,7 +1126,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [205] = 205,
   [206] = 206,
   [207] = 207,
-  [208] = 208,
+  [208] = 72,
   [209] = 209,
   [210] = 210,
   [211] = 211,
@@ -1134,13 +1134,13 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [213] = 213,
   [214] = 214,
   [215] = 215,
-  [216] = 85,
+  [216] = 216,
   [217] = 217,
   [218] = 218,
   [219] = 219,
   [220] = 220,
   [221] = 221,
-  [222] = 206,
+  [222] = 222,
   [223] = 223,
   [224] = 224,
   [225] = 225,
@@ -1151,97 +1151,97 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [230] = 230,
   [231] = 231,
   [232] = 232,
-  [233] = 233,
-  [234] = 234,
-  [235] = 235,
+  [233] = 72,
+  [234] = 170,
+  [235] = 174,
   [236] = 236,
-  [237] = 237,
+  [237] = 81,
   [238] = 238,
   [239] = 239,
-  [240] = 41,
-  [241] = 241,
-  [242] = 215,
-  [243] = 171,
-  [244] = 154,
+  [240] = 240,
+  [241] = 85,
+  [242] = 242,
+  [243] = 204,
+  [244] = 238,
   [245] = 245,
-  [246] = 79,
+  [246] = 246,
   [247] = 247,
-  [248] = 207,
+  [248] = 248,
   [249] = 249,
-  [250] = 250,
-  [251] = 251,
+  [250] = 240,
+  [251] = 246,
   [252] = 252,
-  [253] = 253,
-  [254] = 202,
-  [255] = 205,
+  [253] = 212,
+  [254] = 254,
+  [255] = 255,
   [256] = 256,
   [257] = 257,
-  [258] = 258,
+  [258] = 85,
   [259] = 259,
   [260] = 260,
   [261] = 261,
-  [262] = 262,
+  [262] = 81,
   [263] = 263,
   [264] = 264,
   [265] = 265,
-  [266] = 263,
+  [266] = 266,
   [267] = 267,
-  [268] = 32,
-  [269] = 267,
+  [268] = 268,
+  [269] = 269,
   [270] = 270,
   [271] = 271,
   [272] = 272,
-  [273] = 265,
+  [273] = 37,
   [274] = 274,
   [275] = 275,
   [276] = 276,
-  [277] = 79,
+  [277] = 277,
   [278] = 278,
   [279] = 279,
   [280] = 280,
   [281] = 281,
   [282] = 282,
-  [283] = 85,
+  [283] = 283,
   [284] = 284,
   [285] = 285,
-  [286] = 286,
+  [286] = 38,
   [287] = 287,
   [288] = 288,
-  [289] = 258,
+  [289] = 289,
   [290] = 290,
-  [291] = 291,
+  [291] = 39,
   [292] = 292,
   [293] = 293,
   [294] = 294,
   [295] = 295,
-  [296] = 259,
-  [297] = 274,
+  [296] = 296,
+  [297] = 297,
   [298] = 298,
   [299] = 299,
   [300] = 300,
   [301] = 301,
   [302] = 302,
-  [303] = 303,
+  [303] = 85,
   [304] = 304,
   [305] = 305,
-  [306] = 35,
+  [306] = 306,
   [307] = 307,
-  [308] = 261,
-  [309] = 309,
+  [308] = 276,
+  [309] = 36,
   [310] = 310,
-  [311] = 311,
-  [312] = 272,
-  [313] = 85,
-  [314] = 38,
+  [311] = 277,
+  [312] = 312,
+  [313] = 312,
+  [314] = 314,
   [315] = 315,
   [316] = 316,
-  [317] = 317,
+  [317] = 274,
   [318] = 318,
-  [319] = 319,
+  [319] = 310,
   [320] = 320,
-  [321] = 321,
-  [322] = 322,
-  [323] = 37,
+  [321] = 278,
+  [322] = 279,
+  [323] = 314,
   [324] = 324,
   [325] = 325,
   [326] = 326,
@@ -1329,57 +1329,58 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [408] = 408,
   [409] = 409,
   [410] = 410,
-  [411] = 411,
-  [412] = 412,
-  [413] = 408,
-  [414] = 343,
-  [415] = 415,
+  [411] = 377,
+  [412] = 404,
+  [413] = 413,
+  [414] = 414,
+  [415] = 382,
   [416] = 416,
   [417] = 417,
-  [418] = 418,
-  [419] = 328,
-  [420] = 398,
-  [421] = 378,
-  [422] = 422,
-  [423] = 408,
-  [424] = 343,
-  [425] = 378,
-  [426] = 408,
-  [427] = 343,
-  [428] = 325,
+  [418] = 343,
+  [419] = 387,
+  [420] = 420,
+  [421] = 377,
+  [422] = 404,
+  [423] = 387,
+  [424] = 377,
+  [425] = 404,
+  [426] = 426,
+  [427] = 427,
+  [428] = 428,
   [429] = 429,
   [430] = 430,
-  [431] = 431,
-  [432] = 432,
+  [431] = 426,
+  [432] = 325,
   [433] = 433,
-  [434] = 417,
+  [434] = 434,
   [435] = 435,
   [436] = 436,
-  [437] = 437,
+  [437] = 350,
   [438] = 438,
-  [439] = 352,
-  [440] = 440,
+  [439] = 439,
+  [440] = 376,
   [441] = 441,
-  [442] = 377,
+  [442] = 426,
   [443] = 443,
-  [444] = 325,
-  [445] = 445,
-  [446] = 325,
-  [447] = 332,
-  [448] = 448,
+  [444] = 426,
+  [445] = 328,
+  [446] = 446,
+  [447] = 447,
+  [448] = 352,
   [449] = 449,
-  [450] = 374,
+  [450] = 450,
   [451] = 451,
-  [452] = 384,
+  [452] = 452,
   [453] = 453,
-  [454] = 454,
+  [454] = 453,
   [455] = 455,
-  [456] = 438,
+  [456] = 456,
   [457] = 457,
-  [458] = 458,
+  [458] = 408,
   [459] = 459,
   [460] = 460,
   [461] = 461,
+  [462] = 462,
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -2656,42 +2657,42 @@ static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [17] = {.lex_state = 23},
   [18] = {.lex_state = 23},
   [19] = {.lex_state = 0},
-  [20] = {.lex_state = 24},
-  [21] = {.lex_state = 0},
+  [20] = {.lex_state = 0},
+  [21] = {.lex_state = 24},
   [22] = {.lex_state = 0},
   [23] = {.lex_state = 23},
-  [24] = {.lex_state = 25},
+  [24] = {.lex_state = 0},
   [25] = {.lex_state = 23},
   [26] = {.lex_state = 23},
-  [27] = {.lex_state = 0},
-  [28] = {.lex_state = 23},
-  [29] = {.lex_state = 26},
-  [30] = {.lex_state = 25},
-  [31] = {.lex_state = 26},
-  [32] = {.lex_state = 30},
+  [27] = {.lex_state = 25},
+  [28] = {.lex_state = 26},
+  [29] = {.lex_state = 23},
+  [30] = {.lex_state = 26},
+  [31] = {.lex_state = 25},
+  [32] = {.lex_state = 25},
   [33] = {.lex_state = 26},
   [34] = {.lex_state = 25},
-  [35] = {.lex_state = 30},
-  [36] = {.lex_state = 26},
+  [35] = {.lex_state = 23},
+  [36] = {.lex_state = 30},
   [37] = {.lex_state = 30},
   [38] = {.lex_state = 30},
-  [39] = {.lex_state = 23},
-  [40] = {.lex_state = 25},
-  [41] = {.lex_state = 23},
-  [42] = {.lex_state = 23},
-  [43] = {.lex_state = 23},
-  [44] = {.lex_state = 23},
-  [45] = {.lex_state = 23},
-  [46] = {.lex_state = 23},
-  [47] = {.lex_state = 23},
-  [48] = {.lex_state = 25},
+  [39] = {.lex_state = 30},
+  [40] = {.lex_state = 26},
+  [41] = {.lex_state = 25},
+  [42] = {.lex_state = 25},
+  [43] = {.lex_state = 25},
+  [44] = {.lex_state = 25},
+  [45] = {.lex_state = 25},
+  [46] = {.lex_state = 25},
+  [47] = {.lex_state = 25},
+  [48] = {.lex_state = 23},
   [49] = {.lex_state = 25},
-  [50] = {.lex_state = 25},
-  [51] = {.lex_state = 25},
+  [50] = {.lex_state = 23},
+  [51] = {.lex_state = 23},
   [52] = {.lex_state = 23},
-  [53] = {.lex_state = 25},
-  [54] = {.lex_state = 25},
-  [55] = {.lex_state = 25},
+  [53] = {.lex_state = 23},
+  [54] = {.lex_state = 23},
+  [55] = {.lex_state = 23},
   [56] = {.lex_state = 23},
   [57] = {.lex_state = 23},
   [58] = {.lex_state = 23},
@@ -2703,225 +2704,225 @@ static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [64] = {.lex_state = 23},
   [65] = {.lex_state = 23},
   [66] = {.lex_state = 23},
-  [67] = {.lex_state = 25},
-  [68] = {.lex_state = 25},
+  [67] = {.lex_state = 23},
+  [68] = {.lex_state = 23},
   [69] = {.lex_state = 23},
   [70] = {.lex_state = 23},
   [71] = {.lex_state = 23},
   [72] = {.lex_state = 23},
   [73] = {.lex_state = 23},
   [74] = {.lex_state = 23},
-  [75] = {.lex_state = 23},
+  [75] = {.lex_state = 25},
   [76] = {.lex_state = 23},
   [77] = {.lex_state = 23},
   [78] = {.lex_state = 23},
-  [79] = {.lex_state = 23},
+  [79] = {.lex_state = 25},
   [80] = {.lex_state = 25},
-  [81] = {.lex_state = 25},
-  [82] = {.lex_state = 25},
-  [83] = {.lex_state = 0},
+  [81] = {.lex_state = 23},
+  [82] = {.lex_state = 0},
+  [83] = {.lex_state = 25},
   [84] = {.lex_state = 25},
   [85] = {.lex_state = 23},
   [86] = {.lex_state = 26},
   [87] = {.lex_state = 26},
-  [88] = {.lex_state = 0},
-  [89] = {.lex_state = 26},
+  [88] = {.lex_state = 26},
+  [89] = {.lex_state = 0},
   [90] = {.lex_state = 26},
   [91] = {.lex_state = 26},
   [92] = {.lex_state = 26},
   [93] = {.lex_state = 26},
   [94] = {.lex_state = 26},
-  [95] = {.lex_state = 0},
-  [96] = {.lex_state = 26},
+  [95] = {.lex_state = 26},
+  [96] = {.lex_state = 0},
   [97] = {.lex_state = 0},
-  [98] = {.lex_state = 0},
-  [99] = {.lex_state = 0},
+  [98] = {.lex_state = 26},
+  [99] = {.lex_state = 26},
   [100] = {.lex_state = 26},
-  [101] = {.lex_state = 0},
+  [101] = {.lex_state = 26},
   [102] = {.lex_state = 26},
   [103] = {.lex_state = 26},
   [104] = {.lex_state = 26},
-  [105] = {.lex_state = 26},
-  [106] = {.lex_state = 26},
-  [107] = {.lex_state = 26},
+  [105] = {.lex_state = 0},
+  [106] = {.lex_state = 0},
+  [107] = {.lex_state = 0},
   [108] = {.lex_state = 26},
   [109] = {.lex_state = 0},
   [110] = {.lex_state = 0},
-  [111] = {.lex_state = 28},
+  [111] = {.lex_state = 0},
   [112] = {.lex_state = 0},
   [113] = {.lex_state = 0},
-  [114] = {.lex_state = 0},
+  [114] = {.lex_state = 28},
   [115] = {.lex_state = 0},
   [116] = {.lex_state = 0},
   [117] = {.lex_state = 0},
   [118] = {.lex_state = 0},
   [119] = {.lex_state = 0},
   [120] = {.lex_state = 0},
-  [121] = {.lex_state = 26},
+  [121] = {.lex_state = 0},
   [122] = {.lex_state = 0},
-  [123] = {.lex_state = 0},
-  [124] = {.lex_state = 25},
+  [123] = {.lex_state = 28},
+  [124] = {.lex_state = 0},
   [125] = {.lex_state = 0},
   [126] = {.lex_state = 0},
-  [127] = {.lex_state = 25},
+  [127] = {.lex_state = 28},
   [128] = {.lex_state = 0},
   [129] = {.lex_state = 0},
   [130] = {.lex_state = 0},
-  [131] = {.lex_state = 0},
+  [131] = {.lex_state = 26},
   [132] = {.lex_state = 0},
-  [133] = {.lex_state = 0},
-  [134] = {.lex_state = 0},
+  [133] = {.lex_state = 25},
+  [134] = {.lex_state = 25},
   [135] = {.lex_state = 26},
   [136] = {.lex_state = 0},
   [137] = {.lex_state = 0},
-  [138] = {.lex_state = 26},
+  [138] = {.lex_state = 0},
   [139] = {.lex_state = 0},
   [140] = {.lex_state = 0},
-  [141] = {.lex_state = 28},
-  [142] = {.lex_state = 0},
+  [141] = {.lex_state = 0},
+  [142] = {.lex_state = 25},
   [143] = {.lex_state = 25},
-  [144] = {.lex_state = 0},
-  [145] = {.lex_state = 25},
-  [146] = {.lex_state = 28},
+  [144] = {.lex_state = 26},
+  [145] = {.lex_state = 0},
+  [146] = {.lex_state = 0},
   [147] = {.lex_state = 0},
   [148] = {.lex_state = 0},
   [149] = {.lex_state = 0},
-  [150] = {.lex_state = 0},
+  [150] = {.lex_state = 26},
   [151] = {.lex_state = 0},
-  [152] = {.lex_state = 26},
+  [152] = {.lex_state = 0},
   [153] = {.lex_state = 0},
-  [154] = {.lex_state = 25},
+  [154] = {.lex_state = 0},
   [155] = {.lex_state = 0},
-  [156] = {.lex_state = 25},
+  [156] = {.lex_state = 0},
   [157] = {.lex_state = 28},
-  [158] = {.lex_state = 0},
-  [159] = {.lex_state = 0},
-  [160] = {.lex_state = 25},
-  [161] = {.lex_state = 25},
-  [162] = {.lex_state = 28},
-  [163] = {.lex_state = 25},
-  [164] = {.lex_state = 28},
-  [165] = {.lex_state = 0},
-  [166] = {.lex_state = 0},
+  [158] = {.lex_state = 28},
+  [159] = {.lex_state = 25},
+  [160] = {.lex_state = 0},
+  [161] = {.lex_state = 28},
+  [162] = {.lex_state = 0},
+  [163] = {.lex_state = 28},
+  [164] = {.lex_state = 25},
+  [165] = {.lex_state = 28},
+  [166] = {.lex_state = 25},
   [167] = {.lex_state = 0},
   [168] = {.lex_state = 0},
-  [169] = {.lex_state = 28},
-  [170] = {.lex_state = 0},
-  [171] = {.lex_state = 25},
+  [169] = {.lex_state = 25},
+  [170] = {.lex_state = 25},
+  [171] = {.lex_state = 0},
   [172] = {.lex_state = 28},
   [173] = {.lex_state = 25},
-  [174] = {.lex_state = 28},
-  [175] = {.lex_state = 26},
+  [174] = {.lex_state = 25},
+  [175] = {.lex_state = 0},
   [176] = {.lex_state = 28},
-  [177] = {.lex_state = 0},
+  [177] = {.lex_state = 26},
   [178] = {.lex_state = 0},
   [179] = {.lex_state = 0},
   [180] = {.lex_state = 26},
-  [181] = {.lex_state = 26},
-  [182] = {.lex_state = 28},
-  [183] = {.lex_state = 0},
-  [184] = {.lex_state = 26},
-  [185] = {.lex_state = 0},
+  [181] = {.lex_state = 0},
+  [182] = {.lex_state = 26},
+  [183] = {.lex_state = 26},
+  [184] = {.lex_state = 0},
+  [185] = {.lex_state = 28},
   [186] = {.lex_state = 0},
-  [187] = {.lex_state = 26},
+  [187] = {.lex_state = 0},
   [188] = {.lex_state = 0},
-  [189] = {.lex_state = 0},
-  [190] = {.lex_state = 0},
+  [189] = {.lex_state = 28},
+  [190] = {.lex_state = 28},
   [191] = {.lex_state = 0},
   [192] = {.lex_state = 26},
   [193] = {.lex_state = 0},
-  [194] = {.lex_state = 0},
-  [195] = {.lex_state = 28},
+  [194] = {.lex_state = 26},
+  [195] = {.lex_state = 0},
   [196] = {.lex_state = 0},
-  [197] = {.lex_state = 26},
-  [198] = {.lex_state = 0},
-  [199] = {.lex_state = 28},
-  [200] = {.lex_state = 26},
+  [197] = {.lex_state = 0},
+  [198] = {.lex_state = 26},
+  [199] = {.lex_state = 0},
+  [200] = {.lex_state = 14},
   [201] = {.lex_state = 0},
-  [202] = {.lex_state = 26},
+  [202] = {.lex_state = 0},
   [203] = {.lex_state = 0},
-  [204] = {.lex_state = 0},
-  [205] = {.lex_state = 26},
-  [206] = {.lex_state = 26},
+  [204] = {.lex_state = 26},
+  [205] = {.lex_state = 0},
+  [206] = {.lex_state = 0},
   [207] = {.lex_state = 26},
-  [208] = {.lex_state = 0},
-  [209] = {.lex_state = 0},
-  [210] = {.lex_state = 26},
-  [211] = {.lex_state = 26},
-  [212] = {.lex_state = 0},
+  [208] = {.lex_state = 26},
+  [209] = {.lex_state = 26},
+  [210] = {.lex_state = 0},
+  [211] = {.lex_state = 0},
+  [212] = {.lex_state = 26},
   [213] = {.lex_state = 0},
   [214] = {.lex_state = 0},
   [215] = {.lex_state = 26},
-  [216] = {.lex_state = 0},
+  [216] = {.lex_state = 26},
   [217] = {.lex_state = 0},
   [218] = {.lex_state = 0},
   [219] = {.lex_state = 0},
-  [220] = {.lex_state = 14},
-  [221] = {.lex_state = 26},
-  [222] = {.lex_state = 26},
-  [223] = {.lex_state = 26},
+  [220] = {.lex_state = 0},
+  [221] = {.lex_state = 0},
+  [222] = {.lex_state = 0},
+  [223] = {.lex_state = 0},
   [224] = {.lex_state = 0},
   [225] = {.lex_state = 0},
   [226] = {.lex_state = 0},
   [227] = {.lex_state = 0},
   [228] = {.lex_state = 0},
-  [229] = {.lex_state = 14},
-  [230] = {.lex_state = 0},
+  [229] = {.lex_state = 26},
+  [230] = {.lex_state = 26},
   [231] = {.lex_state = 0},
-  [232] = {.lex_state = 0},
-  [233] = {.lex_state = 0},
-  [234] = {.lex_state = 0},
+  [232] = {.lex_state = 14},
+  [233] = {.lex_state = 28},
+  [234] = {.lex_state = 26},
   [235] = {.lex_state = 26},
-  [236] = {.lex_state = 0},
+  [236] = {.lex_state = 14},
   [237] = {.lex_state = 0},
-  [238] = {.lex_state = 0},
-  [239] = {.lex_state = 14},
-  [240] = {.lex_state = 28},
-  [241] = {.lex_state = 26},
-  [242] = {.lex_state = 26},
+  [238] = {.lex_state = 26},
+  [239] = {.lex_state = 0},
+  [240] = {.lex_state = 26},
+  [241] = {.lex_state = 0},
+  [242] = {.lex_state = 0},
   [243] = {.lex_state = 26},
   [244] = {.lex_state = 26},
   [245] = {.lex_state = 0},
-  [246] = {.lex_state = 0},
+  [246] = {.lex_state = 26},
   [247] = {.lex_state = 0},
-  [248] = {.lex_state = 26},
+  [248] = {.lex_state = 0},
   [249] = {.lex_state = 0},
-  [250] = {.lex_state = 0},
-  [251] = {.lex_state = 0},
+  [250] = {.lex_state = 26},
+  [251] = {.lex_state = 26},
   [252] = {.lex_state = 0},
-  [253] = {.lex_state = 0},
-  [254] = {.lex_state = 26},
-  [255] = {.lex_state = 26},
+  [253] = {.lex_state = 26},
+  [254] = {.lex_state = 0},
+  [255] = {.lex_state = 0},
   [256] = {.lex_state = 0},
-  [257] = {.lex_state = 0},
-  [258] = {.lex_state = 0},
-  [259] = {.lex_state = 0},
-  [260] = {.lex_state = 0},
+  [257] = {.lex_state = 26},
+  [258] = {.lex_state = 26},
+  [259] = {.lex_state = 26},
+  [260] = {.lex_state = 28},
   [261] = {.lex_state = 0},
-  [262] = {.lex_state = 0},
+  [262] = {.lex_state = 26},
   [263] = {.lex_state = 0},
-  [264] = {.lex_state = 26},
+  [264] = {.lex_state = 0},
   [265] = {.lex_state = 0},
   [266] = {.lex_state = 0},
   [267] = {.lex_state = 0},
   [268] = {.lex_state = 0},
   [269] = {.lex_state = 0},
   [270] = {.lex_state = 0},
-  [271] = {.lex_state = 0},
+  [271] = {.lex_state = 26},
   [272] = {.lex_state = 0},
   [273] = {.lex_state = 0},
   [274] = {.lex_state = 0},
-  [275] = {.lex_state = 26},
+  [275] = {.lex_state = 0},
   [276] = {.lex_state = 0},
-  [277] = {.lex_state = 26},
+  [277] = {.lex_state = 0},
   [278] = {.lex_state = 0},
-  [279] = {.lex_state = 26},
-  [280] = {.lex_state = 0},
-  [281] = {.lex_state = 0},
+  [279] = {.lex_state = 0},
+  [280] = {.lex_state = 26},
+  [281] = {.lex_state = 26},
   [282] = {.lex_state = 0},
-  [283] = {.lex_state = 28},
-  [284] = {.lex_state = 26},
-  [285] = {.lex_state = 26},
+  [283] = {.lex_state = 0},
+  [284] = {.lex_state = 0},
+  [285] = {.lex_state = 0},
   [286] = {.lex_state = 0},
   [287] = {.lex_state = 0},
   [288] = {.lex_state = 0},
@@ -2939,81 +2940,81 @@ static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [300] = {.lex_state = 0},
   [301] = {.lex_state = 0},
   [302] = {.lex_state = 0},
-  [303] = {.lex_state = 0},
-  [304] = {.lex_state = 0},
+  [303] = {.lex_state = 28},
+  [304] = {.lex_state = 26},
   [305] = {.lex_state = 0},
-  [306] = {.lex_state = 0},
+  [306] = {.lex_state = 14},
   [307] = {.lex_state = 26},
   [308] = {.lex_state = 0},
-  [309] = {.lex_state = 14},
-  [310] = {.lex_state = 26},
+  [309] = {.lex_state = 0},
+  [310] = {.lex_state = 0},
   [311] = {.lex_state = 0},
   [312] = {.lex_state = 0},
-  [313] = {.lex_state = 26},
+  [313] = {.lex_state = 0},
   [314] = {.lex_state = 0},
   [315] = {.lex_state = 0},
-  [316] = {.lex_state = 26},
+  [316] = {.lex_state = 0},
   [317] = {.lex_state = 0},
-  [318] = {.lex_state = 0},
+  [318] = {.lex_state = 26},
   [319] = {.lex_state = 0},
   [320] = {.lex_state = 0},
-  [321] = {.lex_state = 28},
+  [321] = {.lex_state = 0},
   [322] = {.lex_state = 0},
   [323] = {.lex_state = 0},
   [324] = {.lex_state = 0},
   [325] = {.lex_state = 0},
-  [326] = {.lex_state = 0},
+  [326] = {.lex_state = 26},
   [327] = {.lex_state = 0},
-  [328] = {.lex_state = 0},
-  [329] = {.lex_state = 0},
+  [328] = {.lex_state = 26},
+  [329] = {.lex_state = 26},
   [330] = {.lex_state = 0},
-  [331] = {.lex_state = 26},
-  [332] = {.lex_state = 26},
+  [331] = {.lex_state = 0},
+  [332] = {.lex_state = 22},
   [333] = {.lex_state = 0},
   [334] = {.lex_state = 26},
   [335] = {.lex_state = 0},
   [336] = {.lex_state = 26},
-  [337] = {.lex_state = 0},
+  [337] = {.lex_state = 26},
   [338] = {.lex_state = 0},
-  [339] = {.lex_state = 26},
-  [340] = {.lex_state = 0},
-  [341] = {.lex_state = 0},
-  [342] = {.lex_state = 26},
+  [339] = {.lex_state = 0},
+  [340] = {.lex_state = 26},
+  [341] = {.lex_state = 26},
+  [342] = {.lex_state = 0},
   [343] = {.lex_state = 0},
-  [344] = {.lex_state = 0},
-  [345] = {.lex_state = 26},
-  [346] = {.lex_state = 26},
-  [347] = {.lex_state = 0},
-  [348] = {.lex_state = 0},
+  [344] = {.lex_state = 26},
+  [345] = {.lex_state = 0},
+  [346] = {.lex_state = 0},
+  [347] = {.lex_state = 26},
+  [348] = {.lex_state = 26},
   [349] = {.lex_state = 0},
-  [350] = {.lex_state = 26},
-  [351] = {.lex_state = 121},
-  [352] = {.lex_state = 0},
+  [350] = {.lex_state = 0},
+  [351] = {.lex_state = 0},
+  [352] = {.lex_state = 26},
   [353] = {.lex_state = 0},
   [354] = {.lex_state = 0},
   [355] = {.lex_state = 0},
-  [356] = {.lex_state = 26},
+  [356] = {.lex_state = 0},
   [357] = {.lex_state = 0},
-  [358] = {.lex_state = 0},
+  [358] = {.lex_state = 26},
   [359] = {.lex_state = 0},
   [360] = {.lex_state = 0},
   [361] = {.lex_state = 0},
-  [362] = {.lex_state = 0},
+  [362] = {.lex_state = 26},
   [363] = {.lex_state = 26},
   [364] = {.lex_state = 0},
-  [365] = {.lex_state = 26},
+  [365] = {.lex_state = 0},
   [366] = {.lex_state = 26},
-  [367] = {.lex_state = 0},
-  [368] = {.lex_state = 26},
-  [369] = {.lex_state = 26},
-  [370] = {.lex_state = 0},
-  [371] = {.lex_state = 26},
+  [367] = {.lex_state = 26},
+  [368] = {.lex_state = 0},
+  [369] = {.lex_state = 0},
+  [370] = {.lex_state = 26},
+  [371] = {.lex_state = 0},
   [372] = {.lex_state = 0},
   [373] = {.lex_state = 0},
   [374] = {.lex_state = 26},
-  [375] = {.lex_state = 26},
+  [375] = {.lex_state = 0},
   [376] = {.lex_state = 0},
-  [377] = {.lex_state = 0},
+  [377] = {.lex_state = 26},
   [378] = {.lex_state = 0},
   [379] = {.lex_state = 0},
   [380] = {.lex_state = 0},
@@ -3023,81 +3024,82 @@ static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [384] = {.lex_state = 0},
   [385] = {.lex_state = 0},
   [386] = {.lex_state = 26},
-  [387] = {.lex_state = 26},
+  [387] = {.lex_state = 0},
   [388] = {.lex_state = 0},
   [389] = {.lex_state = 0},
-  [390] = {.lex_state = 26},
-  [391] = {.lex_state = 0},
-  [392] = {.lex_state = 26},
+  [390] = {.lex_state = 0},
+  [391] = {.lex_state = 26},
+  [392] = {.lex_state = 0},
   [393] = {.lex_state = 0},
-  [394] = {.lex_state = 0},
+  [394] = {.lex_state = 26},
   [395] = {.lex_state = 0},
-  [396] = {.lex_state = 0},
-  [397] = {.lex_state = 0},
+  [396] = {.lex_state = 26},
+  [397] = {.lex_state = 26},
   [398] = {.lex_state = 0},
-  [399] = {.lex_state = 26},
+  [399] = {.lex_state = 0},
   [400] = {.lex_state = 0},
   [401] = {.lex_state = 0},
   [402] = {.lex_state = 0},
   [403] = {.lex_state = 0},
-  [404] = {.lex_state = 26},
+  [404] = {.lex_state = 0},
   [405] = {.lex_state = 0},
-  [406] = {.lex_state = 26},
+  [406] = {.lex_state = 0},
   [407] = {.lex_state = 0},
-  [408] = {.lex_state = 26},
-  [409] = {.lex_state = 0},
+  [408] = {.lex_state = 0},
+  [409] = {.lex_state = 26},
   [410] = {.lex_state = 0},
-  [411] = {.lex_state = 0},
+  [411] = {.lex_state = 26},
   [412] = {.lex_state = 0},
-  [413] = {.lex_state = 26},
+  [413] = {.lex_state = 0},
   [414] = {.lex_state = 0},
   [415] = {.lex_state = 0},
   [416] = {.lex_state = 0},
   [417] = {.lex_state = 0},
   [418] = {.lex_state = 0},
   [419] = {.lex_state = 0},
-  [420] = {.lex_state = 0},
-  [421] = {.lex_state = 0},
+  [420] = {.lex_state = 26},
+  [421] = {.lex_state = 26},
   [422] = {.lex_state = 0},
-  [423] = {.lex_state = 26},
-  [424] = {.lex_state = 0},
+  [423] = {.lex_state = 0},
+  [424] = {.lex_state = 26},
   [425] = {.lex_state = 0},
-  [426] = {.lex_state = 26},
+  [426] = {.lex_state = 0},
   [427] = {.lex_state = 0},
   [428] = {.lex_state = 0},
-  [429] = {.lex_state = 0},
-  [430] = {.lex_state = 26},
-  [431] = {.lex_state = 26},
+  [429] = {.lex_state = 26},
+  [430] = {.lex_state = 0},
+  [431] = {.lex_state = 0},
   [432] = {.lex_state = 0},
-  [433] = {.lex_state = 0},
-  [434] = {.lex_state = 0},
-  [435] = {.lex_state = 26},
-  [436] = {.lex_state = 0},
+  [433] = {.lex_state = 26},
+  [434] = {.lex_state = 26},
+  [435] = {.lex_state = 0},
+  [436] = {.lex_state = 26},
   [437] = {.lex_state = 0},
   [438] = {.lex_state = 0},
   [439] = {.lex_state = 0},
   [440] = {.lex_state = 0},
-  [441] = {.lex_state = 22},
+  [441] = {.lex_state = 0},
   [442] = {.lex_state = 0},
-  [443] = {.lex_state = 26},
+  [443] = {.lex_state = 0},
   [444] = {.lex_state = 0},
-  [445] = {.lex_state = 0},
+  [445] = {.lex_state = 26},
   [446] = {.lex_state = 0},
-  [447] = {.lex_state = 26},
-  [448] = {.lex_state = 0},
+  [447] = {.lex_state = 0},
+  [448] = {.lex_state = 26},
   [449] = {.lex_state = 0},
-  [450] = {.lex_state = 26},
-  [451] = {.lex_state = 0},
+  [450] = {.lex_state = 0},
+  [451] = {.lex_state = 121},
   [452] = {.lex_state = 0},
   [453] = {.lex_state = 0},
   [454] = {.lex_state = 0},
-  [455] = {.lex_state = 26},
-  [456] = {.lex_state = 0},
-  [457] = {.lex_state = 26},
+  [455] = {.lex_state = 0},
+  [456] = {.lex_state = 26},
+  [457] = {.lex_state = 0},
   [458] = {.lex_state = 0},
   [459] = {.lex_state = 0},
-  [460] = {(TSStateId)(-1)},
+  [460] = {.lex_state = 0},
   [461] = {(TSStateId)(-1)},
+  [462] = {(TSStateId)(-1)},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
@@ -3159,18 +3161,18 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_o] = ACTIONS(1),
   },
   [1] = {
-    [sym_source_file] = STATE(449),
+    [sym_source_file] = STATE(398),
     [sym_comment] = STATE(1),
     [sym_import] = STATE(117),
-    [sym_metadata_ci] = STATE(448),
-    [sym_metadata] = STATE(445),
-    [sym_component] = STATE(140),
-    [sym_comb_or_static] = STATE(260),
-    [sym_primitive] = STATE(140),
-    [sym_extern] = STATE(140),
-    [sym_static_annotation] = STATE(204),
+    [sym_metadata_ci] = STATE(405),
+    [sym_metadata] = STATE(417),
+    [sym_component] = STATE(136),
+    [sym_comb_or_static] = STATE(298),
+    [sym_primitive] = STATE(136),
+    [sym_extern] = STATE(136),
+    [sym_static_annotation] = STATE(219),
     [aux_sym_source_file_repeat1] = STATE(19),
-    [aux_sym_source_file_repeat2] = STATE(21),
+    [aux_sym_source_file_repeat2] = STATE(22),
     [ts_builtin_sym_end] = ACTIONS(7),
     [anon_sym_SLASH_SLASH] = ACTIONS(3),
     [aux_sym_comment_token2] = ACTIONS(5),
@@ -3214,17 +3216,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_ident,
     STATE(2), 1,
       sym_comment,
-    STATE(7), 1,
+    STATE(6), 1,
       aux_sym_seq_repeat1,
-    STATE(26), 1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3245,33 +3247,33 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_seq,
     ACTIONS(31), 1,
       anon_sym_par,
-    ACTIONS(33), 1,
-      anon_sym_if,
-    ACTIONS(35), 1,
-      anon_sym_while,
-    ACTIONS(37), 1,
-      anon_sym_static,
-    ACTIONS(39), 1,
-      anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
     ACTIONS(45), 1,
       anon_sym_RBRACE,
+    ACTIONS(47), 1,
+      anon_sym_if,
+    ACTIONS(49), 1,
+      anon_sym_while,
+    ACTIONS(51), 1,
+      anon_sym_static,
+    ACTIONS(53), 1,
+      anon_sym_repeat,
     STATE(3), 1,
       sym_comment,
-    STATE(11), 1,
-      aux_sym_seq_repeat1,
     STATE(26), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
-      sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(188), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(427), 1,
+      sym_control_inner,
+    STATE(441), 1,
+      sym_stmt,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3304,21 +3306,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(47), 1,
+    ACTIONS(55), 1,
       anon_sym_RBRACE,
     STATE(4), 1,
       sym_comment,
-    STATE(10), 1,
+    STATE(5), 1,
       aux_sym_seq_repeat1,
-    STATE(26), 1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3351,21 +3353,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(45), 1,
+    ACTIONS(57), 1,
       anon_sym_RBRACE,
     STATE(5), 1,
       sym_comment,
-    STATE(7), 1,
+    STATE(9), 1,
       aux_sym_seq_repeat1,
-    STATE(26), 1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3398,21 +3400,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(47), 1,
+    ACTIONS(59), 1,
       anon_sym_RBRACE,
     STATE(6), 1,
       sym_comment,
-    STATE(7), 1,
+    STATE(9), 1,
       aux_sym_seq_repeat1,
-    STATE(26), 1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3420,45 +3422,46 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [335] = 19,
+  [335] = 20,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(49), 1,
+    ACTIONS(23), 1,
       anon_sym_SEMI,
-    ACTIONS(52), 1,
-      anon_sym_RBRACE,
-    ACTIONS(54), 1,
+    ACTIONS(27), 1,
       anon_sym_invoke,
-    ACTIONS(57), 1,
+    ACTIONS(29), 1,
       anon_sym_seq,
-    ACTIONS(60), 1,
+    ACTIONS(31), 1,
       anon_sym_par,
-    ACTIONS(63), 1,
+    ACTIONS(33), 1,
       anon_sym_if,
-    ACTIONS(66), 1,
+    ACTIONS(35), 1,
       anon_sym_while,
-    ACTIONS(69), 1,
+    ACTIONS(37), 1,
       anon_sym_static,
-    ACTIONS(72), 1,
+    ACTIONS(39), 1,
       anon_sym_repeat,
-    ACTIONS(75), 1,
+    ACTIONS(41), 1,
       anon_sym_AT,
-    ACTIONS(78), 1,
+    ACTIONS(43), 1,
       sym_ident,
-    STATE(26), 1,
+    ACTIONS(59), 1,
+      anon_sym_RBRACE,
+    STATE(7), 1,
+      sym_comment,
+    STATE(11), 1,
+      aux_sym_seq_repeat1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(7), 2,
-      sym_comment,
-      aux_sym_seq_repeat1,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3466,7 +3469,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [400] = 20,
+  [402] = 20,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -3491,21 +3494,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(81), 1,
+    ACTIONS(57), 1,
       anon_sym_RBRACE,
-    STATE(5), 1,
-      aux_sym_seq_repeat1,
     STATE(8), 1,
       sym_comment,
-    STATE(26), 1,
+    STATE(10), 1,
+      aux_sym_seq_repeat1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3513,46 +3516,45 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [467] = 20,
+  [469] = 19,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(23), 1,
+    ACTIONS(61), 1,
       anon_sym_SEMI,
-    ACTIONS(27), 1,
+    ACTIONS(64), 1,
+      anon_sym_RBRACE,
+    ACTIONS(66), 1,
       anon_sym_invoke,
-    ACTIONS(29), 1,
+    ACTIONS(69), 1,
       anon_sym_seq,
-    ACTIONS(31), 1,
+    ACTIONS(72), 1,
       anon_sym_par,
-    ACTIONS(33), 1,
+    ACTIONS(75), 1,
       anon_sym_if,
-    ACTIONS(35), 1,
+    ACTIONS(78), 1,
       anon_sym_while,
-    ACTIONS(37), 1,
+    ACTIONS(81), 1,
       anon_sym_static,
-    ACTIONS(39), 1,
+    ACTIONS(84), 1,
       anon_sym_repeat,
-    ACTIONS(41), 1,
+    ACTIONS(87), 1,
       anon_sym_AT,
-    ACTIONS(43), 1,
+    ACTIONS(90), 1,
       sym_ident,
-    ACTIONS(83), 1,
-      anon_sym_RBRACE,
-    STATE(6), 1,
-      aux_sym_seq_repeat1,
-    STATE(9), 1,
-      sym_comment,
-    STATE(26), 1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(9), 2,
+      sym_comment,
+      aux_sym_seq_repeat1,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3585,21 +3587,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(85), 1,
+    ACTIONS(93), 1,
       anon_sym_RBRACE,
-    STATE(7), 1,
+    STATE(9), 1,
       aux_sym_seq_repeat1,
     STATE(10), 1,
       sym_comment,
-    STATE(26), 1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3632,21 +3634,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(87), 1,
+    ACTIONS(95), 1,
       anon_sym_RBRACE,
-    STATE(7), 1,
+    STATE(9), 1,
       aux_sym_seq_repeat1,
     STATE(11), 1,
       sym_comment,
-    STATE(26), 1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3679,21 +3681,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(85), 1,
+    ACTIONS(93), 1,
       anon_sym_RBRACE,
     STATE(12), 1,
       sym_comment,
     STATE(14), 1,
       aux_sym_seq_repeat1,
-    STATE(26), 1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3726,21 +3728,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(87), 1,
+    ACTIONS(95), 1,
       anon_sym_RBRACE,
-    STATE(2), 1,
-      aux_sym_seq_repeat1,
     STATE(13), 1,
       sym_comment,
-    STATE(26), 1,
+    STATE(15), 1,
+      aux_sym_seq_repeat1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3773,21 +3775,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(89), 1,
+    ACTIONS(97), 1,
       anon_sym_RBRACE,
-    STATE(7), 1,
+    STATE(9), 1,
       aux_sym_seq_repeat1,
     STATE(14), 1,
       sym_comment,
-    STATE(26), 1,
+    STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(76), 1,
       sym_stmt,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(185), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3795,7 +3797,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [869] = 19,
+  [869] = 20,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -3808,31 +3810,33 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_seq,
     ACTIONS(31), 1,
       anon_sym_par,
-    ACTIONS(41), 1,
-      anon_sym_AT,
-    ACTIONS(43), 1,
-      sym_ident,
-    ACTIONS(91), 1,
+    ACTIONS(33), 1,
       anon_sym_if,
-    ACTIONS(93), 1,
+    ACTIONS(35), 1,
       anon_sym_while,
-    ACTIONS(95), 1,
+    ACTIONS(37), 1,
       anon_sym_static,
-    ACTIONS(97), 1,
+    ACTIONS(39), 1,
       anon_sym_repeat,
+    ACTIONS(41), 1,
+      anon_sym_AT,
+    ACTIONS(43), 1,
+      sym_ident,
+    ACTIONS(99), 1,
+      anon_sym_RBRACE,
+    STATE(9), 1,
+      aux_sym_seq_repeat1,
     STATE(15), 1,
       sym_comment,
     STATE(25), 1,
       aux_sym_io_port_repeat1,
-    STATE(79), 1,
+    STATE(76), 1,
+      sym_stmt,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(189), 1,
+    STATE(196), 1,
       sym_static_annotation,
-    STATE(357), 1,
-      sym_stmt,
-    STATE(394), 1,
-      sym_control_inner,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3840,7 +3844,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [933] = 18,
+  [936] = 18,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -3857,25 +3861,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(91), 1,
+    ACTIONS(47), 1,
       anon_sym_if,
-    ACTIONS(93), 1,
+    ACTIONS(49), 1,
       anon_sym_while,
-    ACTIONS(95), 1,
+    ACTIONS(51), 1,
       anon_sym_static,
-    ACTIONS(97), 1,
+    ACTIONS(53), 1,
       anon_sym_repeat,
     STATE(16), 1,
       sym_comment,
-    STATE(25), 1,
+    STATE(26), 1,
       aux_sym_io_port_repeat1,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(189), 1,
+    STATE(188), 1,
       sym_static_annotation,
-    STATE(378), 1,
+    STATE(387), 1,
       sym_stmt,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3883,7 +3887,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [994] = 18,
+  [997] = 18,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -3900,25 +3904,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(91), 1,
+    ACTIONS(47), 1,
       anon_sym_if,
-    ACTIONS(93), 1,
+    ACTIONS(49), 1,
       anon_sym_while,
-    ACTIONS(95), 1,
+    ACTIONS(51), 1,
       anon_sym_static,
-    ACTIONS(97), 1,
+    ACTIONS(53), 1,
       anon_sym_repeat,
     STATE(17), 1,
       sym_comment,
-    STATE(25), 1,
+    STATE(26), 1,
       aux_sym_io_port_repeat1,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(189), 1,
+    STATE(188), 1,
       sym_static_annotation,
-    STATE(421), 1,
+    STATE(419), 1,
       sym_stmt,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3926,7 +3930,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [1055] = 18,
+  [1058] = 18,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -3943,25 +3947,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AT,
     ACTIONS(43), 1,
       sym_ident,
-    ACTIONS(91), 1,
+    ACTIONS(47), 1,
       anon_sym_if,
-    ACTIONS(93), 1,
+    ACTIONS(49), 1,
       anon_sym_while,
-    ACTIONS(95), 1,
+    ACTIONS(51), 1,
       anon_sym_static,
-    ACTIONS(97), 1,
+    ACTIONS(53), 1,
       anon_sym_repeat,
     STATE(18), 1,
       sym_comment,
-    STATE(25), 1,
+    STATE(26), 1,
       aux_sym_io_port_repeat1,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(189), 1,
+    STATE(188), 1,
       sym_static_annotation,
-    STATE(425), 1,
+    STATE(423), 1,
       sym_stmt,
-    STATE(45), 7,
+    STATE(57), 7,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3969,7 +3973,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [1116] = 19,
+  [1119] = 19,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -3988,53 +3992,29 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_extern,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(99), 1,
+    ACTIONS(101), 1,
       ts_builtin_sym_end,
     STATE(19), 1,
       sym_comment,
-    STATE(22), 1,
+    STATE(20), 1,
       aux_sym_source_file_repeat2,
-    STATE(83), 1,
+    STATE(82), 1,
       aux_sym_source_file_repeat1,
     STATE(117), 1,
       sym_import,
-    STATE(204), 1,
+    STATE(219), 1,
       sym_static_annotation,
-    STATE(260), 1,
+    STATE(298), 1,
       sym_comb_or_static,
-    STATE(429), 1,
+    STATE(351), 1,
       sym_metadata,
-    STATE(448), 1,
+    STATE(405), 1,
       sym_metadata_ci,
-    STATE(140), 3,
+    STATE(136), 3,
       sym_component,
       sym_primitive,
       sym_extern,
-  [1176] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(20), 1,
-      sym_comment,
-    ACTIONS(103), 3,
-      anon_sym_EQ,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(101), 12,
-      anon_sym_SEMI,
-      anon_sym_LBRACE,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-      anon_sym_with,
-  [1205] = 16,
+  [1179] = 16,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -4051,25 +4031,49 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_extern,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(99), 1,
+    ACTIONS(103), 1,
       ts_builtin_sym_end,
-    STATE(21), 1,
+    STATE(20), 1,
       sym_comment,
-    STATE(27), 1,
+    STATE(24), 1,
       aux_sym_source_file_repeat2,
-    STATE(204), 1,
+    STATE(219), 1,
       sym_static_annotation,
-    STATE(260), 1,
+    STATE(298), 1,
       sym_comb_or_static,
-    STATE(429), 1,
+    STATE(335), 1,
       sym_metadata,
-    STATE(448), 1,
+    STATE(405), 1,
       sym_metadata_ci,
-    STATE(140), 3,
+    STATE(136), 3,
       sym_component,
       sym_primitive,
       sym_extern,
-  [1256] = 16,
+  [1230] = 5,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(21), 1,
+      sym_comment,
+    ACTIONS(107), 3,
+      anon_sym_EQ,
+      anon_sym_GT,
+      anon_sym_LT,
+    ACTIONS(105), 12,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_QMARK,
+      anon_sym_with,
+  [1259] = 16,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -4086,40 +4090,40 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_extern,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(105), 1,
+    ACTIONS(101), 1,
       ts_builtin_sym_end,
     STATE(22), 1,
       sym_comment,
-    STATE(27), 1,
+    STATE(24), 1,
       aux_sym_source_file_repeat2,
-    STATE(204), 1,
+    STATE(219), 1,
       sym_static_annotation,
-    STATE(260), 1,
+    STATE(298), 1,
       sym_comb_or_static,
-    STATE(409), 1,
+    STATE(351), 1,
       sym_metadata,
-    STATE(448), 1,
+    STATE(405), 1,
       sym_metadata_ci,
-    STATE(140), 3,
+    STATE(136), 3,
       sym_component,
       sym_primitive,
       sym_extern,
-  [1307] = 7,
+  [1310] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(109), 1,
+    ACTIONS(111), 1,
       anon_sym_LBRACK,
-    ACTIONS(113), 1,
+    ACTIONS(115), 1,
       anon_sym_DOT,
     STATE(23), 1,
       sym_comment,
-    ACTIONS(111), 3,
+    ACTIONS(113), 3,
       anon_sym_EQ,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(107), 9,
+    ACTIONS(109), 9,
       anon_sym_SEMI,
       anon_sym_RPAREN,
       anon_sym_EQ_EQ,
@@ -4129,196 +4133,173 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_QMARK,
-  [1339] = 14,
+  [1342] = 12,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(115), 1,
-      anon_sym_RBRACE,
-    ACTIONS(117), 1,
-      anon_sym_comb,
     ACTIONS(119), 1,
-      anon_sym_group,
-    ACTIONS(121), 1,
-      anon_sym_AT,
-    ACTIONS(123), 1,
-      sym_ident,
-    STATE(24), 1,
+      anon_sym_component,
+    ACTIONS(122), 1,
+      anon_sym_comb,
+    ACTIONS(125), 1,
+      anon_sym_primitive,
+    ACTIONS(128), 1,
+      anon_sym_extern,
+    ACTIONS(131), 1,
+      anon_sym_static,
+    STATE(219), 1,
+      sym_static_annotation,
+    STATE(298), 1,
+      sym_comb_or_static,
+    ACTIONS(117), 2,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+    STATE(24), 2,
       sym_comment,
-    STATE(40), 1,
-      aux_sym_wires_inner_repeat1,
-    STATE(197), 1,
-      sym_at_attribute,
-    STATE(332), 1,
-      sym_lhs,
-    STATE(335), 1,
-      sym_wires_inner,
-    STATE(156), 2,
-      sym_group,
-      sym_wire_assignment,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [1384] = 16,
+      aux_sym_source_file_repeat2,
+    STATE(136), 3,
+      sym_component,
+      sym_primitive,
+      sym_extern,
+  [1383] = 16,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(41), 1,
       anon_sym_AT,
-    ACTIONS(125), 1,
+    ACTIONS(134), 1,
       anon_sym_SEMI,
-    ACTIONS(127), 1,
+    ACTIONS(136), 1,
       anon_sym_invoke,
-    ACTIONS(129), 1,
+    ACTIONS(138), 1,
       anon_sym_seq,
-    ACTIONS(131), 1,
+    ACTIONS(140), 1,
       anon_sym_par,
-    ACTIONS(133), 1,
+    ACTIONS(142), 1,
       anon_sym_if,
-    ACTIONS(135), 1,
+    ACTIONS(144), 1,
       anon_sym_while,
-    ACTIONS(137), 1,
+    ACTIONS(146), 1,
       anon_sym_static,
-    ACTIONS(139), 1,
+    ACTIONS(148), 1,
       anon_sym_repeat,
-    ACTIONS(141), 1,
+    ACTIONS(150), 1,
       sym_ident,
     STATE(25), 1,
       sym_comment,
-    STATE(28), 1,
+    STATE(35), 1,
       aux_sym_io_port_repeat1,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(196), 1,
+    STATE(197), 1,
       sym_static_annotation,
-  [1433] = 16,
+  [1432] = 16,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(41), 1,
       anon_sym_AT,
-    ACTIONS(125), 1,
+    ACTIONS(134), 1,
       anon_sym_SEMI,
-    ACTIONS(127), 1,
+    ACTIONS(136), 1,
       anon_sym_invoke,
-    ACTIONS(129), 1,
+    ACTIONS(138), 1,
       anon_sym_seq,
-    ACTIONS(131), 1,
+    ACTIONS(140), 1,
       anon_sym_par,
-    ACTIONS(141), 1,
+    ACTIONS(150), 1,
       sym_ident,
-    ACTIONS(143), 1,
+    ACTIONS(152), 1,
       anon_sym_if,
-    ACTIONS(145), 1,
+    ACTIONS(154), 1,
       anon_sym_while,
-    ACTIONS(147), 1,
+    ACTIONS(156), 1,
       anon_sym_static,
-    ACTIONS(149), 1,
+    ACTIONS(158), 1,
       anon_sym_repeat,
     STATE(26), 1,
       sym_comment,
-    STATE(28), 1,
+    STATE(35), 1,
       aux_sym_io_port_repeat1,
-    STATE(79), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(194), 1,
+    STATE(179), 1,
       sym_static_annotation,
-  [1482] = 12,
+  [1481] = 14,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(153), 1,
-      anon_sym_component,
-    ACTIONS(156), 1,
-      anon_sym_comb,
-    ACTIONS(159), 1,
-      anon_sym_primitive,
+    ACTIONS(160), 1,
+      anon_sym_RBRACE,
     ACTIONS(162), 1,
-      anon_sym_extern,
-    ACTIONS(165), 1,
-      anon_sym_static,
-    STATE(204), 1,
-      sym_static_annotation,
-    STATE(260), 1,
-      sym_comb_or_static,
-    ACTIONS(151), 2,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-    STATE(27), 2,
-      sym_comment,
-      aux_sym_source_file_repeat2,
-    STATE(140), 3,
-      sym_component,
-      sym_primitive,
-      sym_extern,
-  [1523] = 7,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(168), 1,
-      anon_sym_SEMI,
-    ACTIONS(172), 1,
+      anon_sym_comb,
+    ACTIONS(164), 1,
+      anon_sym_group,
+    ACTIONS(166), 1,
       anon_sym_AT,
-    STATE(79), 1,
-      sym_at_attribute,
-    STATE(28), 2,
-      sym_comment,
-      aux_sym_io_port_repeat1,
-    ACTIONS(170), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
+    ACTIONS(168), 1,
       sym_ident,
-  [1553] = 12,
+    STATE(27), 1,
+      sym_comment,
+    STATE(32), 1,
+      aux_sym_wires_inner_repeat1,
+    STATE(183), 1,
+      sym_at_attribute,
+    STATE(328), 1,
+      sym_lhs,
+    STATE(449), 1,
+      sym_wires_inner,
+    STATE(173), 2,
+      sym_group,
+      sym_wire_assignment,
+    STATE(326), 2,
+      sym_hole,
+      sym_port,
+  [1526] = 12,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
+    ACTIONS(170), 1,
       anon_sym_LPAREN,
-    ACTIONS(177), 1,
+    ACTIONS(172), 1,
       anon_sym_BANG,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(176), 1,
       sym_number,
-    STATE(29), 1,
+    STATE(28), 1,
       sym_comment,
     STATE(80), 1,
       sym_expr,
-    STATE(422), 1,
+    STATE(373), 1,
       sym_term,
-    STATE(434), 1,
+    STATE(382), 1,
       sym_switch,
-    STATE(68), 2,
+    STATE(42), 2,
       sym_base_expr,
       sym_cmp_expr,
-    STATE(67), 3,
+    STATE(75), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [1593] = 5,
+  [1566] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(30), 1,
+    STATE(29), 1,
       sym_comment,
-    ACTIONS(185), 2,
+    ACTIONS(180), 3,
+      anon_sym_EQ,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(183), 10,
+    ACTIONS(178), 9,
       anon_sym_SEMI,
-      anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
@@ -4327,218 +4308,132 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_QMARK,
-  [1619] = 12,
+  [1592] = 12,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
+    ACTIONS(170), 1,
       anon_sym_LPAREN,
-    ACTIONS(177), 1,
+    ACTIONS(172), 1,
       anon_sym_BANG,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(176), 1,
       sym_number,
-    STATE(31), 1,
+    STATE(30), 1,
       sym_comment,
     STATE(84), 1,
       sym_expr,
-    STATE(398), 1,
-      sym_switch,
-    STATE(422), 1,
+    STATE(373), 1,
       sym_term,
-    STATE(68), 2,
+    STATE(418), 1,
+      sym_switch,
+    STATE(42), 2,
       sym_base_expr,
       sym_cmp_expr,
-    STATE(67), 3,
+    STATE(75), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [1659] = 6,
+  [1632] = 12,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(191), 1,
-      anon_sym_else,
-    STATE(32), 1,
-      sym_comment,
-    ACTIONS(187), 3,
-      anon_sym_SEMI,
+    ACTIONS(182), 1,
       anon_sym_RBRACE,
+    ACTIONS(184), 1,
+      anon_sym_comb,
+    ACTIONS(187), 1,
+      anon_sym_group,
+    ACTIONS(190), 1,
       anon_sym_AT,
-    ACTIONS(189), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
+    ACTIONS(193), 1,
       sym_ident,
-  [1687] = 12,
+    STATE(183), 1,
+      sym_at_attribute,
+    STATE(328), 1,
+      sym_lhs,
+    STATE(31), 2,
+      sym_comment,
+      aux_sym_wires_inner_repeat1,
+    STATE(173), 2,
+      sym_group,
+      sym_wire_assignment,
+    STATE(326), 2,
+      sym_hole,
+      sym_port,
+  [1672] = 13,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(177), 1,
-      anon_sym_BANG,
-    ACTIONS(179), 1,
+    ACTIONS(162), 1,
+      anon_sym_comb,
+    ACTIONS(164), 1,
+      anon_sym_group,
+    ACTIONS(166), 1,
+      anon_sym_AT,
+    ACTIONS(168), 1,
       sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(33), 1,
-      sym_comment,
-    STATE(82), 1,
-      sym_expr,
-    STATE(420), 1,
-      sym_switch,
-    STATE(422), 1,
-      sym_term,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
-      sym_hole,
-      sym_port,
-      sym_literal,
-  [1727] = 12,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(193), 1,
+    ACTIONS(196), 1,
       anon_sym_RBRACE,
-    ACTIONS(195), 1,
-      anon_sym_comb,
-    ACTIONS(198), 1,
-      anon_sym_group,
-    ACTIONS(201), 1,
-      anon_sym_AT,
-    ACTIONS(204), 1,
-      sym_ident,
-    STATE(197), 1,
+    STATE(31), 1,
+      aux_sym_wires_inner_repeat1,
+    STATE(32), 1,
+      sym_comment,
+    STATE(183), 1,
       sym_at_attribute,
-    STATE(332), 1,
+    STATE(328), 1,
       sym_lhs,
-    STATE(34), 2,
-      sym_comment,
-      aux_sym_wires_inner_repeat1,
-    STATE(156), 2,
+    STATE(173), 2,
       sym_group,
       sym_wire_assignment,
-    STATE(334), 2,
+    STATE(326), 2,
       sym_hole,
       sym_port,
-  [1767] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(211), 1,
-      anon_sym_else,
-    STATE(35), 1,
-      sym_comment,
-    ACTIONS(207), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(209), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
-      sym_ident,
-  [1795] = 12,
+  [1714] = 12,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
+    ACTIONS(170), 1,
       anon_sym_LPAREN,
-    ACTIONS(177), 1,
+    ACTIONS(172), 1,
       anon_sym_BANG,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(176), 1,
       sym_number,
-    STATE(36), 1,
+    STATE(33), 1,
       sym_comment,
-    STATE(81), 1,
+    STATE(79), 1,
       sym_expr,
-    STATE(417), 1,
+    STATE(343), 1,
       sym_switch,
-    STATE(422), 1,
+    STATE(373), 1,
       sym_term,
-    STATE(68), 2,
+    STATE(42), 2,
       sym_base_expr,
       sym_cmp_expr,
-    STATE(67), 3,
+    STATE(75), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [1835] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(37), 1,
-      sym_comment,
-    ACTIONS(213), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(215), 9,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_else,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
-      sym_ident,
-  [1861] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(221), 1,
-      anon_sym_else,
-    STATE(38), 1,
-      sym_comment,
-    ACTIONS(217), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(219), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
-      sym_ident,
-  [1889] = 5,
+  [1754] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(39), 1,
+    STATE(34), 1,
       sym_comment,
-    ACTIONS(225), 3,
-      anon_sym_EQ,
+    ACTIONS(200), 2,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(223), 9,
+    ACTIONS(198), 10,
       anon_sym_SEMI,
+      anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
@@ -4547,48 +4442,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_QMARK,
-  [1915] = 13,
+  [1780] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(117), 1,
-      anon_sym_comb,
-    ACTIONS(119), 1,
-      anon_sym_group,
-    ACTIONS(121), 1,
+    ACTIONS(202), 1,
+      anon_sym_SEMI,
+    ACTIONS(206), 1,
       anon_sym_AT,
-    ACTIONS(123), 1,
-      sym_ident,
-    ACTIONS(227), 1,
-      anon_sym_RBRACE,
-    STATE(34), 1,
-      aux_sym_wires_inner_repeat1,
-    STATE(40), 1,
-      sym_comment,
-    STATE(197), 1,
+    STATE(81), 1,
       sym_at_attribute,
-    STATE(332), 1,
-      sym_lhs,
-    STATE(156), 2,
-      sym_group,
-      sym_wire_assignment,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [1957] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(231), 1,
-      anon_sym_LPAREN,
-    STATE(41), 1,
+    STATE(35), 2,
       sym_comment,
-    ACTIONS(229), 2,
-      anon_sym_SEMI,
-      anon_sym_AT,
-    ACTIONS(233), 8,
+      aux_sym_io_port_repeat1,
+    ACTIONS(204), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -4597,18 +4465,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [1984] = 5,
+  [1810] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(42), 1,
+    ACTIONS(213), 1,
+      anon_sym_else,
+    STATE(36), 1,
       sym_comment,
-    ACTIONS(235), 3,
+    ACTIONS(209), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(237), 8,
+    ACTIONS(211), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -4617,18 +4487,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2009] = 5,
+  [1838] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(43), 1,
+    ACTIONS(219), 1,
+      anon_sym_else,
+    STATE(37), 1,
       sym_comment,
-    ACTIONS(239), 3,
+    ACTIONS(215), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(241), 8,
+    ACTIONS(217), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -4637,38 +4509,41 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2034] = 5,
+  [1866] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(44), 1,
+    STATE(38), 1,
       sym_comment,
-    ACTIONS(243), 3,
+    ACTIONS(221), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(245), 8,
+    ACTIONS(223), 9,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
+      anon_sym_else,
       anon_sym_while,
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2059] = 5,
+  [1892] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(45), 1,
+    ACTIONS(229), 1,
+      anon_sym_else,
+    STATE(39), 1,
       sym_comment,
-    ACTIONS(247), 3,
+    ACTIONS(225), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(249), 8,
+    ACTIONS(227), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -4677,77 +4552,69 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2084] = 5,
+  [1920] = 12,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(46), 1,
-      sym_comment,
-    ACTIONS(251), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(253), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
+    ACTIONS(170), 1,
+      anon_sym_LPAREN,
+    ACTIONS(172), 1,
+      anon_sym_BANG,
+    ACTIONS(174), 1,
       sym_ident,
-  [2109] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(47), 1,
+    ACTIONS(176), 1,
+      sym_number,
+    STATE(40), 1,
       sym_comment,
-    ACTIONS(213), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(215), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
-      sym_ident,
-  [2134] = 5,
+    STATE(83), 1,
+      sym_expr,
+    STATE(373), 1,
+      sym_term,
+    STATE(415), 1,
+      sym_switch,
+    STATE(42), 2,
+      sym_base_expr,
+      sym_cmp_expr,
+    STATE(75), 3,
+      sym_hole,
+      sym_port,
+      sym_literal,
+  [1960] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(48), 1,
+    ACTIONS(233), 1,
+      anon_sym_EQ_EQ,
+    ACTIONS(235), 1,
+      anon_sym_BANG_EQ,
+    ACTIONS(237), 1,
+      anon_sym_LT_EQ,
+    ACTIONS(239), 1,
+      anon_sym_GT_EQ,
+    STATE(41), 1,
       sym_comment,
-    ACTIONS(257), 2,
+    ACTIONS(241), 2,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(255), 9,
+    ACTIONS(231), 5,
       anon_sym_SEMI,
       anon_sym_RPAREN,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_QMARK,
-  [2159] = 5,
+  [1993] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(49), 1,
+    STATE(42), 1,
       sym_comment,
-    ACTIONS(261), 2,
+    ACTIONS(245), 2,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(259), 9,
+    ACTIONS(243), 9,
       anon_sym_SEMI,
       anon_sym_RPAREN,
       anon_sym_EQ_EQ,
@@ -4757,153 +4624,124 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_QMARK,
-  [2184] = 6,
+  [2018] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    STATE(50), 1,
+    STATE(43), 1,
       sym_comment,
-    ACTIONS(261), 2,
+    ACTIONS(249), 2,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(259), 8,
+    ACTIONS(247), 9,
       anon_sym_SEMI,
       anon_sym_RPAREN,
+      anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_QMARK,
-  [2211] = 7,
+  [2043] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    STATE(51), 1,
+    STATE(44), 1,
       sym_comment,
-    ACTIONS(261), 2,
+    ACTIONS(241), 2,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(259), 7,
+    ACTIONS(231), 9,
       anon_sym_SEMI,
       anon_sym_RPAREN,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_QMARK,
-  [2240] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(52), 1,
-      sym_comment,
-    ACTIONS(267), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(269), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
-      sym_ident,
-  [2265] = 8,
+  [2068] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
+    ACTIONS(233), 1,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    STATE(53), 1,
+    STATE(45), 1,
       sym_comment,
-    ACTIONS(261), 2,
+    ACTIONS(241), 2,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(259), 6,
+    ACTIONS(231), 8,
       anon_sym_SEMI,
       anon_sym_RPAREN,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_QMARK,
-  [2296] = 9,
+  [2095] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
+    ACTIONS(233), 1,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
+    ACTIONS(235), 1,
       anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    ACTIONS(273), 1,
-      anon_sym_GT_EQ,
-    STATE(54), 1,
+    STATE(46), 1,
       sym_comment,
-    ACTIONS(261), 2,
+    ACTIONS(241), 2,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(259), 5,
+    ACTIONS(231), 7,
       anon_sym_SEMI,
       anon_sym_RPAREN,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_QMARK,
-  [2329] = 10,
+  [2124] = 8,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(261), 1,
-      anon_sym_LT,
-    ACTIONS(263), 1,
+    ACTIONS(233), 1,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
+    ACTIONS(235), 1,
       anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
+    ACTIONS(237), 1,
       anon_sym_LT_EQ,
-    ACTIONS(273), 1,
-      anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    STATE(55), 1,
+    STATE(47), 1,
       sym_comment,
-    ACTIONS(259), 5,
+    ACTIONS(241), 2,
+      anon_sym_GT,
+      anon_sym_LT,
+    ACTIONS(231), 6,
       anon_sym_SEMI,
       anon_sym_RPAREN,
+      anon_sym_GT_EQ,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_QMARK,
-  [2364] = 5,
+  [2155] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(56), 1,
+    STATE(48), 1,
       sym_comment,
-    ACTIONS(277), 3,
+    ACTIONS(251), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(279), 8,
+    ACTIONS(253), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -4912,7 +4750,172 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2389] = 5,
+  [2180] = 10,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    ACTIONS(233), 1,
+      anon_sym_EQ_EQ,
+    ACTIONS(235), 1,
+      anon_sym_BANG_EQ,
+    ACTIONS(237), 1,
+      anon_sym_LT_EQ,
+    ACTIONS(239), 1,
+      anon_sym_GT_EQ,
+    ACTIONS(241), 1,
+      anon_sym_LT,
+    ACTIONS(255), 1,
+      anon_sym_GT,
+    STATE(49), 1,
+      sym_comment,
+    ACTIONS(231), 5,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_QMARK,
+  [2215] = 5,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(50), 1,
+      sym_comment,
+    ACTIONS(257), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(259), 8,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_static,
+      anon_sym_repeat,
+      sym_ident,
+  [2240] = 5,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(51), 1,
+      sym_comment,
+    ACTIONS(225), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(227), 8,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_static,
+      anon_sym_repeat,
+      sym_ident,
+  [2265] = 5,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(52), 1,
+      sym_comment,
+    ACTIONS(261), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(263), 8,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_static,
+      anon_sym_repeat,
+      sym_ident,
+  [2290] = 5,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(53), 1,
+      sym_comment,
+    ACTIONS(265), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(267), 8,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_static,
+      anon_sym_repeat,
+      sym_ident,
+  [2315] = 5,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(54), 1,
+      sym_comment,
+    ACTIONS(269), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(271), 8,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_static,
+      anon_sym_repeat,
+      sym_ident,
+  [2340] = 5,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(55), 1,
+      sym_comment,
+    ACTIONS(273), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(275), 8,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_static,
+      anon_sym_repeat,
+      sym_ident,
+  [2365] = 5,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(56), 1,
+      sym_comment,
+    ACTIONS(277), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(279), 8,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_static,
+      anon_sym_repeat,
+      sym_ident,
+  [2390] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -4932,7 +4935,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2414] = 5,
+  [2415] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -4952,7 +4955,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2439] = 5,
+  [2440] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -4972,7 +4975,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2464] = 5,
+  [2465] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -4992,18 +4995,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2489] = 5,
+  [2490] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(61), 1,
       sym_comment,
-    ACTIONS(217), 3,
+    ACTIONS(297), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(219), 8,
+    ACTIONS(299), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5012,18 +5015,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2514] = 5,
+  [2515] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(62), 1,
       sym_comment,
-    ACTIONS(297), 3,
+    ACTIONS(301), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(299), 8,
+    ACTIONS(303), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5032,18 +5035,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2539] = 5,
+  [2540] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(63), 1,
       sym_comment,
-    ACTIONS(301), 3,
+    ACTIONS(305), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(303), 8,
+    ACTIONS(307), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5052,18 +5055,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2564] = 5,
+  [2565] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(64), 1,
       sym_comment,
-    ACTIONS(305), 3,
+    ACTIONS(309), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(307), 8,
+    ACTIONS(311), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5072,18 +5075,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2589] = 5,
+  [2590] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(65), 1,
       sym_comment,
-    ACTIONS(309), 3,
+    ACTIONS(313), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(311), 8,
+    ACTIONS(315), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5092,18 +5095,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2614] = 5,
+  [2615] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(66), 1,
       sym_comment,
-    ACTIONS(313), 3,
+    ACTIONS(317), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(315), 8,
+    ACTIONS(319), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5112,53 +5115,33 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2639] = 5,
+  [2640] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(67), 1,
       sym_comment,
-    ACTIONS(319), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(317), 9,
+    ACTIONS(321), 3,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-  [2664] = 5,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(323), 8,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_static,
+      anon_sym_repeat,
+      sym_ident,
+  [2665] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(68), 1,
       sym_comment,
-    ACTIONS(323), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(321), 9,
-      anon_sym_SEMI,
-      anon_sym_RPAREN,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-  [2689] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(69), 1,
-      sym_comment,
     ACTIONS(325), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
@@ -5172,12 +5155,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2714] = 5,
+  [2690] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(70), 1,
+    STATE(69), 1,
       sym_comment,
     ACTIONS(329), 3,
       anon_sym_SEMI,
@@ -5192,12 +5175,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2739] = 5,
+  [2715] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(71), 1,
+    STATE(70), 1,
       sym_comment,
     ACTIONS(333), 3,
       anon_sym_SEMI,
@@ -5212,12 +5195,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2764] = 5,
+  [2740] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(72), 1,
+    STATE(71), 1,
       sym_comment,
     ACTIONS(337), 3,
       anon_sym_SEMI,
@@ -5232,18 +5215,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2789] = 5,
+  [2765] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(73), 1,
+    ACTIONS(343), 1,
+      anon_sym_LPAREN,
+    STATE(72), 1,
       sym_comment,
-    ACTIONS(341), 3,
+    ACTIONS(341), 2,
       anon_sym_SEMI,
-      anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(343), 8,
+    ACTIONS(345), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5252,18 +5236,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2814] = 5,
+  [2792] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(74), 1,
+    STATE(73), 1,
       sym_comment,
-    ACTIONS(345), 3,
+    ACTIONS(221), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(347), 8,
+    ACTIONS(223), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5272,18 +5256,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2839] = 5,
+  [2817] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(75), 1,
+    STATE(74), 1,
       sym_comment,
-    ACTIONS(349), 3,
+    ACTIONS(347), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(351), 8,
+    ACTIONS(349), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5292,38 +5276,38 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2864] = 5,
+  [2842] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(76), 1,
+    STATE(75), 1,
       sym_comment,
-    ACTIONS(353), 3,
+    ACTIONS(353), 2,
+      anon_sym_GT,
+      anon_sym_LT,
+    ACTIONS(351), 9,
       anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(355), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
-      sym_ident,
-  [2889] = 5,
+      anon_sym_RPAREN,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_QMARK,
+  [2867] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(77), 1,
+    STATE(76), 1,
       sym_comment,
-    ACTIONS(357), 3,
+    ACTIONS(355), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(359), 8,
+    ACTIONS(357), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5332,18 +5316,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2914] = 5,
+  [2892] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(78), 1,
+    STATE(77), 1,
       sym_comment,
-    ACTIONS(361), 3,
+    ACTIONS(359), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(363), 8,
+    ACTIONS(361), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5352,17 +5336,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2939] = 5,
+  [2917] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(79), 1,
+    STATE(78), 1,
       sym_comment,
-    ACTIONS(365), 2,
+    ACTIONS(363), 3,
       anon_sym_SEMI,
+      anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(367), 8,
+    ACTIONS(365), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5371,88 +5356,80 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [2963] = 13,
+  [2942] = 13,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
+    ACTIONS(233), 1,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
+    ACTIONS(235), 1,
       anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
+    ACTIONS(237), 1,
       anon_sym_LT_EQ,
-    ACTIONS(273), 1,
+    ACTIONS(239), 1,
       anon_sym_GT_EQ,
-    ACTIONS(275), 1,
+    ACTIONS(255), 1,
       anon_sym_GT,
-    ACTIONS(369), 1,
+    ACTIONS(367), 1,
       anon_sym_SEMI,
-    ACTIONS(371), 1,
+    ACTIONS(369), 1,
       anon_sym_LT,
-    ACTIONS(373), 1,
+    ACTIONS(371), 1,
       anon_sym_PIPE,
-    ACTIONS(375), 1,
+    ACTIONS(373), 1,
       anon_sym_AMP,
-    ACTIONS(377), 1,
+    ACTIONS(375), 1,
       anon_sym_QMARK,
-    STATE(80), 1,
+    STATE(79), 1,
       sym_comment,
-  [3003] = 13,
+  [2982] = 13,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
+    ACTIONS(233), 1,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
+    ACTIONS(235), 1,
       anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
+    ACTIONS(237), 1,
       anon_sym_LT_EQ,
-    ACTIONS(273), 1,
+    ACTIONS(239), 1,
       anon_sym_GT_EQ,
-    ACTIONS(275), 1,
+    ACTIONS(255), 1,
       anon_sym_GT,
-    ACTIONS(371), 1,
+    ACTIONS(369), 1,
       anon_sym_LT,
-    ACTIONS(373), 1,
+    ACTIONS(371), 1,
       anon_sym_PIPE,
-    ACTIONS(375), 1,
+    ACTIONS(373), 1,
       anon_sym_AMP,
-    ACTIONS(377), 1,
+    ACTIONS(375), 1,
       anon_sym_QMARK,
-    ACTIONS(379), 1,
+    ACTIONS(377), 1,
       anon_sym_SEMI,
-    STATE(81), 1,
+    STATE(80), 1,
       sym_comment,
-  [3043] = 13,
+  [3022] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    ACTIONS(273), 1,
-      anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    ACTIONS(371), 1,
-      anon_sym_LT,
-    ACTIONS(373), 1,
-      anon_sym_PIPE,
-    ACTIONS(375), 1,
-      anon_sym_AMP,
-    ACTIONS(377), 1,
-      anon_sym_QMARK,
-    ACTIONS(381), 1,
-      anon_sym_SEMI,
-    STATE(82), 1,
+    STATE(81), 1,
       sym_comment,
-  [3083] = 6,
+    ACTIONS(379), 2,
+      anon_sym_SEMI,
+      anon_sym_AT,
+    ACTIONS(381), 8,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_static,
+      anon_sym_repeat,
+      sym_ident,
+  [3046] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -5461,7 +5438,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_import,
     STATE(117), 1,
       sym_import,
-    STATE(83), 2,
+    STATE(82), 2,
       sym_comment,
       aux_sym_source_file_repeat1,
     ACTIONS(383), 7,
@@ -5472,44 +5449,71 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [3109] = 13,
+  [3072] = 13,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
+    ACTIONS(233), 1,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
+    ACTIONS(235), 1,
       anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
+    ACTIONS(237), 1,
       anon_sym_LT_EQ,
-    ACTIONS(273), 1,
+    ACTIONS(239), 1,
       anon_sym_GT_EQ,
-    ACTIONS(275), 1,
+    ACTIONS(255), 1,
       anon_sym_GT,
-    ACTIONS(371), 1,
+    ACTIONS(369), 1,
       anon_sym_LT,
-    ACTIONS(373), 1,
+    ACTIONS(371), 1,
       anon_sym_PIPE,
-    ACTIONS(375), 1,
+    ACTIONS(373), 1,
       anon_sym_AMP,
-    ACTIONS(377), 1,
+    ACTIONS(375), 1,
       anon_sym_QMARK,
     ACTIONS(388), 1,
       anon_sym_SEMI,
+    STATE(83), 1,
+      sym_comment,
+  [3112] = 13,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    ACTIONS(233), 1,
+      anon_sym_EQ_EQ,
+    ACTIONS(235), 1,
+      anon_sym_BANG_EQ,
+    ACTIONS(237), 1,
+      anon_sym_LT_EQ,
+    ACTIONS(239), 1,
+      anon_sym_GT_EQ,
+    ACTIONS(255), 1,
+      anon_sym_GT,
+    ACTIONS(369), 1,
+      anon_sym_LT,
+    ACTIONS(371), 1,
+      anon_sym_PIPE,
+    ACTIONS(373), 1,
+      anon_sym_AMP,
+    ACTIONS(375), 1,
+      anon_sym_QMARK,
+    ACTIONS(390), 1,
+      anon_sym_SEMI,
     STATE(84), 1,
       sym_comment,
-  [3149] = 5,
+  [3152] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(85), 1,
       sym_comment,
-    ACTIONS(390), 2,
+    ACTIONS(392), 2,
       anon_sym_SEMI,
       anon_sym_AT,
-    ACTIONS(392), 8,
+    ACTIONS(394), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
@@ -5518,607 +5522,545 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_repeat,
       sym_ident,
-  [3173] = 10,
+  [3176] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(394), 1,
-      anon_sym_RBRACE,
-    ACTIONS(396), 1,
-      anon_sym_AT,
-    ACTIONS(399), 1,
+    ACTIONS(170), 1,
+      anon_sym_LPAREN,
+    ACTIONS(174), 1,
       sym_ident,
-    STATE(192), 1,
-      sym_at_attribute,
-    STATE(241), 1,
-      sym_wire_assignment,
-    STATE(447), 1,
-      sym_lhs,
-    STATE(86), 2,
+    ACTIONS(176), 1,
+      sym_number,
+    STATE(44), 1,
+      sym_expr,
+    STATE(86), 1,
       sym_comment,
-      aux_sym_group_repeat1,
-    STATE(334), 2,
+    STATE(42), 2,
+      sym_base_expr,
+      sym_cmp_expr,
+    STATE(75), 3,
       sym_hole,
       sym_port,
-  [3206] = 11,
+      sym_literal,
+  [3207] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(121), 1,
-      anon_sym_AT,
-    ACTIONS(179), 1,
+    ACTIONS(170), 1,
+      anon_sym_LPAREN,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(402), 1,
-      anon_sym_RBRACE,
+    ACTIONS(176), 1,
+      sym_number,
+    STATE(46), 1,
+      sym_expr,
     STATE(87), 1,
       sym_comment,
-    STATE(107), 1,
-      aux_sym_group_repeat1,
-    STATE(192), 1,
-      sym_at_attribute,
-    STATE(241), 1,
-      sym_wire_assignment,
-    STATE(447), 1,
-      sym_lhs,
-    STATE(334), 2,
+    STATE(42), 2,
+      sym_base_expr,
+      sym_cmp_expr,
+    STATE(75), 3,
+      sym_hole,
+      sym_port,
+      sym_literal,
+  [3238] = 9,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    ACTIONS(170), 1,
+      anon_sym_LPAREN,
+    ACTIONS(174), 1,
+      sym_ident,
+    ACTIONS(176), 1,
+      sym_number,
+    STATE(47), 1,
+      sym_expr,
+    STATE(88), 1,
+      sym_comment,
+    STATE(42), 2,
+      sym_base_expr,
+      sym_cmp_expr,
+    STATE(75), 3,
       sym_hole,
       sym_port,
-  [3241] = 11,
+      sym_literal,
+  [3269] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(404), 1,
+    ACTIONS(396), 1,
       anon_sym_LBRACE,
-    ACTIONS(406), 1,
+    ACTIONS(398), 1,
       anon_sym_if,
-    ACTIONS(408), 1,
+    ACTIONS(400), 1,
       anon_sym_AT,
-    STATE(88), 1,
+    STATE(89), 1,
       sym_comment,
     STATE(151), 1,
       aux_sym_io_port_repeat1,
-    STATE(246), 1,
+    STATE(237), 1,
       sym_at_attribute,
-    STATE(352), 1,
+    STATE(350), 1,
       sym_static_annotation,
     STATE(59), 2,
       sym_block,
       sym_if_stmt,
-  [3276] = 9,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(54), 1,
-      sym_expr,
-    STATE(89), 1,
-      sym_comment,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
-      sym_hole,
-      sym_port,
-      sym_literal,
-  [3307] = 9,
+  [3304] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
+    ACTIONS(170), 1,
       anon_sym_LPAREN,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(176), 1,
       sym_number,
-    STATE(55), 1,
+    STATE(41), 1,
       sym_expr,
     STATE(90), 1,
       sym_comment,
-    STATE(68), 2,
+    STATE(42), 2,
       sym_base_expr,
       sym_cmp_expr,
-    STATE(67), 3,
+    STATE(75), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [3338] = 9,
+  [3335] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
+    ACTIONS(170), 1,
       anon_sym_LPAREN,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(176), 1,
       sym_number,
-    STATE(51), 1,
+    STATE(49), 1,
       sym_expr,
     STATE(91), 1,
       sym_comment,
-    STATE(68), 2,
+    STATE(42), 2,
       sym_base_expr,
       sym_cmp_expr,
-    STATE(67), 3,
+    STATE(75), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [3369] = 9,
+  [3366] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
+    ACTIONS(170), 1,
       anon_sym_LPAREN,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(176), 1,
       sym_number,
     STATE(92), 1,
       sym_comment,
-    STATE(143), 1,
+    STATE(142), 1,
       sym_expr,
-    STATE(68), 2,
+    STATE(42), 2,
       sym_base_expr,
       sym_cmp_expr,
-    STATE(67), 3,
+    STATE(75), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [3400] = 11,
+  [3397] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(121), 1,
+    ACTIONS(166), 1,
       anon_sym_AT,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(410), 1,
+    ACTIONS(402), 1,
       anon_sym_RBRACE,
-    STATE(86), 1,
-      aux_sym_group_repeat1,
     STATE(93), 1,
       sym_comment,
-    STATE(192), 1,
+    STATE(101), 1,
+      aux_sym_group_repeat1,
+    STATE(198), 1,
       sym_at_attribute,
-    STATE(241), 1,
+    STATE(207), 1,
       sym_wire_assignment,
-    STATE(447), 1,
+    STATE(445), 1,
       sym_lhs,
-    STATE(334), 2,
+    STATE(326), 2,
       sym_hole,
       sym_port,
-  [3435] = 9,
+  [3432] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
+    ACTIONS(170), 1,
       anon_sym_LPAREN,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(176), 1,
       sym_number,
-    STATE(50), 1,
-      sym_expr,
     STATE(94), 1,
       sym_comment,
-    STATE(68), 2,
+    STATE(143), 1,
+      sym_expr,
+    STATE(42), 2,
       sym_base_expr,
       sym_cmp_expr,
-    STATE(67), 3,
+    STATE(75), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [3466] = 11,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(21), 1,
-      anon_sym_static,
-    ACTIONS(408), 1,
-      anon_sym_AT,
-    ACTIONS(412), 1,
-      anon_sym_LBRACE,
-    ACTIONS(414), 1,
-      anon_sym_if,
-    STATE(95), 1,
-      sym_comment,
-    STATE(149), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
-      sym_at_attribute,
-    STATE(439), 1,
-      sym_static_annotation,
-    STATE(61), 2,
-      sym_block,
-      sym_if_stmt,
-  [3501] = 9,
+  [3463] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
+    ACTIONS(170), 1,
       anon_sym_LPAREN,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(176), 1,
       sym_number,
-    STATE(96), 1,
-      sym_comment,
-    STATE(145), 1,
+    STATE(45), 1,
       sym_expr,
-    STATE(68), 2,
+    STATE(95), 1,
+      sym_comment,
+    STATE(42), 2,
       sym_base_expr,
       sym_cmp_expr,
-    STATE(67), 3,
+    STATE(75), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [3532] = 11,
+  [3494] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(404), 1,
+    ACTIONS(396), 1,
       anon_sym_LBRACE,
-    ACTIONS(406), 1,
+    ACTIONS(398), 1,
       anon_sym_if,
-    ACTIONS(408), 1,
+    ACTIONS(400), 1,
       anon_sym_AT,
-    STATE(97), 1,
+    STATE(96), 1,
       sym_comment,
     STATE(151), 1,
       aux_sym_io_port_repeat1,
-    STATE(246), 1,
+    STATE(237), 1,
       sym_at_attribute,
-    STATE(352), 1,
+    STATE(350), 1,
       sym_static_annotation,
-    STATE(61), 2,
+    STATE(63), 2,
       sym_block,
       sym_if_stmt,
-  [3567] = 11,
+  [3529] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(408), 1,
-      anon_sym_AT,
-    ACTIONS(412), 1,
+    ACTIONS(396), 1,
       anon_sym_LBRACE,
-    ACTIONS(414), 1,
+    ACTIONS(398), 1,
       anon_sym_if,
-    STATE(98), 1,
+    ACTIONS(400), 1,
+      anon_sym_AT,
+    STATE(97), 1,
       sym_comment,
-    STATE(149), 1,
+    STATE(151), 1,
       aux_sym_io_port_repeat1,
-    STATE(246), 1,
+    STATE(237), 1,
       sym_at_attribute,
-    STATE(439), 1,
+    STATE(350), 1,
       sym_static_annotation,
-    STATE(74), 2,
+    STATE(51), 2,
       sym_block,
       sym_if_stmt,
-  [3602] = 11,
+  [3564] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(21), 1,
-      anon_sym_static,
-    ACTIONS(408), 1,
+    ACTIONS(166), 1,
       anon_sym_AT,
-    ACTIONS(412), 1,
-      anon_sym_LBRACE,
-    ACTIONS(414), 1,
-      anon_sym_if,
-    STATE(99), 1,
+    ACTIONS(174), 1,
+      sym_ident,
+    ACTIONS(404), 1,
+      anon_sym_RBRACE,
+    STATE(93), 1,
+      aux_sym_group_repeat1,
+    STATE(98), 1,
       sym_comment,
-    STATE(149), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
+    STATE(198), 1,
       sym_at_attribute,
-    STATE(439), 1,
-      sym_static_annotation,
-    STATE(59), 2,
-      sym_block,
-      sym_if_stmt,
-  [3637] = 11,
+    STATE(207), 1,
+      sym_wire_assignment,
+    STATE(445), 1,
+      sym_lhs,
+    STATE(326), 2,
+      sym_hole,
+      sym_port,
+  [3599] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(121), 1,
+    ACTIONS(166), 1,
       anon_sym_AT,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(416), 1,
+    ACTIONS(406), 1,
       anon_sym_RBRACE,
-    STATE(93), 1,
-      aux_sym_group_repeat1,
-    STATE(100), 1,
+    STATE(99), 1,
       sym_comment,
-    STATE(192), 1,
+    STATE(108), 1,
+      aux_sym_group_repeat1,
+    STATE(198), 1,
       sym_at_attribute,
-    STATE(241), 1,
+    STATE(207), 1,
       sym_wire_assignment,
-    STATE(447), 1,
+    STATE(445), 1,
       sym_lhs,
-    STATE(334), 2,
+    STATE(326), 2,
       sym_hole,
       sym_port,
-  [3672] = 11,
+  [3634] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(21), 1,
-      anon_sym_static,
+    ACTIONS(166), 1,
+      anon_sym_AT,
+    ACTIONS(174), 1,
+      sym_ident,
     ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    ACTIONS(406), 1,
-      anon_sym_if,
-    ACTIONS(408), 1,
-      anon_sym_AT,
-    STATE(101), 1,
-      sym_comment,
-    STATE(151), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
-      sym_at_attribute,
-    STATE(352), 1,
-      sym_static_annotation,
-    STATE(74), 2,
-      sym_block,
-      sym_if_stmt,
-  [3707] = 11,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(121), 1,
-      anon_sym_AT,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(402), 1,
       anon_sym_RBRACE,
-    STATE(86), 1,
-      aux_sym_group_repeat1,
-    STATE(102), 1,
+    STATE(100), 1,
       sym_comment,
-    STATE(192), 1,
+    STATE(101), 1,
+      aux_sym_group_repeat1,
+    STATE(198), 1,
       sym_at_attribute,
-    STATE(241), 1,
+    STATE(207), 1,
       sym_wire_assignment,
-    STATE(447), 1,
+    STATE(445), 1,
       sym_lhs,
-    STATE(334), 2,
+    STATE(326), 2,
       sym_hole,
       sym_port,
-  [3742] = 11,
+  [3669] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(121), 1,
+    ACTIONS(408), 1,
+      anon_sym_RBRACE,
+    ACTIONS(410), 1,
       anon_sym_AT,
-    ACTIONS(179), 1,
+    ACTIONS(413), 1,
       sym_ident,
-    ACTIONS(410), 1,
-      anon_sym_RBRACE,
-    STATE(102), 1,
-      aux_sym_group_repeat1,
-    STATE(103), 1,
-      sym_comment,
-    STATE(192), 1,
+    STATE(198), 1,
       sym_at_attribute,
-    STATE(241), 1,
+    STATE(207), 1,
       sym_wire_assignment,
-    STATE(447), 1,
+    STATE(445), 1,
       sym_lhs,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [3777] = 9,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(53), 1,
-      sym_expr,
-    STATE(104), 1,
+    STATE(101), 2,
       sym_comment,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
+      aux_sym_group_repeat1,
+    STATE(326), 2,
       sym_hole,
       sym_port,
-      sym_literal,
-  [3808] = 9,
+  [3702] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
+    ACTIONS(170), 1,
       anon_sym_LPAREN,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(176), 1,
       sym_number,
-    STATE(105), 1,
+    STATE(102), 1,
       sym_comment,
-    STATE(124), 1,
+    STATE(133), 1,
       sym_expr,
-    STATE(68), 2,
+    STATE(42), 2,
       sym_base_expr,
       sym_cmp_expr,
-    STATE(67), 3,
+    STATE(75), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [3839] = 9,
+  [3733] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
+    ACTIONS(170), 1,
       anon_sym_LPAREN,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(176), 1,
       sym_number,
-    STATE(106), 1,
+    STATE(103), 1,
       sym_comment,
-    STATE(127), 1,
+    STATE(134), 1,
       sym_expr,
-    STATE(68), 2,
+    STATE(42), 2,
       sym_base_expr,
       sym_cmp_expr,
-    STATE(67), 3,
+    STATE(75), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [3870] = 11,
+  [3764] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(121), 1,
+    ACTIONS(166), 1,
       anon_sym_AT,
-    ACTIONS(179), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    ACTIONS(418), 1,
+    ACTIONS(416), 1,
       anon_sym_RBRACE,
-    STATE(86), 1,
+    STATE(100), 1,
       aux_sym_group_repeat1,
-    STATE(107), 1,
+    STATE(104), 1,
       sym_comment,
-    STATE(192), 1,
+    STATE(198), 1,
       sym_at_attribute,
-    STATE(241), 1,
+    STATE(207), 1,
       sym_wire_assignment,
-    STATE(447), 1,
+    STATE(445), 1,
       sym_lhs,
-    STATE(334), 2,
+    STATE(326), 2,
       sym_hole,
       sym_port,
-  [3905] = 9,
+  [3799] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(49), 1,
-      sym_expr,
-    STATE(108), 1,
+    ACTIONS(21), 1,
+      anon_sym_static,
+    ACTIONS(400), 1,
+      anon_sym_AT,
+    ACTIONS(418), 1,
+      anon_sym_LBRACE,
+    ACTIONS(420), 1,
+      anon_sym_if,
+    STATE(105), 1,
       sym_comment,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
-      sym_hole,
-      sym_port,
-      sym_literal,
-  [3936] = 11,
+    STATE(152), 1,
+      aux_sym_io_port_repeat1,
+    STATE(237), 1,
+      sym_at_attribute,
+    STATE(437), 1,
+      sym_static_annotation,
+    STATE(51), 2,
+      sym_block,
+      sym_if_stmt,
+  [3834] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(15), 1,
-      anon_sym_comb,
-    ACTIONS(17), 1,
-      anon_sym_primitive,
     ACTIONS(21), 1,
       anon_sym_static,
+    ACTIONS(400), 1,
+      anon_sym_AT,
+    ACTIONS(418), 1,
+      anon_sym_LBRACE,
     ACTIONS(420), 1,
-      anon_sym_RBRACE,
-    STATE(109), 1,
+      anon_sym_if,
+    STATE(106), 1,
       sym_comment,
-    STATE(118), 1,
-      aux_sym_extern_repeat1,
-    STATE(198), 1,
-      sym_primitive,
-    STATE(204), 1,
+    STATE(152), 1,
+      aux_sym_io_port_repeat1,
+    STATE(237), 1,
+      sym_at_attribute,
+    STATE(437), 1,
       sym_static_annotation,
-    STATE(396), 1,
-      sym_comb_or_static,
-  [3970] = 4,
+    STATE(59), 2,
+      sym_block,
+      sym_if_stmt,
+  [3869] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(110), 1,
-      sym_comment,
-    ACTIONS(422), 8,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-      anon_sym_component,
-      anon_sym_RBRACE,
-      anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_extern,
+    ACTIONS(21), 1,
       anon_sym_static,
-  [3990] = 11,
+    ACTIONS(400), 1,
+      anon_sym_AT,
+    ACTIONS(418), 1,
+      anon_sym_LBRACE,
+    ACTIONS(420), 1,
+      anon_sym_if,
+    STATE(107), 1,
+      sym_comment,
+    STATE(152), 1,
+      aux_sym_io_port_repeat1,
+    STATE(237), 1,
+      sym_at_attribute,
+    STATE(437), 1,
+      sym_static_annotation,
+    STATE(63), 2,
+      sym_block,
+      sym_if_stmt,
+  [3904] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(424), 1,
-      anon_sym_RBRACE,
-    ACTIONS(426), 1,
-      anon_sym_ref,
-    ACTIONS(428), 1,
+    ACTIONS(166), 1,
       anon_sym_AT,
-    ACTIONS(430), 1,
+    ACTIONS(174), 1,
       sym_ident,
-    STATE(111), 1,
+    ACTIONS(416), 1,
+      anon_sym_RBRACE,
+    STATE(101), 1,
+      aux_sym_group_repeat1,
+    STATE(108), 1,
       sym_comment,
-    STATE(146), 1,
-      aux_sym_cells_inner_repeat1,
-    STATE(182), 1,
-      sym_cell_assignment,
-    STATE(321), 1,
+    STATE(198), 1,
       sym_at_attribute,
-    STATE(338), 1,
-      sym_cells_inner,
-  [4024] = 4,
+    STATE(207), 1,
+      sym_wire_assignment,
+    STATE(445), 1,
+      sym_lhs,
+    STATE(326), 2,
+      sym_hole,
+      sym_port,
+  [3939] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(112), 1,
+    STATE(109), 1,
       sym_comment,
-    ACTIONS(432), 8,
+    ACTIONS(422), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
@@ -6127,14 +6069,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4044] = 4,
+  [3959] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(113), 1,
+    STATE(110), 1,
       sym_comment,
-    ACTIONS(434), 8,
+    ACTIONS(424), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
@@ -6143,30 +6085,30 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4064] = 4,
+  [3979] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(114), 1,
+    STATE(111), 1,
       sym_comment,
-    ACTIONS(436), 8,
+    ACTIONS(426), 8,
       ts_builtin_sym_end,
-      anon_sym_import,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
+      anon_sym_RBRACE,
       anon_sym_comb,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4084] = 4,
+  [3999] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(115), 1,
+    STATE(112), 1,
       sym_comment,
-    ACTIONS(438), 8,
+    ACTIONS(428), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
@@ -6175,36 +6117,59 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4104] = 10,
+  [4019] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(440), 1,
+    ACTIONS(430), 1,
       anon_sym_RBRACE,
-    ACTIONS(442), 1,
+    ACTIONS(432), 1,
       anon_sym_comb,
-    ACTIONS(445), 1,
+    ACTIONS(435), 1,
       anon_sym_primitive,
-    ACTIONS(448), 1,
+    ACTIONS(438), 1,
       anon_sym_static,
-    STATE(198), 1,
+    STATE(195), 1,
       sym_primitive,
-    STATE(204), 1,
+    STATE(219), 1,
       sym_static_annotation,
-    STATE(396), 1,
+    STATE(447), 1,
       sym_comb_or_static,
-    STATE(116), 2,
+    STATE(113), 2,
       sym_comment,
       aux_sym_extern_repeat1,
-  [4136] = 4,
+  [4051] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(117), 1,
+    ACTIONS(441), 1,
+      anon_sym_RBRACE,
+    ACTIONS(443), 1,
+      anon_sym_ref,
+    ACTIONS(445), 1,
+      anon_sym_AT,
+    ACTIONS(447), 1,
+      sym_ident,
+    STATE(114), 1,
+      sym_comment,
+    STATE(123), 1,
+      aux_sym_cells_inner_repeat1,
+    STATE(176), 1,
+      sym_cell_assignment,
+    STATE(260), 1,
+      sym_at_attribute,
+    STATE(414), 1,
+      sym_cells_inner,
+  [4085] = 4,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(115), 1,
       sym_comment,
-    ACTIONS(451), 8,
+    ACTIONS(449), 8,
       ts_builtin_sym_end,
       anon_sym_import,
       aux_sym_metadata_ci_token1,
@@ -6213,7 +6178,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4156] = 11,
+  [4105] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -6224,222 +6189,180 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_primitive,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(453), 1,
+    ACTIONS(451), 1,
       anon_sym_RBRACE,
     STATE(116), 1,
-      aux_sym_extern_repeat1,
-    STATE(118), 1,
       sym_comment,
-    STATE(198), 1,
+    STATE(119), 1,
+      aux_sym_extern_repeat1,
+    STATE(195), 1,
       sym_primitive,
-    STATE(204), 1,
+    STATE(219), 1,
       sym_static_annotation,
-    STATE(396), 1,
+    STATE(447), 1,
       sym_comb_or_static,
-  [4190] = 4,
+  [4139] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(119), 1,
+    STATE(117), 1,
       sym_comment,
-    ACTIONS(455), 8,
+    ACTIONS(453), 8,
       ts_builtin_sym_end,
+      anon_sym_import,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
-      anon_sym_RBRACE,
       anon_sym_comb,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4210] = 4,
+  [4159] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(120), 1,
+    STATE(118), 1,
       sym_comment,
-    ACTIONS(457), 7,
+    ACTIONS(455), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
+      anon_sym_RBRACE,
       anon_sym_comb,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4229] = 10,
+  [4179] = 11,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(121), 1,
-      anon_sym_AT,
-    ACTIONS(459), 1,
-      anon_sym_RPAREN,
-    ACTIONS(461), 1,
-      sym_ident,
-    STATE(121), 1,
-      sym_comment,
-    STATE(135), 1,
-      aux_sym_io_port_list_repeat1,
-    STATE(187), 1,
-      aux_sym_io_port_repeat1,
-    STATE(276), 1,
-      sym_io_port,
-    STATE(277), 1,
-      sym_at_attribute,
-  [4260] = 4,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(122), 1,
-      sym_comment,
-    ACTIONS(463), 7,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-      anon_sym_component,
+    ACTIONS(15), 1,
       anon_sym_comb,
+    ACTIONS(17), 1,
       anon_sym_primitive,
-      anon_sym_extern,
+    ACTIONS(21), 1,
       anon_sym_static,
-  [4279] = 4,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(123), 1,
+    ACTIONS(457), 1,
+      anon_sym_RBRACE,
+    STATE(113), 1,
+      aux_sym_extern_repeat1,
+    STATE(119), 1,
       sym_comment,
-    ACTIONS(465), 7,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-      anon_sym_component,
-      anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_extern,
-      anon_sym_static,
-  [4298] = 10,
+    STATE(195), 1,
+      sym_primitive,
+    STATE(219), 1,
+      sym_static_annotation,
+    STATE(447), 1,
+      sym_comb_or_static,
+  [4213] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    ACTIONS(273), 1,
-      anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    ACTIONS(371), 1,
+    ACTIONS(459), 1,
+      anon_sym_LPAREN,
+    ACTIONS(461), 1,
+      anon_sym_LBRACK,
+    ACTIONS(463), 1,
       anon_sym_LT,
-    ACTIONS(467), 1,
-      anon_sym_QMARK,
-    STATE(124), 1,
+    STATE(120), 1,
       sym_comment,
-  [4329] = 4,
+    STATE(162), 1,
+      sym_attributes,
+    STATE(242), 1,
+      sym_signature,
+    STATE(247), 1,
+      sym_params,
+    STATE(403), 1,
+      sym_io_port_list,
+  [4244] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(125), 1,
+    ACTIONS(465), 1,
+      anon_sym_LT,
+    ACTIONS(469), 1,
+      anon_sym_repeat,
+    STATE(121), 1,
       sym_comment,
-    ACTIONS(469), 7,
-      anon_sym_component,
-      anon_sym_comb,
-      anon_sym_primitive,
+    STATE(128), 1,
+      sym_latency_annotation,
+    ACTIONS(467), 4,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
-  [4348] = 7,
+  [4269] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(471), 1,
-      anon_sym_LT,
+      anon_sym_RBRACE,
+    ACTIONS(473), 1,
+      anon_sym_cells,
     ACTIONS(475), 1,
-      anon_sym_repeat,
-    STATE(126), 1,
+      anon_sym_wires,
+    ACTIONS(477), 1,
+      anon_sym_control,
+    STATE(122), 1,
       sym_comment,
-    STATE(132), 1,
-      sym_latency_annotation,
-    ACTIONS(473), 4,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-  [4373] = 10,
+    STATE(167), 1,
+      sym_cells,
+    STATE(252), 1,
+      sym_wires,
+    STATE(389), 1,
+      sym_control,
+  [4300] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    ACTIONS(273), 1,
-      anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    ACTIONS(371), 1,
-      anon_sym_LT,
-    ACTIONS(477), 1,
-      anon_sym_QMARK,
-    STATE(127), 1,
+    ACTIONS(443), 1,
+      anon_sym_ref,
+    ACTIONS(445), 1,
+      anon_sym_AT,
+    ACTIONS(447), 1,
+      sym_ident,
+    ACTIONS(479), 1,
+      anon_sym_RBRACE,
+    STATE(123), 1,
       sym_comment,
-  [4404] = 7,
+    STATE(127), 1,
+      aux_sym_cells_inner_repeat1,
+    STATE(176), 1,
+      sym_cell_assignment,
+    STATE(260), 1,
+      sym_at_attribute,
+  [4331] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(471), 1,
+    ACTIONS(465), 1,
       anon_sym_LT,
-    ACTIONS(479), 1,
+    ACTIONS(481), 1,
       anon_sym_repeat,
-    STATE(128), 1,
+    STATE(124), 1,
       sym_comment,
-    STATE(132), 1,
+    STATE(128), 1,
       sym_latency_annotation,
-    ACTIONS(473), 4,
+    ACTIONS(467), 4,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
-  [4429] = 10,
+  [4356] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(481), 1,
-      anon_sym_RBRACE,
-    ACTIONS(483), 1,
-      anon_sym_cells,
-    ACTIONS(485), 1,
-      anon_sym_wires,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    STATE(129), 1,
-      sym_comment,
-    STATE(153), 1,
-      sym_cells,
-    STATE(232), 1,
-      sym_wires,
-    STATE(379), 1,
-      sym_control,
-  [4460] = 4,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(130), 1,
+    STATE(125), 1,
       sym_comment,
-    ACTIONS(489), 7,
+    ACTIONS(483), 7,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
@@ -6447,35 +6370,55 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4479] = 10,
+  [4375] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
+    ACTIONS(459), 1,
       anon_sym_LPAREN,
-    ACTIONS(493), 1,
+    ACTIONS(461), 1,
       anon_sym_LBRACK,
-    ACTIONS(495), 1,
+    ACTIONS(463), 1,
       anon_sym_LT,
-    STATE(131), 1,
+    STATE(126), 1,
       sym_comment,
-    STATE(155), 1,
+    STATE(154), 1,
       sym_attributes,
-    STATE(249), 1,
+    STATE(225), 1,
       sym_params,
-    STATE(250), 1,
+    STATE(255), 1,
       sym_signature,
-    STATE(412), 1,
+    STATE(403), 1,
       sym_io_port_list,
-  [4510] = 4,
+  [4406] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(132), 1,
+    ACTIONS(485), 1,
+      anon_sym_RBRACE,
+    ACTIONS(487), 1,
+      anon_sym_ref,
+    ACTIONS(490), 1,
+      anon_sym_AT,
+    ACTIONS(493), 1,
+      sym_ident,
+    STATE(176), 1,
+      sym_cell_assignment,
+    STATE(260), 1,
+      sym_at_attribute,
+    STATE(127), 2,
+      sym_comment,
+      aux_sym_cells_inner_repeat1,
+  [4435] = 4,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(128), 1,
       sym_comment,
-    ACTIONS(497), 7,
+    ACTIONS(496), 7,
       anon_sym_component,
       anon_sym_comb,
       anon_sym_primitive,
@@ -6483,35 +6426,32 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
-  [4529] = 10,
+  [4454] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(483), 1,
-      anon_sym_cells,
-    ACTIONS(485), 1,
-      anon_sym_wires,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(499), 1,
-      anon_sym_RBRACE,
-    STATE(133), 1,
+    ACTIONS(465), 1,
+      anon_sym_LT,
+    ACTIONS(498), 1,
+      anon_sym_repeat,
+    STATE(128), 1,
+      sym_latency_annotation,
+    STATE(129), 1,
       sym_comment,
-    STATE(165), 1,
-      sym_cells,
-    STATE(224), 1,
-      sym_wires,
-    STATE(353), 1,
-      sym_control,
-  [4560] = 4,
+    ACTIONS(467), 4,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+  [4479] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(134), 1,
+    STATE(130), 1,
       sym_comment,
-    ACTIONS(501), 7,
+    ACTIONS(500), 7,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
@@ -6519,53 +6459,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4579] = 10,
+  [4498] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(121), 1,
+    ACTIONS(166), 1,
       anon_sym_AT,
-    ACTIONS(461), 1,
-      sym_ident,
-    ACTIONS(503), 1,
+    ACTIONS(502), 1,
       anon_sym_RPAREN,
-    STATE(135), 1,
+    ACTIONS(504), 1,
+      sym_ident,
+    STATE(131), 1,
       sym_comment,
-    STATE(138), 1,
+    STATE(135), 1,
       aux_sym_io_port_list_repeat1,
-    STATE(187), 1,
+    STATE(177), 1,
       aux_sym_io_port_repeat1,
-    STATE(277), 1,
-      sym_at_attribute,
-    STATE(286), 1,
+    STATE(261), 1,
       sym_io_port,
-  [4610] = 7,
+    STATE(262), 1,
+      sym_at_attribute,
+  [4529] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(471), 1,
-      anon_sym_LT,
-    ACTIONS(505), 1,
-      anon_sym_repeat,
     STATE(132), 1,
-      sym_latency_annotation,
-    STATE(136), 1,
       sym_comment,
-    ACTIONS(473), 4,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-  [4635] = 4,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(137), 1,
-      sym_comment,
-    ACTIONS(507), 7,
+    ACTIONS(506), 7,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
@@ -6573,55 +6495,131 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4654] = 9,
+  [4548] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(509), 1,
+    ACTIONS(233), 1,
+      anon_sym_EQ_EQ,
+    ACTIONS(235), 1,
+      anon_sym_BANG_EQ,
+    ACTIONS(237), 1,
+      anon_sym_LT_EQ,
+    ACTIONS(239), 1,
+      anon_sym_GT_EQ,
+    ACTIONS(255), 1,
+      anon_sym_GT,
+    ACTIONS(369), 1,
+      anon_sym_LT,
+    ACTIONS(508), 1,
       anon_sym_RPAREN,
-    ACTIONS(511), 1,
+    STATE(133), 1,
+      sym_comment,
+  [4579] = 10,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    ACTIONS(233), 1,
+      anon_sym_EQ_EQ,
+    ACTIONS(235), 1,
+      anon_sym_BANG_EQ,
+    ACTIONS(237), 1,
+      anon_sym_LT_EQ,
+    ACTIONS(239), 1,
+      anon_sym_GT_EQ,
+    ACTIONS(255), 1,
+      anon_sym_GT,
+    ACTIONS(369), 1,
+      anon_sym_LT,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    STATE(134), 1,
+      sym_comment,
+  [4610] = 10,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    ACTIONS(166), 1,
       anon_sym_AT,
-    ACTIONS(514), 1,
+    ACTIONS(504), 1,
       sym_ident,
-    STATE(187), 1,
+    ACTIONS(512), 1,
+      anon_sym_RPAREN,
+    STATE(135), 1,
+      sym_comment,
+    STATE(144), 1,
+      aux_sym_io_port_list_repeat1,
+    STATE(177), 1,
       aux_sym_io_port_repeat1,
-    STATE(277), 1,
+    STATE(262), 1,
       sym_at_attribute,
-    STATE(355), 1,
+    STATE(288), 1,
       sym_io_port,
-    STATE(138), 2,
+  [4641] = 4,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(136), 1,
       sym_comment,
-      aux_sym_io_port_list_repeat1,
-  [4683] = 10,
+    ACTIONS(514), 7,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [4660] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(483), 1,
+    ACTIONS(473), 1,
       anon_sym_cells,
-    ACTIONS(485), 1,
+    ACTIONS(475), 1,
       anon_sym_wires,
-    ACTIONS(487), 1,
+    ACTIONS(477), 1,
       anon_sym_control,
-    ACTIONS(517), 1,
+    ACTIONS(516), 1,
       anon_sym_RBRACE,
-    STATE(139), 1,
+    STATE(137), 1,
       sym_comment,
-    STATE(170), 1,
+    STATE(168), 1,
       sym_cells,
-    STATE(208), 1,
+    STATE(231), 1,
       sym_wires,
-    STATE(360), 1,
+    STATE(388), 1,
       sym_control,
-  [4714] = 4,
+  [4691] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(140), 1,
+    ACTIONS(465), 1,
+      anon_sym_LT,
+    ACTIONS(518), 1,
+      anon_sym_repeat,
+    STATE(128), 1,
+      sym_latency_annotation,
+    STATE(138), 1,
       sym_comment,
-    ACTIONS(519), 7,
+    ACTIONS(467), 4,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+  [4716] = 4,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(139), 1,
+      sym_comment,
+    ACTIONS(520), 7,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
@@ -6629,346 +6627,339 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4733] = 9,
+  [4735] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(521), 1,
-      anon_sym_RBRACE,
-    ACTIONS(523), 1,
-      anon_sym_ref,
-    ACTIONS(526), 1,
-      anon_sym_AT,
-    ACTIONS(529), 1,
-      sym_ident,
-    STATE(182), 1,
-      sym_cell_assignment,
-    STATE(321), 1,
-      sym_at_attribute,
-    STATE(141), 2,
+    STATE(140), 1,
       sym_comment,
-      aux_sym_cells_inner_repeat1,
-  [4762] = 4,
+    ACTIONS(522), 7,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [4754] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(142), 1,
+    STATE(141), 1,
       sym_comment,
-    ACTIONS(532), 7,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
+    ACTIONS(524), 7,
       anon_sym_component,
       anon_sym_comb,
       anon_sym_primitive,
-      anon_sym_extern,
-      anon_sym_static,
-  [4781] = 10,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+  [4773] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
+    ACTIONS(233), 1,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
+    ACTIONS(235), 1,
       anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
+    ACTIONS(237), 1,
       anon_sym_LT_EQ,
-    ACTIONS(273), 1,
+    ACTIONS(239), 1,
       anon_sym_GT_EQ,
-    ACTIONS(275), 1,
+    ACTIONS(255), 1,
       anon_sym_GT,
-    ACTIONS(371), 1,
+    ACTIONS(369), 1,
       anon_sym_LT,
-    ACTIONS(477), 1,
+    ACTIONS(526), 1,
       anon_sym_QMARK,
-    STATE(143), 1,
-      sym_comment,
-  [4812] = 4,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(144), 1,
+    STATE(142), 1,
       sym_comment,
-    ACTIONS(534), 7,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-      anon_sym_component,
-      anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_extern,
-      anon_sym_static,
-  [4831] = 10,
+  [4804] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(263), 1,
+    ACTIONS(233), 1,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
+    ACTIONS(235), 1,
       anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
+    ACTIONS(237), 1,
       anon_sym_LT_EQ,
-    ACTIONS(273), 1,
+    ACTIONS(239), 1,
       anon_sym_GT_EQ,
-    ACTIONS(275), 1,
+    ACTIONS(255), 1,
       anon_sym_GT,
-    ACTIONS(371), 1,
+    ACTIONS(369), 1,
       anon_sym_LT,
-    ACTIONS(536), 1,
+    ACTIONS(526), 1,
+      anon_sym_QMARK,
+    STATE(143), 1,
+      sym_comment,
+  [4835] = 9,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    ACTIONS(528), 1,
       anon_sym_RPAREN,
+    ACTIONS(530), 1,
+      anon_sym_AT,
+    ACTIONS(533), 1,
+      sym_ident,
+    STATE(177), 1,
+      aux_sym_io_port_repeat1,
+    STATE(262), 1,
+      sym_at_attribute,
+    STATE(443), 1,
+      sym_io_port,
+    STATE(144), 2,
+      sym_comment,
+      aux_sym_io_port_list_repeat1,
+  [4864] = 4,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
     STATE(145), 1,
       sym_comment,
-  [4862] = 10,
+    ACTIONS(536), 7,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [4883] = 10,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(426), 1,
-      anon_sym_ref,
-    ACTIONS(428), 1,
-      anon_sym_AT,
-    ACTIONS(430), 1,
-      sym_ident,
+    ACTIONS(473), 1,
+      anon_sym_cells,
+    ACTIONS(475), 1,
+      anon_sym_wires,
+    ACTIONS(477), 1,
+      anon_sym_control,
     ACTIONS(538), 1,
       anon_sym_RBRACE,
-    STATE(141), 1,
-      aux_sym_cells_inner_repeat1,
     STATE(146), 1,
       sym_comment,
-    STATE(182), 1,
-      sym_cell_assignment,
-    STATE(321), 1,
-      sym_at_attribute,
-  [4893] = 10,
+    STATE(156), 1,
+      sym_cells,
+    STATE(249), 1,
+      sym_wires,
+    STATE(460), 1,
+      sym_control,
+  [4914] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
-    ACTIONS(493), 1,
-      anon_sym_LBRACK,
-    ACTIONS(495), 1,
-      anon_sym_LT,
     STATE(147), 1,
       sym_comment,
-    STATE(159), 1,
-      sym_attributes,
-    STATE(237), 1,
-      sym_params,
-    STATE(238), 1,
-      sym_signature,
-    STATE(412), 1,
-      sym_io_port_list,
-  [4924] = 7,
+    ACTIONS(540), 7,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [4933] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(471), 1,
-      anon_sym_LT,
-    ACTIONS(540), 1,
-      anon_sym_repeat,
-    STATE(132), 1,
-      sym_latency_annotation,
     STATE(148), 1,
       sym_comment,
-    ACTIONS(473), 4,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-  [4949] = 9,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(21), 1,
+    ACTIONS(542), 7,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_primitive,
+      anon_sym_extern,
       anon_sym_static,
-    ACTIONS(408), 1,
-      anon_sym_AT,
-    ACTIONS(542), 1,
-      anon_sym_if,
-    STATE(149), 1,
-      sym_comment,
-    STATE(167), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
-      sym_at_attribute,
-    STATE(442), 1,
-      sym_static_annotation,
-  [4977] = 6,
+  [4952] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(471), 1,
+    ACTIONS(465), 1,
       anon_sym_LT,
-    STATE(132), 1,
+    STATE(128), 1,
       sym_latency_annotation,
-    STATE(150), 1,
+    STATE(149), 1,
       sym_comment,
-    ACTIONS(473), 4,
+    ACTIONS(467), 4,
       anon_sym_component,
       anon_sym_comb,
       anon_sym_primitive,
       anon_sym_if,
-  [4999] = 9,
+  [4974] = 7,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    ACTIONS(174), 1,
+      sym_ident,
+    ACTIONS(176), 1,
+      sym_number,
+    STATE(150), 1,
+      sym_comment,
+    STATE(333), 1,
+      sym_base_expr,
+    STATE(75), 3,
+      sym_hole,
+      sym_port,
+      sym_literal,
+  [4998] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(408), 1,
+    ACTIONS(400), 1,
       anon_sym_AT,
     ACTIONS(544), 1,
       anon_sym_if,
     STATE(151), 1,
       sym_comment,
-    STATE(167), 1,
+    STATE(171), 1,
       aux_sym_io_port_repeat1,
-    STATE(246), 1,
+    STATE(237), 1,
       sym_at_attribute,
-    STATE(377), 1,
+    STATE(376), 1,
       sym_static_annotation,
-  [5027] = 7,
+  [5026] = 9,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
+    ACTIONS(21), 1,
+      anon_sym_static,
+    ACTIONS(400), 1,
+      anon_sym_AT,
+    ACTIONS(546), 1,
+      anon_sym_if,
     STATE(152), 1,
       sym_comment,
-    STATE(340), 1,
-      sym_base_expr,
-    STATE(67), 3,
-      sym_hole,
-      sym_port,
-      sym_literal,
-  [5051] = 8,
+    STATE(171), 1,
+      aux_sym_io_port_repeat1,
+    STATE(237), 1,
+      sym_at_attribute,
+    STATE(440), 1,
+      sym_static_annotation,
+  [5054] = 8,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(485), 1,
-      anon_sym_wires,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(499), 1,
-      anon_sym_RBRACE,
+    ACTIONS(459), 1,
+      anon_sym_LPAREN,
+    ACTIONS(463), 1,
+      anon_sym_LT,
     STATE(153), 1,
       sym_comment,
-    STATE(224), 1,
-      sym_wires,
-    STATE(353), 1,
-      sym_control,
-  [5076] = 5,
+    STATE(220), 1,
+      sym_attributes,
+    STATE(401), 1,
+      sym_signature,
+    STATE(403), 1,
+      sym_io_port_list,
+  [5079] = 8,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(459), 1,
+      anon_sym_LPAREN,
+    ACTIONS(461), 1,
+      anon_sym_LBRACK,
     STATE(154), 1,
       sym_comment,
-    ACTIONS(546), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(548), 3,
-      anon_sym_comb,
-      anon_sym_group,
-      sym_ident,
-  [5095] = 8,
+    STATE(242), 1,
+      sym_signature,
+    STATE(247), 1,
+      sym_params,
+    STATE(403), 1,
+      sym_io_port_list,
+  [5104] = 8,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
+    ACTIONS(459), 1,
       anon_sym_LPAREN,
-    ACTIONS(493), 1,
-      anon_sym_LBRACK,
+    ACTIONS(463), 1,
+      anon_sym_LT,
     STATE(155), 1,
       sym_comment,
-    STATE(237), 1,
-      sym_params,
-    STATE(238), 1,
+    STATE(206), 1,
+      sym_attributes,
+    STATE(379), 1,
       sym_signature,
-    STATE(412), 1,
+    STATE(403), 1,
       sym_io_port_list,
-  [5120] = 5,
+  [5129] = 8,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(471), 1,
+      anon_sym_RBRACE,
+    ACTIONS(475), 1,
+      anon_sym_wires,
+    ACTIONS(477), 1,
+      anon_sym_control,
     STATE(156), 1,
       sym_comment,
-    ACTIONS(550), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(552), 3,
-      anon_sym_comb,
-      anon_sym_group,
-      sym_ident,
-  [5139] = 5,
+    STATE(252), 1,
+      sym_wires,
+    STATE(389), 1,
+      sym_control,
+  [5154] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(157), 1,
       sym_comment,
-    ACTIONS(556), 2,
+    ACTIONS(550), 2,
       anon_sym_ref,
       sym_ident,
-    ACTIONS(554), 3,
+    ACTIONS(548), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-  [5158] = 8,
+  [5173] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
-    ACTIONS(495), 1,
-      anon_sym_LT,
+    ACTIONS(552), 1,
+      anon_sym_SEMI,
     STATE(158), 1,
       sym_comment,
-    STATE(251), 1,
-      sym_attributes,
-    STATE(412), 1,
-      sym_io_port_list,
-    STATE(416), 1,
-      sym_signature,
-  [5183] = 8,
+    ACTIONS(554), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(556), 2,
+      anon_sym_ref,
+      sym_ident,
+  [5194] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
-    ACTIONS(493), 1,
-      anon_sym_LBRACK,
     STATE(159), 1,
       sym_comment,
-    STATE(227), 1,
-      sym_params,
-    STATE(228), 1,
-      sym_signature,
-    STATE(412), 1,
-      sym_io_port_list,
-  [5208] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(160), 1,
-      sym_comment,
     ACTIONS(558), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
@@ -6976,41 +6967,72 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_comb,
       anon_sym_group,
       sym_ident,
-  [5227] = 5,
+  [5213] = 5,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    ACTIONS(115), 1,
+      anon_sym_DOT,
+    STATE(160), 1,
+      sym_comment,
+    ACTIONS(109), 4,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_with,
+  [5232] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(562), 1,
+      anon_sym_SEMI,
     STATE(161), 1,
       sym_comment,
-    ACTIONS(562), 2,
+    ACTIONS(564), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(564), 3,
-      anon_sym_comb,
-      anon_sym_group,
+    ACTIONS(566), 2,
+      anon_sym_ref,
       sym_ident,
-  [5246] = 6,
+  [5253] = 8,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(566), 1,
-      anon_sym_SEMI,
+    ACTIONS(459), 1,
+      anon_sym_LPAREN,
+    ACTIONS(461), 1,
+      anon_sym_LBRACK,
     STATE(162), 1,
       sym_comment,
-    ACTIONS(568), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
+    STATE(239), 1,
+      sym_signature,
+    STATE(245), 1,
+      sym_params,
+    STATE(403), 1,
+      sym_io_port_list,
+  [5278] = 5,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    STATE(163), 1,
+      sym_comment,
     ACTIONS(570), 2,
       anon_sym_ref,
       sym_ident,
-  [5267] = 5,
+    ACTIONS(568), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+  [5297] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(163), 1,
+    STATE(164), 1,
       sym_comment,
     ACTIONS(572), 2,
       anon_sym_RBRACE,
@@ -7019,12 +7041,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_comb,
       anon_sym_group,
       sym_ident,
-  [5286] = 5,
+  [5316] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(164), 1,
+    STATE(165), 1,
       sym_comment,
     ACTIONS(578), 2,
       anon_sym_ref,
@@ -7033,145 +7055,127 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-  [5305] = 8,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(485), 1,
-      anon_sym_wires,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(517), 1,
-      anon_sym_RBRACE,
-    STATE(165), 1,
-      sym_comment,
-    STATE(208), 1,
-      sym_wires,
-    STATE(360), 1,
-      sym_control,
-  [5330] = 8,
+  [5335] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
-    ACTIONS(495), 1,
-      anon_sym_LT,
     STATE(166), 1,
       sym_comment,
-    STATE(236), 1,
-      sym_attributes,
-    STATE(401), 1,
-      sym_signature,
-    STATE(412), 1,
-      sym_io_port_list,
-  [5355] = 6,
+    ACTIONS(580), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(582), 3,
+      anon_sym_comb,
+      anon_sym_group,
+      sym_ident,
+  [5354] = 8,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(580), 1,
-      anon_sym_AT,
-    STATE(246), 1,
-      sym_at_attribute,
-    ACTIONS(168), 2,
-      anon_sym_if,
-      anon_sym_static,
-    STATE(167), 2,
+    ACTIONS(475), 1,
+      anon_sym_wires,
+    ACTIONS(477), 1,
+      anon_sym_control,
+    ACTIONS(584), 1,
+      anon_sym_RBRACE,
+    STATE(167), 1,
       sym_comment,
-      aux_sym_io_port_repeat1,
-  [5376] = 5,
+    STATE(203), 1,
+      sym_wires,
+    STATE(331), 1,
+      sym_control,
+  [5379] = 8,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(113), 1,
-      anon_sym_DOT,
+    ACTIONS(475), 1,
+      anon_sym_wires,
+    ACTIONS(477), 1,
+      anon_sym_control,
+    ACTIONS(538), 1,
+      anon_sym_RBRACE,
     STATE(168), 1,
       sym_comment,
-    ACTIONS(107), 4,
-      anon_sym_LBRACE,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-      anon_sym_with,
-  [5395] = 6,
+    STATE(249), 1,
+      sym_wires,
+    STATE(460), 1,
+      sym_control,
+  [5404] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(583), 1,
-      anon_sym_SEMI,
     STATE(169), 1,
       sym_comment,
-    ACTIONS(585), 2,
+    ACTIONS(586), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(587), 2,
-      anon_sym_ref,
+    ACTIONS(588), 3,
+      anon_sym_comb,
+      anon_sym_group,
       sym_ident,
-  [5416] = 8,
+  [5423] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(485), 1,
-      anon_sym_wires,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(589), 1,
-      anon_sym_RBRACE,
     STATE(170), 1,
       sym_comment,
-    STATE(212), 1,
-      sym_wires,
-    STATE(405), 1,
-      sym_control,
-  [5441] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(171), 1,
-      sym_comment,
-    ACTIONS(591), 2,
+    ACTIONS(590), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(593), 3,
+    ACTIONS(592), 3,
       anon_sym_comb,
       anon_sym_group,
       sym_ident,
-  [5460] = 6,
+  [5442] = 6,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    ACTIONS(594), 1,
+      anon_sym_AT,
+    STATE(237), 1,
+      sym_at_attribute,
+    ACTIONS(202), 2,
+      anon_sym_if,
+      anon_sym_static,
+    STATE(171), 2,
+      sym_comment,
+      aux_sym_io_port_repeat1,
+  [5463] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(595), 1,
+    ACTIONS(597), 1,
       anon_sym_SEMI,
     STATE(172), 1,
       sym_comment,
-    ACTIONS(597), 2,
+    ACTIONS(599), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(599), 2,
+    ACTIONS(601), 2,
       anon_sym_ref,
       sym_ident,
-  [5481] = 5,
+  [5484] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(173), 1,
       sym_comment,
-    ACTIONS(601), 2,
+    ACTIONS(603), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(603), 3,
+    ACTIONS(605), 3,
       anon_sym_comb,
       anon_sym_group,
       sym_ident,
-  [5500] = 5,
+  [5503] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -7179,1891 +7183,1891 @@ static const uint16_t ts_small_parse_table[] = {
     STATE(174), 1,
       sym_comment,
     ACTIONS(607), 2,
-      anon_sym_ref,
-      sym_ident,
-    ACTIONS(605), 3,
-      anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-  [5519] = 6,
+    ACTIONS(609), 3,
+      anon_sym_comb,
+      anon_sym_group,
+      sym_ident,
+  [5522] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(181), 1,
-      sym_number,
-    ACTIONS(609), 1,
-      sym_ident,
+    ACTIONS(611), 1,
+      anon_sym_GT,
+    ACTIONS(613), 1,
+      sym_string,
     STATE(175), 1,
       sym_comment,
-    STATE(304), 2,
-      sym_port,
-      sym_literal,
-  [5539] = 5,
+    STATE(187), 1,
+      aux_sym_attributes_repeat1,
+    STATE(293), 1,
+      sym_attribute,
+  [5544] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(176), 1,
       sym_comment,
-    ACTIONS(568), 2,
+    ACTIONS(615), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(570), 2,
+    ACTIONS(617), 2,
       anon_sym_ref,
       sym_ident,
-  [5557] = 7,
+  [5562] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    ACTIONS(613), 1,
-      anon_sym_LBRACK,
+    ACTIONS(166), 1,
+      anon_sym_AT,
+    ACTIONS(619), 1,
+      sym_ident,
     STATE(177), 1,
       sym_comment,
-    STATE(294), 1,
-      sym_invoke_args,
-    STATE(303), 1,
-      sym_invoke_ref_args,
-  [5579] = 7,
+    STATE(182), 1,
+      aux_sym_io_port_repeat1,
+    STATE(262), 1,
+      sym_at_attribute,
+  [5584] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(615), 1,
-      anon_sym_GT,
-    ACTIONS(617), 1,
-      sym_string,
+    ACTIONS(621), 1,
+      anon_sym_LPAREN,
+    ACTIONS(623), 1,
+      anon_sym_LBRACK,
     STATE(178), 1,
       sym_comment,
-    STATE(190), 1,
-      aux_sym_attributes_repeat1,
-    STATE(280), 1,
-      sym_attribute,
-  [5601] = 7,
+    STATE(267), 1,
+      sym_invoke_args,
+    STATE(272), 1,
+      sym_invoke_ref_args,
+  [5606] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    ACTIONS(613), 1,
-      anon_sym_LBRACK,
+    ACTIONS(625), 1,
+      anon_sym_invoke,
+    ACTIONS(627), 1,
+      anon_sym_seq,
+    ACTIONS(629), 1,
+      anon_sym_par,
+    ACTIONS(631), 1,
+      anon_sym_if,
     STATE(179), 1,
       sym_comment,
-    STATE(315), 1,
-      sym_invoke_ref_args,
-    STATE(318), 1,
-      sym_invoke_args,
-  [5623] = 7,
+  [5628] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(619), 1,
-      anon_sym_RPAREN,
-    ACTIONS(621), 1,
+    ACTIONS(176), 1,
+      sym_number,
+    ACTIONS(633), 1,
       sym_ident,
     STATE(180), 1,
       sym_comment,
-    STATE(211), 1,
-      aux_sym_invoke_args_repeat1,
-    STATE(288), 1,
-      sym_invoke_arg,
-  [5645] = 6,
+    STATE(294), 2,
+      sym_port,
+      sym_literal,
+  [5648] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(168), 1,
-      sym_ident,
+    ACTIONS(621), 1,
+      anon_sym_LPAREN,
     ACTIONS(623), 1,
-      anon_sym_AT,
-    STATE(277), 1,
-      sym_at_attribute,
-    STATE(181), 2,
+      anon_sym_LBRACK,
+    STATE(181), 1,
       sym_comment,
-      aux_sym_io_port_repeat1,
-  [5665] = 5,
+    STATE(301), 1,
+      sym_invoke_ref_args,
+    STATE(305), 1,
+      sym_invoke_args,
+  [5670] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(182), 1,
-      sym_comment,
-    ACTIONS(626), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(628), 2,
-      anon_sym_ref,
+    ACTIONS(202), 1,
       sym_ident,
-  [5683] = 7,
+    ACTIONS(635), 1,
+      anon_sym_AT,
+    STATE(262), 1,
+      sym_at_attribute,
+    STATE(182), 2,
+      sym_comment,
+      aux_sym_io_port_repeat1,
+  [5690] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    ACTIONS(613), 1,
-      anon_sym_LBRACK,
+    ACTIONS(174), 1,
+      sym_ident,
     STATE(183), 1,
       sym_comment,
-    STATE(270), 1,
-      sym_invoke_args,
-    STATE(271), 1,
-      sym_invoke_ref_args,
-  [5705] = 7,
+    STATE(352), 1,
+      sym_lhs,
+    STATE(326), 2,
+      sym_hole,
+      sym_port,
+  [5710] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(630), 1,
-      anon_sym_RBRACK,
-    ACTIONS(632), 1,
-      sym_ident,
+    ACTIONS(21), 1,
+      anon_sym_static,
     STATE(184), 1,
       sym_comment,
-    STATE(210), 1,
-      aux_sym_invoke_ref_args_repeat1,
     STATE(292), 1,
-      sym_invoke_ref_arg,
-  [5727] = 7,
+      sym_static_annotation,
+    ACTIONS(638), 2,
+      anon_sym_component,
+      anon_sym_primitive,
+  [5730] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(542), 1,
-      anon_sym_if,
-    ACTIONS(634), 1,
-      anon_sym_invoke,
-    ACTIONS(636), 1,
-      anon_sym_seq,
-    ACTIONS(638), 1,
-      anon_sym_par,
     STATE(185), 1,
       sym_comment,
-  [5749] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(640), 1,
-      anon_sym_GT,
-    ACTIONS(642), 1,
-      sym_string,
-    STATE(354), 1,
-      sym_attribute,
-    STATE(186), 2,
-      sym_comment,
-      aux_sym_attributes_repeat1,
-  [5769] = 7,
+    ACTIONS(640), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(642), 2,
+      anon_sym_ref,
+      sym_ident,
+  [5748] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(121), 1,
-      anon_sym_AT,
-    ACTIONS(645), 1,
-      sym_ident,
-    STATE(181), 1,
-      aux_sym_io_port_repeat1,
-    STATE(187), 1,
+    ACTIONS(621), 1,
+      anon_sym_LPAREN,
+    ACTIONS(623), 1,
+      anon_sym_LBRACK,
+    STATE(186), 1,
       sym_comment,
-    STATE(277), 1,
-      sym_at_attribute,
-  [5791] = 6,
+    STATE(287), 1,
+      sym_invoke_args,
+    STATE(289), 1,
+      sym_invoke_ref_args,
+  [5770] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(21), 1,
-      anon_sym_static,
-    STATE(188), 1,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    ACTIONS(644), 1,
+      anon_sym_GT,
+    ACTIONS(646), 1,
+      sym_string,
+    STATE(450), 1,
+      sym_attribute,
+    STATE(187), 2,
       sym_comment,
-    STATE(262), 1,
-      sym_static_annotation,
-    ACTIONS(647), 2,
-      anon_sym_component,
-      anon_sym_primitive,
-  [5811] = 7,
+      aux_sym_attributes_repeat1,
+  [5790] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(544), 1,
       anon_sym_if,
-    ACTIONS(634), 1,
+    ACTIONS(649), 1,
       anon_sym_invoke,
-    ACTIONS(636), 1,
+    ACTIONS(651), 1,
       anon_sym_seq,
-    ACTIONS(638), 1,
+    ACTIONS(653), 1,
       anon_sym_par,
+    STATE(188), 1,
+      sym_comment,
+  [5812] = 5,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
     STATE(189), 1,
       sym_comment,
-  [5833] = 7,
+    ACTIONS(554), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(556), 2,
+      anon_sym_ref,
+      sym_ident,
+  [5830] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(617), 1,
-      sym_string,
-    ACTIONS(649), 1,
-      anon_sym_GT,
-    STATE(186), 1,
-      aux_sym_attributes_repeat1,
     STATE(190), 1,
       sym_comment,
-    STATE(291), 1,
-      sym_attribute,
-  [5855] = 4,
+    ACTIONS(564), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(566), 2,
+      anon_sym_ref,
+      sym_ident,
+  [5848] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(613), 1,
+      sym_string,
+    ACTIONS(655), 1,
+      anon_sym_GT,
+    STATE(175), 1,
+      aux_sym_attributes_repeat1,
     STATE(191), 1,
       sym_comment,
-    ACTIONS(651), 4,
-      anon_sym_d,
-      anon_sym_b,
-      anon_sym_x,
-      anon_sym_o,
-  [5871] = 6,
+    STATE(264), 1,
+      sym_attribute,
+  [5870] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(179), 1,
+    ACTIONS(657), 1,
+      anon_sym_RPAREN,
+    ACTIONS(659), 1,
       sym_ident,
     STATE(192), 1,
       sym_comment,
-    STATE(450), 1,
-      sym_lhs,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [5891] = 5,
+    STATE(229), 1,
+      aux_sym_invoke_args_repeat1,
+    STATE(256), 1,
+      sym_invoke_arg,
+  [5892] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(653), 1,
+    ACTIONS(661), 1,
       anon_sym_LPAREN,
     STATE(193), 1,
       sym_comment,
-    ACTIONS(229), 3,
+    ACTIONS(341), 3,
       anon_sym_if,
       anon_sym_static,
       anon_sym_AT,
-  [5909] = 7,
+  [5910] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(655), 1,
-      anon_sym_invoke,
-    ACTIONS(657), 1,
-      anon_sym_seq,
-    ACTIONS(659), 1,
-      anon_sym_par,
-    ACTIONS(661), 1,
-      anon_sym_if,
+    ACTIONS(663), 1,
+      anon_sym_RBRACK,
+    ACTIONS(665), 1,
+      sym_ident,
     STATE(194), 1,
       sym_comment,
-  [5931] = 5,
+    STATE(230), 1,
+      aux_sym_invoke_ref_args_repeat1,
+    STATE(266), 1,
+      sym_invoke_ref_arg,
+  [5932] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(195), 1,
       sym_comment,
-    ACTIONS(585), 2,
+    ACTIONS(667), 4,
       anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(587), 2,
-      anon_sym_ref,
-      sym_ident,
-  [5949] = 7,
+      anon_sym_comb,
+      anon_sym_primitive,
+      anon_sym_static,
+  [5948] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(655), 1,
+    ACTIONS(546), 1,
+      anon_sym_if,
+    ACTIONS(649), 1,
       anon_sym_invoke,
-    ACTIONS(657), 1,
+    ACTIONS(651), 1,
       anon_sym_seq,
-    ACTIONS(659), 1,
+    ACTIONS(653), 1,
       anon_sym_par,
-    ACTIONS(663), 1,
-      anon_sym_if,
     STATE(196), 1,
       sym_comment,
-  [5971] = 6,
+  [5970] = 7,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(179), 1,
-      sym_ident,
+    ACTIONS(625), 1,
+      anon_sym_invoke,
+    ACTIONS(627), 1,
+      anon_sym_seq,
+    ACTIONS(629), 1,
+      anon_sym_par,
+    ACTIONS(669), 1,
+      anon_sym_if,
     STATE(197), 1,
       sym_comment,
-    STATE(374), 1,
-      sym_lhs,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [5991] = 4,
+  [5992] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(174), 1,
+      sym_ident,
     STATE(198), 1,
       sym_comment,
-    ACTIONS(665), 4,
-      anon_sym_RBRACE,
-      anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_static,
-  [6007] = 5,
+    STATE(448), 1,
+      sym_lhs,
+    STATE(326), 2,
+      sym_hole,
+      sym_port,
+  [6012] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(199), 1,
       sym_comment,
-    ACTIONS(667), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(669), 2,
-      anon_sym_ref,
-      sym_ident,
-  [6025] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(671), 4,
+      anon_sym_d,
+      anon_sym_b,
+      anon_sym_x,
+      anon_sym_o,
+  [6028] = 6,
+    ACTIONS(673), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
+    ACTIONS(675), 1,
       aux_sym_comment_token2,
-    ACTIONS(671), 1,
-      anon_sym_LPAREN,
+    ACTIONS(677), 1,
+      anon_sym_RBRACE,
+    ACTIONS(679), 1,
+      sym_any_line,
     STATE(200), 1,
       sym_comment,
-    ACTIONS(229), 2,
-      anon_sym_AT,
-      sym_ident,
-  [6042] = 5,
+    STATE(236), 1,
+      aux_sym_primitive_blob_repeat1,
+  [6047] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(673), 1,
-      anon_sym_COMMA,
+    ACTIONS(681), 1,
+      anon_sym_SEMI,
+    ACTIONS(683), 1,
+      anon_sym_LBRACE,
     STATE(201), 1,
       sym_comment,
-    ACTIONS(675), 2,
-      anon_sym_RPAREN,
-      sym_number,
-  [6059] = 6,
+    STATE(360), 1,
+      sym_primitive_blob,
+  [6066] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
+    ACTIONS(685), 1,
+      anon_sym_RPAREN,
+    ACTIONS(687), 1,
+      sym_number,
     STATE(202), 1,
       sym_comment,
-    STATE(297), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6078] = 6,
+    STATE(224), 1,
+      aux_sym_arg_list_repeat1,
+  [6085] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(495), 1,
-      anon_sym_LT,
-    ACTIONS(677), 1,
-      anon_sym_LBRACE,
+    ACTIONS(477), 1,
+      anon_sym_control,
+    ACTIONS(689), 1,
+      anon_sym_RBRACE,
     STATE(203), 1,
       sym_comment,
-    STATE(411), 1,
-      sym_attributes,
-  [6097] = 5,
+    STATE(365), 1,
+      sym_control,
+  [6104] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(679), 1,
-      anon_sym_comb,
+    ACTIONS(633), 1,
+      sym_ident,
     STATE(204), 1,
       sym_comment,
-    ACTIONS(647), 2,
-      anon_sym_component,
-      anon_sym_primitive,
-  [6114] = 6,
+    STATE(275), 1,
+      sym_port,
+    STATE(276), 1,
+      sym_port_with,
+  [6123] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
+    ACTIONS(463), 1,
+      anon_sym_LT,
+    ACTIONS(691), 1,
+      anon_sym_LBRACE,
     STATE(205), 1,
       sym_comment,
-    STATE(265), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6133] = 6,
+    STATE(338), 1,
+      sym_attributes,
+  [6142] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
+    ACTIONS(459), 1,
+      anon_sym_LPAREN,
     STATE(206), 1,
       sym_comment,
-    STATE(263), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6152] = 6,
+    STATE(403), 1,
+      sym_io_port_list,
+    STATE(428), 1,
+      sym_signature,
+  [6161] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
     STATE(207), 1,
       sym_comment,
-    STATE(296), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6171] = 6,
+    ACTIONS(693), 3,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+      sym_ident,
+  [6176] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(589), 1,
-      anon_sym_RBRACE,
+    ACTIONS(695), 1,
+      anon_sym_LPAREN,
     STATE(208), 1,
       sym_comment,
-    STATE(405), 1,
-      sym_control,
-  [6190] = 4,
+    ACTIONS(341), 2,
+      anon_sym_AT,
+      sym_ident,
+  [6193] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(209), 1,
       sym_comment,
-    ACTIONS(681), 3,
-      anon_sym_RBRACE,
-      anon_sym_wires,
-      anon_sym_control,
-  [6205] = 6,
+    ACTIONS(528), 3,
+      anon_sym_RPAREN,
+      anon_sym_AT,
+      sym_ident,
+  [6208] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(632), 1,
-      sym_ident,
     STATE(210), 1,
       sym_comment,
-    STATE(223), 1,
-      aux_sym_invoke_ref_args_repeat1,
-    STATE(319), 1,
-      sym_invoke_ref_arg,
-  [6224] = 6,
+    ACTIONS(697), 3,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_LBRACE,
+  [6223] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(621), 1,
-      sym_ident,
     STATE(211), 1,
       sym_comment,
-    STATE(221), 1,
-      aux_sym_invoke_args_repeat1,
-    STATE(322), 1,
-      sym_invoke_arg,
-  [6243] = 6,
+    ACTIONS(699), 3,
+      anon_sym_RBRACE,
+      anon_sym_wires,
+      anon_sym_control,
+  [6238] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(683), 1,
-      anon_sym_RBRACE,
+    ACTIONS(633), 1,
+      sym_ident,
     STATE(212), 1,
       sym_comment,
-    STATE(453), 1,
-      sym_control,
-  [6262] = 4,
+    STATE(275), 1,
+      sym_port,
+    STATE(278), 1,
+      sym_port_with,
+  [6257] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(463), 1,
+      anon_sym_LT,
+    ACTIONS(701), 1,
+      anon_sym_LBRACE,
     STATE(213), 1,
       sym_comment,
-    ACTIONS(685), 3,
-      anon_sym_SEMI,
-      anon_sym_LPAREN,
-      anon_sym_with,
-  [6277] = 6,
+    STATE(353), 1,
+      sym_attributes,
+  [6276] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(687), 1,
-      anon_sym_RPAREN,
-    ACTIONS(689), 1,
-      sym_number,
     STATE(214), 1,
       sym_comment,
-    STATE(230), 1,
-      aux_sym_arg_list_repeat1,
-  [6296] = 6,
+    ACTIONS(703), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_with,
+  [6291] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(609), 1,
+    ACTIONS(705), 1,
       sym_ident,
-    STATE(215), 1,
+    STATE(369), 1,
+      sym_invoke_arg,
+    STATE(215), 2,
       sym_comment,
-    STATE(272), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6315] = 4,
+      aux_sym_invoke_args_repeat1,
+  [6308] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(216), 1,
+    ACTIONS(708), 1,
+      sym_ident,
+    STATE(372), 1,
+      sym_invoke_ref_arg,
+    STATE(216), 2,
       sym_comment,
-    ACTIONS(390), 3,
-      anon_sym_if,
-      anon_sym_static,
-      anon_sym_AT,
-  [6330] = 6,
+      aux_sym_invoke_ref_args_repeat1,
+  [6325] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(691), 1,
-      anon_sym_SEMI,
-    ACTIONS(693), 1,
-      anon_sym_LBRACE,
     STATE(217), 1,
       sym_comment,
-    STATE(361), 1,
-      sym_primitive_blob,
-  [6349] = 6,
+    ACTIONS(711), 3,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_LBRACE,
+  [6340] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(495), 1,
-      anon_sym_LT,
-    ACTIONS(695), 1,
-      anon_sym_LBRACE,
     STATE(218), 1,
       sym_comment,
-    STATE(437), 1,
-      sym_attributes,
-  [6368] = 4,
+    ACTIONS(713), 3,
+      anon_sym_RBRACE,
+      anon_sym_wires,
+      anon_sym_control,
+  [6355] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(715), 1,
+      anon_sym_comb,
     STATE(219), 1,
       sym_comment,
-    ACTIONS(697), 3,
-      anon_sym_SEMI,
-      anon_sym_LPAREN,
-      anon_sym_with,
-  [6383] = 5,
-    ACTIONS(699), 1,
+    ACTIONS(638), 2,
+      anon_sym_component,
+      anon_sym_primitive,
+  [6372] = 6,
+    ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
+    ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(703), 1,
-      anon_sym_RBRACE,
-    ACTIONS(705), 1,
-      sym_any_line,
-    STATE(220), 2,
+    ACTIONS(459), 1,
+      anon_sym_LPAREN,
+    STATE(220), 1,
       sym_comment,
-      aux_sym_primitive_blob_repeat1,
-  [6400] = 5,
+    STATE(379), 1,
+      sym_signature,
+    STATE(403), 1,
+      sym_io_port_list,
+  [6391] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(708), 1,
-      sym_ident,
-    STATE(370), 1,
-      sym_invoke_arg,
-    STATE(221), 2,
+    STATE(221), 1,
       sym_comment,
-      aux_sym_invoke_args_repeat1,
-  [6417] = 6,
+    ACTIONS(717), 3,
+      anon_sym_LBRACE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+  [6406] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
     STATE(222), 1,
       sym_comment,
-    STATE(266), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6436] = 5,
+    ACTIONS(719), 3,
+      anon_sym_LBRACE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+  [6421] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(711), 1,
-      sym_ident,
-    STATE(373), 1,
-      sym_invoke_ref_arg,
+    ACTIONS(721), 1,
+      anon_sym_RPAREN,
+    ACTIONS(723), 1,
+      sym_number,
     STATE(223), 2,
       sym_comment,
-      aux_sym_invoke_ref_args_repeat1,
-  [6453] = 6,
+      aux_sym_arg_list_repeat1,
+  [6438] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(517), 1,
-      anon_sym_RBRACE,
+    ACTIONS(687), 1,
+      sym_number,
+    ACTIONS(726), 1,
+      anon_sym_RPAREN,
+    STATE(223), 1,
+      aux_sym_arg_list_repeat1,
     STATE(224), 1,
       sym_comment,
-    STATE(360), 1,
-      sym_control,
-  [6472] = 4,
+  [6457] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(459), 1,
+      anon_sym_LPAREN,
     STATE(225), 1,
       sym_comment,
-    ACTIONS(714), 3,
-      anon_sym_LBRACE,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-  [6487] = 4,
+    STATE(242), 1,
+      sym_signature,
+    STATE(403), 1,
+      sym_io_port_list,
+  [6476] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(226), 1,
       sym_comment,
-    ACTIONS(716), 3,
+    ACTIONS(728), 3,
       anon_sym_SEMI,
-      anon_sym_DASH_GT,
-      anon_sym_LBRACE,
-  [6502] = 6,
+      anon_sym_LPAREN,
+      anon_sym_with,
+  [6491] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
-    STATE(217), 1,
-      sym_signature,
     STATE(227), 1,
       sym_comment,
-    STATE(412), 1,
-      sym_io_port_list,
-  [6521] = 6,
+    ACTIONS(730), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_with,
+  [6506] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(693), 1,
-      anon_sym_LBRACE,
-    ACTIONS(718), 1,
-      anon_sym_SEMI,
     STATE(228), 1,
       sym_comment,
-    STATE(344), 1,
-      sym_primitive_blob,
-  [6540] = 6,
-    ACTIONS(699), 1,
+    ACTIONS(732), 3,
+      anon_sym_LBRACE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+  [6521] = 6,
+    ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
+    ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(720), 1,
-      anon_sym_RBRACE,
-    ACTIONS(722), 1,
-      sym_any_line,
-    STATE(220), 1,
-      aux_sym_primitive_blob_repeat1,
+    ACTIONS(659), 1,
+      sym_ident,
+    STATE(215), 1,
+      aux_sym_invoke_args_repeat1,
     STATE(229), 1,
       sym_comment,
-  [6559] = 5,
+    STATE(283), 1,
+      sym_invoke_arg,
+  [6540] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(724), 1,
-      anon_sym_RPAREN,
-    ACTIONS(726), 1,
-      sym_number,
-    STATE(230), 2,
+    ACTIONS(665), 1,
+      sym_ident,
+    STATE(216), 1,
+      aux_sym_invoke_ref_args_repeat1,
+    STATE(230), 1,
       sym_comment,
-      aux_sym_arg_list_repeat1,
-  [6576] = 4,
+    STATE(284), 1,
+      sym_invoke_ref_arg,
+  [6559] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(477), 1,
+      anon_sym_control,
+    ACTIONS(538), 1,
+      anon_sym_RBRACE,
     STATE(231), 1,
       sym_comment,
-    ACTIONS(729), 3,
-      anon_sym_SEMI,
-      anon_sym_LPAREN,
-      anon_sym_with,
-  [6591] = 6,
-    ACTIONS(3), 1,
+    STATE(460), 1,
+      sym_control,
+  [6578] = 5,
+    ACTIONS(673), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
+    ACTIONS(675), 1,
       aux_sym_comment_token2,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(499), 1,
+    ACTIONS(734), 1,
       anon_sym_RBRACE,
-    STATE(232), 1,
+    ACTIONS(736), 1,
+      sym_any_line,
+    STATE(232), 2,
       sym_comment,
-    STATE(353), 1,
-      sym_control,
-  [6610] = 4,
+      aux_sym_primitive_blob_repeat1,
+  [6595] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(739), 1,
+      anon_sym_LPAREN,
     STATE(233), 1,
       sym_comment,
-    ACTIONS(731), 3,
-      anon_sym_LBRACE,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-  [6625] = 4,
+    ACTIONS(345), 2,
+      anon_sym_ref,
+      sym_ident,
+  [6612] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(234), 1,
       sym_comment,
-    ACTIONS(733), 3,
-      anon_sym_SEMI,
-      anon_sym_DASH_GT,
-      anon_sym_LBRACE,
-  [6640] = 4,
+    ACTIONS(590), 3,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+      sym_ident,
+  [6627] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(235), 1,
       sym_comment,
-    ACTIONS(509), 3,
-      anon_sym_RPAREN,
+    ACTIONS(607), 3,
+      anon_sym_RBRACE,
       anon_sym_AT,
       sym_ident,
-  [6655] = 6,
-    ACTIONS(3), 1,
+  [6642] = 6,
+    ACTIONS(673), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
+    ACTIONS(675), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
+    ACTIONS(679), 1,
+      sym_any_line,
+    ACTIONS(741), 1,
+      anon_sym_RBRACE,
+    STATE(232), 1,
+      aux_sym_primitive_blob_repeat1,
     STATE(236), 1,
       sym_comment,
-    STATE(359), 1,
-      sym_signature,
-    STATE(412), 1,
-      sym_io_port_list,
-  [6674] = 6,
+  [6661] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
-    STATE(228), 1,
-      sym_signature,
     STATE(237), 1,
       sym_comment,
-    STATE(412), 1,
-      sym_io_port_list,
-  [6693] = 6,
+    ACTIONS(379), 3,
+      anon_sym_if,
+      anon_sym_static,
+      anon_sym_AT,
+  [6676] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(693), 1,
-      anon_sym_LBRACE,
-    ACTIONS(735), 1,
-      anon_sym_SEMI,
+    ACTIONS(633), 1,
+      sym_ident,
     STATE(238), 1,
       sym_comment,
-    STATE(362), 1,
-      sym_primitive_blob,
-  [6712] = 6,
-    ACTIONS(699), 1,
+    STATE(275), 1,
+      sym_port,
+    STATE(319), 1,
+      sym_port_with,
+  [6695] = 6,
+    ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
+    ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(722), 1,
-      sym_any_line,
-    ACTIONS(737), 1,
-      anon_sym_RBRACE,
-    STATE(229), 1,
-      aux_sym_primitive_blob_repeat1,
+    ACTIONS(683), 1,
+      anon_sym_LBRACE,
+    ACTIONS(743), 1,
+      anon_sym_SEMI,
     STATE(239), 1,
       sym_comment,
-  [6731] = 5,
+    STATE(330), 1,
+      sym_primitive_blob,
+  [6714] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(739), 1,
-      anon_sym_LPAREN,
+    ACTIONS(633), 1,
+      sym_ident,
     STATE(240), 1,
       sym_comment,
-    ACTIONS(233), 2,
-      anon_sym_ref,
-      sym_ident,
-  [6748] = 4,
+    STATE(275), 1,
+      sym_port,
+    STATE(323), 1,
+      sym_port_with,
+  [6733] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(241), 1,
       sym_comment,
-    ACTIONS(741), 3,
-      anon_sym_RBRACE,
+    ACTIONS(392), 3,
+      anon_sym_if,
+      anon_sym_static,
       anon_sym_AT,
-      sym_ident,
-  [6763] = 6,
+  [6748] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
+    ACTIONS(683), 1,
+      anon_sym_LBRACE,
+    ACTIONS(745), 1,
+      anon_sym_SEMI,
     STATE(242), 1,
       sym_comment,
-    STATE(298), 1,
-      sym_port,
-    STATE(312), 1,
-      sym_port_with,
-  [6782] = 4,
+    STATE(407), 1,
+      sym_primitive_blob,
+  [6767] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(633), 1,
+      sym_ident,
     STATE(243), 1,
       sym_comment,
-    ACTIONS(591), 3,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-      sym_ident,
-  [6797] = 4,
+    STATE(275), 1,
+      sym_port,
+    STATE(308), 1,
+      sym_port_with,
+  [6786] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(633), 1,
+      sym_ident,
     STATE(244), 1,
       sym_comment,
-    ACTIONS(546), 3,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-      sym_ident,
-  [6812] = 4,
+    STATE(275), 1,
+      sym_port,
+    STATE(310), 1,
+      sym_port_with,
+  [6805] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(459), 1,
+      anon_sym_LPAREN,
+    STATE(201), 1,
+      sym_signature,
     STATE(245), 1,
       sym_comment,
-    ACTIONS(743), 3,
-      anon_sym_LBRACE,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-  [6827] = 4,
+    STATE(403), 1,
+      sym_io_port_list,
+  [6824] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(633), 1,
+      sym_ident,
     STATE(246), 1,
       sym_comment,
-    ACTIONS(365), 3,
-      anon_sym_if,
-      anon_sym_static,
-      anon_sym_AT,
-  [6842] = 4,
+    STATE(275), 1,
+      sym_port,
+    STATE(311), 1,
+      sym_port_with,
+  [6843] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(459), 1,
+      anon_sym_LPAREN,
+    STATE(239), 1,
+      sym_signature,
     STATE(247), 1,
       sym_comment,
-    ACTIONS(745), 3,
-      anon_sym_SEMI,
-      anon_sym_DASH_GT,
-      anon_sym_LBRACE,
-  [6857] = 6,
+    STATE(403), 1,
+      sym_io_port_list,
+  [6862] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
     STATE(248), 1,
       sym_comment,
-    STATE(259), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6876] = 6,
+    ACTIONS(747), 3,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_LBRACE,
+  [6877] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
-    STATE(238), 1,
-      sym_signature,
+    ACTIONS(471), 1,
+      anon_sym_RBRACE,
+    ACTIONS(477), 1,
+      anon_sym_control,
     STATE(249), 1,
       sym_comment,
-    STATE(412), 1,
-      sym_io_port_list,
-  [6895] = 6,
+    STATE(389), 1,
+      sym_control,
+  [6896] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(693), 1,
-      anon_sym_LBRACE,
-    ACTIONS(747), 1,
-      anon_sym_SEMI,
+    ACTIONS(633), 1,
+      sym_ident,
     STATE(250), 1,
       sym_comment,
-    STATE(397), 1,
-      sym_primitive_blob,
-  [6914] = 6,
+    STATE(275), 1,
+      sym_port,
+    STATE(314), 1,
+      sym_port_with,
+  [6915] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
+    ACTIONS(633), 1,
+      sym_ident,
     STATE(251), 1,
       sym_comment,
-    STATE(401), 1,
-      sym_signature,
-    STATE(412), 1,
-      sym_io_port_list,
-  [6933] = 6,
+    STATE(275), 1,
+      sym_port,
+    STATE(277), 1,
+      sym_port_with,
+  [6934] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(689), 1,
-      sym_number,
-    ACTIONS(749), 1,
-      anon_sym_RPAREN,
-    STATE(214), 1,
-      aux_sym_arg_list_repeat1,
+    ACTIONS(477), 1,
+      anon_sym_control,
+    ACTIONS(584), 1,
+      anon_sym_RBRACE,
     STATE(252), 1,
       sym_comment,
-  [6952] = 4,
+    STATE(331), 1,
+      sym_control,
+  [6953] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(633), 1,
+      sym_ident,
     STATE(253), 1,
       sym_comment,
-    ACTIONS(751), 3,
-      anon_sym_RBRACE,
-      anon_sym_wires,
-      anon_sym_control,
-  [6967] = 6,
+    STATE(275), 1,
+      sym_port,
+    STATE(321), 1,
+      sym_port_with,
+  [6972] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
+    ACTIONS(749), 1,
+      anon_sym_COMMA,
     STATE(254), 1,
       sym_comment,
-    STATE(274), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6986] = 6,
+    ACTIONS(751), 2,
+      anon_sym_RPAREN,
+      sym_number,
+  [6989] = 6,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
+    ACTIONS(683), 1,
+      anon_sym_LBRACE,
+    ACTIONS(753), 1,
+      anon_sym_SEMI,
     STATE(255), 1,
       sym_comment,
-    STATE(273), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [7005] = 4,
+    STATE(402), 1,
+      sym_primitive_blob,
+  [7008] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(256), 1,
-      sym_comment,
-    ACTIONS(753), 2,
+    ACTIONS(755), 1,
       anon_sym_COMMA,
+    ACTIONS(757), 1,
       anon_sym_RPAREN,
-  [7019] = 4,
+    STATE(256), 1,
+      sym_comment,
+  [7024] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(759), 1,
+      sym_ident,
+    STATE(158), 1,
+      sym_instantiation,
     STATE(257), 1,
       sym_comment,
-    ACTIONS(755), 2,
-      anon_sym_COMMA,
-      anon_sym_GT,
-  [7033] = 5,
+  [7040] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(412), 1,
-      anon_sym_LBRACE,
-    STATE(56), 1,
-      sym_block,
     STATE(258), 1,
       sym_comment,
-  [7049] = 5,
+    ACTIONS(392), 2,
+      anon_sym_AT,
+      sym_ident,
+  [7054] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(412), 1,
-      anon_sym_LBRACE,
-    STATE(63), 1,
-      sym_block,
+    ACTIONS(759), 1,
+      sym_ident,
+    STATE(172), 1,
+      sym_instantiation,
     STATE(259), 1,
       sym_comment,
-  [7065] = 5,
+  [7070] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(757), 1,
-      anon_sym_component,
-    ACTIONS(759), 1,
-      anon_sym_primitive,
+    ACTIONS(761), 1,
+      anon_sym_ref,
+    ACTIONS(763), 1,
+      sym_ident,
     STATE(260), 1,
       sym_comment,
-  [7081] = 5,
+  [7086] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(412), 1,
-      anon_sym_LBRACE,
-    STATE(66), 1,
-      sym_block,
+    ACTIONS(512), 1,
+      anon_sym_RPAREN,
+    ACTIONS(765), 1,
+      anon_sym_COMMA,
     STATE(261), 1,
       sym_comment,
-  [7097] = 4,
+  [7102] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(262), 1,
       sym_comment,
-    ACTIONS(761), 2,
-      anon_sym_component,
-      anon_sym_primitive,
-  [7111] = 5,
+    ACTIONS(379), 2,
+      anon_sym_AT,
+      sym_ident,
+  [7116] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    STATE(44), 1,
-      sym_block,
     STATE(263), 1,
       sym_comment,
-  [7127] = 5,
+    ACTIONS(767), 2,
+      anon_sym_RBRACE,
+      anon_sym_control,
+  [7130] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(763), 1,
-      sym_ident,
-    STATE(162), 1,
-      sym_instantiation,
+    ACTIONS(611), 1,
+      anon_sym_GT,
+    ACTIONS(769), 1,
+      anon_sym_COMMA,
     STATE(264), 1,
       sym_comment,
-  [7143] = 5,
+  [7146] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
     STATE(265), 1,
       sym_comment,
-    STATE(306), 1,
-      sym_block,
-  [7159] = 5,
+    ACTIONS(771), 2,
+      anon_sym_RBRACE,
+      anon_sym_control,
+  [7160] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(412), 1,
-      anon_sym_LBRACE,
-    STATE(44), 1,
-      sym_block,
+    ACTIONS(773), 1,
+      anon_sym_COMMA,
+    ACTIONS(775), 1,
+      anon_sym_RBRACK,
     STATE(266), 1,
       sym_comment,
-  [7175] = 5,
+  [7176] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    STATE(43), 1,
-      sym_block,
+    ACTIONS(621), 1,
+      anon_sym_LPAREN,
     STATE(267), 1,
       sym_comment,
-  [7191] = 5,
+    STATE(285), 1,
+      sym_invoke_args,
+  [7192] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(187), 1,
-      anon_sym_RBRACE,
-    ACTIONS(765), 1,
-      anon_sym_else,
+    ACTIONS(777), 1,
+      anon_sym_SEMI,
+    ACTIONS(779), 1,
+      anon_sym_with,
     STATE(268), 1,
       sym_comment,
-  [7207] = 5,
+  [7208] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(412), 1,
-      anon_sym_LBRACE,
-    STATE(43), 1,
-      sym_block,
+    ACTIONS(459), 1,
+      anon_sym_LPAREN,
     STATE(269), 1,
       sym_comment,
-  [7223] = 5,
+    STATE(300), 1,
+      sym_io_port_list,
+  [7224] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
+    ACTIONS(781), 1,
+      anon_sym_COMMA,
+    ACTIONS(783), 1,
+      anon_sym_RBRACK,
     STATE(270), 1,
       sym_comment,
-    STATE(295), 1,
-      sym_invoke_args,
-  [7239] = 5,
+  [7240] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
+    ACTIONS(785), 1,
+      sym_ident,
     STATE(271), 1,
       sym_comment,
-    STATE(294), 1,
-      sym_invoke_args,
-  [7255] = 5,
+    STATE(304), 1,
+      aux_sym_params_repeat1,
+  [7256] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(767), 1,
-      anon_sym_LBRACE,
-    STATE(38), 1,
-      sym_block,
+    ACTIONS(621), 1,
+      anon_sym_LPAREN,
     STATE(272), 1,
       sym_comment,
-  [7271] = 5,
+    STATE(287), 1,
+      sym_invoke_args,
+  [7272] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(767), 1,
-      anon_sym_LBRACE,
-    STATE(35), 1,
-      sym_block,
+    ACTIONS(215), 1,
+      anon_sym_RBRACE,
+    ACTIONS(787), 1,
+      anon_sym_else,
     STATE(273), 1,
       sym_comment,
-  [7287] = 5,
+  [7288] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(767), 1,
+    ACTIONS(396), 1,
       anon_sym_LBRACE,
-    STATE(32), 1,
+    STATE(54), 1,
       sym_block,
     STATE(274), 1,
       sym_comment,
-  [7303] = 5,
+  [7304] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(769), 1,
-      sym_ident,
+    ACTIONS(789), 1,
+      anon_sym_LBRACE,
+    ACTIONS(791), 1,
+      anon_sym_with,
     STATE(275), 1,
       sym_comment,
-    STATE(284), 1,
-      aux_sym_params_repeat1,
-  [7319] = 5,
+  [7320] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(503), 1,
-      anon_sym_RPAREN,
-    ACTIONS(771), 1,
-      anon_sym_COMMA,
+    ACTIONS(396), 1,
+      anon_sym_LBRACE,
     STATE(276), 1,
       sym_comment,
-  [7335] = 4,
+    STATE(309), 1,
+      sym_block,
+  [7336] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(396), 1,
+      anon_sym_LBRACE,
     STATE(277), 1,
       sym_comment,
-    ACTIONS(365), 2,
-      anon_sym_AT,
-      sym_ident,
-  [7349] = 5,
+    STATE(291), 1,
+      sym_block,
+  [7352] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(773), 1,
-      anon_sym_LPAREN,
-    STATE(174), 1,
-      sym_arg_list,
+    ACTIONS(396), 1,
+      anon_sym_LBRACE,
+    STATE(71), 1,
+      sym_block,
     STATE(278), 1,
       sym_comment,
-  [7365] = 5,
+  [7368] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(763), 1,
-      sym_ident,
-    STATE(169), 1,
-      sym_instantiation,
+    ACTIONS(396), 1,
+      anon_sym_LBRACE,
+    STATE(55), 1,
+      sym_block,
     STATE(279), 1,
       sym_comment,
-  [7381] = 5,
+  [7384] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(649), 1,
-      anon_sym_GT,
-    ACTIONS(775), 1,
-      anon_sym_COMMA,
     STATE(280), 1,
       sym_comment,
-  [7397] = 5,
+    ACTIONS(793), 2,
+      sym_ident,
+      sym_number,
+  [7398] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
+    ACTIONS(759), 1,
+      sym_ident,
+    STATE(161), 1,
+      sym_instantiation,
     STATE(281), 1,
       sym_comment,
-    STATE(300), 1,
-      sym_io_port_list,
-  [7413] = 5,
+  [7414] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(777), 1,
-      anon_sym_COMMA,
-    ACTIONS(779), 1,
-      anon_sym_RBRACK,
+    ACTIONS(795), 1,
+      anon_sym_LPAREN,
+    STATE(163), 1,
+      sym_arg_list,
     STATE(282), 1,
       sym_comment,
-  [7429] = 4,
+  [7430] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(755), 1,
+      anon_sym_COMMA,
+    ACTIONS(797), 1,
+      anon_sym_RPAREN,
     STATE(283), 1,
       sym_comment,
-    ACTIONS(392), 2,
-      anon_sym_ref,
-      sym_ident,
-  [7443] = 5,
+  [7446] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(781), 1,
-      sym_ident,
+    ACTIONS(773), 1,
+      anon_sym_COMMA,
+    ACTIONS(799), 1,
+      anon_sym_RBRACK,
     STATE(284), 1,
       sym_comment,
-    STATE(307), 1,
-      aux_sym_params_repeat1,
-  [7459] = 4,
+  [7462] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(801), 1,
+      anon_sym_SEMI,
+    ACTIONS(803), 1,
+      anon_sym_with,
     STATE(285), 1,
       sym_comment,
-    ACTIONS(783), 2,
-      sym_ident,
-      sym_number,
-  [7473] = 5,
+  [7478] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(771), 1,
-      anon_sym_COMMA,
-    ACTIONS(785), 1,
-      anon_sym_RPAREN,
     STATE(286), 1,
       sym_comment,
-  [7489] = 5,
+    ACTIONS(221), 2,
+      anon_sym_RBRACE,
+      anon_sym_else,
+  [7492] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(787), 1,
-      anon_sym_SEMI,
-    ACTIONS(789), 1,
-      anon_sym_with,
+    ACTIONS(621), 1,
+      anon_sym_LPAREN,
     STATE(287), 1,
       sym_comment,
-  [7505] = 5,
+    STATE(296), 1,
+      sym_invoke_args,
+  [7508] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(791), 1,
+    ACTIONS(765), 1,
       anon_sym_COMMA,
-    ACTIONS(793), 1,
+    ACTIONS(805), 1,
       anon_sym_RPAREN,
     STATE(288), 1,
       sym_comment,
-  [7521] = 5,
+  [7524] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    STATE(56), 1,
-      sym_block,
+    ACTIONS(621), 1,
+      anon_sym_LPAREN,
     STATE(289), 1,
       sym_comment,
-  [7537] = 4,
+    STATE(297), 1,
+      sym_invoke_args,
+  [7540] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(290), 1,
       sym_comment,
-    ACTIONS(640), 2,
+    ACTIONS(644), 2,
       anon_sym_GT,
       sym_string,
-  [7551] = 5,
+  [7554] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(775), 1,
-      anon_sym_COMMA,
-    ACTIONS(795), 1,
-      anon_sym_GT,
+    ACTIONS(225), 1,
+      anon_sym_RBRACE,
+    ACTIONS(807), 1,
+      anon_sym_else,
     STATE(291), 1,
       sym_comment,
-  [7567] = 5,
+  [7570] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(797), 1,
-      anon_sym_COMMA,
-    ACTIONS(799), 1,
-      anon_sym_RBRACK,
     STATE(292), 1,
       sym_comment,
-  [7583] = 5,
+    ACTIONS(809), 2,
+      anon_sym_component,
+      anon_sym_primitive,
+  [7584] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    STATE(287), 1,
-      sym_invoke_args,
+    ACTIONS(769), 1,
+      anon_sym_COMMA,
+    ACTIONS(811), 1,
+      anon_sym_GT,
     STATE(293), 1,
       sym_comment,
-  [7599] = 5,
+  [7600] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
     STATE(294), 1,
       sym_comment,
-    STATE(324), 1,
-      sym_invoke_args,
-  [7615] = 5,
+    ACTIONS(813), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [7614] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(801), 1,
-      anon_sym_SEMI,
-    ACTIONS(803), 1,
-      anon_sym_with,
     STATE(295), 1,
       sym_comment,
-  [7631] = 5,
+    ACTIONS(815), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [7628] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    STATE(63), 1,
-      sym_block,
+    ACTIONS(817), 1,
+      anon_sym_SEMI,
+    ACTIONS(819), 1,
+      anon_sym_with,
     STATE(296), 1,
       sym_comment,
-  [7647] = 5,
+  [7644] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    STATE(268), 1,
-      sym_block,
+    ACTIONS(621), 1,
+      anon_sym_LPAREN,
     STATE(297), 1,
       sym_comment,
-  [7663] = 5,
+    STATE(299), 1,
+      sym_invoke_args,
+  [7660] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(805), 1,
-      anon_sym_LBRACE,
-    ACTIONS(807), 1,
-      anon_sym_with,
+    ACTIONS(821), 1,
+      anon_sym_component,
+    ACTIONS(823), 1,
+      anon_sym_primitive,
     STATE(298), 1,
       sym_comment,
-  [7679] = 5,
+  [7676] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(809), 1,
+    ACTIONS(825), 1,
       anon_sym_SEMI,
-    ACTIONS(811), 1,
+    ACTIONS(827), 1,
       anon_sym_with,
     STATE(299), 1,
       sym_comment,
-  [7695] = 4,
+  [7692] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(300), 1,
       sym_comment,
-    ACTIONS(813), 2,
+    ACTIONS(829), 2,
       anon_sym_SEMI,
       anon_sym_LBRACE,
-  [7709] = 4,
+  [7706] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(621), 1,
+      anon_sym_LPAREN,
+    STATE(267), 1,
+      sym_invoke_args,
     STATE(301), 1,
       sym_comment,
-    ACTIONS(815), 2,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-  [7723] = 4,
+  [7722] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(781), 1,
+      anon_sym_COMMA,
+    ACTIONS(831), 1,
+      anon_sym_RBRACK,
     STATE(302), 1,
       sym_comment,
-    ACTIONS(817), 2,
-      anon_sym_RBRACE,
-      anon_sym_control,
-  [7737] = 5,
+  [7738] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
     STATE(303), 1,
       sym_comment,
-    STATE(318), 1,
-      sym_invoke_args,
-  [7753] = 4,
+    ACTIONS(394), 2,
+      anon_sym_ref,
+      sym_ident,
+  [7752] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    STATE(304), 1,
+    ACTIONS(833), 1,
+      sym_ident,
+    STATE(304), 2,
       sym_comment,
-    ACTIONS(819), 2,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-  [7767] = 5,
+      aux_sym_params_repeat1,
+  [7766] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(777), 1,
-      anon_sym_COMMA,
-    ACTIONS(821), 1,
-      anon_sym_RBRACK,
+    ACTIONS(621), 1,
+      anon_sym_LPAREN,
+    STATE(268), 1,
+      sym_invoke_args,
     STATE(305), 1,
       sym_comment,
-  [7783] = 5,
-    ACTIONS(3), 1,
+  [7782] = 4,
+    ACTIONS(673), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
+    ACTIONS(675), 1,
       aux_sym_comment_token2,
-    ACTIONS(207), 1,
-      anon_sym_RBRACE,
-    ACTIONS(823), 1,
-      anon_sym_else,
     STATE(306), 1,
       sym_comment,
-  [7799] = 4,
+    ACTIONS(836), 2,
+      anon_sym_RBRACE,
+      sym_any_line,
+  [7796] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(825), 1,
+    ACTIONS(838), 1,
       sym_ident,
-    STATE(307), 2,
-      sym_comment,
+    STATE(271), 1,
       aux_sym_params_repeat1,
-  [7813] = 5,
+    STATE(307), 1,
+      sym_comment,
+  [7812] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(404), 1,
+    ACTIONS(840), 1,
       anon_sym_LBRACE,
-    STATE(66), 1,
+    STATE(36), 1,
       sym_block,
     STATE(308), 1,
       sym_comment,
-  [7829] = 4,
-    ACTIONS(699), 1,
+  [7828] = 5,
+    ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
+    ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(209), 1,
+      anon_sym_RBRACE,
+    ACTIONS(842), 1,
+      anon_sym_else,
     STATE(309), 1,
       sym_comment,
-    ACTIONS(828), 2,
-      anon_sym_RBRACE,
-      sym_any_line,
-  [7843] = 5,
+  [7844] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(763), 1,
-      sym_ident,
-    STATE(172), 1,
-      sym_instantiation,
+    ACTIONS(840), 1,
+      anon_sym_LBRACE,
+    STATE(37), 1,
+      sym_block,
     STATE(310), 1,
       sym_comment,
-  [7859] = 4,
+  [7860] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(840), 1,
+      anon_sym_LBRACE,
+    STATE(39), 1,
+      sym_block,
     STATE(311), 1,
       sym_comment,
-    ACTIONS(724), 2,
-      anon_sym_RPAREN,
-      sym_number,
-  [7873] = 5,
+  [7876] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(404), 1,
+    ACTIONS(396), 1,
       anon_sym_LBRACE,
+    STATE(69), 1,
+      sym_block,
     STATE(312), 1,
       sym_comment,
-    STATE(314), 1,
-      sym_block,
-  [7889] = 4,
+  [7892] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(418), 1,
+      anon_sym_LBRACE,
+    STATE(69), 1,
+      sym_block,
     STATE(313), 1,
       sym_comment,
-    ACTIONS(390), 2,
-      anon_sym_AT,
-      sym_ident,
-  [7903] = 5,
+  [7908] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(217), 1,
-      anon_sym_RBRACE,
-    ACTIONS(830), 1,
-      anon_sym_else,
+    ACTIONS(418), 1,
+      anon_sym_LBRACE,
+    STATE(74), 1,
+      sym_block,
     STATE(314), 1,
       sym_comment,
-  [7919] = 5,
+  [7924] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    STATE(293), 1,
-      sym_invoke_args,
     STATE(315), 1,
       sym_comment,
-  [7935] = 4,
+    ACTIONS(844), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [7938] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(316), 1,
       sym_comment,
-    ACTIONS(832), 2,
-      sym_ident,
-      sym_number,
-  [7949] = 4,
+    ACTIONS(846), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [7952] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(418), 1,
+      anon_sym_LBRACE,
+    STATE(54), 1,
+      sym_block,
     STATE(317), 1,
       sym_comment,
-    ACTIONS(834), 2,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-  [7963] = 5,
+  [7968] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    STATE(299), 1,
-      sym_invoke_args,
     STATE(318), 1,
       sym_comment,
-  [7979] = 5,
+    ACTIONS(848), 2,
+      sym_ident,
+      sym_number,
+  [7982] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(797), 1,
-      anon_sym_COMMA,
-    ACTIONS(836), 1,
-      anon_sym_RBRACK,
+    ACTIONS(396), 1,
+      anon_sym_LBRACE,
+    STATE(273), 1,
+      sym_block,
     STATE(319), 1,
       sym_comment,
-  [7995] = 4,
+  [7998] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     STATE(320), 1,
       sym_comment,
-    ACTIONS(838), 2,
-      anon_sym_RBRACE,
-      anon_sym_control,
-  [8009] = 5,
+    ACTIONS(850), 2,
+      anon_sym_COMMA,
+      anon_sym_GT,
+  [8012] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(840), 1,
-      anon_sym_ref,
-    ACTIONS(842), 1,
-      sym_ident,
+    ACTIONS(418), 1,
+      anon_sym_LBRACE,
+    STATE(71), 1,
+      sym_block,
     STATE(321), 1,
       sym_comment,
-  [8025] = 5,
+  [8028] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(791), 1,
-      anon_sym_COMMA,
-    ACTIONS(844), 1,
-      anon_sym_RPAREN,
+    ACTIONS(418), 1,
+      anon_sym_LBRACE,
+    STATE(55), 1,
+      sym_block,
     STATE(322), 1,
       sym_comment,
-  [8041] = 4,
+  [8044] = 5,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
+    ACTIONS(396), 1,
+      anon_sym_LBRACE,
+    STATE(74), 1,
+      sym_block,
     STATE(323), 1,
       sym_comment,
-    ACTIONS(213), 2,
-      anon_sym_RBRACE,
-      anon_sym_else,
-  [8055] = 5,
+  [8060] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(846), 1,
-      anon_sym_SEMI,
-    ACTIONS(848), 1,
-      anon_sym_with,
     STATE(324), 1,
       sym_comment,
-  [8071] = 4,
+    ACTIONS(721), 2,
+      anon_sym_RPAREN,
+      sym_number,
+  [8074] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(850), 1,
+    ACTIONS(852), 1,
       sym_number,
     STATE(325), 1,
       sym_comment,
-  [8084] = 4,
+  [8087] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(852), 1,
-      anon_sym_SEMI,
+    ACTIONS(854), 1,
+      anon_sym_EQ,
     STATE(326), 1,
       sym_comment,
-  [8097] = 4,
+  [8100] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(125), 1,
+    ACTIONS(856), 1,
       anon_sym_SEMI,
     STATE(327), 1,
       sym_comment,
-  [8110] = 4,
+  [8113] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(854), 1,
-      sym_number,
+    ACTIONS(858), 1,
+      anon_sym_EQ,
     STATE(328), 1,
       sym_comment,
-  [8123] = 4,
+  [8126] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(856), 1,
-      anon_sym_LBRACE,
+    ACTIONS(860), 1,
+      anon_sym_EQ,
     STATE(329), 1,
       sym_comment,
-  [8136] = 4,
+  [8139] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(858), 1,
-      anon_sym_LBRACE,
+    ACTIONS(681), 1,
+      anon_sym_SEMI,
     STATE(330), 1,
       sym_comment,
-  [8149] = 4,
+  [8152] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(860), 1,
-      sym_ident,
+    ACTIONS(689), 1,
+      anon_sym_RBRACE,
     STATE(331), 1,
       sym_comment,
-  [8162] = 4,
-    ACTIONS(3), 1,
+  [8165] = 4,
+    ACTIONS(673), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
+    ACTIONS(675), 1,
       aux_sym_comment_token2,
     ACTIONS(862), 1,
-      anon_sym_EQ,
+      anon_sym_LF,
     STATE(332), 1,
       sym_comment,
-  [8175] = 4,
+  [8178] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(864), 1,
-      sym_number,
+      anon_sym_SEMI,
     STATE(333), 1,
       sym_comment,
-  [8188] = 4,
+  [8191] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(866), 1,
-      anon_sym_EQ,
+      sym_ident,
     STATE(334), 1,
       sym_comment,
-  [8201] = 4,
+  [8204] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(868), 1,
-      anon_sym_RBRACE,
+      ts_builtin_sym_end,
     STATE(335), 1,
       sym_comment,
-  [8214] = 4,
+  [8217] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
@@ -9072,1118 +9076,1127 @@ static const uint16_t ts_small_parse_table[] = {
       sym_ident,
     STATE(336), 1,
       sym_comment,
-  [8227] = 4,
+  [8230] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(872), 1,
-      anon_sym_group,
+      sym_ident,
     STATE(337), 1,
       sym_comment,
-  [8240] = 4,
+  [8243] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(874), 1,
-      anon_sym_RBRACE,
+      anon_sym_LBRACE,
     STATE(338), 1,
       sym_comment,
-  [8253] = 4,
+  [8256] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(876), 1,
-      anon_sym_EQ,
+      anon_sym_SEMI,
     STATE(339), 1,
       sym_comment,
-  [8266] = 4,
+  [8269] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(878), 1,
-      anon_sym_SEMI,
+      sym_ident,
     STATE(340), 1,
       sym_comment,
-  [8279] = 4,
+  [8282] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(880), 1,
-      anon_sym_LPAREN,
+      sym_ident,
     STATE(341), 1,
       sym_comment,
-  [8292] = 4,
+  [8295] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(882), 1,
-      sym_ident,
+      anon_sym_LPAREN,
     STATE(342), 1,
       sym_comment,
-  [8305] = 4,
+  [8308] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(884), 1,
-      anon_sym_RPAREN,
+    ACTIONS(367), 1,
+      anon_sym_SEMI,
     STATE(343), 1,
       sym_comment,
-  [8318] = 4,
+  [8321] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(691), 1,
-      anon_sym_SEMI,
+    ACTIONS(884), 1,
+      anon_sym_EQ,
     STATE(344), 1,
       sym_comment,
-  [8331] = 4,
+  [8334] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(886), 1,
-      sym_ident,
+      anon_sym_LBRACE,
     STATE(345), 1,
       sym_comment,
-  [8344] = 4,
+  [8347] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(888), 1,
-      sym_ident,
+      anon_sym_RBRACE,
     STATE(346), 1,
       sym_comment,
-  [8357] = 4,
+  [8360] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(890), 1,
-      anon_sym_LPAREN,
+      sym_ident,
     STATE(347), 1,
       sym_comment,
-  [8370] = 4,
+  [8373] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
     ACTIONS(892), 1,
-      anon_sym_SEMI,
+      anon_sym_EQ,
     STATE(348), 1,
       sym_comment,
-  [8383] = 4,
+  [8386] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(777), 1,
-      anon_sym_COMMA,
+    ACTIONS(894), 1,
+      anon_sym_COLON,
     STATE(349), 1,
       sym_comment,
-  [8396] = 4,
+  [8399] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(894), 1,
-      sym_ident,
+    ACTIONS(544), 1,
+      anon_sym_if,
     STATE(350), 1,
       sym_comment,
-  [8409] = 4,
-    ACTIONS(699), 1,
+  [8412] = 4,
+    ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
+    ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(896), 1,
-      aux_sym_comment_token1,
+    ACTIONS(103), 1,
+      ts_builtin_sym_end,
     STATE(351), 1,
       sym_comment,
-  [8422] = 4,
+  [8425] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(544), 1,
-      anon_sym_if,
+    ACTIONS(896), 1,
+      anon_sym_EQ,
     STATE(352), 1,
       sym_comment,
-  [8435] = 4,
+  [8438] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(517), 1,
-      anon_sym_RBRACE,
+    ACTIONS(691), 1,
+      anon_sym_LBRACE,
     STATE(353), 1,
       sym_comment,
-  [8448] = 4,
+  [8451] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(775), 1,
-      anon_sym_COMMA,
+    ACTIONS(898), 1,
+      sym_number,
     STATE(354), 1,
       sym_comment,
-  [8461] = 4,
+  [8464] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(771), 1,
-      anon_sym_COMMA,
+    ACTIONS(900), 1,
+      anon_sym_RBRACK,
     STATE(355), 1,
       sym_comment,
-  [8474] = 4,
+  [8477] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(898), 1,
-      sym_ident,
+    ACTIONS(902), 1,
+      sym_number,
     STATE(356), 1,
       sym_comment,
-  [8487] = 4,
+  [8490] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(900), 1,
-      anon_sym_RBRACE,
+    ACTIONS(904), 1,
+      anon_sym_LPAREN,
     STATE(357), 1,
       sym_comment,
-  [8500] = 4,
+  [8503] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(902), 1,
-      anon_sym_LBRACE,
+    ACTIONS(906), 1,
+      anon_sym_EQ,
     STATE(358), 1,
       sym_comment,
-  [8513] = 4,
+  [8516] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(904), 1,
-      anon_sym_LBRACE,
+    ACTIONS(908), 1,
+      aux_sym_metadata_token1,
     STATE(359), 1,
       sym_comment,
-  [8526] = 4,
+  [8529] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(589), 1,
-      anon_sym_RBRACE,
+    ACTIONS(910), 1,
+      anon_sym_SEMI,
     STATE(360), 1,
       sym_comment,
-  [8539] = 4,
+  [8542] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(906), 1,
-      anon_sym_SEMI,
+    ACTIONS(912), 1,
+      anon_sym_group,
     STATE(361), 1,
       sym_comment,
-  [8552] = 4,
+  [8555] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(718), 1,
-      anon_sym_SEMI,
+    ACTIONS(914), 1,
+      sym_ident,
     STATE(362), 1,
       sym_comment,
-  [8565] = 4,
+  [8568] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(908), 1,
-      anon_sym_EQ,
+    ACTIONS(916), 1,
+      sym_ident,
     STATE(363), 1,
       sym_comment,
-  [8578] = 4,
+  [8581] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(910), 1,
-      anon_sym_SEMI,
+    ACTIONS(918), 1,
+      anon_sym_SQUOTE,
     STATE(364), 1,
       sym_comment,
-  [8591] = 4,
+  [8594] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(912), 1,
-      sym_ident,
+    ACTIONS(920), 1,
+      anon_sym_RBRACE,
     STATE(365), 1,
       sym_comment,
-  [8604] = 4,
+  [8607] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(914), 1,
+    ACTIONS(922), 1,
       sym_ident,
     STATE(366), 1,
       sym_comment,
-  [8617] = 4,
+  [8620] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(916), 1,
-      anon_sym_LPAREN,
+    ACTIONS(924), 1,
+      anon_sym_EQ,
     STATE(367), 1,
       sym_comment,
-  [8630] = 4,
+  [8633] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(918), 1,
-      sym_ident,
+    ACTIONS(926), 1,
+      anon_sym_LBRACE,
     STATE(368), 1,
       sym_comment,
-  [8643] = 4,
+  [8646] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(920), 1,
-      sym_ident,
+    ACTIONS(755), 1,
+      anon_sym_COMMA,
     STATE(369), 1,
       sym_comment,
-  [8656] = 4,
+  [8659] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(791), 1,
-      anon_sym_COMMA,
+    ACTIONS(928), 1,
+      sym_ident,
     STATE(370), 1,
       sym_comment,
-  [8669] = 4,
+  [8672] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(922), 1,
-      sym_ident,
+    ACTIONS(930), 1,
+      anon_sym_LPAREN,
     STATE(371), 1,
       sym_comment,
-  [8682] = 4,
+  [8685] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(924), 1,
-      anon_sym_LPAREN,
+    ACTIONS(773), 1,
+      anon_sym_COMMA,
     STATE(372), 1,
       sym_comment,
-  [8695] = 4,
+  [8698] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(797), 1,
-      anon_sym_COMMA,
+    ACTIONS(932), 1,
+      anon_sym_QMARK,
     STATE(373), 1,
       sym_comment,
-  [8708] = 4,
+  [8711] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(926), 1,
-      anon_sym_EQ,
+    ACTIONS(934), 1,
+      sym_ident,
     STATE(374), 1,
       sym_comment,
-  [8721] = 4,
+  [8724] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(928), 1,
-      sym_ident,
+    ACTIONS(817), 1,
+      anon_sym_SEMI,
     STATE(375), 1,
       sym_comment,
-  [8734] = 4,
+  [8737] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(809), 1,
-      anon_sym_SEMI,
+    ACTIONS(631), 1,
+      anon_sym_if,
     STATE(376), 1,
       sym_comment,
-  [8747] = 4,
+  [8750] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(663), 1,
-      anon_sym_if,
+    ACTIONS(936), 1,
+      sym_ident,
     STATE(377), 1,
       sym_comment,
-  [8760] = 4,
+  [8763] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(930), 1,
-      anon_sym_RBRACE,
+    ACTIONS(938), 1,
+      anon_sym_LBRACE,
     STATE(378), 1,
       sym_comment,
-  [8773] = 4,
+  [8776] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(499), 1,
-      anon_sym_RBRACE,
+    ACTIONS(940), 1,
+      anon_sym_LBRACE,
     STATE(379), 1,
       sym_comment,
-  [8786] = 4,
+  [8789] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(932), 1,
+    ACTIONS(942), 1,
       anon_sym_LBRACE,
     STATE(380), 1,
       sym_comment,
-  [8799] = 4,
+  [8802] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(934), 1,
+    ACTIONS(944), 1,
       anon_sym_LBRACE,
     STATE(381), 1,
       sym_comment,
-  [8812] = 4,
+  [8815] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(936), 1,
-      anon_sym_LBRACE,
+    ACTIONS(377), 1,
+      anon_sym_SEMI,
     STATE(382), 1,
       sym_comment,
-  [8825] = 4,
+  [8828] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(938), 1,
+    ACTIONS(946), 1,
       anon_sym_LBRACE,
     STATE(383), 1,
       sym_comment,
-  [8838] = 4,
+  [8841] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(940), 1,
-      sym_number,
+    ACTIONS(825), 1,
+      anon_sym_SEMI,
     STATE(384), 1,
       sym_comment,
-  [8851] = 4,
+  [8854] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(787), 1,
-      anon_sym_SEMI,
+    ACTIONS(948), 1,
+      anon_sym_LBRACE,
     STATE(385), 1,
       sym_comment,
-  [8864] = 4,
+  [8867] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(942), 1,
-      anon_sym_EQ,
+    ACTIONS(950), 1,
+      sym_ident,
     STATE(386), 1,
       sym_comment,
-  [8877] = 4,
+  [8880] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(944), 1,
-      sym_ident,
+    ACTIONS(952), 1,
+      anon_sym_RBRACE,
     STATE(387), 1,
       sym_comment,
-  [8890] = 4,
+  [8893] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(946), 1,
-      anon_sym_LPAREN,
+    ACTIONS(538), 1,
+      anon_sym_RBRACE,
     STATE(388), 1,
       sym_comment,
-  [8903] = 4,
+  [8906] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(948), 1,
-      sym_number,
+    ACTIONS(584), 1,
+      anon_sym_RBRACE,
     STATE(389), 1,
       sym_comment,
-  [8916] = 4,
+  [8919] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(950), 1,
-      anon_sym_EQ,
+    ACTIONS(954), 1,
+      anon_sym_SEMI,
     STATE(390), 1,
       sym_comment,
-  [8929] = 4,
+  [8932] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(952), 1,
-      anon_sym_SEMI,
+    ACTIONS(956), 1,
+      sym_ident,
     STATE(391), 1,
       sym_comment,
-  [8942] = 4,
+  [8945] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(954), 1,
-      sym_ident,
+    ACTIONS(958), 1,
+      anon_sym_LPAREN,
     STATE(392), 1,
       sym_comment,
-  [8955] = 4,
+  [8958] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(956), 1,
-      anon_sym_COLON,
+    ACTIONS(960), 1,
+      anon_sym_SEMI,
     STATE(393), 1,
       sym_comment,
-  [8968] = 4,
+  [8971] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(958), 1,
-      anon_sym_RBRACE,
+    ACTIONS(962), 1,
+      sym_ident,
     STATE(394), 1,
       sym_comment,
-  [8981] = 4,
+  [8984] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(960), 1,
-      anon_sym_RBRACE,
+      aux_sym_comment_token2,
+    ACTIONS(964), 1,
+      anon_sym_LPAREN,
     STATE(395), 1,
       sym_comment,
-  [8994] = 4,
+  [8997] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(759), 1,
-      anon_sym_primitive,
+    ACTIONS(966), 1,
+      anon_sym_EQ,
     STATE(396), 1,
       sym_comment,
-  [9007] = 4,
+  [9010] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(735), 1,
-      anon_sym_SEMI,
+    ACTIONS(968), 1,
+      sym_ident,
     STATE(397), 1,
       sym_comment,
-  [9020] = 4,
+  [9023] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(388), 1,
-      anon_sym_SEMI,
+    ACTIONS(970), 1,
+      ts_builtin_sym_end,
     STATE(398), 1,
       sym_comment,
-  [9033] = 4,
+  [9036] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(962), 1,
-      sym_ident,
+    ACTIONS(972), 1,
+      ts_builtin_sym_end,
     STATE(399), 1,
       sym_comment,
-  [9046] = 4,
+  [9049] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(964), 1,
-      anon_sym_LBRACE,
+    ACTIONS(974), 1,
+      anon_sym_SEMI,
     STATE(400), 1,
       sym_comment,
-  [9059] = 4,
+  [9062] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(966), 1,
+    ACTIONS(976), 1,
       anon_sym_LBRACE,
     STATE(401), 1,
       sym_comment,
-  [9072] = 4,
+  [9075] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(968), 1,
-      anon_sym_LBRACE,
+    ACTIONS(745), 1,
+      anon_sym_SEMI,
     STATE(402), 1,
       sym_comment,
-  [9085] = 4,
+  [9088] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(970), 1,
-      anon_sym_SEMI,
+    ACTIONS(978), 1,
+      anon_sym_DASH_GT,
     STATE(403), 1,
       sym_comment,
-  [9098] = 4,
+  [9101] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(972), 1,
-      anon_sym_EQ,
+    ACTIONS(980), 1,
+      anon_sym_RPAREN,
     STATE(404), 1,
       sym_comment,
-  [9111] = 4,
+  [9114] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(683), 1,
-      anon_sym_RBRACE,
+    ACTIONS(982), 1,
+      aux_sym_metadata_token1,
     STATE(405), 1,
       sym_comment,
-  [9124] = 4,
+  [9127] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(974), 1,
-      anon_sym_EQ,
+    ACTIONS(984), 1,
+      anon_sym_COLON,
     STATE(406), 1,
       sym_comment,
-  [9137] = 4,
+  [9140] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(976), 1,
-      anon_sym_COLON,
+    ACTIONS(743), 1,
+      anon_sym_SEMI,
     STATE(407), 1,
       sym_comment,
-  [9150] = 4,
+  [9153] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(978), 1,
-      sym_ident,
+    ACTIONS(986), 1,
+      sym_number,
     STATE(408), 1,
       sym_comment,
-  [9163] = 4,
+  [9166] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(980), 1,
-      ts_builtin_sym_end,
+    ACTIONS(988), 1,
+      sym_ident,
     STATE(409), 1,
       sym_comment,
-  [9176] = 4,
+  [9179] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(982), 1,
-      anon_sym_GT,
+    ACTIONS(134), 1,
+      anon_sym_SEMI,
     STATE(410), 1,
       sym_comment,
-  [9189] = 4,
+  [9192] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(695), 1,
-      anon_sym_LBRACE,
+    ACTIONS(990), 1,
+      sym_ident,
     STATE(411), 1,
       sym_comment,
-  [9202] = 4,
+  [9205] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(984), 1,
-      anon_sym_DASH_GT,
+    ACTIONS(992), 1,
+      anon_sym_RPAREN,
     STATE(412), 1,
       sym_comment,
-  [9215] = 4,
+  [9218] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(986), 1,
-      sym_ident,
+    ACTIONS(994), 1,
+      sym_string,
     STATE(413), 1,
       sym_comment,
-  [9228] = 4,
+  [9231] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(988), 1,
-      anon_sym_RPAREN,
+    ACTIONS(996), 1,
+      anon_sym_RBRACE,
     STATE(414), 1,
       sym_comment,
-  [9241] = 4,
+  [9244] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(990), 1,
-      anon_sym_RBRACK,
+    ACTIONS(388), 1,
+      anon_sym_SEMI,
     STATE(415), 1,
       sym_comment,
-  [9254] = 4,
+  [9257] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(992), 1,
-      anon_sym_LBRACE,
+    ACTIONS(998), 1,
+      anon_sym_SEMI,
     STATE(416), 1,
       sym_comment,
-  [9267] = 4,
+  [9270] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(379), 1,
-      anon_sym_SEMI,
+    ACTIONS(101), 1,
+      ts_builtin_sym_end,
     STATE(417), 1,
       sym_comment,
-  [9280] = 4,
+  [9283] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(994), 1,
-      anon_sym_SQUOTE,
+    ACTIONS(390), 1,
+      anon_sym_SEMI,
     STATE(418), 1,
       sym_comment,
-  [9293] = 4,
+  [9296] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(996), 1,
-      sym_number,
+    ACTIONS(1000), 1,
+      anon_sym_RBRACE,
     STATE(419), 1,
       sym_comment,
-  [9306] = 4,
+  [9309] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(381), 1,
-      anon_sym_SEMI,
+    ACTIONS(1002), 1,
+      sym_ident,
     STATE(420), 1,
       sym_comment,
-  [9319] = 4,
+  [9322] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(998), 1,
-      anon_sym_RBRACE,
+    ACTIONS(1004), 1,
+      sym_ident,
     STATE(421), 1,
       sym_comment,
-  [9332] = 4,
+  [9335] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1000), 1,
-      anon_sym_QMARK,
+    ACTIONS(1006), 1,
+      anon_sym_RPAREN,
     STATE(422), 1,
       sym_comment,
-  [9345] = 4,
+  [9348] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1002), 1,
-      sym_ident,
+    ACTIONS(1008), 1,
+      anon_sym_RBRACE,
     STATE(423), 1,
       sym_comment,
-  [9358] = 4,
+  [9361] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1004), 1,
-      anon_sym_RPAREN,
+    ACTIONS(1010), 1,
+      sym_ident,
     STATE(424), 1,
       sym_comment,
-  [9371] = 4,
+  [9374] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1006), 1,
-      anon_sym_RBRACE,
+    ACTIONS(1012), 1,
+      anon_sym_RPAREN,
     STATE(425), 1,
       sym_comment,
-  [9384] = 4,
+  [9387] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1008), 1,
-      sym_ident,
+    ACTIONS(1014), 1,
+      sym_number,
     STATE(426), 1,
       sym_comment,
-  [9397] = 4,
+  [9400] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1010), 1,
-      anon_sym_RPAREN,
+    ACTIONS(1016), 1,
+      anon_sym_RBRACE,
     STATE(427), 1,
       sym_comment,
-  [9410] = 4,
+  [9413] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1012), 1,
-      sym_number,
+    ACTIONS(1018), 1,
+      anon_sym_LBRACE,
     STATE(428), 1,
       sym_comment,
-  [9423] = 4,
+  [9426] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(105), 1,
-      ts_builtin_sym_end,
+    ACTIONS(1020), 1,
+      sym_ident,
     STATE(429), 1,
       sym_comment,
-  [9436] = 4,
+  [9439] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1014), 1,
-      sym_ident,
+    ACTIONS(1022), 1,
+      sym_string,
     STATE(430), 1,
       sym_comment,
-  [9449] = 4,
+  [9452] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1016), 1,
-      sym_ident,
+    ACTIONS(1024), 1,
+      sym_number,
     STATE(431), 1,
       sym_comment,
-  [9462] = 4,
+  [9465] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1018), 1,
-      ts_builtin_sym_end,
+    ACTIONS(1026), 1,
+      sym_number,
     STATE(432), 1,
       sym_comment,
-  [9475] = 4,
+  [9478] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1020), 1,
-      sym_number,
+    ACTIONS(1028), 1,
+      sym_ident,
     STATE(433), 1,
       sym_comment,
-  [9488] = 4,
+  [9491] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(369), 1,
-      anon_sym_SEMI,
+    ACTIONS(1030), 1,
+      sym_ident,
     STATE(434), 1,
       sym_comment,
-  [9501] = 4,
+  [9504] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1022), 1,
-      sym_ident,
+    ACTIONS(1032), 1,
+      anon_sym_RBRACE,
     STATE(435), 1,
       sym_comment,
-  [9514] = 4,
+  [9517] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1024), 1,
-      anon_sym_LBRACE,
+    ACTIONS(1034), 1,
+      sym_ident,
     STATE(436), 1,
       sym_comment,
-  [9527] = 4,
+  [9530] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1026), 1,
-      anon_sym_LBRACE,
+    ACTIONS(546), 1,
+      anon_sym_if,
     STATE(437), 1,
       sym_comment,
-  [9540] = 4,
+  [9543] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1028), 1,
-      sym_number,
+    ACTIONS(1036), 1,
+      anon_sym_GT,
     STATE(438), 1,
       sym_comment,
-  [9553] = 4,
+  [9556] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(542), 1,
-      anon_sym_if,
+    ACTIONS(1038), 1,
+      anon_sym_LBRACE,
     STATE(439), 1,
       sym_comment,
-  [9566] = 4,
+  [9569] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1030), 1,
-      anon_sym_SEMI,
+    ACTIONS(669), 1,
+      anon_sym_if,
     STATE(440), 1,
       sym_comment,
-  [9579] = 4,
-    ACTIONS(699), 1,
+  [9582] = 4,
+    ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
+    ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1032), 1,
-      anon_sym_LF,
+    ACTIONS(1040), 1,
+      anon_sym_RBRACE,
     STATE(441), 1,
       sym_comment,
-  [9592] = 4,
+  [9595] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(661), 1,
-      anon_sym_if,
+    ACTIONS(1042), 1,
+      sym_number,
     STATE(442), 1,
       sym_comment,
-  [9605] = 4,
+  [9608] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1034), 1,
-      sym_ident,
+    ACTIONS(765), 1,
+      anon_sym_COMMA,
     STATE(443), 1,
       sym_comment,
-  [9618] = 4,
+  [9621] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1036), 1,
+    ACTIONS(1044), 1,
       sym_number,
     STATE(444), 1,
       sym_comment,
-  [9631] = 4,
+  [9634] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(99), 1,
-      ts_builtin_sym_end,
+    ACTIONS(1046), 1,
+      anon_sym_EQ,
     STATE(445), 1,
       sym_comment,
-  [9644] = 4,
+  [9647] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1038), 1,
-      sym_number,
+    ACTIONS(1048), 1,
+      anon_sym_LBRACE,
     STATE(446), 1,
       sym_comment,
-  [9657] = 4,
+  [9660] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1040), 1,
-      anon_sym_EQ,
+    ACTIONS(823), 1,
+      anon_sym_primitive,
     STATE(447), 1,
       sym_comment,
-  [9670] = 4,
+  [9673] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1042), 1,
-      aux_sym_metadata_token1,
+    ACTIONS(1050), 1,
+      anon_sym_EQ,
     STATE(448), 1,
       sym_comment,
-  [9683] = 4,
+  [9686] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1044), 1,
-      ts_builtin_sym_end,
+    ACTIONS(1052), 1,
+      anon_sym_RBRACE,
     STATE(449), 1,
       sym_comment,
-  [9696] = 4,
+  [9699] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1046), 1,
-      anon_sym_EQ,
+    ACTIONS(769), 1,
+      anon_sym_COMMA,
     STATE(450), 1,
       sym_comment,
-  [9709] = 4,
-    ACTIONS(3), 1,
+  [9712] = 4,
+    ACTIONS(673), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
+    ACTIONS(675), 1,
       aux_sym_comment_token2,
-    ACTIONS(1048), 1,
-      anon_sym_LBRACE,
+    ACTIONS(1054), 1,
+      aux_sym_comment_token1,
     STATE(451), 1,
       sym_comment,
-  [9722] = 4,
+  [9725] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1050), 1,
+    ACTIONS(1056), 1,
       sym_number,
     STATE(452), 1,
       sym_comment,
-  [9735] = 4,
+  [9738] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1052), 1,
-      anon_sym_RBRACE,
+    ACTIONS(1058), 1,
+      sym_number,
     STATE(453), 1,
       sym_comment,
-  [9748] = 4,
+  [9751] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1054), 1,
-      sym_string,
+    ACTIONS(1060), 1,
+      sym_number,
     STATE(454), 1,
       sym_comment,
-  [9761] = 4,
+  [9764] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1056), 1,
-      sym_ident,
+    ACTIONS(781), 1,
+      anon_sym_COMMA,
     STATE(455), 1,
       sym_comment,
-  [9774] = 4,
+  [9777] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1058), 1,
-      sym_number,
+    ACTIONS(1062), 1,
+      sym_ident,
     STATE(456), 1,
       sym_comment,
-  [9787] = 4,
+  [9790] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1060), 1,
-      sym_ident,
+    ACTIONS(1064), 1,
+      anon_sym_LBRACE,
     STATE(457), 1,
       sym_comment,
-  [9800] = 4,
+  [9803] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1062), 1,
-      aux_sym_metadata_token1,
+    ACTIONS(1066), 1,
+      sym_number,
     STATE(458), 1,
       sym_comment,
-  [9813] = 4,
+  [9816] = 4,
     ACTIONS(3), 1,
       anon_sym_SLASH_SLASH,
     ACTIONS(5), 1,
       aux_sym_comment_token2,
-    ACTIONS(1064), 1,
-      sym_string,
+    ACTIONS(1068), 1,
+      anon_sym_LBRACE,
     STATE(459), 1,
       sym_comment,
-  [9826] = 1,
-    ACTIONS(1066), 1,
+  [9829] = 4,
+    ACTIONS(3), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(5), 1,
+      aux_sym_comment_token2,
+    ACTIONS(471), 1,
+      anon_sym_RBRACE,
+    STATE(460), 1,
+      sym_comment,
+  [9842] = 1,
+    ACTIONS(1070), 1,
       ts_builtin_sym_end,
-  [9830] = 1,
-    ACTIONS(1068), 1,
+  [9846] = 1,
+    ACTIONS(1072), 1,
       ts_builtin_sym_end,
 };
 
@@ -10194,979 +10207,982 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(5)] = 201,
   [SMALL_STATE(6)] = 268,
   [SMALL_STATE(7)] = 335,
-  [SMALL_STATE(8)] = 400,
-  [SMALL_STATE(9)] = 467,
+  [SMALL_STATE(8)] = 402,
+  [SMALL_STATE(9)] = 469,
   [SMALL_STATE(10)] = 534,
   [SMALL_STATE(11)] = 601,
   [SMALL_STATE(12)] = 668,
   [SMALL_STATE(13)] = 735,
   [SMALL_STATE(14)] = 802,
   [SMALL_STATE(15)] = 869,
-  [SMALL_STATE(16)] = 933,
-  [SMALL_STATE(17)] = 994,
-  [SMALL_STATE(18)] = 1055,
-  [SMALL_STATE(19)] = 1116,
-  [SMALL_STATE(20)] = 1176,
-  [SMALL_STATE(21)] = 1205,
-  [SMALL_STATE(22)] = 1256,
-  [SMALL_STATE(23)] = 1307,
-  [SMALL_STATE(24)] = 1339,
-  [SMALL_STATE(25)] = 1384,
-  [SMALL_STATE(26)] = 1433,
-  [SMALL_STATE(27)] = 1482,
-  [SMALL_STATE(28)] = 1523,
-  [SMALL_STATE(29)] = 1553,
-  [SMALL_STATE(30)] = 1593,
-  [SMALL_STATE(31)] = 1619,
-  [SMALL_STATE(32)] = 1659,
-  [SMALL_STATE(33)] = 1687,
-  [SMALL_STATE(34)] = 1727,
-  [SMALL_STATE(35)] = 1767,
-  [SMALL_STATE(36)] = 1795,
-  [SMALL_STATE(37)] = 1835,
-  [SMALL_STATE(38)] = 1861,
-  [SMALL_STATE(39)] = 1889,
-  [SMALL_STATE(40)] = 1915,
-  [SMALL_STATE(41)] = 1957,
-  [SMALL_STATE(42)] = 1984,
-  [SMALL_STATE(43)] = 2009,
-  [SMALL_STATE(44)] = 2034,
-  [SMALL_STATE(45)] = 2059,
-  [SMALL_STATE(46)] = 2084,
-  [SMALL_STATE(47)] = 2109,
-  [SMALL_STATE(48)] = 2134,
-  [SMALL_STATE(49)] = 2159,
-  [SMALL_STATE(50)] = 2184,
-  [SMALL_STATE(51)] = 2211,
-  [SMALL_STATE(52)] = 2240,
-  [SMALL_STATE(53)] = 2265,
-  [SMALL_STATE(54)] = 2296,
-  [SMALL_STATE(55)] = 2329,
-  [SMALL_STATE(56)] = 2364,
-  [SMALL_STATE(57)] = 2389,
-  [SMALL_STATE(58)] = 2414,
-  [SMALL_STATE(59)] = 2439,
-  [SMALL_STATE(60)] = 2464,
-  [SMALL_STATE(61)] = 2489,
-  [SMALL_STATE(62)] = 2514,
-  [SMALL_STATE(63)] = 2539,
-  [SMALL_STATE(64)] = 2564,
-  [SMALL_STATE(65)] = 2589,
-  [SMALL_STATE(66)] = 2614,
-  [SMALL_STATE(67)] = 2639,
-  [SMALL_STATE(68)] = 2664,
-  [SMALL_STATE(69)] = 2689,
-  [SMALL_STATE(70)] = 2714,
-  [SMALL_STATE(71)] = 2739,
-  [SMALL_STATE(72)] = 2764,
-  [SMALL_STATE(73)] = 2789,
-  [SMALL_STATE(74)] = 2814,
-  [SMALL_STATE(75)] = 2839,
-  [SMALL_STATE(76)] = 2864,
-  [SMALL_STATE(77)] = 2889,
-  [SMALL_STATE(78)] = 2914,
-  [SMALL_STATE(79)] = 2939,
-  [SMALL_STATE(80)] = 2963,
-  [SMALL_STATE(81)] = 3003,
-  [SMALL_STATE(82)] = 3043,
-  [SMALL_STATE(83)] = 3083,
-  [SMALL_STATE(84)] = 3109,
-  [SMALL_STATE(85)] = 3149,
-  [SMALL_STATE(86)] = 3173,
-  [SMALL_STATE(87)] = 3206,
-  [SMALL_STATE(88)] = 3241,
-  [SMALL_STATE(89)] = 3276,
-  [SMALL_STATE(90)] = 3307,
-  [SMALL_STATE(91)] = 3338,
-  [SMALL_STATE(92)] = 3369,
-  [SMALL_STATE(93)] = 3400,
-  [SMALL_STATE(94)] = 3435,
-  [SMALL_STATE(95)] = 3466,
-  [SMALL_STATE(96)] = 3501,
-  [SMALL_STATE(97)] = 3532,
-  [SMALL_STATE(98)] = 3567,
-  [SMALL_STATE(99)] = 3602,
-  [SMALL_STATE(100)] = 3637,
-  [SMALL_STATE(101)] = 3672,
-  [SMALL_STATE(102)] = 3707,
-  [SMALL_STATE(103)] = 3742,
-  [SMALL_STATE(104)] = 3777,
-  [SMALL_STATE(105)] = 3808,
-  [SMALL_STATE(106)] = 3839,
-  [SMALL_STATE(107)] = 3870,
-  [SMALL_STATE(108)] = 3905,
-  [SMALL_STATE(109)] = 3936,
-  [SMALL_STATE(110)] = 3970,
-  [SMALL_STATE(111)] = 3990,
-  [SMALL_STATE(112)] = 4024,
-  [SMALL_STATE(113)] = 4044,
-  [SMALL_STATE(114)] = 4064,
-  [SMALL_STATE(115)] = 4084,
-  [SMALL_STATE(116)] = 4104,
-  [SMALL_STATE(117)] = 4136,
-  [SMALL_STATE(118)] = 4156,
-  [SMALL_STATE(119)] = 4190,
-  [SMALL_STATE(120)] = 4210,
-  [SMALL_STATE(121)] = 4229,
-  [SMALL_STATE(122)] = 4260,
-  [SMALL_STATE(123)] = 4279,
-  [SMALL_STATE(124)] = 4298,
-  [SMALL_STATE(125)] = 4329,
-  [SMALL_STATE(126)] = 4348,
-  [SMALL_STATE(127)] = 4373,
-  [SMALL_STATE(128)] = 4404,
-  [SMALL_STATE(129)] = 4429,
-  [SMALL_STATE(130)] = 4460,
-  [SMALL_STATE(131)] = 4479,
-  [SMALL_STATE(132)] = 4510,
-  [SMALL_STATE(133)] = 4529,
-  [SMALL_STATE(134)] = 4560,
-  [SMALL_STATE(135)] = 4579,
-  [SMALL_STATE(136)] = 4610,
-  [SMALL_STATE(137)] = 4635,
-  [SMALL_STATE(138)] = 4654,
-  [SMALL_STATE(139)] = 4683,
-  [SMALL_STATE(140)] = 4714,
-  [SMALL_STATE(141)] = 4733,
-  [SMALL_STATE(142)] = 4762,
-  [SMALL_STATE(143)] = 4781,
-  [SMALL_STATE(144)] = 4812,
-  [SMALL_STATE(145)] = 4831,
-  [SMALL_STATE(146)] = 4862,
-  [SMALL_STATE(147)] = 4893,
-  [SMALL_STATE(148)] = 4924,
-  [SMALL_STATE(149)] = 4949,
-  [SMALL_STATE(150)] = 4977,
-  [SMALL_STATE(151)] = 4999,
-  [SMALL_STATE(152)] = 5027,
-  [SMALL_STATE(153)] = 5051,
-  [SMALL_STATE(154)] = 5076,
-  [SMALL_STATE(155)] = 5095,
-  [SMALL_STATE(156)] = 5120,
-  [SMALL_STATE(157)] = 5139,
-  [SMALL_STATE(158)] = 5158,
-  [SMALL_STATE(159)] = 5183,
-  [SMALL_STATE(160)] = 5208,
-  [SMALL_STATE(161)] = 5227,
-  [SMALL_STATE(162)] = 5246,
-  [SMALL_STATE(163)] = 5267,
-  [SMALL_STATE(164)] = 5286,
-  [SMALL_STATE(165)] = 5305,
-  [SMALL_STATE(166)] = 5330,
-  [SMALL_STATE(167)] = 5355,
-  [SMALL_STATE(168)] = 5376,
-  [SMALL_STATE(169)] = 5395,
-  [SMALL_STATE(170)] = 5416,
-  [SMALL_STATE(171)] = 5441,
-  [SMALL_STATE(172)] = 5460,
-  [SMALL_STATE(173)] = 5481,
-  [SMALL_STATE(174)] = 5500,
-  [SMALL_STATE(175)] = 5519,
-  [SMALL_STATE(176)] = 5539,
-  [SMALL_STATE(177)] = 5557,
-  [SMALL_STATE(178)] = 5579,
-  [SMALL_STATE(179)] = 5601,
-  [SMALL_STATE(180)] = 5623,
-  [SMALL_STATE(181)] = 5645,
-  [SMALL_STATE(182)] = 5665,
-  [SMALL_STATE(183)] = 5683,
-  [SMALL_STATE(184)] = 5705,
-  [SMALL_STATE(185)] = 5727,
-  [SMALL_STATE(186)] = 5749,
-  [SMALL_STATE(187)] = 5769,
-  [SMALL_STATE(188)] = 5791,
-  [SMALL_STATE(189)] = 5811,
-  [SMALL_STATE(190)] = 5833,
-  [SMALL_STATE(191)] = 5855,
-  [SMALL_STATE(192)] = 5871,
-  [SMALL_STATE(193)] = 5891,
-  [SMALL_STATE(194)] = 5909,
-  [SMALL_STATE(195)] = 5931,
-  [SMALL_STATE(196)] = 5949,
-  [SMALL_STATE(197)] = 5971,
-  [SMALL_STATE(198)] = 5991,
-  [SMALL_STATE(199)] = 6007,
-  [SMALL_STATE(200)] = 6025,
-  [SMALL_STATE(201)] = 6042,
-  [SMALL_STATE(202)] = 6059,
-  [SMALL_STATE(203)] = 6078,
-  [SMALL_STATE(204)] = 6097,
-  [SMALL_STATE(205)] = 6114,
-  [SMALL_STATE(206)] = 6133,
-  [SMALL_STATE(207)] = 6152,
-  [SMALL_STATE(208)] = 6171,
-  [SMALL_STATE(209)] = 6190,
-  [SMALL_STATE(210)] = 6205,
-  [SMALL_STATE(211)] = 6224,
-  [SMALL_STATE(212)] = 6243,
-  [SMALL_STATE(213)] = 6262,
-  [SMALL_STATE(214)] = 6277,
-  [SMALL_STATE(215)] = 6296,
-  [SMALL_STATE(216)] = 6315,
-  [SMALL_STATE(217)] = 6330,
-  [SMALL_STATE(218)] = 6349,
-  [SMALL_STATE(219)] = 6368,
-  [SMALL_STATE(220)] = 6383,
-  [SMALL_STATE(221)] = 6400,
-  [SMALL_STATE(222)] = 6417,
-  [SMALL_STATE(223)] = 6436,
-  [SMALL_STATE(224)] = 6453,
-  [SMALL_STATE(225)] = 6472,
-  [SMALL_STATE(226)] = 6487,
-  [SMALL_STATE(227)] = 6502,
-  [SMALL_STATE(228)] = 6521,
-  [SMALL_STATE(229)] = 6540,
-  [SMALL_STATE(230)] = 6559,
-  [SMALL_STATE(231)] = 6576,
-  [SMALL_STATE(232)] = 6591,
-  [SMALL_STATE(233)] = 6610,
-  [SMALL_STATE(234)] = 6625,
-  [SMALL_STATE(235)] = 6640,
-  [SMALL_STATE(236)] = 6655,
-  [SMALL_STATE(237)] = 6674,
-  [SMALL_STATE(238)] = 6693,
-  [SMALL_STATE(239)] = 6712,
-  [SMALL_STATE(240)] = 6731,
-  [SMALL_STATE(241)] = 6748,
-  [SMALL_STATE(242)] = 6763,
-  [SMALL_STATE(243)] = 6782,
-  [SMALL_STATE(244)] = 6797,
-  [SMALL_STATE(245)] = 6812,
-  [SMALL_STATE(246)] = 6827,
-  [SMALL_STATE(247)] = 6842,
-  [SMALL_STATE(248)] = 6857,
-  [SMALL_STATE(249)] = 6876,
-  [SMALL_STATE(250)] = 6895,
-  [SMALL_STATE(251)] = 6914,
-  [SMALL_STATE(252)] = 6933,
-  [SMALL_STATE(253)] = 6952,
-  [SMALL_STATE(254)] = 6967,
-  [SMALL_STATE(255)] = 6986,
-  [SMALL_STATE(256)] = 7005,
-  [SMALL_STATE(257)] = 7019,
-  [SMALL_STATE(258)] = 7033,
-  [SMALL_STATE(259)] = 7049,
-  [SMALL_STATE(260)] = 7065,
-  [SMALL_STATE(261)] = 7081,
-  [SMALL_STATE(262)] = 7097,
-  [SMALL_STATE(263)] = 7111,
-  [SMALL_STATE(264)] = 7127,
-  [SMALL_STATE(265)] = 7143,
-  [SMALL_STATE(266)] = 7159,
-  [SMALL_STATE(267)] = 7175,
-  [SMALL_STATE(268)] = 7191,
-  [SMALL_STATE(269)] = 7207,
-  [SMALL_STATE(270)] = 7223,
-  [SMALL_STATE(271)] = 7239,
-  [SMALL_STATE(272)] = 7255,
-  [SMALL_STATE(273)] = 7271,
-  [SMALL_STATE(274)] = 7287,
-  [SMALL_STATE(275)] = 7303,
-  [SMALL_STATE(276)] = 7319,
-  [SMALL_STATE(277)] = 7335,
-  [SMALL_STATE(278)] = 7349,
-  [SMALL_STATE(279)] = 7365,
-  [SMALL_STATE(280)] = 7381,
-  [SMALL_STATE(281)] = 7397,
-  [SMALL_STATE(282)] = 7413,
-  [SMALL_STATE(283)] = 7429,
-  [SMALL_STATE(284)] = 7443,
-  [SMALL_STATE(285)] = 7459,
-  [SMALL_STATE(286)] = 7473,
-  [SMALL_STATE(287)] = 7489,
-  [SMALL_STATE(288)] = 7505,
-  [SMALL_STATE(289)] = 7521,
-  [SMALL_STATE(290)] = 7537,
-  [SMALL_STATE(291)] = 7551,
-  [SMALL_STATE(292)] = 7567,
-  [SMALL_STATE(293)] = 7583,
-  [SMALL_STATE(294)] = 7599,
-  [SMALL_STATE(295)] = 7615,
-  [SMALL_STATE(296)] = 7631,
-  [SMALL_STATE(297)] = 7647,
-  [SMALL_STATE(298)] = 7663,
-  [SMALL_STATE(299)] = 7679,
-  [SMALL_STATE(300)] = 7695,
-  [SMALL_STATE(301)] = 7709,
-  [SMALL_STATE(302)] = 7723,
-  [SMALL_STATE(303)] = 7737,
-  [SMALL_STATE(304)] = 7753,
-  [SMALL_STATE(305)] = 7767,
-  [SMALL_STATE(306)] = 7783,
-  [SMALL_STATE(307)] = 7799,
-  [SMALL_STATE(308)] = 7813,
-  [SMALL_STATE(309)] = 7829,
-  [SMALL_STATE(310)] = 7843,
-  [SMALL_STATE(311)] = 7859,
-  [SMALL_STATE(312)] = 7873,
-  [SMALL_STATE(313)] = 7889,
-  [SMALL_STATE(314)] = 7903,
-  [SMALL_STATE(315)] = 7919,
-  [SMALL_STATE(316)] = 7935,
-  [SMALL_STATE(317)] = 7949,
-  [SMALL_STATE(318)] = 7963,
-  [SMALL_STATE(319)] = 7979,
-  [SMALL_STATE(320)] = 7995,
-  [SMALL_STATE(321)] = 8009,
-  [SMALL_STATE(322)] = 8025,
-  [SMALL_STATE(323)] = 8041,
-  [SMALL_STATE(324)] = 8055,
-  [SMALL_STATE(325)] = 8071,
-  [SMALL_STATE(326)] = 8084,
-  [SMALL_STATE(327)] = 8097,
-  [SMALL_STATE(328)] = 8110,
-  [SMALL_STATE(329)] = 8123,
-  [SMALL_STATE(330)] = 8136,
-  [SMALL_STATE(331)] = 8149,
-  [SMALL_STATE(332)] = 8162,
-  [SMALL_STATE(333)] = 8175,
-  [SMALL_STATE(334)] = 8188,
-  [SMALL_STATE(335)] = 8201,
-  [SMALL_STATE(336)] = 8214,
-  [SMALL_STATE(337)] = 8227,
-  [SMALL_STATE(338)] = 8240,
-  [SMALL_STATE(339)] = 8253,
-  [SMALL_STATE(340)] = 8266,
-  [SMALL_STATE(341)] = 8279,
-  [SMALL_STATE(342)] = 8292,
-  [SMALL_STATE(343)] = 8305,
-  [SMALL_STATE(344)] = 8318,
-  [SMALL_STATE(345)] = 8331,
-  [SMALL_STATE(346)] = 8344,
-  [SMALL_STATE(347)] = 8357,
-  [SMALL_STATE(348)] = 8370,
-  [SMALL_STATE(349)] = 8383,
-  [SMALL_STATE(350)] = 8396,
-  [SMALL_STATE(351)] = 8409,
-  [SMALL_STATE(352)] = 8422,
-  [SMALL_STATE(353)] = 8435,
-  [SMALL_STATE(354)] = 8448,
-  [SMALL_STATE(355)] = 8461,
-  [SMALL_STATE(356)] = 8474,
-  [SMALL_STATE(357)] = 8487,
-  [SMALL_STATE(358)] = 8500,
-  [SMALL_STATE(359)] = 8513,
-  [SMALL_STATE(360)] = 8526,
-  [SMALL_STATE(361)] = 8539,
-  [SMALL_STATE(362)] = 8552,
-  [SMALL_STATE(363)] = 8565,
-  [SMALL_STATE(364)] = 8578,
-  [SMALL_STATE(365)] = 8591,
-  [SMALL_STATE(366)] = 8604,
-  [SMALL_STATE(367)] = 8617,
-  [SMALL_STATE(368)] = 8630,
-  [SMALL_STATE(369)] = 8643,
-  [SMALL_STATE(370)] = 8656,
-  [SMALL_STATE(371)] = 8669,
-  [SMALL_STATE(372)] = 8682,
-  [SMALL_STATE(373)] = 8695,
-  [SMALL_STATE(374)] = 8708,
-  [SMALL_STATE(375)] = 8721,
-  [SMALL_STATE(376)] = 8734,
-  [SMALL_STATE(377)] = 8747,
-  [SMALL_STATE(378)] = 8760,
-  [SMALL_STATE(379)] = 8773,
-  [SMALL_STATE(380)] = 8786,
-  [SMALL_STATE(381)] = 8799,
-  [SMALL_STATE(382)] = 8812,
-  [SMALL_STATE(383)] = 8825,
-  [SMALL_STATE(384)] = 8838,
-  [SMALL_STATE(385)] = 8851,
-  [SMALL_STATE(386)] = 8864,
-  [SMALL_STATE(387)] = 8877,
-  [SMALL_STATE(388)] = 8890,
-  [SMALL_STATE(389)] = 8903,
-  [SMALL_STATE(390)] = 8916,
-  [SMALL_STATE(391)] = 8929,
-  [SMALL_STATE(392)] = 8942,
-  [SMALL_STATE(393)] = 8955,
-  [SMALL_STATE(394)] = 8968,
-  [SMALL_STATE(395)] = 8981,
-  [SMALL_STATE(396)] = 8994,
-  [SMALL_STATE(397)] = 9007,
-  [SMALL_STATE(398)] = 9020,
-  [SMALL_STATE(399)] = 9033,
-  [SMALL_STATE(400)] = 9046,
-  [SMALL_STATE(401)] = 9059,
-  [SMALL_STATE(402)] = 9072,
-  [SMALL_STATE(403)] = 9085,
-  [SMALL_STATE(404)] = 9098,
-  [SMALL_STATE(405)] = 9111,
-  [SMALL_STATE(406)] = 9124,
-  [SMALL_STATE(407)] = 9137,
-  [SMALL_STATE(408)] = 9150,
-  [SMALL_STATE(409)] = 9163,
-  [SMALL_STATE(410)] = 9176,
-  [SMALL_STATE(411)] = 9189,
-  [SMALL_STATE(412)] = 9202,
-  [SMALL_STATE(413)] = 9215,
-  [SMALL_STATE(414)] = 9228,
-  [SMALL_STATE(415)] = 9241,
-  [SMALL_STATE(416)] = 9254,
-  [SMALL_STATE(417)] = 9267,
-  [SMALL_STATE(418)] = 9280,
-  [SMALL_STATE(419)] = 9293,
-  [SMALL_STATE(420)] = 9306,
-  [SMALL_STATE(421)] = 9319,
-  [SMALL_STATE(422)] = 9332,
-  [SMALL_STATE(423)] = 9345,
-  [SMALL_STATE(424)] = 9358,
-  [SMALL_STATE(425)] = 9371,
-  [SMALL_STATE(426)] = 9384,
-  [SMALL_STATE(427)] = 9397,
-  [SMALL_STATE(428)] = 9410,
-  [SMALL_STATE(429)] = 9423,
-  [SMALL_STATE(430)] = 9436,
-  [SMALL_STATE(431)] = 9449,
-  [SMALL_STATE(432)] = 9462,
-  [SMALL_STATE(433)] = 9475,
-  [SMALL_STATE(434)] = 9488,
-  [SMALL_STATE(435)] = 9501,
-  [SMALL_STATE(436)] = 9514,
-  [SMALL_STATE(437)] = 9527,
-  [SMALL_STATE(438)] = 9540,
-  [SMALL_STATE(439)] = 9553,
-  [SMALL_STATE(440)] = 9566,
-  [SMALL_STATE(441)] = 9579,
-  [SMALL_STATE(442)] = 9592,
-  [SMALL_STATE(443)] = 9605,
-  [SMALL_STATE(444)] = 9618,
-  [SMALL_STATE(445)] = 9631,
-  [SMALL_STATE(446)] = 9644,
-  [SMALL_STATE(447)] = 9657,
-  [SMALL_STATE(448)] = 9670,
-  [SMALL_STATE(449)] = 9683,
-  [SMALL_STATE(450)] = 9696,
-  [SMALL_STATE(451)] = 9709,
-  [SMALL_STATE(452)] = 9722,
-  [SMALL_STATE(453)] = 9735,
-  [SMALL_STATE(454)] = 9748,
-  [SMALL_STATE(455)] = 9761,
-  [SMALL_STATE(456)] = 9774,
-  [SMALL_STATE(457)] = 9787,
-  [SMALL_STATE(458)] = 9800,
-  [SMALL_STATE(459)] = 9813,
-  [SMALL_STATE(460)] = 9826,
-  [SMALL_STATE(461)] = 9830,
+  [SMALL_STATE(16)] = 936,
+  [SMALL_STATE(17)] = 997,
+  [SMALL_STATE(18)] = 1058,
+  [SMALL_STATE(19)] = 1119,
+  [SMALL_STATE(20)] = 1179,
+  [SMALL_STATE(21)] = 1230,
+  [SMALL_STATE(22)] = 1259,
+  [SMALL_STATE(23)] = 1310,
+  [SMALL_STATE(24)] = 1342,
+  [SMALL_STATE(25)] = 1383,
+  [SMALL_STATE(26)] = 1432,
+  [SMALL_STATE(27)] = 1481,
+  [SMALL_STATE(28)] = 1526,
+  [SMALL_STATE(29)] = 1566,
+  [SMALL_STATE(30)] = 1592,
+  [SMALL_STATE(31)] = 1632,
+  [SMALL_STATE(32)] = 1672,
+  [SMALL_STATE(33)] = 1714,
+  [SMALL_STATE(34)] = 1754,
+  [SMALL_STATE(35)] = 1780,
+  [SMALL_STATE(36)] = 1810,
+  [SMALL_STATE(37)] = 1838,
+  [SMALL_STATE(38)] = 1866,
+  [SMALL_STATE(39)] = 1892,
+  [SMALL_STATE(40)] = 1920,
+  [SMALL_STATE(41)] = 1960,
+  [SMALL_STATE(42)] = 1993,
+  [SMALL_STATE(43)] = 2018,
+  [SMALL_STATE(44)] = 2043,
+  [SMALL_STATE(45)] = 2068,
+  [SMALL_STATE(46)] = 2095,
+  [SMALL_STATE(47)] = 2124,
+  [SMALL_STATE(48)] = 2155,
+  [SMALL_STATE(49)] = 2180,
+  [SMALL_STATE(50)] = 2215,
+  [SMALL_STATE(51)] = 2240,
+  [SMALL_STATE(52)] = 2265,
+  [SMALL_STATE(53)] = 2290,
+  [SMALL_STATE(54)] = 2315,
+  [SMALL_STATE(55)] = 2340,
+  [SMALL_STATE(56)] = 2365,
+  [SMALL_STATE(57)] = 2390,
+  [SMALL_STATE(58)] = 2415,
+  [SMALL_STATE(59)] = 2440,
+  [SMALL_STATE(60)] = 2465,
+  [SMALL_STATE(61)] = 2490,
+  [SMALL_STATE(62)] = 2515,
+  [SMALL_STATE(63)] = 2540,
+  [SMALL_STATE(64)] = 2565,
+  [SMALL_STATE(65)] = 2590,
+  [SMALL_STATE(66)] = 2615,
+  [SMALL_STATE(67)] = 2640,
+  [SMALL_STATE(68)] = 2665,
+  [SMALL_STATE(69)] = 2690,
+  [SMALL_STATE(70)] = 2715,
+  [SMALL_STATE(71)] = 2740,
+  [SMALL_STATE(72)] = 2765,
+  [SMALL_STATE(73)] = 2792,
+  [SMALL_STATE(74)] = 2817,
+  [SMALL_STATE(75)] = 2842,
+  [SMALL_STATE(76)] = 2867,
+  [SMALL_STATE(77)] = 2892,
+  [SMALL_STATE(78)] = 2917,
+  [SMALL_STATE(79)] = 2942,
+  [SMALL_STATE(80)] = 2982,
+  [SMALL_STATE(81)] = 3022,
+  [SMALL_STATE(82)] = 3046,
+  [SMALL_STATE(83)] = 3072,
+  [SMALL_STATE(84)] = 3112,
+  [SMALL_STATE(85)] = 3152,
+  [SMALL_STATE(86)] = 3176,
+  [SMALL_STATE(87)] = 3207,
+  [SMALL_STATE(88)] = 3238,
+  [SMALL_STATE(89)] = 3269,
+  [SMALL_STATE(90)] = 3304,
+  [SMALL_STATE(91)] = 3335,
+  [SMALL_STATE(92)] = 3366,
+  [SMALL_STATE(93)] = 3397,
+  [SMALL_STATE(94)] = 3432,
+  [SMALL_STATE(95)] = 3463,
+  [SMALL_STATE(96)] = 3494,
+  [SMALL_STATE(97)] = 3529,
+  [SMALL_STATE(98)] = 3564,
+  [SMALL_STATE(99)] = 3599,
+  [SMALL_STATE(100)] = 3634,
+  [SMALL_STATE(101)] = 3669,
+  [SMALL_STATE(102)] = 3702,
+  [SMALL_STATE(103)] = 3733,
+  [SMALL_STATE(104)] = 3764,
+  [SMALL_STATE(105)] = 3799,
+  [SMALL_STATE(106)] = 3834,
+  [SMALL_STATE(107)] = 3869,
+  [SMALL_STATE(108)] = 3904,
+  [SMALL_STATE(109)] = 3939,
+  [SMALL_STATE(110)] = 3959,
+  [SMALL_STATE(111)] = 3979,
+  [SMALL_STATE(112)] = 3999,
+  [SMALL_STATE(113)] = 4019,
+  [SMALL_STATE(114)] = 4051,
+  [SMALL_STATE(115)] = 4085,
+  [SMALL_STATE(116)] = 4105,
+  [SMALL_STATE(117)] = 4139,
+  [SMALL_STATE(118)] = 4159,
+  [SMALL_STATE(119)] = 4179,
+  [SMALL_STATE(120)] = 4213,
+  [SMALL_STATE(121)] = 4244,
+  [SMALL_STATE(122)] = 4269,
+  [SMALL_STATE(123)] = 4300,
+  [SMALL_STATE(124)] = 4331,
+  [SMALL_STATE(125)] = 4356,
+  [SMALL_STATE(126)] = 4375,
+  [SMALL_STATE(127)] = 4406,
+  [SMALL_STATE(128)] = 4435,
+  [SMALL_STATE(129)] = 4454,
+  [SMALL_STATE(130)] = 4479,
+  [SMALL_STATE(131)] = 4498,
+  [SMALL_STATE(132)] = 4529,
+  [SMALL_STATE(133)] = 4548,
+  [SMALL_STATE(134)] = 4579,
+  [SMALL_STATE(135)] = 4610,
+  [SMALL_STATE(136)] = 4641,
+  [SMALL_STATE(137)] = 4660,
+  [SMALL_STATE(138)] = 4691,
+  [SMALL_STATE(139)] = 4716,
+  [SMALL_STATE(140)] = 4735,
+  [SMALL_STATE(141)] = 4754,
+  [SMALL_STATE(142)] = 4773,
+  [SMALL_STATE(143)] = 4804,
+  [SMALL_STATE(144)] = 4835,
+  [SMALL_STATE(145)] = 4864,
+  [SMALL_STATE(146)] = 4883,
+  [SMALL_STATE(147)] = 4914,
+  [SMALL_STATE(148)] = 4933,
+  [SMALL_STATE(149)] = 4952,
+  [SMALL_STATE(150)] = 4974,
+  [SMALL_STATE(151)] = 4998,
+  [SMALL_STATE(152)] = 5026,
+  [SMALL_STATE(153)] = 5054,
+  [SMALL_STATE(154)] = 5079,
+  [SMALL_STATE(155)] = 5104,
+  [SMALL_STATE(156)] = 5129,
+  [SMALL_STATE(157)] = 5154,
+  [SMALL_STATE(158)] = 5173,
+  [SMALL_STATE(159)] = 5194,
+  [SMALL_STATE(160)] = 5213,
+  [SMALL_STATE(161)] = 5232,
+  [SMALL_STATE(162)] = 5253,
+  [SMALL_STATE(163)] = 5278,
+  [SMALL_STATE(164)] = 5297,
+  [SMALL_STATE(165)] = 5316,
+  [SMALL_STATE(166)] = 5335,
+  [SMALL_STATE(167)] = 5354,
+  [SMALL_STATE(168)] = 5379,
+  [SMALL_STATE(169)] = 5404,
+  [SMALL_STATE(170)] = 5423,
+  [SMALL_STATE(171)] = 5442,
+  [SMALL_STATE(172)] = 5463,
+  [SMALL_STATE(173)] = 5484,
+  [SMALL_STATE(174)] = 5503,
+  [SMALL_STATE(175)] = 5522,
+  [SMALL_STATE(176)] = 5544,
+  [SMALL_STATE(177)] = 5562,
+  [SMALL_STATE(178)] = 5584,
+  [SMALL_STATE(179)] = 5606,
+  [SMALL_STATE(180)] = 5628,
+  [SMALL_STATE(181)] = 5648,
+  [SMALL_STATE(182)] = 5670,
+  [SMALL_STATE(183)] = 5690,
+  [SMALL_STATE(184)] = 5710,
+  [SMALL_STATE(185)] = 5730,
+  [SMALL_STATE(186)] = 5748,
+  [SMALL_STATE(187)] = 5770,
+  [SMALL_STATE(188)] = 5790,
+  [SMALL_STATE(189)] = 5812,
+  [SMALL_STATE(190)] = 5830,
+  [SMALL_STATE(191)] = 5848,
+  [SMALL_STATE(192)] = 5870,
+  [SMALL_STATE(193)] = 5892,
+  [SMALL_STATE(194)] = 5910,
+  [SMALL_STATE(195)] = 5932,
+  [SMALL_STATE(196)] = 5948,
+  [SMALL_STATE(197)] = 5970,
+  [SMALL_STATE(198)] = 5992,
+  [SMALL_STATE(199)] = 6012,
+  [SMALL_STATE(200)] = 6028,
+  [SMALL_STATE(201)] = 6047,
+  [SMALL_STATE(202)] = 6066,
+  [SMALL_STATE(203)] = 6085,
+  [SMALL_STATE(204)] = 6104,
+  [SMALL_STATE(205)] = 6123,
+  [SMALL_STATE(206)] = 6142,
+  [SMALL_STATE(207)] = 6161,
+  [SMALL_STATE(208)] = 6176,
+  [SMALL_STATE(209)] = 6193,
+  [SMALL_STATE(210)] = 6208,
+  [SMALL_STATE(211)] = 6223,
+  [SMALL_STATE(212)] = 6238,
+  [SMALL_STATE(213)] = 6257,
+  [SMALL_STATE(214)] = 6276,
+  [SMALL_STATE(215)] = 6291,
+  [SMALL_STATE(216)] = 6308,
+  [SMALL_STATE(217)] = 6325,
+  [SMALL_STATE(218)] = 6340,
+  [SMALL_STATE(219)] = 6355,
+  [SMALL_STATE(220)] = 6372,
+  [SMALL_STATE(221)] = 6391,
+  [SMALL_STATE(222)] = 6406,
+  [SMALL_STATE(223)] = 6421,
+  [SMALL_STATE(224)] = 6438,
+  [SMALL_STATE(225)] = 6457,
+  [SMALL_STATE(226)] = 6476,
+  [SMALL_STATE(227)] = 6491,
+  [SMALL_STATE(228)] = 6506,
+  [SMALL_STATE(229)] = 6521,
+  [SMALL_STATE(230)] = 6540,
+  [SMALL_STATE(231)] = 6559,
+  [SMALL_STATE(232)] = 6578,
+  [SMALL_STATE(233)] = 6595,
+  [SMALL_STATE(234)] = 6612,
+  [SMALL_STATE(235)] = 6627,
+  [SMALL_STATE(236)] = 6642,
+  [SMALL_STATE(237)] = 6661,
+  [SMALL_STATE(238)] = 6676,
+  [SMALL_STATE(239)] = 6695,
+  [SMALL_STATE(240)] = 6714,
+  [SMALL_STATE(241)] = 6733,
+  [SMALL_STATE(242)] = 6748,
+  [SMALL_STATE(243)] = 6767,
+  [SMALL_STATE(244)] = 6786,
+  [SMALL_STATE(245)] = 6805,
+  [SMALL_STATE(246)] = 6824,
+  [SMALL_STATE(247)] = 6843,
+  [SMALL_STATE(248)] = 6862,
+  [SMALL_STATE(249)] = 6877,
+  [SMALL_STATE(250)] = 6896,
+  [SMALL_STATE(251)] = 6915,
+  [SMALL_STATE(252)] = 6934,
+  [SMALL_STATE(253)] = 6953,
+  [SMALL_STATE(254)] = 6972,
+  [SMALL_STATE(255)] = 6989,
+  [SMALL_STATE(256)] = 7008,
+  [SMALL_STATE(257)] = 7024,
+  [SMALL_STATE(258)] = 7040,
+  [SMALL_STATE(259)] = 7054,
+  [SMALL_STATE(260)] = 7070,
+  [SMALL_STATE(261)] = 7086,
+  [SMALL_STATE(262)] = 7102,
+  [SMALL_STATE(263)] = 7116,
+  [SMALL_STATE(264)] = 7130,
+  [SMALL_STATE(265)] = 7146,
+  [SMALL_STATE(266)] = 7160,
+  [SMALL_STATE(267)] = 7176,
+  [SMALL_STATE(268)] = 7192,
+  [SMALL_STATE(269)] = 7208,
+  [SMALL_STATE(270)] = 7224,
+  [SMALL_STATE(271)] = 7240,
+  [SMALL_STATE(272)] = 7256,
+  [SMALL_STATE(273)] = 7272,
+  [SMALL_STATE(274)] = 7288,
+  [SMALL_STATE(275)] = 7304,
+  [SMALL_STATE(276)] = 7320,
+  [SMALL_STATE(277)] = 7336,
+  [SMALL_STATE(278)] = 7352,
+  [SMALL_STATE(279)] = 7368,
+  [SMALL_STATE(280)] = 7384,
+  [SMALL_STATE(281)] = 7398,
+  [SMALL_STATE(282)] = 7414,
+  [SMALL_STATE(283)] = 7430,
+  [SMALL_STATE(284)] = 7446,
+  [SMALL_STATE(285)] = 7462,
+  [SMALL_STATE(286)] = 7478,
+  [SMALL_STATE(287)] = 7492,
+  [SMALL_STATE(288)] = 7508,
+  [SMALL_STATE(289)] = 7524,
+  [SMALL_STATE(290)] = 7540,
+  [SMALL_STATE(291)] = 7554,
+  [SMALL_STATE(292)] = 7570,
+  [SMALL_STATE(293)] = 7584,
+  [SMALL_STATE(294)] = 7600,
+  [SMALL_STATE(295)] = 7614,
+  [SMALL_STATE(296)] = 7628,
+  [SMALL_STATE(297)] = 7644,
+  [SMALL_STATE(298)] = 7660,
+  [SMALL_STATE(299)] = 7676,
+  [SMALL_STATE(300)] = 7692,
+  [SMALL_STATE(301)] = 7706,
+  [SMALL_STATE(302)] = 7722,
+  [SMALL_STATE(303)] = 7738,
+  [SMALL_STATE(304)] = 7752,
+  [SMALL_STATE(305)] = 7766,
+  [SMALL_STATE(306)] = 7782,
+  [SMALL_STATE(307)] = 7796,
+  [SMALL_STATE(308)] = 7812,
+  [SMALL_STATE(309)] = 7828,
+  [SMALL_STATE(310)] = 7844,
+  [SMALL_STATE(311)] = 7860,
+  [SMALL_STATE(312)] = 7876,
+  [SMALL_STATE(313)] = 7892,
+  [SMALL_STATE(314)] = 7908,
+  [SMALL_STATE(315)] = 7924,
+  [SMALL_STATE(316)] = 7938,
+  [SMALL_STATE(317)] = 7952,
+  [SMALL_STATE(318)] = 7968,
+  [SMALL_STATE(319)] = 7982,
+  [SMALL_STATE(320)] = 7998,
+  [SMALL_STATE(321)] = 8012,
+  [SMALL_STATE(322)] = 8028,
+  [SMALL_STATE(323)] = 8044,
+  [SMALL_STATE(324)] = 8060,
+  [SMALL_STATE(325)] = 8074,
+  [SMALL_STATE(326)] = 8087,
+  [SMALL_STATE(327)] = 8100,
+  [SMALL_STATE(328)] = 8113,
+  [SMALL_STATE(329)] = 8126,
+  [SMALL_STATE(330)] = 8139,
+  [SMALL_STATE(331)] = 8152,
+  [SMALL_STATE(332)] = 8165,
+  [SMALL_STATE(333)] = 8178,
+  [SMALL_STATE(334)] = 8191,
+  [SMALL_STATE(335)] = 8204,
+  [SMALL_STATE(336)] = 8217,
+  [SMALL_STATE(337)] = 8230,
+  [SMALL_STATE(338)] = 8243,
+  [SMALL_STATE(339)] = 8256,
+  [SMALL_STATE(340)] = 8269,
+  [SMALL_STATE(341)] = 8282,
+  [SMALL_STATE(342)] = 8295,
+  [SMALL_STATE(343)] = 8308,
+  [SMALL_STATE(344)] = 8321,
+  [SMALL_STATE(345)] = 8334,
+  [SMALL_STATE(346)] = 8347,
+  [SMALL_STATE(347)] = 8360,
+  [SMALL_STATE(348)] = 8373,
+  [SMALL_STATE(349)] = 8386,
+  [SMALL_STATE(350)] = 8399,
+  [SMALL_STATE(351)] = 8412,
+  [SMALL_STATE(352)] = 8425,
+  [SMALL_STATE(353)] = 8438,
+  [SMALL_STATE(354)] = 8451,
+  [SMALL_STATE(355)] = 8464,
+  [SMALL_STATE(356)] = 8477,
+  [SMALL_STATE(357)] = 8490,
+  [SMALL_STATE(358)] = 8503,
+  [SMALL_STATE(359)] = 8516,
+  [SMALL_STATE(360)] = 8529,
+  [SMALL_STATE(361)] = 8542,
+  [SMALL_STATE(362)] = 8555,
+  [SMALL_STATE(363)] = 8568,
+  [SMALL_STATE(364)] = 8581,
+  [SMALL_STATE(365)] = 8594,
+  [SMALL_STATE(366)] = 8607,
+  [SMALL_STATE(367)] = 8620,
+  [SMALL_STATE(368)] = 8633,
+  [SMALL_STATE(369)] = 8646,
+  [SMALL_STATE(370)] = 8659,
+  [SMALL_STATE(371)] = 8672,
+  [SMALL_STATE(372)] = 8685,
+  [SMALL_STATE(373)] = 8698,
+  [SMALL_STATE(374)] = 8711,
+  [SMALL_STATE(375)] = 8724,
+  [SMALL_STATE(376)] = 8737,
+  [SMALL_STATE(377)] = 8750,
+  [SMALL_STATE(378)] = 8763,
+  [SMALL_STATE(379)] = 8776,
+  [SMALL_STATE(380)] = 8789,
+  [SMALL_STATE(381)] = 8802,
+  [SMALL_STATE(382)] = 8815,
+  [SMALL_STATE(383)] = 8828,
+  [SMALL_STATE(384)] = 8841,
+  [SMALL_STATE(385)] = 8854,
+  [SMALL_STATE(386)] = 8867,
+  [SMALL_STATE(387)] = 8880,
+  [SMALL_STATE(388)] = 8893,
+  [SMALL_STATE(389)] = 8906,
+  [SMALL_STATE(390)] = 8919,
+  [SMALL_STATE(391)] = 8932,
+  [SMALL_STATE(392)] = 8945,
+  [SMALL_STATE(393)] = 8958,
+  [SMALL_STATE(394)] = 8971,
+  [SMALL_STATE(395)] = 8984,
+  [SMALL_STATE(396)] = 8997,
+  [SMALL_STATE(397)] = 9010,
+  [SMALL_STATE(398)] = 9023,
+  [SMALL_STATE(399)] = 9036,
+  [SMALL_STATE(400)] = 9049,
+  [SMALL_STATE(401)] = 9062,
+  [SMALL_STATE(402)] = 9075,
+  [SMALL_STATE(403)] = 9088,
+  [SMALL_STATE(404)] = 9101,
+  [SMALL_STATE(405)] = 9114,
+  [SMALL_STATE(406)] = 9127,
+  [SMALL_STATE(407)] = 9140,
+  [SMALL_STATE(408)] = 9153,
+  [SMALL_STATE(409)] = 9166,
+  [SMALL_STATE(410)] = 9179,
+  [SMALL_STATE(411)] = 9192,
+  [SMALL_STATE(412)] = 9205,
+  [SMALL_STATE(413)] = 9218,
+  [SMALL_STATE(414)] = 9231,
+  [SMALL_STATE(415)] = 9244,
+  [SMALL_STATE(416)] = 9257,
+  [SMALL_STATE(417)] = 9270,
+  [SMALL_STATE(418)] = 9283,
+  [SMALL_STATE(419)] = 9296,
+  [SMALL_STATE(420)] = 9309,
+  [SMALL_STATE(421)] = 9322,
+  [SMALL_STATE(422)] = 9335,
+  [SMALL_STATE(423)] = 9348,
+  [SMALL_STATE(424)] = 9361,
+  [SMALL_STATE(425)] = 9374,
+  [SMALL_STATE(426)] = 9387,
+  [SMALL_STATE(427)] = 9400,
+  [SMALL_STATE(428)] = 9413,
+  [SMALL_STATE(429)] = 9426,
+  [SMALL_STATE(430)] = 9439,
+  [SMALL_STATE(431)] = 9452,
+  [SMALL_STATE(432)] = 9465,
+  [SMALL_STATE(433)] = 9478,
+  [SMALL_STATE(434)] = 9491,
+  [SMALL_STATE(435)] = 9504,
+  [SMALL_STATE(436)] = 9517,
+  [SMALL_STATE(437)] = 9530,
+  [SMALL_STATE(438)] = 9543,
+  [SMALL_STATE(439)] = 9556,
+  [SMALL_STATE(440)] = 9569,
+  [SMALL_STATE(441)] = 9582,
+  [SMALL_STATE(442)] = 9595,
+  [SMALL_STATE(443)] = 9608,
+  [SMALL_STATE(444)] = 9621,
+  [SMALL_STATE(445)] = 9634,
+  [SMALL_STATE(446)] = 9647,
+  [SMALL_STATE(447)] = 9660,
+  [SMALL_STATE(448)] = 9673,
+  [SMALL_STATE(449)] = 9686,
+  [SMALL_STATE(450)] = 9699,
+  [SMALL_STATE(451)] = 9712,
+  [SMALL_STATE(452)] = 9725,
+  [SMALL_STATE(453)] = 9738,
+  [SMALL_STATE(454)] = 9751,
+  [SMALL_STATE(455)] = 9764,
+  [SMALL_STATE(456)] = 9777,
+  [SMALL_STATE(457)] = 9790,
+  [SMALL_STATE(458)] = 9803,
+  [SMALL_STATE(459)] = 9816,
+  [SMALL_STATE(460)] = 9829,
+  [SMALL_STATE(461)] = 9842,
+  [SMALL_STATE(462)] = 9846,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
-  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(351),
-  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(461),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(451),
+  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(462),
   [7] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(459),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(458),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(457),
-  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
-  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(455),
-  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(454),
-  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
-  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
-  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
-  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(331),
-  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(330),
-  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(329),
-  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(254),
-  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(248),
-  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(128),
-  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(419),
-  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(423),
-  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(327),
-  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
-  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
-  [49] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(46),
-  [52] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 2),
-  [54] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(331),
-  [57] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(330),
-  [60] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(329),
-  [63] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(254),
-  [66] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(248),
-  [69] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(128),
-  [72] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(419),
-  [75] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(423),
-  [78] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(327),
-  [81] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
-  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
-  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
-  [87] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
-  [89] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
-  [91] = {.entry = {.count = 1, .reusable = false}}, SHIFT(202),
-  [93] = {.entry = {.count = 1, .reusable = false}}, SHIFT(207),
-  [95] = {.entry = {.count = 1, .reusable = false}}, SHIFT(148),
-  [97] = {.entry = {.count = 1, .reusable = false}}, SHIFT(328),
-  [99] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1),
-  [101] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port, 3),
-  [103] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_port, 3),
-  [105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2),
-  [107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port, 1),
-  [109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(368),
-  [111] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_port, 1),
-  [113] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
-  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
-  [117] = {.entry = {.count = 1, .reusable = false}}, SHIFT(337),
-  [119] = {.entry = {.count = 1, .reusable = false}}, SHIFT(336),
-  [121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(408),
-  [123] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
-  [125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
-  [127] = {.entry = {.count = 1, .reusable = false}}, SHIFT(399),
-  [129] = {.entry = {.count = 1, .reusable = false}}, SHIFT(400),
-  [131] = {.entry = {.count = 1, .reusable = false}}, SHIFT(402),
-  [133] = {.entry = {.count = 1, .reusable = false}}, SHIFT(205),
-  [135] = {.entry = {.count = 1, .reusable = false}}, SHIFT(206),
-  [137] = {.entry = {.count = 1, .reusable = false}}, SHIFT(126),
-  [139] = {.entry = {.count = 1, .reusable = false}}, SHIFT(384),
-  [141] = {.entry = {.count = 1, .reusable = false}}, SHIFT(403),
-  [143] = {.entry = {.count = 1, .reusable = false}}, SHIFT(255),
-  [145] = {.entry = {.count = 1, .reusable = false}}, SHIFT(222),
-  [147] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
-  [149] = {.entry = {.count = 1, .reusable = false}}, SHIFT(452),
-  [151] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2),
-  [153] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(457),
-  [156] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(188),
-  [159] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(455),
-  [162] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(454),
-  [165] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(150),
-  [168] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2),
-  [170] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_io_port_repeat1, 2),
-  [172] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(423),
-  [175] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
-  [177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
-  [179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
-  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(418),
-  [183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 4),
-  [185] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 4),
-  [187] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 3),
-  [189] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 3),
-  [191] = {.entry = {.count = 1, .reusable = false}}, SHIFT(95),
-  [193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_wires_inner_repeat1, 2),
-  [195] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(337),
-  [198] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(336),
-  [201] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(408),
-  [204] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(23),
-  [207] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 4),
-  [209] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 4),
-  [211] = {.entry = {.count = 1, .reusable = false}}, SHIFT(99),
-  [213] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3),
-  [215] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3),
-  [217] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 5),
-  [219] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 5),
-  [221] = {.entry = {.count = 1, .reusable = false}}, SHIFT(98),
-  [223] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hole, 4),
-  [225] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hole, 4),
-  [227] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires_inner, 1),
-  [229] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_attribute, 2),
-  [231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(444),
-  [233] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_attribute, 2),
-  [235] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 5),
-  [237] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 5),
-  [239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 4),
-  [241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 4),
-  [243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_stmt, 4),
-  [245] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_stmt, 4),
-  [247] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_stmt, 1),
-  [249] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_stmt, 1),
-  [251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 1),
-  [253] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 1),
-  [255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 3),
-  [257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 3),
-  [259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cmp_expr, 3),
-  [261] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cmp_expr, 3),
-  [263] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
-  [265] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
-  [267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 9),
-  [269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 9),
-  [271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
-  [273] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
-  [275] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
-  [277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 3),
-  [279] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 3),
-  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 2),
-  [283] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 2),
-  [285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 5),
-  [287] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 5),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(362),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(430),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(363),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(368),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(383),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(243),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(253),
+  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(121),
+  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(458),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(421),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(410),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(204),
+  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(212),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(124),
+  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(408),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [61] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(77),
+  [64] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 2),
+  [66] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(363),
+  [69] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(368),
+  [72] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(383),
+  [75] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(243),
+  [78] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(253),
+  [81] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(121),
+  [84] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(458),
+  [87] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(421),
+  [90] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(410),
+  [93] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [99] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [101] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1),
+  [103] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2),
+  [105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port, 3),
+  [107] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_port, 3),
+  [109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port, 1),
+  [111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
+  [113] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_port, 1),
+  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [117] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2),
+  [119] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(362),
+  [122] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(184),
+  [125] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(366),
+  [128] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(430),
+  [131] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(149),
+  [134] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [136] = {.entry = {.count = 1, .reusable = false}}, SHIFT(436),
+  [138] = {.entry = {.count = 1, .reusable = false}}, SHIFT(439),
+  [140] = {.entry = {.count = 1, .reusable = false}}, SHIFT(446),
+  [142] = {.entry = {.count = 1, .reusable = false}}, SHIFT(244),
+  [144] = {.entry = {.count = 1, .reusable = false}}, SHIFT(250),
+  [146] = {.entry = {.count = 1, .reusable = false}}, SHIFT(138),
+  [148] = {.entry = {.count = 1, .reusable = false}}, SHIFT(325),
+  [150] = {.entry = {.count = 1, .reusable = false}}, SHIFT(327),
+  [152] = {.entry = {.count = 1, .reusable = false}}, SHIFT(238),
+  [154] = {.entry = {.count = 1, .reusable = false}}, SHIFT(240),
+  [156] = {.entry = {.count = 1, .reusable = false}}, SHIFT(129),
+  [158] = {.entry = {.count = 1, .reusable = false}}, SHIFT(432),
+  [160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
+  [162] = {.entry = {.count = 1, .reusable = false}}, SHIFT(361),
+  [164] = {.entry = {.count = 1, .reusable = false}}, SHIFT(370),
+  [166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(377),
+  [168] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
+  [170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [172] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [176] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
+  [178] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hole, 4),
+  [180] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hole, 4),
+  [182] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_wires_inner_repeat1, 2),
+  [184] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(361),
+  [187] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(370),
+  [190] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(377),
+  [193] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(23),
+  [196] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires_inner, 1),
+  [198] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 4),
+  [200] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 4),
+  [202] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2),
+  [204] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_io_port_repeat1, 2),
+  [206] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(421),
+  [209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 3),
+  [211] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 3),
+  [213] = {.entry = {.count = 1, .reusable = false}}, SHIFT(105),
+  [215] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 4),
+  [217] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 4),
+  [219] = {.entry = {.count = 1, .reusable = false}}, SHIFT(106),
+  [221] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3),
+  [223] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3),
+  [225] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 5),
+  [227] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 5),
+  [229] = {.entry = {.count = 1, .reusable = false}}, SHIFT(107),
+  [231] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cmp_expr, 3),
+  [233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [237] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [239] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cmp_expr, 3),
+  [243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 1),
+  [245] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 1),
+  [247] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 3),
+  [249] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 3),
+  [251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 3),
+  [253] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 3),
+  [255] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
+  [257] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 5),
+  [259] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 5),
+  [261] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 5),
+  [263] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 5),
+  [265] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 5),
+  [267] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 5),
+  [269] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 5),
+  [271] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 5),
+  [273] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 3),
+  [275] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 3),
+  [277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 3),
+  [279] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 3),
+  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_stmt, 1),
+  [283] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_stmt, 1),
+  [285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 6),
+  [287] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 6),
   [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 6),
   [291] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 6),
   [293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 6),
   [295] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 6),
-  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 5),
-  [299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 5),
-  [301] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_stmt, 3),
-  [303] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_stmt, 3),
-  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 7),
-  [307] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 7),
-  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 3),
-  [311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 3),
-  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 5),
-  [315] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 5),
-  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_base_expr, 1),
-  [319] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_base_expr, 1),
-  [321] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 1),
-  [323] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 1),
+  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 6),
+  [299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 6),
+  [301] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 7),
+  [303] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 7),
+  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 7),
+  [307] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 7),
+  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 8),
+  [311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 8),
+  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 9),
+  [315] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 9),
+  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 10),
+  [319] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 10),
+  [321] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 3),
+  [323] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 3),
   [325] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 4),
   [327] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 4),
-  [329] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 6),
-  [331] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 6),
-  [333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 3),
-  [335] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 3),
-  [337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 4),
-  [339] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 4),
-  [341] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 8),
-  [343] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 8),
-  [345] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 7),
-  [347] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 7),
-  [349] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 1),
-  [351] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 1),
-  [353] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 3),
-  [355] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 3),
-  [357] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 6),
-  [359] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 6),
-  [361] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 10),
-  [363] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 10),
-  [365] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 1),
-  [367] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_io_port_repeat1, 1),
-  [369] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
-  [371] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
-  [373] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
-  [375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
-  [377] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term, 1),
-  [379] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
-  [381] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [329] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 4),
+  [331] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 4),
+  [333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 2),
+  [335] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 2),
+  [337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_stmt, 3),
+  [339] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_stmt, 3),
+  [341] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_attribute, 2),
+  [343] = {.entry = {.count = 1, .reusable = true}}, SHIFT(442),
+  [345] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_attribute, 2),
+  [347] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_stmt, 4),
+  [349] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_stmt, 4),
+  [351] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_base_expr, 1),
+  [353] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_base_expr, 1),
+  [355] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 1),
+  [357] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 1),
+  [359] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 1),
+  [361] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 1),
+  [363] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 4),
+  [365] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 4),
+  [367] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [369] = {.entry = {.count = 1, .reusable = false}}, SHIFT(91),
+  [371] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [373] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [375] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term, 1),
+  [377] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [379] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 1),
+  [381] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_io_port_repeat1, 1),
   [383] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2),
-  [385] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2), SHIFT_REPEAT(459),
-  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
-  [390] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_attribute, 5),
-  [392] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_attribute, 5),
-  [394] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2),
-  [396] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2), SHIFT_REPEAT(408),
-  [399] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2), SHIFT_REPEAT(23),
-  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
-  [404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
-  [406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
-  [408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(426),
-  [410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
-  [412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
-  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
-  [418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
-  [420] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
-  [422] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 7),
-  [424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
-  [426] = {.entry = {.count = 1, .reusable = false}}, SHIFT(356),
-  [428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
-  [430] = {.entry = {.count = 1, .reusable = false}}, SHIFT(339),
-  [432] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 6),
-  [434] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 5),
-  [436] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import, 3),
-  [438] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 4),
-  [440] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2),
-  [442] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(188),
-  [445] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(455),
-  [448] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(150),
-  [451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 1),
-  [453] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
-  [455] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 8),
-  [457] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern, 5),
-  [459] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
-  [461] = {.entry = {.count = 1, .reusable = true}}, SHIFT(407),
-  [463] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern, 4),
-  [465] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 8),
-  [467] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term, 2),
-  [469] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_latency_annotation, 3),
-  [471] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
-  [473] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_static_annotation, 1),
-  [475] = {.entry = {.count = 1, .reusable = true}}, SHIFT(438),
-  [477] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term, 3),
-  [479] = {.entry = {.count = 1, .reusable = true}}, SHIFT(452),
-  [481] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
-  [483] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
-  [485] = {.entry = {.count = 1, .reusable = true}}, SHIFT(382),
-  [487] = {.entry = {.count = 1, .reusable = true}}, SHIFT(381),
-  [489] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 9),
-  [491] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
-  [493] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
-  [495] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
-  [497] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_static_annotation, 2),
-  [499] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
-  [501] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 6),
-  [503] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
-  [505] = {.entry = {.count = 1, .reusable = true}}, SHIFT(456),
-  [507] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 5),
-  [509] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2),
-  [511] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2), SHIFT_REPEAT(408),
-  [514] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2), SHIFT_REPEAT(407),
-  [517] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
-  [519] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 1),
-  [521] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_cells_inner_repeat1, 2),
-  [523] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(356),
-  [526] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(413),
-  [529] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(339),
-  [532] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 10),
-  [534] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 7),
-  [536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
-  [538] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells_inner, 1),
-  [540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
-  [542] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
-  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
-  [546] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wire_assignment, 5),
-  [548] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wire_assignment, 5),
-  [550] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_wires_inner_repeat1, 1),
-  [552] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 1),
-  [554] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arg_list, 3),
-  [556] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arg_list, 3),
+  [385] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2), SHIFT_REPEAT(413),
+  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
+  [390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [392] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_attribute, 5),
+  [394] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_attribute, 5),
+  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [398] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [400] = {.entry = {.count = 1, .reusable = true}}, SHIFT(424),
+  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [408] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2),
+  [410] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2), SHIFT_REPEAT(377),
+  [413] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2), SHIFT_REPEAT(23),
+  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [420] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [422] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 6),
+  [424] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 7),
+  [426] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 5),
+  [428] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 8),
+  [430] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2),
+  [432] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(184),
+  [435] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(366),
+  [438] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(149),
+  [441] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
+  [443] = {.entry = {.count = 1, .reusable = false}}, SHIFT(429),
+  [445] = {.entry = {.count = 1, .reusable = true}}, SHIFT(411),
+  [447] = {.entry = {.count = 1, .reusable = false}}, SHIFT(396),
+  [449] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import, 3),
+  [451] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [453] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 1),
+  [455] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 4),
+  [457] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [459] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [461] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [463] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [465] = {.entry = {.count = 1, .reusable = true}}, SHIFT(354),
+  [467] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_static_annotation, 1),
+  [469] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [471] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [473] = {.entry = {.count = 1, .reusable = true}}, SHIFT(378),
+  [475] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [477] = {.entry = {.count = 1, .reusable = true}}, SHIFT(381),
+  [479] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells_inner, 1),
+  [481] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
+  [483] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 7),
+  [485] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_cells_inner_repeat1, 2),
+  [487] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(429),
+  [490] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(411),
+  [493] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(396),
+  [496] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_static_annotation, 2),
+  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(453),
+  [500] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 8),
+  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
+  [506] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 9),
+  [508] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [510] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term, 2),
+  [512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
+  [514] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 1),
+  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [518] = {.entry = {.count = 1, .reusable = true}}, SHIFT(454),
+  [520] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 10),
+  [522] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern, 4),
+  [524] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_latency_annotation, 3),
+  [526] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term, 3),
+  [528] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2),
+  [530] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2), SHIFT_REPEAT(377),
+  [533] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2), SHIFT_REPEAT(406),
+  [536] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 5),
+  [538] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [540] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern, 5),
+  [542] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 6),
+  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [546] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [548] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arg_list, 2),
+  [550] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arg_list, 2),
+  [552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [554] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 5),
+  [556] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 5),
   [558] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 5),
   [560] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 5),
-  [562] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 6),
-  [564] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 6),
-  [566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
-  [568] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 5),
-  [570] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 5),
+  [562] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [564] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 4),
+  [566] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 4),
+  [568] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_instantiation, 2),
+  [570] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_instantiation, 2),
   [572] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 4),
   [574] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 4),
-  [576] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arg_list, 2),
-  [578] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arg_list, 2),
-  [580] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(426),
-  [583] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
-  [585] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 4),
-  [587] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 4),
-  [589] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
-  [591] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wire_assignment, 4),
-  [593] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wire_assignment, 4),
-  [595] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
-  [597] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 3),
-  [599] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 3),
-  [601] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 7),
-  [603] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 7),
-  [605] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_instantiation, 2),
-  [607] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_instantiation, 2),
-  [609] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
-  [611] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
-  [613] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
-  [615] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
-  [617] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
-  [619] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
-  [621] = {.entry = {.count = 1, .reusable = true}}, SHIFT(390),
-  [623] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(408),
-  [626] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_cells_inner_repeat1, 1),
-  [628] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 1),
-  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(388),
-  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(386),
-  [634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(399),
-  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
-  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(402),
-  [640] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_attributes_repeat1, 2),
-  [642] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attributes_repeat1, 2), SHIFT_REPEAT(404),
-  [645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(393),
-  [647] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comb_or_static, 1),
-  [649] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
-  [651] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
+  [576] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arg_list, 3),
+  [578] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arg_list, 3),
+  [580] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 6),
+  [582] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 6),
+  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [586] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 7),
+  [588] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 7),
+  [590] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wire_assignment, 4),
+  [592] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wire_assignment, 4),
+  [594] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(424),
+  [597] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [599] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 3),
+  [601] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 3),
+  [603] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_wires_inner_repeat1, 1),
+  [605] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 1),
+  [607] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wire_assignment, 5),
+  [609] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wire_assignment, 5),
+  [611] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [613] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [615] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_cells_inner_repeat1, 1),
+  [617] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 1),
+  [619] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
+  [621] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [623] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [625] = {.entry = {.count = 1, .reusable = true}}, SHIFT(456),
+  [627] = {.entry = {.count = 1, .reusable = true}}, SHIFT(457),
+  [629] = {.entry = {.count = 1, .reusable = true}}, SHIFT(459),
+  [631] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [633] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [635] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(377),
+  [638] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comb_or_static, 1),
+  [640] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 6),
+  [642] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 6),
+  [644] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_attributes_repeat1, 2),
+  [646] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attributes_repeat1, 2), SHIFT_REPEAT(329),
+  [649] = {.entry = {.count = 1, .reusable = true}}, SHIFT(436),
+  [651] = {.entry = {.count = 1, .reusable = true}}, SHIFT(439),
   [653] = {.entry = {.count = 1, .reusable = true}}, SHIFT(446),
-  [655] = {.entry = {.count = 1, .reusable = true}}, SHIFT(443),
-  [657] = {.entry = {.count = 1, .reusable = true}}, SHIFT(451),
-  [659] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
-  [661] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
-  [663] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
-  [665] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 1),
-  [667] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 6),
-  [669] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 6),
-  [671] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
-  [673] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
-  [675] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 1),
-  [677] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
-  [679] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
-  [681] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells, 4),
-  [683] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
-  [685] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 2),
-  [687] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
-  [689] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
-  [691] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
-  [693] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
-  [695] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
-  [697] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 3),
-  [699] = {.entry = {.count = 1, .reusable = false}}, SHIFT(351),
-  [701] = {.entry = {.count = 1, .reusable = false}}, SHIFT(461),
-  [703] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 2),
-  [705] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 2), SHIFT_REPEAT(309),
-  [708] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invoke_args_repeat1, 2), SHIFT_REPEAT(390),
-  [711] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invoke_ref_args_repeat1, 2), SHIFT_REPEAT(386),
-  [714] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 4),
-  [716] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 4),
-  [718] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
-  [720] = {.entry = {.count = 1, .reusable = false}}, SHIFT(348),
-  [722] = {.entry = {.count = 1, .reusable = false}}, SHIFT(309),
-  [724] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 2),
-  [726] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 2), SHIFT_REPEAT(201),
-  [729] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 4),
-  [731] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 3),
-  [733] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 3),
-  [735] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
-  [737] = {.entry = {.count = 1, .reusable = false}}, SHIFT(364),
-  [739] = {.entry = {.count = 1, .reusable = true}}, SHIFT(428),
-  [741] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 1),
-  [743] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 2),
-  [745] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 2),
-  [747] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
-  [749] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
-  [751] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells, 3),
-  [753] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port, 3),
-  [755] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3),
-  [757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(431),
-  [759] = {.entry = {.count = 1, .reusable = true}}, SHIFT(430),
-  [761] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comb_or_static, 2),
-  [763] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
-  [765] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
-  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
-  [769] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
-  [771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
-  [773] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
-  [775] = {.entry = {.count = 1, .reusable = true}}, SHIFT(290),
-  [777] = {.entry = {.count = 1, .reusable = true}}, SHIFT(369),
-  [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
-  [781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
-  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
-  [785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
-  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
-  [789] = {.entry = {.count = 1, .reusable = true}}, SHIFT(392),
-  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
-  [793] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
-  [795] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
-  [797] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
-  [799] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [655] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
+  [657] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
+  [659] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [661] = {.entry = {.count = 1, .reusable = true}}, SHIFT(444),
+  [663] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [665] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [667] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 1),
+  [669] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [671] = {.entry = {.count = 1, .reusable = true}}, SHIFT(452),
+  [673] = {.entry = {.count = 1, .reusable = false}}, SHIFT(451),
+  [675] = {.entry = {.count = 1, .reusable = false}}, SHIFT(462),
+  [677] = {.entry = {.count = 1, .reusable = false}}, SHIFT(400),
+  [679] = {.entry = {.count = 1, .reusable = false}}, SHIFT(306),
+  [681] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [683] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [685] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [687] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [689] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [691] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [693] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 1),
+  [695] = {.entry = {.count = 1, .reusable = true}}, SHIFT(431),
+  [697] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 2),
+  [699] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells, 4),
+  [701] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [703] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 3),
+  [705] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invoke_args_repeat1, 2), SHIFT_REPEAT(348),
+  [708] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invoke_ref_args_repeat1, 2), SHIFT_REPEAT(358),
+  [711] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 3),
+  [713] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells, 3),
+  [715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(292),
+  [717] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 3),
+  [719] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 2),
+  [721] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 2),
+  [723] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 2), SHIFT_REPEAT(254),
+  [726] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [728] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 2),
+  [730] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 4),
+  [732] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 4),
+  [734] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 2),
+  [736] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 2), SHIFT_REPEAT(306),
+  [739] = {.entry = {.count = 1, .reusable = true}}, SHIFT(426),
+  [741] = {.entry = {.count = 1, .reusable = false}}, SHIFT(416),
+  [743] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [745] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [747] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 4),
+  [749] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [751] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 1),
+  [753] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [755] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
+  [757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [759] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
+  [761] = {.entry = {.count = 1, .reusable = false}}, SHIFT(397),
+  [763] = {.entry = {.count = 1, .reusable = false}}, SHIFT(367),
+  [765] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [767] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires, 3),
+  [769] = {.entry = {.count = 1, .reusable = true}}, SHIFT(290),
+  [771] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires, 4),
+  [773] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
+  [775] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [777] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(394),
+  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(395),
+  [785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [789] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port_with, 1),
+  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(409),
+  [793] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [795] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [797] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
+  [799] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
   [801] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
-  [803] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
-  [805] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port_with, 1),
-  [807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(435),
-  [809] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
-  [811] = {.entry = {.count = 1, .reusable = true}}, SHIFT(387),
-  [813] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_signature, 3),
+  [803] = {.entry = {.count = 1, .reusable = true}}, SHIFT(374),
+  [805] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [809] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comb_or_static, 2),
+  [811] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
+  [813] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_arg, 3),
   [815] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_arg, 3),
-  [817] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires, 4),
-  [819] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_arg, 3),
-  [821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
-  [823] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
-  [825] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 2), SHIFT_REPEAT(349),
-  [828] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 1),
-  [830] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
-  [832] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
-  [834] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port, 4),
-  [836] = {.entry = {.count = 1, .reusable = true}}, SHIFT(372),
-  [838] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires, 3),
-  [840] = {.entry = {.count = 1, .reusable = false}}, SHIFT(365),
-  [842] = {.entry = {.count = 1, .reusable = false}}, SHIFT(363),
-  [844] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
-  [846] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
-  [848] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
-  [850] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
-  [852] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
-  [854] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
-  [856] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
-  [858] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [860] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
-  [862] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
-  [864] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
-  [866] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lhs, 1),
-  [868] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
-  [870] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
-  [872] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
-  [874] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
-  [876] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
-  [878] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_switch, 3),
-  [880] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 4),
-  [882] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_invoke_args_repeat1, 2),
-  [884] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
-  [886] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
-  [888] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_invoke_ref_args_repeat1, 2),
-  [890] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 3),
-  [892] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive_blob, 3),
-  [894] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
-  [896] = {.entry = {.count = 1, .reusable = false}}, SHIFT(441),
-  [898] = {.entry = {.count = 1, .reusable = true}}, SHIFT(363),
-  [900] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_control_inner, 1),
-  [902] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
-  [904] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
-  [906] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
-  [908] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
-  [910] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive_blob, 2),
-  [912] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
-  [914] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
-  [916] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 3),
-  [918] = {.entry = {.count = 1, .reusable = true}}, SHIFT(415),
-  [920] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 2),
-  [922] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [924] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 4),
-  [926] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
-  [928] = {.entry = {.count = 1, .reusable = true}}, SHIFT(385),
-  [930] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
-  [932] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port_with, 3),
-  [934] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
-  [936] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
-  [938] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
-  [940] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
-  [942] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
-  [944] = {.entry = {.count = 1, .reusable = true}}, SHIFT(391),
-  [946] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 2),
-  [948] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
-  [950] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
-  [952] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
-  [954] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
-  [956] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
-  [958] = {.entry = {.count = 1, .reusable = true}}, SHIFT(395),
-  [960] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_control, 4),
-  [962] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
-  [964] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [966] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
-  [968] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
-  [970] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
-  [972] = {.entry = {.count = 1, .reusable = true}}, SHIFT(389),
-  [974] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
-  [976] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
-  [978] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
-  [980] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3),
-  [982] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
-  [984] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
-  [986] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
-  [988] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
-  [990] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
-  [992] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
-  [994] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
-  [996] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
-  [998] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
-  [1000] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
-  [1002] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
-  [1004] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
-  [1006] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
-  [1008] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
-  [1010] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
-  [1012] = {.entry = {.count = 1, .reusable = true}}, SHIFT(414),
-  [1014] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
-  [1016] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
-  [1018] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_metadata, 2),
-  [1020] = {.entry = {.count = 1, .reusable = true}}, SHIFT(410),
-  [1022] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
-  [1024] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
-  [1026] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
-  [1028] = {.entry = {.count = 1, .reusable = true}}, SHIFT(308),
-  [1030] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
-  [1032] = {.entry = {.count = 1, .reusable = true}}, SHIFT(460),
-  [1034] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
-  [1036] = {.entry = {.count = 1, .reusable = true}}, SHIFT(424),
-  [1038] = {.entry = {.count = 1, .reusable = true}}, SHIFT(427),
-  [1040] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
-  [1042] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
-  [1044] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [1046] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
-  [1048] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [1050] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
-  [1052] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
-  [1054] = {.entry = {.count = 1, .reusable = true}}, SHIFT(436),
-  [1056] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
-  [1058] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
-  [1060] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
-  [1062] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_metadata_ci, 1),
-  [1064] = {.entry = {.count = 1, .reusable = true}}, SHIFT(440),
-  [1066] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 3),
-  [1068] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 1),
+  [817] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [819] = {.entry = {.count = 1, .reusable = true}}, SHIFT(386),
+  [821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(434),
+  [823] = {.entry = {.count = 1, .reusable = true}}, SHIFT(334),
+  [825] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [827] = {.entry = {.count = 1, .reusable = true}}, SHIFT(391),
+  [829] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_signature, 3),
+  [831] = {.entry = {.count = 1, .reusable = true}}, SHIFT(392),
+  [833] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 2), SHIFT_REPEAT(455),
+  [836] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 1),
+  [838] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
+  [840] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [842] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [844] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port, 4),
+  [846] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port, 3),
+  [848] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [850] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3),
+  [852] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [854] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lhs, 1),
+  [856] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [858] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [860] = {.entry = {.count = 1, .reusable = true}}, SHIFT(356),
+  [862] = {.entry = {.count = 1, .reusable = true}}, SHIFT(461),
+  [864] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_switch, 3),
+  [866] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [868] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3),
+  [870] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_invoke_args_repeat1, 2),
+  [872] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [874] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [876] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [878] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
+  [880] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_invoke_ref_args_repeat1, 2),
+  [882] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 3),
+  [884] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
+  [886] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [888] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_control, 3),
+  [890] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [892] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [894] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [896] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [898] = {.entry = {.count = 1, .reusable = true}}, SHIFT(438),
+  [900] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [902] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [904] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 2),
+  [906] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
+  [908] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_metadata_ci, 1),
+  [910] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [912] = {.entry = {.count = 1, .reusable = true}}, SHIFT(420),
+  [914] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [916] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [918] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
+  [920] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [922] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [924] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [926] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [928] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [930] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 4),
+  [932] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [934] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [936] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [938] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [940] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [942] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [944] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [946] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [948] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port_with, 3),
+  [950] = {.entry = {.count = 1, .reusable = true}}, SHIFT(390),
+  [952] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
+  [954] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [956] = {.entry = {.count = 1, .reusable = true}}, SHIFT(393),
+  [958] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 4),
+  [960] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [962] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 2),
+  [964] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 3),
+  [966] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [968] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
+  [970] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [972] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_metadata, 2),
+  [974] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive_blob, 2),
+  [976] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [978] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [980] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [982] = {.entry = {.count = 1, .reusable = true}}, SHIFT(399),
+  [984] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
+  [986] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [988] = {.entry = {.count = 1, .reusable = true}}, SHIFT(385),
+  [990] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [992] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
+  [994] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [996] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [998] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive_blob, 3),
+  [1000] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [1002] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [1004] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [1006] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [1008] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [1010] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [1012] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [1014] = {.entry = {.count = 1, .reusable = true}}, SHIFT(412),
+  [1016] = {.entry = {.count = 1, .reusable = true}}, SHIFT(435),
+  [1018] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [1020] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [1022] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
+  [1024] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
+  [1026] = {.entry = {.count = 1, .reusable = true}}, SHIFT(312),
+  [1028] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [1030] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [1032] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_control, 4),
+  [1034] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [1036] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [1038] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [1040] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_control_inner, 1),
+  [1042] = {.entry = {.count = 1, .reusable = true}}, SHIFT(422),
+  [1044] = {.entry = {.count = 1, .reusable = true}}, SHIFT(425),
+  [1046] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [1048] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [1050] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [1052] = {.entry = {.count = 1, .reusable = true}}, SHIFT(265),
+  [1054] = {.entry = {.count = 1, .reusable = false}}, SHIFT(332),
+  [1056] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [1058] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [1060] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
+  [1062] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [1064] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [1066] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [1068] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [1070] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 3),
+  [1072] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 1),
 };
 
 #ifdef __cplusplus
@@ -1,4 +1,5 @@
 use crate::passes::math_utilities::get_bit_width_from;
+use calyx_ir::profiling::StaticFSMRegister;
 use calyx_ir::{self as ir};
 use calyx_ir::{build_assignments, Nothing};
 use calyx_ir::{guard, structure};
@@ -16,13 +17,14 @@ enum FSMEncoding {
     OneHot,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, Default)]
 enum FSMImplementationSpec {
+    #[default]
     Single,
     // How many duplicates
-    _Duplicate(u64),
+    Duplicate(u64),
     // How many times to split
-    _Split(u64),
+    Split(u64),
 }
 
 #[derive(Debug)]
@@ -32,20 +34,23 @@ enum FSMImplementation {
     Single(ir::RRC<ir::Cell>),
     // Duplicate the register to reduce fanout when querying
     // (all FSMs in this vec still have all of the states)
-    _Duplicate(Vec<ir::RRC<ir::Cell>>),
+    Duplicate(Vec<ir::RRC<ir::Cell>>),
     // Split the FSM to reduce fanout when querying.
     // (the FSMs partition the states exactly).
-    // Each FSM has fewer bits but I suspect the logic might be more complicated.
-    _Split(Vec<ir::RRC<ir::Cell>>),
+    // Each FSM is paired with the [beg, end) range of states it holds.
+    // The first FSM holds state s as s, and holds `end` when another FSM is
+    // active. Every other FSM holds state s as s - beg + 1, and holds 0 when
+    // it is not active.
+    Split(Vec<(ir::RRC<ir::Cell>, (u64, u64))>),
 }
 
 impl FSMImplementation {
-    fn get_single_cell(&self) -> ir::RRC<ir::Cell> {
+    // Returns the register that holds the first state of the FSM.
+    fn get_first_cell(&self) -> ir::RRC<ir::Cell> {
         match self {
             FSMImplementation::Single(cell) => Rc::clone(cell),
-            _ => unreachable!(
-                "called `get_single_cell()` on non-single FSM implementation "
-            ),
+            FSMImplementation::Duplicate(cells) => Rc::clone(&cells[0]),
+            FSMImplementation::Split(fsms) => Rc::clone(&fsms[0].0),
         }
     }
 }
@@ -55,18 +60,24 @@ pub struct StaticFSM {
     encoding: FSMEncoding,
     // The fsm's bitwidth (this redundant information bc  we have `cell`)
     // but makes it easier if we easily have access to this.
+    // For split FSMs, this is the bitwidth of the first register.
     bitwidth: u64,
     // The actual register(s) used to implement the FSM
     implementation: FSMImplementation,
+    // Number of states of the FSM
+    num_states: u64,
     // Mapping of queries from (u64, u64) -> Port
     queries: HashMap<(u64, u64), ir::RRC<ir::Port>>,
+    // Number of queries made so far. Duplicated FSMs use this to spread
+    // their queries across the copies of the register.
+    num_queries: usize,
 }
 impl StaticFSM {
     // Builds a static_fsm from: num_states and encoding type.
     fn from_basic_info(
         num_states: u64,
         encoding: FSMEncoding,
-        _implementation: FSMImplementationSpec,
+        implementation: FSMImplementationSpec,
         builder: &mut ir::Builder,
     ) -> Self {
         // Determine number of bits needed in the register.
@@ -76,21 +87,60 @@ impl StaticFSM {
             FSMEncoding::OneHot => num_states,
         };
         // OHE needs an initial value of 1.
-        let register = match encoding {
+        let mut add_register = |width| match encoding {
             FSMEncoding::Binary => {
-                builder.add_primitive("fsm", "std_reg", &[fsm_size])
+                builder.add_primitive("fsm", "std_reg", &[width])
             }
             FSMEncoding::OneHot => {
-                builder.add_primitive("fsm", "init_one_reg", &[fsm_size])
+                builder.add_primitive("fsm", "init_one_reg", &[width])
             }
         };
-        let fsm = FSMImplementation::Single(register);
+        let fsm = match implementation {
+            FSMImplementationSpec::Single => {
+                FSMImplementation::Single(add_register(fsm_size))
+            }
+            FSMImplementationSpec::Duplicate(copies) => {
+                FSMImplementation::Duplicate(
+                    (0..copies).map(|_| add_register(fsm_size)).collect(),
+                )
+            }
+            FSMImplementationSpec::Split(splits) => {
+                assert!(
+                    matches!(encoding, FSMEncoding::Binary),
+                    "only binary FSMs can be split"
+                );
+                // Partition the states as evenly as possible. Every FSM must
+                // hold at least one state.
+                let splits = splits.clamp(1, num_states.max(1));
+                let (size, rem) = (num_states / splits, num_states % splits);
+                let mut beg = 0;
+                let fsms = (0..splits)
+                    .map(|i| {
+                        let end = beg + size + u64::from(i < rem);
+                        // The first FSM also needs to represent `end`; the
+                        // others need to represent 0 and each of their states.
+                        let width = if i == 0 {
+                            get_bit_width_from(end + 1)
+                        } else {
+                            get_bit_width_from(end - beg + 1)
+                        };
+                        let fsm = (add_register(width), (beg, end));
+                        beg = end;
+                        fsm
+                    })
+                    .collect_vec();
+                FSMImplementation::Split(fsms)
+            }
+        };
+        let bitwidth = Self::get_cell_bitwidth(&fsm.get_first_cell());
 
         StaticFSM {
             encoding,
-            bitwidth: fsm_size,
+            bitwidth,
             implementation: fsm,
+            num_states,
             queries: HashMap::new(),
+            num_queries: 0,
         }
     }
 
@@ -110,105 +160,232 @@ impl StaticFSM {
         n: u64,
         incr_condition: Option<Guard<Nothing>>,
     ) -> Vec<ir::Assignment<Nothing>> {
-        {
-            assert!(matches!(
-                self.implementation,
-                FSMImplementation::Single(_)
-            ));
-            let fsm_cell: Rc<std::cell::RefCell<ir::Cell>> =
-                self.implementation.get_single_cell();
-            // For OHE, the "adder" can just be a shifter.
-            // For OHE the first_state = 1 rather than 0.
-            // Final state is encoded differently for OHE vs. Binary
-            let (adder, first_state, final_state_guard) = match self.encoding {
-                FSMEncoding::Binary => (
-                    builder.add_primitive("adder", "std_add", &[self.bitwidth]),
-                    builder.add_constant(0, self.bitwidth),
-                    {
-                        let const_n = builder.add_constant(n, self.bitwidth);
-                        let g = guard!(fsm_cell["out"] == const_n["out"]);
-                        g
-                    },
+        if matches!(self.implementation, FSMImplementation::Split(_)) {
+            return self.count_to_n_split(builder, n, incr_condition);
+        }
+        // For duplicated FSMs, we count using the first register and then
+        // write the same values into each of the copies.
+        let fsm_cell = self.implementation.get_first_cell();
+        // For OHE, the "adder" can just be a shifter.
+        // For OHE the first_state = 1 rather than 0.
+        // Final state is encoded differently for OHE vs. Binary
+        let (adder, first_state, final_state_guard) = match self.encoding {
+            FSMEncoding::Binary => (
+                builder.add_primitive("adder", "std_add", &[self.bitwidth]),
+                builder.add_constant(0, self.bitwidth),
+                {
+                    let const_n = builder.add_constant(n, self.bitwidth);
+                    let g = guard!(fsm_cell["out"] == const_n["out"]);
+                    g
+                },
+            ),
+            FSMEncoding::OneHot => (
+                builder.add_primitive("lsh", "std_lsh", &[self.bitwidth]),
+                builder.add_constant(1, self.bitwidth),
+                self.get_one_hot_query(
+                    Rc::clone(&fsm_cell),
+                    (n, n + 1),
+                    builder,
                 ),
-                FSMEncoding::OneHot => (
-                    builder.add_primitive("lsh", "std_lsh", &[self.bitwidth]),
-                    builder.add_constant(1, self.bitwidth),
-                    self.get_one_hot_query(
+            ),
+        };
+        structure!( builder;
+            let signal_on = constant(1,1);
+            let const_one = constant(1, self.bitwidth);
+        );
+        let not_final_state_guard =
+            ir::Guard::Not(Box::new(final_state_guard.clone()));
+        let assigns = match incr_condition {
+            None => {
+                // Unconditionally increment FSM.
+                build_assignments!(
+                  builder;
+                  // increments the fsm
+                  adder["left"] = ? fsm_cell["out"];
+                  adder["right"] = ? const_one["out"];
+                  fsm_cell["write_en"] = ? signal_on["out"];
+                  fsm_cell["in"] =  not_final_state_guard ? adder["out"];
+                   // resets the fsm early
+                   fsm_cell["in"] = final_state_guard ? first_state["out"];
+                )
+                .to_vec()
+            }
+            Some(condition_guard) => {
+                // Only start incrementing when FSM == first_state and
+                // conditiona_guard is true.
+                // After that, we can unconditionally increment.
+                let first_state_guard = match self.encoding {
+                    FSMEncoding::Binary => {
+                        let g = guard!(fsm_cell["out"] == first_state["out"]);
+                        g
+                    }
+                    // This is better than checking if FSM == first_state
+                    // be this is only checking a single bit.
+                    FSMEncoding::OneHot => self.get_one_hot_query(
                         Rc::clone(&fsm_cell),
-                        (n, n + 1),
+                        (0, 1),
                         builder,
                     ),
-                ),
-            };
-            structure!( builder;
-                let signal_on = constant(1,1);
-                let const_one = constant(1, self.bitwidth);
+                };
+                let not_first_state: ir::Guard<Nothing> =
+                    ir::Guard::Not(Box::new(first_state_guard.clone()));
+                let cond_and_first_state = ir::Guard::and(
+                    condition_guard.clone(),
+                    first_state_guard.clone(),
+                );
+                let not_cond_and_first_state =
+                    ir::Guard::not(condition_guard.clone())
+                        .and(first_state_guard);
+                let in_between_guard =
+                    ir::Guard::and(not_first_state, not_final_state_guard);
+                let my_assigns = build_assignments!(
+                  builder;
+                  // Incrementsthe fsm
+                  adder["left"] = ? fsm_cell["out"];
+                  adder["right"] = ? const_one["out"];
+                  // Always write into fsm.
+                  fsm_cell["write_en"] = ? signal_on["out"];
+                  // If fsm == first_state and cond is high, then we start an execution.
+                  fsm_cell["in"] = cond_and_first_state ? adder["out"];
+                  // If first_state < fsm < n, then we unconditionally increment the fsm.
+                  fsm_cell["in"] = in_between_guard ? adder["out"];
+                  // If fsm == n, then we reset the FSM.
+                  fsm_cell["in"] = final_state_guard ? first_state["out"];
+                  // Otherwise we set the FSM equal to first_state.
+                  fsm_cell["in"] = not_cond_and_first_state ? first_state["out"];
+                );
+                my_assigns.to_vec()
+            }
+        };
+        match &self.implementation {
+            FSMImplementation::Duplicate(cells) => {
+                // Copy the writes to the first register into the other ones.
+                let copies = assigns
+                    .iter()
+                    .filter(|assign| {
+                        Rc::ptr_eq(
+                            &assign.dst.borrow().cell_parent(),
+                            &fsm_cell,
+                        )
+                    })
+                    .flat_map(|assign| {
+                        cells[1..].iter().map(move |copy| {
+                            let mut assign = assign.clone();
+                            let port_name = assign.dst.borrow().name;
+                            assign.dst = copy.borrow().get(port_name);
+                            assign
+                        })
+                    })
+                    .collect_vec();
+                assigns.into_iter().chain(copies).collect()
+            }
+            _ => assigns,
+        }
+    }
+
+    // `count_to_n` for split FSMs. The FSM holding the current state counts
+    // through its states and then hands off to the FSM holding the next
+    // state. FSMs that hold states after `n` are never used.
+    fn count_to_n_split(
+        &self,
+        builder: &mut ir::Builder,
+        n: u64,
+        incr_condition: Option<Guard<Nothing>>,
+    ) -> Vec<ir::Assignment<Nothing>> {
+        let FSMImplementation::Split(fsms) = &self.implementation else {
+            unreachable!("called `count_to_n_split()` on non-split FSM")
+        };
+        let cond = incr_condition.unwrap_or(ir::Guard::True);
+        let at_n = self.query_split(builder, (n, n + 1));
+        structure!(builder;
+            let signal_on = constant(1,1);
+        );
+        let mut assigns = vec![];
+        for (i, (fsm_cell, (beg, end))) in fsms.iter().enumerate() {
+            if *beg > n {
+                break;
+            }
+            let width = Self::get_cell_bitwidth(fsm_cell);
+            structure!(builder;
+                let adder = prim std_add(width);
+                let const_zero = constant(0, width);
+                let const_one = constant(1, width);
             );
-            let not_final_state_guard =
-                ir::Guard::Not(Box::new(final_state_guard.clone()));
-            match incr_condition {
-                None => {
-                    // Unconditionally increment FSM.
-                    build_assignments!(
-                      builder;
-                      // increments the fsm
-                      adder["left"] = ? fsm_cell["out"];
-                      adder["right"] = ? const_one["out"];
-                      fsm_cell["write_en"] = ? signal_on["out"];
-                      fsm_cell["in"] =  not_final_state_guard ? adder["out"];
-                       // resets the fsm early
-                       fsm_cell["in"] = final_state_guard ? first_state["out"];
-                    )
-                    .to_vec()
+            // The last state this FSM counts up to before it resets or hands
+            // off to the next FSM.
+            let last = std::cmp::min(n, end - 1);
+            // (guard, value) pairs for the writes into the FSM. The FSM is
+            // set to 0 when none of the guards hold.
+            let mut writes = vec![];
+            if i == 0 {
+                // If fsm < last, increment the fsm (starting an execution
+                // requires `cond`).
+                let last_const = builder.add_constant(last, width);
+                let in_progress = guard!(fsm_cell["out"] < last_const["out"]);
+                let started = guard!(fsm_cell["out"] != const_zero["out"]);
+                writes.push((
+                    in_progress & (started | cond.clone()),
+                    Rc::clone(&adder),
+                ));
+                if n >= *end {
+                    // Hand off to the next FSM and wait until it reaches n.
+                    let out_const = builder.add_constant(*end, width);
+                    let is_out = guard!(fsm_cell["out"] == out_const["out"]);
+                    let leave = self.split_advance(builder, end - 1, &cond);
+                    writes.push((leave | (is_out & !at_n.clone()), out_const));
                 }
-                Some(condition_guard) => {
-                    // Only start incrementing when FSM == first_state and
-                    // conditiona_guard is true.
-                    // After that, we can unconditionally increment.
-                    let first_state_guard = match self.encoding {
-                        FSMEncoding::Binary => {
-                            let g =
-                                guard!(fsm_cell["out"] == first_state["out"]);
-                            g
-                        }
-                        // This is better than checking if FSM == first_state
-                        // be this is only checking a single bit.
-                        FSMEncoding::OneHot => self.get_one_hot_query(
-                            Rc::clone(&fsm_cell),
-                            (0, 1),
-                            builder,
-                        ),
-                    };
-                    let not_first_state: ir::Guard<Nothing> =
-                        ir::Guard::Not(Box::new(first_state_guard.clone()));
-                    let cond_and_first_state = ir::Guard::and(
-                        condition_guard.clone(),
-                        first_state_guard.clone(),
-                    );
-                    let not_cond_and_first_state =
-                        ir::Guard::not(condition_guard.clone())
-                            .and(first_state_guard);
-                    let in_between_guard =
-                        ir::Guard::and(not_first_state, not_final_state_guard);
-                    let my_assigns = build_assignments!(
-                      builder;
-                      // Incrementsthe fsm
-                      adder["left"] = ? fsm_cell["out"];
-                      adder["right"] = ? const_one["out"];
-                      // Always write into fsm.
-                      fsm_cell["write_en"] = ? signal_on["out"];
-                      // If fsm == first_state and cond is high, then we start an execution.
-                      fsm_cell["in"] = cond_and_first_state ? adder["out"];
-                      // If first_state < fsm < n, then we unconditionally increment the fsm.
-                      fsm_cell["in"] = in_between_guard ? adder["out"];
-                      // If fsm == n, then we reset the FSM.
-                      fsm_cell["in"] = final_state_guard ? first_state["out"];
-                      // Otherwise we set the FSM equal to first_state.
-                      fsm_cell["in"] = not_cond_and_first_state ? first_state["out"];
+            } else {
+                // Take over from the previous FSM.
+                writes.push((
+                    self.split_advance(builder, beg - 1, &cond),
+                    Rc::clone(&const_one),
+                ));
+                let local_last = last - beg + 1;
+                if local_last > 1 {
+                    let in_progress = Self::build_binary_query(
+                        Rc::clone(fsm_cell),
+                        width,
+                        (1, local_last),
+                        builder,
                     );
-                    my_assigns.to_vec()
+                    writes.push((in_progress, Rc::clone(&adder)));
                 }
             }
+            let reset_guard = !writes
+                .iter()
+                .map(|(guard, _)| guard.clone())
+                .reduce(|acc, guard| acc | guard)
+                .unwrap();
+            assigns.extend(build_assignments!(builder;
+                adder["left"] = ? fsm_cell["out"];
+                adder["right"] = ? const_one["out"];
+                fsm_cell["write_en"] = ? signal_on["out"];
+                fsm_cell["in"] = reset_guard ? const_zero["out"];
+            ));
+            assigns.extend(writes.into_iter().map(|(guard, value)| {
+                builder.build_assignment(
+                    fsm_cell.borrow().get("in"),
+                    value.borrow().get("out"),
+                    guard,
+                )
+            }));
+        }
+        assigns
+    }
+
+    // For split FSMs, returns a guard that holds when the FSM moves on from
+    // state `state`. Moving on from state 0 also requires `cond`.
+    fn split_advance(
+        &self,
+        builder: &mut ir::Builder,
+        state: u64,
+        cond: &ir::Guard<Nothing>,
+    ) -> ir::Guard<Nothing> {
+        let at_state = self.query_split(builder, (state, state + 1));
+        if state == 0 {
+            at_state & cond.clone()
+        } else {
+            at_state
         }
     }
 
@@ -219,37 +396,104 @@ impl StaticFSM {
         builder: &mut ir::Builder,
         query: (u64, u64),
     ) -> Box<ir::Guard<Nothing>> {
-        assert!(matches!(self.implementation, FSMImplementation::Single(_)));
+        if matches!(self.implementation, FSMImplementation::Split(_)) {
+            return Box::new(self.query_split(builder, query));
+        }
 
-        let (beg, end) = query;
+        let fsm_cell = self.get_query_cell();
         if matches!(self.encoding, FSMEncoding::OneHot) {
             // Querying OHE is easy, since we already have `self.get_one_hot_query()`
-            let fsm_cell = self.implementation.get_single_cell();
-            let g = self.get_one_hot_query(fsm_cell, (beg, end), builder);
+            let g = self.get_one_hot_query(fsm_cell, query, builder);
             return Box::new(g);
         }
 
-        let fsm_cell = self.implementation.get_single_cell();
+        Box::new(Self::build_binary_query(
+            fsm_cell,
+            self.bitwidth,
+            query,
+            builder,
+        ))
+    }
+
+    // Returns the register that should answer the next query. Duplicated FSMs
+    // use each of their copies in turn.
+    fn get_query_cell(&mut self) -> ir::RRC<ir::Cell> {
+        let cell = match &self.implementation {
+            FSMImplementation::Single(cell) => Rc::clone(cell),
+            FSMImplementation::Duplicate(cells) => {
+                Rc::clone(&cells[self.num_queries % cells.len()])
+            }
+            FSMImplementation::Split(_) => unreachable!(
+                "called `get_query_cell()` on split FSM implementation"
+            ),
+        };
+        self.num_queries += 1;
+        cell
+    }
+
+    // Given a (beg, end) query on a split FSM, returns a guard that is true
+    // when one of the FSMs holding states in [beg, end) is in one of them.
+    fn query_split(
+        &self,
+        builder: &mut ir::Builder,
+        (beg, end): (u64, u64),
+    ) -> ir::Guard<Nothing> {
+        let FSMImplementation::Split(fsms) = &self.implementation else {
+            unreachable!("called `query_split()` on non-split FSM")
+        };
+        assert!(
+            beg < end && end <= self.num_states,
+            "query [{beg}, {end}) is not within the {} states of the split FSM",
+            self.num_states
+        );
+        fsms.iter()
+            .enumerate()
+            .filter_map(|(i, (fsm_cell, (fsm_beg, fsm_end)))| {
+                let (lb, ub) = (beg.max(*fsm_beg), end.min(*fsm_end));
+                if lb >= ub {
+                    return None;
+                }
+                // Every FSM but the first stores its states offset by one.
+                let offset = if i == 0 { 0 } else { fsm_beg - 1 };
+                Some(Self::build_binary_query(
+                    Rc::clone(fsm_cell),
+                    Self::get_cell_bitwidth(fsm_cell),
+                    (lb - offset, ub - offset),
+                    builder,
+                ))
+            })
+            .reduce(|acc, guard| acc | guard)
+            .expect("the split FSMs hold every state of the FSM")
+    }
+
+    // Given a (beg, end) query, and a binary fsm (and for convenience, a
+    // bitwidth), returns the guard `beg <= fsm.out < end`.
+    fn build_binary_query(
+        fsm_cell: ir::RRC<ir::Cell>,
+        fsm_bitwidth: u64,
+        (beg, end): (u64, u64),
+        builder: &mut ir::Builder,
+    ) -> ir::Guard<Nothing> {
         if beg + 1 == end {
             // if beg + 1 == end then we only need to check if fsm == beg
-            let interval_const = builder.add_constant(beg, self.bitwidth);
+            let interval_const = builder.add_constant(beg, fsm_bitwidth);
             let g = guard!(fsm_cell["out"] == interval_const["out"]);
-            Box::new(g)
+            g
         } else if beg == 0 {
             // if beg == 0, then we only need to check if fsm < end
-            let end_const = builder.add_constant(end, self.bitwidth);
+            let end_const = builder.add_constant(end, fsm_bitwidth);
             let lt: ir::Guard<Nothing> =
                 guard!(fsm_cell["out"] < end_const["out"]);
-            Box::new(lt)
+            lt
         } else {
             // otherwise, check if fsm >= beg & fsm < end
-            let beg_const = builder.add_constant(beg, self.bitwidth);
-            let end_const = builder.add_constant(end, self.bitwidth);
+            let beg_const = builder.add_constant(beg, fsm_bitwidth);
+            let end_const = builder.add_constant(end, fsm_bitwidth);
             let beg_guard: ir::Guard<Nothing> =
                 guard!(fsm_cell["out"] >= beg_const["out"]);
             let end_guard: ir::Guard<Nothing> =
                 guard!(fsm_cell["out"] < end_const["out"]);
-            Box::new(ir::Guard::And(Box::new(beg_guard), Box::new(end_guard)))
+            ir::Guard::And(Box::new(beg_guard), Box::new(end_guard))
         }
     }
 
@@ -322,15 +566,49 @@ impl StaticFSM {
     // Return a unique id (i.e., get_unique_id for each FSM in the same component
     // will be different).
     pub fn get_unique_id(&self) -> ir::Id {
-        assert!(matches!(self.implementation, FSMImplementation::Single(_)));
-        self.implementation.get_single_cell().borrow().name()
+        self.implementation.get_first_cell().borrow().name()
     }
 
     // Return the bitwidth of an FSM object
     pub fn get_bitwidth(&self) -> u64 {
-        assert!(matches!(self.implementation, FSMImplementation::Single(_)));
         self.bitwidth
     }
+
+    /// Returns the registers that hold the states of the FSM, for profiling.
+    /// Duplicated FSMs only return their first register.
+    pub fn get_profiling_registers(&self) -> Vec<StaticFSMRegister> {
+        let one_hot = matches!(self.encoding, FSMEncoding::OneHot);
+        let register =
+            |cell: &ir::RRC<ir::Cell>, (beg, end): (u64, u64), offset| {
+                StaticFSMRegister {
+                    fsm: cell.borrow().name(),
+                    one_hot,
+                    beg,
+                    end,
+                    offset,
+                }
+            };
+        match &self.implementation {
+            FSMImplementation::Single(cell) => {
+                vec![register(cell, (0, self.num_states), 0)]
+            }
+            FSMImplementation::Duplicate(cells) => {
+                vec![register(&cells[0], (0, self.num_states), 0)]
+            }
+            FSMImplementation::Split(fsms) => fsms
+                .iter()
+                .enumerate()
+                .map(|(i, (cell, range))| {
+                    register(cell, *range, u64::from(i > 0))
+                })
+                .collect(),
+        }
+    }
+
+    // Return the bitwidth of one of the registers implementing an FSM.
+    fn get_cell_bitwidth(fsm_cell: &ir::RRC<ir::Cell>) -> u64 {
+        fsm_cell.borrow().get("out").borrow().width
+    }
 }
 
 /// Represents a static schedule.
@@ -345,6 +623,8 @@ pub struct StaticSchedule {
     queries: HashSet<(u64, u64)>,
     /// Encoding type for the FSM
     encoding: FSMEncoding,
+    /// How the FSM is implemented (i.e., whether it is duplicated or split)
+    implementation: FSMImplementationSpec,
     /// The static groups the FSM will schedule. It is a vec because sometimes
     /// the same FSM will handle two different static islands.
     pub static_groups: Vec<ir::RRC<ir::StaticGroup>>,
@@ -368,6 +648,34 @@ impl From<Vec<ir::RRC<ir::StaticGroup>>> for StaticSchedule {
             } else {
                 FSMEncoding::Binary
             };
+        // Similarly, the coloring algorithm inserts conflicts if the
+        // @fsm_duplicate or @fsm_split attributes don't match.
+        // One-hot FSMs cannot be split, so they ignore @fsm_split.
+        schedule.implementation = match schedule.static_groups.first() {
+            Some(sgroup) => {
+                let sgroup = sgroup.borrow();
+                let attrs = &sgroup.attributes;
+                match (
+                    attrs.get(ir::NumAttr::FSMSplit),
+                    attrs.get(ir::NumAttr::FSMDuplicate),
+                ) {
+                    (Some(n), _)
+                        if n > 1
+                            && matches!(
+                                schedule.encoding,
+                                FSMEncoding::Binary
+                            ) =>
+                    {
+                        FSMImplementationSpec::Split(n)
+                    }
+                    (_, Some(n)) if n > 1 => {
+                        FSMImplementationSpec::Duplicate(n)
+                    }
+                    _ => FSMImplementationSpec::Single,
+                }
+            }
+            None => FSMImplementationSpec::Single,
+        };
         for static_group in &schedule.static_groups {
             // Getting self.queries
             for static_assign in &static_group.borrow().assignments {
@@ -430,7 +738,7 @@ impl StaticSchedule {
         let mut fsm_object = StaticFSM::from_basic_info(
             self.num_states,
             self.encoding,
-            FSMImplementationSpec::Single,
+            self.implementation,
             builder,
         );
 
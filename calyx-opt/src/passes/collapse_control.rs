@@ -133,12 +133,16 @@ impl Visitor for CollapseControl {
             return Ok(Action::static_change(ir::StaticControl::empty()));
         }
         if s.stmts.len() == 1 {
-            // Want to preserve @one_hot attribute.
+            // Want to preserve @one_hot, @fsm_duplicate, and @fsm_split attributes.
             let mut replacement_ctrl = s.stmts.pop().unwrap();
             let attrs = std::mem::take(&mut s.attributes);
             replacement_ctrl
                 .get_mut_attributes()
-                .copy_from(attrs, vec![ir::BoolAttr::OneHot]);
+                .copy_from(attrs.clone(), vec![ir::BoolAttr::OneHot]);
+            replacement_ctrl.get_mut_attributes().copy_from(
+                attrs,
+                vec![ir::NumAttr::FSMDuplicate, ir::NumAttr::FSMSplit],
+            );
 
             return Ok(Action::static_change(replacement_ctrl));
         }
@@ -167,12 +171,16 @@ impl Visitor for CollapseControl {
             return Ok(Action::static_change(ir::StaticControl::empty()));
         }
         if s.stmts.len() == 1 {
-            // Want to preserve @one_hot attribute.
+            // Want to preserve @one_hot, @fsm_duplicate, and @fsm_split attributes.
             let mut replacement_ctrl = s.stmts.pop().unwrap();
             let attrs = std::mem::take(&mut s.attributes);
             replacement_ctrl
                 .get_mut_attributes()
-                .copy_from(attrs, vec![ir::BoolAttr::OneHot]);
+                .copy_from(attrs.clone(), vec![ir::BoolAttr::OneHot]);
+            replacement_ctrl.get_mut_attributes().copy_from(
+                attrs,
+                vec![ir::NumAttr::FSMDuplicate, ir::NumAttr::FSMSplit],
+            );
             return Ok(Action::static_change(replacement_ctrl));
         }
         let mut seqs: Vec<ir::StaticControl> = vec![];
@@ -1,8 +1,11 @@
 use crate::analysis::{GraphColoring, StaticFSM, StaticSchedule};
-use crate::traversal::{Action, Named, ParseVal, PassOpt, VisResult, Visitor};
+use crate::traversal::{
+    Action, ConstructVisitor, Named, ParseVal, PassOpt, VisResult, Visitor,
+};
 use calyx_ir as ir;
+use calyx_ir::profiling::{StaticFSMGroup, StaticFSMInfo};
 use calyx_ir::{guard, structure, GetAttributes};
-use calyx_utils::Error;
+use calyx_utils::{CalyxResult, Error};
 use ir::{build_assignments, RRC};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
@@ -10,7 +13,6 @@ use std::ops::Not;
 use std::rc::Rc;
 
 /// Compiles Static Islands
-#[derive(Default)]
 pub struct CompileStatic {
     /// maps original static group names to the corresponding group that has an FSM that reset early
     reset_early_map: HashMap<ir::Id, ir::Id>,
@@ -20,6 +22,40 @@ pub struct CompileStatic {
     signal_reg_map: HashMap<ir::Id, ir::Id>,
     /// maps reset_early_group names to StaticFSM object
     fsm_info_map: HashMap<ir::Id, ir::RRC<StaticFSM>>,
+    /// maps reset_early_group names to the "while wrapper" groups built for them
+    while_wrapper_map: HashMap<ir::Id, Vec<ir::Id>>,
+    /// Profiling information for the FSMs of the current component.
+    /// The wrappers of each group are added once the component is compiled.
+    static_fsms: Vec<StaticFSMInfo>,
+    /// Profiling information for the FSMs of all components
+    profiling_info: Vec<ir::ProfilingInfo>,
+}
+
+impl ConstructVisitor for CompileStatic {
+    fn from(_ctx: &ir::Context) -> CalyxResult<Self>
+    where
+        Self: Sized,
+    {
+        Ok(CompileStatic {
+            reset_early_map: HashMap::new(),
+            wrapper_map: HashMap::new(),
+            signal_reg_map: HashMap::new(),
+            fsm_info_map: HashMap::new(),
+            while_wrapper_map: HashMap::new(),
+            static_fsms: Vec::new(),
+            profiling_info: Vec::new(),
+        })
+    }
+
+    fn clear_data(&mut self) {
+        // The profiling information is kept across components.
+        self.reset_early_map.clear();
+        self.wrapper_map.clear();
+        self.signal_reg_map.clear();
+        self.fsm_info_map.clear();
+        self.while_wrapper_map.clear();
+        self.static_fsms.clear();
+    }
 }
 
 impl Named for CompileStatic {
@@ -411,21 +447,27 @@ impl CompileStatic {
         }
     }
 
-    /// Adds conflicts between static groups that require different encodings.
-    /// For example: if one group is one-hot and another is binary, then
-    /// we insert a conflict between those two groups.
+    /// Adds conflicts between static groups that require different encodings
+    /// or FSM implementations.
+    /// For example: if one group is one-hot and another is binary, or if one
+    /// group duplicates its FSM and the other does not, then we insert a
+    /// conflict between those two groups.
     fn add_encoding_conflicts(
         sgroups: &[ir::RRC<ir::StaticGroup>],
         conflict_graph: &mut GraphColoring<ir::Id>,
     ) {
         for (sgroup1, sgroup2) in sgroups.iter().tuple_combinations() {
-            if sgroup1.borrow().attributes.has(ir::BoolAttr::OneHot)
-                != sgroup2.borrow().attributes.has(ir::BoolAttr::OneHot)
+            let (sgroup1, sgroup2) = (sgroup1.borrow(), sgroup2.borrow());
+            let (attrs1, attrs2) = (&sgroup1.attributes, &sgroup2.attributes);
+            if attrs1.has(ir::BoolAttr::OneHot)
+                != attrs2.has(ir::BoolAttr::OneHot)
+                || attrs1.get(ir::NumAttr::FSMDuplicate)
+                    != attrs2.get(ir::NumAttr::FSMDuplicate)
+                || attrs1.get(ir::NumAttr::FSMSplit)
+                    != attrs2.get(ir::NumAttr::FSMSplit)
             {
-                conflict_graph.insert_conflict(
-                    &sgroup1.borrow().name(),
-                    &sgroup2.borrow().name(),
-                );
+                conflict_graph
+                    .insert_conflict(&sgroup1.name(), &sgroup2.name());
             }
         }
     }
@@ -748,6 +790,11 @@ impl Visitor for CompileStatic {
             } else {
                 let (mut static_group_assigns, fsm) = sch
                     .realize_schedule(&mut builder, static_component_interface);
+                let mut fsm_profiling = StaticFSMInfo {
+                    component: builder.component.name,
+                    registers: fsm.get_profiling_registers(),
+                    groups: Vec::new(),
+                };
                 let fsm_ref = ir::rrc(fsm);
                 for static_group in sch.static_groups.iter() {
                     // Create the dynamic "early reset group" that will replace the static group.
@@ -800,7 +847,14 @@ impl Visitor for CompileStatic {
                         early_reset_group.borrow().name(),
                         Rc::clone(&fsm_ref),
                     );
+
+                    fsm_profiling.groups.push(StaticFSMGroup {
+                        group: static_group_name,
+                        early_reset: early_reset_group.borrow().name(),
+                        wrappers: Vec::new(),
+                    });
                 }
+                self.static_fsms.push(fsm_profiling);
             }
         }
 
@@ -961,16 +1015,20 @@ impl Visitor for CompileStatic {
         if s.cond.is_none() {
             if let ir::Control::Static(sc) = &mut *(s.body) {
                 let mut builder = ir::Builder::new(comp, sigs);
-                let reset_group_name = self.get_reset_group_name(sc);
+                let reset_group_name = *self.get_reset_group_name(sc);
 
                 // Get fsm for reset_group
-                let fsm_object = self.fsm_info_map.get(reset_group_name).unwrap_or_else(|| unreachable!("group {} has no correspondoing fsm in self.fsm_map", reset_group_name));
+                let fsm_object = self.fsm_info_map.get(&reset_group_name).unwrap_or_else(|| unreachable!("group {} has no correspondoing fsm in self.fsm_map", reset_group_name));
                 let wrapper_group = self.build_wrapper_group_while(
                     Rc::clone(fsm_object),
-                    reset_group_name,
+                    &reset_group_name,
                     Rc::clone(&s.port),
                     &mut builder,
                 );
+                self.while_wrapper_map
+                    .entry(reset_group_name)
+                    .or_default()
+                    .push(wrapper_group.borrow().name());
                 let c = ir::Control::enable(wrapper_group);
                 return Ok(Action::change(c));
             }
@@ -995,6 +1053,25 @@ impl Visitor for CompileStatic {
         // remove all static groups
         comp.get_static_groups_mut().retain(|_| false);
 
+        // Record the groups that run each early reset group for profiling.
+        for mut info in self.static_fsms.drain(..) {
+            for group in &mut info.groups {
+                group.wrappers = self
+                    .wrapper_map
+                    .get(&group.early_reset)
+                    .into_iter()
+                    .chain(
+                        self.while_wrapper_map
+                            .get(&group.early_reset)
+                            .into_iter()
+                            .flatten(),
+                    )
+                    .copied()
+                    .collect();
+            }
+            self.profiling_info.push(ir::ProfilingInfo::StaticFsm(info));
+        }
+
         // Remove control if static component
         if comp.is_static() {
             comp.control = ir::rrc(ir::Control::empty())
@@ -1002,4 +1079,9 @@ impl Visitor for CompileStatic {
 
         Ok(Action::Continue)
     }
+
+    fn finish_context(&mut self, ctx: &mut ir::Context) -> VisResult {
+        ctx.profiling_info.append(&mut self.profiling_info);
+        Ok(Action::Continue)
+    }
 }
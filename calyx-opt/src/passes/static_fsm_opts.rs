@@ -8,6 +8,8 @@ use calyx_utils::CalyxResult;
 /// component that contains an input `clk` port.
 pub struct StaticFSMOpts {
     one_hot_cutoff: u64,
+    fsm_duplicates: u64,
+    fsm_splits: u64,
 }
 
 impl Named for StaticFSMOpts {
@@ -20,13 +22,29 @@ impl Named for StaticFSMOpts {
     }
 
     fn opts() -> Vec<PassOpt> {
-        vec![PassOpt::new(
-            "one-hot-cutoff",
-            "The upper limit on the number of states the static FSM must have before we pick binary \
-            encoding over one-hot. Defaults to 0 (i.e., always choose binary encoding)",
-            ParseVal::Num(0),
-            PassOpt::parse_num,
-        )]
+        vec![
+            PassOpt::new(
+                "one-hot-cutoff",
+                "The upper limit on the number of states the static FSM must have before we pick binary \
+                encoding over one-hot. Defaults to 0 (i.e., always choose binary encoding)",
+                ParseVal::Num(0),
+                PassOpt::parse_num,
+            ),
+            PassOpt::new(
+                "fsm-duplicates",
+                "The number of copies of the register used to implement each static FSM. \
+                Defaults to 1 (i.e., no duplication)",
+                ParseVal::Num(1),
+                PassOpt::parse_num,
+            ),
+            PassOpt::new(
+                "fsm-splits",
+                "The number of registers that the states of each binary static FSM are split across. \
+                Defaults to 1 (i.e., no splitting)",
+                ParseVal::Num(1),
+                PassOpt::parse_num,
+            ),
+        ]
     }
 }
 
@@ -36,6 +54,8 @@ impl ConstructVisitor for StaticFSMOpts {
 
         Ok(StaticFSMOpts {
             one_hot_cutoff: opts["one-hot-cutoff"].pos_num().unwrap(),
+            fsm_duplicates: opts["fsm-duplicates"].pos_num().unwrap(),
+            fsm_splits: opts["fsm-splits"].pos_num().unwrap(),
         })
     }
 
@@ -50,14 +70,22 @@ impl Visitor for StaticFSMOpts {
         _comps: &[ir::Component],
     ) -> VisResult {
         comp.get_static_groups_mut().iter_mut().for_each(|sgroup| {
-            let sgroup_latency = sgroup.borrow().get_latency();
+            let mut sgroup = sgroup.borrow_mut();
+            let sgroup_latency = sgroup.get_latency();
             // If static group's latency is less than the cutoff, encode as a
             // one-hot FSM.
             if sgroup_latency < self.one_hot_cutoff {
-                sgroup
-                    .borrow_mut()
-                    .attributes
-                    .insert(ir::BoolAttr::OneHot, 1);
+                sgroup.attributes.insert(ir::BoolAttr::OneHot, 1);
+            }
+            // Attributes placed on the control program take precedence over
+            // the global options.
+            for (attr, n) in [
+                (ir::NumAttr::FSMDuplicate, self.fsm_duplicates),
+                (ir::NumAttr::FSMSplit, self.fsm_splits),
+            ] {
+                if n > 1 && !sgroup.attributes.has(attr) {
+                    sgroup.attributes.insert(attr, n);
+                }
             }
         });
 
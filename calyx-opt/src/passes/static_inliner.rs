@@ -1,12 +1,31 @@
-use crate::traversal::{Action, Named, VisResult, Visitor};
+use crate::traversal::{Action, ConstructVisitor, Named, VisResult, Visitor};
 use calyx_ir as ir;
+use calyx_ir::profiling::{StaticChildInfo, StaticGroupInfo};
 use calyx_ir::structure;
 use calyx_ir::LibrarySignatures;
+use calyx_utils::CalyxResult;
 use ir::build_assignments;
 use std::rc::Rc;
 
-#[derive(Default)]
-pub struct StaticInliner;
+pub struct StaticInliner {
+    /// Profiling information for the static groups built by the pass
+    profiling_info: Vec<ir::ProfilingInfo>,
+}
+
+impl ConstructVisitor for StaticInliner {
+    fn from(_ctx: &ir::Context) -> CalyxResult<Self>
+    where
+        Self: Sized,
+    {
+        Ok(StaticInliner {
+            profiling_info: Vec::new(),
+        })
+    }
+
+    fn clear_data(&mut self) {
+        /* The profiling information is kept across components */
+    }
+}
 
 impl Named for StaticInliner {
     fn name() -> &'static str {
@@ -116,8 +135,36 @@ impl StaticInliner {
         cond_assigns
     }
 
+    // The name of `port` in the component that defines it, for profiling.
+    fn profiling_port_name(port: &ir::RRC<ir::Port>) -> String {
+        let port = port.borrow();
+        match &port.parent {
+            ir::PortParent::Cell(cell) if cell.upgrade().borrow().is_this() => {
+                port.name.to_string()
+            }
+            _ => port.canonical().to_string(),
+        }
+    }
+
+    // Records that the children run during the given states of `group`.
+    fn add_profiling_info(
+        &mut self,
+        group: &ir::RRC<ir::StaticGroup>,
+        children: Vec<StaticChildInfo>,
+        builder: &ir::Builder,
+    ) {
+        self.profiling_info.push(ir::ProfilingInfo::StaticGroup(
+            StaticGroupInfo {
+                component: builder.component.name,
+                group: group.borrow().name(),
+                children,
+            },
+        ));
+    }
+
     // inlines the static control `sc` and returns an equivalent single static group
     fn inline_static_control(
+        &mut self,
         sc: &ir::StaticControl,
         builder: &mut ir::Builder,
     ) -> ir::RRC<ir::StaticGroup> {
@@ -136,11 +183,12 @@ impl StaticInliner {
                     ir::Assignment<ir::StaticTiming>,
                 > = vec![];
                 let mut cur_offset = 0;
+                let mut children = Vec::with_capacity(stmts.len());
                 for stmt in stmts {
                     let stmt_latency = stmt.get_latency();
                     // first recursively call each stmt in seq, and turn each stmt
                     // into static group g.
-                    let g = StaticInliner::inline_static_control(stmt, builder);
+                    let g = self.inline_static_control(stmt, builder);
                     assert!(
                         g.borrow().get_latency() == stmt_latency,
                         "static group latency doesn't match static stmt latency"
@@ -162,6 +210,12 @@ impl StaticInliner {
                     );
                     // add g_assigns to seq_group_assigns
                     seq_group_assigns.extend(g_assigns.into_iter());
+                    children.push(StaticChildInfo {
+                        group: g.borrow().name(),
+                        beg: cur_offset,
+                        end: cur_offset + stmt_latency,
+                        condition: None,
+                    });
                     // updates cur_offset so that next stmt gets its static timing
                     // offset appropriately
                     cur_offset += stmt_latency;
@@ -172,6 +226,7 @@ impl StaticInliner {
                 );
                 seq_group.borrow_mut().assignments = seq_group_assigns;
                 seq_group.borrow_mut().attributes = attributes.clone();
+                self.add_profiling_info(&seq_group, children, builder);
                 seq_group
             }
             ir::StaticControl::Par(ir::StaticPar {
@@ -184,10 +239,11 @@ impl StaticInliner {
                 let mut par_group_assigns: Vec<
                     ir::Assignment<ir::StaticTiming>,
                 > = vec![];
+                let mut children = Vec::with_capacity(stmts.len());
                 for stmt in stmts {
                     let stmt_latency = stmt.get_latency();
                     // recursively turn each stmt in the par block into a group g
-                    let g = StaticInliner::inline_static_control(stmt, builder);
+                    let g = self.inline_static_control(stmt, builder);
                     assert!(
                         g.borrow().get_latency() == stmt_latency,
                         "static group latency doesn't match static stmt latency"
@@ -206,9 +262,16 @@ impl StaticInliner {
                     );
                     // add g_assigns to par_group_assigns
                     par_group_assigns.extend(g_assigns.into_iter());
+                    children.push(StaticChildInfo {
+                        group: g.borrow().name(),
+                        beg: 0,
+                        end: stmt_latency,
+                        condition: None,
+                    });
                 }
                 par_group.borrow_mut().assignments = par_group_assigns;
                 par_group.borrow_mut().attributes = attributes.clone();
+                self.add_profiling_info(&par_group, children, builder);
                 par_group
             }
             ir::StaticControl::If(ir::StaticIf {
@@ -228,8 +291,7 @@ impl StaticInliner {
 
                 // Inline assignments in tbranch and fbranch, and get resulting
                 // tgroup_assigns and fgroup_assigns
-                let tgroup =
-                    StaticInliner::inline_static_control(tbranch, builder);
+                let tgroup = self.inline_static_control(tbranch, builder);
                 let mut tgroup_assigns: Vec<ir::Assignment<ir::StaticTiming>> =
                     tgroup.borrow_mut().assignments.clone();
                 assert_eq!(
@@ -237,15 +299,28 @@ impl StaticInliner {
                     tgroup.borrow().get_latency(),
                     "tru branch and tru branch group latency do not match"
                 );
+                // The conditions of the branches are added once we know which
+                // port holds the condition.
+                let mut children = vec![StaticChildInfo {
+                    group: tgroup.borrow().name(),
+                    beg: 0,
+                    end: tbranch_latency,
+                    condition: None,
+                }];
                 // turn fgroup (if it exists) into group and put assigns into fgroup_assigns
                 let mut fgroup_assigns: Vec<ir::Assignment<ir::StaticTiming>> =
                     match **fbranch {
                         ir::StaticControl::Empty(_) => vec![],
                         _ => {
-                            let fgroup = StaticInliner::inline_static_control(
-                                fbranch, builder,
-                            );
+                            let fgroup =
+                                self.inline_static_control(fbranch, builder);
                             assert_eq!(fbranch_latency, fgroup.borrow().get_latency(), "false branch and false branch group latency do not match");
+                            children.push(StaticChildInfo {
+                                group: fgroup.borrow().name(),
+                                beg: 0,
+                                end: fbranch_latency,
+                                condition: None,
+                            });
                             let fgroup_assigns: Vec<
                                 ir::Assignment<ir::StaticTiming>,
                             > = fgroup.borrow_mut().assignments.clone();
@@ -259,7 +334,7 @@ impl StaticInliner {
                 let mut if_group_assigns: Vec<
                     ir::Assignment<ir::StaticTiming>,
                 > = vec![];
-                if *latency == 1 {
+                let cond_port = if *latency == 1 {
                     // Special case: if latency = 1, we don't need a register
                     // to hold the value of the cond port.
                     let cond_port_guard = ir::Guard::Port(Rc::clone(port));
@@ -277,6 +352,7 @@ impl StaticInliner {
                             guard.and(not_cond_port_guard.clone())
                         })
                     });
+                    Rc::clone(port)
                 } else {
                     // If latency != 1, we do need a register to hold the
                     // value of the cond port.
@@ -329,11 +405,19 @@ impl StaticInliner {
                             guard.and(not_cond_wire_guard.clone())
                         })
                     });
-                }
+                    let cond_out = cond_wire.borrow().get("out");
+                    cond_out
+                };
                 if_group_assigns.extend(tgroup_assigns);
                 if_group_assigns.extend(fgroup_assigns);
                 if_group.borrow_mut().assignments = if_group_assigns;
                 if_group.borrow_mut().attributes = attributes.clone();
+                // The true branch is first, followed by the false branch.
+                let cond_name = Self::profiling_port_name(&cond_port);
+                for (child, value) in children.iter_mut().zip([true, false]) {
+                    child.condition = Some((cond_name.clone(), value));
+                }
+                self.add_profiling_info(&if_group, children, builder);
                 if_group
             }
             ir::StaticControl::Repeat(ir::StaticRepeat {
@@ -345,8 +429,7 @@ impl StaticInliner {
                 let repeat_group =
                     builder.add_static_group("static_repeat", *latency);
                 // turn body into a group body_group by recursively calling inline_static_control
-                let body_group =
-                    StaticInliner::inline_static_control(body, builder);
+                let body_group = self.inline_static_control(body, builder);
                 assert_eq!(*latency, (num_repeats * body_group.borrow().get_latency()), "latency of static repeat is not equal to num_repeats * latency of body");
                 // the assignments in the repeat group should simply trigger the
                 // body group. So the static group will literally look like:
@@ -359,6 +442,15 @@ impl StaticInliner {
                 );
                 repeat_group.borrow_mut().assignments = trigger_body.to_vec();
                 repeat_group.borrow_mut().attributes = attributes.clone();
+                // The body has its own FSM, which keeps track of the state of
+                // the current iteration.
+                let children = vec![StaticChildInfo {
+                    group: body_group.borrow().name(),
+                    beg: 0,
+                    end: *latency,
+                    condition: None,
+                }];
+                self.add_profiling_info(&repeat_group, children, builder);
                 repeat_group
             }
             ir::StaticControl::Empty(_) => unreachable!(
@@ -382,10 +474,14 @@ impl Visitor for StaticInliner {
         _comps: &[ir::Component],
     ) -> VisResult {
         let mut builder = ir::Builder::new(comp, sigs);
-        let replacement_group =
-            StaticInliner::inline_static_control(s, &mut builder);
+        let replacement_group = self.inline_static_control(s, &mut builder);
         Ok(Action::Change(Box::new(ir::Control::from(
             ir::StaticControl::from(replacement_group),
         ))))
     }
+
+    fn finish_context(&mut self, ctx: &mut ir::Context) -> VisResult {
+        ctx.profiling_info.append(&mut self.profiling_info);
+        Ok(Action::Continue)
+    }
 }
@@ -1,4 +1,5 @@
-use crate::traversal::{Action, Named, VisResult, Visitor};
+use crate::traversal::{Action, ConstructVisitor, Named, VisResult, Visitor};
+use calyx_ir::profiling::BarrierInfo;
 use calyx_ir::RRC;
 use calyx_ir::{self as ir, GetAttributes};
 use calyx_ir::{build_assignments, guard, structure};
@@ -7,7 +8,6 @@ use linked_hash_map::LinkedHashMap;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
-#[derive(Default)]
 /// 1. loop through all control statements under "par" block to find # barriers
 ///    needed and # members of each barrier
 /// 2. add all cells and groups needed
@@ -31,6 +31,29 @@ use std::rc::Rc;
 
 pub struct CompileSync {
     barriers: BarrierMap,
+    /// the groups that implement each barrier, for profiling
+    barrier_groups: LinkedHashMap<u64, Vec<ir::Id>>,
+    /// profiling information for the barriers of all components
+    profiling_info: Vec<ir::ProfilingInfo>,
+}
+
+impl ConstructVisitor for CompileSync {
+    fn from(_ctx: &ir::Context) -> CalyxResult<Self>
+    where
+        Self: Sized,
+    {
+        Ok(CompileSync {
+            barriers: BarrierMap::new(),
+            barrier_groups: LinkedHashMap::new(),
+            profiling_info: Vec::new(),
+        })
+    }
+
+    fn clear_data(&mut self) {
+        // the profiling information is kept across components
+        self.barriers.clear();
+        self.barrier_groups.clear();
+    }
 }
 
 /// the structure used to store cells and groups shared by one barrier
@@ -117,6 +140,18 @@ impl CompileSync {
 
                     let mut new_s =
                         build_member(builder, cells, groups, &member_idx);
+                    // record the groups of the member for profiling
+                    if let ir::Control::Seq(seq) = &new_s {
+                        let names = self.barrier_groups.entry(*n).or_default();
+                        for stmt in &seq.stmts {
+                            if let ir::Control::Enable(en) = stmt {
+                                let name = en.group.borrow().name();
+                                if !names.contains(&name) {
+                                    names.push(name);
+                                }
+                            }
+                        }
+                    }
                     std::mem::swap(s, &mut new_s);
                 }
             }
@@ -160,6 +195,10 @@ impl CompileSync {
         let shared_cells: [RRC<ir::Cell>; 2] = [barrier, eq];
         let shared_groups: [RRC<ir::Group>; 3] =
             [wait_restore, restore, clear_barrier];
+        self.barrier_groups.insert(
+            *barrier_idx,
+            shared_groups.iter().map(|g| g.borrow().name()).collect(),
+        );
         let info = (shared_cells, shared_groups);
         self.barriers.insert(*barrier_idx, info);
     }
@@ -397,4 +436,26 @@ impl Visitor for CompileSync {
 
         Ok(Action::change(ir::Control::seq(changed_sequence)))
     }
+
+    fn finish(
+        &mut self,
+        comp: &mut ir::Component,
+        _sigs: &ir::LibrarySignatures,
+        _comps: &[ir::Component],
+    ) -> VisResult {
+        for (barrier, groups) in self.barrier_groups.iter() {
+            self.profiling_info
+                .push(ir::ProfilingInfo::Barrier(BarrierInfo {
+                    component: comp.name,
+                    barrier: *barrier,
+                    groups: groups.clone(),
+                }));
+        }
+        Ok(Action::Continue)
+    }
+
+    fn finish_context(&mut self, ctx: &mut ir::Context) -> VisResult {
+        ctx.profiling_info.append(&mut self.profiling_info);
+        Ok(Action::Continue)
+    }
 }
@@ -3,6 +3,9 @@ use crate::passes;
 use crate::traversal::{
     Action, ConstructVisitor, Named, ParseVal, PassOpt, VisResult, Visitor,
 };
+use calyx_ir::profiling::{
+    FSMInfo, FSMStateInfo, ParInfo, ProfilingInfo, SingleEnableInfo,
+};
 use calyx_ir::{self as ir, GetAttributes, LibrarySignatures, Printer, RRC};
 use calyx_ir::{build_assignments, guard, structure, Id};
 use calyx_utils::Error;
@@ -10,7 +13,6 @@ use calyx_utils::{CalyxResult, OutputFile};
 use ir::Nothing;
 use itertools::Itertools;
 use petgraph::graph::DiGraph;
-use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use std::rc::Rc;
@@ -209,15 +211,118 @@ fn compute_unique_ids(con: &mut ir::Control, cur_state: u64) -> u64 {
     }
 }
 
+/// Encodings for the states of an FSM.
+#[derive(Clone, Copy)]
 enum Encoding {
+    /// State `s` is represented by `s`.
     Binary,
+    /// State `s` is represented by setting bit `s`.
     OneHot,
+    /// State `s` is represented by the Gray code of `s`, so moving to the
+    /// next state only changes one bit.
+    Gray,
+    /// A Johnson (twisted ring) counter: `n` bits represent `2n` states and
+    /// moving to the next state only changes one bit.
+    Johnson,
+}
+
+/// FSMs with at most this many states use a one-hot encoding when the
+/// encoding is chosen automatically.
+const AUTO_ONE_HOT_STATES: u64 = 8;
+/// Mostly sequential FSMs with at most this many states use a Johnson
+/// encoding when the encoding is chosen automatically.
+const AUTO_JOHNSON_STATES: u64 = 16;
+/// The widest FSM register, since the values of states are 64-bit constants.
+/// FSMs that need more bits in the requested encoding are binary instead.
+const MAX_FSM_SIZE: u64 = 64;
+
+impl Encoding {
+    /// Parses the value of the `encoding` option. `auto` is represented by
+    /// `None`.
+    fn from_opt(s: &str) -> Option<Option<Self>> {
+        match s {
+            "binary" => Some(Some(Encoding::Binary)),
+            "one-hot" => Some(Some(Encoding::OneHot)),
+            "gray" => Some(Some(Encoding::Gray)),
+            "johnson" => Some(Some(Encoding::Johnson)),
+            "auto" => Some(None),
+            _ => None,
+        }
+    }
+
+    /// Chooses an encoding for an FSM from its number of states and its
+    /// transitions.
+    /// Small FSMs are one-hot since their queries only need to check a single
+    /// bit. FSMs where most transitions move to the next state use an
+    /// encoding that only changes one bit on those transitions. Johnson
+    /// counters need one bit for every two states, so larger FSMs use Gray
+    /// codes instead. All other FSMs are binary since it needs the fewest
+    /// bits.
+    fn choose(
+        num_states: u64,
+        transitions: &[(u64, u64, ir::Guard<Nothing>)],
+    ) -> Self {
+        let sequential =
+            transitions.iter().filter(|(s, e, _)| *e == s + 1).count();
+        if num_states <= AUTO_ONE_HOT_STATES {
+            Encoding::OneHot
+        } else if 4 * sequential >= 3 * transitions.len() {
+            if num_states <= AUTO_JOHNSON_STATES {
+                Encoding::Johnson
+            } else {
+                Encoding::Gray
+            }
+        } else {
+            Encoding::Binary
+        }
+    }
+
+    /// The number of bits needed to represent states `0..=final_state`.
+    fn fsm_size(&self, final_state: u64) -> u64 {
+        match self {
+            Encoding::Binary | Encoding::Gray => get_bit_width_from(
+                final_state + 1, /* represent 0..final_state */
+            ),
+            Encoding::OneHot => final_state + 1,
+            Encoding::Johnson => (final_state + 2) / 2,
+        }
+    }
+
+    /// The value of an FSM with `fsm_size` bits in state `state`. Values are
+    /// constants, so `fsm_size` must be at most [MAX_FSM_SIZE].
+    fn state_value(&self, state: u64, fsm_size: u64) -> u64 {
+        assert!(
+            fsm_size <= MAX_FSM_SIZE,
+            "FSM with {fsm_size} bits does not fit in a constant"
+        );
+        match self {
+            Encoding::Binary => state,
+            Encoding::OneHot => 1 << state,
+            Encoding::Gray => state ^ (state >> 1),
+            // The counter fills up with ones from the least significant bit
+            // and then empties from the least significant bit.
+            Encoding::Johnson => {
+                let ones =
+                    |n: u64| u64::MAX.checked_shr(64 - n as u32).unwrap_or(0);
+                if state <= fsm_size {
+                    ones(state)
+                } else {
+                    ones(fsm_size) ^ ones(state - fsm_size)
+                }
+            }
+        }
+    }
+}
+
+/// Parses the `encoding` option of [TopDownCompileControl].
+fn parse_encoding(s: &str) -> Option<ParseVal> {
+    Encoding::from_opt(s).map(|_| ParseVal::String(s.to_string()))
 }
 
 /// Represents the dyanmic execution schedule of a control program.
 struct Schedule<'b, 'a: 'b> {
-    /// A mapping from groups to corresponding FSM state ids
-    pub groups_to_states: HashSet<FSMStateInfo>,
+    /// A mapping from FSM state ids to the groups enabled in them
+    pub groups_to_states: HashSet<(u64, Id)>,
     /// Assigments that should be enabled in a given state.
     pub enables: HashMap<u64, Vec<ir::Assignment<Nothing>>>,
     /// Transition from one state to another when the guard is true.
@@ -227,50 +332,6 @@ struct Schedule<'b, 'a: 'b> {
     pub builder: &'b mut ir::Builder<'a>,
 }
 
-/// Information to serialize for profiling purposes
-#[derive(PartialEq, Eq, Hash, Clone, Serialize)]
-enum ProfilingInfo {
-    Fsm(FSMInfo),
-    SingleEnable(SingleEnableInfo),
-}
-
-/// Information to be serialized for a group that isn't managed by a FSM
-/// This can happen if the group is the only group in a control block or a par arm
-#[derive(PartialEq, Eq, Hash, Clone, Serialize)]
-struct SingleEnableInfo {
-    #[serde(serialize_with = "id_serialize_passthrough")]
-    pub component: Id,
-    #[serde(serialize_with = "id_serialize_passthrough")]
-    pub group: Id,
-}
-
-/// Information to be serialized for a single FSM
-#[derive(PartialEq, Eq, Hash, Clone, Serialize)]
-struct FSMInfo {
-    #[serde(serialize_with = "id_serialize_passthrough")]
-    pub component: Id,
-    #[serde(serialize_with = "id_serialize_passthrough")]
-    pub group: Id,
-    #[serde(serialize_with = "id_serialize_passthrough")]
-    pub fsm: Id,
-    pub states: Vec<FSMStateInfo>,
-}
-
-/// Mapping of FSM state ids to corresponding group names
-#[derive(PartialEq, Eq, Hash, Clone, Serialize)]
-struct FSMStateInfo {
-    id: u64,
-    #[serde(serialize_with = "id_serialize_passthrough")]
-    group: Id,
-}
-
-fn id_serialize_passthrough<S>(id: &Id, ser: S) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    id.to_string().serialize(ser)
-}
-
 impl<'b, 'a> From<&'b mut ir::Builder<'a>> for Schedule<'b, 'a> {
     fn from(builder: &'b mut ir::Builder<'a>) -> Self {
         Schedule {
@@ -368,13 +429,47 @@ impl<'b, 'a> Schedule<'b, 'a> {
         }
     }
 
+    /// Returns a guard that is true when `fsm` is in state `state`. One-hot
+    /// FSMs only query a single bit of the FSM.
+    fn query_state(
+        builder: &mut ir::Builder,
+        used_slicers: &mut HashMap<u64, ir::RRC<ir::Cell>>,
+        fsm: &ir::RRC<ir::Cell>,
+        signal_on: &ir::RRC<ir::Cell>,
+        (encoding, fsm_size): (Encoding, u64),
+        state: u64,
+    ) -> ir::Guard<Nothing> {
+        match encoding {
+            Encoding::OneHot => Self::build_one_hot_query(
+                builder,
+                used_slicers,
+                fsm,
+                signal_on,
+                &state,
+                &fsm_size,
+            ),
+            Encoding::Binary | Encoding::Gray | Encoding::Johnson => {
+                let state_const = builder.add_constant(
+                    encoding.state_value(state, fsm_size),
+                    fsm_size,
+                );
+                let state_guard = guard!(fsm["out"] == state_const["out"]);
+                state_guard
+            }
+        }
+    }
+
     /// Implement a given [Schedule] and return the name of the [ir::Group] that
     /// implements it.
+    /// FSMs with at most `one_hot_cutoff` states are one-hot. Other FSMs use
+    /// `encoding`, or choose an encoding based on their transitions if it is
+    /// `None`.
     fn realize_schedule(
         self,
         dump_fsm: bool,
-        fsm_groups: &mut HashSet<ProfilingInfo>,
+        fsm_groups: &mut Vec<ProfilingInfo>,
         one_hot_cutoff: u64,
+        encoding: Option<Encoding>,
     ) -> RRC<ir::Group> {
         self.validate();
 
@@ -393,45 +488,57 @@ impl<'b, 'a> Schedule<'b, 'a> {
         let encoding = if final_state <= one_hot_cutoff {
             Encoding::OneHot
         } else {
+            encoding.unwrap_or_else(|| {
+                Encoding::choose(final_state + 1, &self.transitions)
+            })
+        };
+        let encoding = if encoding.fsm_size(final_state) > MAX_FSM_SIZE {
+            log::warn!(
+                "{}: FSM with {} states is too large for the requested encoding, using a binary encoding",
+                self.builder.component.name,
+                final_state + 1
+            );
             Encoding::Binary
+        } else {
+            encoding
         };
+        let fsm_size = encoding.fsm_size(final_state);
 
         // build necessary primitives dependent on encoding
         let signal_on = self.builder.add_constant(1, 1);
-        let (fsm, first_state, last_state_opt, fsm_size) = match encoding {
-            Encoding::Binary => {
-                let fsm_size = get_bit_width_from(
-                    final_state + 1, /* represent 0..final_state */
-                );
-                structure!(self.builder;
-                    let fsm = prim std_reg(fsm_size);
-                    let last_state = constant(final_state, fsm_size);
-                    let first_state = constant(0, fsm_size);
-                );
-                (fsm, first_state, Some(last_state), fsm_size)
-            }
+        // OHE needs an initial value of 1.
+        let fsm = match encoding {
             Encoding::OneHot => {
-                let fsm_size = final_state + 1; /* represent 0..final_state */
-
-                let fsm = self.builder.add_primitive(
-                    "fsm",
-                    "init_one_reg",
-                    &[fsm_size],
-                );
-                let first_state = self.builder.add_constant(1, fsm_size);
-                (fsm, first_state, None, fsm_size)
+                self.builder
+                    .add_primitive("fsm", "init_one_reg", &[fsm_size])
+            }
+            Encoding::Binary | Encoding::Gray | Encoding::Johnson => {
+                self.builder.add_primitive("fsm", "std_reg", &[fsm_size])
             }
         };
+        let first_state = self
+            .builder
+            .add_constant(encoding.state_value(0, fsm_size), fsm_size);
 
         // Add last state to JSON info
-        let mut states = self.groups_to_states.iter().cloned().collect_vec();
+        let mut states = self
+            .groups_to_states
+            .iter()
+            .sorted()
+            .map(|(id, group)| FSMStateInfo {
+                id: *id,
+                value: encoding.state_value(*id, fsm_size),
+                group: *group,
+            })
+            .collect_vec();
         states.push(FSMStateInfo {
             id: final_state,
+            value: encoding.state_value(final_state, fsm_size),
             group: Id::new(format!("{}_END", fsm.borrow().name())),
         });
 
         // Keep track of groups to FSM state id information for dumping to json
-        fsm_groups.insert(ProfilingInfo::Fsm(FSMInfo {
+        fsm_groups.push(ProfilingInfo::Fsm(FSMInfo {
             component: self.builder.component.name,
             fsm: fsm.borrow().name(),
             group: group.borrow().name(),
@@ -446,69 +553,37 @@ impl<'b, 'a> Schedule<'b, 'a> {
             self.enables
                 .into_iter()
                 .sorted_by(|(k1, _), (k2, _)| k1.cmp(k2))
-                .flat_map(|(state, mut assigns)| match encoding {
-                    Encoding::Binary => {
-                        let state_const =
-                            self.builder.add_constant(state, fsm_size);
-                        let state_guard =
-                            guard!(fsm["out"] == state_const["out"]);
-                        assigns.iter_mut().for_each(|asgn| {
-                            asgn.guard.update(|g| g.and(state_guard.clone()))
-                        });
-                        assigns
-                    }
-                    Encoding::OneHot => {
-                        let state_guard = Self::build_one_hot_query(
-                            self.builder,
-                            &mut used_slicers,
-                            &fsm,
-                            &signal_on,
-                            &state,
-                            &fsm_size,
-                        );
-                        assigns.iter_mut().for_each(|asgn| {
-                            asgn.guard.update(|g| g.and(state_guard.clone()))
-                        });
-                        assigns
-                    }
+                .flat_map(|(state, mut assigns)| {
+                    let state_guard = Self::query_state(
+                        self.builder,
+                        &mut used_slicers,
+                        &fsm,
+                        &signal_on,
+                        (encoding, fsm_size),
+                        state,
+                    );
+                    assigns.iter_mut().for_each(|asgn| {
+                        asgn.guard.update(|g| g.and(state_guard.clone()))
+                    });
+                    assigns
                 }),
         );
 
         // transition assignments
         group.borrow_mut().assignments.extend(
             self.transitions.into_iter().flat_map(|(s, e, guard)| {
-                let (end_const, trans_guard) = match encoding {
-                    Encoding::Binary => {
-                        structure!(self.builder;
-                            let end_const = constant(e, fsm_size);
-                            let start_const = constant(s, fsm_size);
-                        );
-                        let trans_guard =
-                            guard!((fsm["out"] == start_const["out"]) & guard);
-
-                        (end_const, trans_guard)
-                    }
-                    Encoding::OneHot => {
-                        let end_constant_value = u64::pow(
-                            2,
-                            e.try_into().expect("failed to convert to u32"),
-                        );
-
-                        let trans_guard = Self::build_one_hot_query(
-                            self.builder,
-                            &mut used_slicers,
-                            &fsm,
-                            &signal_on,
-                            &s,
-                            &fsm_size,
-                        );
-                        let end_const = self
-                            .builder
-                            .add_constant(end_constant_value, fsm_size);
-
-                        (end_const, trans_guard.and(guard))
-                    }
-                };
+                let end_const = self
+                    .builder
+                    .add_constant(encoding.state_value(e, fsm_size), fsm_size);
+                let trans_guard = Self::query_state(
+                    self.builder,
+                    &mut used_slicers,
+                    &fsm,
+                    &signal_on,
+                    (encoding, fsm_size),
+                    s,
+                )
+                .and(guard);
                 let ec_borrow = end_const.borrow();
                 vec![
                     self.builder.build_assignment(
@@ -526,51 +601,26 @@ impl<'b, 'a> Schedule<'b, 'a> {
         );
 
         // done condition for group
-        let reset_fsm = match last_state_opt {
-            // binary branch; only binary needs last state constant
-            Some(last_state) => {
-                let last_guard = guard!(fsm["out"] == last_state["out"]);
-                let done_assign = self.builder.build_assignment(
-                    group.borrow().get("done"),
-                    signal_on.borrow().get("out"),
-                    last_guard.clone(),
-                );
-                group.borrow_mut().assignments.push(done_assign);
-
-                // Cleanup: Add a transition from last state to the first state.
-                let reset_fsm = build_assignments!(self.builder;
-                    fsm["in"] = last_guard ? first_state["out"];
-                    fsm["write_en"] = last_guard ? signal_on["out"];
-                );
-
-                reset_fsm.to_vec()
-            }
-
-            // ohe branch does not need last state constant
-            None => {
-                let last_guard = Self::build_one_hot_query(
-                    self.builder,
-                    &mut used_slicers,
-                    &fsm,
-                    &signal_on,
-                    &final_state,
-                    &fsm_size,
-                );
-                let done_assign = self.builder.build_assignment(
-                    group.borrow().get("done"),
-                    signal_on.borrow().get("out"),
-                    last_guard.clone(),
-                );
-                group.borrow_mut().assignments.push(done_assign);
-                // Cleanup: Add a transition from last state to the first state.
-                let reset_fsm = build_assignments!(self.builder;
-                    fsm["in"] = last_guard ? first_state["out"];
-                    fsm["write_en"] = last_guard ? signal_on["out"];
-                );
+        let last_guard = Self::query_state(
+            self.builder,
+            &mut used_slicers,
+            &fsm,
+            &signal_on,
+            (encoding, fsm_size),
+            final_state,
+        );
+        let done_assign = self.builder.build_assignment(
+            group.borrow().get("done"),
+            signal_on.borrow().get("out"),
+            last_guard.clone(),
+        );
+        group.borrow_mut().assignments.push(done_assign);
 
-                reset_fsm.to_vec()
-            }
-        };
+        // Cleanup: Add a transition from last state to the first state.
+        let reset_fsm = build_assignments!(self.builder;
+            fsm["in"] = last_guard ? first_state["out"];
+            fsm["write_en"] = last_guard ? signal_on["out"];
+        );
 
         // extend with conditions to set fsm to initial state
         self.builder
@@ -624,7 +674,7 @@ impl Schedule<'_, '_> {
             };
 
             // Add group to mapping for emitting group JSON info
-            self.groups_to_states.insert(FSMStateInfo { id: cur_state, group: group.borrow().name() });
+            self.groups_to_states.insert((cur_state, group.borrow().name()));
 
             let not_done = !guard!(group["done"]);
             let signal_on = self.builder.add_constant(1, 1);
@@ -993,10 +1043,13 @@ pub struct TopDownCompileControl {
     /// Enable early transitions
     early_transitions: bool,
     /// Bookkeeping for FSM ids for groups across all FSMs in the program
-    fsm_groups: HashSet<ProfilingInfo>,
+    fsm_groups: Vec<ProfilingInfo>,
     /// How many states the dynamic FSM must have before we pick binary encoding over
     /// one-hot
     one_hot_cutoff: u64,
+    /// Encoding for FSMs above the one-hot cutoff. `None` chooses an encoding
+    /// for each FSM.
+    encoding: Option<Encoding>,
 }
 
 impl ConstructVisitor for TopDownCompileControl {
@@ -1005,15 +1058,24 @@ impl ConstructVisitor for TopDownCompileControl {
         Self: Sized + Named,
     {
         let opts = Self::get_opts(ctx);
+        // `-x tdcc:encoding` without a value is parsed as a flag
+        let ParseVal::String(encoding) = &opts[&"encoding"] else {
+            return Err(Error::misc(format!(
+                "{}: option `encoding` requires a value: binary, one-hot, gray, johnson, or auto",
+                Self::name()
+            )));
+        };
 
         Ok(TopDownCompileControl {
             dump_fsm: opts[&"dump-fsm"].bool(),
             dump_fsm_json: opts[&"dump-fsm-json"].not_null_outstream(),
             early_transitions: opts[&"early-transitions"].bool(),
-            fsm_groups: HashSet::new(),
+            fsm_groups: Vec::new(),
             one_hot_cutoff: opts[&"one-hot-cutoff"]
                 .pos_num()
                 .expect("requires non-negative OHE cutoff parameter"),
+            // the value was checked by `parse_encoding`
+            encoding: Encoding::from_opt(encoding).unwrap(),
         })
     }
 
@@ -1041,7 +1103,7 @@ impl Named for TopDownCompileControl {
             ),
             PassOpt::new(
                 "dump-fsm-json",
-                "Write the state machine implementing the schedule to a JSON file",
+                "Write the profiling information of all FSMs in the program to a JSON file",
                 ParseVal::OutStream(OutputFile::Null),
                 PassOpt::parse_outstream,
             ),
@@ -1057,31 +1119,18 @@ impl Named for TopDownCompileControl {
                 ParseVal::Num(0),
                 PassOpt::parse_num,
             ),
+            PassOpt::new(
+                "encoding",
+                "The encoding of FSMs above the one-hot cutoff: binary, one-hot, gray, johnson, or auto \
+                (choose an encoding for each FSM from its number of states and transitions). \
+                FSMs that would need more than 64 bits are binary",
+                ParseVal::String("binary".to_string()),
+                parse_encoding,
+            ),
         ]
     }
 }
 
-/// Helper function to emit profiling information when the control consists of a single group.
-fn emit_single_enable(
-    con: &mut ir::Control,
-    component: Id,
-    json_out_file: &OutputFile,
-) {
-    if let ir::Control::Enable(enable) = con {
-        let mut profiling_info_set: HashSet<ProfilingInfo> = HashSet::new();
-        profiling_info_set.insert(ProfilingInfo::SingleEnable(
-            SingleEnableInfo {
-                component,
-                group: enable.group.borrow().name(),
-            },
-        ));
-        let _ = serde_json::to_writer_pretty(
-            json_out_file.get_write(),
-            &profiling_info_set,
-        );
-    }
-}
-
 impl Visitor for TopDownCompileControl {
     fn start(
         &mut self,
@@ -1091,8 +1140,13 @@ impl Visitor for TopDownCompileControl {
     ) -> VisResult {
         let mut con = comp.control.borrow_mut();
         if matches!(*con, ir::Control::Empty(..) | ir::Control::Enable(..)) {
-            if let Some(json_out_file) = &self.dump_fsm_json {
-                emit_single_enable(&mut con, comp.name, json_out_file);
+            if let ir::Control::Enable(enable) = &*con {
+                self.fsm_groups.push(ProfilingInfo::SingleEnable(
+                    SingleEnableInfo {
+                        component: comp.name,
+                        group: enable.group.borrow().name(),
+                    },
+                ));
             }
             return Ok(Action::Stop);
         }
@@ -1121,6 +1175,7 @@ impl Visitor for TopDownCompileControl {
             self.dump_fsm,
             &mut self.fsm_groups,
             self.one_hot_cutoff,
+            self.encoding,
         );
 
         // Add NODE_ID to compiled group.
@@ -1151,6 +1206,7 @@ impl Visitor for TopDownCompileControl {
             self.dump_fsm,
             &mut self.fsm_groups,
             self.one_hot_cutoff,
+            self.encoding,
         );
 
         // Add NODE_ID to compiled group.
@@ -1181,6 +1237,7 @@ impl Visitor for TopDownCompileControl {
             self.dump_fsm,
             &mut self.fsm_groups,
             self.one_hot_cutoff,
+            self.encoding,
         );
 
         // Add NODE_ID to compiled group.
@@ -1211,13 +1268,15 @@ impl Visitor for TopDownCompileControl {
 
         // Registers to save the done signal from each child.
         let mut done_regs = Vec::with_capacity(s.stmts.len());
+        // Groups that run the children, for profiling.
+        let mut children = Vec::with_capacity(s.stmts.len());
 
         // For each child, build the enabling logic.
         for con in &s.stmts {
             let group = match con {
                 // Do not compile enables
                 ir::Control::Enable(ir::Enable { group, .. }) => {
-                    self.fsm_groups.insert(ProfilingInfo::SingleEnable(
+                    self.fsm_groups.push(ProfilingInfo::SingleEnable(
                         SingleEnableInfo {
                             group: group.borrow().name(),
                             component: builder.component.name,
@@ -1233,6 +1292,7 @@ impl Visitor for TopDownCompileControl {
                         self.dump_fsm,
                         &mut self.fsm_groups,
                         self.one_hot_cutoff,
+                        self.encoding,
                     )
                 }
             };
@@ -1251,9 +1311,16 @@ impl Visitor for TopDownCompileControl {
                 pd["write_en"] = group_done ? signal_on["out"];
             );
             par_group.borrow_mut().assignments.extend(assigns);
-            done_regs.push(pd)
+            done_regs.push(pd);
+            children.push(group.borrow().name());
         }
 
+        self.fsm_groups.push(ProfilingInfo::Par(ParInfo {
+            component: builder.component.name,
+            group: par_group.borrow().name(),
+            children,
+        }));
+
         // Done condition for this group
         let done_guard = done_regs
             .clone()
@@ -1308,13 +1375,21 @@ impl Visitor for TopDownCompileControl {
             self.dump_fsm,
             &mut self.fsm_groups,
             self.one_hot_cutoff,
+            self.encoding,
         );
+        Ok(Action::change(ir::Control::enable(comp_group)))
+    }
+
+    fn finish_context(&mut self, ctx: &mut ir::Context) -> VisResult {
+        ctx.profiling_info.append(&mut self.fsm_groups);
+        // This pass runs after all other passes that generate control logic,
+        // so the profiling information is complete.
         if let Some(json_out_file) = &self.dump_fsm_json {
             let _ = serde_json::to_writer_pretty(
                 json_out_file.get_write(),
-                &self.fsm_groups,
+                &ctx.profiling_info,
             );
         }
-        Ok(Action::change(ir::Control::enable(comp_group)))
+        Ok(Action::Continue)
     }
 }
@@ -3,7 +3,7 @@ use calyx_ir::{
     self as ir, CellType, Component, GetAttributes, LibrarySignatures,
     RESERVED_NAMES,
 };
-use calyx_utils::{CalyxResult, Error, WithPos};
+use calyx_utils::{CalyxResult, DiagnosticContext, Error, WithPos};
 use ir::Nothing;
 use ir::StaticTiming;
 use itertools::Itertools;
@@ -73,6 +73,8 @@ impl ActiveAssignments {
 /// 5. Invoking components with unmentioned ref cells.
 /// 6. Invoking components with wrong ref cell name.
 /// 7. Invoking components with impatible fed-in cell type for ref cells.
+///
+/// The pass reports every problem it finds, instead of stopping at the first one.
 pub struct WellFormed {
     /// Reserved names
     reserved_names: HashSet<ir::Id>,
@@ -84,6 +86,8 @@ pub struct WellFormed {
     ref_cell_types: HashMap<ir::Id, LinkedHashMap<ir::Id, CellType>>,
     /// Stack of currently active combinational groups
     active_comb: ActiveAssignments,
+    /// Errors and warnings found so far
+    diag: DiagnosticContext,
 }
 
 impl ConstructVisitor for WellFormed {
@@ -94,26 +98,23 @@ impl ConstructVisitor for WellFormed {
         let reserved_names =
             RESERVED_NAMES.iter().map(|s| ir::Id::from(*s)).collect();
 
+        let mut diag = DiagnosticContext::default();
         let mut ref_cell_types = HashMap::new();
         for comp in ctx.components.iter() {
             // Non-main components cannot use @external attribute
-            let cellmap: LinkedHashMap<ir::Id, CellType> = comp
-                .cells
-                .iter()
-                .filter_map(|cr| {
-                    let cell = cr.borrow();
-                    // Make sure @external cells are not defined in non-entrypoint components
-                    if cell.attributes.has(ir::BoolAttr::External)
-                        && comp.name != ctx.entrypoint
-                    {
-                        Some(Err(Error::malformed_structure("Cell cannot be marked `@external` in non-entrypoint component").with_pos(&cell.attributes)))
-                    } else if cell.is_reference() {
-                        Some(Ok((cell.name(), cell.prototype.clone())))
-                    } else {
-                        None
-                    }
-                })
-                .collect::<CalyxResult<_>>()?;
+            let mut cellmap: LinkedHashMap<ir::Id, CellType> =
+                LinkedHashMap::new();
+            for cr in comp.cells.iter() {
+                let cell = cr.borrow();
+                // Make sure @external cells are not defined in non-entrypoint components
+                if cell.attributes.has(ir::BoolAttr::External)
+                    && comp.name != ctx.entrypoint
+                {
+                    diag.err(Error::malformed_structure("Cell cannot be marked `@external` in non-entrypoint component").with_pos(&cell.attributes));
+                } else if cell.is_reference() {
+                    cellmap.insert(cell.name(), cell.prototype.clone());
+                }
+            }
             ref_cell_types.insert(comp.name, cellmap);
         }
 
@@ -123,6 +124,7 @@ impl ConstructVisitor for WellFormed {
             used_comb_groups: HashSet::new(),
             ref_cell_types,
             active_comb: ActiveAssignments::default(),
+            diag,
         };
 
         Ok(w_f)
@@ -214,16 +216,20 @@ impl Visitor for WellFormed {
             let cell = cell_ref.borrow();
             // Check if any of the cells use a reserved name.
             if self.reserved_names.contains(&cell.name()) {
-                return Err(Error::reserved_name(cell.name())
-                    .with_pos(cell.get_attributes()));
+                self.diag.err(
+                    Error::reserved_name(cell.name())
+                        .with_pos(cell.get_attributes()),
+                );
             }
             // Check if a `ref` cell is invalid
             if cell.is_reference() {
                 if cell.is_primitive(Some("std_const")) {
-                    return Err(Error::malformed_structure(
-                        "constant not allowed for ref cells".to_string(),
-                    )
-                    .with_pos(cell.get_attributes()));
+                    self.diag.err(
+                        Error::malformed_structure(
+                            "constant not allowed for ref cells".to_string(),
+                        )
+                        .with_pos(cell.get_attributes()),
+                    );
                 }
                 if matches!(cell.prototype, CellType::ThisComponent) {
                     unreachable!(
@@ -237,23 +243,23 @@ impl Visitor for WellFormed {
         // there are no group or comb group definitions, and the control program is empty
         if comp.is_comb {
             if !matches!(&*comp.control.borrow(), ir::Control::Empty(..)) {
-                return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but has a non-empty control program", comp.name)));
+                self.diag.err(Error::malformed_structure(format!("Component `{}` is marked combinational but has a non-empty control program", comp.name)));
             }
 
             if !comp.get_groups().is_empty() {
                 let group = comp.get_groups().iter().next().unwrap().borrow();
-                return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains a group `{}`", comp.name, group.name())).with_pos(&group.attributes));
+                self.diag.err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains a group `{}`", comp.name, group.name())).with_pos(&group.attributes));
             }
 
             if !comp.get_static_groups().is_empty() {
                 let group =
                     comp.get_static_groups().iter().next().unwrap().borrow();
-                return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains a group `{}`", comp.name, group.name())).with_pos(&group.attributes));
+                self.diag.err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains a group `{}`", comp.name, group.name())).with_pos(&group.attributes));
             }
 
             if !comp.comb_groups.is_empty() {
                 let group = comp.comb_groups.iter().next().unwrap().borrow();
-                return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains a group `{}`", comp.name, group.name())).with_pos(&group.attributes));
+                self.diag.err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains a group `{}`", comp.name, group.name())).with_pos(&group.attributes));
             }
 
             for cell_ref in comp.cells.iter() {
@@ -272,7 +278,7 @@ impl Visitor for WellFormed {
                     _ => false,
                 };
                 if !is_comb {
-                    return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains non-combinational cell `{}`", comp.name, cell.name())).with_pos(&cell.attributes));
+                    self.diag.err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains non-combinational cell `{}`", comp.name, cell.name())).with_pos(&cell.attributes));
                 }
             }
         }
@@ -296,66 +302,31 @@ impl Visitor for WellFormed {
         if go_ports.iter().any(|go_port| {
             go_port.borrow().attributes.has(ir::NumAttr::Interval)
         }) {
-            match &*comp.control.borrow() {
-                ir::Control::Static(_) | ir::Control::Empty(_) => (),
-                _ => return Err(Error::malformed_structure(
-                    format!("component {} has dynamic control but has @interval annotations", comp.name),
-                    )
-                    .with_pos(&comp.attributes)),
+            let static_control = match &*comp.control.borrow() {
+                ir::Control::Static(_) | ir::Control::Empty(_) => true,
+                _ => {
+                    self.diag.err(Error::malformed_structure(
+                        format!("component {} has dynamic control but has @interval annotations", comp.name),
+                        )
+                        .with_pos(&comp.attributes));
+                    false
+                }
             };
-            if !comp.control.borrow().is_empty() {
+            if static_control && !comp.control.borrow().is_empty() {
                 // Getting "reference value" should be the same for all go ports and
                 // the control.
-                let reference_val = match go_ports[0]
-                    .borrow()
-                    .attributes
-                    .get(ir::NumAttr::Interval)
+                match go_ports[0].borrow().attributes.get(ir::NumAttr::Interval)
                 {
-                    Some(val) => val,
-                    None => {
-                        return Err(Error::malformed_structure(
+                    Some(reference_val) => {
+                        self.check_intervals(comp, &go_ports, reference_val)
+                    }
+                    None => self.diag.err(
+                        Error::malformed_structure(
                         "@interval(n) attribute on all @go ports since there is static<n> control",
                         )
-                        .with_pos(&comp.attributes))
-                    }
+                        .with_pos(&comp.attributes),
+                    ),
                 };
-                // Checking go ports.
-                for go_port in &go_ports {
-                    let go_port_val = match go_port
-                        .borrow()
-                        .attributes
-                        .get(ir::NumAttr::Interval)
-                    {
-                        Some(val) => val,
-                        None => {
-                            return Err(Error::malformed_structure(format!(
-                                "@go port expected @interval({reference_val}) attribute on all ports \
-                                since the component has static<n> control",
-                            ))
-                            .with_pos(&comp.attributes))
-                        }
-                    };
-                    if go_port_val != reference_val {
-                        return Err(Error::malformed_structure(format!(
-                            "@go port expected @interval {reference_val}, got @interval {go_port_val}",
-                        ))
-                        .with_pos(&go_port.borrow().attributes));
-                    }
-                    // Checking control latency
-                    match comp.control.borrow().get_latency() {
-                        None => {
-                            unreachable!("already checked control is static")
-                        }
-                        Some(control_latency) => {
-                            if control_latency != reference_val {
-                                return Err(Error::malformed_structure(format!(
-                                    "component {} expected @interval {reference_val}, got @interval {control_latency}", comp.name,
-                                ))
-                                .with_pos(&comp.attributes));
-                            }
-                        }
-                    }
-                }
             }
         }
 
@@ -369,39 +340,45 @@ impl Visitor for WellFormed {
             for assign in &group.assignments {
                 let dst = assign.dst.borrow();
                 if port_is_static_prim(&dst) {
-                    return Err(Error::malformed_structure(format!(
-                        "Static cell `{}` written to in non-static group",
-                        dst.get_parent_name()
-                    ))
-                    .with_pos(&assign.attributes));
+                    self.diag.err(
+                        Error::malformed_structure(format!(
+                            "Static cell `{}` written to in non-static group",
+                            dst.get_parent_name()
+                        ))
+                        .with_pos(&assign.attributes),
+                    );
                 }
                 if dst.is_hole() && dst.name == "done" {
-                    // Group has multiple done conditions
-                    if has_done {
-                        return Err(Error::malformed_structure(format!(
-                            "Group `{}` has multiple done conditions",
-                            gname
-                        ))
-                        .with_pos(&assign.attributes));
-                    } else {
-                        has_done = true;
-                    }
                     // Group uses another group's done condition
                     if gname != dst.get_parent_name() {
-                        return Err(Error::malformed_structure(
+                        self.diag.err(Error::malformed_structure(
                             format!("Group `{}` refers to the done condition of another group (`{}`).",
                             gname,
                             dst.get_parent_name())).with_pos(&dst.attributes));
                     }
+                    // Group has multiple done conditions
+                    else if has_done {
+                        self.diag.err(
+                            Error::malformed_structure(format!(
+                                "Group `{}` has multiple done conditions",
+                                gname
+                            ))
+                            .with_pos(&assign.attributes),
+                        );
+                    } else {
+                        has_done = true;
+                    }
                 }
             }
 
             // Group does not have a done condition
             if !has_done {
-                return Err(Error::malformed_structure(format!(
-                    "No writes to the `done' hole for group `{gname}'",
-                ))
-                .with_pos(&group.attributes));
+                self.diag.err(
+                    Error::malformed_structure(format!(
+                        "No writes to the `done' hole for group `{gname}'",
+                    ))
+                    .with_pos(&group.attributes),
+                );
             }
         }
 
@@ -413,7 +390,7 @@ impl Visitor for WellFormed {
             let group_latency = group.get_latency();
             // Check that for each interval %[beg, end], end > beg.
             for assign in &group.assignments {
-                assign.guard.check_for_each_info(
+                let res = assign.guard.check_for_each_info(
                     &mut |static_timing: &StaticTiming| {
                         if static_timing.get_interval().0
                             >= static_timing.get_interval().1
@@ -434,34 +411,37 @@ impl Visitor for WellFormed {
                             Ok(())
                         }
                     },
-                )?;
+                );
+                self.diag.accumulate(res);
             }
         }
 
         // Check for obvious conflicting assignments in the continuous assignments
-        obvious_conflicts(
+        self.diag.accumulate(obvious_conflicts(
             comp.continuous_assignments.iter(),
             std::iter::empty::<&ir::Assignment<StaticTiming>>(),
-        )?;
+        ));
         // Check for obvious conflicting assignments between the continuous assignments and the groups
         for cgr in comp.comb_groups.iter() {
             for assign in &cgr.borrow().assignments {
                 let dst = assign.dst.borrow();
                 if port_is_static_prim(&dst) {
-                    return Err(Error::malformed_structure(format!(
-                        "Static cell `{}` written to in non-static group",
-                        dst.get_parent_name()
-                    ))
-                    .with_pos(&assign.attributes));
+                    self.diag.err(
+                        Error::malformed_structure(format!(
+                            "Static cell `{}` written to in non-static group",
+                            dst.get_parent_name()
+                        ))
+                        .with_pos(&assign.attributes),
+                    );
                 }
             }
-            obvious_conflicts(
+            self.diag.accumulate(obvious_conflicts(
                 cgr.borrow()
                     .assignments
                     .iter()
                     .chain(comp.continuous_assignments.iter()),
                 std::iter::empty::<&ir::Assignment<StaticTiming>>(),
-            )?;
+            ));
         }
 
         Ok(Action::Continue)
@@ -479,7 +459,7 @@ impl Visitor for WellFormed {
         let group = s.group.borrow();
 
         // check for obvious conflicts within static groups and continuous/comb group assigns
-        obvious_conflicts(
+        let res = obvious_conflicts(
             comp.continuous_assignments
                 .iter()
                 .chain(self.active_comb.iter()),
@@ -492,7 +472,8 @@ impl Visitor for WellFormed {
                 .into_option()
                 .map(|s| s.format("Assigments activated by group enable"));
             err.with_post_msg(msg)
-        })?;
+        });
+        self.diag.accumulate(res);
 
         Ok(Action::Continue)
     }
@@ -507,12 +488,20 @@ impl Visitor for WellFormed {
         self.used_groups.insert(s.group.borrow().name());
 
         let group = s.group.borrow();
-        let asgn = group.done_cond();
-        let const_done_assign =
-            asgn.guard.is_true() && asgn.src.borrow().is_constant(1, 1);
+        // groups without a done condition have already been reported
+        let const_done_assign = group
+            .assignments
+            .iter()
+            .find(|asgn| {
+                let dst = asgn.dst.borrow();
+                dst.is_hole() && dst.name == "done"
+            })
+            .is_some_and(|asgn| {
+                asgn.guard.is_true() && asgn.src.borrow().is_constant(1, 1)
+            });
 
         if const_done_assign {
-            return Err(Error::malformed_structure("Group with constant done condition is invalid. Use `comb group` instead to define a combinational group.").with_pos(&group.attributes));
+            self.diag.err(Error::malformed_structure("Group with constant done condition is invalid. Use `comb group` instead to define a combinational group.").with_pos(&group.attributes));
         }
 
         // A group with "static"=0 annotation
@@ -522,11 +511,11 @@ impl Visitor for WellFormed {
             .map(|v| v == 0)
             .unwrap_or(false)
         {
-            return Err(Error::malformed_structure("Group with annotation \"promotable\"=0 is invalid. Use `comb group` instead to define a combinational group or if the group's done condition is not constant, provide the correct \"static\" annotation.").with_pos(&group.attributes));
+            self.diag.err(Error::malformed_structure("Group with annotation \"promotable\"=0 is invalid. Use `comb group` instead to define a combinational group or if the group's done condition is not constant, provide the correct \"static\" annotation.").with_pos(&group.attributes));
         }
 
         // Check if the group has obviously conflicting assignments with the continuous assignments and the active combinational groups
-        obvious_conflicts(
+        let res = obvious_conflicts(
             group
                 .assignments
                 .iter()
@@ -541,7 +530,8 @@ impl Visitor for WellFormed {
                 .into_option()
                 .map(|s| s.format("Assigments activated by group enable"));
             err.with_post_msg(msg)
-        })?;
+        });
+        self.diag.accumulate(res);
 
         Ok(Action::Continue)
     }
@@ -565,23 +555,30 @@ impl Visitor for WellFormed {
             for (outcell, incell) in s.ref_cells.iter() {
                 if let Some(t) = cellmap.get(outcell) {
                     let proto = incell.borrow().prototype.clone();
-                    same_type(t, &proto)
-                        .map_err(|err| err.with_pos(&s.attributes))?;
+                    self.diag.accumulate(
+                        same_type(t, &proto)
+                            .map_err(|err| err.with_pos(&s.attributes)),
+                    );
                     mentioned_cells.insert(outcell);
                 } else {
-                    return Err(Error::malformed_control(format!(
-                        "{} does not have ref cell named {}",
-                        id, outcell
-                    )));
+                    self.diag.err(
+                        Error::malformed_control(format!(
+                            "{} does not have ref cell named {}",
+                            id, outcell
+                        ))
+                        .with_pos(&s.attributes),
+                    );
                 }
             }
             for id in cellmap.keys() {
                 if mentioned_cells.get(id).is_none() {
-                    return Err(Error::malformed_control(format!(
-                        "unmentioned ref cell: {}",
-                        id
-                    ))
-                    .with_pos(&s.attributes));
+                    self.diag.err(
+                        Error::malformed_control(format!(
+                            "unmentioned ref cell: {}",
+                            id
+                        ))
+                        .with_pos(&s.attributes),
+                    );
                 }
             }
         }
@@ -605,23 +602,30 @@ impl Visitor for WellFormed {
             for (outcell, incell) in s.ref_cells.iter() {
                 if let Some(t) = cellmap.get(outcell) {
                     let proto = incell.borrow().prototype.clone();
-                    same_type(t, &proto)
-                        .map_err(|err| err.with_pos(&s.attributes))?;
+                    self.diag.accumulate(
+                        same_type(t, &proto)
+                            .map_err(|err| err.with_pos(&s.attributes)),
+                    );
                     mentioned_cells.insert(outcell);
                 } else {
-                    return Err(Error::malformed_control(format!(
-                        "{} does not have ref cell named {}",
-                        id, outcell
-                    )));
+                    self.diag.err(
+                        Error::malformed_control(format!(
+                            "{} does not have ref cell named {}",
+                            id, outcell
+                        ))
+                        .with_pos(&s.attributes),
+                    );
                 }
             }
             for id in cellmap.keys() {
                 if mentioned_cells.get(id).is_none() {
-                    return Err(Error::malformed_control(format!(
-                        "unmentioned ref cell: {}",
-                        id
-                    ))
-                    .with_pos(&s.attributes));
+                    self.diag.err(
+                        Error::malformed_control(format!(
+                            "unmentioned ref cell: {}",
+                            id
+                        ))
+                        .with_pos(&s.attributes),
+                    );
                 }
             }
         }
@@ -640,7 +644,7 @@ impl Visitor for WellFormed {
             let cg = cgr.borrow();
             let assigns = &cg.assignments;
             // Check if the combinational group conflicts with the active combinational groups
-            obvious_conflicts(
+            let res = obvious_conflicts(
                 assigns.iter().chain(self.active_comb.iter()),
                 std::iter::empty::<&ir::Assignment<StaticTiming>>(),
             )
@@ -650,15 +654,23 @@ impl Visitor for WellFormed {
                     cg.name()
                 ));
                 err.with_post_msg(Some(msg))
-            })?;
-            // Push the combinational group to the stack of active groups
-            self.active_comb.push(assigns);
+            });
+            // Push the combinational group to the stack of active groups.
+            // A conflicting group is not made active so that the conflict is not reported again
+            // by the children.
+            if self.diag.accumulate(res).is_some() {
+                self.active_comb.push(assigns);
+            } else {
+                self.active_comb.push(&[]);
+            }
         } else if !s.port.borrow().has_attribute(ir::BoolAttr::Stable) {
-            let msg = s.attributes.copy_span().format(format!(
+            self.diag.warning(
+                Error::misc(format!(
                     "If statement has no comb group and its condition port {} is unstable",
                     s.port.borrow().canonical()
-                ));
-            log::warn!("{msg}");
+                ))
+                .with_pos(&s.attributes),
+            );
         }
         Ok(Action::Continue)
     }
@@ -671,11 +683,13 @@ impl Visitor for WellFormed {
         _comps: &[ir::Component],
     ) -> VisResult {
         if !s.port.borrow().has_attribute(ir::BoolAttr::Stable) {
-            let msg = s.attributes.copy_span().format(format!(
-                "static if statement's condition port {} is unstable",
-                s.port.borrow().canonical()
-            ));
-            log::warn!("{msg}");
+            self.diag.warning(
+                Error::misc(format!(
+                    "static if statement's condition port {} is unstable",
+                    s.port.borrow().canonical()
+                ))
+                .with_pos(&s.attributes),
+            );
         }
         Ok(Action::Continue)
     }
@@ -707,7 +721,7 @@ impl Visitor for WellFormed {
             let cg = cgr.borrow();
             let assigns = &cg.assignments;
             // Check if the combinational group conflicts with the active combinational groups
-            obvious_conflicts(
+            let res = obvious_conflicts(
                 assigns.iter().chain(self.active_comb.iter()),
                 std::iter::empty::<&ir::Assignment<StaticTiming>>(),
             )
@@ -717,15 +731,23 @@ impl Visitor for WellFormed {
                     cg.name()
                 ));
                 err.with_post_msg(Some(msg))
-            })?;
-            // Push the combinational group to the stack of active groups
-            self.active_comb.push(assigns);
+            });
+            // Push the combinational group to the stack of active groups.
+            // A conflicting group is not made active so that the conflict is not reported again
+            // by the children.
+            if self.diag.accumulate(res).is_some() {
+                self.active_comb.push(assigns);
+            } else {
+                self.active_comb.push(&[]);
+            }
         } else if !s.port.borrow().has_attribute(ir::BoolAttr::Stable) {
-            let msg = s.attributes.copy_span().format(format!(
+            self.diag.warning(
+                Error::misc(format!(
                     "While loop has no comb group and its condition port {} is unstable",
                     s.port.borrow().canonical()
-                ));
-            log::warn!("{msg}");
+                ))
+                .with_pos(&s.attributes),
+            );
         }
         Ok(Action::Continue)
     }
@@ -772,6 +794,11 @@ impl Visitor for WellFormed {
             })
         });
 
+        // Groups in combinational components are already reported by `start`
+        if comp.is_comb {
+            return Ok(Action::Continue);
+        }
+
         // Find unused groups
         let mut all_groups: HashSet<ir::Id> = comp
             .get_groups()
@@ -785,34 +812,92 @@ impl Visitor for WellFormed {
             .collect();
         all_groups.extend(static_groups);
 
-        if let Some(group) = all_groups.difference(&self.used_groups).next() {
-            match comp.find_group(*group) {
+        for group in all_groups.difference(&self.used_groups) {
+            let err = match comp.find_group(*group) {
                 Some(gr) => {
                     let gr = gr.borrow();
-                    return Err(
-                        Error::unused(*group, "group").with_pos(&gr.attributes)
-                    );
+                    Error::unused(*group, "group").with_pos(&gr.attributes)
                 }
                 None => {
                     let gr = comp.find_static_group(*group).unwrap();
                     let gr = gr.borrow();
-                    return Err(
-                        Error::unused(*group, "group").with_pos(&gr.attributes)
-                    );
+                    Error::unused(*group, "group").with_pos(&gr.attributes)
                 }
-            }
-        };
+            };
+            self.diag.err(err);
+        }
 
         let all_comb_groups: HashSet<ir::Id> =
             comp.comb_groups.iter().map(|g| g.borrow().name()).collect();
-        if let Some(comb_group) =
-            all_comb_groups.difference(&self.used_comb_groups).next()
-        {
+        for comb_group in all_comb_groups.difference(&self.used_comb_groups) {
             let cgr = comp.find_comb_group(*comb_group).unwrap();
             let cgr = cgr.borrow();
-            return Err(Error::unused(*comb_group, "combinational group")
-                .with_pos(&cgr.attributes));
+            self.diag.err(
+                Error::unused(*comb_group, "combinational group")
+                    .with_pos(&cgr.attributes),
+            );
         }
         Ok(Action::Continue)
     }
+
+    fn finish_context(&mut self, ctx: &mut ir::Context) -> VisResult {
+        // Report all the problems found in the program at once
+        // Warnings are printed by the driver, which knows the diagnostics format
+        let warnings = std::mem::take(&mut self.diag).into_result()?;
+        ctx.warnings.extend(warnings);
+        Ok(Action::Continue)
+    }
+}
+
+impl WellFormed {
+    /// Check that the @interval annotations on the go ports and the latency of the static
+    /// control program all agree with `reference_val`.
+    fn check_intervals(
+        &mut self,
+        comp: &Component,
+        go_ports: &[ir::RRC<ir::Port>],
+        reference_val: u64,
+    ) {
+        for go_port in go_ports {
+            let go_port_val = match go_port
+                .borrow()
+                .attributes
+                .get(ir::NumAttr::Interval)
+            {
+                Some(val) => val,
+                None => {
+                    self.diag.err(
+                            Error::malformed_structure(format!(
+                            "@go port expected @interval({reference_val}) attribute on all ports \
+                            since the component has static<n> control",
+                        ))
+                            .with_pos(&comp.attributes),
+                        );
+                    continue;
+                }
+            };
+            if go_port_val != reference_val {
+                self.diag.err(
+                    Error::malformed_structure(format!(
+                        "@go port expected @interval {reference_val}, got @interval {go_port_val}",
+                    ))
+                    .with_pos(&go_port.borrow().attributes),
+                );
+            }
+        }
+        // Checking control latency
+        match comp.control.borrow().get_latency() {
+            None => {
+                unreachable!("already checked control is static")
+            }
+            Some(control_latency) => {
+                if control_latency != reference_val {
+                    self.diag.err(Error::malformed_structure(format!(
+                        "component {} expected @interval {reference_val}, got @interval {control_latency}", comp.name,
+                    ))
+                    .with_pos(&comp.attributes));
+                }
+            }
+        }
+    }
 }
@@ -16,6 +16,8 @@ pub enum ParseVal {
     List(Vec<ParseVal>),
     /// An output stream (stdout, stderr, file name)
     OutStream(OutputFile),
+    /// A string option.
+    String(String),
 }
 
 impl ParseVal {
@@ -67,6 +69,13 @@ impl ParseVal {
             .unwrap()
     }
 
+    pub fn string(&self) -> &str {
+        let ParseVal::String(s) = self else {
+            panic!("Expected string, got {self}");
+        };
+        s
+    }
+
     /// Returns an output stream if it is not the null stream
     pub fn not_null_outstream(&self) -> Option<OutputFile> {
         match self {
@@ -97,6 +106,7 @@ impl std::fmt::Display for ParseVal {
                 write!(f, "]")
             }
             ParseVal::OutStream(o) => write!(f, "{}", o.to_string()),
+            ParseVal::String(s) => write!(f, "{s}"),
         }
     }
 }
@@ -0,0 +1,114 @@
+//! Accumulate errors and warnings so that several problems can be reported at once.
+use crate::{CalyxResult, Error, GPosIdx};
+
+/// How serious a diagnostic is.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A collection of errors and warnings found while checking a program.
+///
+/// Compiler stages that can keep going after finding a problem push their errors and warnings
+/// here instead of returning at the first error. [DiagnosticContext::into_result] turns the
+/// collected errors into a single [Error] that carries all of them.
+#[derive(Default)]
+pub struct DiagnosticContext {
+    errors: Vec<Error>,
+    warnings: Vec<Error>,
+}
+
+impl DiagnosticContext {
+    /// Report an error. Errors that carry several diagnostics are flattened into this context.
+    pub fn err(&mut self, error: Error) {
+        self.extend(error.into_diagnostics());
+    }
+
+    /// Record a single error without flattening it.
+    pub(crate) fn push_error(&mut self, error: Error) {
+        self.errors.push(error);
+    }
+
+    /// Report a warning.
+    pub fn warning(&mut self, warning: Error) {
+        self.warnings.push(warning);
+    }
+
+    /// Report the error in `result`, if any, and return the successful value otherwise.
+    pub fn accumulate<T>(&mut self, result: CalyxResult<T>) -> Option<T> {
+        match result {
+            Ok(v) => Some(v),
+            Err(e) => {
+                self.err(e);
+                None
+            }
+        }
+    }
+
+    /// Add all the diagnostics from `other` to this context.
+    pub fn extend(&mut self, other: DiagnosticContext) {
+        self.errors.extend(other.errors);
+        self.warnings.extend(other.warnings);
+    }
+
+    /// Check whether any errors have been reported.
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    /// The number of errors reported so far.
+    pub fn num_errors(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn errors_iter(&self) -> impl Iterator<Item = &Error> {
+        self.errors.iter()
+    }
+
+    pub fn warnings_iter(&self) -> impl Iterator<Item = &Error> {
+        self.warnings.iter()
+    }
+
+    /// All the diagnostics, sorted by their position in the source files. Diagnostics without
+    /// a position come last, and diagnostics at the same position stay in the order they were
+    /// reported.
+    pub fn sorted(&self) -> Vec<(Severity, &Error)> {
+        let mut all: Vec<(Severity, &Error)> = self
+            .errors
+            .iter()
+            .map(|e| (Severity::Error, e))
+            .chain(self.warnings.iter().map(|w| (Severity::Warning, w)))
+            .collect();
+        all.sort_by_cached_key(|(_, e)| {
+            let (file, start, _) = e.location();
+            (e.pos() == GPosIdx::UNKNOWN, file.to_string(), start)
+        });
+        all
+    }
+
+    /// Turn the collected diagnostics into a result. If there are any errors, the result is an
+    /// [Error] carrying all the diagnostics; otherwise, it holds the warnings, sorted by
+    /// position, for the caller to report.
+    pub fn into_result(self) -> CalyxResult<Vec<Error>> {
+        if self.has_errors() {
+            Err(Error::from_diagnostics(self))
+        } else {
+            let mut warnings = self.warnings;
+            warnings.sort_by_cached_key(|w| {
+                let (file, start, _) = w.location();
+                (w.pos() == GPosIdx::UNKNOWN, file.to_string(), start)
+            });
+            Ok(warnings)
+        }
+    }
+}
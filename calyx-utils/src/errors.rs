@@ -1,5 +1,5 @@
 //! Errors generated by the compiler.
-use crate::{GPosIdx, Id, WithPos};
+use crate::{DiagnosticContext, GPosIdx, Id, Severity, WithPos};
 
 /// Convience wrapper to represent success or meaningul compiler error.
 pub type CalyxResult<T> = std::result::Result<T, Error>;
@@ -116,12 +116,74 @@ impl Error {
             post_msg: None,
         }
     }
+    /// An error that carries several diagnostics at once.
+    pub fn from_diagnostics(diagnostics: DiagnosticContext) -> Self {
+        Self {
+            kind: Box::new(ErrorKind::Diagnostics(diagnostics)),
+            pos: GPosIdx::UNKNOWN,
+            post_msg: None,
+        }
+    }
+    /// All the diagnostics carried by this error. An ordinary error carries just itself.
+    pub fn into_diagnostics(self) -> DiagnosticContext {
+        match *self.kind {
+            ErrorKind::Diagnostics(diagnostics) => diagnostics,
+            kind => {
+                let mut diagnostics = DiagnosticContext::default();
+                diagnostics.push_error(Self {
+                    kind: Box::new(kind),
+                    ..self
+                });
+                diagnostics
+            }
+        }
+    }
+    pub fn pos(&self) -> GPosIdx {
+        self.pos
+    }
     pub fn location(&self) -> (&str, usize, usize) {
         self.pos.get_location()
     }
     pub fn message(&self) -> String {
         self.kind.to_string()
     }
+    /// The name of the kind of this error, like `unused` or `papercut`.
+    pub fn kind(&self) -> &'static str {
+        self.kind.name()
+    }
+    /// Machine-readable representation of this error, reported with the given severity.
+    /// Positions are `null` when the error is not associated with a source location.
+    pub fn to_json(&self, severity: Severity) -> serde_json::Value {
+        let (file, span, start, end) = match self.pos.into_option() {
+            Some(pos) => {
+                let (file, start, end) = pos.get_location();
+                let ((start_line, start_col), (end_line, end_col)) =
+                    pos.get_line_col();
+                (
+                    serde_json::json!(file),
+                    serde_json::json!({ "start": start, "end": end }),
+                    serde_json::json!({ "line": start_line, "column": start_col }),
+                    serde_json::json!({ "line": end_line, "column": end_col }),
+                )
+            }
+            None => (
+                serde_json::Value::Null,
+                serde_json::Value::Null,
+                serde_json::Value::Null,
+                serde_json::Value::Null,
+            ),
+        };
+        serde_json::json!({
+            "severity": severity.to_string(),
+            "kind": self.kind(),
+            "message": self.message(),
+            "file": file,
+            "span": span,
+            "start": start,
+            "end": end,
+            "post_msg": self.post_msg,
+        })
+    }
 }
 
 /// Standard error type for Calyx errors.
@@ -155,6 +217,30 @@ enum ErrorKind {
     InvalidFile(String),
     /// Failed to write the output
     WriteError(String),
+
+    /// Several errors and warnings reported together.
+    Diagnostics(DiagnosticContext),
+}
+
+impl ErrorKind {
+    /// Name of the kind of error, used in machine-readable diagnostics.
+    fn name(&self) -> &'static str {
+        use ErrorKind::*;
+        match self {
+            ReservedName(..) => "reserved-name",
+            MalformedControl(..) => "malformed-control",
+            MalformedStructure(..) => "malformed-structure",
+            PassAssumption(..) => "pass-assumption",
+            Undefined(..) => "undefined",
+            AlreadyBound(..) => "already-bound",
+            Unused(..) => "unused",
+            Papercut(..) => "papercut",
+            Misc(..) => "misc",
+            InvalidFile(..) => "invalid-file",
+            WriteError(..) => "write-error",
+            Diagnostics(..) => "diagnostics",
+        }
+    }
 }
 
 impl std::fmt::Display for ErrorKind {
@@ -186,6 +272,19 @@ impl std::fmt::Display for ErrorKind {
             InvalidFile(msg) | WriteError(msg) | Misc(msg) => {
                 write!(f, "{msg}")
             }
+            Diagnostics(diagnostics) => {
+                let all = diagnostics.sorted();
+                for (idx, (severity, diag)) in all.iter().enumerate() {
+                    if idx > 0 {
+                        writeln!(f)?;
+                    }
+                    if *severity == Severity::Warning {
+                        write!(f, "Warning: ")?;
+                    }
+                    write!(f, "{diag:?}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
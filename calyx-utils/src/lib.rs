@@ -1,4 +1,5 @@
 //! Shared utilities for the Calyx compiler.
+mod diagnostics;
 mod errors;
 mod id;
 mod namegenerator;
@@ -9,12 +10,14 @@ mod weight_graph;
 mod math;
 pub(crate) mod measure_time;
 
+pub use diagnostics::{DiagnosticContext, Severity};
 pub use errors::{CalyxResult, Error};
 pub use id::{GSym, GetName, Id};
 pub use math::bits_needed_for;
 pub use namegenerator::NameGenerator;
 pub use out_file::OutputFile;
 pub use position::{
-    FileIdx, GPosIdx, GlobalPositionTable, PosIdx, PositionTable, WithPos,
+    Checkpoint, FileIdx, GPosIdx, GlobalPositionTable, PosIdx, PositionTable,
+    WithPos,
 };
 pub use weight_graph::{BoolIdx, Idx, WeightGraph};
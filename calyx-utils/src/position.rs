@@ -31,6 +31,14 @@ struct PosData {
     end: usize,
 }
 
+/// The size of a [PositionTable] at some point in time. Used to remove the
+/// files and positions that were added after it.
+#[derive(Clone, Copy)]
+pub struct Checkpoint {
+    files: usize,
+    indices: usize,
+}
+
 /// Source position information for a Calyx program.
 pub struct PositionTable {
     /// The source files of the program
@@ -94,6 +102,30 @@ impl PositionTable {
     fn get_pos(&self, pos: PosIdx) -> &PosData {
         &self.indices[pos.0 as usize]
     }
+
+    /// Return the index of the file with the given name, if it was added
+    pub fn find_file(&self, name: &str) -> Option<FileIdx> {
+        self.files
+            .iter()
+            .position(|f| f.name == name)
+            .map(|idx| FileIdx(idx as u32))
+    }
+
+    /// Return a checkpoint that the table can be rolled back to.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            files: self.files.len(),
+            indices: self.indices.len(),
+        }
+    }
+
+    /// Remove the files and positions added since `checkpoint`. Positions
+    /// and files created after the checkpoint must not be used afterwards.
+    /// Used by long-running tools that parse the same files repeatedly.
+    pub fn rollback(&mut self, checkpoint: Checkpoint) {
+        self.files.truncate(checkpoint.files);
+        self.indices.truncate(checkpoint.indices);
+    }
 }
 
 /// The global position table
@@ -222,6 +254,21 @@ impl GPosIdx {
         (name, pos_d.start, pos_d.end)
     }
 
+    /// The 1-indexed line and column of the start and the end of this span.
+    /// Columns are counted in bytes.
+    pub fn get_line_col(&self) -> ((usize, usize), (usize, usize)) {
+        let table = GlobalPositionTable::as_ref();
+        let pos_d = table.get_pos(self.0);
+        let source = &table.get_file_data(pos_d.file).source;
+        let line_col = |offset: usize| {
+            let before = &source[..cmp::min(offset, source.len())];
+            let line = before.matches('\n').count() + 1;
+            let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+            (line, before.len() - line_start + 1)
+        };
+        (line_col(pos_d.start), line_col(pos_d.end))
+    }
+
     /// Visualizes the span without any message or mkaring
     pub fn show(&self) -> String {
         let (lines, _, linum) = self.get_lines();
@@ -231,8 +278,61 @@ impl GPosIdx {
     }
 }
 
+/// Positions are serialized with the name of their file and their byte
+/// offsets in it.
+#[cfg(feature = "serialize")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerPos<F> {
+    file: F,
+    start: usize,
+    end: usize,
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for GPosIdx {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let (file, start, end) = self.get_location();
+        serde::Serialize::serialize(&SerPos { file, start, end }, ser)
+    }
+}
+
+/// Deserialized positions are added to the global position table. The file
+/// they refer to is read again so that errors can show the source; if it
+/// cannot be read or no longer contains the span, the position is unknown.
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for GPosIdx {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let SerPos::<String> { file, start, end } =
+            serde::Deserialize::deserialize(de)?;
+        let table = GlobalPositionTable::as_mut();
+        let file_idx = match table.find_file(&file) {
+            Some(idx) => idx,
+            None => match std::fs::read_to_string(&file) {
+                Ok(source) => table.add_file(file, source),
+                Err(_) => return Ok(GPosIdx::UNKNOWN),
+            },
+        };
+        if start > end || end > table.get_source(file_idx).len() {
+            return Ok(GPosIdx::UNKNOWN);
+        }
+        Ok(GPosIdx(table.add_pos(file_idx, start, end)))
+    }
+}
+
 /// An IR node that may contain position information.
 pub trait WithPos {
     /// Copy the span associated with this node.
     fn copy_span(&self) -> GPosIdx;
 }
+
+impl WithPos for GPosIdx {
+    fn copy_span(&self) -> GPosIdx {
+        *self
+    }
+}
@@ -1,18 +1,28 @@
-use crate::error::AdapterResult;
-use dap::types::{Breakpoint, Source, SourceBreakpoint, StackFrame, Thread};
+use crate::error::{AdapterResult, MyAdapterError};
+use dap::events::ThreadEventBody;
+use dap::types::{
+    Breakpoint, Scope, Source, SourceBreakpoint, StackFrame, Thread,
+    ThreadEventReason, Variable,
+};
 use interp::debugger::source::structures::NewSourceMap;
-use interp::debugger::Debugger;
+use interp::debugger::{ActiveFrame, Debugger, ProgramStatus, VariableInfo};
 use std::path::PathBuf;
 
 pub struct MyAdapter {
-    #[allow(dead_code)]
     debugger: Debugger,
     break_count: Counter,
     thread_count: Counter,
-    stack_count: Counter,
-    breakpoints: Vec<(Source, i64)>, // This field is a placeholder
-    stack_frames: Vec<StackFrame>,   // This field is a placeholder
-    threads: Vec<Thread>,            // This field is a placeholder
+    /// The id of each running thread and the frame ids on its stack,
+    /// innermost first
+    threads: Vec<(i64, Vec<usize>)>,
+    /// The innermost frame of each running thread. A thread keeps its id for
+    /// as long as it runs the same control node.
+    thread_ids: Vec<(ActiveFrame, i64)>,
+    /// The frames of all threads, indexed by frame id
+    frames: Vec<ActiveFrame>,
+    /// The variables handed out to the client. A variables reference is the
+    /// index into this list plus one, since zero means "no children".
+    variables: Vec<Vec<VariableInfo>>,
     source: String,
     ids: NewSourceMap,
 }
@@ -20,116 +30,287 @@ pub struct MyAdapter {
 impl MyAdapter {
     pub fn new(path: &str, std_path: PathBuf) -> AdapterResult<Self> {
         let (debugger, metadata) =
-            Debugger::from_file(&PathBuf::from(path), &std_path).unwrap();
+            Debugger::from_file(&PathBuf::from(path), &std_path)
+                .map_err(|e| MyAdapterError::InvalidFile(e.to_string()))?;
         Ok(MyAdapter {
             debugger,
             break_count: Counter::new(),
             thread_count: Counter::new(),
-            stack_count: Counter::new(),
-            breakpoints: Vec::new(),
-            stack_frames: Vec::new(),
             threads: Vec::new(),
+            thread_ids: Vec::new(),
+            frames: Vec::new(),
+            variables: Vec::new(),
             source: path.to_string(),
             ids: metadata,
         })
     }
-    ///Set breakpoints for adapter
+    ///Set breakpoints for adapter. Each line is resolved to the group defined
+    ///on it; lines without a group give unverified breakpoints.
     pub fn set_breakpoint(
         &mut self,
         path: Source,
         source: &Vec<SourceBreakpoint>,
     ) -> Vec<Breakpoint> {
-        //Keep all the new breakpoints made
-        let mut out_vec: Vec<Breakpoint> = vec![];
-
-        //Loop over all breakpoints
-        for source_point in source {
-            self.breakpoints.push((path.clone(), source_point.line));
-            //Create new Breakpoint instance
-            let breakpoint = make_breakpoint(
-                self.break_count.increment().into(),
-                true,
-                Some(path.clone()),
-                Some(source_point.line),
-            );
-
-            out_vec.push(breakpoint);
+        let groups: Vec<Option<String>> = source
+            .iter()
+            .map(|point| self.ids.lookup_line(point.line as u64).cloned())
+            .collect();
+        let names: Vec<&String> = groups.iter().flatten().collect();
+        let mut verified = self.debugger.set_breakpoints(&names).into_iter();
+
+        source
+            .iter()
+            .zip(groups.iter())
+            .map(|(point, group)| {
+                let found = group.is_some() && verified.next().unwrap();
+                make_breakpoint(
+                    self.break_count.increment().into(),
+                    found,
+                    Some(path.clone()),
+                    Some(point.line),
+                )
+            })
+            .collect()
+    }
+
+    /// Rebuild the threads and stacks from the state of the debugger and
+    /// forget the variables handed out so far. Should be called every time
+    /// the program stops. Returns the thread events to send to the client.
+    pub fn refresh(&mut self) -> Vec<ThreadEventBody> {
+        let mut old_ids = std::mem::take(&mut self.thread_ids);
+        let mut events = vec![];
+
+        self.threads.clear();
+        self.frames.clear();
+        self.variables.clear();
+        for stack in self.debugger.active_threads() {
+            let key = stack[0].clone();
+            let id = match old_ids.iter().position(|(old, _)| *old == key) {
+                Some(idx) => old_ids.swap_remove(idx).1,
+                None => {
+                    let id = self.thread_count.increment();
+                    events.push(ThreadEventBody {
+                        reason: ThreadEventReason::Started,
+                        thread_id: id,
+                    });
+                    id
+                }
+            };
+            self.thread_ids.push((key, id));
+            let start = self.frames.len();
+            self.frames.extend(stack);
+            self.threads
+                .push((id, (start..self.frames.len()).collect()));
         }
 
-        out_vec
+        // the threads that are no longer running
+        events.extend(old_ids.into_iter().map(|(_, id)| ThreadEventBody {
+            reason: ThreadEventReason::Exited,
+            thread_id: id,
+        }));
+        events
+    }
+
+    /// The id of the thread reported as stopped, which is the first one
+    pub fn stopped_thread(&self) -> i64 {
+        self.threads.first().map_or(0, |(id, _)| *id)
+    }
+
+    /// The running threads, named after the group they are running
+    pub fn threads(&self) -> Vec<Thread> {
+        self.threads
+            .iter()
+            .map(|(id, stack)| Thread {
+                id: *id,
+                name: self.frames[stack[0]].name(),
+            })
+            .collect()
+    }
+
+    /// The stack of the given thread, innermost frame first
+    pub fn stack_trace(&self, thread_id: i64) -> Vec<StackFrame> {
+        let Some((_, stack)) =
+            self.threads.iter().find(|(id, _)| *id == thread_id)
+        else {
+            return vec![];
+        };
+        stack
+            .iter()
+            .map(|&id| {
+                let frame = &self.frames[id];
+                let contents = frame
+                    .group()
+                    .and_then(|group| self.ids.lookup(group.to_string()));
+                let path = contents
+                    .map(|c| c.path.clone())
+                    .unwrap_or_else(|| self.source.clone());
+                StackFrame {
+                    id: id as i64,
+                    name: frame.name(),
+                    source: Some(Source {
+                        name: None,
+                        path: Some(path),
+                        source_reference: None,
+                        presentation_hint: None,
+                        origin: None,
+                        sources: None,
+                        adapter_data: None,
+                        checksums: None,
+                    }),
+                    line: contents.map_or(0, |c| c.line as i64),
+                    column: 0,
+                    end_line: None,
+                    end_column: None,
+                    can_restart: None,
+                    instruction_pointer_reference: None,
+                    module_id: None,
+                    presentation_hint: None,
+                }
+            })
+            .collect()
     }
 
-    ///Creates a thread using the parameter name.
-    pub fn create_thread(&mut self, name: String) -> Thread {
-        let thread = Thread {
-            id: self.thread_count.increment(),
-            name,
+    /// The scopes of the given frame. There is a single scope holding the
+    /// cells of the component instance running the frame.
+    pub fn scopes(&mut self, frame_id: i64) -> Vec<Scope> {
+        let Some(cells) = self
+            .frames
+            .get(frame_id as usize)
+            .and_then(|frame| self.debugger.frame_variables(frame))
+        else {
+            return vec![];
         };
-        self.threads.push(thread.clone());
-        thread
-    }
-
-    /// Clone threads
-    pub fn clone_threads(&self) -> Vec<Thread> {
-        self.threads.clone()
-    }
-
-    //Returns a dummy stack frame, set to change.
-    pub fn create_stack(&mut self) -> Vec<StackFrame> {
-        let frame = StackFrame {
-            id: self.stack_count.increment(),
-            // Maybe automate the name in the future?
-            name: String::from("Frame"),
-            source: Some(Source {
-                name: None,
-                path: Some(self.source.clone()),
-                source_reference: None,
-                presentation_hint: None,
-                origin: None,
-                sources: None,
-                adapter_data: None,
-                checksums: None,
-            }),
-            line: 1,
-            column: 0,
+        let count = cells.len();
+        vec![Scope {
+            name: String::from("Cells"),
+            presentation_hint: None,
+            variables_reference: self.add_variables(cells),
+            named_variables: Some(count as i64),
+            indexed_variables: None,
+            expensive: false,
+            source: None,
+            line: None,
+            column: None,
             end_line: None,
             end_column: None,
-            can_restart: None,
-            instruction_pointer_reference: None,
-            module_id: None,
-            presentation_hint: None,
+        }]
+    }
+
+    /// The variables for the given reference. Cells can be expanded to show
+    /// their ports.
+    pub fn variables(&mut self, reference: i64) -> Vec<Variable> {
+        let Some(vars) = (reference as usize)
+            .checked_sub(1)
+            .and_then(|idx| self.variables.get(idx))
+            .cloned()
+        else {
+            return vec![];
         };
-        self.stack_frames.push(frame);
-        // Return all stack frames
-        self.stack_frames.clone()
-    }
-
-    pub fn clone_stack(&self) -> Vec<StackFrame> {
-        self.stack_frames.clone()
-    }
-
-    pub fn next_line(&mut self, _thread: i64) -> bool {
-        // Step through once
-        let status = self.debugger.step(1).unwrap();
-
-        // Check if done:
-        if status.get_done() {
-            // Give bool to exit the debugger
-            true
-        } else {
-            let map = status.get_status();
-            let mut line_number = 0;
-            // Implemented for loop for when more than 1 group is running,
-            // the code for now goes to the line of the last group running in the map, should deal
-            // with this in the future for when groups run in parallel.
-            for id in map {
-                let value = self.ids.lookup(id.to_string()).unwrap().line;
-                line_number = value;
-            }
-            // Set line of the stack frame and tell debugger we're not finished.
-            self.stack_frames[0].line = line_number as i64;
-            false
-        }
+        vars.into_iter()
+            .map(|var| {
+                let children = var.children.len();
+                let variables_reference = if children == 0 {
+                    0
+                } else {
+                    self.add_variables(var.children)
+                };
+                Variable {
+                    name: var.name,
+                    value: var.value,
+                    type_field: None,
+                    presentation_hint: None,
+                    evaluate_name: Some(var.evaluate_name),
+                    variables_reference,
+                    named_variables: Some(children as i64),
+                    indexed_variables: None,
+                    memory_reference: None,
+                }
+            })
+            .collect()
+    }
+
+    /// Find the variable with the given name under the given reference
+    fn find_variable(
+        &self,
+        reference: i64,
+        name: &str,
+    ) -> Option<&VariableInfo> {
+        (reference as usize)
+            .checked_sub(1)
+            .and_then(|idx| self.variables.get(idx))
+            .and_then(|vars| vars.iter().find(|var| var.name == name))
+    }
+
+    /// Evaluate a print command or target, as in the REPL
+    pub fn evaluate(&self, expr: &str) -> Result<String, String> {
+        self.debugger.evaluate(expr).map_err(|e| e.to_string())
+    }
+
+    /// Set the value of a variable, returning its new value
+    pub fn set_variable(
+        &mut self,
+        reference: i64,
+        name: &str,
+        value: &str,
+    ) -> Result<String, String> {
+        let target = self
+            .find_variable(reference, name)
+            .ok_or_else(|| format!("unknown variable {}", name))?
+            .evaluate_name
+            .clone();
+        self.debugger
+            .set_variable(&target, value)
+            .map_err(|e| e.to_string())
+    }
+
+    /// The data id used to break on changes to the given variable, if the
+    /// variable has contents that can change
+    pub fn data_breakpoint_id(
+        &self,
+        reference: i64,
+        name: &str,
+    ) -> Option<String> {
+        let target = &self.find_variable(reference, name)?.evaluate_name;
+        self.debugger.cell_state(target).map(|_| target.clone())
+    }
+
+    /// Replace all the data breakpoints. The data ids are the evaluate names
+    /// of the variables.
+    pub fn set_data_breakpoints(
+        &mut self,
+        data_ids: &[String],
+    ) -> Vec<Breakpoint> {
+        self.debugger
+            .set_data_breakpoints(data_ids)
+            .into_iter()
+            .map(|verified| {
+                make_breakpoint(
+                    self.break_count.increment().into(),
+                    verified,
+                    None,
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn add_variables(&mut self, vars: Vec<VariableInfo>) -> i64 {
+        self.variables.push(vars);
+        self.variables.len() as i64
+    }
+
+    /// Step through once. Returns true if the program is done.
+    pub fn next_line(&mut self, _thread: i64) -> Result<bool, String> {
+        self.debugger
+            .step(1)
+            .map(|status| status.get_done())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Run until a breakpoint is hit or the contents of a data breakpoint
+    /// change
+    pub fn cont(&mut self) -> Result<ProgramStatus, String> {
+        self.debugger.cont().map_err(|e| e.to_string())
     }
 }
 
@@ -174,3 +355,103 @@ pub fn make_breakpoint(
         offset: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    /// An adapter for the program `name` in `interp/tests/debugger`
+    fn adapter(name: &str) -> MyAdapter {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let file = root.join("interp/tests/debugger").join(name);
+        let mut adapter =
+            MyAdapter::new(file.to_str().unwrap(), root.to_path_buf()).unwrap();
+        adapter.refresh();
+        adapter
+    }
+
+    #[test]
+    fn missing_file() {
+        assert!(MyAdapter::new("nope.futil", PathBuf::from(".")).is_err());
+    }
+
+    #[test]
+    fn threads() {
+        let mut adapter = adapter("threads.futil");
+        let mut names: Vec<String> =
+            adapter.threads().into_iter().map(|t| t.name).collect();
+        names.sort();
+        assert_eq!(names, ["main::wr_a", "main::wr_b"]);
+
+        // threads keep their ids while they run the same group
+        let ids: Vec<i64> = adapter.threads().iter().map(|t| t.id).collect();
+        assert!(adapter.refresh().is_empty());
+        let same: Vec<i64> = adapter.threads().iter().map(|t| t.id).collect();
+        assert_eq!(ids, same);
+        assert!(ids.contains(&adapter.stopped_thread()));
+    }
+
+    #[test]
+    fn thread_events() {
+        let mut adapter = adapter("threads.futil");
+        let old: Vec<i64> = adapter.threads().iter().map(|t| t.id).collect();
+        adapter.debugger.set_breakpoints(&["bump"]);
+        adapter.cont().unwrap();
+        let events = adapter.refresh();
+
+        // the arms of the par exited and the subcomponent started
+        for id in &old {
+            assert!(events.iter().any(|e| e.thread_id == *id
+                && matches!(e.reason, ThreadEventReason::Exited)));
+        }
+        let new = adapter.threads();
+        assert!(new.iter().any(|t| t.name == "main.sub::bump"));
+        for thread in new {
+            assert!(!old.contains(&thread.id));
+            assert!(events.iter().any(|e| e.thread_id == thread.id
+                && matches!(e.reason, ThreadEventReason::Started)));
+        }
+    }
+
+    #[test]
+    fn stack_scopes_variables() {
+        let mut adapter = adapter("threads.futil");
+        adapter.debugger.set_breakpoints(&["bump"]);
+        adapter.cont().unwrap();
+        adapter.refresh();
+        let thread = adapter
+            .threads()
+            .into_iter()
+            .find(|t| t.name == "main.sub::bump")
+            .unwrap();
+
+        let stack = adapter.stack_trace(thread.id);
+        let names: Vec<&str> = stack.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["main.sub::bump", "main"]);
+        // groups are found in the source map
+        assert_eq!(stack[0].line, 9);
+        assert!(adapter.stack_trace(-1).is_empty());
+
+        let scopes = adapter.scopes(stack[0].id);
+        assert_eq!(scopes.len(), 1);
+        let cells = adapter.variables(scopes[0].variables_reference);
+        assert_eq!(cells.len(), 1);
+        assert_eq!(cells[0].name, "acc");
+        assert_eq!(cells[0].evaluate_name.as_deref(), Some("sub.acc"));
+
+        // cells expand to their ports
+        let ports = adapter.variables(cells[0].variables_reference);
+        assert!(ports.iter().any(|p| p.name == "out"));
+        assert!(adapter.variables(0).is_empty());
+        assert!(adapter.scopes(-1).is_empty());
+    }
+
+    #[test]
+    fn errors_are_returned() {
+        let mut adapter = adapter("threads.futil");
+        assert!(adapter.evaluate("p nope").is_err());
+        assert!(adapter.set_variable(1, "nope", "1").is_err());
+        while !adapter.next_line(0).unwrap() {}
+    }
+}
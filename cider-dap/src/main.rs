@@ -2,10 +2,12 @@ mod adapter;
 mod error;
 
 use adapter::MyAdapter;
-use dap::events::{ExitedEventBody, StoppedEventBody, ThreadEventBody};
+use dap::events::{ExitedEventBody, StoppedEventBody};
 use dap::responses::{
-    ContinueResponse, ScopesResponse, SetBreakpointsResponse,
-    SetExceptionBreakpointsResponse, StackTraceResponse, ThreadsResponse,
+    ContinueResponse, DataBreakpointInfoResponse, EvaluateResponse,
+    ScopesResponse, SetBreakpointsResponse, SetDataBreakpointsResponse,
+    SetExceptionBreakpointsResponse, SetVariableResponse, StackTraceResponse,
+    ThreadsResponse, VariablesResponse,
 };
 use error::MyAdapterError;
 
@@ -109,6 +111,8 @@ where
                     // Not sure if we need it
                     // Make VSCode send disassemble request
                     supports_stepping_granularity: Some(true),
+                    supports_data_breakpoints: Some(true),
+                    supports_set_variable: Some(true),
                     ..Default::default()
                 }));
             server.respond(rsp)?;
@@ -148,22 +152,10 @@ where
     // Construct the adapter
     let mut adapter = MyAdapter::new(program_path, std_path)?;
 
-    // Currently, we need two threads to run the debugger and step through,
-    // not sure why but would be good to look into for the future.
-    let thread = &adapter.create_thread(String::from("Main"));
-    let thread2 = &adapter.create_thread(String::from("Thread 1"));
-
-    // Notify server of first thread
-    server.send_event(Event::Thread(ThreadEventBody {
-        reason: types::ThreadEventReason::Started,
-        thread_id: thread.id,
-    }))?;
-
-    //Notify server of second thread
-    server.send_event(Event::Thread(ThreadEventBody {
-        reason: types::ThreadEventReason::Started,
-        thread_id: thread2.id,
-    }))?;
+    // Notify server of the threads running at the start
+    for thread in adapter.refresh() {
+        server.send_event(Event::Thread(thread))?;
+    }
 
     // Return the adapter instead of running the server
     Ok(adapter)
@@ -211,7 +203,7 @@ fn run_server<R: Read, W: Write>(
             // Retrieve a list of all threads
             Command::Threads => {
                 let rsp = req.success(ResponseBody::Threads(ThreadsResponse {
-                    threads: adapter.clone_threads(),
+                    threads: adapter.threads(),
                 }));
                 server.respond(rsp)?;
             }
@@ -227,28 +219,56 @@ fn run_server<R: Read, W: Write>(
                 info!(logger, "exited debugger");
                 return Ok(());
             }
-            // Send StackTrace, may be useful to make it more robust in the future
-            Command::StackTrace(_args) => {
-                // Create new frame if empty, SUBJECT TO CHANGE
-                let frames = if adapter.clone_stack().is_empty() {
-                    adapter.create_stack()
-                } else {
-                    adapter.clone_stack()
-                };
+            // Send the stack of component instances running the thread
+            Command::StackTrace(args) => {
+                let frames = adapter.stack_trace(args.thread_id);
+                let total = frames.len() as i64;
                 let rsp =
                     req.success(ResponseBody::StackTrace(StackTraceResponse {
                         stack_frames: frames,
-                        total_frames: Some(0),
+                        total_frames: Some(total),
                     }));
                 server.respond(rsp)?;
             }
-            // Continue the debugger
+            // Continue the debugger until a breakpoint is hit
             Command::Continue(_args) => {
+                // If done then disconnect
+                let status = match adapter.cont() {
+                    Ok(status) => status,
+                    Err(msg) => {
+                        server.respond(req.error(&msg))?;
+                        continue;
+                    }
+                };
+                if status.get_done() {
+                    let rsp = req.clone().success(ResponseBody::Disconnect);
+                    server.send_event(Event::Exited(ExitedEventBody {
+                        exit_code: 0,
+                    }))?;
+                    server.respond(rsp)?;
+
+                    // Exit
+                    info!(logger, "exited debugger");
+                    return Ok(());
+                }
+
                 let rsp =
                     req.success(ResponseBody::Continue(ContinueResponse {
-                        all_threads_continued: None,
+                        all_threads_continued: Some(true),
                     }));
                 server.respond(rsp)?;
+                let (reason, description) = if status.get_changed().is_empty() {
+                    (
+                        types::StoppedEventReason::Breakpoint,
+                        String::from("Hit breakpoint"),
+                    )
+                } else {
+                    (
+                        types::StoppedEventReason::Data,
+                        format!("Changed {}", status.get_changed().join(", ")),
+                    )
+                };
+                send_stopped(server, &mut adapter, reason, description)?;
             }
             // Send a Stopped event with reason Pause
             Command::Pause(args) => {
@@ -258,14 +278,25 @@ fn run_server<R: Read, W: Write>(
                 // Send response first
                 server.respond(rsp)?;
                 // Send event
-                let stopped = create_stopped(String::from("Paused"), thread_id);
+                let stopped = create_stopped(
+                    types::StoppedEventReason::Pause,
+                    String::from("Paused"),
+                    thread_id,
+                );
                 server.send_event(stopped)?;
             }
             // Step over
             Command::Next(args) => {
                 // Move stack frame
                 // If done then disconnect
-                if adapter.next_line(args.thread_id) {
+                let done = match adapter.next_line(args.thread_id) {
+                    Ok(done) => done,
+                    Err(msg) => {
+                        server.respond(req.error(&msg))?;
+                        continue;
+                    }
+                };
+                if done {
                     let rsp = req.clone().success(ResponseBody::Disconnect);
                     server.send_event(Event::Exited(ExitedEventBody {
                         exit_code: 0,
@@ -277,15 +308,16 @@ fn run_server<R: Read, W: Write>(
                     return Ok(());
                 }
 
-                // Get ID before rsp takes ownership
-                let thread_id = args.thread_id;
                 let rsp = req.success(ResponseBody::Next);
                 // Send response first
                 server.respond(rsp)?;
                 // Send event
-                let stopped =
-                    create_stopped(String::from("Continue"), thread_id);
-                server.send_event(stopped)?;
+                send_stopped(
+                    server,
+                    &mut adapter,
+                    types::StoppedEventReason::Step,
+                    String::from("Continue"),
+                )?;
             }
             // Step in
             Command::StepIn(args) => {
@@ -295,8 +327,11 @@ fn run_server<R: Read, W: Write>(
                 let rsp = req.success(ResponseBody::StepIn);
                 server.respond(rsp)?;
                 // Send event
-                let stopped =
-                    create_stopped(String::from("Paused on step"), thread_id);
+                let stopped = create_stopped(
+                    types::StoppedEventReason::Step,
+                    String::from("Paused on step"),
+                    thread_id,
+                );
                 server.send_event(stopped)?;
             }
             // Step out
@@ -307,16 +342,96 @@ fn run_server<R: Read, W: Write>(
                 let rsp = req.success(ResponseBody::StepOut);
                 server.respond(rsp)?;
                 // Send event
-                let stopped =
-                    create_stopped(String::from("Paused on step"), thread_id);
+                let stopped = create_stopped(
+                    types::StoppedEventReason::Step,
+                    String::from("Paused on step"),
+                    thread_id,
+                );
                 server.send_event(stopped)?;
             }
-            Command::Scopes(_) => {
+            Command::Scopes(args) => {
                 let rsp = req.success(ResponseBody::Scopes(ScopesResponse {
-                    scopes: vec![],
+                    scopes: adapter.scopes(args.frame_id),
                 }));
                 server.respond(rsp)?;
             }
+            Command::Variables(args) => {
+                let rsp =
+                    req.success(ResponseBody::Variables(VariablesResponse {
+                        variables: adapter.variables(args.variables_reference),
+                    }));
+                server.respond(rsp)?;
+            }
+
+            // Print a target using the same syntax as the print command of
+            // the REPL
+            Command::Evaluate(args) => {
+                let rsp = match adapter.evaluate(&args.expression) {
+                    Ok(result) => {
+                        req.success(ResponseBody::Evaluate(EvaluateResponse {
+                            result,
+                            type_field: None,
+                            presentation_hint: None,
+                            variables_reference: 0,
+                            named_variables: None,
+                            indexed_variables: None,
+                            memory_reference: None,
+                        }))
+                    }
+                    Err(msg) => req.error(&msg),
+                };
+                server.respond(rsp)?;
+            }
+            Command::SetVariable(args) => {
+                let rsp = match adapter.set_variable(
+                    args.variables_reference,
+                    &args.name,
+                    &args.value,
+                ) {
+                    Ok(value) => req.success(ResponseBody::SetVariable(
+                        SetVariableResponse {
+                            value,
+                            type_field: None,
+                            variables_reference: None,
+                            named_variables: None,
+                            indexed_variables: None,
+                        },
+                    )),
+                    Err(msg) => req.error(&msg),
+                };
+                server.respond(rsp)?;
+            }
+            // Only registers and memories can have data breakpoints
+            Command::DataBreakpointInfo(args) => {
+                let data_id = args.variables_reference.and_then(|reference| {
+                    adapter.data_breakpoint_id(reference, &args.name)
+                });
+                let description = match &data_id {
+                    Some(id) => format!("Break when {} changes", id),
+                    None => format!("{} has no contents to watch", args.name),
+                };
+                let rsp = req.success(ResponseBody::DataBreakpointInfo(
+                    DataBreakpointInfoResponse {
+                        data_id,
+                        description,
+                        access_types: None,
+                        can_persist: None,
+                    },
+                ));
+                server.respond(rsp)?;
+            }
+            Command::SetDataBreakpoints(args) => {
+                let data_ids: Vec<String> = args
+                    .breakpoints
+                    .iter()
+                    .map(|point| point.data_id.clone())
+                    .collect();
+                let breakpoints = adapter.set_data_breakpoints(&data_ids);
+                let rsp = req.success(ResponseBody::SetDataBreakpoints(
+                    SetDataBreakpointsResponse { breakpoints },
+                ));
+                server.respond(rsp)?;
+            }
 
             unknown_command => {
                 return Err(MyAdapterError::UnhandledCommandError(
@@ -328,14 +443,34 @@ fn run_server<R: Read, W: Write>(
 }
 
 /// Helper function used to create a Stopped event
-fn create_stopped(reason: String, thread_id: i64) -> Event {
+fn create_stopped(
+    reason: types::StoppedEventReason,
+    description: String,
+    thread_id: i64,
+) -> Event {
     Event::Stopped(StoppedEventBody {
-        reason: types::StoppedEventReason::Step,
-        description: Some(reason),
+        reason,
+        description: Some(description),
         thread_id: Some(thread_id),
         preserve_focus_hint: None,
         text: None,
-        all_threads_stopped: None,
+        all_threads_stopped: Some(true),
         hit_breakpoint_ids: None,
     })
 }
+
+/// Tell the client that the program stopped after running. The threads are
+/// rebuilt since running may have started or finished arms of a `par`.
+fn send_stopped<R: Read, W: Write>(
+    server: &mut Server<R, W>,
+    adapter: &mut MyAdapter,
+    reason: types::StoppedEventReason,
+    description: String,
+) -> AdapterResult<()> {
+    for thread in adapter.refresh() {
+        server.send_event(Event::Thread(thread))?;
+    }
+    let thread_id = adapter.stopped_thread();
+    server.send_event(create_stopped(reason, description, thread_id))?;
+    Ok(())
+}
@@ -1,9 +1,10 @@
 use crate::config;
-use crate::exec::{Driver, Request, StateRef};
+use crate::exec::{Driver, OpRef, Request, StateRef};
 use crate::run::Run;
 use anyhow::{anyhow, bail};
 use argh::FromArgs;
 use camino::Utf8PathBuf;
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::str::FromStr;
 
@@ -13,6 +14,7 @@ enum Mode {
     ShowDot,
     Generate,
     Run,
+    Exec,
 }
 
 impl FromStr for Mode {
@@ -24,6 +26,7 @@ impl FromStr for Mode {
             "plan" => Ok(Mode::ShowPlan),
             "gen" => Ok(Mode::Generate),
             "run" => Ok(Mode::Run),
+            "exec" => Ok(Mode::Exec),
             "dot" => Ok(Mode::ShowDot),
             _ => Err("unknown mode".to_string()),
         }
@@ -37,6 +40,7 @@ impl Display for Mode {
             Mode::ShowPlan => write!(f, "plan"),
             Mode::Generate => write!(f, "gen"),
             Mode::Run => write!(f, "run"),
+            Mode::Exec => write!(f, "exec"),
             Mode::ShowDot => write!(f, "dot"),
         }
     }
@@ -89,23 +93,23 @@ struct FakeArgs {
     #[argh(subcommand)]
     pub sub: Option<Subcommand>,
 
-    /// the input file
+    /// the input files
     #[argh(positional)]
-    input: Option<Utf8PathBuf>,
+    input: Vec<Utf8PathBuf>,
 
-    /// the output file
+    /// the output files
     #[argh(option, short = 'o')]
-    output: Option<Utf8PathBuf>,
+    output: Vec<Utf8PathBuf>,
 
-    /// the state to start from
+    /// the states to start from, one for each input file
     #[argh(option)]
-    from: Option<String>,
+    from: Vec<String>,
 
-    /// the state to produce
+    /// the states to produce, one for each output file
     #[argh(option)]
-    to: Option<String>,
+    to: Vec<String>,
 
-    /// execution mode (run, plan, emit, gen, dot)
+    /// execution mode (run, exec, plan, emit, gen, dot)
     #[argh(option, short = 'm', default = "Mode::Run")]
     mode: Mode,
 
@@ -117,6 +121,10 @@ struct FakeArgs {
     #[argh(switch)]
     keep: Option<bool>,
 
+    /// in exec mode, the number of commands to run in parallel
+    #[argh(option, short = 'j')]
+    jobs: Option<usize>,
+
     /// set a configuration variable (key=value)
     #[argh(option, short = 's')]
     set: Vec<String>,
@@ -134,38 +142,124 @@ struct FakeArgs {
     pub log_level: log::LevelFilter,
 }
 
-fn from_state(driver: &Driver, args: &FakeArgs) -> anyhow::Result<StateRef> {
-    match &args.from {
-        Some(name) => driver
-            .get_state(name)
-            .ok_or(anyhow!("unknown --from state")),
-        None => match args.input {
-            Some(ref input) => driver
+fn from_states(
+    driver: &Driver,
+    args: &FakeArgs,
+) -> anyhow::Result<Vec<StateRef>> {
+    if args.input.is_empty() {
+        // With no input files, we read a single input from stdin.
+        return match args.from.as_slice() {
+            [] => bail!("specify an input file or use --from"),
+            [name] => Ok(vec![driver
+                .get_state(name)
+                .ok_or(anyhow!("unknown --from state {}", name))?]),
+            _ => bail!("multiple --from states require input files"),
+        };
+    }
+    if args.from.len() > args.input.len() {
+        bail!("more --from states than input files");
+    }
+
+    // Each input file has the corresponding `--from` state, if any, or a guessed one.
+    args.input
+        .iter()
+        .enumerate()
+        .map(|(idx, input)| match args.from.get(idx) {
+            Some(name) => driver
+                .get_state(name)
+                .ok_or(anyhow!("unknown --from state {}", name)),
+            None => driver
                 .guess_state(input)
-                .ok_or(anyhow!("could not infer input state")),
-            None => bail!("specify an input file or use --from"),
-        },
+                .ok_or(anyhow!("could not infer state of input {}", input)),
+        })
+        .collect()
+}
+
+fn to_states(
+    driver: &Driver,
+    args: &FakeArgs,
+) -> anyhow::Result<Vec<StateRef>> {
+    if args.to.is_empty() && args.output.is_empty() {
+        bail!("specify an output file or use --to");
     }
+
+    // Each output file has the corresponding `--to` state, if any, or a guessed one. Extra
+    // `--to` states get generated filenames.
+    (0..args.to.len().max(args.output.len()))
+        .map(|idx| match (args.to.get(idx), args.output.get(idx)) {
+            (Some(name), _) => driver
+                .get_state(name)
+                .ok_or(anyhow!("unknown --to state {}", name)),
+            (None, Some(out)) => driver
+                .guess_state(out)
+                .ok_or(anyhow!("could not infer state of output {}", out)),
+            (None, None) => unreachable!(),
+        })
+        .collect()
 }
 
-fn to_state(driver: &Driver, args: &FakeArgs) -> anyhow::Result<StateRef> {
-    match &args.to {
-        Some(name) => {
-            driver.get_state(name).ok_or(anyhow!("unknown --to state"))
+/// Read the cost overrides for operations from the configuration, as `ops.<name>.cost` keys.
+fn op_costs(
+    driver: &Driver,
+    config_data: &figment::Figment,
+) -> anyhow::Result<HashMap<OpRef, u32>> {
+    // Settings for operations that do not exist are most likely typos, which would otherwise go
+    // unnoticed.
+    if let Some(ops) = config_data
+        .find_value("ops")
+        .ok()
+        .and_then(|v| v.into_dict())
+    {
+        for (name, settings) in ops {
+            if driver.get_op(&name).is_none() {
+                log::warn!(
+                    "ignoring ops.{}: there is no operation named {}",
+                    name,
+                    name
+                );
+                continue;
+            }
+            for key in
+                settings.into_dict().into_iter().flat_map(|d| d.into_keys())
+            {
+                if key != "cost" {
+                    log::warn!("ignoring unknown setting ops.{}.{}", name, key);
+                }
+            }
         }
-        None => match &args.output {
-            Some(out) => driver
-                .guess_state(out)
-                .ok_or(anyhow!("could not infer output state")),
-            None => Err(anyhow!("specify an output file or use --to")),
-        },
     }
+
+    let mut costs = HashMap::new();
+    for (op_ref, op) in driver.ops.iter() {
+        let key = format!("ops.{}.cost", op.name);
+        if config_data.find_value(&key).is_err() {
+            continue;
+        }
+
+        // Values from `--set` are strings, so we also accept those.
+        let cost = match config_data.extract_inner::<u32>(&key) {
+            Ok(cost) => cost,
+            Err(_) => config_data
+                .extract_inner::<String>(&key)?
+                .parse()
+                .map_err(|_| anyhow!("{} must be a positive integer", key))?,
+        };
+        if cost == 0 {
+            bail!("{} must be a positive integer", key);
+        }
+        costs.insert(op_ref, cost);
+    }
+    Ok(costs)
 }
 
-fn get_request(driver: &Driver, args: &FakeArgs) -> anyhow::Result<Request> {
+fn get_request(
+    driver: &Driver,
+    args: &FakeArgs,
+    config_data: &figment::Figment,
+) -> anyhow::Result<Request> {
     // The default working directory (if not specified) depends on the mode.
     let workdir = args.dir.clone().unwrap_or_else(|| match args.mode {
-        Mode::Generate | Mode::Run => {
+        Mode::Generate | Mode::Run | Mode::Exec => {
             if args.keep.unwrap_or(false) {
                 driver.stable_workdir()
             } else {
@@ -187,11 +281,12 @@ fn get_request(driver: &Driver, args: &FakeArgs) -> anyhow::Result<Request> {
         .collect();
 
     Ok(Request {
-        start_file: args.input.clone(),
-        start_state: from_state(driver, args)?,
-        end_file: args.output.clone(),
-        end_state: to_state(driver, args)?,
+        start_files: args.input.clone(),
+        start_states: from_states(driver, args)?,
+        end_files: args.output.clone(),
+        end_states: to_states(driver, args)?,
         through: through?,
+        op_costs: op_costs(driver, config_data)?,
         workdir,
     })
 }
@@ -266,13 +361,26 @@ pub fn cli(driver: &Driver) -> anyhow::Result<()> {
         None => {}
     }
 
+    // Load the configuration, using `--set` arguments to override values.
+    let mut config_data = config::load_config(&driver.name);
+    for set in &args.set {
+        let mut parts = set.splitn(2, '=');
+        let key = parts.next().unwrap();
+        let value = parts
+            .next()
+            .ok_or(anyhow!("--set arguments must be in key=value form"))?;
+        let dict = figment::util::nest(key, value.into());
+        config_data =
+            config_data.merge(figment::providers::Serialized::defaults(dict));
+    }
+
     // Make a plan.
-    let req = get_request(driver, &args)?;
+    let req = get_request(driver, &args, &config_data)?;
     let workdir = req.workdir.clone();
     let plan = driver.plan(req).ok_or(anyhow!("could not find path"))?;
 
     // Configure.
-    let mut run = Run::new(driver, plan);
+    let mut run = Run::with_config(driver, plan, config_data);
 
     // Override some global config options.
     if let Some(keep) = args.keep {
@@ -281,18 +389,8 @@ pub fn cli(driver: &Driver) -> anyhow::Result<()> {
     if let Some(verbose) = args.verbose {
         run.global_config.verbose = verbose;
     }
-
-    // Use `--set` arguments to override configuration values.
-    for set in args.set {
-        let mut parts = set.splitn(2, '=');
-        let key = parts.next().unwrap();
-        let value = parts
-            .next()
-            .ok_or(anyhow!("--set arguments must be in key=value form"))?;
-        let dict = figment::util::nest(key, value.into());
-        run.config_data = run
-            .config_data
-            .merge(figment::providers::Serialized::defaults(dict));
+    if let Some(jobs) = args.jobs {
+        run.global_config.jobs = Some(jobs);
     }
 
     // Execute.
@@ -302,6 +400,7 @@ pub fn cli(driver: &Driver) -> anyhow::Result<()> {
         Mode::EmitNinja => run.emit_to_stdout()?,
         Mode::Generate => run.emit_to_dir(&workdir)?.keep(),
         Mode::Run => run.emit_and_run(&workdir)?,
+        Mode::Exec => run.emit_and_exec(&workdir)?,
     }
 
     Ok(())
@@ -18,6 +18,9 @@ pub struct GlobalConfig {
 
     /// The path to the build tool executable.
     pub exe: String,
+
+    /// The number of commands to run at once in `exec` mode. Defaults to the number of CPUs.
+    pub jobs: Option<usize>,
 }
 
 impl Default for GlobalConfig {
@@ -31,6 +34,7 @@ impl Default for GlobalConfig {
                 .to_str()
                 .expect("invalid executable name")
                 .into(),
+            jobs: None,
         }
     }
 }
@@ -32,11 +32,15 @@ impl State {
 pub struct StateRef(u32);
 entity_impl!(StateRef, "state");
 
-/// An Operation transforms files from one State to another.
+/// An Operation transforms files from one set of States to another.
 pub struct Operation {
     pub name: String,
-    pub input: StateRef,
-    pub output: StateRef,
+    /// The states consumed by the operation, in the order their files are passed to `emit`.
+    pub input: Vec<StateRef>,
+    /// The states produced by the operation, in the order their files are passed to `emit`.
+    pub output: Vec<StateRef>,
+    /// The relative cost of running the operation. The planner picks the cheapest route.
+    pub cost: u32,
     pub setups: Vec<SetupRef>,
     pub emit: Box<dyn run::EmitBuild>,
 }
@@ -5,12 +5,6 @@ use cranelift_entity::{PrimaryMap, SecondaryMap};
 use rand::distributions::{Alphanumeric, DistString};
 use std::{collections::HashMap, error::Error, ffi::OsStr, fmt::Display};
 
-#[derive(PartialEq)]
-enum Destination {
-    State(StateRef),
-    Op(OpRef),
-}
-
 type FileData = HashMap<&'static str, &'static [u8]>;
 
 /// A Driver encapsulates a set of States and the Operations that can transform between them. It
@@ -24,145 +18,303 @@ pub struct Driver {
     pub rsrc_files: Option<FileData>,
 }
 
-impl Driver {
-    /// Find a chain of Operations from the `start` state to the `end`, which may be a state or the
-    /// final operation in the chain.
-    fn find_path_segment(
-        &self,
-        start: StateRef,
-        end: Destination,
-    ) -> Option<Vec<OpRef>> {
-        // Our start state is the input.
-        let mut visited = SecondaryMap::<StateRef, bool>::new();
-        visited[start] = true;
-
-        // Build the incoming edges for each vertex.
-        let mut breadcrumbs = SecondaryMap::<StateRef, Option<OpRef>>::new();
+/// The total cost of producing the inputs of `op`, if they can all be produced. Costs saturate
+/// instead of overflowing, so very expensive operations are still ordered sensibly.
+fn input_cost(
+    op: &Operation,
+    costs: &SecondaryMap<StateRef, Option<u32>>,
+) -> Option<u32> {
+    op.input
+        .iter()
+        .try_fold(0u32, |total, s| Some(total.saturating_add(costs[*s]?)))
+}
 
-        // Breadth-first search.
-        let mut state_queue: Vec<StateRef> = vec![start];
-        while !state_queue.is_empty() {
-            let cur_state = state_queue.remove(0);
+impl Driver {
+    /// The cost of running an operation, taking any overrides for this build into account.
+    fn op_cost(&self, op: OpRef, overrides: &HashMap<OpRef, u32>) -> u32 {
+        overrides.get(&op).copied().unwrap_or(self.ops[op].cost)
+    }
 
-            // Finish when we reach the goal vertex.
-            if end == Destination::State(cur_state) {
-                break;
-            }
+    /// Find the cheapest operation for producing each state from the `available` states.
+    ///
+    /// The cost of producing a state is the total cost of the operations needed to produce it;
+    /// available states cost nothing. Producing a state with an operation costs the operation's
+    /// own cost plus the sum of the costs of its inputs. This generalizes shortest-path search to
+    /// operations with several inputs. Every reachable state gets a producer, including the
+    /// available ones, so that a requested output can be produced anew even when a file of the
+    /// same state is one of the inputs. Ties go to the operation registered first.
+    fn find_producers(
+        &self,
+        available: &[StateRef],
+        overrides: &HashMap<OpRef, u32>,
+    ) -> Producers {
+        let mut costs = SecondaryMap::<StateRef, Option<u32>>::new();
+        for state in available {
+            costs[*state] = Some(0);
+        }
 
-            // Traverse any edge from the current state to an unvisited state.
+        // Relax costs until we reach a fixed point. Costs only decrease, so this terminates.
+        let mut changed = true;
+        while changed {
+            changed = false;
             for (op_ref, op) in self.ops.iter() {
-                if op.input == cur_state && !visited[op.output] {
-                    state_queue.push(op.output);
-                    visited[op.output] = true;
-                    breadcrumbs[op.output] = Some(op_ref);
-                }
-
-                // Finish when we reach the goal edge.
-                if end == Destination::Op(op_ref) {
-                    break;
+                let Some(input_cost) = input_cost(op, &costs) else {
+                    continue;
+                };
+                let cost =
+                    input_cost.saturating_add(self.op_cost(op_ref, overrides));
+                for &state in &op.output {
+                    if !costs[state].is_some_and(|c| c <= cost) {
+                        costs[state] = Some(cost);
+                        changed = true;
+                    }
                 }
             }
         }
 
-        // Traverse the breadcrumbs backward to build up the path back from output to input.
-        let mut op_path: Vec<OpRef> = vec![];
-        let mut cur_state = match end {
-            Destination::State(state) => state,
-            Destination::Op(op) => {
-                op_path.push(op);
-                self.ops[op].input
-            }
-        };
-        while cur_state != start {
-            match breadcrumbs[cur_state] {
-                Some(op) => {
-                    op_path.push(op);
-                    cur_state = self.ops[op].input;
+        // Now that the costs are settled, collect every way of producing each state, in
+        // registration order, and pick the cheapest.
+        let mut candidates = SecondaryMap::<StateRef, Vec<(OpRef, u32)>>::new();
+        for (op_ref, op) in self.ops.iter() {
+            if let Some(input_cost) = input_cost(op, &costs) {
+                let cost =
+                    input_cost.saturating_add(self.op_cost(op_ref, overrides));
+                for &state in &op.output {
+                    candidates[state].push((op_ref, cost));
                 }
-                None => return None,
             }
         }
-        op_path.reverse();
+        let mut best = SecondaryMap::<StateRef, Option<(OpRef, u32)>>::new();
+        for (state, options) in candidates.iter() {
+            best[state] = options.iter().min_by_key(|(_, cost)| *cost).copied();
+        }
+
+        Producers { best, candidates }
+    }
 
-        Some(op_path)
+    /// Add the operations needed to produce `state` to `segment`, after the operations that
+    /// produce their inputs. Available states need no operations unless `produce` is set.
+    /// Operations already in the segment are not repeated.
+    fn derive_state(
+        &self,
+        producers: &Producers,
+        available: &[StateRef],
+        state: StateRef,
+        produce: bool,
+        segment: &mut PathSearch,
+    ) -> Option<()> {
+        if !produce && available.contains(&state) {
+            return Some(());
+        }
+        let (op, cost) = producers.best[state]?;
+        if segment.ops.contains(&op) {
+            return Some(());
+        }
+        for input in &self.ops[op].input {
+            self.derive_state(producers, available, *input, false, segment)?;
+        }
+        segment.ops.push(op);
+
+        // Remember the other ways we could have produced this state.
+        for &(other, other_cost) in &producers.candidates[state] {
+            if other != op {
+                segment.alternatives.push(Alternative {
+                    state,
+                    chosen: (op, cost),
+                    rejected: (other, other_cost),
+                });
+            }
+        }
+        Some(())
     }
 
-    /// Find a chain of operations from the `start` state to the `end` state, passing through each
-    /// `through` operation in order.
+    /// Find a sequence of operations that produces every `end` state from the `start` states,
+    /// passing through each `through` operation in order.
+    ///
+    /// After each `through` operation, the search continues from that operation's outputs along
+    /// with any start states that have not been consumed yet.
     pub fn find_path(
         &self,
-        start: StateRef,
-        end: StateRef,
+        start: &[StateRef],
+        end: &[StateRef],
         through: &[OpRef],
     ) -> Option<Vec<OpRef>> {
-        let mut cur_state = start;
-        let mut op_path: Vec<OpRef> = vec![];
+        self.search_path(start, end, through, &HashMap::new())
+            .map(|search| search.ops)
+    }
 
-        // Build path segments through each through required operation.
+    /// Find the cheapest path like [Driver::find_path], using `overrides` in place of the
+    /// operations' own costs, and also report the alternatives we passed over along the way.
+    fn search_path(
+        &self,
+        start: &[StateRef],
+        end: &[StateRef],
+        through: &[OpRef],
+        overrides: &HashMap<OpRef, u32>,
+    ) -> Option<PathSearch> {
+        let mut search = PathSearch::default();
+
+        // The states available to the next segment: the unused inputs, and the outputs of the
+        // last `through` operation.
+        let mut inputs: Vec<StateRef> = start.to_vec();
+        let mut produced: Vec<StateRef> = vec![];
+
+        // Build path segments through each required operation.
         for op in through {
-            let segment =
-                self.find_path_segment(cur_state, Destination::Op(*op))?;
-            op_path.extend(segment);
-            cur_state = self.ops[*op].output;
+            let available = [inputs.as_slice(), produced.as_slice()].concat();
+            let producers = self.find_producers(&available, overrides);
+            let mut segment = PathSearch::default();
+            for input in &self.ops[*op].input {
+                self.derive_state(
+                    &producers,
+                    &available,
+                    *input,
+                    false,
+                    &mut segment,
+                )?;
+            }
+            segment.ops.push(*op);
+
+            let used: Vec<StateRef> = segment
+                .ops
+                .iter()
+                .flat_map(|op| self.ops[*op].input.iter().copied())
+                .collect();
+            inputs.retain(|s| !used.contains(s));
+            produced = self.ops[*op].output.clone();
+            search.extend(segment);
         }
 
-        // Build the final path segment to the destination state.
-        let segment =
-            self.find_path_segment(cur_state, Destination::State(end))?;
-        op_path.extend(segment);
+        // Build the final segment to the destination states. Outputs of the last `through`
+        // operation are already done; everything else must be produced by some operation.
+        let available = [inputs.as_slice(), produced.as_slice()].concat();
+        let producers = self.find_producers(&available, overrides);
+        let mut segment = PathSearch::default();
+        for state in end {
+            self.derive_state(
+                &producers,
+                &available,
+                *state,
+                !produced.contains(state),
+                &mut segment,
+            )?;
+        }
+        search.extend(segment);
 
-        Some(op_path)
+        Some(search)
     }
 
-    /// Generate a filename with an extension appropriate for the given State.
-    fn gen_name(&self, stem: &str, state: StateRef) -> Utf8PathBuf {
+    /// Generate a filename with an extension appropriate for the given State. The name never
+    /// collides with one of the `inputs` to the build.
+    fn gen_name(
+        &self,
+        stem: &str,
+        state: StateRef,
+        inputs: &[Utf8PathBuf],
+    ) -> Utf8PathBuf {
         let state = &self.states[state];
         if state.is_pseudo() {
             Utf8PathBuf::from(format!("_pseudo_{}", state.name))
         } else {
             // TODO avoid collisions in case we reuse extensions...
-            Utf8PathBuf::from(stem).with_extension(&state.extensions[0])
+            let ext = &state.extensions[0];
+            let mut name = Utf8PathBuf::from(stem).with_extension(ext);
+            let mut suffix = 1;
+            while inputs.contains(&name) {
+                name = Utf8PathBuf::from(format!("{}_{}", stem, suffix))
+                    .with_extension(ext);
+                suffix += 1;
+            }
+            name
         }
     }
 
     /// Concoct a plan to carry out the requested build.
     ///
-    /// This works by searching for a path through the available operations from the input state
-    /// to the output state. If no such path exists in the operation graph, we return None.
+    /// This works by searching for a set of operations that produces all the output states from
+    /// the input states. If no such path exists in the operation graph, we return None.
     pub fn plan(&self, req: Request) -> Option<Plan> {
         // Find a path through the states.
-        let path =
-            self.find_path(req.start_state, req.end_state, &req.through)?;
-
-        let mut steps: Vec<(OpRef, Utf8PathBuf)> = vec![];
-
-        // Get the initial input filename and the stem to use to generate all intermediate filenames.
-        let (stdin, start_file) = match req.start_file {
-            Some(path) => (false, utils::relative_path(&path, &req.workdir)),
-            None => (true, "stdin".into()),
-        };
-        let stem = start_file.file_stem().unwrap();
-
-        // Generate filenames for each step.
-        steps.extend(path.into_iter().map(|op| {
-            let filename = self.gen_name(stem, self.ops[op].output);
-            (op, filename)
-        }));
-
-        // If we have a specified output filename, use that instead of the generated one.
-        let stdout = if let Some(end_file) = req.end_file {
-            // TODO Can we just avoid generating the unused filename in the first place?
-            let last_step = steps.last_mut().expect("no steps");
-            last_step.1 = utils::relative_path(&end_file, &req.workdir);
-            false
+        let search = self.search_path(
+            &req.start_states,
+            &req.end_states,
+            &req.through,
+            &req.op_costs,
+        )?;
+
+        // Get the input filenames and the stem to use to generate all intermediate filenames.
+        let stdin = req.start_files.is_empty();
+        let inputs: Vec<Utf8PathBuf> = if stdin {
+            vec!["stdin".into()]
         } else {
-            // Print to stdout if the last state is a real (non-pseudo) state.
-            !self.states[req.end_state].is_pseudo()
+            req.start_files
+                .iter()
+                .map(|path| utils::relative_path(path, &req.workdir))
+                .collect()
         };
+        let stem = inputs[0].file_stem().unwrap();
+
+        // Track the file currently holding each state, and generate filenames for each step.
+        let mut files = SecondaryMap::<StateRef, Option<Utf8PathBuf>>::new();
+        for (state, file) in req.start_states.iter().zip(&inputs) {
+            files[*state] = Some(file.clone());
+        }
+        let mut steps: Vec<Step> = vec![];
+        for op in search.ops {
+            let op_data = &self.ops[op];
+            let step_inputs = op_data
+                .input
+                .iter()
+                .map(|state| files[*state].clone())
+                .collect::<Option<Vec<_>>>()?;
+            let step_outputs: Vec<Utf8PathBuf> = op_data
+                .output
+                .iter()
+                .map(|state| self.gen_name(stem, *state, &inputs))
+                .collect();
+            for (state, file) in op_data.output.iter().zip(&step_outputs) {
+                files[*state] = Some(file.clone());
+            }
+            steps.push(Step {
+                op,
+                inputs: step_inputs,
+                outputs: step_outputs,
+            });
+        }
+
+        // If we have specified output filenames, use those instead of the generated ones.
+        let mut results = vec![];
+        for (idx, state) in req.end_states.iter().enumerate() {
+            let mut result = files[*state].clone()?;
+            if let Some(end_file) = req.end_files.get(idx) {
+                // TODO Can we just avoid generating the unused filename in the first place?
+                let end_file = utils::relative_path(end_file, &req.workdir);
+                let producer = steps.iter().rposition(|step| {
+                    self.ops[step.op].output.contains(state)
+                })?;
+                for step in &mut steps[producer..] {
+                    for file in step.inputs.iter_mut().chain(&mut step.outputs)
+                    {
+                        if *file == result {
+                            *file = end_file.clone();
+                        }
+                    }
+                }
+                result = end_file;
+            }
+            results.push(result);
+        }
+
+        // Print to stdout if there is a single real (non-pseudo) output without a filename.
+        let stdout = req.end_files.is_empty()
+            && req.end_states.len() == 1
+            && !self.states[req.end_states[0]].is_pseudo();
 
         Some(Plan {
-            start: start_file,
+            inputs,
             steps,
+            results,
+            alternatives: search.alternatives,
             workdir: req.workdir,
             stdin,
             stdout,
@@ -215,6 +367,15 @@ impl Driver {
         }
     }
 
+    /// A comma-separated list of state names.
+    pub fn state_names(&self, states: &[StateRef]) -> String {
+        states
+            .iter()
+            .map(|state| self.states[*state].name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     /// Print a list of registered states and operations to stdout.
     pub fn print_info(&self) {
         println!("States:");
@@ -229,12 +390,16 @@ impl Driver {
         println!();
         println!("Operations:");
         for (_, op) in self.ops.iter() {
-            println!(
+            print!(
                 "  {}: {} -> {}",
                 op.name,
-                self.states[op.input].name,
-                self.states[op.output].name
+                self.state_names(&op.input),
+                self.state_names(&op.output)
             );
+            if op.cost != 1 {
+                print!(" (cost {})", op.cost);
+            }
+            println!();
         }
     }
 }
@@ -327,19 +492,27 @@ impl DriverBuilder {
         &mut self,
         name: &str,
         setups: &[SetupRef],
-        input: StateRef,
-        output: StateRef,
+        input: &[StateRef],
+        output: &[StateRef],
         emit: T,
     ) -> OpRef {
         self.ops.push(Operation {
             name: name.into(),
             setups: setups.into(),
-            input,
-            output,
+            input: input.into(),
+            output: output.into(),
+            cost: 1,
             emit: Box::new(emit),
         })
     }
 
+    /// Set the cost of an operation, which makes the planner prefer cheaper routes. Costs must
+    /// be positive; operations cost 1 by default.
+    pub fn set_cost(&mut self, op: OpRef, cost: u32) {
+        assert!(cost > 0, "operation costs must be positive");
+        self.ops[op].cost = cost;
+    }
+
     pub fn op(
         &mut self,
         name: &str,
@@ -347,6 +520,18 @@ impl DriverBuilder {
         input: StateRef,
         output: StateRef,
         build: run::EmitBuildFn,
+    ) -> OpRef {
+        self.add_op(name, setups, &[input], &[output], build)
+    }
+
+    /// Add an operation that consumes and produces several files at once.
+    pub fn multi_op(
+        &mut self,
+        name: &str,
+        setups: &[SetupRef],
+        input: &[StateRef],
+        output: &[StateRef],
+        build: run::EmitBuildFn,
     ) -> OpRef {
         self.add_op(name, setups, input, output, build)
     }
@@ -361,8 +546,8 @@ impl DriverBuilder {
         self.add_op(
             rule_name,
             setups,
-            input,
-            output,
+            &[input],
+            &[output],
             run::EmitRuleBuild {
                 rule_name: rule_name.to_string(),
             },
@@ -436,13 +621,68 @@ impl DriverBuilder {
     }
 }
 
+/// The best way to produce each state from some available states, found by
+/// [Driver::find_producers].
+struct Producers {
+    /// The cheapest operation for producing each state, with the total cost of producing it.
+    best: SecondaryMap<StateRef, Option<(OpRef, u32)>>,
+
+    /// Every operation that can produce each state, with the total cost of producing it.
+    candidates: SecondaryMap<StateRef, Vec<(OpRef, u32)>>,
+}
+
+/// The operations found by a path search, and the alternatives that lost out to them.
+#[derive(Default)]
+struct PathSearch {
+    ops: Vec<OpRef>,
+    alternatives: Vec<Alternative>,
+}
+
+impl PathSearch {
+    fn extend(&mut self, other: PathSearch) {
+        self.ops.extend(other.ops);
+        self.alternatives.extend(other.alternatives);
+    }
+}
+
+/// An operation that could have produced a state in a [Plan] but lost out to a cheaper one.
+#[derive(Debug)]
+pub struct Alternative {
+    /// The state that both operations produce.
+    pub state: StateRef,
+
+    /// The operation in the plan, with the total cost of producing the state with it.
+    pub chosen: (OpRef, u32),
+
+    /// The rejected operation, with the total cost of producing the state with it.
+    pub rejected: (OpRef, u32),
+}
+
+/// A single operation in a [Plan], along with the files it reads and writes.
+#[derive(Debug)]
+pub struct Step {
+    pub op: OpRef,
+
+    /// The input files, one for each of the operation's input states.
+    pub inputs: Vec<Utf8PathBuf>,
+
+    /// The output files, one for each of the operation's output states.
+    pub outputs: Vec<Utf8PathBuf>,
+}
+
 #[derive(Debug)]
 pub struct Plan {
-    /// The input to the first step.
-    pub start: Utf8PathBuf,
+    /// The input files, one for each start state of the request.
+    pub inputs: Vec<Utf8PathBuf>,
+
+    /// The operations to run, in order.
+    pub steps: Vec<Step>,
 
-    /// The chain of operations to run and each step's output file.
-    pub steps: Vec<(OpRef, Utf8PathBuf)>,
+    /// The final output files, one for each end state of the request.
+    pub results: Vec<Utf8PathBuf>,
+
+    /// The operations the planner considered but rejected in favor of cheaper ones.
+    pub alternatives: Vec<Alternative>,
 
     /// The directory that the build will happen in.
     pub workdir: Utf8PathBuf,
@@ -450,15 +690,6 @@ pub struct Plan {
     /// Read the first input from stdin.
     pub stdin: bool,
 
-    /// Write the final output to stdout.
+    /// Write the first result to stdout.
     pub stdout: bool,
 }
-
-impl Plan {
-    pub fn end(&self) -> &Utf8Path {
-        match self.steps.last() {
-            Some((_, path)) => path,
-            None => &self.start,
-        }
-    }
-}
@@ -4,5 +4,5 @@ mod request;
 
 pub use data::{OpRef, SetupRef, StateRef};
 pub(super) use data::{Operation, Setup, State};
-pub use driver::{Driver, DriverBuilder, Plan};
+pub use driver::{Alternative, Driver, DriverBuilder, Plan, Step};
 pub use request::Request;
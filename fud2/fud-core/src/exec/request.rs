@@ -1,24 +1,30 @@
 use super::{OpRef, StateRef};
 use camino::Utf8PathBuf;
+use std::collections::HashMap;
 
 /// A request to the Driver directing it what to build.
 #[derive(Debug)]
 pub struct Request {
-    /// The input format.
-    pub start_state: StateRef,
+    /// The input formats.
+    pub start_states: Vec<StateRef>,
 
-    /// The output format to produce.
-    pub end_state: StateRef,
+    /// The output formats to produce.
+    pub end_states: Vec<StateRef>,
 
-    /// The filename to read the input from, or None to read from stdin.
-    pub start_file: Option<Utf8PathBuf>,
+    /// The filenames to read the inputs from, one for each start state. If this is empty, the
+    /// (single) input is read from stdin.
+    pub start_files: Vec<Utf8PathBuf>,
 
-    /// The filename to write the output to, or None to print to stdout.
-    pub end_file: Option<Utf8PathBuf>,
+    /// The filenames to write the outputs to, one for each end state. Outputs without a filename
+    /// get a generated one. If this is empty, the (single) output is printed to stdout.
+    pub end_files: Vec<Utf8PathBuf>,
 
     /// A sequence of operators to route the conversion through.
     pub through: Vec<OpRef>,
 
+    /// Costs to use for some operations in place of the ones they were registered with.
+    pub op_costs: HashMap<OpRef, u32>,
+
     /// The working directory for the build.
     pub workdir: Utf8PathBuf,
 }
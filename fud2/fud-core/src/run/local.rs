@@ -0,0 +1,756 @@
+//! A built-in executor that runs builds in-process instead of invoking `ninja`.
+//!
+//! The executor interprets the same Ninja code we emit for `ninja`, so operations and setups do
+//! not need to know which executor will run them. It understands the subset of Ninja syntax that
+//! our emitters produce: variables, rules, build statements (with implicit and order-only
+//! dependencies), the `console` pool, and `default` targets.
+
+use super::RunError;
+use camino::{Utf8Path, Utf8PathBuf};
+use std::collections::{HashMap, HashSet};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc;
+use std::time::SystemTime;
+
+/// A single build statement, with its command fully expanded.
+#[derive(Debug)]
+pub struct Edge {
+    /// The name of the rule that this build uses.
+    pub rule: String,
+
+    /// The files that the command produces.
+    pub outputs: Vec<String>,
+
+    /// The explicit inputs, which appear in `$in`.
+    pub inputs: Vec<String>,
+
+    /// Implicit dependencies, which the command reads but which don't appear in `$in`.
+    pub implicit: Vec<String>,
+
+    /// Order-only dependencies, which must be built first but never make this build stale.
+    pub order_only: Vec<String>,
+
+    /// The shell command to run.
+    pub command: String,
+
+    /// Run in the `console` pool, with direct access to the terminal.
+    pub console: bool,
+}
+
+/// The build statements and default targets from a Ninja file.
+#[derive(Debug, Default)]
+pub struct BuildGraph {
+    pub edges: Vec<Edge>,
+    pub defaults: Vec<String>,
+}
+
+/// A build statement before we expand its command.
+struct RawEdge {
+    rule: String,
+    outputs: Vec<String>,
+    inputs: Vec<String>,
+    implicit: Vec<String>,
+    order_only: Vec<String>,
+    bindings: HashMap<String, String>,
+}
+
+/// The most deeply nested variable references we will expand, to catch cyclic definitions.
+const MAX_EXPANSION_DEPTH: usize = 64;
+
+/// Expand the `$` escapes and variable references in a Ninja string.
+fn expand(text: &str, lookup: &dyn Fn(&str) -> String) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            out.push(c);
+            continue;
+        }
+        match chars.peek().copied() {
+            Some('{') => {
+                chars.next();
+                let name: String =
+                    chars.by_ref().take_while(|c| *c != '}').collect();
+                out.push_str(&lookup(&name));
+            }
+            Some(c) if is_var_char(c) => {
+                let mut name = String::new();
+                while let Some(c) = chars.next_if(|c| is_var_char(*c)) {
+                    name.push(c);
+                }
+                out.push_str(&lookup(&name));
+            }
+            Some(c) => {
+                // `$$`, `$ `, and `$:` escape the second character.
+                chars.next();
+                out.push(c);
+            }
+            None => out.push('$'),
+        }
+    }
+    out
+}
+
+/// Characters that can appear in an unbracketed variable reference.
+fn is_var_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Split a list of paths on unescaped spaces, without expanding anything.
+fn split_paths(text: &str) -> Vec<&str> {
+    let mut paths = vec![];
+    let mut start = None;
+    let mut chars = text.char_indices();
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            ' ' => {
+                if let Some(s) = start.take() {
+                    paths.push(&text[s..idx]);
+                }
+            }
+            '$' => {
+                start.get_or_insert(idx);
+                chars.next();
+            }
+            _ => {
+                start.get_or_insert(idx);
+            }
+        }
+    }
+    if let Some(s) = start {
+        paths.push(&text[s..]);
+    }
+    paths
+}
+
+/// Find the first unescaped `:` in a build statement.
+fn find_colon(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices();
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            ':' => return Some(idx),
+            '$' => {
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Join lines ending in a `$` escape with the following line.
+fn logical_lines(text: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut current: Option<String> = None;
+    for line in text.lines() {
+        let line = match &mut current {
+            Some(cur) => {
+                cur.push_str(line.trim_start());
+                std::mem::take(cur)
+            }
+            None => line.to_string(),
+        };
+        current = None;
+
+        // A trailing `$` continues the line, unless it is itself escaped.
+        let dollars = line.len() - line.trim_end_matches('$').len();
+        if dollars % 2 == 1 {
+            current = Some(line[..line.len() - 1].to_string());
+        } else {
+            lines.push(line);
+        }
+    }
+    lines.extend(current);
+    lines
+}
+
+/// Parse a `name = value` binding.
+fn parse_binding(line: &str) -> Result<(&str, &str), RunError> {
+    let (name, value) = line
+        .split_once('=')
+        .ok_or_else(|| RunError::InvalidBuild(format!("bad line: {}", line)))?;
+    Ok((name.trim(), value.trim_start()))
+}
+
+impl BuildGraph {
+    /// Parse the Ninja code for a build.
+    pub fn parse(ninja: &str) -> Result<Self, RunError> {
+        let mut vars: HashMap<String, String> = HashMap::new();
+        let mut rules: HashMap<String, HashMap<String, String>> =
+            HashMap::new();
+        let mut raw_edges: Vec<RawEdge> = vec![];
+        let mut defaults = vec![];
+
+        // Indented lines add bindings to the preceding rule or build statement.
+        enum Scope {
+            File,
+            Rule(String),
+            Build,
+            Pool,
+        }
+        let mut scope = Scope::File;
+
+        for line in logical_lines(ninja) {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with(' ') {
+                let (name, value) = parse_binding(trimmed)?;
+                match &scope {
+                    Scope::Rule(rule) => {
+                        rules
+                            .get_mut(rule)
+                            .unwrap()
+                            .insert(name.to_string(), value.to_string());
+                    }
+                    Scope::Build => {
+                        // Build bindings are expanded in the file scope right away.
+                        let value = expand(value, &|n| {
+                            vars.get(n).cloned().unwrap_or_default()
+                        });
+                        raw_edges
+                            .last_mut()
+                            .unwrap()
+                            .bindings
+                            .insert(name.to_string(), value);
+                    }
+                    Scope::Pool => {}
+                    Scope::File => {
+                        return Err(RunError::InvalidBuild(format!(
+                            "unexpected indented line: {}",
+                            trimmed
+                        )))
+                    }
+                }
+                continue;
+            }
+
+            let (keyword, rest) =
+                trimmed.split_once(' ').unwrap_or((trimmed, ""));
+            match keyword {
+                "rule" => {
+                    let name = rest.trim().to_string();
+                    rules.insert(name.clone(), HashMap::new());
+                    scope = Scope::Rule(name);
+                }
+                "build" => {
+                    raw_edges.push(Self::parse_build(rest, &vars)?);
+                    scope = Scope::Build;
+                }
+                "default" => {
+                    let lookup =
+                        |n: &str| vars.get(n).cloned().unwrap_or_default();
+                    defaults.extend(
+                        split_paths(rest).iter().map(|p| expand(p, &lookup)),
+                    );
+                    scope = Scope::File;
+                }
+                "pool" => {
+                    scope = Scope::Pool;
+                }
+                "include" | "subninja" => {
+                    return Err(RunError::InvalidBuild(format!(
+                        "`{}` is not supported",
+                        keyword
+                    )));
+                }
+                _ => {
+                    // Top-level variables are expanded as they are defined.
+                    let (name, value) = parse_binding(trimmed)?;
+                    let value = expand(value, &|n| {
+                        vars.get(n).cloned().unwrap_or_default()
+                    });
+                    vars.insert(name.to_string(), value);
+                    scope = Scope::File;
+                }
+            }
+        }
+
+        // Expand the commands, now that all the variables are defined.
+        let edges = raw_edges
+            .into_iter()
+            .map(|raw| {
+                let rule = rules.get(&raw.rule).ok_or_else(|| {
+                    RunError::InvalidBuild(format!("unknown rule {}", raw.rule))
+                })?;
+                let command = Self::edge_var(&raw, rule, &vars, "command", 0);
+                let pool = raw.bindings.get("pool").or(rule.get("pool"));
+                let console = pool.map(String::as_str) == Some("console");
+                Ok(Edge {
+                    rule: raw.rule,
+                    outputs: raw.outputs,
+                    inputs: raw.inputs,
+                    implicit: raw.implicit,
+                    order_only: raw.order_only,
+                    command,
+                    console,
+                })
+            })
+            .collect::<Result<_, RunError>>()?;
+
+        Ok(Self { edges, defaults })
+    }
+
+    /// Parse the part of a build statement after the `build` keyword.
+    fn parse_build(
+        text: &str,
+        vars: &HashMap<String, String>,
+    ) -> Result<RawEdge, RunError> {
+        let colon = find_colon(text).ok_or_else(|| {
+            RunError::InvalidBuild(format!("missing `:` in build {}", text))
+        })?;
+        let lookup = |n: &str| vars.get(n).cloned().unwrap_or_default();
+        let expand_all = |paths: &[&str]| -> Vec<String> {
+            paths.iter().map(|p| expand(p, &lookup)).collect()
+        };
+
+        // Implicit outputs (after a `|`) are just outputs as far as we're concerned.
+        let outputs: Vec<&str> = split_paths(&text[..colon])
+            .into_iter()
+            .filter(|p| *p != "|")
+            .collect();
+
+        let mut deps = split_paths(&text[colon + 1..]).into_iter();
+        let rule = deps.next().ok_or_else(|| {
+            RunError::InvalidBuild(format!("missing rule in build {}", text))
+        })?;
+        let (mut inputs, mut implicit, mut order_only) =
+            (vec![], vec![], vec![]);
+        let mut section = &mut inputs;
+        for dep in deps {
+            match dep {
+                "|" => section = &mut implicit,
+                "||" => section = &mut order_only,
+                _ => section.push(dep),
+            }
+        }
+
+        Ok(RawEdge {
+            rule: rule.to_string(),
+            outputs: expand_all(&outputs),
+            inputs: expand_all(&inputs),
+            implicit: expand_all(&implicit),
+            order_only: expand_all(&order_only),
+            bindings: HashMap::new(),
+        })
+    }
+
+    /// Look up a variable in the scope of a build statement: the special `$in` and `$out`
+    /// variables, then the statement's own bindings, then the rule's, then the file's.
+    fn edge_var(
+        raw: &RawEdge,
+        rule: &HashMap<String, String>,
+        vars: &HashMap<String, String>,
+        name: &str,
+        depth: usize,
+    ) -> String {
+        match name {
+            "in" => raw.inputs.join(" "),
+            "out" => raw.outputs.join(" "),
+            _ => {
+                if let Some(value) = raw.bindings.get(name) {
+                    value.clone()
+                } else if let Some(value) = rule.get(name) {
+                    if depth >= MAX_EXPANSION_DEPTH {
+                        return String::new();
+                    }
+                    expand(value, &|n| {
+                        Self::edge_var(raw, rule, vars, n, depth + 1)
+                    })
+                } else {
+                    vars.get(name).cloned().unwrap_or_default()
+                }
+            }
+        }
+    }
+}
+
+/// The progress of a build statement during execution.
+#[derive(Clone, Copy, PartialEq)]
+enum Status {
+    /// Waiting for dependencies to finish.
+    Waiting,
+    /// Running, or ready to run.
+    Ready,
+    /// Done, either because we ran the command or because the outputs were up to date.
+    Done { ran: bool },
+}
+
+/// Executes a [BuildGraph] in a directory, running independent commands in parallel.
+pub struct Executor<'a> {
+    graph: &'a BuildGraph,
+    dir: &'a Utf8Path,
+
+    /// The maximum number of commands to run at once.
+    pub jobs: usize,
+
+    /// Print each command as we run it.
+    pub verbose: bool,
+}
+
+impl<'a> Executor<'a> {
+    pub fn new(graph: &'a BuildGraph, dir: &'a Utf8Path) -> Self {
+        let jobs = std::thread::available_parallelism().map_or(1, |n| n.get());
+        Self {
+            graph,
+            dir,
+            jobs,
+            verbose: false,
+        }
+    }
+
+    /// The modification time of a file in the build directory, if it exists.
+    fn mtime(&self, file: &str) -> Option<SystemTime> {
+        let path: Utf8PathBuf = self.dir.join(file);
+        std::fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+
+    /// Find the build statements needed to produce `targets`, and the statements that each one
+    /// depends on.
+    fn needed_edges(
+        &self,
+        producers: &HashMap<&str, usize>,
+        targets: &[String],
+    ) -> HashMap<usize, HashSet<usize>> {
+        let mut needed: HashMap<usize, HashSet<usize>> = HashMap::new();
+        let mut worklist: Vec<usize> = targets
+            .iter()
+            .filter_map(|t| producers.get(t.as_str()).copied())
+            .collect();
+        while let Some(idx) = worklist.pop() {
+            if needed.contains_key(&idx) {
+                continue;
+            }
+            let edge = &self.graph.edges[idx];
+            let deps: HashSet<usize> = edge
+                .inputs
+                .iter()
+                .chain(&edge.implicit)
+                .chain(&edge.order_only)
+                .filter_map(|f| producers.get(f.as_str()).copied())
+                .collect();
+            worklist.extend(&deps);
+            needed.insert(idx, deps);
+        }
+        needed
+    }
+
+    /// Check whether a build statement needs to run, given that its dependencies are done.
+    fn is_stale(
+        &self,
+        edge: &Edge,
+        producers: &HashMap<&str, usize>,
+        status: &HashMap<usize, Status>,
+    ) -> Result<bool, RunError> {
+        let mut newest_input = None;
+        for input in edge.inputs.iter().chain(&edge.implicit) {
+            // Anything downstream of a command we just ran must also run.
+            if let Some(dep) = producers.get(input.as_str()) {
+                if status.get(dep) == Some(&Status::Done { ran: true }) {
+                    return Ok(true);
+                }
+            }
+            match self.mtime(input) {
+                Some(time) => newest_input = newest_input.max(Some(time)),
+                None if producers.contains_key(input.as_str()) => {
+                    return Ok(true)
+                }
+                None => {
+                    return Err(RunError::InvalidBuild(format!(
+                        "missing input {}",
+                        input
+                    )))
+                }
+            }
+        }
+
+        // Missing outputs (including pseudo-targets) always need to be built.
+        let mut oldest_output = None;
+        for output in &edge.outputs {
+            match self.mtime(output) {
+                Some(time) => {
+                    oldest_output = Some(
+                        oldest_output.map_or(time, |t: SystemTime| t.min(time)),
+                    )
+                }
+                None => return Ok(true),
+            }
+        }
+        Ok(newest_input > oldest_output)
+    }
+
+    /// Start running a command on a new thread, which reports back on `done`.
+    fn spawn(
+        &self,
+        idx: usize,
+        done: &mpsc::Sender<(usize, std::io::Result<ExitStatus>)>,
+    ) {
+        let edge = &self.graph.edges[idx];
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(&edge.command).current_dir(self.dir);
+        if edge.console {
+            // Console commands talk to the user directly. Like the rest of the build output,
+            // their stdout goes to our stderr.
+            cmd.stdout(std::io::stderr());
+        } else {
+            cmd.stdin(Stdio::null());
+        }
+        let console = edge.console;
+        let done = done.clone();
+        std::thread::spawn(move || {
+            let result = if console {
+                cmd.status()
+            } else {
+                // Buffer the output so that parallel commands don't interleave.
+                cmd.output().map(|output| {
+                    eprint!("{}", String::from_utf8_lossy(&output.stdout));
+                    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+                    output.status
+                })
+            };
+            // The receiver only goes away once the build is over.
+            let _ = done.send((idx, result));
+        });
+    }
+
+    /// Build the default targets, or every target if there are no defaults.
+    pub fn run(&self) -> Result<(), RunError> {
+        let graph = self.graph;
+        let mut producers: HashMap<&str, usize> = HashMap::new();
+        for (idx, edge) in graph.edges.iter().enumerate() {
+            for output in &edge.outputs {
+                producers.insert(output, idx);
+            }
+        }
+        let targets: Vec<String> = if graph.defaults.is_empty() {
+            graph.edges.iter().flat_map(|e| e.outputs.clone()).collect()
+        } else {
+            graph.defaults.clone()
+        };
+        let needed = self.needed_edges(&producers, &targets);
+
+        // Track which statements are waiting on each one.
+        let mut dependents: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut pending: HashMap<usize, usize> = HashMap::new();
+        for (idx, deps) in &needed {
+            pending.insert(*idx, deps.len());
+            for dep in deps {
+                dependents.entry(*dep).or_default().push(*idx);
+            }
+        }
+        let mut status: HashMap<usize, Status> =
+            needed.keys().map(|idx| (*idx, Status::Waiting)).collect();
+
+        // Start with the statements that have no dependencies, in file order.
+        let mut ready: Vec<usize> = pending
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(idx, _)| *idx)
+            .collect();
+        ready.sort_unstable_by(|a, b| b.cmp(a));
+        for idx in &ready {
+            status.insert(*idx, Status::Ready);
+        }
+
+        let (send, recv) = mpsc::channel();
+        let total = needed.len();
+        let mut finished = 0;
+        let mut running = 0;
+        let mut console_running = false;
+        let mut failure: Option<RunError> = None;
+
+        loop {
+            // Launch as many ready commands as we can.
+            while failure.is_none() && running < self.jobs.max(1) {
+                // Only one console command can run at a time.
+                let Some(pos) = ready.iter().rposition(|idx| {
+                    !(console_running && graph.edges[*idx].console)
+                }) else {
+                    break;
+                };
+                let idx = ready.remove(pos);
+                let edge = &graph.edges[idx];
+                match self.is_stale(edge, &producers, &status) {
+                    Ok(true) => {
+                        if self.verbose {
+                            eprintln!(
+                                "[{}/{}] {}",
+                                finished + 1,
+                                total,
+                                edge.command
+                            );
+                        }
+                        log::info!(
+                            "running {} for {}",
+                            edge.rule,
+                            edge.outputs.join(" ")
+                        );
+                        self.spawn(idx, &send);
+                        running += 1;
+                        console_running |= edge.console;
+                    }
+                    Ok(false) => {
+                        // Up to date; finish it without running anything.
+                        log::info!("{} is up to date", edge.outputs.join(" "));
+                        status.insert(idx, Status::Done { ran: false });
+                        let _ = send.send((idx, Ok(ExitStatus::default())));
+                        running += 1;
+                    }
+                    Err(e) => failure = Some(e),
+                }
+            }
+
+            if running == 0 {
+                break;
+            }
+
+            // Wait for a command to finish.
+            let (idx, result) = recv.recv().expect("executor channel closed");
+            running -= 1;
+            finished += 1;
+            let edge = &graph.edges[idx];
+            let ran = status[&idx] == Status::Ready;
+            if ran && edge.console {
+                console_running = false;
+            }
+            match result {
+                Ok(exit) if exit.success() => {
+                    status.insert(idx, Status::Done { ran });
+                    for dep in dependents.get(&idx).into_iter().flatten() {
+                        let count = pending.get_mut(dep).unwrap();
+                        *count -= 1;
+                        if *count == 0 {
+                            status.insert(*dep, Status::Ready);
+                            ready.insert(0, *dep);
+                        }
+                    }
+                }
+                Ok(exit) => {
+                    failure.get_or_insert(RunError::BuildFailed(
+                        edge.outputs.join(" "),
+                        exit,
+                    ));
+                }
+                Err(e) => {
+                    failure.get_or_insert(RunError::Io(e));
+                }
+            }
+        }
+
+        match failure {
+            Some(e) => Err(e),
+            // Anything left waiting must depend on itself.
+            None if finished < total => {
+                Err(RunError::InvalidBuild("dependency cycle".to_string()))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run::TempDir;
+    use rand::distributions::{Alphanumeric, DistString};
+
+    /// Copies its input and records the output it built in `log.txt`.
+    const COPY: &str = "rule copy
+  command = echo $out >> log.txt && cp $in $out
+";
+
+    /// A fresh build directory, which is deleted when the [TempDir] is dropped.
+    fn build_dir() -> (TempDir, Utf8PathBuf) {
+        let name = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+        let path = std::env::temp_dir().join(format!("fud-exec-{name}"));
+        let path = Utf8PathBuf::from_path_buf(path).unwrap();
+        (TempDir::new(&path, false).unwrap(), path)
+    }
+
+    fn exec(dir: &Utf8Path, ninja: &str) -> Result<(), RunError> {
+        let graph = BuildGraph::parse(ninja)?;
+        let mut executor = Executor::new(&graph, dir);
+        executor.jobs = 4;
+        executor.run()
+    }
+
+    /// The outputs built since the log was last cleared, in the order they were built.
+    fn take_log(dir: &Utf8Path) -> String {
+        let path = dir.join("log.txt");
+        let log = std::fs::read_to_string(&path).unwrap_or_default();
+        let _ = std::fs::remove_file(path);
+        log
+    }
+
+    #[test]
+    fn dependencies_run_first() {
+        let (_tmp, dir) = build_dir();
+        std::fs::write(dir.join("a.txt"), "hello").unwrap();
+
+        // The statements are listed in the opposite order to their dependencies.
+        let ninja = format!(
+            "{COPY}
+build c.txt: copy b.txt
+build b.txt: copy a.txt
+default c.txt
+"
+        );
+        exec(&dir, &ninja).unwrap();
+        assert_eq!(take_log(&dir), "b.txt\nc.txt\n");
+        assert_eq!(
+            std::fs::read_to_string(dir.join("c.txt")).unwrap(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn up_to_date_outputs_are_skipped() {
+        let (_tmp, dir) = build_dir();
+        std::fs::write(dir.join("a.txt"), "hello").unwrap();
+        let ninja = format!(
+            "{COPY}
+build b.txt: copy a.txt
+build c.txt: copy b.txt
+default c.txt
+"
+        );
+        exec(&dir, &ninja).unwrap();
+        assert_eq!(take_log(&dir), "b.txt\nc.txt\n");
+
+        // Nothing changed, so nothing runs.
+        exec(&dir, &ninja).unwrap();
+        assert_eq!(take_log(&dir), "");
+
+        // Rebuilding a missing file also rebuilds everything downstream of it.
+        std::fs::remove_file(dir.join("b.txt")).unwrap();
+        exec(&dir, &ninja).unwrap();
+        assert_eq!(take_log(&dir), "b.txt\nc.txt\n");
+    }
+
+    #[test]
+    fn failed_command_stops_build() {
+        let (_tmp, dir) = build_dir();
+        std::fs::write(dir.join("a.txt"), "hello").unwrap();
+        let ninja = format!(
+            "{COPY}
+rule fail
+  command = exit 3
+
+build bad.txt: fail a.txt
+build c.txt: copy bad.txt
+default c.txt
+"
+        );
+        match exec(&dir, &ninja) {
+            Err(RunError::BuildFailed(target, status)) => {
+                assert_eq!(target, "bad.txt");
+                assert_eq!(status.code(), Some(3));
+            }
+            res => panic!("expected a failed build, got {res:?}"),
+        }
+        assert_eq!(take_log(&dir), "");
+        assert!(!dir.join("c.txt").exists());
+    }
+}
@@ -6,7 +6,10 @@ use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use std::process::{Command, ExitStatus};
 
-/// An error that arises while emitting the Ninja file or executing Ninja.
+mod local;
+pub use local::{BuildGraph, Edge, Executor};
+
+/// An error that arises while emitting the Ninja file or executing the build.
 #[derive(Debug)]
 pub enum RunError {
     /// An IO error when writing the Ninja file.
@@ -17,6 +20,12 @@ pub enum RunError {
 
     /// The Ninja process exited with nonzero status.
     NinjaFailed(ExitStatus),
+
+    /// The built-in executor could not understand the build.
+    InvalidBuild(String),
+
+    /// A command run by the built-in executor exited with nonzero status.
+    BuildFailed(String, ExitStatus),
 }
 
 impl From<std::io::Error> for RunError {
@@ -35,6 +44,14 @@ impl std::fmt::Display for RunError {
             RunError::NinjaFailed(c) => {
                 write!(f, "ninja exited with {}", c)
             }
+            RunError::InvalidBuild(s) => write!(f, "invalid build: {}", s),
+            RunError::BuildFailed(target, c) => {
+                write!(
+                    f,
+                    "building {} failed: command exited with {}",
+                    target, c
+                )
+            }
         }
     }
 }
@@ -44,23 +61,26 @@ impl std::error::Error for RunError {}
 pub type EmitResult = std::result::Result<(), RunError>;
 
 /// Code to emit a Ninja `build` command.
+///
+/// The `input` and `output` files correspond, in order, to the input and output states of the
+/// operation.
 pub trait EmitBuild {
     fn build(
         &self,
         emitter: &mut StreamEmitter,
-        input: &str,
-        output: &str,
+        input: &[&str],
+        output: &[&str],
     ) -> EmitResult;
 }
 
-pub type EmitBuildFn = fn(&mut StreamEmitter, &str, &str) -> EmitResult;
+pub type EmitBuildFn = fn(&mut StreamEmitter, &[&str], &[&str]) -> EmitResult;
 
 impl EmitBuild for EmitBuildFn {
     fn build(
         &self,
         emitter: &mut StreamEmitter,
-        input: &str,
-        output: &str,
+        input: &[&str],
+        output: &[&str],
     ) -> EmitResult {
         self(emitter, input, output)
     }
@@ -76,10 +96,10 @@ impl EmitBuild for EmitRuleBuild {
     fn build(
         &self,
         emitter: &mut StreamEmitter,
-        input: &str,
-        output: &str,
+        input: &[&str],
+        output: &[&str],
     ) -> EmitResult {
-        emitter.build(&self.rule_name, input, output)?;
+        emitter.build_cmd(output, &self.rule_name, input, &[])?;
         Ok(())
     }
 }
@@ -128,15 +148,40 @@ impl<'a> Run<'a> {
     /// Just print the plan for debugging purposes.
     pub fn show(self) {
         if self.plan.stdin {
-            println!("(stdin) -> {}", self.plan.start);
+            println!("(stdin) -> {}", self.plan.inputs[0]);
         } else {
-            println!("start: {}", self.plan.start);
+            println!("start: {}", join_paths(&self.plan.inputs));
         }
-        for (op, file) in self.plan.steps {
-            println!("{}: {} -> {}", op, self.driver.ops[op].name, file);
+        for step in &self.plan.steps {
+            println!(
+                "{}: {} {} -> {}",
+                step.op,
+                self.driver.ops[step.op].name,
+                join_paths(&step.inputs),
+                join_paths(&step.outputs)
+            );
         }
         if self.plan.stdout {
             println!("-> (stdout)");
+        } else {
+            println!("results: {}", join_paths(&self.plan.results));
+        }
+
+        // Explain the choices the planner made among alternative operations.
+        if !self.plan.alternatives.is_empty() {
+            println!("rejected:");
+        }
+        for alt in &self.plan.alternatives {
+            let (chosen, chosen_cost) = alt.chosen;
+            let (rejected, rejected_cost) = alt.rejected;
+            println!(
+                "  {} for {} (cost {}), in favor of {} (cost {})",
+                self.driver.ops[rejected].name,
+                self.driver.states[alt.state].name,
+                rejected_cost,
+                self.driver.ops[chosen].name,
+                chosen_cost,
+            );
         }
     }
 
@@ -149,14 +194,17 @@ impl<'a> Run<'a> {
         // Record the states and ops that are actually used in the plan.
         let mut states: HashMap<StateRef, String> = HashMap::new();
         let mut ops: HashSet<OpRef> = HashSet::new();
-        let first_op = self.plan.steps[0].0;
-        states.insert(
-            self.driver.ops[first_op].input,
-            self.plan.start.to_string(),
-        );
-        for (op, file) in &self.plan.steps {
-            states.insert(self.driver.ops[*op].output, file.to_string());
-            ops.insert(*op);
+        for step in &self.plan.steps {
+            let op = &self.driver.ops[step.op];
+            let files = op
+                .input
+                .iter()
+                .zip(&step.inputs)
+                .chain(op.output.iter().zip(&step.outputs));
+            for (state, file) in files {
+                states.insert(*state, file.to_string());
+            }
+            ops.insert(step.op);
         }
 
         // Show all states.
@@ -173,13 +221,17 @@ impl<'a> Run<'a> {
             println!("];");
         }
 
-        // Show all operations.
+        // Show all operations, with an edge from each input to each output.
         for (op_ref, op) in self.driver.ops.iter() {
-            print!("  {} -> {} [label=\"{}\"", op.input, op.output, op.name);
-            if ops.contains(&op_ref) {
-                print!(" penwidth=3");
+            for input in &op.input {
+                for output in &op.output {
+                    print!("  {} -> {} [label=\"{}\"", input, output, op.name);
+                    if ops.contains(&op_ref) {
+                        print!(" penwidth=3");
+                    }
+                    println!("];");
+                }
             }
-            println!("];");
         }
 
         println!("}}");
@@ -205,17 +257,7 @@ impl<'a> Run<'a> {
     pub fn emit_and_run(&self, dir: &Utf8Path) -> EmitResult {
         // Emit the Ninja file.
         let dir = self.emit_to_dir(dir)?;
-
-        // Capture stdin.
-        if self.plan.stdin {
-            let stdin_file = std::fs::File::create(
-                self.plan.workdir.join(&self.plan.start),
-            )?;
-            std::io::copy(
-                &mut std::io::stdin(),
-                &mut std::io::BufWriter::new(stdin_file),
-            )?;
-        }
+        self.capture_stdin()?;
 
         // Run `ninja` in the working directory.
         let mut cmd = Command::new(&self.global_config.ninja);
@@ -233,20 +275,57 @@ impl<'a> Run<'a> {
         let status = cmd.status()?;
 
         // Emit stdout, only when Ninja succeeded.
-        if status.success() && self.plan.stdout {
-            let stdout_file =
-                std::fs::File::open(self.plan.workdir.join(self.plan.end()))?;
+        if status.success() {
+            self.emit_stdout()
+        } else {
+            Err(RunError::NinjaFailed(status))
+        }
+    }
+
+    /// Emit `build.ninja` to a temporary directory and then execute it with the built-in
+    /// executor, without using `ninja`.
+    pub fn emit_and_exec(&self, dir: &Utf8Path) -> EmitResult {
+        let dir = self.emit_to_dir(dir)?;
+        self.capture_stdin()?;
+
+        let ninja = std::fs::read_to_string(dir.path.join("build.ninja"))?;
+        let graph = BuildGraph::parse(&ninja)?;
+        let mut executor = Executor::new(&graph, &dir.path);
+        executor.verbose = self.global_config.verbose;
+        if let Some(jobs) = self.global_config.jobs {
+            executor.jobs = jobs;
+        }
+        executor.run()?;
+
+        self.emit_stdout()
+    }
+
+    /// Write our stdin to the input file, if the plan reads from stdin.
+    fn capture_stdin(&self) -> EmitResult {
+        if self.plan.stdin {
+            let stdin_file = std::fs::File::create(
+                self.plan.workdir.join(&self.plan.inputs[0]),
+            )?;
             std::io::copy(
-                &mut std::io::BufReader::new(stdout_file),
-                &mut std::io::stdout(),
+                &mut std::io::stdin(),
+                &mut std::io::BufWriter::new(stdin_file),
             )?;
         }
+        Ok(())
+    }
 
-        if status.success() {
-            Ok(())
-        } else {
-            Err(RunError::NinjaFailed(status))
+    /// Copy the result to our stdout, if the plan writes to stdout.
+    fn emit_stdout(&self) -> EmitResult {
+        if self.plan.stdout {
+            let stdout_file = std::fs::File::open(
+                self.plan.workdir.join(&self.plan.results[0]),
+            )?;
+            std::io::copy(
+                &mut std::io::BufReader::new(stdout_file),
+                &mut std::io::stdout(),
+            )?;
         }
+        Ok(())
     }
 
     pub fn emit<T: Write + 'a>(&self, out: T) -> EmitResult {
@@ -263,8 +342,8 @@ impl<'a> Run<'a> {
 
         // Emit the setup for each operation used in the plan, only once.
         let mut done_setups = HashSet::<SetupRef>::new();
-        for (op, _) in &self.plan.steps {
-            for setup in &self.driver.ops[*op].setups {
+        for step in &self.plan.steps {
+            for setup in &self.driver.ops[step.op].setups {
                 if done_setups.insert(*setup) {
                     let setup = &self.driver.setups[*setup];
                     writeln!(emitter.out, "# {}", setup.name)?;
@@ -276,20 +355,18 @@ impl<'a> Run<'a> {
 
         // Emit the build commands for each step in the plan.
         emitter.comment("build targets")?;
-        let mut last_file = &self.plan.start;
-        for (op, out_file) in &self.plan.steps {
-            let op = &self.driver.ops[*op];
-            op.emit.build(
-                &mut emitter,
-                last_file.as_str(),
-                out_file.as_str(),
-            )?;
-            last_file = out_file;
+        for step in &self.plan.steps {
+            let op = &self.driver.ops[step.op];
+            let inputs: Vec<&str> =
+                step.inputs.iter().map(|f| f.as_str()).collect();
+            let outputs: Vec<&str> =
+                step.outputs.iter().map(|f| f.as_str()).collect();
+            op.emit.build(&mut emitter, &inputs, &outputs)?;
         }
         writeln!(emitter.out)?;
 
-        // Mark the last file as the default target.
-        writeln!(emitter.out, "default {}", last_file)?;
+        // Mark the results as the default targets.
+        writeln!(emitter.out, "default {}", join_paths(&self.plan.results))?;
 
         Ok(())
     }
@@ -451,6 +528,15 @@ impl<W: Write> Emitter<W> {
     }
 }
 
+/// A space-separated list of paths.
+fn join_paths(paths: &[Utf8PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|p| p.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Check whether a Ninja executable supports the `--quiet` flag.
 fn ninja_supports_quiet(ninja: &str) -> std::io::Result<bool> {
     let version_output = Command::new(ninja).arg("--version").output()?;
@@ -11,6 +11,8 @@ pub(super) struct RhaiSystemError {
 #[derive(Debug)]
 pub(super) enum RhaiSystemErrorKind {
     ErrorSetupRef(String),
+    ErrorStateRef(String),
+    ErrorCost(rhai::INT),
 }
 
 impl RhaiSystemError {
@@ -21,6 +23,20 @@ impl RhaiSystemError {
         }
     }
 
+    pub(super) fn state_ref(v: rhai::Dynamic) -> Self {
+        Self {
+            kind: RhaiSystemErrorKind::ErrorStateRef(v.to_string()),
+            position: rhai::Position::NONE,
+        }
+    }
+
+    pub(super) fn cost(v: rhai::INT) -> Self {
+        Self {
+            kind: RhaiSystemErrorKind::ErrorCost(v),
+            position: rhai::Position::NONE,
+        }
+    }
+
     pub(super) fn with_pos(mut self, p: rhai::Position) -> Self {
         self.position = p;
         self
@@ -33,6 +49,12 @@ impl Display for RhaiSystemError {
             RhaiSystemErrorKind::ErrorSetupRef(v) => {
                 write!(f, "Unable to construct SetupRef: `{v:?}`")
             }
+            RhaiSystemErrorKind::ErrorStateRef(v) => {
+                write!(f, "Unable to construct StateRef: `{v:?}`")
+            }
+            RhaiSystemErrorKind::ErrorCost(v) => {
+                write!(f, "Operation cost must be a positive integer: `{v}`")
+            }
         }
     }
 }
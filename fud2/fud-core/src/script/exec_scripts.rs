@@ -38,6 +38,10 @@ pub(super) fn to_str_slice(arr: &rhai::Array) -> Vec<String> {
         .collect()
 }
 
+fn to_rhai_array(strs: &[&str]) -> rhai::Array {
+    strs.iter().map(|s| (*s).into()).collect()
+}
+
 impl RhaiEmitter {
     fn config_val(&mut self, key: &str) -> RhaiResult<String> {
         self.0.borrow().config_val(key).map_err(to_rhai_err)
@@ -167,6 +171,9 @@ pub(super) struct RhaiSetupCtx {
     pub path: Rc<PathBuf>,
     pub ast: Rc<rhai::AST>,
     pub name: String,
+    /// Pass the input and output files of a build as arrays rather than single strings. This
+    /// is set for operations declared with several input or output states.
+    pub multi: bool,
 }
 
 impl EmitSetup for RhaiSetupCtx {
@@ -191,16 +198,21 @@ impl EmitBuild for RhaiSetupCtx {
     fn build(
         &self,
         emitter: &mut StreamEmitter,
-        input: &str,
-        output: &str,
+        input: &[&str],
+        output: &[&str],
     ) -> EmitResult {
+        let (input, output): (rhai::Dynamic, rhai::Dynamic) = if self.multi {
+            (to_rhai_array(input).into(), to_rhai_array(output).into())
+        } else {
+            (input[0].into(), output[0].into())
+        };
         RhaiEmitter::with(emitter, |rhai_emit| {
             EMIT_ENGINE.with(|e| {
                 e.call_fn::<()>(
                     &mut rhai::Scope::new(),
                     &self.ast,
                     &self.name,
-                    (rhai_emit.clone(), input.to_string(), output.to_string()),
+                    (rhai_emit.clone(), input.clone(), output.clone()),
                 )
                 .report(self.path.as_ref())
             });
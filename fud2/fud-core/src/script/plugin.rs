@@ -39,6 +39,7 @@ impl ScriptContext {
                         path: Rc::clone(&self.path),
                         ast: Rc::new(self.ast.clone_functions_only()),
                         name: fnptr.fn_name().to_string(),
+                        multi: false,
                     };
                     Ok(self.builder.borrow_mut().add_setup(
                         &format!("{} (plugin)", fnptr.fn_name()),
@@ -56,6 +57,35 @@ impl ScriptContext {
             })
             .collect::<RhaiResult<Vec<_>>>()
     }
+
+    /// Take a Rhai array value that is supposed to contain states and produce an array of
+    /// actual references to states.
+    fn states_array(
+        &self,
+        ctx: &rhai::NativeCallContext,
+        states: rhai::Array,
+    ) -> RhaiResult<Vec<StateRef>> {
+        states
+            .into_iter()
+            .map(|s| {
+                s.clone().try_cast::<StateRef>().ok_or_else(move || {
+                    RhaiSystemError::state_ref(s)
+                        .with_pos(ctx.position())
+                        .into()
+                })
+            })
+            .collect::<RhaiResult<Vec<_>>>()
+    }
+
+    /// The context for running the build function `build` of an operation.
+    fn build_ctx(&self, build: &rhai::FnPtr, multi: bool) -> RhaiSetupCtx {
+        RhaiSetupCtx {
+            path: Rc::clone(&self.path),
+            ast: Rc::new(self.ast.clone_functions_only()),
+            name: build.fn_name().to_string(),
+            multi,
+        }
+    }
 }
 
 pub struct ScriptRunner {
@@ -76,6 +106,7 @@ impl ScriptRunner {
         this.reg_state();
         this.reg_get_state();
         this.reg_get_setup();
+        this.reg_set_cost();
         this
     }
 
@@ -143,6 +174,24 @@ impl ScriptRunner {
             });
     }
 
+    fn reg_set_cost(&mut self) {
+        let bld = Rc::clone(&self.builder);
+        self.engine.register_fn::<_, 2, true, (), true, _>(
+            "set_cost",
+            move |ctx: rhai::NativeCallContext, op: OpRef, cost: rhai::INT| {
+                match u32::try_from(cost) {
+                    Ok(c) if c > 0 => {
+                        bld.borrow_mut().set_cost(op, c);
+                        Ok(())
+                    }
+                    _ => Err(RhaiSystemError::cost(cost)
+                        .with_pos(ctx.position())
+                        .into()),
+                }
+            },
+        );
+    }
+
     fn reg_rule(&mut self, sctx: ScriptContext) {
         let bld = Rc::clone(&self.builder);
         self.engine.register_fn::<_, 4, true, OpRef, true, _>(
@@ -160,6 +209,7 @@ impl ScriptRunner {
 
     fn reg_op(&mut self, sctx: ScriptContext) {
         let bld = Rc::clone(&self.builder);
+        let op_sctx = sctx.clone();
         self.engine.register_fn::<_, 5, true, OpRef, true, _>(
             "op",
             move |ctx: rhai::NativeCallContext,
@@ -168,13 +218,35 @@ impl ScriptRunner {
                   input: StateRef,
                   output: StateRef,
                   build: rhai::FnPtr| {
+                let setups = op_sctx.setups_array(&ctx, setups)?;
+                let rctx = op_sctx.build_ctx(&build, false);
+                Ok(bld.borrow_mut().add_op(
+                    name,
+                    &setups,
+                    &[input],
+                    &[output],
+                    rctx,
+                ))
+            },
+        );
+
+        // An overload for operations with several inputs and outputs, given as arrays of states.
+        let bld = Rc::clone(&self.builder);
+        self.engine.register_fn::<_, 5, true, OpRef, true, _>(
+            "op",
+            move |ctx: rhai::NativeCallContext,
+                  name: &str,
+                  setups: rhai::Array,
+                  input: rhai::Array,
+                  output: rhai::Array,
+                  build: rhai::FnPtr| {
                 let setups = sctx.setups_array(&ctx, setups)?;
-                let rctx = RhaiSetupCtx {
-                    path: sctx.path.clone(),
-                    ast: Rc::new(sctx.ast.clone_functions_only()),
-                    name: build.fn_name().to_string(),
-                };
-                Ok(bld.borrow_mut().add_op(name, &setups, input, output, rctx))
+                let input = sctx.states_array(&ctx, input)?;
+                let output = sctx.states_array(&ctx, output)?;
+                let rctx = sctx.build_ctx(&build, true);
+                Ok(bld
+                    .borrow_mut()
+                    .add_op(name, &setups, &input, &output, rctx))
             },
         );
     }
@@ -33,7 +33,7 @@ fn setup_calyx(
         calyx,
         verilog,
         |e, input, output| {
-            e.build_cmd(&[output], "calyx", &[input], &[])?;
+            e.build_cmd(&[output[0]], "calyx", &[input[0]], &[])?;
             e.arg("backend", "verilog")?;
             Ok(())
         },
@@ -86,6 +86,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
     let dat = bld.state("dat", &["json"]);
     let vcd = bld.state("vcd", &["vcd"]);
     let simulator = bld.state("sim", &["exe"]);
+    let datadir = bld.state("datadir", &["data"]);
     let sim_setup = bld.setup("RTL simulation", |e| {
         // Data conversion to and from JSON.
         e.config_var_or("python", "python", "python3")?;
@@ -93,20 +94,6 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         e.rule("hex-data", "$python json-dat.py --from-json $in $out")?;
         e.rule("json-data", "$python json-dat.py --to-json $out $in")?;
 
-        // The input data file. `sim.data` is required.
-        let data_name = e.config_val("sim.data")?;
-        let data_path = e.external_path(data_name.as_ref());
-        e.var("sim_data", data_path.as_str())?;
-
-        // Produce the data directory.
-        e.var("datadir", "sim_data")?;
-        e.build_cmd(
-            &["$datadir"],
-            "hex-data",
-            &["$sim_data"],
-            &["json-dat.py"],
-        )?;
-
         // Rule for simulation execution.
         e.rule(
             "sim-run",
@@ -118,35 +105,60 @@ pub fn build_driver(bld: &mut DriverBuilder) {
 
         Ok(())
     });
+    // The directory of hex files is its own step so that simulating and tracing the same
+    // program share it.
     bld.op(
-        "simulate",
+        "hex-data",
         &[sim_setup],
-        simulator,
         dat,
+        datadir,
         |e, input, output| {
-            e.build_cmd(&["sim.log"], "sim-run", &[input, "$datadir"], &[])?;
-            e.arg("bin", input)?;
+            e.build_cmd(
+                &[output[0]],
+                "hex-data",
+                &[input[0]],
+                &["json-dat.py"],
+            )?;
+            Ok(())
+        },
+    );
+    bld.multi_op(
+        "simulate",
+        &[sim_setup],
+        &[simulator, datadir],
+        &[dat],
+        |e, input, output| {
+            e.build_cmd(&["sim.log"], "sim-run", &[input[0], input[1]], &[])?;
+            e.arg("bin", input[0])?;
+            e.arg("datadir", input[1])?;
             e.arg("args", "+NOTRACE=1")?;
             e.build_cmd(
-                &[output],
+                &[output[0]],
                 "json-data",
-                &["$datadir", "sim.log"],
+                &[input[1], "sim.log"],
                 &["json-dat.py"],
             )?;
             Ok(())
         },
     );
-    bld.op("trace", &[sim_setup], simulator, vcd, |e, input, output| {
-        e.build_cmd(
-            &["sim.log", output],
-            "sim-run",
-            &[input, "$datadir"],
-            &[],
-        )?;
-        e.arg("bin", input)?;
-        e.arg("args", &format!("+NOTRACE=0 +OUT={}", output))?;
-        Ok(())
-    });
+    bld.multi_op(
+        "trace",
+        &[sim_setup],
+        &[simulator, datadir],
+        &[vcd],
+        |e, input, output| {
+            e.build_cmd(
+                &["trace.log", output[0]],
+                "sim-run",
+                &[input[0], input[1]],
+                &[],
+            )?;
+            e.arg("bin", input[0])?;
+            e.arg("datadir", input[1])?;
+            e.arg("args", &format!("+NOTRACE=0 +OUT={}", output[0]))?;
+            Ok(())
+        },
+    );
 
     // The "verilog_refmem" states are variants of the other Verilog states that use the external testbench style.
     // "refmem" refers to the fact that their memories are external, meaning that they need to be linked with
@@ -207,7 +219,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         verilog_noverify,
         |e, input, output| {
             // Icarus requires a special --disable-verify version of Calyx code.
-            e.build_cmd(&[output], "calyx", &[input], &[])?;
+            e.build_cmd(&[output[0]], "calyx", &[input[0]], &[])?;
             e.arg("backend", "verilog")?;
             e.arg("args", "--disable-verify")?;
             Ok(())
@@ -221,9 +233,9 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         simulator,
         |e, input, output| {
             e.build_cmd(
-                &[output],
+                &[output[0]],
                 "icarus-compile-standalone-tb",
-                &[input],
+                &[input[0]],
                 &["tb.sv"],
             )?;
             Ok(())
@@ -236,30 +248,15 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         simulator,
         |e, input, output| {
             e.build_cmd(
-                &[output],
+                &[output[0]],
                 "icarus-compile-custom-tb",
-                &[input],
+                &[input[0]],
                 &["tb.sv", "memories.sv"],
             )?;
             Ok(())
         },
     );
 
-    // setup for FIRRTL-implemented primitives
-    let firrtl_primitives_setup = bld.setup("FIRRTL with primitives", |e| {
-        // Produce FIRRTL with FIRRTL-defined primitives.
-        e.var(
-            "gen-firrtl-primitives-script",
-            "$calyx-base/tools/firrtl/generate-firrtl-with-primitives.py",
-        )?;
-        e.rule(
-            "generate-firrtl-with-primitives",
-            "python3 $gen-firrtl-primitives-script $in > $out",
-        )?;
-
-        Ok(())
-    });
-
     fn calyx_to_firrtl_helper(
         e: &mut StreamEmitter,
         input: &str,
@@ -292,41 +289,13 @@ pub fn build_driver(bld: &mut DriverBuilder) {
             &[],
         )?;
 
+        e.build_cmd(&[tmp_out], "calyx", &[only_refs_calyx], &[])?;
+        e.arg("backend", "firrtl")?;
         if firrtl_primitives {
-            let core_program_firrtl = "core.fir";
-
-            // Obtain FIRRTL of core program
-            e.build_cmd(
-                &[core_program_firrtl],
-                "calyx",
-                &[only_refs_calyx],
-                &[],
-            )?;
-            e.arg("backend", "firrtl")?;
-            e.arg("args", "--synthesis")?;
-
-            // Obtain primitive uses JSON for metaprogramming
-            let primitive_uses_json = "primitive-uses.json";
-            e.build_cmd(
-                &[primitive_uses_json],
-                "calyx",
-                &[only_refs_calyx],
-                &[],
-            )?;
-            e.arg("backend", "primitive-uses")?;
-            e.arg("args", "--synthesis")?;
-
-            // run metaprogramming script to get FIRRTL with primitives
-            e.build_cmd(
-                &[tmp_out],
-                "generate-firrtl-with-primitives",
-                &[core_program_firrtl, primitive_uses_json],
-                &[],
-            )?;
+            // use FIRRTL implementations of primitives
+            e.arg("args", "--emit-firrtl-primitives")?;
         } else {
             // emit extmodule declarations to use Verilog primitive implementations
-            e.build_cmd(&[tmp_out], "calyx", &[only_refs_calyx], &[])?;
-            e.arg("backend", "firrtl")?;
             e.arg("args", "--emit-primitive-extmodules")?;
         }
 
@@ -345,15 +314,17 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         &[calyx_setup, custom_testbench_setup],
         calyx,
         firrtl,
-        |e, input, output| calyx_to_firrtl_helper(e, input, output, false),
+        |e, input, output| {
+            calyx_to_firrtl_helper(e, input[0], output[0], false)
+        },
     );
 
     bld.op(
         "firrtl-with-primitives",
-        &[calyx_setup, firrtl_primitives_setup, custom_testbench_setup],
+        &[calyx_setup, custom_testbench_setup],
         calyx,
         firrtl_with_primitives,
-        |e, input, output| calyx_to_firrtl_helper(e, input, output, true),
+        |e, input, output| calyx_to_firrtl_helper(e, input[0], output[0], true),
     );
 
     // The FIRRTL compiler.
@@ -397,7 +368,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         &[firrtl_setup],
         firrtl,
         verilog_refmem,
-        |e, input, output| firrtl_compile_helper(e, input, output, false),
+        |e, input, output| firrtl_compile_helper(e, input[0], output[0], false),
     );
     // FIRRTL --> Verilog compilation using Verilog primitive implementations for Icarus
     // This is a bit of a hack, but the Icarus-friendly "noverify" state is identical for this path
@@ -407,7 +378,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         &[firrtl_setup],
         firrtl,
         verilog_refmem_noverify,
-        |e, input, output| firrtl_compile_helper(e, input, output, false),
+        |e, input, output| firrtl_compile_helper(e, input[0], output[0], false),
     );
     // FIRRTL --> Verilog compilation using FIRRTL primitive implementations for Verilator
     bld.op(
@@ -415,7 +386,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         &[firrtl_setup],
         firrtl_with_primitives,
         verilog_refmem,
-        |e, input, output| firrtl_compile_helper(e, input, output, true),
+        |e, input, output| firrtl_compile_helper(e, input[0], output[0], true),
     );
     // FIRRTL --> Verilog compilation using FIRRTL primitive implementations for Icarus
     bld.op(
@@ -423,7 +394,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         &[firrtl_setup],
         firrtl_with_primitives,
         verilog_refmem_noverify,
-        |e, input, output| firrtl_compile_helper(e, input, output, true),
+        |e, input, output| firrtl_compile_helper(e, input[0], output[0], true),
     );
 
     // primitive-uses backend
@@ -434,7 +405,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         calyx,
         primitive_uses_json,
         |e, input, output| {
-            e.build_cmd(&[output], "calyx", &[input], &[])?;
+            e.build_cmd(&[output[0]], "calyx", &[input[0]], &[])?;
             e.arg("backend", "primitive-uses")?;
             Ok(())
         },
@@ -488,7 +459,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         &[sim_setup, standalone_testbench_setup, verilator_setup],
         verilog,
         simulator,
-        |e, input, output| verilator_build(e, input, output, true),
+        |e, input, output| verilator_build(e, input[0], output[0], true),
     );
 
     bld.op(
@@ -496,7 +467,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         &[sim_setup, custom_testbench_setup, verilator_setup],
         verilog_refmem,
         simulator,
-        |e, input, output| verilator_build(e, input, output, false),
+        |e, input, output| verilator_build(e, input[0], output[0], false),
     );
 
     // Interpreter.
@@ -530,12 +501,6 @@ pub fn build_driver(bld: &mut DriverBuilder) {
             "interp-to-dat",
             "$python interp-dat.py --from-interp $in $sim_data > $out",
         )?;
-        e.build_cmd(
-            &["data.json"],
-            "dat-to-interp",
-            &["$sim_data"],
-            &["interp-dat.py"],
-        )?;
 
         e.rule(
             "cider2",
@@ -543,17 +508,14 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         )?;
 
         e.rule("dump-to-interp", "$cider-converter --to cider $in > $out")?;
-        e.rule("interp-to-dump", "$cider-converter --to json $in > $out")?;
-        e.build_cmd(
-            &["data.dump"],
-            "dump-to-interp",
-            &["$sim_data"],
-            &["$cider-converter"],
+        e.rule(
+            "interp-to-dump",
+            "$cider-converter --to json --format $sim_data $in > $out",
         )?;
 
         Ok(())
     });
-    bld.op(
+    bld.multi_op(
         "interp",
         &[
             sim_setup,
@@ -561,38 +523,52 @@ pub fn build_driver(bld: &mut DriverBuilder) {
             calyx_setup,
             cider_setup,
         ],
-        calyx,
-        dat,
+        &[calyx, dat],
+        &[dat],
         |e, input, output| {
+            e.build_cmd(
+                &["data.json"],
+                "dat-to-interp",
+                &[input[1]],
+                &["interp-dat.py"],
+            )?;
             let out_file = "interp_out.json";
-            e.build_cmd(&[out_file], "cider", &[input], &["data.json"])?;
+            e.build_cmd(&[out_file], "cider", &[input[0]], &["data.json"])?;
             e.build_cmd(
-                &[output],
+                &[output[0]],
                 "interp-to-dat",
                 &[out_file],
-                &["$sim_data", "interp-dat.py"],
+                &[input[1], "interp-dat.py"],
             )?;
+            e.arg("sim_data", input[1])?;
             Ok(())
         },
     );
-    bld.op(
+    bld.multi_op(
         "interp-flat",
         &[sim_setup, calyx_setup, cider_setup],
-        calyx,
-        dat,
+        &[calyx, dat],
+        &[dat],
         |e, input, output| {
+            e.build_cmd(
+                &["data.dump"],
+                "dump-to-interp",
+                &[input[1]],
+                &["$cider-converter"],
+            )?;
             let out_file = "interp_out.dump";
-            e.build_cmd(&[out_file], "cider2", &[input], &["data.dump"])?;
+            e.build_cmd(&[out_file], "cider2", &[input[0]], &["data.dump"])?;
             e.build_cmd(
-                &[output],
+                &[output[0]],
                 "interp-to-dump",
                 &[out_file],
-                &["$sim_data", "$cider-converter"],
+                &[input[1], "$cider-converter"],
             )?;
+            e.arg("sim_data", input[1])?;
             Ok(())
         },
     );
-    bld.op(
+    bld.multi_op(
         "debug",
         &[
             sim_setup,
@@ -600,10 +576,21 @@ pub fn build_driver(bld: &mut DriverBuilder) {
             calyx_setup,
             cider_setup,
         ],
-        calyx,
-        debug,
+        &[calyx, dat],
+        &[debug],
         |e, input, output| {
-            e.build_cmd(&[output], "cider-debug", &[input], &["data.json"])?;
+            e.build_cmd(
+                &["data.json"],
+                "dat-to-interp",
+                &[input[1]],
+                &["interp-dat.py"],
+            )?;
+            e.build_cmd(
+                &[output[0]],
+                "cider-debug",
+                &[input[0]],
+                &["data.json"],
+            )?;
             Ok(())
         },
     );
@@ -638,19 +625,19 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         xo,
         |e, input, output| {
             // Emit the Verilog itself in "synthesis mode."
-            e.build_cmd(&["main.sv"], "calyx", &[input], &[])?;
+            e.build_cmd(&["main.sv"], "calyx", &[input[0]], &[])?;
             e.arg("backend", "verilog")?;
             e.arg("args", "--synthesis -p external")?;
 
             // Extra ingredients for the `.xo` package.
-            e.build_cmd(&["toplevel.v"], "calyx", &[input], &[])?;
+            e.build_cmd(&["toplevel.v"], "calyx", &[input[0]], &[])?;
             e.arg("backend", "xilinx")?;
-            e.build_cmd(&["kernel.xml"], "calyx", &[input], &[])?;
+            e.build_cmd(&["kernel.xml"], "calyx", &[input[0]], &[])?;
             e.arg("backend", "xilinx-xml")?;
 
             // Package the `.xo`.
             e.build_cmd(
-                &[output],
+                &[output[0]],
                 "gen-xo",
                 &[],
                 &[
@@ -665,7 +652,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         },
     );
     bld.op("xclbin", &[xilinx_setup], xo, xclbin, |e, input, output| {
-        e.build_cmd(&[output], "compile-xclbin", &[input], &[])?;
+        e.build_cmd(&[output[0]], "compile-xclbin", &[input[0]], &[])?;
         Ok(())
     });
 
@@ -690,7 +677,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
 
         Ok(())
     });
-    bld.op(
+    bld.multi_op(
         "xrt",
         &[
             xilinx_setup,
@@ -698,21 +685,21 @@ pub fn build_driver(bld: &mut DriverBuilder) {
             standalone_testbench_setup,
             xrt_setup,
         ],
-        xclbin,
-        dat,
+        &[xclbin, dat],
+        &[dat],
         |e, input, output| {
             e.rsrc("xrt.ini")?;
             e.build_cmd(
-                &[output],
+                &[output[0]],
                 "xclrun",
-                &[input, "$sim_data"],
+                &[input[0], input[1]],
                 &["emconfig.json", "xrt.ini"],
             )?;
             e.arg("xrt_ini", "xrt.ini")?;
             Ok(())
         },
     );
-    bld.op(
+    bld.multi_op(
         "xrt-trace",
         &[
             xilinx_setup,
@@ -720,14 +707,14 @@ pub fn build_driver(bld: &mut DriverBuilder) {
             standalone_testbench_setup,
             xrt_setup,
         ],
-        xclbin,
-        vcd,
+        &[xclbin, dat],
+        &[vcd],
         |e, input, output| {
             e.rsrc("xrt_trace.ini")?;
             e.build_cmd(
-                &[output], // TODO not the VCD, yet...
+                &[output[0]], // TODO not the VCD, yet...
                 "xclrun",
-                &[input, "$sim_data"],
+                &[input[0], input[1]],
                 &[
                     "emconfig.json",
                     "pre_sim.tcl",
@@ -747,7 +734,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         calyx,
         yxi,
         |e, input, output| {
-            e.build_cmd(&[output], "calyx", &[input], &[])?;
+            e.build_cmd(&[output[0]], "calyx", &[input[0]], &[])?;
             e.arg("backend", "yxi")?;
             Ok(())
         },
@@ -781,7 +768,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         |e, input, output| {
             // Generate the YXI file.
             // no extension
-            let file_name = input
+            let file_name = input[0]
                 .rsplit_once('/')
                 .unwrap()
                 .1
@@ -793,12 +780,12 @@ pub fn build_driver(bld: &mut DriverBuilder) {
             // TODO(nate): Eventually (#1952) This will be able to use the `yxi` operation
             // instead of hardcoding the build cmd calyx rule with arguments
             let tmp_yxi = format!("{}.yxi", file_name);
-            e.build_cmd(&[&tmp_yxi], "calyx", &[input], &[])?;
+            e.build_cmd(&[&tmp_yxi], "calyx", &[input[0]], &[])?;
             e.arg("backend", "yxi")?;
 
             // Generate the AXI wrapper.
             let refified_calyx = format!("refified_{}.futil", file_name);
-            e.build_cmd(&[&refified_calyx], "calyx-pass", &[input], &[])?;
+            e.build_cmd(&[&refified_calyx], "calyx-pass", &[input[0]], &[])?;
             e.arg("pass", "external-to-ref")?;
 
             let axi_wrapper = "axi_wrapper.futil";
@@ -815,7 +802,7 @@ pub fn build_driver(bld: &mut DriverBuilder) {
 
             // Combine the original Calyx and the wrapper.
             e.build_cmd(
-                &[output],
+                &[output[0]],
                 "combine",
                 &[axi_wrapper, &no_imports_calyx],
                 &[],
@@ -826,12 +813,6 @@ pub fn build_driver(bld: &mut DriverBuilder) {
 
     let cocotb_setup = bld.setup("cocotb", |e| {
         e.config_var_or("cocotb-makefile-dir", "cocotb.makefile-dir", "$calyx-base/yxi/axi-calyx/cocotb")?;
-        // TODO (nate): this is duplicated from the sim_setup above. Can this be shared?
-        // The input data file. `sim.data` is required.
-        let data_name = e.config_val("sim.data")?;
-        let data_path = e.external_path(data_name.as_ref());
-        e.var("sim_data", data_path.as_str())?;
-
         // Cocotb is wants files relative to the location of the makefile.
         // This is annoying to calculate on the fly, so we just copy necessary files to the build directory
         e.rule("copy", "cp $in $out")?;
@@ -842,12 +823,12 @@ pub fn build_driver(bld: &mut DriverBuilder) {
     });
 
     let cocotb_axi = bld.state("cocotb-axi", &["dat"]);
-    // Example invocation: `fud2 <path to axi wrapped verilog> --from verilog-noverify --to cocotb-axi --set sim.data=<path to .data/json file>`
-    bld.op(
+    // Example invocation: `fud2 <path to axi wrapped verilog> <path to .data/json file> --from verilog-noverify --from dat --to cocotb-axi`
+    bld.multi_op(
         "calyx-to-cocotb-axi",
         &[calyx_setup, cocotb_setup],
-        verilog_noverify,
-        cocotb_axi,
+        &[verilog_noverify, dat],
+        &[cocotb_axi],
         |e, input, output| {
             e.build_cmd(
                 &["Makefile"],
@@ -870,11 +851,12 @@ pub fn build_driver(bld: &mut DriverBuilder) {
             e.build_cmd(
                 &["tmp.dat"],
                 "make-cocotb",
-                &[input],
-                &["Makefile", "axi_test.py", "run_axi_test.py"],
+                &[input[0]],
+                &["Makefile", "axi_test.py", "run_axi_test.py", input[1]],
             )?;
+            e.arg("sim_data", input[1])?;
 
-            e.build_cmd(&[output], "cleanup-cocotb", &["tmp.dat"], &[])?;
+            e.build_cmd(&[output[0]], "cleanup-cocotb", &["tmp.dat"], &[])?;
 
             Ok(())
         },
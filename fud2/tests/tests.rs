@@ -1,6 +1,9 @@
 use fud2::build_driver;
 use fud_core::{
-    config::default_config, exec::Request, run::Run, Driver, DriverBuilder,
+    config::default_config,
+    exec::Request,
+    run::{BuildGraph, Run},
+    Driver, DriverBuilder,
 };
 
 fn test_driver() -> Driver {
@@ -9,18 +12,37 @@ fn test_driver() -> Driver {
     bld.build()
 }
 
+/// The input file to use for a state in requests with several inputs.
+fn input_file(state: &str) -> &'static str {
+    match state {
+        "calyx" => "prog.futil",
+        "dat" => "/test/data.json",
+        _ => panic!("no test input for state {}", state),
+    }
+}
+
 fn request(
     driver: &Driver,
-    start: &str,
-    end: &str,
+    start: &[&str],
+    end: &[&str],
     through: &[&str],
 ) -> Request {
+    // A single input is read from stdin.
+    let start_files = if start.len() == 1 {
+        vec![]
+    } else {
+        start.iter().map(|s| input_file(s).into()).collect()
+    };
     fud_core::exec::Request {
-        start_file: None,
-        start_state: driver.get_state(start).unwrap(),
-        end_file: None,
-        end_state: driver.get_state(end).unwrap(),
+        start_files,
+        start_states: start
+            .iter()
+            .map(|s| driver.get_state(s).unwrap())
+            .collect(),
+        end_files: vec![],
+        end_states: end.iter().map(|s| driver.get_state(s).unwrap()).collect(),
         through: through.iter().map(|s| driver.get_op(s).unwrap()).collect(),
+        op_costs: Default::default(),
         workdir: ".".into(),
     }
 }
@@ -31,7 +53,6 @@ fn emit_ninja(driver: &Driver, req: Request) -> String {
         .merge(("exe", "fud2"))
         .merge(("calyx.base", "/test/calyx"))
         .merge(("firrtl.exe", "/test/bin/firrtl"))
-        .merge(("sim.data", "/test/data.json"))
         .merge(("xilinx.vivado", "/test/xilinx/vivado"))
         .merge(("xilinx.vitis", "/test/xilinx/vitis"))
         .merge(("xilinx.xrt", "/test/xilinx/xrt"))
@@ -46,7 +67,8 @@ fn emit_ninja(driver: &Driver, req: Request) -> String {
 fn req_desc(driver: &Driver, req: &Request) -> String {
     let mut desc = format!(
         "emit {} -> {}",
-        driver.states[req.start_state].name, driver.states[req.end_state].name
+        driver.state_names(&req.start_states),
+        driver.state_names(&req.end_states)
     );
     if !req.through.is_empty() {
         desc.push_str(" through");
@@ -60,13 +82,15 @@ fn req_desc(driver: &Driver, req: &Request) -> String {
 
 /// Get a short string uniquely identifying a request.
 fn req_slug(driver: &Driver, req: &Request) -> String {
-    let mut desc = driver.states[req.start_state].name.to_string();
+    let mut desc = driver.states[req.start_states[0]].name.to_string();
     for op in &req.through {
         desc.push('_');
         desc.push_str(&driver.ops[*op].name);
     }
-    desc.push('_');
-    desc.push_str(&driver.states[req.end_state].name);
+    for state in &req.end_states {
+        desc.push('_');
+        desc.push_str(&driver.states[*state].name);
+    }
     desc
 }
 
@@ -86,7 +110,7 @@ fn test_emit(driver: &Driver, req: Request) {
 #[test]
 fn calyx_to_verilog() {
     let driver = test_driver();
-    test_emit(&driver, request(&driver, "calyx", "verilog", &[]));
+    test_emit(&driver, request(&driver, &["calyx"], &["verilog"], &[]));
 }
 
 #[test]
@@ -94,7 +118,7 @@ fn calyx_via_firrtl() {
     let driver = test_driver();
     test_emit(
         &driver,
-        request(&driver, "calyx", "verilog-refmem", &["firrtl"]),
+        request(&driver, &["calyx"], &["verilog-refmem"], &["firrtl"]),
     );
 }
 
@@ -103,29 +127,187 @@ fn sim_tests() {
     let driver = test_driver();
     for dest in &["dat", "vcd"] {
         for sim in &["icarus", "verilator"] {
-            test_emit(&driver, request(&driver, "calyx", dest, &[sim]));
+            test_emit(
+                &driver,
+                request(&driver, &["calyx", "dat"], &[dest], &[sim]),
+            );
         }
     }
 }
 
+#[test]
+fn sim_and_trace() {
+    let driver = test_driver();
+    let req =
+        || request(&driver, &["calyx", "dat"], &["dat", "vcd"], &["verilator"]);
+    test_emit(&driver, req());
+
+    // Both simulations read the same data directory, which is only built once.
+    let graph = BuildGraph::parse(&emit_ninja(&driver, req())).unwrap();
+    let mut outputs: Vec<&String> =
+        graph.edges.iter().flat_map(|edge| &edge.outputs).collect();
+    let count = outputs.len();
+    outputs.sort();
+    outputs.dedup();
+    assert_eq!(outputs.len(), count);
+}
+
 #[test]
 fn cider_tests() {
     let driver = test_driver();
-    test_emit(&driver, request(&driver, "calyx", "dat", &["interp"]));
-    test_emit(&driver, request(&driver, "calyx", "debug", &[]));
+    test_emit(
+        &driver,
+        request(&driver, &["calyx", "dat"], &["dat"], &["interp"]),
+    );
+    test_emit(
+        &driver,
+        request(&driver, &["calyx", "dat"], &["debug"], &[]),
+    );
 }
 
 #[test]
 fn xrt_tests() {
     let driver = test_driver();
-    test_emit(&driver, request(&driver, "calyx", "dat", &["xrt"]));
-    test_emit(&driver, request(&driver, "calyx", "vcd", &["xrt-trace"]));
+    test_emit(
+        &driver,
+        request(&driver, &["calyx", "dat"], &["dat"], &["xrt"]),
+    );
+    test_emit(
+        &driver,
+        request(&driver, &["calyx", "dat"], &["vcd"], &["xrt-trace"]),
+    );
 }
 
 #[test]
 fn frontend_tests() {
     let driver = test_driver();
     for frontend in &["dahlia", "mrxl"] {
-        test_emit(&driver, request(&driver, frontend, "calyx", &[]));
+        test_emit(&driver, request(&driver, &[frontend], &["calyx"], &[]));
+    }
+}
+
+#[test]
+fn multi_output() {
+    let driver = test_driver();
+    test_emit(
+        &driver,
+        request(&driver, &["calyx"], &["verilog", "yxi"], &[]),
+    );
+}
+
+/// Plan a request with some operation costs overridden, and get the names of the planned ops.
+fn plan_ops(driver: &Driver, costs: &[(&str, u32)]) -> Vec<String> {
+    let mut req = request(driver, &["calyx", "dat"], &["dat"], &[]);
+    for (op, cost) in costs {
+        req.op_costs.insert(driver.get_op(op).unwrap(), *cost);
     }
+    let plan = driver.plan(req).unwrap();
+    plan.steps
+        .iter()
+        .map(|step| driver.ops[step.op].name.clone())
+        .collect()
+}
+
+#[test]
+fn op_costs() {
+    let driver = test_driver();
+
+    // Running the interpreter directly is the shortest route.
+    assert_eq!(plan_ops(&driver, &[]), ["interp"]);
+
+    // Make the interpreters and running on an FPGA expensive, and pick among
+    // the RTL simulators.
+    assert_eq!(
+        plan_ops(
+            &driver,
+            &[
+                ("interp", 10),
+                ("interp-flat", 10),
+                ("xrt", 10),
+                ("verilator", 5)
+            ]
+        ),
+        ["calyx-noverify", "icarus", "hex-data", "simulate"]
+    );
+    assert_eq!(
+        plan_ops(
+            &driver,
+            &[
+                ("interp", 10),
+                ("interp-flat", 10),
+                ("xrt", 10),
+                ("icarus", 5)
+            ]
+        ),
+        ["calyx-to-verilog", "verilator", "hex-data", "simulate"]
+    );
+
+    // Huge costs saturate instead of overflowing.
+    assert_eq!(
+        plan_ops(
+            &driver,
+            &[
+                ("interp", u32::MAX),
+                ("interp-flat", u32::MAX),
+                ("xrt", u32::MAX),
+                ("icarus", u32::MAX)
+            ]
+        ),
+        ["calyx-to-verilog", "verilator", "hex-data", "simulate"]
+    );
+}
+
+#[test]
+fn rejected_alternatives() {
+    let driver = test_driver();
+    let req = request(&driver, &["calyx", "dat"], &["dat"], &[]);
+    let plan = driver.plan(req).unwrap();
+
+    // The interpreter wins over the other ways of producing `dat`.
+    let interp = driver.get_op("interp").unwrap();
+    let dat = driver.get_state("dat").unwrap();
+    let rejected: Vec<&str> = plan
+        .alternatives
+        .iter()
+        .filter(|alt| alt.state == dat)
+        .map(|alt| {
+            assert_eq!(alt.chosen, (interp, 1));
+            driver.ops[alt.rejected.0].name.as_str()
+        })
+        .collect();
+    assert!(rejected.contains(&"interp-flat"));
+    assert!(rejected.contains(&"simulate"));
+}
+
+#[test]
+fn exec_build_graph() {
+    let driver = test_driver();
+
+    // Commands are expanded using the rule, build, and file variables.
+    let ninja =
+        emit_ninja(&driver, request(&driver, &["calyx"], &["verilog"], &[]));
+    let graph = BuildGraph::parse(&ninja).unwrap();
+    assert_eq!(graph.defaults, ["stdin.sv"]);
+    assert_eq!(graph.edges.len(), 1);
+    assert_eq!(graph.edges[0].outputs, ["stdin.sv"]);
+    assert_eq!(graph.edges[0].inputs, ["stdin"]);
+    assert_eq!(
+        graph.edges[0].command,
+        "/test/calyx/target/debug/calyx -l /test/calyx -b verilog  stdin > stdin.sv"
+    );
+
+    // Implicit dependencies and the console pool.
+    let ninja = emit_ninja(
+        &driver,
+        request(&driver, &["calyx", "dat"], &["debug"], &[]),
+    );
+    let graph = BuildGraph::parse(&ninja).unwrap();
+    let debug = graph
+        .edges
+        .iter()
+        .find(|e| e.outputs == ["_pseudo_debug"])
+        .unwrap();
+    assert_eq!(debug.inputs, ["prog.futil"]);
+    assert_eq!(debug.implicit, ["data.json"]);
+    assert!(debug.console);
 }
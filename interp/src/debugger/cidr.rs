@@ -3,11 +3,16 @@ use super::{
     context::DebuggingContext,
     interactive_errors::DebuggerError,
     io_utils::Input,
+    name_tree::ActiveTreeNode,
     new_parser::parse_metadata,
+    parser::parse_command,
     source::structures::NewSourceMap,
 };
 use crate::interpreter::{ComponentInterpreter, ConstCell, Interpreter};
-use crate::structures::names::{CompGroupName, ComponentQualifiedInstanceName};
+use crate::structures::names::{
+    CompGroupName, ComponentQualifiedInstanceName, GroupName,
+    GroupQualifiedInstanceName,
+};
 use crate::structures::state_views::StateView;
 use crate::utils::AsRaw;
 use crate::{configuration, debugger::source::SourceMap};
@@ -19,7 +24,9 @@ use crate::{
     errors::{InterpreterError, InterpreterResult},
     structures::names::GroupQIN,
 };
-use crate::{interpreter_ir as iir, serialization::Serializable};
+use crate::{
+    interpreter_ir as iir, serialization::Serializable, values::Value,
+};
 use std::collections::HashSet;
 
 use calyx_frontend::Workspace;
@@ -40,6 +47,8 @@ pub struct ProgramStatus {
     status: HashSet<Id>,
     /// states whether the program has finished
     done: bool,
+    /// data breakpoints whose contents changed
+    changed: Vec<String>,
 }
 
 impl ProgramStatus {
@@ -53,9 +62,16 @@ impl ProgramStatus {
         ProgramStatus {
             status: set,
             done: is_done,
+            changed: vec![],
         }
     }
 
+    /// record the data breakpoints whose contents changed
+    pub fn with_changed(mut self, changed: Vec<String>) -> Self {
+        self.changed = changed;
+        self
+    }
+
     /// get status
     pub fn get_status(&self) -> &HashSet<Id> {
         &self.status
@@ -65,6 +81,11 @@ impl ProgramStatus {
     pub fn get_done(&self) -> bool {
         self.done
     }
+
+    /// get the data breakpoints whose contents changed
+    pub fn get_changed(&self) -> &[String] {
+        &self.changed
+    }
 }
 
 /// The interactive Calyx debugger. The debugger itself is run with the
@@ -76,6 +97,8 @@ pub struct Debugger {
     debugging_ctx: DebuggingContext,
     source_map: Option<SourceMap>,
     interpreter: ComponentInterpreter,
+    /// The data breakpoints and the last contents of the entries they watch
+    data_breakpoints: Vec<DataBreakpoint>,
 }
 
 impl Debugger {
@@ -109,8 +132,8 @@ impl Debugger {
         let components: iir::ComponentCtx = Rc::new(
             ctx.components
                 .into_iter()
-                .map(|x| Rc::new(x.into()))
-                .collect(),
+                .map(|x| x.try_into().map(Rc::new))
+                .collect::<Result<_, InterpreterError>>()?,
         );
 
         let main_component = components
@@ -161,6 +184,7 @@ impl Debugger {
             debugging_ctx: DebuggingContext::new(context, &main_component.name),
             source_map,
             interpreter: component_interpreter,
+            data_breakpoints: vec![],
         })
     }
 
@@ -178,52 +202,330 @@ impl Debugger {
         ))
     }
 
-    pub fn cont(&mut self) -> InterpreterResult<()> {
+    /// Run until a breakpoint is hit, the contents of a data breakpoint change,
+    /// or the program finishes. Unlike the
+    /// `continue` command of [Debugger::main_loop], this does not print
+    /// anything.
+    pub fn cont(&mut self) -> InterpreterResult<ProgramStatus> {
         self.debugging_ctx
             .set_current_time(self.interpreter.currently_executing_group());
 
-        let mut ctx = std::mem::replace(
-            &mut self.debugging_ctx,
-            DebuggingContext::new(&self._context, &self.main_component.name),
-        );
-
-        let mut breakpoints: Vec<CompGroupName> = vec![];
+        let mut hit_breakpoint = false;
+        let mut changed = vec![];
 
-        while breakpoints.is_empty() && !self.interpreter.is_done() {
+        while !hit_breakpoint
+            && changed.is_empty()
+            && !self.interpreter.is_done()
+        {
             self.interpreter.step()?;
             let current_exec = self.interpreter.currently_executing_group();
 
-            ctx.advance_time(current_exec);
-
-            for watch in ctx.process_watchpoints() {
-                for target in watch.target() {
-                    if let Ok(msg) = Self::do_print(
-                        self.main_component.name,
-                        target,
-                        watch.print_code(),
-                        self.interpreter.get_env(),
-                        watch.print_mode(),
-                    ) {
-                        println!("{}", msg.on_black().yellow().bold());
-                    }
-                }
-            }
+            self.debugging_ctx.advance_time(current_exec);
+            hit_breakpoint = !self.debugging_ctx.hit_breakpoints().is_empty();
+            changed = self.changed_data_breakpoints();
+        }
 
-            breakpoints = ctx.hit_breakpoints().into_iter().cloned().collect();
+        if !self.interpreter.is_done() {
+            self.interpreter.converge()?;
         }
 
-        self.debugging_ctx = ctx;
+        Ok(ProgramStatus::generate(
+            self.interpreter.currently_executing_group(),
+            self.interpreter.is_done(),
+        )
+        .with_changed(changed))
+    }
 
-        if !self.interpreter.is_done() {
-            for breakpoint in breakpoints {
-                println!(
-                    "Hit breakpoint: {}",
-                    breakpoint.bright_purple().underline()
-                );
+    /// Replace all the breakpoints with breakpoints on the given groups.
+    /// A group written as `comp::group` belongs to the component `comp`,
+    /// while a bare group name binds in every component defining it, so the
+    /// breakpoint is hit inside subcomponents too. Returns whether each
+    /// group exists.
+    pub fn set_breakpoints<S: AsRef<str>>(
+        &mut self,
+        groups: &[S],
+    ) -> Vec<bool> {
+        let mut targets = vec![];
+        let found = groups
+            .iter()
+            .map(|group| {
+                let count = targets.len();
+                targets.extend(self.breakpoint_targets(group.as_ref()));
+                targets.len() > count
+            })
+            .collect();
+        self.debugging_ctx.replace_breakpoints(targets);
+        found
+    }
+
+    /// The groups a breakpoint on `group` binds to
+    fn breakpoint_targets(&self, group: &str) -> Vec<CompGroupName> {
+        let (component, group) = match group.split_once("::") {
+            Some((comp, group)) => (Some(Id::from(comp.trim())), group),
+            None => (None, group),
+        };
+        let group = Id::from(group.trim());
+        self._context
+            .iter()
+            .filter(|comp| component.map_or(true, |name| comp.name == name))
+            .filter(|comp| {
+                comp.groups.find(group).is_some()
+                    || comp.comb_groups.find(group).is_some()
+            })
+            .map(|comp| CompGroupName::new(group, comp.name))
+            .collect()
+    }
+
+    /// The stacks of all the threads of execution, innermost frame first.
+    /// Every running group, such as each arm of a `par`, gets its own thread
+    /// whose stack lists the component instances that invoked it. If no group
+    /// is running, there is a single thread holding the main component.
+    pub fn active_threads(&self) -> Vec<Vec<ActiveFrame>> {
+        let mut threads = vec![];
+        for root in self.interpreter.get_active_tree() {
+            collect_threads(&root, &mut vec![], &mut threads);
+        }
+        if threads.is_empty() {
+            threads.push(vec![ActiveFrame {
+                path: vec![self.main_component.name],
+                component: self.main_component.name,
+                group: None,
+                pos_tag: None,
+            }]);
+        }
+        threads
+    }
+
+    /// The cells of the component instance running `frame`, along with their
+    /// contents and ports. Returns `None` if the instance no longer exists.
+    pub fn frame_variables(
+        &self,
+        frame: &ActiveFrame,
+    ) -> Option<Vec<VariableInfo>> {
+        // The first name in the path is the main component itself
+        let path = &frame.path[1..];
+        let prefix: String =
+            path.iter().map(|inst| format!("{}.", inst)).collect();
+        visit_instance(&self.interpreter.get_env(), path, &|env| {
+            cell_variables(env, &prefix)
+        })
+    }
+
+    /// Evaluate a print command, such as `p \\u reg.out` or `print-state mem`,
+    /// and return what the REPL would print, without colors. A bare target
+    /// like `reg.out` is printed as if it were `print reg.out`.
+    pub fn evaluate(&self, expr: &str) -> InterpreterResult<String> {
+        let command = match parse_command(expr) {
+            Ok(command @ Command::Print(..)) => command,
+            _ => parse_command(&format!("print {}", expr))?,
+        };
+        let Command::Print(targets, code, mode) = command else {
+            return Err(InterpreterError::InvalidCommand(format!(
+                "cannot evaluate \"{}\"",
+                expr
+            ))
+            .into());
+        };
+
+        let mut out = vec![];
+        for target in targets {
+            let msg = Self::do_print(
+                self.main_component.name,
+                &target,
+                &code,
+                self.interpreter.get_env(),
+                &mode,
+            )
+            .map_err(|e| InterpreterError::InvalidCommand(e.to_string()))?;
+            out.push(strip_colors(msg.trim_end()));
+        }
+        Ok(out.join("\n"))
+    }
+
+    /// Overwrite the value of a port, register, or memory entry, returning
+    /// the new value. Targets are written as in [VariableInfo::evaluate_name]:
+    /// `reg.in` is a port, `reg` is the contents of a register, and `mem[3]`
+    /// is an entry of a memory. Ports can only be set in the main component
+    /// and keep their value until the next step recomputes them.
+    pub fn set_variable(
+        &mut self,
+        target: &str,
+        value: &str,
+    ) -> InterpreterResult<String> {
+        let num: i128 = value.trim().parse().map_err(|_| {
+            InterpreterError::InvalidCommand(format!(
+                "{} is not a number",
+                value
+            ))
+        })?;
+        let (path, index) = self.split_target(target);
+        let cannot_set = || {
+            InterpreterError::InvalidCommand(format!("cannot set {}", target))
+        };
+        let Some((last, instances)) = path.split_last() else {
+            return Err(cannot_set().into());
+        };
+
+        // a memory must be given the entry to set
+        if index.is_none() && self.cell_entry(instances, *last, 1).is_some() {
+            return Err(InterpreterError::InvalidCommand(format!(
+                "{} has more than one entry, set one of them like {}[0]",
+                target, target
+            ))
+            .into());
+        }
+
+        // registers and memories
+        let is_state =
+            visit_instance(&self.interpreter.get_env(), instances, &|env| {
+                env.get_cell(*last).is_some_and(|cell| {
+                    env.get_cell_map()
+                        .borrow_mut()
+                        .get_mut(&cell.as_raw())
+                        .map_or(false, |prim| {
+                            prim.set_entry(index.unwrap_or_default(), num)
+                        })
+                })
+            });
+        if is_state == Some(true) {
+            return self.cell_state(target).ok_or_else(|| cannot_set().into());
+        }
+
+        // ports of cells in the main component
+        let port = match (path.as_slice(), index) {
+            ([cell, port], None) => {
+                self.main_component.find_cell(*cell).and_then(|cell| {
+                    let port = cell.borrow().find(*port);
+                    port
+                })
+            }
+            _ => None,
+        }
+        .ok_or_else(cannot_set)?;
+        let width = port.borrow().width;
+        let new_value = Value::from(num, width);
+        let out = new_value.as_unsigned().to_string();
+        self.interpreter
+            .get_env_mut()
+            .insert(port.as_raw(), new_value);
+        Ok(out)
+    }
+
+    /// The contents of the register, memory, or memory entry named by
+    /// `target`. Returns `None` if there is no such cell or it has no state.
+    pub fn cell_state(&self, target: &str) -> Option<String> {
+        let (path, index) = self.split_target(target);
+        let (last, instances) = path.split_last()?;
+        visit_instance(&self.interpreter.get_env(), instances, &|env| {
+            let cell = env.get_cell(*last)?;
+            match (env.get_cell_state(&cell, &PrintCode::Unsigned), index) {
+                (Serializable::Empty, _) => None,
+                (Serializable::Array(entries, _), Some(idx)) => {
+                    entries.get(idx).map(|e| e.to_string())
+                }
+                (_, Some(_)) => None,
+                (state, None) => Some(state.to_string()),
+            }
+        })
+        .flatten()
+    }
+
+    /// The entry at `index` of the cell `name` in the instance found by
+    /// following `instances` from the main component
+    fn cell_entry(
+        &self,
+        instances: &[Id],
+        name: Id,
+        index: usize,
+    ) -> Option<Value> {
+        visit_instance(&self.interpreter.get_env(), instances, &|env| {
+            let cell = env.get_cell(name)?;
+            let map = env.get_cell_map().borrow();
+            map.get(&cell.as_raw())?.get_entry(index)
+        })
+        .flatten()
+    }
+
+    /// Resolve a data breakpoint on `target`, which watches the one entry of
+    /// `mem[3]` or every entry of a register or memory without an index.
+    /// Returns `None` if `target` names nothing with contents.
+    fn resolve_data_breakpoint(&self, target: &str) -> Option<DataBreakpoint> {
+        let (path, index) = self.split_target(target);
+        let (last, instances) = path.split_last()?;
+        let (cell, map) =
+            visit_instance(&self.interpreter.get_env(), instances, &|env| {
+                let cell = env.get_cell(*last)?;
+                let cell = cell.as_raw();
+                Some((cell, Rc::clone(env.get_cell_map())))
+            })
+            .flatten()?;
+
+        let entries: Vec<Value> = {
+            let map_ref = map.borrow();
+            let prim = map_ref.get(&cell)?;
+            match index {
+                Some(idx) => vec![prim.get_entry(idx)?],
+                None => (0..).map_while(|idx| prim.get_entry(idx)).collect(),
             }
-            self.interpreter.converge()?;
         };
-        Ok(())
+
+        (!entries.is_empty()).then(|| DataBreakpoint {
+            target: target.to_string(),
+            cell,
+            map,
+            offset: index.unwrap_or_default(),
+            entries,
+        })
+    }
+
+    /// Replace all the data breakpoints with breakpoints on the given
+    /// registers, memories, or memory entries. [Debugger::cont] stops when
+    /// the contents of any of them change. Returns whether each target
+    /// names something with contents.
+    pub fn set_data_breakpoints<S: AsRef<str>>(
+        &mut self,
+        targets: &[S],
+    ) -> Vec<bool> {
+        self.data_breakpoints.clear();
+        targets
+            .iter()
+            .map(|target| {
+                if let Some(bp) = self.resolve_data_breakpoint(target.as_ref())
+                {
+                    self.data_breakpoints.push(bp);
+                    true
+                } else {
+                    false
+                }
+            })
+            .collect()
+    }
+
+    /// Update the values of the data breakpoints, returning the targets whose
+    /// contents changed
+    fn changed_data_breakpoints(&mut self) -> Vec<String> {
+        self.data_breakpoints
+            .iter_mut()
+            .filter_map(|bp| bp.update().then(|| bp.target.clone()))
+            .collect()
+    }
+
+    /// Split a target like `sub.mem[3]` into the names leading to the cell,
+    /// without the main component, and the index into the cell, if any
+    fn split_target(&self, target: &str) -> (Vec<Id>, Option<usize>) {
+        let (names, index) = match target.strip_suffix(']') {
+            Some(rest) => match rest.rsplit_once('[') {
+                Some((names, idx)) => (names, idx.trim().parse().ok()),
+                None => (target, None),
+            },
+            None => (target, None),
+        };
+        let mut path: Vec<Id> =
+            names.split('.').map(|name| name.trim().into()).collect();
+        if path.len() > 1 && path[0] == self.main_component.name {
+            path.remove(0);
+        }
+        (path, index)
     }
 
     // so on and so forth
@@ -573,6 +875,8 @@ impl Debugger {
         } else {
             print_list.len()
         };
+        // the number of targets that the loop below skips
+        let skipped = print_list.len() - length;
 
         let mut current_target = CurrentTarget::Env(&root);
 
@@ -592,7 +896,7 @@ impl Debugger {
                         print_mode,
                     ));
                 } else if idx != 0 {
-                    let prior = &print_list[idx - 1];
+                    let prior = &print_list[skipped + idx - 1];
 
                     if let Some(parent) = current_env.get_cell(*prior) {
                         let parent_ref = parent.borrow();
@@ -617,7 +921,7 @@ impl Debugger {
                         return Ok(print_port(
                             &port,
                             &current_env,
-                            Some(print_list[idx - 1]),
+                            Some(print_list[skipped + idx - 1]),
                             code,
                         ));
                     } else {
@@ -656,6 +960,197 @@ impl Debugger {
     }
 }
 
+/// A frame on the stack of a thread of execution: an instance of a component
+/// and the group it is running, if any. Two frames are equal when they run
+/// the same control node, so the innermost frame of a thread identifies it
+/// across steps.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActiveFrame {
+    /// The names of the instances leading from the main component to this one
+    path: Vec<Id>,
+    /// The name of the component definition
+    component: Id,
+    /// The running group
+    group: Option<Id>,
+    /// The position tag of the enable running the group, which tells apart
+    /// enables of the same group
+    pos_tag: Option<u64>,
+}
+
+impl ActiveFrame {
+    fn new(name: &GroupQualifiedInstanceName) -> Self {
+        let group = match &name.group {
+            GroupName::Group(g) | GroupName::Phantom(g) => Some(*g),
+            GroupName::None => None,
+        };
+        Self {
+            path: name.prefix.iter().map(|x| x.instance).collect(),
+            component: name.prefix.last().unwrap().component_id.name,
+            group,
+            pos_tag: name.pos_tag,
+        }
+    }
+
+    /// The fully-qualified name of the frame, such as `main.sub::group`
+    pub fn name(&self) -> String {
+        let mut out = self
+            .path
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+        if let Some(group) = self.group {
+            write!(out, "::{}", group).unwrap();
+        }
+        out
+    }
+
+    /// The name of the component definition the frame is an instance of
+    pub fn component(&self) -> Id {
+        self.component
+    }
+
+    /// The group running in the frame, if any
+    pub fn group(&self) -> Option<Id> {
+        self.group
+    }
+}
+
+/// A value shown by a debugger front-end. Cells show their contents, if they
+/// have any, and have their ports as children. Memories also have each of
+/// their entries as children.
+#[derive(Debug, Clone)]
+pub struct VariableInfo {
+    pub name: String,
+    pub value: String,
+    /// The target naming this value in [Debugger::evaluate] and
+    /// [Debugger::set_variable], such as `sub.mem[2]`
+    pub evaluate_name: String,
+    pub children: Vec<VariableInfo>,
+}
+
+/// Add a thread for every running group under `node` to `threads`. `stack`
+/// holds the frames of the component instances enclosing `node`.
+fn collect_threads(
+    node: &ActiveTreeNode,
+    stack: &mut Vec<ActiveFrame>,
+    threads: &mut Vec<Vec<ActiveFrame>>,
+) {
+    let frame = ActiveFrame::new(node.name());
+    if node.name().is_leaf() {
+        // the group replaces the frame of the instance running it
+        let mut thread = stack.clone();
+        if matches!(thread.last(), Some(last) if last.path == frame.path) {
+            thread.pop();
+        }
+        thread.push(frame);
+        thread.reverse();
+        threads.push(thread);
+        for child in node.children() {
+            collect_threads(child, stack, threads);
+        }
+    } else {
+        stack.push(frame);
+        for child in node.children() {
+            collect_threads(child, stack, threads);
+        }
+        stack.pop();
+    }
+}
+
+/// Call `visit` on the environment of the instance found by following `path`
+/// from `env`. Returns `None` if there is no such instance.
+fn visit_instance<R>(
+    env: &StateView,
+    path: &[Id],
+    visit: &dyn Fn(&StateView) -> R,
+) -> Option<R> {
+    match path.split_first() {
+        None => Some(visit(env)),
+        Some((instance, rest)) => {
+            let cell = env.get_cell(*instance)?;
+            let map = env.get_cell_map().borrow();
+            let sub_env = map.get(&cell.as_raw())?.get_state()?;
+            visit_instance(&sub_env, rest, visit)
+        }
+    }
+}
+
+/// The cells of the component in `env`. `prefix` is the path of the instance
+/// used to build the evaluate names of the cells, such as `sub.`.
+fn cell_variables(env: &StateView, prefix: &str) -> Vec<VariableInfo> {
+    env.get_comp()
+        .cells
+        .iter()
+        // constants are not variables of the program
+        .filter(|cell| {
+            !matches!(cell.borrow().prototype, ir::CellType::Constant { .. })
+        })
+        .map(|cell| {
+            let cell_ref = cell.borrow();
+            let cell_name = format!("{}{}", prefix, cell_ref.name());
+            let mut children = vec![];
+            let value = if let ir::CellType::Component { name } =
+                &cell_ref.prototype
+            {
+                format!("instance of {}", name)
+            } else {
+                let state = env.get_cell_state(&cell_ref, &PrintCode::Unsigned);
+                // memories show each of their entries
+                if let Serializable::Array(entries, _) = &state {
+                    children.extend(entries.iter().enumerate().map(
+                        |(idx, entry)| VariableInfo {
+                            name: format!("[{}]", idx),
+                            value: entry.to_string(),
+                            evaluate_name: format!("{}[{}]", cell_name, idx),
+                            children: vec![],
+                        },
+                    ));
+                }
+                state.to_string()
+            };
+            children.extend(cell_ref.ports.iter().map(|port| {
+                let port_name = port.borrow().name;
+                VariableInfo {
+                    name: port_name.to_string(),
+                    value: env.lookup(port.as_raw()).as_unsigned().to_string(),
+                    evaluate_name: format!("{}.{}", cell_name, port_name),
+                    children: vec![],
+                }
+            }));
+            VariableInfo {
+                name: cell_ref.name().to_string(),
+                value,
+                evaluate_name: cell_name,
+                children,
+            }
+        })
+        .collect()
+}
+
+/// Remove the terminal colors added by [OwoColorize] and the indentation of
+/// the debugger messages from `msg`
+fn strip_colors(msg: &str) -> String {
+    let mut out = String::with_capacity(msg.len());
+    let mut chars = msg.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // skip to the end of the escape sequence
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out.lines()
+        .map(|line| line.strip_prefix(SPACING).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn print_cell(
     target: &RRC<ir::Cell>,
     state: &StateView,
@@ -750,6 +1245,48 @@ fn print_port(
     )
 }
 
+/// A data breakpoint on a register, memory, or memory entry. The cell is
+/// resolved once when the breakpoint is set.
+struct DataBreakpoint {
+    /// The target as given by the user, such as `sub.mem[3]`
+    target: String,
+    cell: ConstCell,
+    /// The primitive map of the instance containing the cell
+    map: PrimitiveMap,
+    /// The index of the first watched entry
+    offset: usize,
+    /// The last contents of the watched entries
+    entries: Vec<Value>,
+}
+
+impl DataBreakpoint {
+    /// Check the watched entries for a change, recording the new contents.
+    /// Only the entry most recently written by the primitive is compared, so
+    /// watching a large memory does not cost more than watching a register.
+    fn update(&mut self) -> bool {
+        let map = self.map.borrow();
+        let Some(prim) = map.get(&self.cell) else {
+            return false;
+        };
+        let idx = if self.entries.len() == 1 {
+            self.offset
+        } else {
+            prim.last_written_entry().unwrap_or(self.offset)
+        };
+        let (Some(new), Some(old)) =
+            (prim.get_entry(idx), self.entries.get_mut(idx - self.offset))
+        else {
+            return false;
+        };
+        if *old == new {
+            false
+        } else {
+            *old = new;
+            true
+        }
+    }
+}
+
 enum CurrentTarget<'a> {
     Env(&'a StateView<'a>),
     Target { name: ConstCell, map: PrimitiveMap },
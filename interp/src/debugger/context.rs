@@ -236,6 +236,44 @@ impl DebuggingContext {
         }
     }
 
+    /// Replace all the breakpoints with breakpoints on `targets`. Unlike
+    /// [DebuggingContext::add_breakpoint], this does not print anything.
+    /// Returns whether each target names a group in the program.
+    pub fn replace_breakpoints(
+        &mut self,
+        targets: Vec<CompGroupName>,
+    ) -> Vec<bool> {
+        self.breakpoints.clear();
+        targets
+            .into_iter()
+            .map(|target| {
+                if !self.group_exists(&target) {
+                    return false;
+                }
+                if !self.breakpoints.contains_key(&target) {
+                    let br = BreakPoint {
+                        id: self.count.next(),
+                        name: target.clone(),
+                        state: BreakPointState::Enabled,
+                    };
+                    self.breakpoints.insert(target, br);
+                }
+                true
+            })
+            .collect()
+    }
+
+    /// Check whether `target` names a group or a combinational group in the
+    /// program.
+    fn group_exists(&self, target: &CompGroupName) -> bool {
+        self.comp_ctx
+            .get(&target.component_name)
+            .is_some_and(|comp| {
+                comp.groups.find(target.group_name).is_some()
+                    || comp.comb_groups.find(target.group_name).is_some()
+            })
+    }
+
     pub fn add_watchpoint<P, N>(
         &mut self,
         key: N,
@@ -11,3 +11,4 @@ pub use commands::PrintCode;
 
 pub use cidr::Debugger;
 pub use cidr::ProgramStatus;
+pub use cidr::{ActiveFrame, VariableInfo};
@@ -21,6 +21,14 @@ impl ActiveTreeNode {
         self.children.push(node);
     }
 
+    pub fn name(&self) -> &GroupQualifiedInstanceName {
+        &self.name
+    }
+
+    pub fn children(&self) -> &[ActiveTreeNode] {
+        &self.children
+    }
+
     pub fn format_tree<const TOP: bool>(&self, indent_level: usize) -> String {
         let mut out = String::new();
         write!(out, "{}", " ".repeat(indent_level)).unwrap();
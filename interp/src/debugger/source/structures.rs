@@ -37,6 +37,16 @@ impl NewSourceMap {
     pub fn lookup(&self, key: String) -> Option<&GroupContents> {
         self.0.get(&key)
     }
+
+    /// look up the group on the given line, if any. If several groups share
+    /// the line, the first one by name is returned
+    pub fn lookup_line(&self, line: u64) -> Option<&String> {
+        self.0
+            .iter()
+            .filter(|(_, contents)| contents.line == line)
+            .map(|(name, _)| name)
+            .min()
+    }
 }
 
 impl From<HashMap<String, GroupContents>> for NewSourceMap {
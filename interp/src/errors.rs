@@ -190,6 +190,12 @@ pub enum InterpreterError {
     #[error("Attempted to read an undefined memory address")]
     UndefinedReadAddr,
 
+    #[error("this interpreter does not support static control. Use the `flat` subcommand or compile the static control away first")]
+    UnsupportedStaticControl,
+
+    #[error("the condition of a static if is undefined in the first cycle of the if")]
+    UndefinedStaticIfCondition,
+
     #[error(transparent)]
     SerializationError(
         #[from] crate::serialization::data_dump::SerializationError,
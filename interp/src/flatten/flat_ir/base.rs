@@ -482,6 +482,11 @@ impl From<PortValue> for Option<AssignedValue> {
 pub struct GroupIdx(u32);
 impl_index!(GroupIdx);
 
+/// A global index for static groups in the IR
+#[derive(Debug, Eq, Copy, Clone, PartialEq, Hash, PartialOrd, Ord)]
+pub struct StaticGroupIdx(u32);
+impl_index!(StaticGroupIdx);
+
 /// A global index for combinational groups in the IR
 ///
 /// This is non-zero to make the option-types of this index used in the IR If and
@@ -533,6 +538,7 @@ pub enum ParentIdx {
     Cell(CellDefinitionIdx),
     RefCell(RefCellDefinitionIdx),
     Group(GroupIdx),
+    StaticGroup(StaticGroupIdx),
 }
 
 impl From<GroupIdx> for ParentIdx {
@@ -541,6 +547,12 @@ impl From<GroupIdx> for ParentIdx {
     }
 }
 
+impl From<StaticGroupIdx> for ParentIdx {
+    fn from(v: StaticGroupIdx) -> Self {
+        Self::StaticGroup(v)
+    }
+}
+
 impl From<RefCellDefinitionIdx> for ParentIdx {
     fn from(v: RefCellDefinitionIdx) -> Self {
         Self::RefCell(v)
@@ -36,6 +36,7 @@ pub enum PrimType1 {
     Rsh,
     Mux,
     Wire,
+    Undef,
     //
     SignedAdd,
     SignedSub,
@@ -52,6 +53,7 @@ pub enum PrimType1 {
     DivPipe,
     SignedDivPipe,
     Sqrt,
+    PipelinedMult,
     //
     UnsynMult,
     UnsynDiv,
@@ -76,6 +78,7 @@ pub enum FPType {
     SignedGt,
     SignedLt,
     Sqrt,
+    PipelinedSignedMult,
 }
 
 #[derive(Debug, Clone)]
@@ -365,6 +368,28 @@ impl CellPrototype {
                         width: width.try_into().unwrap(),
                     }
                 }
+                "pipelined_mult" => {
+                    get_params![params; width: "WIDTH"];
+
+                    Self::SingleWidth {
+                        op: PrimType1::PipelinedMult,
+                        width: width.try_into().unwrap(),
+                    }
+                }
+                "pipelined_fp_smult" => {
+                    get_params![params;
+                        width: "WIDTH",
+                        int_width: "INT_WIDTH",
+                        frac_width: "FRAC_WIDTH"
+                    ];
+
+                    Self::FixedPoint {
+                        op: FPType::PipelinedSignedMult,
+                        width: width.try_into().unwrap(),
+                        int_width: int_width.try_into().unwrap(),
+                        frac_width: frac_width.try_into().unwrap(),
+                    }
+                }
                 "fp_sqrt" => {
                     get_params![params;
                         width: "WIDTH",
@@ -435,6 +460,14 @@ impl CellPrototype {
                         width: width.try_into().unwrap(),
                     }
                 }
+                "undef" => {
+                    get_params![params; width: "WIDTH"];
+
+                    Self::SingleWidth {
+                        op: PrimType1::Undef,
+                        width: width.try_into().unwrap(),
+                    }
+                }
                 n @ ("std_eq" | "std_neq" | "std_lt" | "std_le" | "std_gt"
                 | "std_ge") => {
                     get_params![params; width: "WIDTH"];
@@ -468,7 +501,7 @@ impl CellPrototype {
                         width: width.try_into().unwrap(),
                     }
                 }
-                n @ ("std_fp_gt" | "std_fp_sgt" | "std_fg_slt") => {
+                n @ ("std_fp_gt" | "std_fp_sgt" | "std_fp_slt") => {
                     get_params![params;
                         width: "WIDTH",
                         int_width: "INT_WIDTH",
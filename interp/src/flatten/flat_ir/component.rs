@@ -11,6 +11,7 @@ pub struct DefinitionRanges {
     ref_cells: IndexRange<RefCellDefinitionIdx>,
     ref_ports: IndexRange<RefPortDefinitionIdx>,
     groups: IndexRange<GroupIdx>,
+    static_groups: IndexRange<StaticGroupIdx>,
     comb_groups: IndexRange<CombGroupIdx>,
 }
 
@@ -35,6 +36,10 @@ impl DefinitionRanges {
         &self.groups
     }
 
+    pub fn static_groups(&self) -> &IndexRange<StaticGroupIdx> {
+        &self.static_groups
+    }
+
     pub fn comb_groups(&self) -> &IndexRange<CombGroupIdx> {
         &self.comb_groups
     }
@@ -48,6 +53,7 @@ impl Default for DefinitionRanges {
             cells: IndexRange::empty_interval(),
             ref_cells: IndexRange::empty_interval(),
             groups: IndexRange::empty_interval(),
+            static_groups: IndexRange::empty_interval(),
             comb_groups: IndexRange::empty_interval(),
         }
     }
@@ -145,6 +151,14 @@ impl AuxillaryComponentInfo {
         self.definitions.groups = IndexRange::new(start, end)
     }
 
+    pub fn set_static_group_range(
+        &mut self,
+        start: StaticGroupIdx,
+        end: StaticGroupIdx,
+    ) {
+        self.definitions.static_groups = IndexRange::new(start, end)
+    }
+
     pub fn set_comb_group_range(
         &mut self,
         start: CombGroupIdx,
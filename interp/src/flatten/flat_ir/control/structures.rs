@@ -18,6 +18,17 @@ pub type ControlMap = IndexedMap<ControlIdx, ControlNode>;
 /// A vector of control indices
 pub type CtrlVec = SmallVec<[ControlIdx; 4]>;
 
+#[derive(Debug, Eq, Copy, Clone, PartialEq, Hash, PartialOrd)]
+pub struct StaticControlIdx(u32);
+impl_index!(StaticControlIdx);
+
+/// A map storing [StaticControlNodes](StaticControlNode) indexed by
+/// [StaticControlIdx]
+pub type StaticControlMap = IndexedMap<StaticControlIdx, StaticControlNode>;
+
+/// A vector of static control indices
+pub type StaticCtrlVec = SmallVec<[StaticControlIdx; 4]>;
+
 /// An empty control node
 #[derive(Debug)]
 pub struct Empty;
@@ -161,6 +172,27 @@ impl While {
     }
 }
 
+/// A repeat control node
+#[derive(Debug)]
+pub struct Repeat {
+    body: ControlIdx,
+    num_repeats: u64,
+}
+
+impl Repeat {
+    pub fn new(body: ControlIdx, num_repeats: u64) -> Self {
+        Self { body, num_repeats }
+    }
+
+    pub fn body(&self) -> ControlIdx {
+        self.body
+    }
+
+    pub fn num_repeats(&self) -> u64 {
+        self.num_repeats
+    }
+}
+
 #[derive(Debug)]
 pub struct InvokeSignature {
     /// The ports attached to the input of the invoked cell, an association list
@@ -240,19 +272,191 @@ pub enum ControlNode {
     Par(Par),
     If(If),
     While(While),
+    Repeat(Repeat),
     Invoke(Invoke),
+    /// The root of a static control tree. The tree is executed as a single
+    /// unit by the program counter.
+    Static(StaticControlIdx),
 }
 
 impl ControlNode {
     pub fn is_leaf(&self) -> bool {
         match self {
             ControlNode::While(_)
+            | ControlNode::Repeat(_)
             | ControlNode::Seq(_)
             | ControlNode::Par(_)
             | ControlNode::If(_) => false,
             ControlNode::Enable(_)
             | ControlNode::Invoke(_)
-            | ControlNode::Empty(_) => true,
+            | ControlNode::Empty(_)
+            | ControlNode::Static(_) => true,
+        }
+    }
+}
+
+// ---------------------
+
+/// A static group enable node
+#[derive(Debug)]
+pub struct StaticEnable {
+    group: StaticGroupIdx,
+    latency: u64,
+}
+
+impl StaticEnable {
+    pub fn new(group: StaticGroupIdx, latency: u64) -> Self {
+        Self { group, latency }
+    }
+
+    pub fn group(&self) -> StaticGroupIdx {
+        self.group
+    }
+}
+
+/// Sequence of static control nodes. Each child starts in the cycle after its
+/// predecessor finishes.
+#[derive(Debug)]
+pub struct StaticSeq {
+    stms: StaticCtrlVec,
+    latency: u64,
+}
+
+impl StaticSeq {
+    pub fn new<S>(input: S, latency: u64) -> Self
+    where
+        S: Iterator<Item = StaticControlIdx>,
+    {
+        Self {
+            stms: input.collect(),
+            latency,
+        }
+    }
+
+    pub fn stms(&self) -> &[StaticControlIdx] {
+        &self.stms
+    }
+}
+
+/// Parallel composition of static control nodes. All children start in the
+/// same cycle.
+#[derive(Debug)]
+pub struct StaticPar {
+    stms: StaticCtrlVec,
+    latency: u64,
+}
+
+impl StaticPar {
+    pub fn new<S>(input: S, latency: u64) -> Self
+    where
+        S: Iterator<Item = StaticControlIdx>,
+    {
+        Self {
+            stms: input.collect(),
+            latency,
+        }
+    }
+
+    pub fn stms(&self) -> &[StaticControlIdx] {
+        &self.stms
+    }
+}
+
+/// A static if-then-else node. The condition is read in the first cycle and
+/// the node takes `latency` cycles regardless of the branch taken.
+#[derive(Debug)]
+pub struct StaticIf {
+    cond_port: PortRef,
+    tbranch: StaticControlIdx,
+    fbranch: StaticControlIdx,
+    latency: u64,
+}
+
+impl StaticIf {
+    pub fn new(
+        cond_port: PortRef,
+        tbranch: StaticControlIdx,
+        fbranch: StaticControlIdx,
+        latency: u64,
+    ) -> Self {
+        Self {
+            cond_port,
+            tbranch,
+            fbranch,
+            latency,
+        }
+    }
+
+    pub fn cond_port(&self) -> PortRef {
+        self.cond_port
+    }
+
+    pub fn tbranch(&self) -> StaticControlIdx {
+        self.tbranch
+    }
+
+    pub fn fbranch(&self) -> StaticControlIdx {
+        self.fbranch
+    }
+}
+
+/// A static repeat node
+#[derive(Debug)]
+pub struct StaticRepeat {
+    body: StaticControlIdx,
+    num_repeats: u64,
+    latency: u64,
+}
+
+impl StaticRepeat {
+    pub fn new(body: StaticControlIdx, num_repeats: u64, latency: u64) -> Self {
+        Self {
+            body,
+            num_repeats,
+            latency,
+        }
+    }
+
+    pub fn body(&self) -> StaticControlIdx {
+        self.body
+    }
+
+    pub fn num_repeats(&self) -> u64 {
+        self.num_repeats
+    }
+}
+
+/// A static invoke node. The go signal of the invoked cell is held high for
+/// `latency` cycles.
+#[derive(Debug)]
+pub struct StaticInvoke {
+    pub invoke: Invoke,
+    pub latency: u64,
+}
+
+/// An enum representing the different types of static control nodes
+#[derive(Debug)]
+pub enum StaticControlNode {
+    Empty(Empty),
+    Enable(StaticEnable),
+    Seq(StaticSeq),
+    Par(StaticPar),
+    If(StaticIf),
+    Repeat(StaticRepeat),
+    Invoke(StaticInvoke),
+}
+
+impl StaticControlNode {
+    /// The number of cycles this node takes to execute
+    pub fn latency(&self) -> u64 {
+        match self {
+            StaticControlNode::Empty(_) => 0,
+            StaticControlNode::Enable(e) => e.latency,
+            StaticControlNode::Seq(s) => s.latency,
+            StaticControlNode::Par(p) => p.latency,
+            StaticControlNode::If(i) => i.latency,
+            StaticControlNode::Repeat(r) => r.latency,
+            StaticControlNode::Invoke(i) => i.latency,
         }
     }
 }
@@ -1,8 +1,10 @@
 use ahash::{HashMap, HashMapExt};
 use calyx_ir::{self as cir, NumAttr, RRC};
+use calyx_utils::Error;
 use itertools::Itertools;
 
 use crate::{
+    errors::InterpreterResult,
     flatten::{
         flat_ir::{
             cell_prototype::{CellPrototype, LiteralOrPrimitive},
@@ -10,9 +12,12 @@ use crate::{
             flatten_trait::{flatten_tree, FlattenTree, SingleHandle},
             prelude::{
                 Assignment, AssignmentIdx, CellRef, CombGroup, CombGroupIdx,
-                ComponentIdx, GroupIdx, GuardIdx, PortRef,
+                ComponentIdx, GroupIdx, GuardIdx, PortRef, StaticGroupIdx,
+            },
+            wires::{
+                core::{AssignmentMap, Group, StaticGroup},
+                guards::{Guard, GuardMap},
             },
-            wires::{core::Group, guards::Guard},
         },
         structures::{
             context::{Context, InterpretationContext, SecondaryContext},
@@ -32,9 +37,13 @@ type ComponentMapper = HashMap<cir::Id, ComponentIdx>;
 pub struct GroupMapper {
     comb_groups: HashMap<*const cir::CombGroup, CombGroupIdx>,
     groups: HashMap<*const cir::Group, GroupIdx>,
+    static_groups: HashMap<*const cir::StaticGroup, StaticGroupIdx>,
+    /// The roots of the static control trees, which are flattened before the
+    /// rest of the control
+    static_roots: HashMap<*const cir::StaticControl, StaticControlIdx>,
 }
 
-pub fn translate(orig_ctx: &cir::Context) -> Context {
+pub fn translate(orig_ctx: &cir::Context) -> InterpreterResult<Context> {
     let mut ctx = Context::new();
 
     let mut component_id_map = ComponentMapper::new();
@@ -44,6 +53,7 @@ pub fn translate(orig_ctx: &cir::Context) -> Context {
     // iteration over the components in a post-order so this is a hack instead
 
     for comp in CompTraversal::new(&orig_ctx.components).iter() {
+        check_static_control(comp)?;
         translate_component(comp, &mut ctx, &mut component_id_map);
     }
 
@@ -51,7 +61,7 @@ pub fn translate(orig_ctx: &cir::Context) -> Context {
         .get(&orig_ctx.entrypoint().name)
         .expect("Unable to find entrypoint");
 
-    ctx
+    Ok(ctx)
 }
 
 #[must_use]
@@ -79,6 +89,79 @@ fn translate_group(
     )
 }
 
+#[must_use]
+fn translate_static_group(
+    group: &cir::StaticGroup,
+    ctx: &mut Context,
+    map: &PortMapper,
+) -> StaticGroup {
+    let id = ctx.secondary.string_table.insert(group.name());
+    let base = ctx.primary.assignments.peek_next_idx();
+
+    for assign in group.assignments.iter() {
+        let assign_new = translate_assignment(assign, &mut ctx.primary, map);
+        ctx.primary.assignments.push(assign_new);
+    }
+
+    let range: IndexRange<AssignmentIdx> =
+        IndexRange::new(base, ctx.primary.assignments.peek_next_idx());
+
+    StaticGroup::new(
+        id,
+        range,
+        *map[&group.get("go").as_raw()].unwrap_local(),
+        group.get_latency(),
+    )
+}
+
+/// Checks that the static groups and static control of `comp` can be run:
+/// timing guards must fit within the latency of their group and every static
+/// control node must take at least as long as its children.
+fn check_static_control(comp: &cir::Component) -> InterpreterResult<()> {
+    for group in comp.static_groups.iter() {
+        let group = group.borrow();
+        for assign in &group.assignments {
+            check_static_timing(&assign.guard, &group)?;
+        }
+    }
+    let control = comp.control.borrow();
+    let mut roots = vec![];
+    find_static_roots(&control, &mut roots);
+    roots.into_iter().try_for_each(check_static_latency)
+}
+
+/// Errors if the given guard uses a timing interval which does not fit within
+/// the latency of the static group it belongs to.
+fn check_static_timing(
+    guard: &cir::Guard<cir::StaticTiming>,
+    group: &cir::StaticGroup,
+) -> InterpreterResult<()> {
+    match guard {
+        cir::Guard::Or(a, b) | cir::Guard::And(a, b) => {
+            check_static_timing(a, group)?;
+            check_static_timing(b, group)
+        }
+        cir::Guard::Not(n) => check_static_timing(n, group),
+        cir::Guard::Info(timing) => {
+            let (start, end) = timing.get_interval();
+            if start < end && end <= group.get_latency() {
+                Ok(())
+            } else {
+                Err(Error::malformed_structure(format!(
+                    "static group `{}` has latency {} but uses the timing guard {}",
+                    group.name(),
+                    group.get_latency(),
+                    timing.to_string()
+                ))
+                .into())
+            }
+        }
+        cir::Guard::True | cir::Guard::CompOp(..) | cir::Guard::Port(_) => {
+            Ok(())
+        }
+    }
+}
+
 #[must_use]
 fn translate_comb_group(
     comb_group: &cir::CombGroup,
@@ -100,11 +183,14 @@ fn translate_comb_group(
 }
 
 #[must_use]
-fn translate_assignment(
-    assign: &cir::Assignment<cir::Nothing>,
+fn translate_assignment<T>(
+    assign: &cir::Assignment<T>,
     interp_ctx: &mut InterpretationContext,
     map: &PortMapper,
-) -> Assignment {
+) -> Assignment
+where
+    T: TranslateGuardInfo,
+{
     Assignment {
         dst: map[&assign.dst.as_raw()],
         src: map[&assign.src.as_raw()],
@@ -112,11 +198,14 @@ fn translate_assignment(
     }
 }
 #[must_use]
-fn translate_guard(
-    guard: &cir::Guard<cir::Nothing>,
+fn translate_guard<T>(
+    guard: &cir::Guard<T>,
     interp_ctx: &mut InterpretationContext,
     map: &PortMapper,
-) -> GuardIdx {
+) -> GuardIdx
+where
+    T: TranslateGuardInfo,
+{
     flatten_tree(guard, None, &mut interp_ctx.guards, map)
 }
 
@@ -150,6 +239,23 @@ fn translate_component(
     auxillary_component_info
         .set_group_range(group_base, ctx.primary.groups.peek_next_idx());
 
+    // Translate the static groups
+    let mut static_group_map = HashMap::with_capacity(comp.static_groups.len());
+
+    let static_group_base = ctx.primary.static_groups.peek_next_idx();
+
+    for group in comp.static_groups.iter() {
+        let group_brw = group.borrow();
+        let group_idx =
+            translate_static_group(&group_brw, ctx, &layout.port_map);
+        let k = ctx.primary.static_groups.push(group_idx);
+        static_group_map.insert(group.as_raw(), k);
+    }
+    auxillary_component_info.set_static_group_range(
+        static_group_base,
+        ctx.primary.static_groups.peek_next_idx(),
+    );
+
     let comb_group_base = ctx.primary.comb_groups.peek_next_idx();
     // Translate comb groups
     let mut comb_group_map = HashMap::with_capacity(comp.comb_groups.len());
@@ -169,6 +275,8 @@ fn translate_component(
     let group_mapper = GroupMapper {
         comb_groups: comb_group_map,
         groups: group_map,
+        static_groups: static_group_map,
+        static_roots: HashMap::new(),
     };
 
     // Continuous Assignments
@@ -192,12 +300,32 @@ fn translate_component(
     // control also must be taken since the flatten needs mutable access to it
     // and this is not possible when it is inside the context
     let mut taken_control = std::mem::take(&mut taken_ctx.primary.control);
+    let mut taken_static_control =
+        std::mem::take(&mut taken_ctx.primary.static_control);
 
     let ctrl_idx_start = taken_control.peek_next_idx();
+    let static_ctrl_idx_start = taken_static_control.peek_next_idx();
 
-    let argument_tuple =
+    let mut argument_tuple =
         (group_mapper, layout, taken_ctx, auxillary_component_info);
 
+    // the static trees live in their own map, so they are flattened first and
+    // the dynamic control refers to their roots
+    let mut static_roots = vec![];
+    find_static_roots(&ctrl_ref, &mut static_roots);
+    for root in static_roots {
+        let root_idx = flatten_tree(
+            root,
+            None,
+            &mut taken_static_control,
+            &argument_tuple,
+        );
+        argument_tuple
+            .0
+            .static_roots
+            .insert(root.as_raw(), root_idx);
+    }
+
     let control: Option<ControlIdx> =
         if matches!(*ctrl_ref, cir::Control::Empty(_)) {
             None
@@ -218,23 +346,31 @@ fn translate_component(
 
     // put stuff back
     taken_ctx.primary.control = taken_control;
+    taken_ctx.primary.static_control = taken_static_control;
     *ctx = taken_ctx;
 
     for node in IndexRange::new(ctrl_idx_start, ctrl_idx_end).iter() {
         if let ControlNode::Invoke(i) = &mut ctx.primary.control[node] {
-            let assign_start_index = ctx.primary.assignments.peek_next_idx();
-
-            for (dst, src) in i.signature.iter() {
-                ctx.primary.assignments.push(Assignment {
-                    dst: *dst,
-                    src: *src,
-                    guard: ctx.primary.guards.push(Guard::True),
-                });
-            }
+            insert_invoke_assignments(
+                i,
+                &mut ctx.primary.assignments,
+                &mut ctx.primary.guards,
+            );
+        }
+    }
 
-            let assign_end_index = ctx.primary.assignments.peek_next_idx();
-            i.assignments =
-                IndexRange::new(assign_start_index, assign_end_index);
+    let static_ctrl_idx_end = ctx.primary.static_control.peek_next_idx();
+    for node in
+        IndexRange::new(static_ctrl_idx_start, static_ctrl_idx_end).iter()
+    {
+        if let StaticControlNode::Invoke(i) =
+            &mut ctx.primary.static_control[node]
+        {
+            insert_invoke_assignments(
+                &mut i.invoke,
+                &mut ctx.primary.assignments,
+                &mut ctx.primary.guards,
+            );
         }
     }
 
@@ -273,6 +409,182 @@ fn translate_component(
     ctrl_ref
 }
 
+/// Creates the assignments which connect the signature of an invoke to the
+/// invoked cell
+fn insert_invoke_assignments(
+    invoke: &mut Invoke,
+    assignments: &mut AssignmentMap,
+    guards: &mut GuardMap,
+) {
+    let assign_start_index = assignments.peek_next_idx();
+
+    for (dst, src) in invoke.signature.iter() {
+        assignments.push(Assignment {
+            dst: *dst,
+            src: *src,
+            guard: guards.push(Guard::True),
+        });
+    }
+
+    let assign_end_index = assignments.peek_next_idx();
+    invoke.assignments = IndexRange::new(assign_start_index, assign_end_index);
+}
+
+/// Collects the roots of all the static control trees in the given control
+/// program
+fn find_static_roots<'a>(
+    control: &'a cir::Control,
+    roots: &mut Vec<&'a cir::StaticControl>,
+) {
+    match control {
+        cir::Control::Seq(cir::Seq { stmts, .. })
+        | cir::Control::Par(cir::Par { stmts, .. }) => {
+            for stmt in stmts {
+                find_static_roots(stmt, roots)
+            }
+        }
+        cir::Control::If(i) => {
+            find_static_roots(&i.tbranch, roots);
+            find_static_roots(&i.fbranch, roots);
+        }
+        cir::Control::While(cir::While { body, .. })
+        | cir::Control::Repeat(cir::Repeat { body, .. }) => {
+            find_static_roots(body, roots)
+        }
+        cir::Control::Static(s) => roots.push(s),
+        cir::Control::Invoke(_)
+        | cir::Control::Enable(_)
+        | cir::Control::Empty(_) => {}
+    }
+}
+
+/// Translates an invoke statement. This is shared by both `invoke` and
+/// `static invoke`.
+fn translate_invoke(
+    comp: &RRC<cir::Cell>,
+    inputs: &[(cir::Id, RRC<cir::Port>)],
+    outputs: &[(cir::Id, RRC<cir::Port>)],
+    ref_cells: &[(cir::Id, RRC<cir::Cell>)],
+    comb_group: &Option<RRC<cir::CombGroup>>,
+    aux: &(GroupMapper, Layout, Context, AuxillaryComponentInfo),
+) -> Invoke {
+    let (group_map, layout, ctx, comp_info) = aux;
+    let invoked_cell = layout.cell_map[&comp.as_raw()];
+
+    let invoked_comp = match invoked_cell {
+        CellRef::Local(local_off) => {
+            let def_idx = comp_info.cell_offset_map[local_off];
+
+            &ctx.secondary[def_idx].prototype
+        }
+        CellRef::Ref(ref_off) => {
+            let def_idx = comp_info.ref_cell_offset_map[ref_off];
+
+            &ctx.secondary[def_idx].prototype
+        }
+    };
+
+    let resolve_id =
+        |id: &cir::Id| *ctx.secondary.string_table.lookup_id(id).unwrap();
+
+    let resolve_invoked_cell_port = |id: &cir::Id| -> PortRef {
+        let id = resolve_id(id);
+
+        match invoked_cell {
+            CellRef::Local(l) => {
+                let def_idx = comp_info.cell_offset_map[l];
+                let cell_def = &ctx.secondary[def_idx];
+
+                cell_def
+                    .ports
+                    .into_iter()
+                    .find(|&candidate_offset| {
+                        let candidate_def =
+                            comp_info.port_offset_map[candidate_offset];
+                        ctx.secondary[candidate_def].name == id
+                    })
+                    .unwrap()
+                    .into()
+            }
+            CellRef::Ref(r) => {
+                let def_idx = comp_info.ref_cell_offset_map[r];
+                let cell_def = &ctx.secondary[def_idx];
+
+                cell_def
+                    .ports
+                    .into_iter()
+                    .find(|&candidate_offset| {
+                        let candidate_def =
+                            comp_info.ref_port_offset_map[candidate_offset];
+                        ctx.secondary[candidate_def] == id
+                    })
+                    .unwrap()
+                    .into()
+            }
+        }
+    };
+
+    let ref_cells = ref_cells.iter().map(|(ref_cell_id, realizing_cell)| {
+        let invoked_comp = invoked_comp
+            .as_component()
+            .expect("cannot invoke a non-component with ref cells");
+        let target = &ctx.secondary[*invoked_comp]
+            .ref_cell_offset_map
+            .iter()
+            .find(|(_idx, &def_idx)| {
+                let def = &ctx.secondary[def_idx];
+                def.name == resolve_id(ref_cell_id)
+            })
+            .map(|(t, _)| t)
+            .expect(
+                "Unable to find the given ref cell in the invoked component",
+            );
+        (*target, layout.cell_map[&realizing_cell.as_raw()])
+    });
+
+    let inputs = inputs.iter().map(|(id, port)| {
+        (
+            resolve_invoked_cell_port(id),
+            layout.port_map[&port.as_raw()],
+        )
+    });
+
+    let outputs = outputs.iter().map(|(id, port)| {
+        (
+            resolve_invoked_cell_port(id),
+            layout.port_map[&port.as_raw()],
+        )
+    });
+
+    let go = comp.borrow().find_all_with_attr(NumAttr::Go).collect_vec();
+    assert!(
+        go.len() == 1,
+        "cannot handle multiple go ports yet or the invoked cell has none"
+    );
+    let comp_go = layout.port_map[&go[0].as_raw()];
+    let done = comp
+        .borrow()
+        .find_all_with_attr(NumAttr::Done)
+        .collect_vec();
+    assert!(
+        done.len() == 1,
+        "cannot handle multiple done ports yet or the invoked cell has none"
+    );
+    let comp_done = layout.port_map[&done[0].as_raw()];
+
+    Invoke::new(
+        invoked_cell,
+        comb_group
+            .as_ref()
+            .map(|x| group_map.comb_groups[&x.as_raw()]),
+        ref_cells,
+        inputs,
+        outputs,
+        comp_go,
+        comp_done,
+    )
+}
+
 fn insert_port(
     secondary_ctx: &mut SecondaryContext,
     aux: &mut AuxillaryComponentInfo,
@@ -397,6 +709,15 @@ fn compute_local_layout(
         }
     }
 
+    // static groups only have a go hole
+    for group in &comp.static_groups {
+        let group = group.borrow();
+        let port = group.get("go");
+        let local_offset =
+            insert_port(&mut ctx.secondary, aux, &port, ContainmentType::Local);
+        layout.port_map.insert(port.as_raw(), local_offset);
+    }
+
     let mut sub_component_queue = vec![];
 
     // third, the primitive cells
@@ -496,7 +817,26 @@ fn is_primitive(cell_ref: &std::cell::Ref<cir::Cell>) -> bool {
         || matches!(&cell_ref.prototype, cir::CellType::Constant { .. })
 }
 
-impl FlattenTree for cir::Guard<cir::Nothing> {
+/// The translation of the extra information attached to a [cir::Guard::Info]
+/// node
+trait TranslateGuardInfo {
+    fn translate_info(&self) -> Guard;
+}
+
+impl TranslateGuardInfo for cir::Nothing {
+    fn translate_info(&self) -> Guard {
+        unreachable!("Guard<Nothing> should not contain info")
+    }
+}
+
+impl TranslateGuardInfo for cir::StaticTiming {
+    fn translate_info(&self) -> Guard {
+        let (start, end) = self.get_interval();
+        Guard::Timing(start, end)
+    }
+}
+
+impl<T: TranslateGuardInfo> FlattenTree for cir::Guard<T> {
     type Output = Guard;
     type IdxType = GuardIdx;
     type AuxillaryData = PortMapper;
@@ -521,7 +861,7 @@ impl FlattenTree for cir::Guard<cir::Nothing> {
                 *aux.get(&b.as_raw()).unwrap(),
             ),
             cir::Guard::Port(p) => Guard::Port(*aux.get(&p.as_raw()).unwrap()),
-            cir::Guard::Info(_) => panic!("Guard::Info(_) not handled yet"),
+            cir::Guard::Info(info) => info.translate_info(),
         }
     }
 }
@@ -538,7 +878,7 @@ impl FlattenTree for cir::Control {
         mut handle: SingleHandle<'_, 'data, Self, Self::IdxType, Self::Output>,
         aux: &Self::AuxillaryData,
     ) -> Self::Output {
-        let (group_map, layout, ctx, comp_info) = aux;
+        let (group_map, layout, _, _) = aux;
         match self {
             cir::Control::Seq(s) => ControlNode::Seq(Seq::new(
                 s.stmts.iter().map(|s| handle.enqueue(s)),
@@ -557,126 +897,127 @@ impl FlattenTree for cir::Control {
                 w.cond.as_ref().map(|c| group_map.comb_groups[&c.as_raw()]),
                 handle.enqueue(&w.body),
             )),
-            cir::Control::Invoke(inv) => {
-                let invoked_cell = layout.cell_map[&inv.comp.as_raw()];
-
-                let invoked_comp = match invoked_cell {
-                    CellRef::Local(local_off) => {
-                        let def_idx = comp_info.cell_offset_map[local_off];
-
-                        &ctx.secondary[def_idx].prototype
-                    }
-                    CellRef::Ref(ref_off) => {
-                        let def_idx = comp_info.ref_cell_offset_map[ref_off];
-
-                        &ctx.secondary[def_idx].prototype
-                    }
-                };
-
-                let resolve_id = |id: &cir::Id| {
-                    *ctx.secondary.string_table.lookup_id(id).unwrap()
-                };
-
-                let resolve_invoked_cell_port = |id: &cir::Id| -> PortRef {
-                    let id = resolve_id(id);
-
-                    match invoked_cell {
-                        CellRef::Local(l) => {
-                            let def_idx = comp_info.cell_offset_map[l];
-                            let cell_def = &ctx.secondary[def_idx];
-
-                            cell_def
-                                .ports
-                                .into_iter()
-                                .find(|&candidate_offset| {
-                                    let candidate_def = comp_info
-                                        .port_offset_map[candidate_offset];
-                                    ctx.secondary[candidate_def].name == id
-                                })
-                                .unwrap()
-                                .into()
-                        }
-                        CellRef::Ref(r) => {
-                            let def_idx = comp_info.ref_cell_offset_map[r];
-                            let cell_def = &ctx.secondary[def_idx];
-
-                            cell_def
-                                .ports
-                                .into_iter()
-                                .find(|&candidate_offset| {
-                                    let candidate_def = comp_info
-                                        .ref_port_offset_map[candidate_offset];
-                                    ctx.secondary[candidate_def] == id
-                                })
-                                .unwrap()
-                                .into()
-                        }
-                    }
-                };
-
-                let ref_cells = inv.ref_cells.iter().map(|(ref_cell_id, realizing_cell)| {
-                        let invoked_comp = invoked_comp.as_component().expect("cannot invoke a non-component with ref cells");
-                        let target = &ctx.secondary[*invoked_comp].ref_cell_offset_map.iter().find(|(_idx, &def_idx)| {
-                            let def = &ctx.secondary[def_idx];
-                            def.name == resolve_id(ref_cell_id)
-                        }).map(|(t, _)| t).expect("Unable to find the given ref cell in the invoked component");
-                        (*target, layout.cell_map[&realizing_cell.as_raw()])
-                    });
-
-                let inputs = inv.inputs.iter().map(|(id, port)| {
-                    (
-                        resolve_invoked_cell_port(id),
-                        layout.port_map[&port.as_raw()],
-                    )
-                });
-
-                let outputs = inv.outputs.iter().map(|(id, port)| {
-                    (
-                        resolve_invoked_cell_port(id),
-                        layout.port_map[&port.as_raw()],
-                    )
-                });
-
-                let go = inv
-                    .comp
-                    .borrow()
-                    .find_all_with_attr(NumAttr::Go)
-                    .collect_vec();
-                assert!(go.len() == 1, "cannot handle multiple go ports yet or the invoked cell has none");
-                let comp_go = layout.port_map[&go[0].as_raw()];
-                let done = inv
-                    .comp
-                    .borrow()
-                    .find_all_with_attr(NumAttr::Done)
-                    .collect_vec();
-                assert!(
-                    done.len() == 1,
-                    "cannot handle multiple done ports yet or the invoked cell has none"
-                );
-                let comp_done = layout.port_map[&done[0].as_raw()];
-
-                ControlNode::Invoke(Invoke::new(
-                    invoked_cell,
-                    inv.comb_group
-                        .as_ref()
-                        .map(|x| group_map.comb_groups[&x.as_raw()]),
-                    ref_cells,
-                    inputs,
-                    outputs,
-                    comp_go,
-                    comp_done,
-                ))
-            }
+            cir::Control::Invoke(inv) => ControlNode::Invoke(translate_invoke(
+                &inv.comp,
+                &inv.inputs,
+                &inv.outputs,
+                &inv.ref_cells,
+                &inv.comb_group,
+                aux,
+            )),
             cir::Control::Enable(e) => ControlNode::Enable(Enable::new(
                 group_map.groups[&e.group.as_raw()],
             )),
             cir::Control::Empty(_) => ControlNode::Empty(Empty),
-            cir::Control::Static(_) => {
-                todo!("The interpreter does not support static control yet")
+            cir::Control::Static(s) => {
+                ControlNode::Static(group_map.static_roots[&s.as_raw()])
+            }
+            cir::Control::Repeat(r) => ControlNode::Repeat(Repeat::new(
+                handle.enqueue(&r.body),
+                r.num_repeats,
+            )),
+        }
+    }
+}
+
+impl FlattenTree for cir::StaticControl {
+    type Output = StaticControlNode;
+
+    type IdxType = StaticControlIdx;
+
+    type AuxillaryData = (GroupMapper, Layout, Context, AuxillaryComponentInfo);
+
+    fn process_element<'data>(
+        &'data self,
+        mut handle: SingleHandle<'_, 'data, Self, Self::IdxType, Self::Output>,
+        aux: &Self::AuxillaryData,
+    ) -> Self::Output {
+        let (group_map, layout, _, _) = aux;
+        match self {
+            cir::StaticControl::Repeat(r) => {
+                StaticControlNode::Repeat(StaticRepeat::new(
+                    handle.enqueue(&r.body),
+                    r.num_repeats,
+                    r.latency,
+                ))
             }
-            cir::Control::Repeat(_) => {
-                todo!("The interpreter does not support repeat yet")
+            cir::StaticControl::Enable(e) => {
+                StaticControlNode::Enable(StaticEnable::new(
+                    group_map.static_groups[&e.group.as_raw()],
+                    e.group.borrow().get_latency(),
+                ))
+            }
+            cir::StaticControl::Par(p) => {
+                StaticControlNode::Par(StaticPar::new(
+                    p.stmts.iter().map(|s| handle.enqueue(s)),
+                    p.latency,
+                ))
+            }
+            cir::StaticControl::Seq(s) => {
+                StaticControlNode::Seq(StaticSeq::new(
+                    s.stmts.iter().map(|s| handle.enqueue(s)),
+                    s.latency,
+                ))
+            }
+            cir::StaticControl::If(i) => StaticControlNode::If(StaticIf::new(
+                layout.port_map[&i.port.as_raw()],
+                handle.enqueue(&i.tbranch),
+                handle.enqueue(&i.fbranch),
+                i.latency,
+            )),
+            cir::StaticControl::Empty(_) => StaticControlNode::Empty(Empty),
+            cir::StaticControl::Invoke(inv) => {
+                StaticControlNode::Invoke(StaticInvoke {
+                    invoke: translate_invoke(
+                        &inv.comp,
+                        &inv.inputs,
+                        &inv.outputs,
+                        &inv.ref_cells,
+                        &inv.comb_group,
+                        aux,
+                    ),
+                    latency: inv.latency,
+                })
             }
         }
     }
 }
+
+/// Errors if a node of the static control tree rooted at `control` claims to
+/// take fewer cycles than its children need.
+fn check_static_latency(control: &cir::StaticControl) -> InterpreterResult<()> {
+    let (children, required, kind): (Vec<&cir::StaticControl>, u64, &str) =
+        match control {
+            cir::StaticControl::Seq(s) => (
+                s.stmts.iter().collect(),
+                s.stmts.iter().map(|c| c.get_latency()).sum(),
+                "static seq",
+            ),
+            cir::StaticControl::Par(p) => (
+                p.stmts.iter().collect(),
+                p.stmts.iter().map(|c| c.get_latency()).max().unwrap_or(0),
+                "static par",
+            ),
+            cir::StaticControl::If(i) => (
+                vec![&*i.tbranch, &*i.fbranch],
+                i.tbranch.get_latency().max(i.fbranch.get_latency()),
+                "static if",
+            ),
+            cir::StaticControl::Repeat(r) => (
+                vec![&*r.body],
+                r.num_repeats * r.body.get_latency(),
+                "static repeat",
+            ),
+            cir::StaticControl::Enable(_)
+            | cir::StaticControl::Empty(_)
+            | cir::StaticControl::Invoke(_) => return Ok(()),
+        };
+    if control.get_latency() < required {
+        return Err(Error::malformed_structure(format!(
+            "{kind} has latency {} but its children require {required} cycles",
+            control.get_latency()
+        ))
+        .into());
+    }
+    children.into_iter().try_for_each(check_static_latency)
+}
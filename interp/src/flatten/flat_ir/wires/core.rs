@@ -5,6 +5,7 @@ use crate::flatten::{
 
 pub type AssignmentMap = IndexedMap<AssignmentIdx, Assignment>;
 pub type GroupMap = IndexedMap<GroupIdx, Group>;
+pub type StaticGroupMap = IndexedMap<StaticGroupIdx, StaticGroup>;
 pub type CombGroupMap = IndexedMap<CombGroupIdx, CombGroup>;
 
 #[derive(Debug)]
@@ -45,6 +46,39 @@ impl Group {
     }
 }
 
+/// A group which runs for a fixed number of cycles. Static groups have no done
+/// hole, they are finished once `latency` cycles have passed.
+#[derive(Debug)]
+pub struct StaticGroup {
+    name: Identifier,
+    /// the assignments in this group
+    pub assignments: IndexRange<AssignmentIdx>,
+    /// the go signal for this group
+    pub go: LocalPortOffset,
+    /// the number of cycles this group takes to run
+    pub latency: u64,
+}
+
+impl StaticGroup {
+    pub fn new(
+        name: Identifier,
+        assignments: IndexRange<AssignmentIdx>,
+        go: LocalPortOffset,
+        latency: u64,
+    ) -> Self {
+        Self {
+            name,
+            assignments,
+            go,
+            latency,
+        }
+    }
+
+    pub fn name(&self) -> Identifier {
+        self.name
+    }
+}
+
 #[derive(Debug)]
 pub struct CombGroup {
     name: Identifier,
@@ -15,4 +15,8 @@ pub enum Guard {
     Not(GuardIdx),
     Comp(PortComp, PortRef, PortRef),
     Port(PortRef),
+    /// A static timing guard `%[start:end]`. This is true when the active
+    /// static group has been running for at least `start` cycles and fewer
+    /// than `end` cycles.
+    Timing(u64, u64),
 }
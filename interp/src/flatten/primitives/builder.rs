@@ -2,7 +2,7 @@ use super::{combinational::*, stateful::*, Primitive};
 use crate::{
     flatten::{
         flat_ir::{
-            cell_prototype::{CellPrototype, MemType, PrimType1},
+            cell_prototype::{CellPrototype, FPType, MemType, PrimType1},
             prelude::{CellInfo, GlobalPortIdx},
         },
         structures::context::Context,
@@ -49,6 +49,7 @@ pub fn build_primitive(
             PrimType1::Rsh => Box::new(StdRsh::new(base_port, *width)),
             PrimType1::Mux => Box::new(StdMux::new(base_port)),
             PrimType1::Wire => Box::new(StdWire::new(base_port)),
+            PrimType1::Undef => Box::new(StdUndef),
             PrimType1::SignedAdd => Box::new(StdAdd::new(base_port)),
             PrimType1::SignedSub => Box::new(StdSub::new(base_port)),
             PrimType1::SignedGt => Box::new(StdSgt::new(base_port)),
@@ -59,11 +60,22 @@ pub fn build_primitive(
             PrimType1::SignedLe => Box::new(StdSle::new(base_port)),
             PrimType1::SignedLsh => Box::new(StdSlsh::new(base_port)),
             PrimType1::SignedRsh => Box::new(StdSrsh::new(base_port)),
-            PrimType1::MultPipe => todo!(),
-            PrimType1::SignedMultPipe => todo!(),
-            PrimType1::DivPipe => todo!(),
-            PrimType1::SignedDivPipe => todo!(),
-            PrimType1::Sqrt => todo!(),
+            PrimType1::MultPipe => {
+                Box::new(StdMultPipe::<false>::new(base_port, *width))
+            }
+            PrimType1::SignedMultPipe => {
+                Box::new(StdMultPipe::<true>::new(base_port, *width))
+            }
+            PrimType1::DivPipe => {
+                Box::new(StdDivPipe::<false, false>::new(base_port, *width))
+            }
+            PrimType1::SignedDivPipe => {
+                Box::new(StdDivPipe::<true, false>::new(base_port, *width))
+            }
+            PrimType1::Sqrt => Box::new(Sqrt::new(base_port, *width)),
+            PrimType1::PipelinedMult => {
+                Box::new(StaticMultPipe::<false>::new(base_port, *width))
+            }
             PrimType1::UnsynMult => {
                 Box::new(StdUnsynMult::new(base_port, *width))
             }
@@ -84,11 +96,60 @@ pub fn build_primitive(
             }
         },
         CellPrototype::FixedPoint {
-            op: _,
-            width: _,
-            int_width: _,
-            frac_width: _,
-        } => todo!("Fixed point implementations not available yet"),
+            op,
+            width,
+            int_width,
+            frac_width,
+        } => match op {
+            FPType::Add | FPType::SignedAdd => {
+                Box::new(StdFpAdd::new(base_port))
+            }
+            FPType::Sub | FPType::SignedSub => {
+                Box::new(StdFpSub::new(base_port))
+            }
+            FPType::Gt => Box::new(StdFpGt::new(base_port)),
+            FPType::SignedGt => Box::new(StdFpSgt::new(base_port)),
+            FPType::SignedLt => Box::new(StdFpSlt::new(base_port)),
+            FPType::Mult => Box::new(StdMultPipe::<false>::new_fixed_point(
+                base_port,
+                *width,
+                *frac_width,
+            )),
+            FPType::SignedMult => {
+                Box::new(StdMultPipe::<true>::new_fixed_point(
+                    base_port,
+                    *width,
+                    *frac_width,
+                ))
+            }
+            FPType::Div => {
+                Box::new(StdDivPipe::<false, true>::new_fixed_point(
+                    base_port,
+                    *width,
+                    *frac_width,
+                ))
+            }
+            FPType::SignedDiv => {
+                Box::new(StdDivPipe::<true, true>::new_fixed_point(
+                    base_port,
+                    *width,
+                    *frac_width,
+                ))
+            }
+            FPType::Sqrt => Box::new(Sqrt::new_fixed_point(
+                base_port,
+                *width,
+                *int_width,
+                *frac_width,
+            )),
+            FPType::PipelinedSignedMult => {
+                Box::new(StaticMultPipe::<true>::new_fixed_point(
+                    base_port,
+                    *width,
+                    *frac_width,
+                ))
+            }
+        },
         CellPrototype::Slice {
             in_width: _, // Not actually needed, should probably remove
             out_width,
@@ -51,6 +51,20 @@ impl Primitive for StdConst {
     }
 }
 
+/// A primitive whose output is never defined. Since ports are undefined at the
+/// start of each cycle there is nothing for it to do.
+pub struct StdUndef;
+
+impl Primitive for StdUndef {
+    fn has_comb(&self) -> bool {
+        false
+    }
+
+    fn has_stateful(&self) -> bool {
+        false
+    }
+}
+
 pub struct StdMux {
     base: GlobalPortIdx,
 }
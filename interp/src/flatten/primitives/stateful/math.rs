@@ -1 +1,535 @@
+use ibig::ops::RemEuclid;
 
+use crate::{
+    errors::{InterpreterError, InterpreterResult},
+    flatten::{
+        flat_ir::prelude::{AssignedValue, GlobalPortIdx},
+        primitives::{
+            declare_ports, ports, prim_trait::UpdateResult, Primitive,
+        },
+        structures::environment::PortMap,
+    },
+    primitives::{
+        prim_utils::ShiftBuffer,
+        stateful::{floored_division, int_sqrt},
+    },
+    serialization::{Entry, Serializable},
+    values::Value,
+};
+
+/// Reads the value of an operand which is about to be latched by a primitive.
+/// Latching an undefined value is treated the same way as writing one into a
+/// register.
+fn read_operand(
+    port_map: &PortMap,
+    port: GlobalPortIdx,
+) -> InterpreterResult<Value> {
+    Ok(port_map[port]
+        .as_option()
+        .ok_or(InterpreterError::UndefinedWrite)?
+        .val()
+        .clone())
+}
+
+fn bit(high: bool) -> AssignedValue {
+    if high {
+        AssignedValue::cell_b_high()
+    } else {
+        AssignedValue::cell_b_low()
+    }
+}
+
+/// Computes the product of two fixed-point numbers with `frac_width`
+/// fractional bits. Integer multiplication is the case where `frac_width` is
+/// zero.
+fn fixed_point_product(
+    left: &Value,
+    right: &Value,
+    frac_width: u32,
+    signed: bool,
+) -> Value {
+    let width = left.width();
+    let backing_val = if signed {
+        Value::from(left.as_signed() * right.as_signed(), 2 * width)
+    } else {
+        Value::from(left.as_unsigned() * right.as_unsigned(), 2 * width)
+    };
+
+    backing_val.slice_out(
+        (width + frac_width as u64 - 1) as usize,
+        frac_width as usize,
+    )
+}
+
+/// The multiplication primitives `std_mult_pipe`, `std_smult_pipe`,
+/// `std_fp_mult_pipe`, and `std_fp_smult_pipe`. The product is available three
+/// cycles after `go` is first asserted, matching the `@interval(3)` annotation
+/// in the signature. Lowering `go` before then abandons the computation.
+pub struct StdMultPipe<const SIGNED: bool> {
+    base_port: GlobalPortIdx,
+    frac_width: u32,
+    pipeline: ShiftBuffer<Value, 2>,
+    output: Value,
+    done_is_high: bool,
+}
+
+impl<const SIGNED: bool> StdMultPipe<SIGNED> {
+    declare_ports![
+        _CLK: 0, RESET: 1, GO: 2, LEFT: 3, RIGHT: 4, OUT: 5, DONE: 6
+    ];
+
+    pub fn new(base_port: GlobalPortIdx, width: u32) -> Self {
+        Self::new_fixed_point(base_port, width, 0)
+    }
+
+    pub fn new_fixed_point(
+        base_port: GlobalPortIdx,
+        width: u32,
+        frac_width: u32,
+    ) -> Self {
+        Self {
+            base_port,
+            frac_width,
+            pipeline: ShiftBuffer::default(),
+            output: Value::zeroes(width),
+            done_is_high: false,
+        }
+    }
+}
+
+impl<const SIGNED: bool> Primitive for StdMultPipe<SIGNED> {
+    fn exec_comb(&self, port_map: &mut PortMap) -> UpdateResult {
+        ports![&self.base_port; out: Self::OUT, done: Self::DONE];
+
+        Ok(port_map
+            .insert_val(out, AssignedValue::cell_value(self.output.clone()))?
+            | port_map.insert_val(done, bit(self.done_is_high))?)
+    }
+
+    fn exec_cycle(&mut self, port_map: &mut PortMap) -> UpdateResult {
+        ports![&self.base_port;
+            reset: Self::RESET,
+            go: Self::GO,
+            left: Self::LEFT,
+            right: Self::RIGHT
+        ];
+
+        self.done_is_high = false;
+
+        if port_map[reset].as_bool().unwrap_or_default() {
+            self.pipeline.reset();
+            self.output = Value::zeroes(self.output.width());
+        } else if port_map[go].as_bool().unwrap_or_default() {
+            let product = fixed_point_product(
+                &read_operand(port_map, left)?,
+                &read_operand(port_map, right)?,
+                self.frac_width,
+                SIGNED,
+            );
+
+            if let Some(product) = self.pipeline.shift(Some(product)) {
+                self.output = product;
+                self.done_is_high = true;
+            }
+        } else {
+            self.pipeline.reset();
+        }
+
+        self.exec_comb(port_map)
+    }
+
+    fn serialize(
+        &self,
+        code: Option<crate::debugger::PrintCode>,
+    ) -> Serializable {
+        Serializable::Val(Entry::from_val_code(
+            &self.output,
+            &code.unwrap_or_default(),
+        ))
+    }
+
+    fn has_serializable_state(&self) -> bool {
+        true
+    }
+}
+
+/// The latency-sensitive multipliers `pipelined_mult` and
+/// `pipelined_fp_smult` from `pipelined.futil`. These have no go/done
+/// interface, instead the output always holds the product of the inputs from
+/// four cycles prior as declared by their `static<4>` signatures.
+pub struct StaticMultPipe<const SIGNED: bool> {
+    reset: Option<GlobalPortIdx>,
+    left: GlobalPortIdx,
+    right: GlobalPortIdx,
+    out: GlobalPortIdx,
+    frac_width: u32,
+    pipeline: ShiftBuffer<Value, 3>,
+    output: Value,
+}
+
+impl<const SIGNED: bool> StaticMultPipe<SIGNED> {
+    /// Constructs the unsigned integer multiplier `pipelined_mult`
+    pub fn new(base_port: GlobalPortIdx, width: u32) -> Self {
+        ports![&base_port; left: 1, right: 2, out: 3];
+        Self::construct(None, left, right, out, width, 0)
+    }
+
+    /// Constructs the fixed-point multiplier `pipelined_fp_smult` which,
+    /// unlike its integer counterpart, has a reset port
+    pub fn new_fixed_point(
+        base_port: GlobalPortIdx,
+        width: u32,
+        frac_width: u32,
+    ) -> Self {
+        ports![&base_port; reset: 1, left: 2, right: 3, out: 4];
+        Self::construct(Some(reset), left, right, out, width, frac_width)
+    }
+
+    fn construct(
+        reset: Option<GlobalPortIdx>,
+        left: GlobalPortIdx,
+        right: GlobalPortIdx,
+        out: GlobalPortIdx,
+        width: u32,
+        frac_width: u32,
+    ) -> Self {
+        Self {
+            reset,
+            left,
+            right,
+            out,
+            frac_width,
+            pipeline: ShiftBuffer::default(),
+            output: Value::zeroes(width),
+        }
+    }
+}
+
+impl<const SIGNED: bool> Primitive for StaticMultPipe<SIGNED> {
+    fn exec_comb(&self, port_map: &mut PortMap) -> UpdateResult {
+        port_map.insert_val(
+            self.out,
+            AssignedValue::cell_value(self.output.clone()),
+        )
+    }
+
+    fn exec_cycle(&mut self, port_map: &mut PortMap) -> UpdateResult {
+        let reset = self
+            .reset
+            .map(|reset| port_map[reset].as_bool().unwrap_or_default())
+            .unwrap_or_default();
+
+        if reset {
+            self.pipeline.reset();
+            self.output = Value::zeroes(self.output.width());
+        } else {
+            // The inputs are only meaningful on the cycles the static
+            // schedule says they are, so undefined values simply propagate
+            // through the pipeline as zeroes rather than raising an error
+            let product =
+                match (port_map[self.left].val(), port_map[self.right].val()) {
+                    (Some(left), Some(right)) => fixed_point_product(
+                        left,
+                        right,
+                        self.frac_width,
+                        SIGNED,
+                    ),
+                    _ => Value::zeroes(self.output.width()),
+                };
+
+            if let Some(product) = self.pipeline.shift(Some(product)) {
+                self.output = product;
+            }
+        }
+
+        self.exec_comb(port_map)
+    }
+
+    fn serialize(
+        &self,
+        code: Option<crate::debugger::PrintCode>,
+    ) -> Serializable {
+        Serializable::Val(Entry::from_val_code(
+            &self.output,
+            &code.unwrap_or_default(),
+        ))
+    }
+
+    fn has_serializable_state(&self) -> bool {
+        true
+    }
+}
+
+/// A computation which has been started by an iterative primitive along with
+/// the number of cycles left until its result is ready.
+struct InFlight<T> {
+    result: T,
+    remaining: u64,
+}
+
+/// The division primitives `std_div_pipe`, `std_sdiv_pipe`, `std_fp_div_pipe`,
+/// and `std_fp_sdiv_pipe`. Once started these run to completion regardless of
+/// `go`, taking as many cycles as the iterative hardware implementation does:
+/// `WIDTH + 2` cycles for integer division and `WIDTH + FRAC_WIDTH + 1` for
+/// fixed-point division. A zero dividend finishes after a single cycle.
+pub struct StdDivPipe<const SIGNED: bool, const FIXED_POINT: bool> {
+    base_port: GlobalPortIdx,
+    width: u32,
+    frac_width: u32,
+    in_flight: Option<InFlight<(Value, Value)>>,
+    quotient: Value,
+    remainder: Value,
+    done_is_high: bool,
+}
+
+impl<const SIGNED: bool, const FIXED_POINT: bool>
+    StdDivPipe<SIGNED, FIXED_POINT>
+{
+    declare_ports![_CLK: 0, RESET: 1, GO: 2, LEFT: 3, RIGHT: 4];
+
+    pub fn new(base_port: GlobalPortIdx, width: u32) -> Self {
+        Self::new_fixed_point(base_port, width, 0)
+    }
+
+    pub fn new_fixed_point(
+        base_port: GlobalPortIdx,
+        width: u32,
+        frac_width: u32,
+    ) -> Self {
+        Self {
+            base_port,
+            width,
+            frac_width,
+            in_flight: None,
+            quotient: Value::zeroes(width),
+            remainder: Value::zeroes(width),
+            done_is_high: false,
+        }
+    }
+
+    /// The integer primitives declare the quotient before the remainder while
+    /// the fixed-point ones use the opposite order
+    fn output_ports(&self) -> (GlobalPortIdx, GlobalPortIdx, GlobalPortIdx) {
+        if FIXED_POINT {
+            ports![&self.base_port; remainder: 5, quotient: 6, done: 7];
+            (quotient, remainder, done)
+        } else {
+            ports![&self.base_port; quotient: 5, remainder: 6, done: 7];
+            (quotient, remainder, done)
+        }
+    }
+
+    fn latency(&self, left: &Value) -> u64 {
+        if left.as_unsigned() == 0_u32.into() {
+            1
+        } else if FIXED_POINT {
+            (self.width + self.frac_width + 1) as u64
+        } else {
+            (self.width + 2) as u64
+        }
+    }
+
+    fn divide(&self, left: &Value, right: &Value) -> (Value, Value) {
+        if right.as_unsigned() == 0_u32.into() {
+            return (Value::zeroes(self.width), Value::zeroes(self.width));
+        }
+
+        let shift = self.frac_width as usize;
+        if SIGNED {
+            let (left, right) = (left.as_signed(), right.as_signed());
+            let quotient = (&left << shift) / &right;
+            let remainder = &left - &right * floored_division(&left, &right);
+            (
+                Value::from(quotient, self.width),
+                Value::from(remainder, self.width),
+            )
+        } else {
+            let (left, right) = (left.as_unsigned(), right.as_unsigned());
+            let quotient = (&left << shift) / &right;
+            let remainder = left.rem_euclid(right);
+            (
+                Value::from(quotient, self.width),
+                Value::from(remainder, self.width),
+            )
+        }
+    }
+}
+
+impl<const SIGNED: bool, const FIXED_POINT: bool> Primitive
+    for StdDivPipe<SIGNED, FIXED_POINT>
+{
+    fn exec_comb(&self, port_map: &mut PortMap) -> UpdateResult {
+        let (quotient, remainder, done) = self.output_ports();
+
+        Ok(port_map.insert_val(
+            quotient,
+            AssignedValue::cell_value(self.quotient.clone()),
+        )? | port_map.insert_val(
+            remainder,
+            AssignedValue::cell_value(self.remainder.clone()),
+        )? | port_map.insert_val(done, bit(self.done_is_high))?)
+    }
+
+    fn exec_cycle(&mut self, port_map: &mut PortMap) -> UpdateResult {
+        ports![&self.base_port;
+            reset: Self::RESET,
+            go: Self::GO,
+            left: Self::LEFT,
+            right: Self::RIGHT
+        ];
+
+        self.done_is_high = false;
+
+        if port_map[reset].as_bool().unwrap_or_default() {
+            self.in_flight = None;
+            self.quotient = Value::zeroes(self.width);
+            self.remainder = Value::zeroes(self.width);
+        } else if let Some(in_flight) = self.in_flight.as_mut() {
+            in_flight.remaining -= 1;
+            if in_flight.remaining == 0 {
+                let (quotient, remainder) =
+                    self.in_flight.take().unwrap().result;
+                self.quotient = quotient;
+                self.remainder = remainder;
+                self.done_is_high = true;
+            }
+        } else if port_map[go].as_bool().unwrap_or_default() {
+            let left = read_operand(port_map, left)?;
+            let right = read_operand(port_map, right)?;
+
+            let result = self.divide(&left, &right);
+            let latency = self.latency(&left);
+
+            if latency == 1 {
+                (self.quotient, self.remainder) = result;
+                self.done_is_high = true;
+            } else {
+                self.in_flight = Some(InFlight {
+                    result,
+                    remaining: latency - 1,
+                });
+            }
+        }
+
+        self.exec_comb(port_map)
+    }
+
+    fn serialize(
+        &self,
+        code: Option<crate::debugger::PrintCode>,
+    ) -> Serializable {
+        let code = code.unwrap_or_default();
+        Serializable::Array(
+            [&self.quotient, &self.remainder]
+                .into_iter()
+                .map(|x| Entry::from_val_code(x, &code))
+                .collect(),
+            2.into(),
+        )
+    }
+
+    fn has_serializable_state(&self) -> bool {
+        true
+    }
+}
+
+/// The square root primitives `sqrt` and `fp_sqrt`. Like the hardware
+/// implementation, the digit-by-digit computation takes one cycle per pair of
+/// bits plus a cycle to start, and runs to completion once begun.
+pub struct Sqrt {
+    base_port: GlobalPortIdx,
+    width: u32,
+    int_width: u32,
+    frac_width: u32,
+    in_flight: Option<InFlight<Value>>,
+    output: Value,
+    done_is_high: bool,
+}
+
+impl Sqrt {
+    declare_ports![_CLK: 0, RESET: 1, GO: 2, IN: 3, OUT: 4, DONE: 5];
+
+    pub fn new(base_port: GlobalPortIdx, width: u32) -> Self {
+        Self::new_fixed_point(base_port, width, width, 0)
+    }
+
+    pub fn new_fixed_point(
+        base_port: GlobalPortIdx,
+        width: u32,
+        int_width: u32,
+        frac_width: u32,
+    ) -> Self {
+        Self {
+            base_port,
+            width,
+            int_width,
+            frac_width,
+            in_flight: None,
+            output: Value::zeroes(width),
+            done_is_high: false,
+        }
+    }
+
+    fn latency(&self) -> u64 {
+        // the algorithm needs an even number of integer bits so an odd width
+        // is extended by one
+        let ext_width = self.width + (self.int_width & 1);
+        ((ext_width + self.frac_width) >> 1) as u64 + 1
+    }
+}
+
+impl Primitive for Sqrt {
+    fn exec_comb(&self, port_map: &mut PortMap) -> UpdateResult {
+        ports![&self.base_port; out: Self::OUT, done: Self::DONE];
+
+        Ok(port_map
+            .insert_val(out, AssignedValue::cell_value(self.output.clone()))?
+            | port_map.insert_val(done, bit(self.done_is_high))?)
+    }
+
+    fn exec_cycle(&mut self, port_map: &mut PortMap) -> UpdateResult {
+        ports![&self.base_port;
+            reset: Self::RESET,
+            go: Self::GO,
+            input: Self::IN
+        ];
+
+        self.done_is_high = false;
+
+        if port_map[reset].as_bool().unwrap_or_default() {
+            self.in_flight = None;
+            self.output = Value::zeroes(self.width);
+        } else if let Some(in_flight) = self.in_flight.as_mut() {
+            in_flight.remaining -= 1;
+            if in_flight.remaining == 0 {
+                self.output = self.in_flight.take().unwrap().result;
+                self.done_is_high = true;
+            }
+        } else if port_map[go].as_bool().unwrap_or_default() {
+            let input = read_operand(port_map, input)?;
+            let root =
+                int_sqrt(&(input.as_unsigned() << (self.frac_width as usize)));
+
+            self.in_flight = Some(InFlight {
+                result: Value::from(root, self.width),
+                remaining: self.latency() - 1,
+            });
+        }
+
+        self.exec_comb(port_map)
+    }
+
+    fn serialize(
+        &self,
+        code: Option<crate::debugger::PrintCode>,
+    ) -> Serializable {
+        Serializable::Val(Entry::from_val_code(
+            &self.output,
+            &code.unwrap_or_default(),
+        ))
+    }
+
+    fn has_serializable_state(&self) -> bool {
+        true
+    }
+}
@@ -1,4 +1,5 @@
 pub mod math;
 pub mod memories;
 
+pub use math::*;
 pub use memories::*;
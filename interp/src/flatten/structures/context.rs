@@ -10,10 +10,11 @@ use crate::flatten::flat_ir::{
         ControlNode, Group, GroupIdx, GuardIdx, Identifier, LocalCellOffset,
         LocalPortOffset, LocalRefCellOffset, LocalRefPortOffset, ParentIdx,
         PortDefinitionIdx, PortDefinitionRef, PortRef, RefCellDefinitionIdx,
-        RefCellInfo, RefPortDefinitionIdx,
+        RefCellInfo, RefPortDefinitionIdx, StaticControlIdx, StaticControlMap,
+        StaticControlNode, StaticGroup, StaticGroupIdx,
     },
     wires::{
-        core::{AssignmentMap, GroupMap},
+        core::{AssignmentMap, GroupMap, StaticGroupMap},
         guards::{Guard, GuardMap},
     },
 };
@@ -34,12 +35,16 @@ pub struct InterpretationContext {
     pub components: ComponentMap,
     /// All the group definitions
     pub groups: GroupMap,
+    /// All the static group definitions
+    pub static_groups: StaticGroupMap,
     /// Comb group definitions
     pub comb_groups: CombGroupMap,
     /// All assignment guards
     pub guards: GuardMap,
     /// Control trees
     pub control: ControlMap,
+    /// Static control trees
+    pub static_control: StaticControlMap,
 }
 
 impl Index<ComponentIdx> for InterpretationContext {
@@ -66,6 +71,14 @@ impl Index<GroupIdx> for InterpretationContext {
     }
 }
 
+impl Index<StaticGroupIdx> for InterpretationContext {
+    type Output = StaticGroup;
+
+    fn index(&self, index: StaticGroupIdx) -> &Self::Output {
+        &self.static_groups[index]
+    }
+}
+
 impl Index<CombGroupIdx> for InterpretationContext {
     type Output = CombGroup;
 
@@ -90,6 +103,14 @@ impl Index<ControlIdx> for InterpretationContext {
     }
 }
 
+impl Index<StaticControlIdx> for InterpretationContext {
+    type Output = StaticControlNode;
+
+    fn index(&self, index: StaticControlIdx) -> &Self::Output {
+        &self.static_control[index]
+    }
+}
+
 impl InterpretationContext {
     pub fn new() -> Self {
         Default::default()
@@ -348,10 +369,14 @@ impl Context {
 
                     if let Some(p) = port {
                         p.into()
-                    } else {
-                         self.secondary[comp].definitions.groups().iter().find(|x| {
+                    } else if let Some(grp) = self.secondary[comp].definitions.groups().iter().find(|x| {
                             let grp_info = &self.primary[*x];
                             grp_info.done == l || grp_info.go == l
+                        }) {
+                        grp.into()
+                    } else {
+                        self.secondary[comp].definitions.static_groups().iter().find(|x| {
+                            self.primary[*x].go == l
                         }).unwrap_or_else(|| panic!("Port {:?} does not belong to any normal cell in the given component", l)).into()
                     }
 
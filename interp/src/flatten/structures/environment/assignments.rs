@@ -14,6 +14,10 @@ pub struct ScheduledAssignments {
     pub active_cell: GlobalCellIdx,
     pub assignments: AssignmentRange,
     pub interface_ports: Option<GroupInterfacePorts>,
+    /// The number of cycles the enclosing static group has been running for.
+    /// This is used to evaluate static timing guards and is `None` for
+    /// dynamic assignments.
+    pub static_time: Option<u64>,
 }
 
 impl ScheduledAssignments {
@@ -26,6 +30,22 @@ impl ScheduledAssignments {
             active_cell,
             assignments,
             interface_ports,
+            static_time: None,
+        }
+    }
+
+    /// Schedules the assignments of a static group which has been running for
+    /// `time` cycles
+    pub fn new_static(
+        active_cell: GlobalCellIdx,
+        assignments: AssignmentRange,
+        time: u64,
+    ) -> Self {
+        Self {
+            active_cell,
+            assignments,
+            interface_ports: None,
+            static_time: Some(time),
         }
     }
 }
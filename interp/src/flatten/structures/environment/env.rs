@@ -1,3 +1,4 @@
+use ahash::{HashSet, HashSetExt};
 use itertools::Itertools;
 
 use super::{
@@ -12,13 +13,14 @@ use crate::{
     errors::{InterpreterError, InterpreterResult},
     flatten::{
         flat_ir::{
-            cell_prototype::{CellPrototype, PrimType1},
+            cell_prototype::{CellPrototype, LiteralOrPrimitive, PrimType1},
             prelude::{
                 AssignedValue, AssignmentIdx, BaseIndices,
                 CellDefinitionRef::{Local, Ref},
                 CellRef, ComponentIdx, ControlNode, GlobalCellIdx,
                 GlobalCellRef, GlobalPortIdx, GlobalPortRef, GlobalRefCellIdx,
                 GlobalRefPortIdx, GuardIdx, Invoke, PortRef, PortValue,
+                StaticControlIdx, StaticControlNode,
             },
             wires::guards::Guard,
         },
@@ -27,10 +29,13 @@ use crate::{
             environment::program_counter::ControlPoint, index_trait::IndexRef,
         },
     },
-    serialization::data_dump::{DataDump, Dimensions},
+    serialization::{
+        data_dump::{DataDump, Dimensions},
+        vcd::{SignalId, VcdWriter},
+    },
     values::Value,
 };
-use std::fmt::Debug;
+use std::{fmt::Debug, io::Write};
 
 pub type PortMap = IndexedMap<GlobalPortIdx, PortValue>;
 
@@ -99,6 +104,15 @@ impl PortMap {
     }
 }
 
+/// A static group enable or invoke which is active in the current cycle
+#[derive(Debug)]
+struct StaticLeaf {
+    comp: GlobalCellIdx,
+    node: StaticControlIdx,
+    /// The number of cycles this node has been running for
+    time: u64,
+}
+
 pub(crate) type CellMap = IndexedMap<GlobalCellIdx, CellLedger>;
 pub(crate) type RefCellMap =
     IndexedMap<GlobalRefCellIdx, Option<GlobalCellIdx>>;
@@ -309,6 +323,11 @@ impl<'a> Environment<'a> {
                 debug_assert_eq!(done, go_actual);
             }
         }
+        // static groups only have a go port
+        for group_idx in comp_aux.definitions.static_groups() {
+            let go = self.ports.push(PortValue::new_undef());
+            debug_assert_eq!(index_bases + self.ctx.primary[group_idx].go, go);
+        }
 
         for (cell_off, def_idx) in comp_aux.cell_offset_map.iter() {
             let info = &self.ctx.secondary[*def_idx];
@@ -482,20 +501,134 @@ impl<'a> Environment<'a> {
     }
 }
 
+/// A VCD trace of a running simulation
+struct VcdTrace {
+    writer: VcdWriter<Box<dyn Write>>,
+    /// The port recorded by each declared signal
+    signals: Vec<(GlobalPortIdx, SignalId)>,
+}
+
+impl VcdTrace {
+    /// Record the current value of every traced port and advance the time
+    fn record(&mut self, ports: &PortMap) -> std::io::Result<()> {
+        for (port, signal) in self.signals.iter() {
+            self.writer.set_value(*signal, ports[*port].val());
+        }
+        self.writer.timestep()
+    }
+}
+
 /// A wrapper struct for the environment that provides the functions used to
 /// simulate the actual program. This is just to keep the simulation logic under
 /// a different namespace than the environment to avoid confusion
 pub struct Simulator<'a> {
     env: Environment<'a>,
+    /// An optional waveform trace of the simulation
+    vcd: Option<VcdTrace>,
 }
 
 impl<'a> Simulator<'a> {
     pub fn new(env: Environment<'a>) -> Self {
-        let mut output = Self { env };
+        let mut output = Self { env, vcd: None };
         output.set_root_go_high();
         output
     }
 
+    /// Record the value of every port in the program to the given output as a
+    /// VCD file. Values are sampled once the assignments have settled, right
+    /// before each clock edge, and the time of each sample is the number of
+    /// edges that came before it. Ports are scoped by component instance and
+    /// cell, with the `go` and `done` holes of each group scoped under the
+    /// group name.
+    pub fn attach_vcd<W: Write + 'static>(
+        &mut self,
+        out: W,
+    ) -> InterpreterResult<()> {
+        let mut writer = VcdWriter::new(Box::new(out) as Box<dyn Write>)?;
+        let mut signals = vec![];
+        let root = GlobalCellIdx::new(0);
+        let root_name = self.env.ctx.lookup_string(
+            self.env.ctx.secondary[self.get_root_component().comp_id].name,
+        );
+
+        self.declare_vcd_component(root, root_name, &mut writer, &mut signals)?;
+        writer.end_definitions()?;
+
+        self.vcd = Some(VcdTrace { writer, signals });
+        Ok(())
+    }
+
+    fn declare_vcd_component(
+        &self,
+        comp: GlobalCellIdx,
+        name: &str,
+        writer: &mut VcdWriter<Box<dyn Write>>,
+        signals: &mut Vec<(GlobalPortIdx, SignalId)>,
+    ) -> std::io::Result<()> {
+        let ctx = self.env.ctx;
+        let ledger = self.env.cells[comp].unwrap_comp();
+        let comp_info = &ctx.secondary[ledger.comp_id];
+
+        writer.push_scope(name)?;
+
+        for port in comp_info.signature.iter() {
+            let def = &ctx.secondary[comp_info.port_offset_map[port]];
+            let signal =
+                writer.add_signal(ctx.lookup_string(def.name), def.width)?;
+            signals.push((&ledger.index_bases + port, signal));
+        }
+
+        for group_idx in comp_info.definitions.groups() {
+            let group = &ctx.primary[group_idx];
+            writer.push_scope(ctx.lookup_string(group.name()))?;
+            let go = writer.add_signal("go", 1)?;
+            signals.push((&ledger.index_bases + group.go, go));
+            let done = writer.add_signal("done", 1)?;
+            signals.push((&ledger.index_bases + group.done, done));
+            writer.pop_scope()?;
+        }
+
+        for group_idx in comp_info.definitions.static_groups() {
+            let group = &ctx.primary[group_idx];
+            writer.push_scope(ctx.lookup_string(group.name()))?;
+            let go = writer.add_signal("go", 1)?;
+            signals.push((&ledger.index_bases + group.go, go));
+            writer.pop_scope()?;
+        }
+
+        for (cell_off, def_idx) in comp_info.cell_offset_map.iter() {
+            let info = &ctx.secondary[*def_idx];
+            let cell_name = ctx.lookup_string(info.name);
+
+            if matches!(
+                info.prototype,
+                CellPrototype::Constant {
+                    c_type: LiteralOrPrimitive::Literal,
+                    ..
+                }
+            ) {
+                // literals never change so there is nothing to trace
+                continue;
+            } else if info.prototype.is_component() {
+                let cell_idx = &ledger.index_bases + cell_off;
+                self.declare_vcd_component(
+                    cell_idx, cell_name, writer, signals,
+                )?;
+            } else {
+                writer.push_scope(cell_name)?;
+                for port in info.ports.iter() {
+                    let def = &ctx.secondary[comp_info.port_offset_map[port]];
+                    let signal = writer
+                        .add_signal(ctx.lookup_string(def.name), def.width)?;
+                    signals.push((&ledger.index_bases + port, signal));
+                }
+                writer.pop_scope()?;
+            }
+        }
+
+        writer.pop_scope()
+    }
+
     pub fn _print_env(&self) {
         self.env._print_env()
     }
@@ -641,9 +774,15 @@ impl<'a> Simulator<'a> {
                             "called `get_assignments` with an empty node"
                         )
                     }
+                    ControlNode::Static(_) => {
+                        unreachable!(
+                            "called `get_assignments` with a static node. Static control is scheduled separately"
+                        )
+                    }
                     // non-leaf nodes
                     ControlNode::If(_)
                     | ControlNode::While(_)
+                    | ControlNode::Repeat(_)
                     | ControlNode::Seq(_)
                     | ControlNode::Par(_) => {
                         unreachable!(
@@ -664,6 +803,163 @@ impl<'a> Simulator<'a> {
             .collect()
     }
 
+    /// Schedules the assignments for the given active static groups and
+    /// invokes
+    fn push_static_assignments(
+        &self,
+        leaves: &[StaticLeaf],
+        bundle: &mut Vec<ScheduledAssignments>,
+    ) {
+        for leaf in leaves {
+            match &self.ctx().primary[leaf.node] {
+                StaticControlNode::Enable(e) => {
+                    let group = &self.ctx().primary[e.group()];
+                    bundle.push(ScheduledAssignments::new_static(
+                        leaf.comp,
+                        group.assignments,
+                        leaf.time,
+                    ));
+                }
+                StaticControlNode::Invoke(i) => {
+                    bundle.push(ScheduledAssignments::new(
+                        leaf.comp,
+                        i.invoke.assignments,
+                        None,
+                    ));
+                    if let Some(comb_group) = i.invoke.comb_group {
+                        bundle.push(ScheduledAssignments::new(
+                            leaf.comp,
+                            self.ctx().primary[comb_group].assignments,
+                            None,
+                        ));
+                    }
+                }
+                non_leaf => unreachable!(
+                    "non-leaf static node {:?} included in list of static leaves. This should never happen, please report it.",
+                    non_leaf
+                ),
+            }
+        }
+    }
+
+    /// Collects the static groups and invokes in the static control tree
+    /// rooted at `node` which are active `time` cycles after the tree started.
+    /// A `static if` in its first cycle is added to `pending_ifs` instead since
+    /// its condition can only be read once the cycle has converged. The branch
+    /// chosen then is used for the remaining cycles. Components whose static
+    /// control is run by a `static invoke` are added to `invoked`.
+    fn collect_static_leaves(
+        &self,
+        comp: GlobalCellIdx,
+        node: StaticControlIdx,
+        time: u64,
+        leaves: &mut Vec<StaticLeaf>,
+        pending_ifs: &mut Vec<(GlobalCellIdx, StaticControlIdx)>,
+        invoked: &mut HashSet<GlobalCellIdx>,
+    ) {
+        let ctx = self.env.ctx;
+        let ctrl = &ctx.primary[node];
+        if time >= ctrl.latency() {
+            // this node has already finished
+            return;
+        }
+
+        match ctrl {
+            StaticControlNode::Empty(_) => {}
+            StaticControlNode::Enable(_) => {
+                leaves.push(StaticLeaf { comp, node, time })
+            }
+            StaticControlNode::Invoke(i) => {
+                leaves.push(StaticLeaf { comp, node, time });
+
+                // the static control of the invoked component is run in
+                // lockstep with the invoke rather than waiting a cycle to see
+                // its go signal
+                let child = self.get_global_cell_idx(&i.invoke.cell, comp);
+                if let Some(ledger) = self.env.cells[child].as_comp() {
+                    if let Some(ControlNode::Static(root)) = ctx.primary
+                        [ledger.comp_id]
+                        .control
+                        .map(|ctrl| &ctx.primary[ctrl])
+                    {
+                        invoked.insert(child);
+                        self.collect_static_leaves(
+                            child,
+                            *root,
+                            time,
+                            leaves,
+                            pending_ifs,
+                            invoked,
+                        );
+                    }
+                }
+            }
+            StaticControlNode::Seq(seq) => {
+                let mut start = 0;
+                for stmt in seq.stms() {
+                    let latency = ctx.primary[*stmt].latency();
+                    if time < start + latency {
+                        self.collect_static_leaves(
+                            comp,
+                            *stmt,
+                            time - start,
+                            leaves,
+                            pending_ifs,
+                            invoked,
+                        );
+                        break;
+                    }
+                    start += latency;
+                }
+            }
+            StaticControlNode::Par(par) => {
+                for stmt in par.stms() {
+                    self.collect_static_leaves(
+                        comp,
+                        *stmt,
+                        time,
+                        leaves,
+                        pending_ifs,
+                        invoked,
+                    );
+                }
+            }
+            StaticControlNode::If(i) => {
+                if time == 0 {
+                    pending_ifs.push((comp, node));
+                } else {
+                    let branch = if self.env.pc.static_branches()[&(comp, node)]
+                    {
+                        i.tbranch()
+                    } else {
+                        i.fbranch()
+                    };
+                    self.collect_static_leaves(
+                        comp,
+                        branch,
+                        time,
+                        leaves,
+                        pending_ifs,
+                        invoked,
+                    );
+                }
+            }
+            StaticControlNode::Repeat(r) => {
+                let body_latency = ctx.primary[r.body()].latency();
+                if body_latency != 0 && time < r.num_repeats() * body_latency {
+                    self.collect_static_leaves(
+                        comp,
+                        r.body(),
+                        time % body_latency,
+                        leaves,
+                        pending_ifs,
+                        invoked,
+                    );
+                }
+            }
+        }
+    }
+
     /// A helper function which inserts indicies for the ref cells and ports
     /// used in the invoke statement
     fn intialize_ref_cells(
@@ -779,8 +1075,18 @@ impl<'a> Simulator<'a> {
         let mut leaf_nodes = vec![];
         let mut set_done = vec![];
 
+        let mut static_leaves = vec![];
+        let mut pending_ifs = vec![];
+        let mut static_invoked = HashSet::new();
+
         let mut new_nodes = vec![];
-        let (mut vecs, mut par_map, mut with_map) = self.env.pc.take_fields();
+        let (
+            mut vecs,
+            mut par_map,
+            mut with_map,
+            mut repeat_map,
+            mut static_map,
+        ) = self.env.pc.take_fields();
 
         // TODO griffin: This has become an unwieldy mess and should really be
         // refactored into a handful of internal functions
@@ -792,6 +1098,12 @@ impl<'a> Simulator<'a> {
                 return true;
             }
 
+            if self.env.pc.static_invoked().contains(&node.comp) {
+                // the static control of this component is being run by a
+                // static invoke in its parent
+                return true;
+            }
+
             // just considering a single node case for the moment
             let retain_bool = match &self.env.ctx.primary[node.control_node_idx] {
                 ControlNode::Seq(seq) => {
@@ -906,8 +1218,38 @@ impl<'a> Simulator<'a> {
                     }
                 }
 
+                ControlNode::Repeat(r) => {
+                    let remaining = repeat_map.entry(node.clone()).or_insert(r.num_repeats());
+
+                    if *remaining > 0 {
+                        // run the body again
+                        *remaining -= 1;
+                        *node = node.new_retain_comp(r.body());
+                        true
+                    } else {
+                        repeat_map.remove(node);
+                        node.mutate_into_next(self.env.ctx)
+                    }
+                }
+
                 // ===== leaf nodes =====
                 ControlNode::Empty(_) => node.mutate_into_next(self.env.ctx),
+                ControlNode::Static(root) => {
+                    let latency = self.env.ctx.primary[*root].latency();
+                    let elapsed = static_map.entry(node.clone()).or_default();
+
+                    self.collect_static_leaves(node.comp, *root, *elapsed, &mut static_leaves, &mut pending_ifs, &mut static_invoked);
+                    *elapsed += 1;
+
+                    if *elapsed < latency {
+                        true
+                    } else {
+                        // the tree runs its last cycle now so the next node
+                        // can start in the following one
+                        static_map.remove(node);
+                        node.mutate_into_next(self.env.ctx)
+                    }
+                }
                 ControlNode::Enable(e) => {
                     let done_local = self.env.ctx.primary[e.group()].done;
                     let done_idx = &self.env.cells[node.comp]
@@ -965,47 +1307,123 @@ impl<'a> Simulator<'a> {
 
         });
 
-        self.env.pc.restore_fields(vecs, par_map, with_map);
+        self.env
+            .pc
+            .restore_fields(vecs, par_map, with_map, repeat_map, static_map);
 
         // insert all the new nodes from the par into the program counter
         self.env.pc.vec_mut().extend(new_nodes);
 
-        self.undef_all_ports();
-        self.set_root_go_high();
-        for port in set_done {
-            self.env.ports[port] = PortValue::new_implicit(Value::bit_high());
-        }
+        // a `static if` reads its condition once the cycle has converged, so
+        // the cycle is re-run with the chosen branches until none remain
+        loop {
+            self.undef_all_ports();
+            self.set_root_go_high();
+            for &port in &set_done {
+                self.env.ports[port] =
+                    PortValue::new_implicit(Value::bit_high());
+            }
 
-        for node in &leaf_nodes {
-            match &self.env.ctx.primary[node.control_node_idx] {
-                ControlNode::Enable(e) => {
-                    let go_local = self.env.ctx.primary[e.group()].go;
-                    let index_bases = &self.env.cells[node.comp]
-                        .as_comp()
-                        .unwrap()
-                        .index_bases;
+            for node in &leaf_nodes {
+                match &self.env.ctx.primary[node.control_node_idx] {
+                    ControlNode::Enable(e) => {
+                        let go_local = self.env.ctx.primary[e.group()].go;
+                        let index_bases = &self.env.cells[node.comp]
+                            .as_comp()
+                            .unwrap()
+                            .index_bases;
 
-                    // set go high
-                    let go_idx = index_bases + go_local;
-                    self.env.ports[go_idx] =
-                        PortValue::new_implicit(Value::bit_high());
-                }
-                ControlNode::Invoke(i) => {
-                    let go = self.get_global_port_idx(&i.go, node.comp);
-                    self.env.ports[go] =
-                        PortValue::new_implicit(Value::bit_high());
+                        // set go high
+                        let go_idx = index_bases + go_local;
+                        self.env.ports[go_idx] =
+                            PortValue::new_implicit(Value::bit_high());
+                    }
+                    ControlNode::Invoke(i) => {
+                        let go = self.get_global_port_idx(&i.go, node.comp);
+                        self.env.ports[go] =
+                            PortValue::new_implicit(Value::bit_high());
 
-                    self.intialize_ref_cells(node.comp, i);
+                        self.intialize_ref_cells(node.comp, i);
+                    }
+                    non_leaf => {
+                        unreachable!("non-leaf node {:?} included in list of leaf nodes. This should never happen, please report it.", non_leaf)
+                    }
                 }
-                non_leaf => {
-                    unreachable!("non-leaf node {:?} included in list of leaf nodes. This should never happen, please report it.", non_leaf)
+            }
+
+            for leaf in &static_leaves {
+                let index_bases =
+                    &self.env.cells[leaf.comp].as_comp().unwrap().index_bases;
+                match &self.env.ctx.primary[leaf.node] {
+                    StaticControlNode::Enable(e) => {
+                        let go_idx =
+                            index_bases + self.env.ctx.primary[e.group()].go;
+                        self.env.ports[go_idx] =
+                            PortValue::new_implicit(Value::bit_high());
+                    }
+                    StaticControlNode::Invoke(i) => {
+                        let go =
+                            self.get_global_port_idx(&i.invoke.go, leaf.comp);
+                        self.env.ports[go] =
+                            PortValue::new_implicit(Value::bit_high());
+
+                        self.intialize_ref_cells(leaf.comp, &i.invoke);
+                    }
+                    non_leaf => {
+                        unreachable!("non-leaf static node {:?} included in list of static leaves. This should never happen, please report it.", non_leaf)
+                    }
                 }
             }
+
+            let mut assigns_bundle = self.get_assignments(&leaf_nodes);
+            self.push_static_assignments(&static_leaves, &mut assigns_bundle);
+
+            self.simulate_combinational(&assigns_bundle)?;
+
+            if pending_ifs.is_empty() {
+                break;
+            }
+
+            for (comp, node) in std::mem::take(&mut pending_ifs) {
+                let StaticControlNode::If(i) = &self.env.ctx.primary[node]
+                else {
+                    unreachable!("non-if static node {:?} included in list of pending ifs. This should never happen, please report it.", node)
+                };
+                let cond = self
+                    .get_value(&i.cond_port(), comp)
+                    .as_bool()
+                    .ok_or(InterpreterError::UndefinedStaticIfCondition)?;
+                self.env.pc.static_branches_mut().insert((comp, node), cond);
+
+                let branch = if cond { i.tbranch() } else { i.fbranch() };
+                self.collect_static_leaves(
+                    comp,
+                    branch,
+                    0,
+                    &mut static_leaves,
+                    &mut pending_ifs,
+                    &mut static_invoked,
+                );
+            }
         }
+        self.env.pc.set_static_invoked(static_invoked);
 
-        let assigns_bundle = self.get_assignments(&leaf_nodes);
+        // static invokes in their final cycle are finished with their ref
+        // cells
+        for leaf in &static_leaves {
+            if let StaticControlNode::Invoke(i) =
+                &self.env.ctx.primary[leaf.node]
+            {
+                if leaf.time + 1 == i.latency {
+                    self.cleanup_ref_cells(leaf.comp, &i.invoke);
+                }
+            }
+        }
 
-        self.simulate_combinational(&assigns_bundle)?;
+        // sample right before the clock edge
+        if let Some(trace) = self.vcd.as_mut() {
+            trace.record(&self.env.ports)?;
+        }
 
         for cell in self.env.cells.values_mut() {
             match cell {
@@ -1019,7 +1437,7 @@ impl<'a> Simulator<'a> {
         Ok(())
     }
 
-    fn is_done(&self) -> bool {
+    pub(crate) fn is_done(&self) -> bool {
         self.env.ports[self.env.get_root_done()]
             .as_bool()
             .unwrap_or_default()
@@ -1030,28 +1448,36 @@ impl<'a> Simulator<'a> {
         while !self.is_done() {
             self.step()?
         }
+        if let Some(trace) = self.vcd.as_mut() {
+            trace.writer.finish()?;
+        }
         Ok(())
     }
 
+    /// Evaluates the given guard. `static_time` is the number of cycles the
+    /// enclosing static group has been running for, if any.
     fn evaluate_guard(
         &self,
         guard: GuardIdx,
         comp: GlobalCellIdx,
+        static_time: Option<u64>,
     ) -> Option<bool> {
         let guard = &self.ctx().primary[guard];
         match guard {
             Guard::True => Some(true),
             Guard::Or(a, b) => {
-                let g1 = self.evaluate_guard(*a, comp)?;
-                let g2 = self.evaluate_guard(*b, comp)?;
+                let g1 = self.evaluate_guard(*a, comp, static_time)?;
+                let g2 = self.evaluate_guard(*b, comp, static_time)?;
                 Some(g1 || g2)
             }
             Guard::And(a, b) => {
-                let g1 = self.evaluate_guard(*a, comp)?;
-                let g2 = self.evaluate_guard(*b, comp)?;
+                let g1 = self.evaluate_guard(*a, comp, static_time)?;
+                let g2 = self.evaluate_guard(*b, comp, static_time)?;
                 Some(g1 && g2)
             }
-            Guard::Not(n) => Some(!self.evaluate_guard(*n, comp)?),
+            Guard::Not(n) => {
+                Some(!self.evaluate_guard(*n, comp, static_time)?)
+            }
             Guard::Comp(c, a, b) => {
                 let comp_v = self.env.cells[comp].unwrap_comp();
 
@@ -1078,6 +1504,9 @@ impl<'a> Simulator<'a> {
                     .lookup_global_port_id(comp_v.convert_to_global_port(p));
                 self.env.ports[p_idx].as_bool()
             }
+            Guard::Timing(start, end) => {
+                static_time.map(|time| *start <= time && time < *end)
+            }
         }
     }
 
@@ -1115,6 +1544,7 @@ impl<'a> Simulator<'a> {
                 active_cell,
                 assignments,
                 interface_ports,
+                static_time,
             } in assigns_bundle.iter()
             {
                 let ledger = self.env.cells[*active_cell].as_comp().unwrap();
@@ -1134,7 +1564,7 @@ impl<'a> Simulator<'a> {
                     // since we may want to do something different if the guard
                     // does not have a defined value
                     if self
-                        .evaluate_guard(assign.guard, *active_cell)
+                        .evaluate_guard(assign.guard, *active_cell, *static_time)
                         .unwrap_or_default()
                     // the go for the group is high
                     && go
@@ -1,12 +1,12 @@
 use std::num::NonZeroU32;
 
-use ahash::{HashMap, HashMapExt};
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
 
 use super::super::context::Context;
 use crate::flatten::{
     flat_ir::prelude::{
         AssignmentIdx, CombGroupIdx, ControlIdx, ControlMap, ControlNode,
-        GlobalCellIdx,
+        GlobalCellIdx, StaticControlIdx,
     },
     structures::index_trait::{impl_index_nonzero, IndexRange, IndexRef},
 };
@@ -171,13 +171,19 @@ impl SearchPath {
                         // next node
                         return Some(*node);
                     }
+                    ControlNode::Repeat(_) => {
+                        // the repeat needs to decide whether to run the body
+                        // again, so this is our next node
+                        return Some(*node);
+                    }
 
-                    // none of these three should be possible as a non-leaf node
+                    // none of these should be possible as a non-leaf node
                     // which is what we are currently searching through on the
                     // path, so this is definitely an error
                     ControlNode::Invoke(_)
                     | ControlNode::Empty(_)
-                    | ControlNode::Enable(_) => {
+                    | ControlNode::Enable(_)
+                    | ControlNode::Static(_) => {
                         unreachable!("SearchPath is malformed. This is an error and should be reported")
                     }
                 }
@@ -206,7 +212,8 @@ impl SearchPath {
             match &context.primary.control[node.node] {
                 ControlNode::Empty(_)
                 | ControlNode::Enable(_)
-                | ControlNode::Invoke(_) => {
+                | ControlNode::Invoke(_)
+                | ControlNode::Static(_) => {
                     // in this case we reached a terminal node which was not the
                     // target since we did not break in the above case. So we
                     // simply remove the current lowest node and ascend the
@@ -293,6 +300,17 @@ impl SearchPath {
                         })
                     }
                 }
+                ControlNode::Repeat(r) => {
+                    if node.search_index.is_some() {
+                        current_path.path.pop();
+                    } else {
+                        node.search_index = Some(SearchIndex::new(0));
+                        current_path.path.push(SearchNode {
+                            node: r.body(),
+                            search_index: None,
+                        })
+                    }
+                }
             }
         }
 
@@ -339,8 +357,29 @@ pub(crate) struct ProgramCounter {
     par_map: HashMap<ControlPoint, ChildCount>,
     continuous_assigns: Vec<ContinuousAssignments>,
     with_map: HashMap<ControlPoint, CombGroupIdx>,
+    /// The number of iterations remaining for each active repeat
+    repeat_map: HashMap<ControlPoint, u64>,
+    /// The number of cycles each active static control tree has been running
+    /// for
+    static_map: HashMap<ControlPoint, u64>,
+    /// The branch taken by each `static if`, as decided in the first cycle of
+    /// its most recent execution
+    static_branches: HashMap<(GlobalCellIdx, StaticControlIdx), bool>,
+    /// The components whose static control was run by a `static invoke` in
+    /// the previous cycle
+    static_invoked: HashSet<GlobalCellIdx>,
 }
 
+/// The mutable state of the program counter as returned by
+/// [ProgramCounter::take_fields]
+pub(crate) type PcFields = (
+    Vec<ControlPoint>,
+    HashMap<ControlPoint, ChildCount>,
+    HashMap<ControlPoint, CombGroupIdx>,
+    HashMap<ControlPoint, u64>,
+    HashMap<ControlPoint, u64>,
+);
+
 // we need a few things from the program counter
 
 impl ProgramCounter {
@@ -350,6 +389,10 @@ impl ProgramCounter {
             par_map: HashMap::new(),
             continuous_assigns: Vec::new(),
             with_map: HashMap::new(),
+            repeat_map: HashMap::new(),
+            static_map: HashMap::new(),
+            static_branches: HashMap::new(),
+            static_invoked: HashSet::new(),
         }
     }
 
@@ -373,17 +416,13 @@ impl ProgramCounter {
         &self.par_map
     }
 
-    pub fn take_fields(
-        &mut self,
-    ) -> (
-        Vec<ControlPoint>,
-        HashMap<ControlPoint, ChildCount>,
-        HashMap<ControlPoint, CombGroupIdx>,
-    ) {
+    pub fn take_fields(&mut self) -> PcFields {
         (
             std::mem::take(&mut self.vec),
             std::mem::take(&mut self.par_map),
             std::mem::take(&mut self.with_map),
+            std::mem::take(&mut self.repeat_map),
+            std::mem::take(&mut self.static_map),
         )
     }
 
@@ -392,10 +431,14 @@ impl ProgramCounter {
         vec: Vec<ControlPoint>,
         par_map: HashMap<ControlPoint, ChildCount>,
         with_map: HashMap<ControlPoint, CombGroupIdx>,
+        repeat_map: HashMap<ControlPoint, u64>,
+        static_map: HashMap<ControlPoint, u64>,
     ) {
         self.vec = vec;
         self.par_map = par_map;
         self.with_map = with_map;
+        self.repeat_map = repeat_map;
+        self.static_map = static_map;
     }
 
     pub(crate) fn push_continuous_assigns(
@@ -411,6 +454,26 @@ impl ProgramCounter {
         &self.continuous_assigns
     }
 
+    pub(crate) fn static_branches(
+        &self,
+    ) -> &HashMap<(GlobalCellIdx, StaticControlIdx), bool> {
+        &self.static_branches
+    }
+
+    pub(crate) fn static_branches_mut(
+        &mut self,
+    ) -> &mut HashMap<(GlobalCellIdx, StaticControlIdx), bool> {
+        &mut self.static_branches
+    }
+
+    pub(crate) fn static_invoked(&self) -> &HashSet<GlobalCellIdx> {
+        &self.static_invoked
+    }
+
+    pub(crate) fn set_static_invoked(&mut self, comps: HashSet<GlobalCellIdx>) {
+        self.static_invoked = comps;
+    }
+
     pub(crate) fn with_map(&self) -> &HashMap<ControlPoint, CombGroupIdx> {
         &self.with_map
     }
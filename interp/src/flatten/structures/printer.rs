@@ -45,6 +45,30 @@ impl<'a> Printer<'a> {
         }
     }
 
+    pub fn print_static_group(
+        &self,
+        group: StaticGroupIdx,
+        parent: ComponentIdx,
+    ) {
+        println!(
+            "{}",
+            text_utils::indent(
+                format!(
+                    "Static Group<{}>: {}",
+                    self.ctx.primary[group].latency,
+                    self.ctx.secondary[self.ctx.primary[group].name()]
+                ),
+                1
+            )
+        );
+        for assign in self.ctx.primary[group].assignments.iter() {
+            println!(
+                "{}",
+                text_utils::indent(self.print_assignment(parent, assign), 2)
+            );
+        }
+    }
+
     pub fn print_comb_group(&self, group: CombGroupIdx, parent: ComponentIdx) {
         println!(
             "{}",
@@ -73,6 +97,10 @@ impl<'a> Printer<'a> {
             self.print_group(x, idx)
         }
 
+        for x in self.ctx.secondary[idx].definitions.static_groups() {
+            self.print_static_group(x, idx)
+        }
+
         for x in self.ctx.secondary[idx].definitions.comb_groups() {
             self.print_comb_group(x, idx)
         }
@@ -159,6 +187,7 @@ impl<'a> Printer<'a> {
                 }
             },
             (PortDefinitionRef::Local(l), ParentIdx::Group(g)) => CanonicalIdentifier::group_port( self.ctx.primary[g].name(), self.ctx.secondary[l].name),
+            (PortDefinitionRef::Local(l), ParentIdx::StaticGroup(g)) => CanonicalIdentifier::group_port( self.ctx.primary[g].name(), self.ctx.secondary[l].name),
             (PortDefinitionRef::Ref(rp), ParentIdx::RefCell(rc)) => CanonicalIdentifier::cell_port( self.ctx.secondary[rc].name, self.ctx.secondary[rp]),
             _ => unreachable!("Inconsistent port definition and parent. This should never happen"),
         }
@@ -256,35 +285,146 @@ impl<'a> Printer<'a> {
 
                 out
             }
-            ControlNode::Invoke(i) => {
-                let invoked_name =
-                    &self.ctx.secondary[self.lookup_cell_id(parent, i.cell)];
+            ControlNode::Repeat(r) => {
+                let mut out = text_utils::indent(
+                    format!("repeat {} {{\n", r.num_repeats()),
+                    indent,
+                );
+                let body = self.format_control(parent, r.body(), indent + 1);
+                out += &(body + "\n");
+                out += &text_utils::indent("}", indent);
 
-                let mut out = format!("invoke {invoked_name}");
+                out
+            }
+            ControlNode::Invoke(i) => {
+                text_utils::indent(self.format_invoke(parent, i), indent)
+            }
+            ControlNode::Static(s) => {
+                self.format_static_control(parent, *s, indent)
+            }
+        }
+    }
 
-                if !i.ref_cells.is_empty() {
-                    let ref_cells = self.format_invoke_ref_cell_list(i, parent);
-                    out += &format!("[{}]", ref_cells);
+    pub fn format_static_control(
+        &self,
+        parent: ComponentIdx,
+        control: StaticControlIdx,
+        indent: usize,
+    ) -> String {
+        let node = &self.ctx.primary[control];
+        match node {
+            StaticControlNode::Empty(_) => String::new(),
+            StaticControlNode::Enable(e) => text_utils::indent(
+                format!(
+                    "{};     ({:?})",
+                    self.ctx.secondary[self.ctx.primary[e.group()].name()]
+                        .clone(),
+                    control
+                ),
+                indent,
+            ),
+            StaticControlNode::Seq(s) => {
+                let mut seq = text_utils::indent(
+                    format!("static<{}> seq {{\n", node.latency()),
+                    indent,
+                );
+                for stmt in s.stms() {
+                    seq +=
+                        &self.format_static_control(parent, *stmt, indent + 1);
+                    seq += "\n";
                 }
-                let inputs =
-                    self.format_invoke_port_lists(&i.signature.inputs, parent);
-                let outputs =
-                    self.format_invoke_port_lists(&i.signature.outputs, parent);
+                seq += &text_utils::indent("}", indent);
+                seq
+            }
+            StaticControlNode::Par(p) => {
+                let mut par = text_utils::indent(
+                    format!("static<{}> par {{\n", node.latency()),
+                    indent,
+                );
+                for stmt in p.stms() {
+                    par +=
+                        &self.format_static_control(parent, *stmt, indent + 1);
+                    par += "\n";
+                }
+                par += &text_utils::indent("}", indent);
+                par
+            }
+            StaticControlNode::If(i) => {
+                let cond = self.lookup_id_from_port(parent, i.cond_port());
+                let mut out = text_utils::indent(
+                    format!(
+                        "static<{}> if {} {{\n",
+                        node.latency(),
+                        cond.format_name(self.string_table())
+                    ),
+                    indent,
+                );
 
-                out += &format!("({inputs})({outputs})");
+                let t_branch =
+                    self.format_static_control(parent, i.tbranch(), indent + 1);
+                let f_branch =
+                    self.format_static_control(parent, i.fbranch(), indent + 1);
 
-                if let Some(grp) = i.comb_group {
-                    out += &format!(
-                        " with {}",
-                        self.ctx.secondary[self.ctx.primary[grp].name()]
-                    );
+                out += &t_branch;
+                out += "\n";
+                out += &text_utils::indent("}", indent);
+
+                if !f_branch.is_empty() {
+                    out += &format!(" else {{\n{}\n", f_branch);
+                    out += &(text_utils::indent("}\n", indent));
                 }
 
-                out += ";";
+                out
+            }
+            StaticControlNode::Repeat(r) => {
+                let mut out = text_utils::indent(
+                    format!("static repeat {} {{\n", r.num_repeats()),
+                    indent,
+                );
+                let body =
+                    self.format_static_control(parent, r.body(), indent + 1);
+                out += &(body + "\n");
+                out += &text_utils::indent("}", indent);
 
-                text_utils::indent(out, indent)
+                out
             }
+            StaticControlNode::Invoke(i) => text_utils::indent(
+                format!(
+                    "static<{}> {}",
+                    i.latency,
+                    self.format_invoke(parent, &i.invoke)
+                ),
+                indent,
+            ),
+        }
+    }
+
+    fn format_invoke(&self, parent: ComponentIdx, i: &Invoke) -> String {
+        let invoked_name =
+            &self.ctx.secondary[self.lookup_cell_id(parent, i.cell)];
+
+        let mut out = format!("invoke {invoked_name}");
+
+        if !i.ref_cells.is_empty() {
+            let ref_cells = self.format_invoke_ref_cell_list(i, parent);
+            out += &format!("[{}]", ref_cells);
+        }
+        let inputs = self.format_invoke_port_lists(&i.signature.inputs, parent);
+        let outputs =
+            self.format_invoke_port_lists(&i.signature.outputs, parent);
+
+        out += &format!("({inputs})({outputs})");
+
+        if let Some(grp) = i.comb_group {
+            out += &format!(
+                " with {}",
+                self.ctx.secondary[self.ctx.primary[grp].name()]
+            );
         }
+
+        out += ";";
+
+        out
     }
 
     fn format_invoke_port_lists(
@@ -393,6 +533,13 @@ impl<'a> Printer<'a> {
                 let p = self.lookup_id_from_port(parent, *p);
                 p.format_name(&self.ctx.secondary.string_table)
             }
+            Guard::Timing(start, end) => {
+                if start + 1 == *end {
+                    format!("%{}", start)
+                } else {
+                    format!("%[{}:{}]", start, end)
+                }
+            }
         }
     }
 
@@ -13,14 +13,16 @@ use crate::{
     errors::InterpreterResult,
     interpreter_ir as iir,
     primitives::{Named, Primitive},
+    serialization::vcd::{SignalId, VcdWriter},
     structures::names::{
         ComponentQualifiedInstanceName, GroupQIN, GroupQualifiedInstanceName,
     },
-    structures::state_views::{MutStateView, StateView},
+    structures::state_views::{MutStateView, PortWalkItem, StateView},
     utils::AsRaw,
     values::Value,
 };
 use calyx_ir::{self as ir, Port, RRC};
+use std::io::Write;
 use std::rc::Rc;
 
 enum StructuralOrControl {
@@ -220,6 +222,89 @@ impl ComponentInterpreter {
         main_comp.set_go_low();
         main_comp.deconstruct()
     }
+
+    /// Interpret a calyx program from the root while recording the value of
+    /// every port into the given VCD writer. Values are sampled once the
+    /// assignments have settled, right before each clock edge, and the time of
+    /// each sample is the number of edges that came before it.
+    pub fn interpret_program_with_vcd<W: Write>(
+        env: InterpreterState,
+        comp: &Rc<iir::Component>,
+        vcd: &mut VcdWriter<W>,
+    ) -> InterpreterResult<InterpreterState> {
+        let qin = ComponentQualifiedInstanceName::new_single(comp, comp.name);
+        let mut main_comp = Self::from_component(comp, env, qin);
+        main_comp.set_go_high();
+
+        let mut signals = vec![];
+        vcd.push_scope(comp.name.as_ref())?;
+        main_comp.get_env().walk_ports(&mut |item| match item {
+            PortWalkItem::EnterScope(name) => vcd.push_scope(name.as_ref()),
+            PortWalkItem::ExitScope => vcd.pop_scope(),
+            PortWalkItem::Port { name, width, .. } => {
+                signals.push(vcd.add_signal(name.as_ref(), width as usize)?);
+                Ok(())
+            }
+        })?;
+        vcd.end_definitions()?;
+
+        while !main_comp.is_done() {
+            main_comp.converge()?;
+            let clock = main_comp.get_env().get_clock();
+            let values = main_comp.sample_ports();
+
+            main_comp.step()?;
+
+            // steps which only advance the control do not cross a clock edge
+            // and have nothing to record
+            if main_comp.get_env().get_clock() > clock {
+                Self::record_vcd(&signals, &values, vcd)?;
+            }
+        }
+
+        // the cycle in which the program is done
+        main_comp.converge()?;
+        let values = main_comp.sample_ports();
+        Self::record_vcd(&signals, &values, vcd)?;
+        vcd.finish()?;
+
+        main_comp.set_go_low();
+        main_comp.deconstruct()
+    }
+
+    /// The current value of every port in the order of
+    /// [StateView::walk_ports]
+    fn sample_ports(&self) -> Vec<Value> {
+        let mut values = vec![];
+        self.get_env()
+            .walk_ports(&mut |item| {
+                if let PortWalkItem::Port { value, .. } = item {
+                    values.push(value.clone());
+                }
+                Ok::<(), std::convert::Infallible>(())
+            })
+            .unwrap();
+        values
+    }
+
+    /// Write the sampled port values to the VCD writer and advance its time.
+    /// The signals must have been declared by walking the ports of this
+    /// component.
+    fn record_vcd<W: Write>(
+        signals: &[SignalId],
+        values: &[Value],
+        vcd: &mut VcdWriter<W>,
+    ) -> std::io::Result<()> {
+        debug_assert_eq!(
+            signals.len(),
+            values.len(),
+            "port structure changed during simulation"
+        );
+        for (signal, value) in signals.iter().zip(values) {
+            vcd.set_value(*signal, Some(value));
+        }
+        vcd.timestep()
+    }
 }
 
 impl Interpreter for ComponentInterpreter {
@@ -192,6 +192,7 @@ impl AssignmentInterpreter {
         for (port, val) in update_list {
             self.state.insert(port, val);
         }
+        self.state.clk += 1;
         self.val_changed = None;
 
         Ok(())
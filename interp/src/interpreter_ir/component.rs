@@ -1,4 +1,5 @@
 use super::control::Control;
+use crate::errors::InterpreterError;
 use calyx_ir::Component as CalyxComponent;
 use calyx_ir::{
     Assignment, Attributes, Cell, CombGroup, Group, Id, IdList, Nothing, RRC,
@@ -26,18 +27,23 @@ pub struct Component {
     pub attributes: Attributes,
 }
 
-impl From<CalyxComponent> for Component {
-    fn from(cc: CalyxComponent) -> Self {
-        Self {
+impl TryFrom<CalyxComponent> for Component {
+    type Error = InterpreterError;
+
+    fn try_from(cc: CalyxComponent) -> Result<Self, Self::Error> {
+        Ok(Self {
             name: cc.name,
             signature: cc.signature,
             cells: cc.cells,
             groups: cc.groups,
             comb_groups: cc.comb_groups,
             continuous_assignments: Rc::new(cc.continuous_assignments),
-            control: Rc::try_unwrap(cc.control).unwrap().into_inner().into(),
+            control: Rc::try_unwrap(cc.control)
+                .unwrap()
+                .into_inner()
+                .try_into()?,
             attributes: cc.attributes,
-        }
+        })
     }
 }
 
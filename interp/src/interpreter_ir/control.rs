@@ -1,6 +1,8 @@
 use calyx_ir::Control as CalyxControl;
 use calyx_ir::{self as ir, Attributes, CombGroup, Port, RRC};
 
+use crate::errors::InterpreterError;
+
 use std::rc::Rc;
 
 // These IR constructs are unchanged but are here re-exported for consistency
@@ -71,63 +73,86 @@ pub enum Control {
     Empty(Rc<Empty>),
 }
 
-impl From<CalyxControl> for Control {
-    fn from(cc: CalyxControl) -> Self {
-        match cc {
-            CalyxControl::Seq(s) => Control::Seq(Rc::new(s.into())),
-            CalyxControl::Par(p) => Control::Par(Rc::new(p.into())),
-            CalyxControl::If(i) => Control::If(Rc::new(i.into())),
-            CalyxControl::While(wh) => Control::While(Rc::new(wh.into())),
+impl TryFrom<CalyxControl> for Control {
+    type Error = InterpreterError;
+
+    fn try_from(cc: CalyxControl) -> Result<Self, Self::Error> {
+        Ok(match cc {
+            CalyxControl::Seq(s) => Control::Seq(Rc::new(s.try_into()?)),
+            CalyxControl::Par(p) => Control::Par(Rc::new(p.try_into()?)),
+            CalyxControl::If(i) => Control::If(Rc::new(i.try_into()?)),
+            CalyxControl::While(wh) => Control::While(Rc::new(wh.try_into()?)),
             CalyxControl::Invoke(invoke) => Control::Invoke(Rc::new(invoke)),
             CalyxControl::Enable(enable) => Control::Enable(Rc::new(enable)),
             CalyxControl::Static(_) => {
-                todo!("interpreter does not yet support static")
+                return Err(InterpreterError::UnsupportedStaticControl)
             }
-            CalyxControl::Repeat(_) => {
-                todo!("interpreter does not yet support repeat")
+            CalyxControl::Repeat(r) => {
+                // a dynamic repeat is equivalent to sequencing its body
+                let body: Control = (*r.body).try_into()?;
+                Control::Seq(Rc::new(Seq {
+                    stmts: vec![body; r.num_repeats as usize],
+                    attributes: r.attributes,
+                }))
             }
             CalyxControl::Empty(empty) => Control::Empty(Rc::new(empty)),
-        }
+        })
     }
 }
 
-impl From<ir::Seq> for Seq {
-    fn from(seq: ir::Seq) -> Self {
-        Self {
-            stmts: seq.stmts.into_iter().map(|x| x.into()).collect(),
+impl TryFrom<ir::Seq> for Seq {
+    type Error = InterpreterError;
+
+    fn try_from(seq: ir::Seq) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stmts: seq
+                .stmts
+                .into_iter()
+                .map(Control::try_from)
+                .collect::<Result<_, _>>()?,
             attributes: seq.attributes,
-        }
+        })
     }
 }
 
-impl From<ir::Par> for Par {
-    fn from(par: ir::Par) -> Self {
-        Self {
-            stmts: par.stmts.into_iter().map(|x| x.into()).collect(),
+impl TryFrom<ir::Par> for Par {
+    type Error = InterpreterError;
+
+    fn try_from(par: ir::Par) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stmts: par
+                .stmts
+                .into_iter()
+                .map(Control::try_from)
+                .collect::<Result<_, _>>()?,
             attributes: par.attributes,
-        }
+        })
     }
 }
 
-impl From<ir::If> for If {
-    fn from(i: ir::If) -> Self {
-        Self {
+impl TryFrom<ir::If> for If {
+    type Error = InterpreterError;
+
+    fn try_from(i: ir::If) -> Result<Self, Self::Error> {
+        Ok(Self {
             port: i.port,
             cond: i.cond,
-            tbranch: (*i.tbranch).into(),
-            fbranch: (*i.fbranch).into(),
+            tbranch: (*i.tbranch).try_into()?,
+            fbranch: (*i.fbranch).try_into()?,
             attributes: i.attributes,
-        }
+        })
     }
 }
 
-impl From<ir::While> for While {
-    fn from(wh: ir::While) -> Self {
-        Self {
+impl TryFrom<ir::While> for While {
+    type Error = InterpreterError;
+
+    fn try_from(wh: ir::While) -> Result<Self, Self::Error> {
+        Ok(Self {
             port: wh.port,
             cond: wh.cond,
-            body: (*wh.body).into(),
+            body: (*wh.body).try_into()?,
             attributes: wh.attributes,
-        }
+        })
     }
 }
@@ -11,12 +11,13 @@ use interp::{
     flatten::structures::environment::{Environment, Simulator},
     interpreter::ComponentInterpreter,
     interpreter_ir as iir,
-    serialization::data_dump::DataDump,
+    serialization::{data_dump::DataDump, vcd::VcdWriter},
 };
 use rustyline::error::ReadlineError;
 use slog::warn;
 use std::{
-    io::stdout,
+    fs::File,
+    io::{stdout, BufWriter},
     path::{Path, PathBuf},
     rc::Rc,
 };
@@ -70,6 +71,11 @@ pub struct Opts {
     #[argh(switch, long = "raw")]
     raw: bool,
 
+    /// path to write a VCD waveform of the simulation to. Not supported by
+    /// the debugger
+    #[argh(option, long = "vcd")]
+    vcd: Option<PathBuf>,
+
     #[argh(subcommand)]
     comm: Option<Command>,
 }
@@ -164,8 +170,8 @@ fn main() -> InterpreterResult<()> {
             let components: iir::ComponentCtx = Rc::new(
                 ctx.components
                     .into_iter()
-                    .map(|x| Rc::new(x.into()))
-                    .collect(),
+                    .map(|x| x.try_into().map(Rc::new))
+                    .collect::<Result<_, InterpreterError>>()?,
             );
 
             let main_component = components
@@ -183,8 +189,22 @@ fn main() -> InterpreterResult<()> {
             )?;
 
             let res = if matches!(comm, Command::Interpret(_)) {
-                ComponentInterpreter::interpret_program(env, main_component)
+                if let Some(path) = &opts.vcd {
+                    let mut vcd =
+                        VcdWriter::new(BufWriter::new(File::create(path)?))?;
+                    ComponentInterpreter::interpret_program_with_vcd(
+                        env,
+                        main_component,
+                        &mut vcd,
+                    )
+                } else {
+                    ComponentInterpreter::interpret_program(env, main_component)
+                }
             } else {
+                if opts.vcd.is_some() {
+                    warn!(log, "VCD output is not supported by the debugger and will be ignored")
+                }
+
                 let map = if let Some(map_res) =
                     metadata.map(SourceMap::from_string)
                 {
@@ -201,7 +221,7 @@ fn main() -> InterpreterResult<()> {
             print_res(res, opts.raw)
         }
         Command::Flat(configs) => {
-            let i_ctx = interp::flatten::flat_ir::translate(&ctx);
+            let i_ctx = interp::flatten::flat_ir::translate(&ctx)?;
             let data_dump = opts
                 .data_file
                 .map(|path| {
@@ -213,6 +233,10 @@ fn main() -> InterpreterResult<()> {
 
             let mut sim = Simulator::new(Environment::new(&i_ctx, data_dump));
 
+            if let Some(path) = &opts.vcd {
+                sim.attach_vcd(BufWriter::new(File::create(path)?))?;
+            }
+
             sim.run_program()?;
 
             let output = sim.dump_memories(configs.dump_registers);
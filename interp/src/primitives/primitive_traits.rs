@@ -55,6 +55,28 @@ pub trait Primitive: Named {
         self.serialize(None).has_state()
     }
 
+    /// Overwrite the entry at the given flattened index of the state of this
+    /// primitive with `value`, truncated to the width of the entry. Used by the
+    /// debugger to poke registers and memories. Returns false if there is no
+    /// such entry.
+    fn set_entry(&mut self, _index: usize, _value: i128) -> bool {
+        false
+    }
+
+    /// The entry at the given flattened index of the state of this primitive,
+    /// if there is one. Lets the debugger watch a single entry without
+    /// serializing the whole state.
+    fn get_entry(&self, _index: usize) -> Option<Value> {
+        None
+    }
+
+    /// The flattened index of the entry most recently written, if any. Lets
+    /// the debugger notice a change to a large state without comparing every
+    /// entry.
+    fn last_written_entry(&self) -> Option<usize> {
+        None
+    }
+
     fn get_state(&self) -> Option<StateView<'_>> {
         None
     }
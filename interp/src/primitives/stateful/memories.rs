@@ -141,6 +141,18 @@ impl Primitive for StdReg {
         let code = signed.unwrap_or_default();
         Serializable::Val(Entry::from_val_code(&self.data[0], &code))
     }
+
+    fn set_entry(&mut self, index: usize, value: i128) -> bool {
+        if index != 0 {
+            return false;
+        }
+        self.data[0] = Value::from(value, self.width);
+        true
+    }
+
+    fn get_entry(&self, index: usize) -> Option<Value> {
+        (index == 0).then(|| self.data[0].clone())
+    }
 }
 
 enum StdMemAction {
@@ -171,6 +183,8 @@ pub struct StdMem<T: MemBinder> {
     full_name: ir::Id,
     allow_invalid_memory_access: bool,
     update: StdMemAction,
+    /// The index of the entry most recently written
+    last_write: Option<usize>,
 }
 
 impl<T: MemBinder> StdMem<T> {
@@ -193,6 +207,7 @@ impl<T: MemBinder> StdMem<T> {
             full_name: name,
             allow_invalid_memory_access,
             update: StdMemAction::None,
+            last_write: None,
         }
     }
 
@@ -229,6 +244,7 @@ impl<T: MemBinder> StdMem<T> {
             full_name: name,
             allow_invalid_memory_access,
             update: StdMemAction::None,
+            last_write: None,
         })
     }
 }
@@ -318,6 +334,7 @@ impl<T: MemBinder> Primitive for StdMem<T> {
                     output![("read_data", v), ("done", Value::bit_high())]
                 } else {
                     self.data[idx] = v.clone();
+                    self.last_write = Some(idx);
                     output![("read_data", v), ("done", Value::bit_high())]
                 }
             }
@@ -349,6 +366,25 @@ impl<T: MemBinder> Primitive for StdMem<T> {
     fn has_serializeable_state(&self) -> bool {
         true
     }
+
+    fn set_entry(&mut self, index: usize, value: i128) -> bool {
+        match self.data.get_mut(index) {
+            Some(entry) => {
+                *entry = Value::from(value, self.width);
+                self.last_write = Some(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn get_entry(&self, index: usize) -> Option<Value> {
+        self.data.get(index).cloned()
+    }
+
+    fn last_written_entry(&self) -> Option<usize> {
+        self.last_write
+    }
 }
 
 impl StdMem<MemD1> {
@@ -479,6 +515,8 @@ pub struct SeqMem<T: MemBinder> {
     // I/O
     read_out: Value,
     update: SeqMemAction<InterpreterResult<u64>>,
+    /// The index of the entry most recently written
+    last_write: Option<usize>,
 }
 
 impl<T: MemBinder> SeqMem<T> {
@@ -502,6 +540,7 @@ impl<T: MemBinder> SeqMem<T> {
             allow_invalid_memory_access,
             read_out: Value::zeroes(width),
             update: SeqMemAction::None,
+            last_write: None,
         }
     }
 
@@ -539,6 +578,7 @@ impl<T: MemBinder> SeqMem<T> {
             allow_invalid_memory_access,
             read_out: Value::zeroes(width),
             update: SeqMemAction::None,
+            last_write: None,
         })
     }
 }
@@ -611,6 +651,7 @@ impl<T: MemBinder> Primitive for SeqMem<T> {
                 let idx = idx? as usize;
                 if idx < self.data.len() {
                     self.data[idx] = v;
+                    self.last_write = Some(idx);
                 }
 
                 self.read_out = Value::zeroes(self.width);
@@ -661,4 +702,23 @@ impl<T: MemBinder> Primitive for SeqMem<T> {
     fn has_serializeable_state(&self) -> bool {
         true
     }
+
+    fn set_entry(&mut self, index: usize, value: i128) -> bool {
+        match self.data.get_mut(index) {
+            Some(entry) => {
+                *entry = Value::from(value, self.width);
+                self.last_write = Some(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn get_entry(&self, index: usize) -> Option<Value> {
+        self.data.get(index).cloned()
+    }
+
+    fn last_written_entry(&self) -> Option<usize> {
+        self.last_write
+    }
 }
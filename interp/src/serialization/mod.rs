@@ -1,3 +1,4 @@
 pub mod data_dump;
 mod old;
+pub mod vcd;
 pub use old::*;
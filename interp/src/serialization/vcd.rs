@@ -0,0 +1,231 @@
+//! A minimal writer for the Value Change Dump (VCD) format.
+//!
+//! The writer is split into two phases. First the signal hierarchy is declared
+//! using [VcdWriter::push_scope], [VcdWriter::add_signal] and
+//! [VcdWriter::pop_scope]. Once [VcdWriter::end_definitions] is called, values
+//! may be supplied via [VcdWriter::set_value] and are flushed to the output
+//! with [VcdWriter::timestep]. Only signals whose value changed since the last
+//! time step are written.
+
+use std::io::Write;
+
+use crate::values::Value;
+
+/// An opaque handle for a signal declared in a [VcdWriter].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignalId(usize);
+
+#[derive(Debug)]
+struct SignalState {
+    /// The identifier code used to refer to this signal in the dump
+    code: String,
+    width: usize,
+    /// The value last written to the dump, `None` if nothing has been written
+    /// yet
+    written: Option<String>,
+    /// The value for the current time step
+    pending: String,
+}
+
+/// Streams port values into a VCD file.
+pub struct VcdWriter<W: Write> {
+    out: W,
+    signals: Vec<SignalState>,
+    scope_depth: usize,
+    definitions_done: bool,
+    time: u64,
+}
+
+impl<W: Write> VcdWriter<W> {
+    /// Create a new writer and emit the VCD preamble.
+    pub fn new(mut out: W) -> std::io::Result<Self> {
+        writeln!(out, "$version Cider {} $end", env!("CARGO_PKG_VERSION"))?;
+        writeln!(out, "$timescale 1ns $end")?;
+        Ok(Self {
+            out,
+            signals: vec![],
+            scope_depth: 0,
+            definitions_done: false,
+            time: 0,
+        })
+    }
+
+    /// Open a new module scope with the given name. All signals declared
+    /// before the matching [VcdWriter::pop_scope] live under this scope.
+    pub fn push_scope(&mut self, name: &str) -> std::io::Result<()> {
+        debug_assert!(!self.definitions_done);
+        self.scope_depth += 1;
+        writeln!(self.out, "$scope module {} $end", sanitize(name))
+    }
+
+    /// Close the most recently opened scope.
+    pub fn pop_scope(&mut self) -> std::io::Result<()> {
+        debug_assert!(self.scope_depth > 0, "no scope to pop");
+        self.scope_depth -= 1;
+        writeln!(self.out, "$upscope $end")
+    }
+
+    /// Declare a new signal with the given name and bit width in the current
+    /// scope.
+    pub fn add_signal(
+        &mut self,
+        name: &str,
+        width: usize,
+    ) -> std::io::Result<SignalId> {
+        debug_assert!(!self.definitions_done);
+        let id = SignalId(self.signals.len());
+        let code = identifier_code(id.0);
+        writeln!(
+            self.out,
+            "$var wire {} {} {} $end",
+            width,
+            code,
+            sanitize(name)
+        )?;
+        self.signals.push(SignalState {
+            code,
+            width,
+            written: None,
+            pending: undefined(width),
+        });
+        Ok(id)
+    }
+
+    /// Finish the declaration section. No signals or scopes may be declared
+    /// after this is called.
+    pub fn end_definitions(&mut self) -> std::io::Result<()> {
+        while self.scope_depth > 0 {
+            self.pop_scope()?;
+        }
+        self.definitions_done = true;
+        writeln!(self.out, "$enddefinitions $end")
+    }
+
+    /// Set the value of the given signal for the current time step. `None`
+    /// marks the signal as undefined.
+    pub fn set_value(&mut self, signal: SignalId, value: Option<&Value>) {
+        let state = &mut self.signals[signal.0];
+        state.pending = match value {
+            Some(v) => {
+                debug_assert_eq!(v.width() as usize, state.width);
+                v.iter()
+                    .rev()
+                    .map(|bit| if bit { '1' } else { '0' })
+                    .collect()
+            }
+            None => undefined(state.width),
+        };
+    }
+
+    /// Write out all the values which have changed since the last time step
+    /// and advance the time.
+    pub fn timestep(&mut self) -> std::io::Result<()> {
+        debug_assert!(self.definitions_done, "definitions not finished");
+        let first = self.time == 0;
+        writeln!(self.out, "#{}", self.time)?;
+        if first {
+            writeln!(self.out, "$dumpvars")?;
+        }
+        for signal in self.signals.iter_mut() {
+            if signal.written.as_ref() == Some(&signal.pending) {
+                continue;
+            }
+            if signal.width == 1 {
+                writeln!(self.out, "{}{}", signal.pending, signal.code)?;
+            } else {
+                writeln!(self.out, "b{} {}", signal.pending, signal.code)?;
+            }
+            signal.written = Some(signal.pending.clone());
+        }
+        if first {
+            writeln!(self.out, "$end")?;
+        }
+        self.time += 1;
+        Ok(())
+    }
+
+    /// Emit the final timestamp and flush the underlying writer.
+    pub fn finish(&mut self) -> std::io::Result<()> {
+        writeln!(self.out, "#{}", self.time)?;
+        self.out.flush()
+    }
+}
+
+/// Produces the identifier code for the signal with the given index. Codes are
+/// base-94 numbers using the printable ASCII characters.
+fn identifier_code(mut idx: usize) -> String {
+    const FIRST: u8 = b'!';
+    const RANGE: usize = (b'~' - b'!' + 1) as usize;
+
+    let mut code = String::new();
+    loop {
+        code.push((FIRST + (idx % RANGE) as u8) as char);
+        idx /= RANGE;
+        if idx == 0 {
+            break;
+        }
+        idx -= 1;
+    }
+    code
+}
+
+fn undefined(width: usize) -> String {
+    "x".repeat(width)
+}
+
+/// VCD references may not contain whitespace
+fn sanitize(name: &str) -> String {
+    name.replace(char::is_whitespace, "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifier_codes_are_unique() {
+        let codes: std::collections::HashSet<_> =
+            (0..20_000).map(identifier_code).collect();
+        assert_eq!(codes.len(), 20_000);
+        assert_eq!(identifier_code(0), "!");
+        assert_eq!(identifier_code(93), "~");
+        assert_eq!(identifier_code(94), "!!");
+    }
+
+    #[test]
+    fn only_changes_are_written() -> std::io::Result<()> {
+        let mut buf = vec![];
+        {
+            let mut writer = VcdWriter::new(&mut buf)?;
+            writer.push_scope("main")?;
+            let go = writer.add_signal("go", 1)?;
+            let out = writer.add_signal("out", 4)?;
+            writer.end_definitions()?;
+
+            writer.set_value(go, Some(&Value::bit_high()));
+            writer.set_value(out, None);
+            writer.timestep()?;
+
+            writer.set_value(out, Some(&Value::from(5_u64, 4_u64)));
+            writer.timestep()?;
+
+            writer.set_value(out, Some(&Value::from(5_u64, 4_u64)));
+            writer.timestep()?;
+            writer.finish()?;
+        }
+        let output = String::from_utf8(buf).unwrap();
+        let body = output
+            .split("$enddefinitions $end\n")
+            .nth(1)
+            .unwrap()
+            .to_string();
+
+        assert!(output.contains("$scope module main $end"));
+        assert!(output.contains("$var wire 4 \" out $end"));
+        assert_eq!(
+            body,
+            "#0\n$dumpvars\n1!\nbxxxx \"\n$end\n#1\nb0101 \"\n#2\n#3\n"
+        );
+        Ok(())
+    }
+}
@@ -11,7 +11,7 @@ use std::{
 use crate::{
     debugger::{name_tree::ActiveTreeNode, PrintCode},
     environment::{InterpreterState, PrimitiveMap},
-    interpreter::ConstCell,
+    interpreter::{ConstCell, Interpreter},
     interpreter_ir as iir,
     primitives::Primitive,
     serialization::{Entry, Serializable},
@@ -197,6 +197,19 @@ impl<'a> StateView<'a> {
             StateView::Composite(c) => &c.0.component,
         }
     }
+    /// The number of clock edges seen by this environment. Par arms advance
+    /// in lockstep so this is the largest count across all of them.
+    pub fn get_clock(&self) -> u64 {
+        match self {
+            StateView::SingleView(sv) => sv.clk,
+            StateView::Composite(cv) => {
+                cv.1.iter()
+                    .map(StateView::get_clock)
+                    .fold(cv.0.clk, u64::max)
+            }
+        }
+    }
+
     /// A wrapper over [InterpreterState::get_active_tree]
     pub fn get_active_tree(&self) -> Vec<ActiveTreeNode> {
         match self {
@@ -251,6 +264,65 @@ impl<'a> StateView<'a> {
         }
     }
 
+    /// Walk every port visible from this view in a fixed order, calling
+    /// `visit` on each. Group holes and the ports of each cell are grouped
+    /// under a scope named after the group or cell. Sub-components are entered
+    /// recursively, with their signature, groups, and cells appearing under the
+    /// scope of the cell instantiating them. Constants are skipped since their
+    /// values never change.
+    ///
+    /// The order of the walk only depends on the program structure so it can
+    /// be repeated to get a consistent view of the values across cycles.
+    pub fn walk_ports<F, E>(&self, visit: &mut F) -> Result<(), E>
+    where
+        F: FnMut(PortWalkItem<'_>) -> Result<(), E>,
+    {
+        let comp = self.get_comp();
+        let visit_port = |port: &RRC<ir::Port>, visit: &mut F| {
+            let port_ref = port.borrow();
+            visit(PortWalkItem::Port {
+                name: port_ref.name,
+                width: port_ref.width,
+                value: self.lookup(port.as_raw()),
+            })
+        };
+
+        for port in comp.signature.borrow().ports.iter() {
+            visit_port(port, visit)?;
+        }
+
+        for group in comp.groups.iter() {
+            let group_ref = group.borrow();
+            visit(PortWalkItem::EnterScope(group_ref.name()))?;
+            for hole in group_ref.holes.iter() {
+                visit_port(hole, visit)?;
+            }
+            visit(PortWalkItem::ExitScope)?;
+        }
+
+        let cell_map = self.get_cell_map().borrow();
+        for cell in comp.cells.iter() {
+            let cell_ref = cell.borrow();
+            if matches!(cell_ref.prototype, ir::CellType::Constant { .. }) {
+                continue;
+            }
+            visit(PortWalkItem::EnterScope(cell_ref.name()))?;
+            if let Some(sub_comp) = cell_map
+                .get(&cell.as_raw())
+                .and_then(|prim| prim.get_comp_interpreter())
+            {
+                sub_comp.get_env().walk_ports(visit)?;
+            } else {
+                for port in cell_ref.ports.iter() {
+                    visit_port(port, visit)?;
+                }
+            }
+            visit(PortWalkItem::ExitScope)?;
+        }
+
+        Ok(())
+    }
+
     /// Generate a serializable representation of the environment. Used to
     /// display the environment at the current component or to output at the end
     /// of the program.
@@ -334,6 +406,20 @@ impl<'a> StateView<'a> {
     }
 }
 
+/// The items produced by [StateView::walk_ports]
+pub enum PortWalkItem<'a> {
+    /// Entering the scope of a group or cell with the given name
+    EnterScope(ir::Id),
+    /// Leaving the most recently entered scope
+    ExitScope,
+    /// A port and its current value
+    Port {
+        name: ir::Id,
+        width: u64,
+        value: &'a Value,
+    },
+}
+
 #[allow(clippy::borrowed_box)]
 #[derive(Serialize, Clone)]
 /// Struct to fully serialize the internal state of the environment
@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod debugger_test {
+    use crate::debugger::Debugger;
+    use std::path::Path;
+
+    /// A debugger for the program `name` in `interp/tests/debugger`
+    fn debugger(name: &str) -> Debugger {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let file = root.join("tests/debugger").join(name);
+        Debugger::from_file(&file, root.parent().unwrap())
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn evaluate_ports() {
+        let mut debugger = debugger("watch.futil");
+        assert_eq!(debugger.evaluate("p \\u r.out").unwrap(), "r.out = 0");
+        // bare targets are printed
+        assert_eq!(debugger.evaluate("\\u r.out").unwrap(), "r.out = 0");
+        while !debugger.step(1).unwrap().get_done() {}
+        assert_eq!(debugger.evaluate("p \\u r.out").unwrap(), "r.out = 5");
+        assert_eq!(debugger.evaluate("p \\u main.r.out").unwrap(), "r.out = 5");
+    }
+
+    #[test]
+    fn evaluate_missing() {
+        let debugger = debugger("watch.futil");
+        assert!(debugger.evaluate("p nope").is_err());
+        assert!(debugger.evaluate("p r.nope").is_err());
+        assert!(debugger.evaluate("step").is_err());
+    }
+
+    #[test]
+    fn set_register_and_port() {
+        let mut debugger = debugger("watch.futil");
+        assert_eq!(debugger.set_variable("r", "12").unwrap(), "12");
+        assert_eq!(debugger.cell_state("r").unwrap(), "12");
+        // values are truncated to the width of the register
+        assert_eq!(debugger.set_variable("r", "4294967297").unwrap(), "1");
+        assert_eq!(debugger.set_variable("r.in", "3").unwrap(), "3");
+        assert_eq!(debugger.evaluate("p \\u r.in").unwrap(), "r.in = 3");
+        assert!(debugger.set_variable("r", "ten").is_err());
+        assert!(debugger.set_variable("nope", "1").is_err());
+    }
+
+    #[test]
+    fn set_memory_entry() {
+        let mut debugger = debugger("watch.futil");
+        assert_eq!(debugger.set_variable("mem[1]", "3").unwrap(), "3");
+        assert_eq!(debugger.cell_state("mem[1]").unwrap(), "3");
+        // the other entries are left alone
+        assert_eq!(debugger.cell_state("mem[0]").unwrap(), "0");
+        assert!(debugger.set_variable("mem[4]", "3").is_err());
+    }
+
+    #[test]
+    fn set_memory_needs_index() {
+        let mut debugger = debugger("watch.futil");
+        assert!(debugger.set_variable("mem", "3").is_err());
+        assert_eq!(debugger.cell_state("mem[0]").unwrap(), "0");
+    }
+
+    #[test]
+    fn data_breakpoints() {
+        let mut debugger = debugger("watch.futil");
+        assert_eq!(
+            debugger.set_data_breakpoints(&["mem[2]", "r", "nope", "mem[4]"]),
+            vec![true, true, false, false]
+        );
+
+        // writing mem[0] does not stop at the breakpoint on mem[2]
+        let status = debugger.cont().unwrap();
+        assert_eq!(status.get_changed(), ["r"]);
+        assert_eq!(debugger.cell_state("mem[0]").unwrap(), "7");
+
+        let status = debugger.cont().unwrap();
+        assert_eq!(status.get_changed(), ["mem[2]"]);
+        assert_eq!(debugger.cell_state("mem[2]").unwrap(), "9");
+
+        let status = debugger.cont().unwrap();
+        assert!(status.get_changed().is_empty());
+        assert!(status.get_done());
+    }
+
+    #[test]
+    fn data_breakpoint_on_memory() {
+        let mut debugger = debugger("watch.futil");
+        assert_eq!(debugger.set_data_breakpoints(&["mem"]), vec![true]);
+        let status = debugger.cont().unwrap();
+        assert_eq!(status.get_changed(), ["mem"]);
+        assert_eq!(debugger.cell_state("r").unwrap(), "0");
+
+        // the write to a later entry is noticed as well
+        let status = debugger.cont().unwrap();
+        assert_eq!(status.get_changed(), ["mem"]);
+        assert_eq!(debugger.cell_state("mem[2]").unwrap(), "9");
+    }
+
+    /// The names of the innermost frames of the running threads
+    fn running(debugger: &Debugger) -> Vec<String> {
+        let mut names: Vec<String> = debugger
+            .active_threads()
+            .iter()
+            .map(|thread| thread[0].name())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn par_threads() {
+        let debugger = debugger("threads.futil");
+        // each arm of the par is a thread
+        assert_eq!(running(&debugger), ["main::wr_a", "main::wr_b"]);
+        // threads running the same control node are equal
+        assert_eq!(debugger.active_threads(), debugger.active_threads());
+    }
+
+    #[test]
+    fn breakpoints_in_subcomponents() {
+        let mut debugger = debugger("threads.futil");
+        assert_eq!(
+            debugger.set_breakpoints(&[
+                "bump",
+                "wr_c",
+                "nope",
+                "add_one::bump",
+                "main::bump"
+            ]),
+            vec![true, true, false, true, false]
+        );
+
+        let status = debugger.cont().unwrap();
+        assert!(!status.get_done());
+        // the finished arm of the par stays until the par is done
+        assert_eq!(running(&debugger), ["main::wr_a", "main::wr_c"]);
+
+        let status = debugger.cont().unwrap();
+        assert!(!status.get_done());
+        let threads = debugger.active_threads();
+        let thread = threads
+            .iter()
+            .find(|thread| thread[0].name() == "main.sub::bump")
+            .unwrap();
+        assert_eq!(thread[0].component(), "add_one");
+        assert_eq!(thread[0].group(), Some("bump".into()));
+        // the instance invoking the subcomponent is further down the stack
+        assert_eq!(thread.last().unwrap().component(), "main");
+
+        assert!(debugger.cont().unwrap().get_done());
+    }
+
+    #[test]
+    fn frame_variables() {
+        let mut debugger = debugger("threads.futil");
+        debugger.set_breakpoints(&["bump"]);
+        debugger.cont().unwrap();
+        let threads = debugger.active_threads();
+        let thread = threads
+            .iter()
+            .find(|thread| thread[0].name() == "main.sub::bump")
+            .unwrap();
+
+        let cells = debugger.frame_variables(&thread[0]).unwrap();
+        let names: Vec<&str> = cells
+            .iter()
+            .map(|cell| cell.evaluate_name.as_str())
+            .collect();
+        assert_eq!(names, ["sub.acc"]);
+        let ports: Vec<&str> = cells[0]
+            .children
+            .iter()
+            .map(|port| port.evaluate_name.as_str())
+            .collect();
+        assert!(ports.contains(&"sub.acc.out"));
+
+        let main = debugger.frame_variables(thread.last().unwrap()).unwrap();
+        let sub = main.iter().find(|cell| cell.name == "sub").unwrap();
+        assert_eq!(sub.value, "instance of add_one");
+        let a = main.iter().find(|cell| cell.name == "a").unwrap();
+        assert_eq!(a.value, "1");
+    }
+}
@@ -1,3 +1,5 @@
+mod debugger;
 mod primitives;
+mod static_control;
 mod stk_env;
 mod values;
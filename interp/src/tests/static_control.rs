@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod static_control_test {
+    use crate::flatten::{
+        flat_ir,
+        structures::environment::{Environment, Simulator},
+    };
+    use calyx_frontend::Workspace;
+    use calyx_ir as ir;
+    use std::path::Path;
+
+    /// The number of cycles the flat interpreter takes to run the program
+    /// `name` in `interp/tests/static`
+    fn cycles(name: &str) -> u64 {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let file = root.join("tests/static").join(name);
+        let ws =
+            Workspace::construct(&Some(file), root.parent().unwrap()).unwrap();
+        let ctx = ir::from_ast::ast_to_ir(ws).unwrap();
+        let i_ctx = flat_ir::translate(&ctx).unwrap();
+
+        let mut sim = Simulator::new(Environment::new(&i_ctx, None));
+        let mut cycles = 0;
+        while !sim.is_done() {
+            sim.step().unwrap();
+            cycles += 1;
+        }
+        cycles
+    }
+
+    #[test]
+    fn static_seq_cycles() {
+        assert_eq!(cycles("static-seq.futil"), 2);
+    }
+
+    #[test]
+    fn static_repeat_cycles() {
+        // 5 cycles for the first repeat and 3 * 2 for the second
+        assert_eq!(cycles("static-repeat.futil"), 11);
+    }
+}
@@ -0,0 +1,46 @@
+# Summarizes a VCD file written by Cider. Prints the scope hierarchy followed by
+# every value change of the signals listed in `signals`, a space separated list
+# of hierarchical names such as `main.r.out`. Each change is prefixed by the
+# time at which it happened.
+BEGIN {
+    n = split(signals, names, " ")
+    for (i = 1; i <= n; i++) watched[names[i]] = 1
+    depth = 0
+    time = 0
+}
+
+$1 == "$scope" {
+    path[++depth] = $3
+    indent = ""
+    for (i = 1; i < depth; i++) indent = indent "  "
+    print indent $3
+    next
+}
+
+$1 == "$upscope" { depth--; next }
+
+$1 == "$var" {
+    name = path[1]
+    for (i = 2; i <= depth; i++) name = name "." path[i]
+    name = name "." $5
+    if (name in watched) codes[$4] = name
+    next
+}
+
+# timestamps: `#<time>`
+/^#/ {
+    time = substr($0, 2)
+    next
+}
+
+# vector values: `b<bits> <code>`
+/^b/ {
+    if ($2 in codes) print "#" time, codes[$2], substr($1, 2)
+    next
+}
+
+# scalar values: `<bit><code>`
+/^[01xz]/ {
+    code = substr($0, 2)
+    if (code in codes) print "#" time, codes[code], substr($0, 1, 1)
+}
@@ -1,7 +1,5 @@
 //! Command line parsing for the Calyx compiler.
 use argh::FromArgs;
-#[cfg(feature = "serialize")]
-use calyx_backend::SexpBackend;
 #[cfg(feature = "yxi")]
 use calyx_backend::YxiBackend;
 use calyx_backend::{
@@ -9,6 +7,8 @@ use calyx_backend::{
     Backend, BackendOpt, FirrtlBackend, MlirBackend, PrimitiveUsesBackend,
     ResourcesBackend, VerilogBackend,
 };
+#[cfg(feature = "serialize")]
+use calyx_backend::{JsonBackend, SexpBackend};
 use calyx_ir as ir;
 use calyx_utils::{CalyxResult, Error, OutputFile};
 use std::path::Path;
@@ -54,6 +54,10 @@ pub struct Opts {
     )]
     pub lib_path: PathBuf,
 
+    /// format of the input program: calyx, mlir, sexp or json
+    #[argh(option, short = 'f', default = "InputFormat::default()")]
+    pub input_format: InputFormat,
+
     /// compilation mode
     #[argh(option, short = 'm', default = "CompileMode::default()")]
     pub compile_mode: CompileMode,
@@ -75,6 +79,11 @@ pub struct Opts {
     #[argh(switch, long = "emit-primitive-extmodules")]
     pub emit_primitive_extmodules: bool,
 
+    /// emit FIRRTL implementations of primitives
+    /// (only relevant to the FIRRTL backend)
+    #[argh(switch, long = "emit-firrtl-primitives")]
+    pub emit_firrtl_primitives: bool,
+
     /// select a backend
     #[argh(option, short = 'b', default = "BackendOpt::default()")]
     pub backend: BackendOpt,
@@ -99,6 +108,18 @@ pub struct Opts {
     #[argh(option, long = "log", default = "log::LevelFilter::Warn")]
     pub log_level: log::LevelFilter,
 
+    /// maximum number of errors to report. 0 reports all of them
+    #[argh(option, long = "error-limit", default = "20")]
+    pub error_limit: usize,
+
+    /// format of the reported errors and warnings: text or json
+    #[argh(
+        option,
+        long = "diagnostics-format",
+        default = "DiagnosticsFormat::default()"
+    )]
+    pub diagnostics_format: DiagnosticsFormat,
+
     #[argh(switch, long = "dump-ir")]
     /// print out the IR after every pass
     pub dump_ir: bool,
@@ -134,6 +155,56 @@ impl FromStr for CompileMode {
     }
 }
 
+// Formats of the input program
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+pub enum InputFormat {
+    #[default]
+    /// Calyx source code.
+    Calyx,
+    /// A program in the CIRCT Calyx dialect.
+    Mlir,
+    /// IR serialized by the `sexp` backend.
+    Sexp,
+    /// IR serialized as JSON.
+    Json,
+}
+
+impl FromStr for InputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "calyx" => Ok(InputFormat::Calyx),
+            "mlir" => Ok(InputFormat::Mlir),
+            "sexp" => Ok(InputFormat::Sexp),
+            "json" => Ok(InputFormat::Json),
+            s => Err(format!("Unknown input format: {}. Valid options are `calyx`, `mlir`, `sexp` or `json`", s))
+        }
+    }
+}
+
+// Formats for reporting errors and warnings
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+pub enum DiagnosticsFormat {
+    #[default]
+    /// Human-readable messages that show the offending source lines.
+    Text,
+    /// One JSON object per line, for consumption by other tools.
+    Json,
+}
+
+impl FromStr for DiagnosticsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(DiagnosticsFormat::Text),
+            "json" => Ok(DiagnosticsFormat::Json),
+            s => Err(format!("Unknown diagnostics format: {}. Valid options are `text` or `json`", s))
+        }
+    }
+}
+
 // ================== Backend Variant and Parsing ===================== //
 
 impl Opts {
@@ -161,6 +232,19 @@ impl Opts {
                     ))
                 }
             }
+            BackendOpt::Json => {
+                #[cfg(feature = "serialize")]
+                {
+                    let backend = JsonBackend;
+                    backend.run(context, self.output)
+                }
+                #[cfg(not(feature = "serialize"))]
+                {
+                    Err(Error::misc(
+                        "Json backend requires the `serialize` feature to be enabled",
+                    ))
+                }
+            }
             BackendOpt::Verilog => {
                 let backend = VerilogBackend;
                 backend.run(context, self.output)
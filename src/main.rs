@@ -10,14 +10,66 @@ use calyx_backend::BackendOpt;
 use calyx_frontend as frontend;
 use calyx_ir as ir;
 use calyx_opt::pass_manager::PassManager;
-use calyx_utils::CalyxResult;
-use cmdline::{CompileMode, Opts};
+use calyx_utils::{CalyxResult, DiagnosticContext, Error, Severity};
+use cmdline::{CompileMode, DiagnosticsFormat, InputFormat, Opts};
 use itertools::Itertools;
 
-fn main() -> CalyxResult<()> {
+fn main() {
     // parse the command line arguments into Opts struct
-    let mut opts = Opts::get_opts()?;
+    let opts = match Opts::get_opts() {
+        Ok(opts) => opts,
+        Err(err) => report_errors(err, 0, DiagnosticsFormat::Text),
+    };
+    let error_limit = opts.error_limit;
+    let format = opts.diagnostics_format;
+    if let Err(err) = run(opts) {
+        report_errors(err, error_limit, format)
+    }
+}
+
+/// Print `diagnostics` sorted by their position in the source files. At most `limit` errors are
+/// printed, unless `limit` is 0. Warnings are always printed.
+fn report(
+    diagnostics: &DiagnosticContext,
+    limit: usize,
+    format: DiagnosticsFormat,
+) {
+    let mut shown = 0;
+    for (severity, diag) in diagnostics.sorted() {
+        if severity == Severity::Error {
+            if limit != 0 && shown >= limit {
+                continue;
+            }
+            shown += 1;
+        }
+        match (format, severity) {
+            (DiagnosticsFormat::Json, _) => {
+                eprintln!("{}", diag.to_json(severity))
+            }
+            (DiagnosticsFormat::Text, Severity::Warning) => {
+                log::warn!("{diag:?}")
+            }
+            (DiagnosticsFormat::Text, Severity::Error) => {
+                eprintln!("Error: {diag:?}")
+            }
+        }
+    }
+    let hidden = diagnostics.num_errors() - shown;
+    if hidden > 0 && format == DiagnosticsFormat::Text {
+        eprintln!(
+            "... and {hidden} more error{}. Use --error-limit to show more.",
+            if hidden == 1 { "" } else { "s" }
+        );
+    }
+}
+
+/// Print the errors and warnings carried by `err` and exit.
+fn report_errors(err: Error, limit: usize, format: DiagnosticsFormat) -> ! {
+    report(&err.into_diagnostics(), limit, format);
+    std::process::exit(1)
+}
 
+fn run(mut opts: Opts) -> CalyxResult<()> {
     // Return the version and the git commit this was built on
     if opts.version {
         println!("Calyx compiler version {}", env!("CARGO_PKG_VERSION"));
@@ -56,31 +108,60 @@ fn main() -> CalyxResult<()> {
         }
     }
 
-    // Construct the namespace.
-    let mut ws = frontend::Workspace::construct(&opts.file, &opts.lib_path)?;
+    let (mut ctx, imports) = match opts.input_format {
+        InputFormat::Calyx | InputFormat::Mlir => {
+            // Construct the namespace.
+            let mut ws = if opts.input_format == InputFormat::Mlir {
+                frontend::Workspace::construct_from_mlir(
+                    &opts.file,
+                    &opts.lib_path,
+                )?
+            } else {
+                frontend::Workspace::construct(&opts.file, &opts.lib_path)?
+            };
 
-    let imports = ws.original_imports.drain(..).collect_vec();
+            let imports = ws.original_imports.drain(..).collect_vec();
 
-    // Build the IR representation
-    let mut ctx = ir::from_ast::ast_to_ir(ws)?;
+            // Build the IR representation
+            (ir::from_ast::ast_to_ir(ws)?, imports)
+        }
+        // Serialized programs have all their primitives resolved already
+        InputFormat::Sexp | InputFormat::Json => {
+            (read_serialized(&opts)?, vec![])
+        }
+    };
     // Configuration for the backend
     ctx.bc = ir::BackendConf {
         synthesis_mode: opts.enable_synthesis,
         enable_verification: !opts.disable_verify,
         flat_assign: !opts.nested_assign,
         emit_primitive_extmodules: opts.emit_primitive_extmodules,
+        emit_firrtl_primitives: opts.emit_firrtl_primitives,
     };
     // Extra options for the passes
     ctx.extra_opts = opts.extra_opts.drain(..).collect();
 
     // Run all passes specified by the command line
-    pm.execute_plan(
+    let result = pm.execute_plan(
         &mut ctx,
         &opts.pass,
         &opts.disable_pass,
         &opts.insertions,
         opts.dump_ir,
-    )?;
+    );
+
+    // Warnings from the passes are reported along with the errors, if any
+    let mut diagnostics = match result {
+        Ok(()) => DiagnosticContext::default(),
+        Err(err) => err.into_diagnostics(),
+    };
+    for warning in std::mem::take(&mut ctx.warnings) {
+        diagnostics.warning(warning);
+    }
+    if diagnostics.has_errors() {
+        return Err(Error::from_diagnostics(diagnostics));
+    }
+    report(&diagnostics, 0, opts.diagnostics_format);
 
     // Print out the Calyx program after transformation.
     if opts.backend == BackendOpt::Calyx {
@@ -103,3 +184,43 @@ fn main() -> CalyxResult<()> {
         opts.run_backend(ctx)
     }
 }
+
+/// Read a serialized IR program from the input file or standard input.
+#[cfg(feature = "serialize")]
+fn read_serialized(opts: &Opts) -> CalyxResult<ir::Context> {
+    use std::io::Read;
+    let mut text = String::new();
+    match &opts.file {
+        Some(path) => {
+            std::fs::File::open(path)
+                .and_then(|mut f| f.read_to_string(&mut text))
+                .map_err(|err| {
+                    Error::invalid_file(format!(
+                        "Failed to read {}: {err}",
+                        path.to_string_lossy()
+                    ))
+                })?;
+        }
+        None => {
+            std::io::stdin().read_to_string(&mut text)?;
+        }
+    }
+    match opts.input_format {
+        InputFormat::Sexp => serde_sexpr::from_str(&text).map_err(|err| {
+            Error::invalid_file(format!("Invalid s-expression IR: {err}"))
+        }),
+        InputFormat::Json => serde_json::from_str(&text).map_err(|err| {
+            Error::invalid_file(format!("Invalid JSON IR: {err}"))
+        }),
+        InputFormat::Calyx | InputFormat::Mlir => {
+            unreachable!("Calyx and MLIR programs are parsed")
+        }
+    }
+}
+
+#[cfg(not(feature = "serialize"))]
+fn read_serialized(_opts: &Opts) -> CalyxResult<ir::Context> {
+    Err(Error::misc(
+        "Reading serialized IR requires the `serialize` feature".to_string(),
+    ))
+}
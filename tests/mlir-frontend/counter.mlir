@@ -0,0 +1,31 @@
+// A counter written the way CIRCT prints the Calyx dialect: constants are
+// defined in the wires and unit attributes have no value.
+module attributes {calyx.entrypoint = "main"} {
+  calyx.component @main(%go: i1 {go}, %clk: i1 {clk}, %reset: i1 {reset}) -> (%out: i32, %done: i1 {done}) {
+    %r.in, %r.write_en, %r.clk, %r.reset, %r.out, %r.done = calyx.register @r : i32, i1, i1, i1, i32, i1
+    %add.left, %add.right, %add.out = calyx.std_add @add : i32, i32, i32
+    %lt.left, %lt.right, %lt.out = calyx.std_lt @lt : i32, i32, i1
+    calyx.wires {
+      %c1_i32 = hw.constant 1 : i32
+      %c10_i32 = hw.constant 10 : i32
+      %true = hw.constant true
+      calyx.assign %out = %r.out : i32
+      calyx.group @incr {
+        calyx.assign %add.left = %r.out : i32
+        calyx.assign %add.right = %c1_i32 : i32
+        calyx.assign %r.in = %add.out : i32
+        calyx.assign %r.write_en = %true : i1
+        calyx.group_done %r.done : i1
+      }
+      calyx.comb_group @cond {
+        calyx.assign %lt.left = %r.out : i32
+        calyx.assign %lt.right = %c10_i32 : i32
+      }
+    }
+    calyx.control {
+      calyx.while %lt.out with @cond {
+        calyx.enable @incr
+      }
+    }
+  }
+}
@@ -426,32 +426,64 @@ impl BitVector {
         }
     }
 
-    pub fn saddo(_bv1: &BitVector, _bv2: &BitVector) -> bool {
-        todo!()
+    /// Returns true if `i` is representable as a two's complement integer of
+    /// the given width
+    pub(crate) fn fits_signed(i: &BigInt, width: usize) -> bool {
+        let bound: BigInt = BigInt::one() << (width - 1);
+        -&bound <= *i && *i < bound
     }
 
-    pub fn uaddo(_bv1: &BitVector, _bv2: &BitVector) -> bool {
-        todo!()
+    /// Returns true if `u` is representable as an unsigned integer of the
+    /// given width
+    pub(crate) fn fits_unsigned(u: &BigUint, width: usize) -> bool {
+        u.bits() <= width as u64
     }
 
-    pub fn sdivo(_bv1: &BitVector, _bv2: &BitVector) -> bool {
-        todo!()
+    pub fn saddo(bv1: &BitVector, bv2: &BitVector) -> bool {
+        !BitVector::fits_signed(
+            &(bv1.to_bigint() + bv2.to_bigint()),
+            bv1.bits.len(),
+        )
     }
 
-    pub fn smulo(_bv1: &BitVector, _bv2: &BitVector) -> bool {
-        todo!()
+    pub fn uaddo(bv1: &BitVector, bv2: &BitVector) -> bool {
+        !BitVector::fits_unsigned(
+            &(bv1.to_biguint() + bv2.to_biguint()),
+            bv1.bits.len(),
+        )
     }
 
-    pub fn umulo(_bv1: &BitVector, _bv2: &BitVector) -> bool {
-        todo!()
+    /// Signed division only overflows when dividing the minimum value by -1.
+    /// Division by zero is not considered an overflow.
+    pub fn sdivo(bv1: &BitVector, bv2: &BitVector) -> bool {
+        bv1.to_bigint()
+            .checked_div(&bv2.to_bigint())
+            .is_some_and(|q| !BitVector::fits_signed(&q, bv1.bits.len()))
     }
 
-    pub fn ssubo(_bv1: &BitVector, _bv2: &BitVector) -> bool {
-        todo!()
+    pub fn smulo(bv1: &BitVector, bv2: &BitVector) -> bool {
+        !BitVector::fits_signed(
+            &(bv1.to_bigint() * bv2.to_bigint()),
+            bv1.bits.len(),
+        )
     }
 
-    pub fn usubo(_bv1: &BitVector, _bv2: &BitVector) -> bool {
-        todo!()
+    pub fn umulo(bv1: &BitVector, bv2: &BitVector) -> bool {
+        !BitVector::fits_unsigned(
+            &(bv1.to_biguint() * bv2.to_biguint()),
+            bv1.bits.len(),
+        )
+    }
+
+    pub fn ssubo(bv1: &BitVector, bv2: &BitVector) -> bool {
+        !BitVector::fits_signed(
+            &(bv1.to_bigint() - bv2.to_bigint()),
+            bv1.bits.len(),
+        )
+    }
+
+    pub fn usubo(bv1: &BitVector, bv2: &BitVector) -> bool {
+        BitVector::ult(bv1, bv2)
     }
 
     pub fn concat(bv1: &BitVector, bv2: &BitVector) -> Self {
@@ -715,4 +747,33 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_overflow() {
+        // 4-bit operands, least significant bit first
+        let seven = BitVector::from(vec![true, true, true, false]);
+        let one = BitVector::from(vec![true, false, false, false]);
+        let min = BitVector::from(vec![false, false, false, true]);
+        let neg_one = BitVector::ones(4);
+        let zero = BitVector::zeros(4);
+
+        assert!(BitVector::saddo(&seven, &one));
+        assert!(!BitVector::saddo(&min, &seven));
+        assert!(!BitVector::uaddo(&seven, &one));
+        assert!(BitVector::uaddo(&seven, &neg_one));
+
+        assert!(BitVector::ssubo(&min, &one));
+        assert!(!BitVector::ssubo(&zero, &seven));
+        assert!(BitVector::usubo(&one, &seven));
+        assert!(!BitVector::usubo(&seven, &seven));
+
+        assert!(!BitVector::smulo(&seven, &one));
+        assert!(BitVector::smulo(&min, &neg_one));
+        assert!(!BitVector::umulo(&one, &neg_one));
+        assert!(BitVector::umulo(&seven, &min));
+
+        assert!(BitVector::sdivo(&min, &neg_one));
+        assert!(!BitVector::sdivo(&min, &one));
+        assert!(!BitVector::sdivo(&seven, &zero));
+    }
 }
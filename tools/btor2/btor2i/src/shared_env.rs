@@ -1,3 +1,4 @@
+use crate::bvec::BitVector;
 use num_integer::Integer;
 use num_traits::{One, Zero};
 use std::cmp::Ordering;
@@ -331,9 +332,13 @@ impl SharedEnvironment {
     }
 
     fn compare_unsigned(&self, i1: usize, i2: usize) -> Ordering {
-        let a = &self.shared_bits[self.offsets[i1]..self.offsets[i1 + 1]];
-        let b = &self.shared_bits[self.offsets[i2]..self.offsets[i2 + 1]];
-        a.cmp(b)
+        let a = Self::slice_to_biguint(
+            &self.shared_bits[self.offsets[i1]..self.offsets[i1 + 1]],
+        );
+        let b = Self::slice_to_biguint(
+            &self.shared_bits[self.offsets[i2]..self.offsets[i2 + 1]],
+        );
+        a.cmp(&b)
     }
 
     pub fn sgt(&mut self, i1: usize, i2: usize, i3: usize) {
@@ -523,36 +528,88 @@ impl SharedEnvironment {
         }
     }
 
-    pub fn saddo(&mut self, _i1: usize, _i2: usize, _i3: usize) {
-        todo!()
+    pub fn saddo(&mut self, i1: usize, i2: usize, i3: usize) {
+        let a = Self::slice_to_bigint(
+            &self.shared_bits[self.offsets[i1]..self.offsets[i1 + 1]],
+        );
+        let b = Self::slice_to_bigint(
+            &self.shared_bits[self.offsets[i2]..self.offsets[i2 + 1]],
+        );
+        let width = self.offsets[i1 + 1] - self.offsets[i1];
+        let ans = !BitVector::fits_signed(&(a + b), width);
+        self.shared_bits[self.offsets[i3]..self.offsets[i3] + 1].fill(ans);
     }
 
-    pub fn uaddo(&mut self, _i1: usize, _i2: usize, _i3: usize) {
-        todo!()
+    pub fn uaddo(&mut self, i1: usize, i2: usize, i3: usize) {
+        let a = Self::slice_to_biguint(
+            &self.shared_bits[self.offsets[i1]..self.offsets[i1 + 1]],
+        );
+        let b = Self::slice_to_biguint(
+            &self.shared_bits[self.offsets[i2]..self.offsets[i2 + 1]],
+        );
+        let width = self.offsets[i1 + 1] - self.offsets[i1];
+        let ans = !BitVector::fits_unsigned(&(a + b), width);
+        self.shared_bits[self.offsets[i3]..self.offsets[i3] + 1].fill(ans);
     }
 
-    pub fn sdivo(&mut self, _i1: usize, _i2: usize, _i3: usize) {
-        todo!()
+    /// Signed division only overflows when dividing the minimum value by -1.
+    /// Division by zero is not considered an overflow.
+    pub fn sdivo(&mut self, i1: usize, i2: usize, i3: usize) {
+        let a = Self::slice_to_bigint(
+            &self.shared_bits[self.offsets[i1]..self.offsets[i1 + 1]],
+        );
+        let b = Self::slice_to_bigint(
+            &self.shared_bits[self.offsets[i2]..self.offsets[i2 + 1]],
+        );
+        let width = self.offsets[i1 + 1] - self.offsets[i1];
+        let ans = !b.is_zero() && !BitVector::fits_signed(&(a / b), width);
+        self.shared_bits[self.offsets[i3]..self.offsets[i3] + 1].fill(ans);
     }
 
     pub fn udivo(&mut self, _i1: usize, _i2: usize, i3: usize) {
         self.shared_bits[self.offsets[i3]..self.offsets[i3] + 1].fill(false);
     }
 
-    pub fn smulo(&mut self, _i1: usize, _i2: usize, _i3: usize) {
-        todo!()
+    pub fn smulo(&mut self, i1: usize, i2: usize, i3: usize) {
+        let a = Self::slice_to_bigint(
+            &self.shared_bits[self.offsets[i1]..self.offsets[i1 + 1]],
+        );
+        let b = Self::slice_to_bigint(
+            &self.shared_bits[self.offsets[i2]..self.offsets[i2 + 1]],
+        );
+        let width = self.offsets[i1 + 1] - self.offsets[i1];
+        let ans = !BitVector::fits_signed(&(a * b), width);
+        self.shared_bits[self.offsets[i3]..self.offsets[i3] + 1].fill(ans);
     }
 
-    pub fn umulo(&mut self, _i1: usize, _i2: usize, _i3: usize) {
-        todo!()
+    pub fn umulo(&mut self, i1: usize, i2: usize, i3: usize) {
+        let a = Self::slice_to_biguint(
+            &self.shared_bits[self.offsets[i1]..self.offsets[i1 + 1]],
+        );
+        let b = Self::slice_to_biguint(
+            &self.shared_bits[self.offsets[i2]..self.offsets[i2 + 1]],
+        );
+        let width = self.offsets[i1 + 1] - self.offsets[i1];
+        let ans = !BitVector::fits_unsigned(&(a * b), width);
+        self.shared_bits[self.offsets[i3]..self.offsets[i3] + 1].fill(ans);
     }
 
-    pub fn ssubo(&mut self, _i1: usize, _i2: usize, _i3: usize) {
-        todo!()
+    pub fn ssubo(&mut self, i1: usize, i2: usize, i3: usize) {
+        let a = Self::slice_to_bigint(
+            &self.shared_bits[self.offsets[i1]..self.offsets[i1 + 1]],
+        );
+        let b = Self::slice_to_bigint(
+            &self.shared_bits[self.offsets[i2]..self.offsets[i2 + 1]],
+        );
+        let width = self.offsets[i1 + 1] - self.offsets[i1];
+        let ans = !BitVector::fits_signed(&(a - b), width);
+        self.shared_bits[self.offsets[i3]..self.offsets[i3] + 1].fill(ans);
     }
 
-    pub fn usubo(&mut self, _i1: usize, _i2: usize, _i3: usize) {
-        todo!()
+    /// Unsigned subtraction overflows when the result would be negative
+    pub fn usubo(&mut self, i1: usize, i2: usize, i3: usize) {
+        let ans = self.compare_unsigned(i1, i2) == Ordering::Less;
+        self.shared_bits[self.offsets[i3]..self.offsets[i3] + 1].fill(ans);
     }
 
     pub fn ite(&mut self, i1: usize, i2: usize, i3: usize, i4: usize) {
@@ -743,4 +800,38 @@ mod tests {
         assert!(s_env.get(3) == bits![0]);
         assert!(s_env.get(4) == bits![1]);
     }
+
+    #[test]
+    fn test_overflow() {
+        let node_widths = vec![4, 4, 4, 1, 1, 1, 1, 1, 1, 1];
+        let mut s_env = SharedEnvironment::new(node_widths);
+        // 7 and 1 as well as -8 (8 unsigned)
+        s_env.set(1, bits![1, 1, 1, 0]);
+        s_env.set(2, bits![1, 0, 0, 0]);
+        s_env.set(3, bits![0, 0, 0, 1]);
+
+        s_env.saddo(1, 2, 4);
+        s_env.uaddo(1, 2, 5);
+        s_env.ssubo(3, 2, 6);
+        s_env.usubo(2, 1, 7);
+        s_env.smulo(1, 2, 8);
+        s_env.umulo(1, 3, 9);
+        s_env.sdivo(3, 2, 10);
+        assert!(s_env.get(4) == bits![1]);
+        assert!(s_env.get(5) == bits![0]);
+        assert!(s_env.get(6) == bits![1]);
+        assert!(s_env.get(7) == bits![1]);
+        assert!(s_env.get(8) == bits![0]);
+        assert!(s_env.get(9) == bits![1]);
+        assert!(s_env.get(10) == bits![0]);
+
+        // -8 / -1 is the only overflowing signed division
+        s_env.set(2, bits![1, 1, 1, 1]);
+        s_env.sdivo(3, 2, 10);
+        s_env.smulo(3, 2, 8);
+        s_env.uaddo(1, 2, 5);
+        assert!(s_env.get(10) == bits![1]);
+        assert!(s_env.get(8) == bits![1]);
+        assert!(s_env.get(5) == bits![1]);
+    }
 }
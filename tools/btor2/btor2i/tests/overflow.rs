@@ -0,0 +1,103 @@
+use btor2i::{interp, shared_env::SharedEnvironment};
+use btor2tools::{Btor2Parser, Btor2SortContent, Btor2Tag};
+use std::collections::HashMap;
+use std::path::Path;
+
+const OVERFLOW: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../core/overflow.btor");
+
+/// Runs `core/overflow.btor` and returns the value of each output. Inputs are
+/// binary strings with the least significant bit first.
+fn run(left: &str, right: &str) -> HashMap<String, usize> {
+    let mut parser = Btor2Parser::new();
+    let lines = parser
+        .read_lines(Path::new(OVERFLOW))
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    let node_sorts = lines
+        .iter()
+        .map(|line| match line.tag() {
+            Btor2Tag::Sort | Btor2Tag::Output => 0,
+            _ => match line.sort().content() {
+                Btor2SortContent::Bitvec { width } => {
+                    usize::try_from(width).unwrap()
+                }
+                Btor2SortContent::Array { .. } => 0,
+            },
+        })
+        .collect::<Vec<_>>();
+    let mut env = SharedEnvironment::new(node_sorts);
+
+    let inputs = [format!("left=0b{}", left), format!("right=0b{}", right)];
+    interp::parse_inputs(&mut env, &lines, &inputs).unwrap();
+    interp::interpret(lines.iter(), &mut env).unwrap();
+
+    lines
+        .iter()
+        .filter(|line| matches!(line.tag(), Btor2Tag::Output))
+        .map(|line| {
+            let name = line.symbol().unwrap().to_string_lossy().into_owned();
+            // every output of the program is a single bit
+            (name, usize::from(env.get(line.args()[0] as usize)[0]))
+        })
+        .collect()
+}
+
+#[test]
+fn add_overflow() {
+    // 7 + 1
+    let out = run("1110", "1000");
+    assert_eq!(out["saddo"], 1);
+    assert_eq!(out["uaddo"], 0);
+
+    // 15 + 1 (-1 + 1)
+    let out = run("1111", "1000");
+    assert_eq!(out["saddo"], 0);
+    assert_eq!(out["uaddo"], 1);
+}
+
+#[test]
+fn sub_overflow() {
+    // -8 - 1
+    let out = run("0001", "1000");
+    assert_eq!(out["ssubo"], 1);
+    assert_eq!(out["usubo"], 0);
+
+    // 1 - 2
+    let out = run("1000", "0100");
+    assert_eq!(out["ssubo"], 0);
+    assert_eq!(out["usubo"], 1);
+}
+
+#[test]
+fn sub_overflow_compares_values() {
+    // the low bits of these operands order differently from their values
+    // 2 - 1
+    assert_eq!(run("0100", "1000")["usubo"], 0);
+    // 6 - 9
+    assert_eq!(run("0110", "1001")["usubo"], 1);
+}
+
+#[test]
+fn mul_overflow() {
+    // 3 * 3
+    let out = run("1100", "1100");
+    assert_eq!(out["smulo"], 1);
+    assert_eq!(out["umulo"], 0);
+
+    // 15 * 2 (-1 * 2)
+    let out = run("1111", "0100");
+    assert_eq!(out["smulo"], 0);
+    assert_eq!(out["umulo"], 1);
+}
+
+#[test]
+fn div_overflow() {
+    // -8 / -1
+    assert_eq!(run("0001", "1111")["sdivo"], 1);
+    // -8 / 1
+    assert_eq!(run("0001", "1000")["sdivo"], 0);
+    // division by zero
+    assert_eq!(run("0001", "0000")["sdivo"], 0);
+}
@@ -1,14 +1,234 @@
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
+use thiserror::Error;
 
 use super::json_data::*;
 use interp::serialization::data_dump::*;
 
-pub fn convert_to_data_dump(json: &JsonData) -> DataDump {
+#[derive(Debug, Error)]
+pub enum ConversionError {
+    #[error(
+        "memory \"{memory}\" is a fixed-point memory but its format specifies neither a valid \"frac_width\" nor \"int_width\""
+    )]
+    MissingFracWidth { memory: String },
+
+    #[error(
+        "memory \"{memory}\" has width {width} but fixed-point values must be between 1 and 64 bits wide"
+    )]
+    UnsupportedWidth { memory: String, width: u64 },
+
+    #[error(
+        "memory \"{memory}\" is a bitnum memory but contains non-integer value(s)"
+    )]
+    NonIntegerBitnum { memory: String },
+
+    #[error(
+        "value {value} in memory \"{memory}\" cannot be represented as a{} {width}-bit fixed-point number with {frac_width} fractional bits",
+        if *.is_signed { " signed" } else { "n unsigned" }
+    )]
+    UnrepresentableValue {
+        memory: String,
+        value: f64,
+        width: u64,
+        frac_width: u64,
+        is_signed: bool,
+    },
+}
+
+/// How fixed-point values which fall between two representable numbers are
+/// rounded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to the closest representable value, breaking ties towards the
+    /// value with an even least-significant bit
+    #[default]
+    Nearest,
+    /// Round towards zero
+    Truncate,
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceil,
+}
+
+impl RoundingMode {
+    fn round(&self, value: f64) -> f64 {
+        match self {
+            RoundingMode::Nearest => {
+                let floor = value.floor();
+                let diff = value - floor;
+                if diff > 0.5 || (diff == 0.5 && floor % 2.0 != 0.0) {
+                    floor + 1.0
+                } else {
+                    floor
+                }
+            }
+            RoundingMode::Truncate => value.trunc(),
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceil => value.ceil(),
+        }
+    }
+}
+
+impl FromStr for RoundingMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "nearest" => Ok(RoundingMode::Nearest),
+            "truncate" | "zero" => Ok(RoundingMode::Truncate),
+            "floor" | "down" => Ok(RoundingMode::Floor),
+            "ceil" | "up" => Ok(RoundingMode::Ceil),
+            _ => Err(format!(
+                "unknown rounding mode \"{s}\", expected one of \"nearest\", \"truncate\", \"floor\", or \"ceil\""
+            )),
+        }
+    }
+}
+
+/// The layout of the fixed-point numbers in a single memory
+#[derive(Debug, Clone, Copy)]
+struct FixedPointFormat {
+    width: u64,
+    frac_width: u64,
+    is_signed: bool,
+}
+
+impl FixedPointFormat {
+    fn from_format_info(
+        format: &FormatInfo,
+        memory: &str,
+    ) -> Result<Self, ConversionError> {
+        if format.width == 0 || format.width > 64 {
+            return Err(ConversionError::UnsupportedWidth {
+                memory: memory.to_string(),
+                width: format.width,
+            });
+        }
+
+        match format.frac_width() {
+            Some(frac_width) if frac_width <= format.width => Ok(Self {
+                width: format.width,
+                frac_width,
+                is_signed: format.is_signed,
+            }),
+            _ => Err(ConversionError::MissingFracWidth {
+                memory: memory.to_string(),
+            }),
+        }
+    }
+
+    /// Returns the bit pattern of the given value in this format. Values
+    /// which are not finite or lie outside of the representable range after
+    /// rounding produce an error.
+    fn encode(
+        &self,
+        value: f64,
+        rounding: RoundingMode,
+        memory: &str,
+    ) -> Result<u64, ConversionError> {
+        let err = || ConversionError::UnrepresentableValue {
+            memory: memory.to_string(),
+            value,
+            width: self.width,
+            frac_width: self.frac_width,
+            is_signed: self.is_signed,
+        };
+
+        // scaling by a power of two is exact so the only imprecision comes
+        // from the rounding
+        let scaled = rounding.round(value * 2_f64.powi(self.frac_width as i32));
+        if !scaled.is_finite() {
+            return Err(err());
+        }
+
+        let (min, max) = if self.is_signed {
+            (
+                -(1_i128 << (self.width - 1)),
+                (1_i128 << (self.width - 1)) - 1,
+            )
+        } else {
+            (0, (1_i128 << self.width) - 1)
+        };
+
+        // values outside the i128 range saturate and are caught by the bounds
+        // check below
+        let scaled = scaled as i128;
+        if scaled < min || scaled > max {
+            return Err(err());
+        }
+
+        Ok((scaled as u128 & ((1_u128 << self.width) - 1)) as u64)
+    }
+
+    fn decode(&self, bits: u64) -> f64 {
+        let value = if self.is_signed && self.width < 64 {
+            // sign extend
+            let shift = 64 - self.width;
+            (((bits << shift) as i64) >> shift) as f64
+        } else if self.is_signed {
+            bits as i64 as f64
+        } else {
+            bits as f64
+        };
+
+        value / 2_f64.powi(self.frac_width as i32)
+    }
+}
+
+/// Flattens a data vector into a single stream of values in row-major order
+fn flat_values(data: &DataVec) -> Box<dyn Iterator<Item = f64> + '_> {
+    match data {
+        DataVec::Id1(v1) => Box::new(v1.iter().map(|x| *x as f64)),
+        DataVec::Id2(v1) => Box::new(v1.iter().flatten().map(|x| *x as f64)),
+        DataVec::Id3(v1) => {
+            Box::new(v1.iter().flatten().flatten().map(|x| *x as f64))
+        }
+        DataVec::Id4(v1) => {
+            Box::new(v1.iter().flatten().flatten().flatten().map(|x| *x as f64))
+        }
+        DataVec::Fd1(v1) => Box::new(v1.iter().copied()),
+        DataVec::Fd2(v1) => Box::new(v1.iter().flatten().copied()),
+        DataVec::Fd3(v1) => Box::new(v1.iter().flatten().flatten().copied()),
+        DataVec::Fd4(v1) => {
+            Box::new(v1.iter().flatten().flatten().flatten().copied())
+        }
+    }
+}
+
+pub fn convert_to_data_dump(
+    json: &JsonData,
+    rounding: RoundingMode,
+) -> Result<DataDump, ConversionError> {
     let mut data_dump = DataDump::new_empty();
 
     for (name, entry) in json.0.iter() {
         let width = &entry.format.width;
+
+        if entry.format.numeric_type == NumericType::Fixed {
+            let format =
+                FixedPointFormat::from_format_info(&entry.format, name)?;
+            let data: Vec<u8> = flat_values(&entry.data)
+                .map(|val| {
+                    format.encode(val, rounding, name).map(|bits| {
+                        bits.to_le_bytes()
+                            .into_iter()
+                            .take(width.div_ceil(8) as usize)
+                    })
+                })
+                .flatten_ok()
+                .collect::<Result<_, _>>()?;
+
+            data_dump.push_memory(
+                name.clone(),
+                *width as usize,
+                entry.data.size(),
+                entry.data.dimensions(),
+                data,
+            );
+            continue;
+        }
+
         let data: Box<dyn Iterator<Item = u8>> = match &entry.data {
             DataVec::Id1(v1) => Box::new(v1.iter().flat_map(|val| {
                 // chopping off the upper bits
@@ -43,10 +263,14 @@ pub fn convert_to_data_dump(json: &JsonData) -> DataDump {
                     })
                 })
             })),
-            DataVec::Fd1(_) => todo!("implement fixed-point"),
-            DataVec::Fd2(_) => todo!("implement fixed-point"),
-            DataVec::Fd3(_) => todo!("implement fixed-point"),
-            DataVec::Fd4(_) => todo!("implement fixed-point"),
+            DataVec::Fd1(_)
+            | DataVec::Fd2(_)
+            | DataVec::Fd3(_)
+            | DataVec::Fd4(_) => {
+                return Err(ConversionError::NonIntegerBitnum {
+                    memory: name.clone(),
+                })
+            }
         };
 
         data_dump.push_memory(
@@ -58,28 +282,38 @@ pub fn convert_to_data_dump(json: &JsonData) -> DataDump {
         )
     }
 
-    data_dump
+    Ok(data_dump)
 }
 
-fn format_data(dimension: &Dimensions, data: &[u8], width: usize) -> DataVec {
+fn read_values(data: &[u8], width: usize) -> impl Iterator<Item = u64> + '_ {
     assert!(width.div_ceil(8) <= 8, "cannot fit in u64");
-    let u64_stream = data.chunks_exact(width.div_ceil(8)).map(|chunk| {
+    // sanity check
+    assert!(data.len() % width.div_ceil(8) == 0);
+
+    data.chunks_exact(width.div_ceil(8)).map(|chunk| {
         let mut array = [0u8; 8];
         array[0..chunk.len()].copy_from_slice(chunk);
         u64::from_le_bytes(array)
-    });
-    // sanity check
-    assert!(data.len() % width.div_ceil(8) == 0);
+    })
+}
 
+fn format_data<T, I>(dimension: &Dimensions, stream: I) -> DataVec
+where
+    I: Iterator<Item = T>,
+    Vec<T>: Into<DataVec>,
+    Vec<Vec<T>>: Into<DataVec>,
+    Vec<Vec<Vec<T>>>: Into<DataVec>,
+    Vec<Vec<Vec<Vec<T>>>>: Into<DataVec>,
+{
     match dimension {
-        Dimensions::D1(_) => u64_stream.collect_vec().into(),
-        Dimensions::D2(_d0, d1) => u64_stream
+        Dimensions::D1(_) => stream.collect_vec().into(),
+        Dimensions::D2(_d0, d1) => stream
             .chunks(*d1)
             .into_iter()
             .map(|v| v.collect_vec())
             .collect_vec()
             .into(),
-        Dimensions::D3(_d0, d1, d2) => u64_stream
+        Dimensions::D3(_d0, d1, d2) => stream
             .chunks(d1 * d2)
             .into_iter()
             .map(|v1| {
@@ -90,7 +324,7 @@ fn format_data(dimension: &Dimensions, data: &[u8], width: usize) -> DataVec {
             })
             .collect_vec()
             .into(),
-        Dimensions::D4(_d0, d1, d2, d3) => u64_stream
+        Dimensions::D4(_d0, d1, d2, d3) => stream
             .chunks(d1 * d2 * d3)
             .into_iter()
             .map(|v1| {
@@ -109,20 +343,38 @@ fn format_data(dimension: &Dimensions, data: &[u8], width: usize) -> DataVec {
     }
 }
 
-pub fn convert_from_data_dump(dump: &DataDump) -> JsonPrintDump {
+/// Converts a data dump back into the json printing format. Memories are
+/// printed as unsigned integers unless `formats` provides a fixed-point format
+/// for them, typically by passing in the json data the dump was created from.
+pub fn convert_from_data_dump(
+    dump: &DataDump,
+    formats: Option<&JsonData>,
+) -> Result<JsonPrintDump, ConversionError> {
     let mut map = HashMap::new();
     for declaration in &dump.header.memories {
         let data = dump.get_data(&declaration.name).unwrap();
-        let formatted_data = format_data(
-            &declaration.dimensions,
-            data,
-            declaration.width.into(),
-        );
+        let values = read_values(data, declaration.width.into());
+
+        let format = formats
+            .and_then(|json| json.0.get(&declaration.name))
+            .map(|entry| &entry.format)
+            .filter(|format| format.numeric_type == NumericType::Fixed);
+
+        let formatted_data = if let Some(format) = format {
+            let format =
+                FixedPointFormat::from_format_info(format, &declaration.name)?;
+            format_data(
+                &declaration.dimensions,
+                values.map(|bits| format.decode(bits)),
+            )
+        } else {
+            format_data(&declaration.dimensions, values)
+        };
 
         map.insert(declaration.name.clone(), formatted_data);
     }
 
-    JsonPrintDump(map)
+    Ok(JsonPrintDump(map))
 }
 
 #[cfg(test)]
@@ -137,6 +389,7 @@ mod tests {
                 is_signed: false,
                 numeric_type: NumericType::Bitnum,
                 int_width: None,
+                frac_width: None,
             }
         }
     }
@@ -187,14 +440,134 @@ mod tests {
         fn test_json_roundtrip(map in prop::collection::hash_map(any::<String>(), arb_json_entry(), 1..4)) {
             let json_data = JsonData(map);
 
-            let dump = convert_to_data_dump(&json_data);
+            let dump = convert_to_data_dump(&json_data, RoundingMode::default()).unwrap();
+
+            let json_print_dump = convert_from_data_dump(&dump, None).unwrap();
+
+            for (name, entry) in &json_data.0 {
+                prop_assert_eq!(&entry.data, json_print_dump.0.get(name).unwrap())
+            }
+        }
+
+        #[test]
+        fn test_fixed_point_roundtrip(map in prop::collection::hash_map(any::<String>(), arb_fixed_point_entry(), 1..4)) {
+            let json_data = JsonData(map);
+
+            let dump = convert_to_data_dump(&json_data, RoundingMode::default()).unwrap();
 
-            let json_print_dump = convert_from_data_dump(&dump);
+            let json_print_dump = convert_from_data_dump(&dump, Some(&json_data)).unwrap();
 
             for (name, entry) in &json_data.0 {
                 prop_assert_eq!(&entry.data, json_print_dump.0.get(name).unwrap())
             }
         }
+    }
+
+    fn arb_fixed_point_entry() -> impl Strategy<Value = JsonDataEntry> {
+        // restricted to widths where every value is exactly representable as
+        // a float
+        (1_u64..=52, any::<bool>(), dim_generator()).prop_flat_map(
+            |(width, is_signed, dimensions)| {
+                (
+                    0..=width,
+                    prop::collection::vec(
+                        0u64..=max_val(width),
+                        dimensions.size(),
+                    ),
+                )
+                    .prop_map(move |(frac_width, bits)| {
+                        let format = FixedPointFormat {
+                            width,
+                            frac_width,
+                            is_signed,
+                        };
+                        JsonDataEntry {
+                            data: format_data(
+                                &dimensions,
+                                bits.into_iter().map(|x| format.decode(x)),
+                            ),
+                            format: FormatInfo {
+                                numeric_type: NumericType::Fixed,
+                                is_signed,
+                                width,
+                                int_width: None,
+                                frac_width: Some(frac_width),
+                            },
+                        }
+                    })
+            },
+        )
+    }
+
+    fn fixed_format(
+        width: u64,
+        frac_width: u64,
+        is_signed: bool,
+    ) -> FormatInfo {
+        FormatInfo {
+            numeric_type: NumericType::Fixed,
+            is_signed,
+            width,
+            int_width: Some(width - frac_width),
+            frac_width: None,
+        }
+    }
+
+    #[test]
+    fn test_fixed_point_rounding() {
+        let format = FixedPointFormat::from_format_info(
+            &fixed_format(8, 2, true),
+            "mem",
+        )
+        .unwrap();
+
+        let encode = |val, rounding| {
+            format.decode(format.encode(val, rounding, "mem").unwrap())
+        };
+
+        assert_eq!(encode(0.625, RoundingMode::Nearest), 0.5);
+        assert_eq!(encode(0.875, RoundingMode::Nearest), 1.0);
+        assert_eq!(encode(0.6, RoundingMode::Nearest), 0.5);
+        assert_eq!(encode(-0.6, RoundingMode::Truncate), -0.5);
+        assert_eq!(encode(-0.6, RoundingMode::Floor), -0.75);
+        assert_eq!(encode(0.6, RoundingMode::Ceil), 0.75);
+    }
+
+    #[test]
+    fn test_fixed_point_zero_width() {
+        let err = FixedPointFormat::from_format_info(
+            &fixed_format(0, 0, true),
+            "mem",
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ConversionError::UnsupportedWidth { width: 0, .. }
+        ));
+    }
+
+    #[test]
+    fn test_fixed_point_out_of_range() {
+        let data = r#"
+{
+  "mem": {
+    "data": [1.5, 300.25],
+    "format": {
+      "numeric_type": "fixed_point",
+      "is_signed": true,
+      "width": 8,
+      "frac_width": 2
+    }
+  }
+}"#;
+        let json_data: JsonData = serde_json::from_str(data).unwrap();
+        let err = convert_to_data_dump(&json_data, RoundingMode::default())
+            .unwrap_err();
 
+        assert!(matches!(
+            err,
+            ConversionError::UnrepresentableValue { value, .. } if value == 300.25
+        ));
     }
 }
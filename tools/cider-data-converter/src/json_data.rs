@@ -3,10 +3,11 @@ use std::collections::HashMap;
 use interp::serialization::data_dump::Dimensions;
 use serde::{self, Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum NumericType {
     Bitnum,
+    #[serde(rename = "fixed_point", alias = "fixed")]
     Fixed,
 }
 
@@ -18,6 +19,22 @@ pub struct FormatInfo {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub int_width: Option<u64>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frac_width: Option<u64>,
+}
+
+impl FormatInfo {
+    /// Returns the number of fractional bits for a fixed-point format. This
+    /// may be given either directly or implied by the integer width. Returns
+    /// `None` if neither is present or the integer width is larger than the
+    /// total width.
+    pub fn frac_width(&self) -> Option<u64> {
+        self.frac_width.or_else(|| {
+            self.int_width
+                .and_then(|int_width| self.width.checked_sub(int_width))
+        })
+    }
 }
 
 // this is stupid
@@ -88,46 +105,15 @@ impl DataVec {
     /// Returns the number of elements in the memory. Will panic if the vectors
     /// do not all have the same length within a given dimension.
     pub fn size(&self) -> usize {
-        // TODO griffin: make the variable names more reasonable
         match self {
             DataVec::Id1(v1) => v1.len(),
-            DataVec::Id2(v1) => {
-                let v0_size = v1[0].len();
-
-                // Check that sizes are the same across each dimension
-                assert!(v1.iter().all(|v2| v2.len() == v0_size));
-                v1.len() * v0_size
-            }
-            DataVec::Id3(v1) => {
-                let v1_0_size = v1[0].len();
-                let v1_0_0_size = v1[0][0].len();
-
-                // Check that sizes are the same across each dimension
-                assert!(v1.iter().all(|v2| { v2.len() == v1_0_size }));
-                assert!(v1
-                    .iter()
-                    .all(|v2| v2.iter().all(|v3| v3.len() == v1_0_0_size)));
-                v1.len() * v1_0_size * v1_0_0_size
-            }
-            DataVec::Id4(v1) => {
-                let v1_0_size = v1[0].len();
-                let v1_0_0_size = v1[0][0].len();
-                let v1_0_0_0_size = v1[0][0][0].len();
-                // Check that sizes are the same across each dimension
-                assert!(v1.iter().all(|v2| { v2.len() == v1_0_size }));
-                assert!(v1
-                    .iter()
-                    .all(|v2| { v2.iter().all(|v3| v3.len() == v1_0_0_size) }));
-                assert!(v1.iter().all(|v2| v2
-                    .iter()
-                    .all(|v3| v3.iter().all(|v4| v4.len() == v1_0_0_0_size))));
-
-                v1.len() * v1_0_size * v1_0_0_size * v1_0_0_0_size
-            }
-            DataVec::Fd1(_) => todo!("implement fixed-point"),
-            DataVec::Fd2(_) => todo!("implement fixed-point"),
-            DataVec::Fd3(_) => todo!("implement fixed-point"),
-            DataVec::Fd4(_) => todo!("implement fixed-point"),
+            DataVec::Id2(v1) => size_2d(v1),
+            DataVec::Id3(v1) => size_3d(v1),
+            DataVec::Id4(v1) => size_4d(v1),
+            DataVec::Fd1(v1) => v1.len(),
+            DataVec::Fd2(v1) => size_2d(v1),
+            DataVec::Fd3(v1) => size_3d(v1),
+            DataVec::Fd4(v1) => size_4d(v1),
         }
     }
 
@@ -159,6 +145,43 @@ impl DataVec {
     }
 }
 
+// TODO griffin: make the variable names more reasonable
+fn size_2d<T>(v1: &[Vec<T>]) -> usize {
+    let v0_size = v1[0].len();
+
+    // Check that sizes are the same across each dimension
+    assert!(v1.iter().all(|v2| v2.len() == v0_size));
+    v1.len() * v0_size
+}
+
+fn size_3d<T>(v1: &[Vec<Vec<T>>]) -> usize {
+    let v1_0_size = v1[0].len();
+    let v1_0_0_size = v1[0][0].len();
+
+    // Check that sizes are the same across each dimension
+    assert!(v1.iter().all(|v2| { v2.len() == v1_0_size }));
+    assert!(v1
+        .iter()
+        .all(|v2| v2.iter().all(|v3| v3.len() == v1_0_0_size)));
+    v1.len() * v1_0_size * v1_0_0_size
+}
+
+fn size_4d<T>(v1: &[Vec<Vec<Vec<T>>>]) -> usize {
+    let v1_0_size = v1[0].len();
+    let v1_0_0_size = v1[0][0].len();
+    let v1_0_0_0_size = v1[0][0][0].len();
+    // Check that sizes are the same across each dimension
+    assert!(v1.iter().all(|v2| { v2.len() == v1_0_size }));
+    assert!(v1
+        .iter()
+        .all(|v2| { v2.iter().all(|v3| v3.len() == v1_0_0_size) }));
+    assert!(v1.iter().all(|v2| v2
+        .iter()
+        .all(|v3| v3.iter().all(|v4| v4.len() == v1_0_0_0_size))));
+
+    v1.len() * v1_0_size * v1_0_0_size * v1_0_0_0_size
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JsonDataEntry {
     pub data: DataVec,
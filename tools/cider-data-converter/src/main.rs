@@ -1,5 +1,8 @@
 use argh::FromArgs;
-use cider_data_converter::{converter, json_data::JsonData};
+use cider_data_converter::{
+    converter::{self, ConversionError, RoundingMode},
+    json_data::JsonData,
+};
 use interp::serialization::data_dump::{self, SerializationError};
 use std::{
     fs::File,
@@ -28,6 +31,9 @@ enum CiderDataConverterError {
 
     #[error(transparent)]
     DataDumpError(#[from] SerializationError),
+
+    #[error(transparent)]
+    ConversionError(#[from] ConversionError),
 }
 
 impl std::fmt::Debug for CiderDataConverterError {
@@ -67,6 +73,17 @@ struct Opts {
     /// "json". If not provided, the converter will try to guess based on file names
     #[argh(option, short = 't', long = "to")]
     action: Option<Action>,
+
+    /// how to round fixed-point values which cannot be represented exactly.
+    /// Can be "nearest" (the default), "truncate", "floor", or "ceil"
+    #[argh(option, long = "round", default = "RoundingMode::default()")]
+    rounding: RoundingMode,
+
+    /// the json data file used to produce the dump. When converting to json,
+    /// this provides the format of each memory so that fixed-point values are
+    /// printed as numbers rather than their raw bits
+    #[argh(option, long = "format")]
+    format_path: Option<PathBuf>,
 }
 
 fn main() -> Result<(), CiderDataConverterError> {
@@ -108,12 +125,22 @@ fn main() -> Result<(), CiderDataConverterError> {
             Action::ToDataDump => {
                 let parsed_json: JsonData =
                     serde_json::from_reader(&mut input)?;
-                converter::convert_to_data_dump(&parsed_json)
+                converter::convert_to_data_dump(&parsed_json, opts.rounding)?
                     .serialize(&mut output)?;
             }
             Action::ToJson => {
                 let data_dump = data_dump::DataDump::deserialize(&mut input)?;
-                let json_data = converter::convert_from_data_dump(&data_dump);
+                let formats: Option<JsonData> = opts
+                    .format_path
+                    .as_ref()
+                    .map(|path| -> Result<_, CiderDataConverterError> {
+                        Ok(serde_json::from_reader(File::open(path)?)?)
+                    })
+                    .transpose()?;
+                let json_data = converter::convert_from_data_dump(
+                    &data_dump,
+                    formats.as_ref(),
+                )?;
                 writeln!(
                     &mut output,
                     "{}",
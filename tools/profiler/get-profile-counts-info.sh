@@ -12,10 +12,11 @@ TMP_DIR=${SCRIPT_DIR}/tmp
 TMP_VERILOG=${TMP_DIR}/no-opt-verilog.sv
 FSM_JSON=${TMP_DIR}/fsm.json
 VCD_FILE=${TMP_DIR}/trace-info.vcd
+FOLDED_FILE=${TMP_DIR}/flame.folded
 LOGS_DIR=${SCRIPT_DIR}/logs
 mkdir -p ${TMP_DIR} ${LOGS_DIR}
 
-rm -f ${TMP_VERILOG} ${FSM_JSON}
+rm -f ${TMP_VERILOG} ${FSM_JSON} ${FOLDED_FILE}
 
 INPUT_FILE=$1
 SIM_DATA_JSON=$2
@@ -33,12 +34,14 @@ echo "[${SCRIPT_NAME}] Obtaining FSM info from TDCC"
 echo "[${SCRIPT_NAME}] Obtaining VCD file via simulation"
 (
     set -o xtrace
-    fud2 ${INPUT_FILE} -o ${VCD_FILE} -s calyx.args='-p no-opt' -s sim.data=${SIM_DATA_JSON}
+    fud2 ${INPUT_FILE} ${SIM_DATA_JSON} --from calyx --from dat -o ${VCD_FILE} -s calyx.args='-p no-opt'
     set +o xtrace
 ) &> ${LOGS_DIR}/gol-vcd
 
-# Run script to get cycle level counts
+# Run the profiler to get cycle level counts and a flame graph
 echo "[${SCRIPT_NAME}] Using FSM info and VCD file to obtain cycle level counts"
 (
-    python3 ${SCRIPT_DIR}/parse-vcd.py ${VCD_FILE} ${FSM_JSON}
+    cd ${CALYX_DIR}
+    cargo run -q -p calyx-profiler -- ${FSM_JSON} ${VCD_FILE} --folded ${FOLDED_FILE}
 ) # &> ${LOGS_DIR}/gol-process
+echo "[${SCRIPT_NAME}] Wrote the flame graph in folded-stack format to ${FOLDED_FILE}"
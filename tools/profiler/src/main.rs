@@ -0,0 +1,236 @@
+//! Attributes the cycles of an RTL simulation of a Calyx program to the
+//! groups of the program, using the profiling metadata emitted by the
+//! compiler and a VCD file from the simulation.
+mod profile;
+mod vcd;
+
+use argh::FromArgs;
+use profile::{ProfilingInfo, Program};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::File,
+    io::{self, BufReader, Write},
+    path::PathBuf,
+};
+use thiserror::Error;
+use vcd::VcdReader;
+
+#[derive(Error)]
+pub enum ProfilerError {
+    #[error("Failed to read file: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Failed to read the profiling metadata: {0}")]
+    SerdeError(#[from] serde_json::Error),
+
+    #[error("Malformed VCD file: {0}")]
+    Vcd(String),
+
+    #[error("Signal `{0}` is not in the VCD file")]
+    MissingSignal(String),
+}
+
+impl std::fmt::Debug for ProfilerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[derive(FromArgs)]
+/// Print the number of cycles each group of a Calyx program runs for in a
+/// simulation, and a flame graph of the cycles in folded-stack format.
+struct Opts {
+    /// profiling metadata emitted by `-x tdcc:dump-fsm-json`
+    #[argh(positional)]
+    metadata: PathBuf,
+
+    /// VCD file from a simulation of the program
+    #[argh(positional)]
+    vcd: PathBuf,
+
+    /// component to profile (default: main)
+    #[argh(option, default = "String::from(\"main\")")]
+    component: String,
+
+    /// instance of the component in the simulation, as a path of scopes
+    /// like `main.foo0` (default: main)
+    #[argh(option, default = "String::from(\"main\")")]
+    instance: String,
+
+    /// write the flame graph to this file instead of printing it
+    #[argh(option)]
+    folded: Option<PathBuf>,
+}
+
+/// The cycles attributed to each group and stack of groups.
+#[derive(Default)]
+struct Profile<'a> {
+    cycles: u64,
+    groups: HashMap<&'a str, u64>,
+    barriers: BTreeMap<u64, u64>,
+    stacks: BTreeMap<String, u64>,
+}
+
+impl<'a> Profile<'a> {
+    /// Attribute a cycle to the groups that run in it. Groups in parallel
+    /// each get the cycle, so the stacks can add up to more than the total.
+    fn add_cycle(&mut self, prog: &'a Program, values: &[Option<u64>]) {
+        self.cycles += 1;
+        let stacks = prog.active_stacks(values);
+
+        let groups: HashSet<&str> = stacks.iter().flatten().copied().collect();
+        for group in &groups {
+            *self.groups.entry(group).or_default() += 1;
+        }
+        for (barrier, barrier_groups) in &prog.barriers {
+            if barrier_groups.iter().any(|g| groups.contains(g.as_str())) {
+                *self.barriers.entry(*barrier).or_default() += 1;
+            }
+        }
+
+        // Only the innermost groups get the cycle in the flame graph.
+        let prefixes: HashSet<&[&str]> = stacks
+            .iter()
+            .flat_map(|s| (0..s.len()).map(move |i| &s[..i]))
+            .collect();
+        let leaves: HashSet<&[&str]> = stacks
+            .iter()
+            .map(Vec::as_slice)
+            .filter(|s| !prefixes.contains(s))
+            .collect();
+        if leaves.is_empty() {
+            *self.stacks.entry(String::new()).or_default() += 1;
+        }
+        for leaf in leaves {
+            *self.stacks.entry(leaf.join(";")).or_default() += 1;
+        }
+    }
+
+    fn print_counts(&self) {
+        println!("Total cycles: {}", self.cycles);
+        let mut groups = self.groups.iter().collect::<Vec<_>>();
+        groups.sort_by(|(g1, c1), (g2, c2)| c2.cmp(c1).then(g1.cmp(g2)));
+        let barriers = self
+            .barriers
+            .iter()
+            .map(|(barrier, cycles)| (format!("barrier {barrier}"), cycles))
+            .collect::<Vec<_>>();
+        let width = groups
+            .iter()
+            .map(|(g, _)| g.len())
+            .chain(barriers.iter().map(|(b, _)| b.len()))
+            .fold("Barrier".len(), usize::max);
+        println!();
+        println!("{:<width$} {:>10}", "Group", "Cycles");
+        for (group, cycles) in groups {
+            println!("{group:<width$} {cycles:>10}");
+        }
+        if !barriers.is_empty() {
+            println!();
+            println!("{:<width$} {:>10}", "Barrier", "Cycles");
+            for (barrier, cycles) in barriers {
+                println!("{barrier:<width$} {cycles:>10}");
+            }
+        }
+    }
+
+    fn write_folded(
+        &self,
+        component: &str,
+        out: &mut dyn Write,
+    ) -> Result<(), ProfilerError> {
+        for (stack, cycles) in &self.stacks {
+            if stack.is_empty() {
+                writeln!(out, "{component} {cycles}")?;
+            } else {
+                writeln!(out, "{component};{stack} {cycles}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn main() -> Result<(), ProfilerError> {
+    let opts: Opts = argh::from_env();
+
+    let infos: Vec<ProfilingInfo> =
+        serde_json::from_reader(BufReader::new(File::open(&opts.metadata)?))?;
+    let prog = Program::new(infos, &opts.component);
+    let signals = prog.signals();
+
+    let mut reader = VcdReader::new(BufReader::new(File::open(&opts.vcd)?));
+    // Map the identifier codes of the VCD to the signals of the component.
+    let mut ids: HashMap<String, Vec<usize>> = HashMap::new();
+    let mut found = vec![false; signals.len()];
+    let instance: Vec<String> =
+        opts.instance.split('.').map(String::from).collect();
+    for var in reader.header()? {
+        let Some(pos) = (0..var.scope.len())
+            .find(|&pos| var.scope[pos..].starts_with(&instance))
+        else {
+            continue;
+        };
+        let mut path = var.scope[pos + instance.len()..].to_vec();
+        path.push(var.name);
+        let name = path.join(".");
+        if let Some(idx) = signals.iter().position(|s| *s == name) {
+            ids.entry(var.id).or_default().push(idx);
+            found[idx] = true;
+        }
+    }
+    for (signal, &found) in signals.iter().zip(&found) {
+        if found {
+            continue;
+        }
+        if [Program::CLK, Program::GO].contains(&signal.as_str()) {
+            return Err(ProfilerError::MissingSignal(format!(
+                "{}.{signal}",
+                opts.instance
+            )));
+        }
+        eprintln!(
+            "warning: signal `{}.{signal}` is not in the VCD file; \
+            the groups that depend on it are never active",
+            opts.instance
+        );
+    }
+
+    let clk = signals.iter().position(|s| s == Program::CLK).unwrap();
+    let go = signals.iter().position(|s| s == Program::GO).unwrap();
+    let mut values = vec![None; signals.len()];
+    let mut changes = Vec::new();
+    let mut profile = Profile::default();
+    loop {
+        let next = reader.changes(&mut changes)?;
+        let prev_clk = values[clk];
+        for change in &changes {
+            for idx in ids.get(&change.id).into_iter().flatten() {
+                values[*idx] = change.value;
+            }
+        }
+        // Sample the signals in the middle of each cycle, on the falling edge
+        // of the clock, while the component runs.
+        if prev_clk == Some(1)
+            && values[clk] == Some(0)
+            && values[go] == Some(1)
+        {
+            profile.add_cycle(&prog, &values);
+        }
+        if next.is_none() {
+            break;
+        }
+    }
+
+    profile.print_counts();
+    match &opts.folded {
+        Some(path) => {
+            profile.write_folded(&opts.component, &mut File::create(path)?)?
+        }
+        None => {
+            println!();
+            println!("Flame graph (folded stacks):");
+            profile.write_folded(&opts.component, &mut io::stdout())?
+        }
+    }
+    Ok(())
+}
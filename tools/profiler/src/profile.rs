@@ -0,0 +1,512 @@
+//! The control structure of a component, read from the profiling metadata
+//! emitted by the compiler, and the groups it runs in each cycle.
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+
+/// The profiling metadata emitted by `-x tdcc:dump-fsm-json`. Only the fields
+/// used by the profiler are read.
+#[derive(Deserialize)]
+pub enum ProfilingInfo {
+    Fsm(FsmInfo),
+    SingleEnable(SingleEnableInfo),
+    Par(ParInfo),
+    StaticFsm(StaticFsmInfo),
+    StaticGroup(StaticGroupInfo),
+    Barrier(BarrierInfo),
+}
+
+#[derive(Deserialize)]
+pub struct FsmInfo {
+    component: String,
+    group: String,
+    fsm: String,
+    states: Vec<FsmStateInfo>,
+}
+
+#[derive(Deserialize)]
+pub struct FsmStateInfo {
+    group: String,
+}
+
+#[derive(Deserialize)]
+pub struct SingleEnableInfo {
+    component: String,
+    group: String,
+}
+
+#[derive(Deserialize)]
+pub struct ParInfo {
+    component: String,
+    group: String,
+    children: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct StaticFsmInfo {
+    component: String,
+    registers: Vec<StaticFsmRegister>,
+    groups: Vec<StaticFsmGroup>,
+}
+
+#[derive(Deserialize)]
+pub struct StaticFsmRegister {
+    fsm: String,
+    one_hot: bool,
+    beg: u64,
+    end: u64,
+    offset: u64,
+}
+
+#[derive(Deserialize)]
+pub struct StaticFsmGroup {
+    group: String,
+    early_reset: String,
+    wrappers: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct StaticGroupInfo {
+    component: String,
+    group: String,
+    children: Vec<StaticChildInfo>,
+}
+
+#[derive(Deserialize)]
+pub struct StaticChildInfo {
+    group: String,
+    beg: u64,
+    end: u64,
+    condition: Option<(String, bool)>,
+}
+
+#[derive(Deserialize)]
+pub struct BarrierInfo {
+    component: String,
+    barrier: u64,
+    groups: Vec<String>,
+}
+
+impl ProfilingInfo {
+    fn component(&self) -> &str {
+        match self {
+            ProfilingInfo::Fsm(info) => &info.component,
+            ProfilingInfo::SingleEnable(info) => &info.component,
+            ProfilingInfo::Par(info) => &info.component,
+            ProfilingInfo::StaticFsm(info) => &info.component,
+            ProfilingInfo::StaticGroup(info) => &info.component,
+            ProfilingInfo::Barrier(info) => &info.component,
+        }
+    }
+}
+
+/// A register of a static FSM. See `calyx_ir::profiling::StaticFSMRegister`.
+struct Register {
+    signal: usize,
+    one_hot: bool,
+    beg: u64,
+    end: u64,
+    offset: u64,
+}
+
+impl Register {
+    /// The state held by the register, if the FSM is in one of its states.
+    fn state(&self, value: u64) -> Option<u64> {
+        let state = if self.one_hot {
+            if !value.is_power_of_two() {
+                return None;
+            }
+            u64::from(value.trailing_zeros())
+        } else {
+            value.checked_sub(self.offset)? + self.beg
+        };
+        (self.beg..self.end).contains(&state).then_some(state)
+    }
+}
+
+/// A child of a static group that runs in some of the states of the group.
+struct Child {
+    group: String,
+    beg: u64,
+    end: u64,
+    /// The signal that must have the given value for the child to run.
+    condition: Option<(usize, bool)>,
+}
+
+/// The control structure of a component.
+pub struct Program {
+    /// The signals that the profiler reads, relative to the instance of the
+    /// component.
+    signals: Vec<String>,
+    /// The groups that run when their `go` signal is high.
+    dynamic: Vec<(String, usize)>,
+    /// The groups that can run each group. A group runs under the first of
+    /// its parents that is active.
+    parents: HashMap<String, Vec<String>>,
+    /// The registers of each static FSM.
+    fsms: Vec<Vec<Register>>,
+    /// Static groups with their own FSM, with the dynamic group that runs
+    /// them and the FSM.
+    static_groups: HashMap<String, (String, usize)>,
+    /// The children of static groups built from static control.
+    children: HashMap<String, Vec<Child>>,
+    /// Barriers and the groups that implement them.
+    pub barriers: Vec<(u64, Vec<String>)>,
+}
+
+impl Program {
+    /// The clock signal of the component.
+    pub const CLK: &'static str = "clk";
+    /// The `go` signal of the component.
+    pub const GO: &'static str = "go";
+
+    /// The signals of the component that must be sampled to profile it.
+    pub fn signals(&self) -> &[String] {
+        &self.signals
+    }
+
+    fn signal(&mut self, name: String) -> usize {
+        match self.signals.iter().position(|s| *s == name) {
+            Some(idx) => idx,
+            None => {
+                self.signals.push(name);
+                self.signals.len() - 1
+            }
+        }
+    }
+
+    fn add_dynamic(&mut self, group: &str) {
+        if !self.dynamic.iter().any(|(g, _)| g == group) {
+            let go = self.signal(format!("{group}_go.out"));
+            self.dynamic.push((group.to_string(), go));
+        }
+    }
+
+    fn add_parent(&mut self, group: &str, parent: &str) {
+        let parents = self.parents.entry(group.to_string()).or_default();
+        if !parents.iter().any(|p| p == parent) {
+            parents.push(parent.to_string());
+        }
+    }
+
+    /// Build the control structure of `component` from profiling metadata.
+    pub fn new(infos: Vec<ProfilingInfo>, component: &str) -> Self {
+        let mut prog = Program {
+            signals: vec![Self::CLK.to_string(), Self::GO.to_string()],
+            dynamic: Vec::new(),
+            parents: HashMap::new(),
+            fsms: Vec::new(),
+            static_groups: HashMap::new(),
+            children: HashMap::new(),
+            barriers: Vec::new(),
+        };
+        for info in infos.into_iter().filter(|i| i.component() == component) {
+            match info {
+                ProfilingInfo::Fsm(fsm) => {
+                    prog.add_dynamic(&fsm.group);
+                    let end = format!("{}_END", fsm.fsm);
+                    for state in fsm.states.iter().filter(|s| s.group != end) {
+                        prog.add_dynamic(&state.group);
+                        prog.add_parent(&state.group, &fsm.group);
+                    }
+                }
+                ProfilingInfo::SingleEnable(se) => prog.add_dynamic(&se.group),
+                ProfilingInfo::Par(par) => {
+                    prog.add_dynamic(&par.group);
+                    for child in &par.children {
+                        prog.add_dynamic(child);
+                        prog.add_parent(child, &par.group);
+                    }
+                }
+                ProfilingInfo::StaticFsm(fsm) => {
+                    let registers: Vec<Register> = fsm
+                        .registers
+                        .into_iter()
+                        .map(|r| Register {
+                            signal: prog.signal(format!("{}.out", r.fsm)),
+                            one_hot: r.one_hot,
+                            beg: r.beg,
+                            end: r.end,
+                            offset: r.offset,
+                        })
+                        .collect();
+                    prog.fsms.push(registers);
+                    let idx = prog.fsms.len() - 1;
+                    for group in fsm.groups {
+                        prog.add_dynamic(&group.early_reset);
+                        for wrapper in &group.wrappers {
+                            prog.add_dynamic(wrapper);
+                            prog.add_parent(&group.early_reset, wrapper);
+                        }
+                        prog.add_parent(&group.group, &group.early_reset);
+                        prog.static_groups
+                            .insert(group.group, (group.early_reset, idx));
+                    }
+                }
+                ProfilingInfo::StaticGroup(sg) => {
+                    let children: Vec<Child> = sg
+                        .children
+                        .into_iter()
+                        .map(|c| Child {
+                            condition: c
+                                .condition
+                                .map(|(port, val)| (prog.signal(port), val)),
+                            group: c.group,
+                            beg: c.beg,
+                            end: c.end,
+                        })
+                        .collect();
+                    prog.children.insert(sg.group, children);
+                }
+                ProfilingInfo::Barrier(barrier) => {
+                    prog.barriers.push((barrier.barrier, barrier.groups))
+                }
+            }
+        }
+
+        // Static groups with their own FSM that are children of other static
+        // groups are run by their parent.
+        let nested = prog
+            .children
+            .iter()
+            .flat_map(|(parent, children)| {
+                children.iter().filter_map(|c| {
+                    prog.static_groups.get(&c.group).map(|(early_reset, _)| {
+                        (early_reset.clone(), parent.clone())
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        for (early_reset, parent) in nested {
+            prog.add_parent(&early_reset, &parent);
+        }
+        prog
+    }
+
+    /// The state of a static FSM.
+    fn state(&self, fsm: usize, values: &[Option<u64>]) -> Option<u64> {
+        self.fsms[fsm]
+            .iter()
+            .find_map(|r| values[r.signal].and_then(|v| r.state(v)))
+    }
+
+    /// The stack of groups that runs `group`.
+    fn stack<'a>(
+        &'a self,
+        group: &'a str,
+        active: &HashSet<&'a str>,
+        memo: &mut HashMap<&'a str, Vec<&'a str>>,
+        visiting: &mut HashSet<&'a str>,
+    ) -> Vec<&'a str> {
+        if let Some(stack) = memo.get(group) {
+            return stack.clone();
+        }
+        visiting.insert(group);
+        let parent = self.parents.get(group).and_then(|parents| {
+            parents
+                .iter()
+                .map(String::as_str)
+                .find(|p| active.contains(p) && !visiting.contains(p))
+        });
+        let mut stack = match parent {
+            Some(parent) => self.stack(parent, active, memo, visiting),
+            None => Vec::new(),
+        };
+        visiting.remove(group);
+        stack.push(group);
+        memo.insert(group, stack.clone());
+        stack
+    }
+
+    /// Add the stacks of the children of the static group at the top of
+    /// `stack`, which is in state `state`.
+    fn static_stacks<'a>(
+        &'a self,
+        stack: &[&'a str],
+        state: u64,
+        values: &[Option<u64>],
+        active: &HashSet<&str>,
+        stacks: &mut Vec<Vec<&'a str>>,
+    ) {
+        let group = stack[stack.len() - 1];
+        for child in self.children.get(group).into_iter().flatten() {
+            if !(child.beg..child.end).contains(&state) {
+                continue;
+            }
+            // Children that are running their own FSM, like the bodies of
+            // `static repeat`, are handled with the dynamic groups.
+            if let Some((early_reset, _)) = self.static_groups.get(&child.group)
+            {
+                if active.contains(early_reset.as_str()) {
+                    continue;
+                }
+            }
+            if let Some((signal, value)) = child.condition {
+                match values[signal] {
+                    Some(v) if (v != 0) == value => (),
+                    _ => continue,
+                }
+            }
+            let mut child_stack = stack.to_vec();
+            child_stack.push(&child.group);
+            self.static_stacks(
+                &child_stack,
+                state - child.beg,
+                values,
+                active,
+                stacks,
+            );
+            stacks.push(child_stack);
+        }
+    }
+
+    /// The stacks of all groups that run when the signals have the given
+    /// values. The stacks start with the outermost group.
+    pub fn active_stacks(&self, values: &[Option<u64>]) -> Vec<Vec<&str>> {
+        let mut active: HashSet<&str> = self
+            .dynamic
+            .iter()
+            .filter(|(_, go)| values[*go].is_some_and(|v| v != 0))
+            .map(|(group, _)| group.as_str())
+            .collect();
+        let static_active = self
+            .static_groups
+            .iter()
+            .filter(|(_, (early_reset, _))| {
+                active.contains(early_reset.as_str())
+            })
+            .map(|(group, (_, fsm))| (group.as_str(), self.state(*fsm, values)))
+            .collect::<Vec<_>>();
+        active.extend(static_active.iter().map(|(group, _)| group));
+
+        let mut memo = HashMap::new();
+        let mut stacks = active
+            .iter()
+            .map(|&group| {
+                self.stack(group, &active, &mut memo, &mut HashSet::new())
+            })
+            .collect::<Vec<_>>();
+        for (group, state) in static_active {
+            if let Some(state) = state {
+                let stack = memo[group].clone();
+                self.static_stacks(&stack, state, values, &active, &mut stacks);
+            }
+        }
+        stacks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(json: &str) -> Program {
+        Program::new(serde_json::from_str(json).unwrap(), "main")
+    }
+
+    /// The values of the signals of `prog`, where only the signals in `set`
+    /// are known.
+    fn values(prog: &Program, set: &[(&str, u64)]) -> Vec<Option<u64>> {
+        prog.signals()
+            .iter()
+            .map(|s| set.iter().find(|(n, _)| n == s).map(|(_, v)| *v))
+            .collect()
+    }
+
+    #[test]
+    fn register_states() {
+        let binary = Register {
+            signal: 0,
+            one_hot: false,
+            beg: 4,
+            end: 8,
+            offset: 1,
+        };
+        assert_eq!(binary.state(1), Some(4));
+        assert_eq!(binary.state(4), Some(7));
+        assert_eq!(binary.state(5), None);
+        assert_eq!(binary.state(0), None);
+
+        let one_hot = Register {
+            signal: 0,
+            one_hot: true,
+            beg: 0,
+            end: 4,
+            offset: 0,
+        };
+        assert_eq!(one_hot.state(0b100), Some(2));
+        assert_eq!(one_hot.state(0b110), None);
+        assert_eq!(one_hot.state(0b10000), None);
+    }
+
+    #[test]
+    fn fsm_and_par() {
+        let prog = program(
+            r#"[
+            {"Fsm": {"component": "main", "group": "tdcc", "fsm": "fsm", "states": [
+                {"id": 0, "value": 0, "group": "A"},
+                {"id": 1, "value": 1, "group": "par0"},
+                {"id": 2, "value": 2, "group": "fsm_END"}]}},
+            {"Par": {"component": "main", "group": "par0", "children": ["B", "C"]}},
+            {"SingleEnable": {"component": "other", "group": "D"}}
+        ]"#,
+        );
+        // the metadata of other components is ignored
+        assert!(!prog.signals().iter().any(|s| s == "D_go.out"));
+
+        let mut stacks = prog.active_stacks(&values(
+            &prog,
+            &[
+                ("tdcc_go.out", 1),
+                ("par0_go.out", 1),
+                ("B_go.out", 1),
+                ("C_go.out", 1),
+            ],
+        ));
+        stacks.sort();
+        assert_eq!(
+            stacks,
+            vec![
+                vec!["tdcc"],
+                vec!["tdcc", "par0"],
+                vec!["tdcc", "par0", "B"],
+                vec!["tdcc", "par0", "C"],
+            ]
+        );
+    }
+
+    #[test]
+    fn static_children() {
+        let prog = program(
+            r#"[
+            {"StaticFsm": {"component": "main",
+                "registers": [{"fsm": "fsm", "one_hot": false, "beg": 0, "end": 4, "offset": 0}],
+                "groups": [{"group": "sg", "early_reset": "early_reset_sg", "wrappers": ["wrapper_sg"]}]}},
+            {"StaticGroup": {"component": "main", "group": "sg", "children": [
+                {"group": "A", "beg": 0, "end": 1, "condition": null},
+                {"group": "B", "beg": 1, "end": 4, "condition": ["cond.out", true]}]}}
+        ]"#,
+        );
+        let run = |state, cond| {
+            let mut stacks = prog.active_stacks(&values(
+                &prog,
+                &[
+                    ("wrapper_sg_go.out", 1),
+                    ("early_reset_sg_go.out", 1),
+                    ("fsm.out", state),
+                    ("cond.out", cond),
+                ],
+            ));
+            stacks.sort();
+            stacks
+        };
+        let outer = vec!["wrapper_sg", "early_reset_sg", "sg"];
+        let with = |child| [outer.clone(), vec![child]].concat();
+
+        assert_eq!(run(0, 0).len(), 4);
+        assert_eq!(run(0, 0)[3], with("A"));
+        assert_eq!(run(2, 1)[3], with("B"));
+        // `B` only runs when its condition holds
+        assert_eq!(run(2, 0).len(), 3);
+        assert_eq!(run(2, 0)[2], outer);
+    }
+}
@@ -0,0 +1,236 @@
+//! A minimal streaming reader for Value Change Dump (VCD) files.
+//!
+//! Only the parts of the format needed to follow the values of signals are
+//! supported: scopes, variables, timestamps, and scalar and vector value
+//! changes. Values wider than 64 bits are truncated, and values that contain
+//! `x` or `z` bits are unknown.
+use crate::ProfilerError;
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+/// A variable defined in the header of a VCD file.
+pub struct Var {
+    /// The names of the scopes that contain the variable, outermost first.
+    pub scope: Vec<String>,
+    pub name: String,
+    /// The identifier code used by value changes of the variable.
+    pub id: String,
+}
+
+/// A change to the value of a variable. `None` is an unknown value.
+pub struct Change {
+    pub id: String,
+    pub value: Option<u64>,
+}
+
+pub struct VcdReader<R: BufRead> {
+    reader: R,
+    tokens: VecDeque<String>,
+}
+
+impl<R: BufRead> VcdReader<R> {
+    pub fn new(reader: R) -> Self {
+        VcdReader {
+            reader,
+            tokens: VecDeque::new(),
+        }
+    }
+
+    fn next_token(&mut self) -> Result<Option<String>, ProfilerError> {
+        while self.tokens.is_empty() {
+            let mut line = String::new();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            self.tokens
+                .extend(line.split_whitespace().map(str::to_string));
+        }
+        Ok(self.tokens.pop_front())
+    }
+
+    fn expect_token(&mut self) -> Result<String, ProfilerError> {
+        self.next_token()?.ok_or_else(|| {
+            ProfilerError::Vcd("unexpected end of file".to_string())
+        })
+    }
+
+    /// Skip the tokens up to and including the next `$end`.
+    fn skip_command(&mut self) -> Result<(), ProfilerError> {
+        while self.expect_token()? != "$end" {}
+        Ok(())
+    }
+
+    /// Read the header and return the variables it defines.
+    pub fn header(&mut self) -> Result<Vec<Var>, ProfilerError> {
+        let mut scope = Vec::new();
+        let mut vars = Vec::new();
+        loop {
+            let token = self.expect_token()?;
+            match token.as_str() {
+                "$scope" => {
+                    let _kind = self.expect_token()?;
+                    scope.push(self.expect_token()?);
+                    self.skip_command()?;
+                }
+                "$upscope" => {
+                    scope.pop();
+                    self.skip_command()?;
+                }
+                "$var" => {
+                    let _kind = self.expect_token()?;
+                    let _width = self.expect_token()?;
+                    let id = self.expect_token()?;
+                    let name = self.expect_token()?;
+                    // Skip the optional bit range
+                    self.skip_command()?;
+                    vars.push(Var {
+                        scope: scope.clone(),
+                        name,
+                        id,
+                    });
+                }
+                "$enddefinitions" => {
+                    self.skip_command()?;
+                    return Ok(vars);
+                }
+                _ if token.starts_with('$') => self.skip_command()?,
+                _ => {
+                    return Err(ProfilerError::Vcd(format!(
+                        "unexpected `{token}` in header"
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Read the value changes up to the next timestamp. Returns the changes
+    /// and the next timestamp, or `None` at the end of the file.
+    pub fn changes(
+        &mut self,
+        changes: &mut Vec<Change>,
+    ) -> Result<Option<u64>, ProfilerError> {
+        changes.clear();
+        while let Some(token) = self.next_token()? {
+            if token == "$comment" {
+                self.skip_command()?;
+                continue;
+            }
+            let Some(first) = token.get(..1) else {
+                return Err(ProfilerError::Vcd(format!(
+                    "unexpected `{token}`"
+                )));
+            };
+            let rest = &token[1..];
+            match first {
+                "#" => {
+                    let time = rest.parse().map_err(|_| {
+                        ProfilerError::Vcd(format!("bad timestamp `{token}`"))
+                    })?;
+                    return Ok(Some(time));
+                }
+                "0" | "1" | "x" | "X" | "z" | "Z" => changes.push(Change {
+                    id: rest.to_string(),
+                    value: first.parse().ok(),
+                }),
+                "b" | "B" => {
+                    let id = self.expect_token()?;
+                    changes.push(Change {
+                        id,
+                        value: parse_binary(rest),
+                    });
+                }
+                // Real values are never used for control signals.
+                "r" | "R" => {
+                    self.expect_token()?;
+                }
+                // `$dumpvars` and friends contain value changes, so only
+                // their keywords and `$end` are skipped.
+                "$" => {}
+                _ => {
+                    return Err(ProfilerError::Vcd(format!(
+                        "unexpected `{token}`"
+                    )))
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Parse a binary value, keeping the 64 least significant bits.
+fn parse_binary(bits: &str) -> Option<u64> {
+    bits.chars().try_fold(0u64, |acc, bit| {
+        let bit = bit.to_digit(2)?;
+        Some((acc << 1) | u64::from(bit))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VCD: &str = r#"$timescale 1ps $end
+$scope module TOP $end
+$scope module main $end
+$var wire 1 ! go $end
+$scope module fsm $end
+$var reg 4 " out [3:0] $end
+$upscope $end
+$upscope $end
+$upscope $end
+$enddefinitions $end
+#0
+$dumpvars
+1!
+bx1 "
+$end
+#10
+$comment ignored $end
+b1010 "
+0!
+"#;
+
+    fn values(changes: &[Change]) -> Vec<(&str, Option<u64>)> {
+        changes.iter().map(|c| (c.id.as_str(), c.value)).collect()
+    }
+
+    #[test]
+    fn header_and_changes() {
+        let mut reader = VcdReader::new(VCD.as_bytes());
+        let vars = reader.header().unwrap();
+        let vars: Vec<_> = vars
+            .iter()
+            .map(|v| (v.scope.join("."), v.name.as_str(), v.id.as_str()))
+            .collect();
+        assert_eq!(
+            vars,
+            vec![
+                ("TOP.main".to_string(), "go", "!"),
+                ("TOP.main.fsm".to_string(), "out", "\""),
+            ]
+        );
+
+        let mut changes = Vec::new();
+        assert_eq!(reader.changes(&mut changes).unwrap(), Some(0));
+        assert!(changes.is_empty());
+        assert_eq!(reader.changes(&mut changes).unwrap(), Some(10));
+        assert_eq!(values(&changes), vec![("!", Some(1)), ("\"", None)]);
+        assert_eq!(reader.changes(&mut changes).unwrap(), None);
+        assert_eq!(values(&changes), vec![("\"", Some(10)), ("!", Some(0))]);
+    }
+
+    #[test]
+    fn truncated_header() {
+        let mut reader =
+            VcdReader::new("$scope module main $end\n$var wire 1".as_bytes());
+        assert!(matches!(reader.header(), Err(ProfilerError::Vcd(_))));
+    }
+
+    #[test]
+    fn binary_values() {
+        assert_eq!(parse_binary("101"), Some(5));
+        assert_eq!(parse_binary("1z"), None);
+        // only the 64 least significant bits are kept
+        assert_eq!(parse_binary(&format!("1{}1", "0".repeat(63))), Some(1));
+    }
+}
@@ -0,0 +1,33 @@
+//! Runs the profiler on the simulations in `tests/data` and compares its
+//! output with the `.expect` file of each simulation. The metadata of a
+//! simulation is the output of `-x tdcc:dump-fsm-json` for the program.
+use std::{fs, path::Path, process::Command};
+
+fn check(name: &str, args: &[&str]) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data");
+    let out = Command::new(env!("CARGO_BIN_EXE_calyx-profiler"))
+        .arg(dir.join(format!("{name}.json")))
+        .arg(dir.join(format!("{name}.vcd")))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let expect =
+        fs::read_to_string(dir.join(format!("{name}.expect"))).unwrap();
+    assert_eq!(String::from_utf8(out.stdout).unwrap(), expect);
+}
+
+#[test]
+fn seq() {
+    check("seq", &[])
+}
+
+/// The program of `seq` instantiated as `seq0` inside of another component.
+#[test]
+fn instance() {
+    check("instance", &["--instance", "main.seq0"])
+}